@@ -1,6 +1,29 @@
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 import 'generated/bindings.dart' as vodozemac;
+import 'generated/bindings/cross_signing.dart' as vodozemac;
+import 'generated/bindings/device_keys.dart' as vodozemac;
+import 'generated/bindings/forwarded_room_key.dart' as vodozemac;
+import 'generated/bindings/key_maintenance.dart' as vodozemac;
+import 'generated/bindings/megolm_signing.dart' as vodozemac;
+import 'generated/bindings/migration.dart' as vodozemac;
+import 'generated/bindings/olm_sessions.dart' as vodozemac;
+import 'generated/bindings/outbound_group_session.dart' as vodozemac;
+import 'generated/bindings/passphrase_pickle.dart' as vodozemac;
+import 'generated/bindings/pickle_format.dart' as vodozemac;
+import 'generated/bindings/rekey.dart' as vodozemac;
+import 'generated/bindings/replay.dart' as vodozemac;
+import 'generated/bindings/retention.dart' as vodozemac;
+import 'generated/bindings/room_event.dart' as vodozemac;
+import 'generated/bindings/room_key.dart' as vodozemac;
+import 'generated/bindings/room_key_bundle.dart' as vodozemac;
+import 'generated/bindings/room_key_info.dart' as vodozemac;
+import 'generated/bindings/share_tracking.dart' as vodozemac;
+import 'generated/bindings/to_device.dart' as vodozemac;
+import 'generated/bindings/trust.dart' as vodozemac;
+import 'generated/bindings/unwedge.dart' as vodozemac;
+import 'generated/bindings/utd_cause.dart' as vodozemac;
+import 'generated/bindings/utd_queue.dart' as vodozemac;
 import 'generated/frb_generated.dart' as vodozemac show RustLib;
 
 /// Initialize by loading the vodozemac library. You can provide the [wasmPath]
@@ -138,6 +161,29 @@ final class GroupSession {
   InboundGroupSession toInbound() =>
       InboundGroupSession._(_session.toInbound());
 
+  /// Encrypt a room event of type [eventType] with JSON [content].
+  ///
+  /// Returns the `m.room.encrypted` content to send to the room.
+  String encryptRoomEvent({
+    required Curve25519PublicKey senderKey,
+    required String deviceId,
+    required String roomId,
+    required String eventType,
+    required String content,
+  }) =>
+      vodozemac.encryptRoomEvent(
+          session: _session,
+          senderKey: senderKey._key,
+          deviceId: deviceId,
+          roomId: roomId,
+          eventType: eventType,
+          content: content);
+
+  /// The `m.room_key` content sharing this session at its current index.
+  String roomKeyContent({required String roomId, bool sharedHistory = false}) =>
+      vodozemac.roomKeyContent(
+          session: _session, roomId: roomId, sharedHistory: sharedHistory);
+
   /// Serialize the session with encryption for storage.
   ///
   /// The pickle can be restored with [fromPickleEncrypted].
@@ -159,6 +205,42 @@ final class GroupSession {
   }) =>
       GroupSession._(vodozemac.VodozemacGroupSession.fromOlmPickleEncrypted(
           pickle: pickle, pickleKey: pickleKey));
+
+  /// Serialize the session with a key derived from [passphrase].
+  ///
+  /// The pickle records the key derivation parameters, so it can be restored
+  /// with [fromPickleWithPassphrase] from the passphrase alone.
+  String toPickleWithPassphrase(
+    String passphrase, {
+    vodozemac.PassphraseKdfParams? params,
+  }) =>
+      _session.pickleWithPassphrase(
+          passphrase: passphrase,
+          params: params ?? vodozemac.PassphraseKdfParams());
+
+  /// Deserialize a session from a pickle protected with a passphrase.
+  static GroupSession fromPickleWithPassphrase({
+    required String pickle,
+    required String passphrase,
+  }) =>
+      GroupSession._(vodozemac.VodozemacGroupSession.fromPickleWithPassphrase(
+          pickle: pickle, passphrase: passphrase));
+
+  /// Deserialize a session from an encrypted pickle in any format.
+  ///
+  /// [pickleKey] can have any length for libolm pickles. The result says
+  /// whether the pickle should be replaced by one from [toPickleEncrypted].
+  static ({GroupSession session, bool needsRepickle}) fromAnyPickle({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) {
+    final result = vodozemac.VodozemacGroupSession.fromAnyPickle(
+        pickle: pickle, pickleKey: pickleKey);
+    return (
+      session: GroupSession._(result.session),
+      needsRepickle: result.needsRepickle,
+    );
+  }
 }
 
 /// Represents a Megolm inbound group session for decrypting messages in Matrix rooms.
@@ -210,6 +292,63 @@ final class InboundGroupSession {
   /// This allows sharing the ability to decrypt all messages this session can decrypt.
   String exportAtFirstKnownIndex() => _session.exportAtFirstKnownIndex();
 
+  /// The Ed25519 key the creator of the session signs its messages with.
+  Ed25519PublicKey get signingKey => Ed25519PublicKey._(_session.signingKey());
+
+  /// Whether the session was created from a session key signed by its creator,
+  /// or only ever imported from an exported one.
+  vodozemac.SessionKeySource get keySource => _session.keySource();
+
+  /// Decrypt a Megolm message and report the key that signed it and where the
+  /// session came from.
+  ({
+    String plaintext,
+    int messageIndex,
+    Ed25519PublicKey signingKey,
+    vodozemac.SessionKeySource keySource,
+  }) decryptExtended(String encrypted) {
+    final result = _session.decryptExtended(encrypted: encrypted);
+    return (
+      plaintext: result.plaintext,
+      messageIndex: result.messageIndex,
+      signingKey: Ed25519PublicKey._(result.signingKey),
+      keySource: result.keySource,
+    );
+  }
+
+  /// Forget the message keys before [index], so older messages can no longer
+  /// be decrypted.
+  ///
+  /// Returns false if the session doesn't know any keys that old.
+  bool discardBefore(int index) => _session.discardBefore(index: index);
+
+  /// Decrypt the `m.room.encrypted` content of an event in [roomId].
+  vodozemac.RoomEventDecryptionResult decryptRoomEvent({
+    required String roomId,
+    required String encryptedContent,
+  }) =>
+      vodozemac.decryptRoomEvent(
+          session: _session,
+          roomId: roomId,
+          encryptedContent: encryptedContent);
+
+  /// Decrypt a room event like [decryptRoomEvent], rejecting it if [guard]
+  /// saw its message index used by another event.
+  vodozemac.RoomEventDecryptionResult decryptRoomEventChecked({
+    required MegolmReplayGuard guard,
+    required String roomId,
+    required String eventId,
+    required int originServerTs,
+    required String encryptedContent,
+  }) =>
+      vodozemac.decryptRoomEventChecked(
+          session: _session,
+          guard: guard._guard,
+          roomId: roomId,
+          eventId: eventId,
+          originServerTs: BigInt.from(originServerTs),
+          encryptedContent: encryptedContent);
+
   /// Serialize the session with encryption for storage.
   ///
   /// The pickle can be restored with [fromPickleEncrypted].
@@ -233,6 +372,43 @@ final class InboundGroupSession {
       InboundGroupSession._(
           vodozemac.VodozemacInboundGroupSession.fromOlmPickleEncrypted(
               pickle: pickle, pickleKey: pickleKey));
+
+  /// Serialize the session with a key derived from [passphrase].
+  ///
+  /// The pickle records the key derivation parameters, so it can be restored
+  /// with [fromPickleWithPassphrase] from the passphrase alone.
+  String toPickleWithPassphrase(
+    String passphrase, {
+    vodozemac.PassphraseKdfParams? params,
+  }) =>
+      _session.pickleWithPassphrase(
+          passphrase: passphrase,
+          params: params ?? vodozemac.PassphraseKdfParams());
+
+  /// Deserialize a session from a pickle protected with a passphrase.
+  static InboundGroupSession fromPickleWithPassphrase({
+    required String pickle,
+    required String passphrase,
+  }) =>
+      InboundGroupSession._(
+          vodozemac.VodozemacInboundGroupSession.fromPickleWithPassphrase(
+              pickle: pickle, passphrase: passphrase));
+
+  /// Deserialize a session from an encrypted pickle in any format.
+  ///
+  /// [pickleKey] can have any length for libolm pickles. The result says
+  /// whether the pickle should be replaced by one from [toPickleEncrypted].
+  static ({InboundGroupSession session, bool needsRepickle}) fromAnyPickle({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) {
+    final result = vodozemac.VodozemacInboundGroupSession.fromAnyPickle(
+        pickle: pickle, pickleKey: pickleKey);
+    return (
+      session: InboundGroupSession._(result.session),
+      needsRepickle: result.needsRepickle,
+    );
+  }
 }
 
 /// Represents an Olm session for end-to-end encrypted communication between two devices.
@@ -295,6 +471,42 @@ final class Session {
   }) =>
       Session._(vodozemac.VodozemacSession.fromOlmPickleEncrypted(
           pickle: pickle, pickleKey: pickleKey));
+
+  /// Serialize the session with a key derived from [passphrase].
+  ///
+  /// The pickle records the key derivation parameters, so it can be restored
+  /// with [fromPickleWithPassphrase] from the passphrase alone.
+  String toPickleWithPassphrase(
+    String passphrase, {
+    vodozemac.PassphraseKdfParams? params,
+  }) =>
+      _session.pickleWithPassphrase(
+          passphrase: passphrase,
+          params: params ?? vodozemac.PassphraseKdfParams());
+
+  /// Deserialize a session from a pickle protected with a passphrase.
+  static Session fromPickleWithPassphrase({
+    required String pickle,
+    required String passphrase,
+  }) =>
+      Session._(vodozemac.VodozemacSession.fromPickleWithPassphrase(
+          pickle: pickle, passphrase: passphrase));
+
+  /// Deserialize a session from an encrypted pickle in any format.
+  ///
+  /// [pickleKey] can have any length for libolm pickles. The result says
+  /// whether the pickle should be replaced by one from [toPickleEncrypted].
+  static ({Session session, bool needsRepickle}) fromAnyPickle({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) {
+    final result = vodozemac.VodozemacSession.fromAnyPickle(
+        pickle: pickle, pickleKey: pickleKey);
+    return (
+      session: Session._(result.session),
+      needsRepickle: result.needsRepickle,
+    );
+  }
 }
 
 /// Represents a Matrix account for end-to-end encryption.
@@ -399,6 +611,117 @@ final class Account {
     return (session: Session._(inb.session), plaintext: inb.plaintext);
   }
 
+  /// Create an outbound Olm session from a key returned by `/keys/claim`.
+  ///
+  /// [claimedKeyJson] maps the key id to the signed key. The signature is
+  /// checked against the Ed25519 key of [deviceKeys] before the key is used.
+  /// [fallback] is true if the device handed out its fallback key.
+  ({Session session, bool fallback}) createOutboundSessionFromClaim({
+    required DeviceKeys deviceKeys,
+    required String claimedKeyJson,
+  }) {
+    final claimed = _account.createOutboundSessionFromClaim(
+        config: vodozemac.VodozemacOlmSessionConfig.def(),
+        deviceKeys: deviceKeys._keys,
+        claimedKeyJson: claimedKeyJson);
+    return (session: Session._(claimed.session), fallback: claimed.fallback);
+  }
+
+  /// Generate the one-time and fallback keys needed after a `/sync`.
+  ///
+  /// [serverCounts] and [unusedFallbackTypes] are the
+  /// `device_one_time_keys_count` and `device_unused_fallback_key_types` of
+  /// the sync response. New keys still have to be uploaded.
+  ({int generateOneTimeKeys, bool rotateFallbackKey, bool forgetOldFallbackKey})
+      planKeyMaintenance({
+    required FallbackKeyTracker tracker,
+    required Map<String, int> serverCounts,
+    List<String>? unusedFallbackTypes,
+    required int nowMs,
+  }) {
+    final plan = _account.planKeyMaintenance(
+        tracker: tracker._tracker,
+        serverCounts: serverCounts,
+        unusedFallbackTypes: unusedFallbackTypes,
+        nowMs: BigInt.from(nowMs));
+    return (
+      generateOneTimeKeys: plan.generateOneTimeKeys.toInt(),
+      rotateFallbackKey: plan.rotateFallbackKey,
+      forgetOldFallbackKey: plan.forgetOldFallbackKey,
+    );
+  }
+
+  /// Replace a wedged session with a new one created from [claimedKeyJson].
+  ///
+  /// Returns the new session and the `m.room.encrypted` content of the dummy
+  /// event that has to be sent to the device. [UnwedgeTracker.startAttempt]
+  /// has to succeed for the device before its key is claimed.
+  ({Session session, String encryptedContent}) unwedgeSession({
+    required UnwedgeTracker tracker,
+    required String ownUserId,
+    required String ownDeviceId,
+    required DeviceKeys deviceKeys,
+    required String claimedKeyJson,
+  }) {
+    final result = _account.unwedgeSession(
+        tracker: tracker._tracker,
+        config: vodozemac.VodozemacOlmSessionConfig.def(),
+        ownUserId: ownUserId,
+        ownDeviceId: ownDeviceId,
+        deviceKeys: deviceKeys._keys,
+        claimedKeyJson: claimedKeyJson);
+    return (
+      session: Session._(result.session),
+      encryptedContent: result.encryptedContent,
+    );
+  }
+
+  /// Encrypt a to-device event for [recipient].
+  ///
+  /// Returns the `m.room.encrypted` content to send.
+  String encryptToDevice({
+    required Session session,
+    required String sender,
+    required String senderDevice,
+    required DeviceKeys recipient,
+    required String eventType,
+    required String content,
+  }) =>
+      vodozemac.encryptToDevice(
+          account: _account,
+          session: session._session,
+          sender: sender,
+          senderDevice: senderDevice,
+          recipient: recipient._keys,
+          eventType: eventType,
+          content: content);
+
+  /// Decrypt an `m.room.encrypted` to-device event.
+  ///
+  /// [sessionsForSender] are tried before a pre-key message creates a new
+  /// session, which is returned with the result.
+  ToDeviceDecryptionResult decryptToDevice({
+    required List<Session> sessionsForSender,
+    required String eventJson,
+  }) =>
+      ToDeviceDecryptionResult._(vodozemac.decryptToDevice(
+          account: _account,
+          sessionsForSender: sessionsForSender.map((s) => s._session).toList(),
+          eventJson: eventJson));
+
+  /// Validate the decrypted payload of a to-device event sent to us by
+  /// [eventSender].
+  OlmPayload validateToDevicePayload({
+    required String ownUserId,
+    required String eventSender,
+    required String plaintext,
+  }) =>
+      OlmPayload._(vodozemac.validateToDevicePayload(
+          account: _account,
+          ownUserId: ownUserId,
+          eventSender: eventSender,
+          plaintext: plaintext));
+
   /// Serialize the account with encryption for storage.
   ///
   /// The pickle can be restored with [fromPickleEncrypted].
@@ -420,6 +743,42 @@ final class Account {
   }) =>
       Account._(vodozemac.VodozemacAccount.fromOlmPickleEncrypted(
           pickle: pickle, pickleKey: pickleKey));
+
+  /// Serialize the account with a key derived from [passphrase].
+  ///
+  /// The pickle records the key derivation parameters, so it can be restored
+  /// with [fromPickleWithPassphrase] from the passphrase alone.
+  String toPickleWithPassphrase(
+    String passphrase, {
+    vodozemac.PassphraseKdfParams? params,
+  }) =>
+      _account.pickleWithPassphrase(
+          passphrase: passphrase,
+          params: params ?? vodozemac.PassphraseKdfParams());
+
+  /// Deserialize an account from a pickle protected with a passphrase.
+  static Account fromPickleWithPassphrase({
+    required String pickle,
+    required String passphrase,
+  }) =>
+      Account._(vodozemac.VodozemacAccount.fromPickleWithPassphrase(
+          pickle: pickle, passphrase: passphrase));
+
+  /// Deserialize an account from an encrypted pickle in any format.
+  ///
+  /// [pickleKey] can have any length for libolm pickles. The result says
+  /// whether the pickle should be replaced by one from [toPickleEncrypted].
+  static ({Account account, bool needsRepickle}) fromAnyPickle({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) {
+    final result = vodozemac.VodozemacAccount.fromAnyPickle(
+        pickle: pickle, pickleKey: pickleKey);
+    return (
+      account: Account._(result.account),
+      needsRepickle: result.needsRepickle,
+    );
+  }
 }
 
 /// Represents a Short Authentication String (SAS) verification process.
@@ -593,6 +952,42 @@ final class PkDecryption {
   /// Serialize to a libolm pickle.
   String toLibolmPickle(Uint8List pickleKey) =>
       _decryption.toLibolmPickle(pickleKey: vodozemac.U8Array32(pickleKey));
+
+  /// Serialize the key pair with a key derived from [passphrase].
+  ///
+  /// The pickle records the key derivation parameters, so it can be restored
+  /// with [fromPickleWithPassphrase] from the passphrase alone.
+  String toPickleWithPassphrase(
+    String passphrase, {
+    vodozemac.PassphraseKdfParams? params,
+  }) =>
+      _decryption.pickleWithPassphrase(
+          passphrase: passphrase,
+          params: params ?? vodozemac.PassphraseKdfParams());
+
+  /// Deserialize a key pair from a pickle protected with a passphrase.
+  static PkDecryption fromPickleWithPassphrase({
+    required String pickle,
+    required String passphrase,
+  }) =>
+      PkDecryption._(vodozemac.VodozemacPkDecryption.fromPickleWithPassphrase(
+          pickle: pickle, passphrase: passphrase));
+
+  /// Deserialize a key pair from an encrypted pickle in any format.
+  ///
+  /// [pickleKey] can have any length for libolm pickles. The result says
+  /// whether the pickle should be replaced by one from [toLibolmPickle].
+  static ({PkDecryption pkDecryption, bool needsRepickle}) fromAnyPickle({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) {
+    final result = vodozemac.VodozemacPkDecryption.fromAnyPickle(
+        pickle: pickle, pickleKey: pickleKey);
+    return (
+      pkDecryption: PkDecryption._(result.pkDecryption),
+      needsRepickle: result.needsRepickle,
+    );
+  }
 }
 
 /// Used for signing messages with a public key.
@@ -622,6 +1017,735 @@ final class PkSigning {
       Ed25519Signature._(_signing.sign(message: message));
 }
 
+/// The identity keys of a device, as published in `/keys/query` responses.
+///
+/// Reference: https://spec.matrix.org/latest/client-server-api/#post_matrixclientv3keysquery
+final class DeviceKeys {
+  final vodozemac.DeviceKeys _keys;
+
+  DeviceKeys._(this._keys);
+
+  /// Parse the device keys object of [deviceId] and check its self-signature.
+  ///
+  /// Throws a [vodozemac.DeviceKeysError] if the object doesn't belong to
+  /// [userId] and [deviceId] or isn't signed by its own Ed25519 key.
+  factory DeviceKeys.parseAndVerify({
+    required String json,
+    required String userId,
+    required String deviceId,
+  }) =>
+      DeviceKeys._(vodozemac.DeviceKeys.parseAndVerify(
+          json: json, expectedUser: userId, expectedDevice: deviceId));
+
+  String get userId => _keys.userId;
+
+  String get deviceId => _keys.deviceId;
+
+  List<String> get algorithms => _keys.algorithms;
+
+  String? get displayName => _keys.displayName;
+
+  /// The Ed25519 key the device signs with.
+  Ed25519PublicKey get ed25519Key => Ed25519PublicKey._(_keys.ed25519);
+
+  /// The Curve25519 key used to establish Olm sessions with the device.
+  Curve25519PublicKey get curve25519Key =>
+      Curve25519PublicKey._(_keys.curve25519);
+}
+
+/// The validated payload of a decrypted to-device event.
+final class OlmPayload {
+  final vodozemac.OlmPayload _payload;
+
+  OlmPayload._(this._payload);
+
+  String get eventType => _payload.eventType;
+
+  /// The JSON content of the event.
+  String get content => _payload.content;
+
+  String get sender => _payload.sender;
+
+  String? get senderDevice => _payload.senderDevice;
+
+  /// The Ed25519 key the sender claims to own.
+  ///
+  /// It is only authenticated once the Curve25519 key of the session was
+  /// matched to a device with this Ed25519 key.
+  Ed25519PublicKey get senderClaimedEd25519 =>
+      Ed25519PublicKey._(_payload.senderClaimedEd25519);
+}
+
+/// A decrypted to-device event.
+final class ToDeviceDecryptionResult {
+  final vodozemac.ToDeviceDecryptionResult _result;
+
+  ToDeviceDecryptionResult._(this._result);
+
+  /// The decrypted payload, to be checked with
+  /// [Account.validateToDevicePayload].
+  String get plaintext => _result.plaintext;
+
+  Curve25519PublicKey get senderKey =>
+      Curve25519PublicKey._(_result.senderKey);
+
+  /// The id of the session that decrypted the event.
+  String get sessionId => _result.sessionId;
+
+  /// The session created from a pre-key message, if none of the known
+  /// sessions could decrypt it.
+  Session? get newSession {
+    final session = _result.newSession;
+    return session == null ? null : Session._(session);
+  }
+}
+
+/// Our own cross-signing keys: the master, self-signing and user-signing keys.
+///
+/// Reference: https://spec.matrix.org/latest/client-server-api/#cross-signing
+final class CrossSigningIdentity {
+  final vodozemac.CrossSigningIdentity _identity;
+
+  CrossSigningIdentity._(this._identity);
+
+  /// Generate a fresh set of cross-signing keys for [userId].
+  factory CrossSigningIdentity(String userId) =>
+      CrossSigningIdentity._(vodozemac.CrossSigningIdentity(userId: userId));
+
+  /// Import existing keys from their secrets, as used by
+  /// [PkSigning.fromSecretKey].
+  factory CrossSigningIdentity.fromSecretKeys({
+    required String userId,
+    required String masterKey,
+    required String selfSigningKey,
+    required String userSigningKey,
+  }) =>
+      CrossSigningIdentity._(vodozemac.CrossSigningIdentity.fromSecretKeys(
+          userId: userId,
+          masterKey: masterKey,
+          selfSigningKey: selfSigningKey,
+          userSigningKey: userSigningKey));
+
+  String get userId => _identity.userId();
+
+  Ed25519PublicKey get masterKey => Ed25519PublicKey._(_identity.masterKey());
+
+  Ed25519PublicKey get selfSigningKey =>
+      Ed25519PublicKey._(_identity.selfSigningKey());
+
+  Ed25519PublicKey get userSigningKey =>
+      Ed25519PublicKey._(_identity.userSigningKey());
+
+  String get masterSecretKey => _identity.masterSecretKey();
+
+  String get selfSigningSecretKey => _identity.selfSigningSecretKey();
+
+  String get userSigningSecretKey => _identity.userSigningSecretKey();
+
+  /// The request body for `/keys/device_signing/upload`, without the `auth`
+  /// field.
+  String deviceSigningUpload() => _identity.deviceSigningUpload();
+
+  /// Sign one of our own devices with the self-signing key.
+  ///
+  /// Takes the device keys as returned by `/keys/query` and returns the
+  /// request body for `/keys/signatures/upload`.
+  String signDevice(String deviceKeys) =>
+      _identity.signDevice(deviceKeys: deviceKeys);
+
+  /// Sign the master key of another user with the user-signing key.
+  ///
+  /// Takes the master key as returned by `/keys/query` and returns the
+  /// request body for `/keys/signatures/upload`.
+  String signUser(String masterKey) => _identity.signUser(masterKey: masterKey);
+
+  /// Evaluate the trust of every device in a `/keys/query` response.
+  ///
+  /// [ownMasterKey] and [ownUserSigningKey] must come from a trusted source,
+  /// not from the response itself.
+  static List<vodozemac.DeviceTrust> evaluateDeviceTrust({
+    required String ownUserId,
+    required Ed25519PublicKey ownMasterKey,
+    Ed25519PublicKey? ownUserSigningKey,
+    required String keysQueryResponse,
+  }) =>
+      vodozemac.evaluateDeviceTrust(
+          ownUserId: ownUserId,
+          ownMasterKey: ownMasterKey._key,
+          ownUserSigningKey: ownUserSigningKey?._key,
+          keysQueryResponse: keysQueryResponse);
+}
+
+/// Remembers when the fallback key was reported unused, so the previous
+/// fallback key is only forgotten after a grace period.
+///
+/// Used by [Account.planKeyMaintenance] and pickled next to the account.
+final class FallbackKeyTracker {
+  final vodozemac.FallbackKeyTracker _tracker;
+
+  FallbackKeyTracker._(this._tracker);
+
+  factory FallbackKeyTracker() =>
+      FallbackKeyTracker._(vodozemac.FallbackKeyTracker());
+
+  int get gracePeriodMs => _tracker.gracePeriodMs().toInt();
+
+  set gracePeriodMs(int value) =>
+      _tracker.setGracePeriodMs(gracePeriodMs: BigInt.from(value));
+
+  /// Serialize the tracker with encryption for storage.
+  String toPickleEncrypted(Uint8List pickleKey) =>
+      _tracker.pickleEncrypted(pickleKey: vodozemac.U8Array32(pickleKey));
+
+  /// Deserialize a tracker from an encrypted pickle.
+  static FallbackKeyTracker fromPickleEncrypted({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) =>
+      FallbackKeyTracker._(vodozemac.FallbackKeyTracker.fromPickleEncrypted(
+          pickle: pickle, pickleKey: vodozemac.U8Array32(pickleKey)));
+}
+
+/// The Olm sessions with other devices, keyed by the Curve25519 key of the
+/// device.
+///
+/// The sessions handed out share their state with the store, so using them
+/// updates the sessions that get pickled with the store.
+final class OlmSessionStore {
+  final vodozemac.OlmSessionStore _store;
+
+  OlmSessionStore._(this._store);
+
+  factory OlmSessionStore() => OlmSessionStore._(vodozemac.OlmSessionStore());
+
+  int get maxSessionsPerDevice => _store.maxSessionsPerDevice();
+
+  /// Change the session limit, returning the ids of the pruned sessions.
+  List<String> setMaxSessionsPerDevice(int maxSessionsPerDevice) =>
+      _store.setMaxSessionsPerDevice(
+          maxSessionsPerDevice: maxSessionsPerDevice);
+
+  /// Add a newly created session with the device owning [senderKey].
+  ///
+  /// Returns the ids of the sessions pruned to stay within the limit.
+  List<String> addSession({
+    required String senderKey,
+    required Session session,
+    required int nowMs,
+  }) =>
+      _store.addSession(
+          senderKey: senderKey,
+          session: session._session,
+          nowMs: BigInt.from(nowMs));
+
+  /// Record that a session was used to encrypt or decrypt a message.
+  ///
+  /// Returns false if the session is not in the store.
+  bool markUsed({
+    required String senderKey,
+    required String sessionId,
+    required int nowMs,
+  }) =>
+      _store.markUsed(
+          senderKey: senderKey,
+          sessionId: sessionId,
+          nowMs: BigInt.from(nowMs));
+
+  /// The session to encrypt the next message to the device with.
+  Session? sessionForEncryption(String senderKey) {
+    final session = _store.sessionForEncryption(senderKey: senderKey);
+    return session == null ? null : Session._(session);
+  }
+
+  /// All sessions with the device, in the order they should be tried for
+  /// decryption.
+  List<Session> sessionsForDevice(String senderKey) => _store
+      .sessionsForDevice(senderKey: senderKey)
+      .map((s) => Session._(s))
+      .toList();
+
+  bool removeSession({required String senderKey, required String sessionId}) =>
+      _store.removeSession(senderKey: senderKey, sessionId: sessionId);
+
+  List<String> get senderKeys => _store.senderKeys();
+
+  /// Serialize the store with encryption for storage.
+  String toPickleEncrypted(Uint8List pickleKey) =>
+      _store.pickleEncrypted(pickleKey: vodozemac.U8Array32(pickleKey));
+
+  /// Deserialize a store from an encrypted pickle.
+  static OlmSessionStore fromPickleEncrypted({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) =>
+      OlmSessionStore._(vodozemac.OlmSessionStore.fromPickleEncrypted(
+          pickle: pickle, pickleKey: vodozemac.U8Array32(pickleKey)));
+}
+
+/// A Megolm group session for a room, with the rotation settings of the room
+/// and the devices the key was shared with.
+///
+/// Reference: https://spec.matrix.org/latest/client-server-api/#mroomencryption
+final class OutboundGroupSession {
+  final vodozemac.OutboundGroupSession _session;
+
+  OutboundGroupSession._(this._session);
+
+  /// Create a new session for a room whose `m.room.encryption` event contains
+  /// the given rotation periods. Missing periods use the defaults of the spec.
+  factory OutboundGroupSession({
+    required int createdAtMs,
+    int? rotationPeriodMs,
+    int? rotationPeriodMsgs,
+  }) =>
+      OutboundGroupSession._(vodozemac.OutboundGroupSession(
+          config: vodozemac.VodozemacMegolmSessionConfig.def(),
+          createdAtMs: BigInt.from(createdAtMs),
+          rotationPeriodMs: _bigInt(rotationPeriodMs),
+          rotationPeriodMsgs: _bigInt(rotationPeriodMsgs)));
+
+  /// Track the rotation of an existing session.
+  factory OutboundGroupSession.fromGroupSession({
+    required GroupSession session,
+    required int createdAtMs,
+    int? rotationPeriodMs,
+    int? rotationPeriodMsgs,
+  }) =>
+      OutboundGroupSession._(vodozemac.OutboundGroupSession.fromGroupSession(
+          session: session._session,
+          createdAtMs: BigInt.from(createdAtMs),
+          rotationPeriodMs: _bigInt(rotationPeriodMs),
+          rotationPeriodMsgs: _bigInt(rotationPeriodMsgs)));
+
+  /// The session to encrypt with. Its messages count towards the rotation.
+  GroupSession get groupSession => GroupSession._(_session.groupSession());
+
+  String get sessionId => _session.sessionId();
+
+  int get createdAtMs => _session.createdAtMs().toInt();
+
+  int get rotationPeriodMs => _session.rotationPeriodMs().toInt();
+
+  int get rotationPeriodMsgs => _session.rotationPeriodMsgs().toInt();
+
+  /// Force a rotation before the next message, e.g. because a member left.
+  void invalidate() => _session.invalidate();
+
+  /// Why the session has to be replaced before the next message, if it has to.
+  vodozemac.RotationReason? expiredReason(int nowMs) =>
+      _session.expiredReason(nowMs: BigInt.from(nowMs));
+
+  bool needsRotation(int nowMs) =>
+      _session.needsRotation(nowMs: BigInt.from(nowMs));
+
+  /// Record that the room key was sent to a device at the current index.
+  void recordShare(vodozemac.ShareRecipient recipient) =>
+      _session.recordShare(recipient: recipient);
+
+  /// Record that an `m.room_key.withheld` was sent to a device instead.
+  void recordWithheld(
+    vodozemac.ShareRecipient recipient,
+    vodozemac.WithheldCode code,
+  ) =>
+      _session.recordWithheld(recipient: recipient, code: code);
+
+  /// The message index the key was shared at with the device, if it was.
+  int? sharedMessageIndex(vodozemac.ShareRecipient recipient) =>
+      _session.sharedMessageIndex(recipient: recipient);
+
+  /// The reason the key was withheld from the device, if it was.
+  vodozemac.WithheldCode? withheldCode(vodozemac.ShareRecipient recipient) =>
+      _session.withheldCode(recipient: recipient);
+
+  /// The [devices] that neither received the key nor were told it was
+  /// withheld.
+  List<vodozemac.ShareRecipient> devicesMissingKey(
+    List<vodozemac.ShareRecipient> devices,
+  ) =>
+      _session.devicesMissingKey(devices: devices);
+
+  /// The devices that received the key but are not part of [devices] anymore.
+  ///
+  /// If any device is returned, the session has to be rotated.
+  List<vodozemac.ShareRecipient> removedDevices(
+    List<vodozemac.ShareRecipient> devices,
+  ) =>
+      _session.removedDevices(devices: devices);
+
+  /// Serialize the session with encryption for storage.
+  String toPickleEncrypted(Uint8List pickleKey) =>
+      _session.pickleEncrypted(pickleKey: vodozemac.U8Array32(pickleKey));
+
+  /// Deserialize a session from an encrypted pickle.
+  static OutboundGroupSession fromPickleEncrypted({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) =>
+      OutboundGroupSession._(
+          vodozemac.OutboundGroupSession.fromPickleEncrypted(
+              pickle: pickle, pickleKey: vodozemac.U8Array32(pickleKey)));
+}
+
+/// Remembers which event used which message index of an inbound group session,
+/// to detect replayed room events.
+final class MegolmReplayGuard {
+  final vodozemac.MegolmReplayGuard _guard;
+
+  MegolmReplayGuard._(this._guard);
+
+  factory MegolmReplayGuard() =>
+      MegolmReplayGuard._(vodozemac.MegolmReplayGuard());
+
+  /// Record that an event used a message index of a session.
+  ///
+  /// Returns false if a different event already used the index.
+  bool record({
+    required String sessionId,
+    required int messageIndex,
+    required String eventId,
+    required int originServerTs,
+  }) =>
+      _guard.record(
+          sessionId: sessionId,
+          messageIndex: messageIndex,
+          eventId: eventId,
+          originServerTs: BigInt.from(originServerTs));
+
+  /// Forget everything recorded for a session.
+  void forgetSession(String sessionId) =>
+      _guard.forgetSession(sessionId: sessionId);
+
+  /// Forget the events recorded for the message indices of a session before
+  /// [index], e.g. after `discardBefore(index)`.
+  void forgetBefore(String sessionId, int index) =>
+      _guard.forgetBefore(sessionId: sessionId, index: index);
+
+  /// Serialize the guard with encryption for storage.
+  String toPickleEncrypted(Uint8List pickleKey) =>
+      _guard.pickleEncrypted(pickleKey: vodozemac.U8Array32(pickleKey));
+
+  /// Deserialize a guard from an encrypted pickle.
+  static MegolmReplayGuard fromPickleEncrypted({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) =>
+      MegolmReplayGuard._(vodozemac.MegolmReplayGuard.fromPickleEncrypted(
+          pickle: pickle, pickleKey: vodozemac.U8Array32(pickleKey)));
+}
+
+/// An inbound group session together with where its key came from.
+final class InboundRoomKey {
+  final vodozemac.InboundRoomKey _key;
+
+  InboundRoomKey._(this._key);
+
+  /// Bundle [session] with its [info]. The session state is shared.
+  factory InboundRoomKey({
+    required InboundGroupSession session,
+    required vodozemac.RoomKeyInfo info,
+  }) =>
+      InboundRoomKey._(
+          vodozemac.InboundRoomKey(session: session._session, info: info));
+
+  /// Create the session from a decrypted `m.room_key` to-device event.
+  ///
+  /// [senderKey] is the Curve25519 key of the Olm session that decrypted
+  /// [payload]. Both keys of the payload have to belong to [senderDevice].
+  factory InboundRoomKey.fromRoomKey({
+    required OlmPayload payload,
+    required Curve25519PublicKey senderKey,
+    required DeviceKeys senderDevice,
+  }) =>
+      InboundRoomKey._(vodozemac.receiveRoomKey(
+          payload: payload._payload,
+          senderKey: senderKey._key,
+          senderDevice: senderDevice._keys));
+
+  /// Import the session of a decrypted `m.forwarded_room_key` event.
+  ///
+  /// The forwarder is appended to the forwarding chain. The session is never
+  /// authenticated, the forwarder could have created it itself.
+  factory InboundRoomKey.fromForwardedRoomKey({
+    required OlmPayload payload,
+    required Curve25519PublicKey forwarderKey,
+    required DeviceKeys forwarderDevice,
+  }) =>
+      InboundRoomKey._(vodozemac.receiveForwardedRoomKey(
+          payload: payload._payload,
+          forwarderKey: forwarderKey._key,
+          forwarderDevice: forwarderDevice._keys));
+
+  String get sessionId => _key.sessionId();
+
+  int get firstKnownIndex => _key.firstKnownIndex();
+
+  /// The session, sharing its state with this key.
+  InboundGroupSession get inboundGroupSession =>
+      InboundGroupSession._(_key.inboundGroupSession());
+
+  vodozemac.RoomKeyInfo get info => _key.info();
+
+  void markBackedUp(bool backedUp) => _key.markBackedUp(backedUp: backedUp);
+
+  /// Decrypt the `m.room.encrypted` content of an event in [roomId].
+  ///
+  /// The room has to match the room the session was shared for.
+  vodozemac.RoomKeyDecryptionResult decryptRoomEvent({
+    required String roomId,
+    required String encryptedContent,
+  }) =>
+      _key.decryptRoomEvent(roomId: roomId, encryptedContent: encryptedContent);
+
+  /// Build the `m.forwarded_room_key` content to forward this key.
+  ///
+  /// The session is exported at [messageIndex], or at its first known index.
+  /// Returns null if the session doesn't know the index anymore.
+  String? forwardedRoomKeyContent({int? messageIndex}) =>
+      vodozemac.forwardedRoomKeyContent(
+          roomKey: _key, messageIndex: messageIndex);
+
+  /// Serialize the key with encryption for storage.
+  String toPickleEncrypted(Uint8List pickleKey) =>
+      _key.pickleEncrypted(pickleKey: vodozemac.U8Array32(pickleKey));
+
+  /// Deserialize a key from an encrypted pickle.
+  static InboundRoomKey fromPickleEncrypted({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) =>
+      InboundRoomKey._(vodozemac.InboundRoomKey.fromPickleEncrypted(
+          pickle: pickle, pickleKey: vodozemac.U8Array32(pickleKey)));
+
+  /// Discard the messages older than the retention of their room from pickled
+  /// keys.
+  ///
+  /// Message indices are mapped to time with the events recorded in [guard].
+  /// The results are in the order of [pickles].
+  static List<vodozemac.RetentionResult> applyRoomRetention({
+    required List<String> pickles,
+    required Uint8List pickleKey,
+    required List<vodozemac.RoomRetention> policies,
+    required MegolmReplayGuard guard,
+    required int nowMs,
+  }) =>
+      vodozemac.applyRoomRetention(
+          pickles: pickles,
+          pickleKey: vodozemac.U8Array32(pickleKey),
+          policies: policies,
+          guard: guard._guard,
+          nowMs: BigInt.from(nowMs));
+}
+
+/// The room keys to share with a user invited to a room, encrypted as an
+/// attachment (MSC4268).
+final class EncryptedRoomKeyBundle {
+  final vodozemac.EncryptedRoomKeyBundle _bundle;
+
+  EncryptedRoomKeyBundle._(this._bundle);
+
+  /// Export the keys of [roomKeys] that belong to [roomId] and may be shared
+  /// with new members, encrypted with a fresh key.
+  factory EncryptedRoomKeyBundle({
+    required String roomId,
+    required List<InboundRoomKey> roomKeys,
+  }) =>
+      EncryptedRoomKeyBundle._(vodozemac.createRoomKeyBundle(
+          roomId: roomId, roomKeys: roomKeys.map((k) => k._key).toList()));
+
+  String get roomId => _bundle.roomId;
+
+  /// The ciphertext to upload to the media repository.
+  Uint8List get ciphertext => _bundle.ciphertext;
+
+  /// Encrypt the location and key of the uploaded bundle for the invitee's
+  /// device.
+  ///
+  /// [url] is the `mxc://` URI the ciphertext was uploaded to. Returns the
+  /// `m.room.encrypted` content to send to the device.
+  String encryptForDevice({
+    required String url,
+    required Account account,
+    required Session session,
+    required String sender,
+    required String senderDevice,
+    required DeviceKeys recipient,
+  }) =>
+      _bundle.encryptForDevice(
+          url: url,
+          account: account._account,
+          session: session._session,
+          sender: sender,
+          senderDevice: senderDevice,
+          recipient: recipient._keys);
+}
+
+/// The location and key of a room key bundle, read from a to-device event.
+final class RoomKeyBundleDownload {
+  final vodozemac.RoomKeyBundleDownload _download;
+
+  RoomKeyBundleDownload._(this._download);
+
+  /// Read the bundle from a decrypted to-device payload.
+  ///
+  /// Like room keys, the payload has to be sent over an Olm session with
+  /// [senderDevice].
+  factory RoomKeyBundleDownload.fromPayload({
+    required OlmPayload payload,
+    required Curve25519PublicKey senderKey,
+    required DeviceKeys senderDevice,
+  }) =>
+      RoomKeyBundleDownload._(vodozemac.receiveRoomKeyBundle(
+          payload: payload._payload,
+          senderKey: senderKey._key,
+          senderDevice: senderDevice._keys));
+
+  String get roomId => _download.roomId;
+
+  /// The `mxc://` URI to download the ciphertext from.
+  String get url => _download.url;
+
+  /// Decrypt the downloaded bundle and import the keys in it.
+  ///
+  /// [skipped] counts the keys that were left out, e.g. because they are not
+  /// marked as shared history.
+  ({List<InboundRoomKey> roomKeys, int skipped}) decrypt(Uint8List ciphertext) {
+    final bundle = _download.decrypt(ciphertext: ciphertext);
+    return (
+      roomKeys: bundle.roomKeys.map((k) => InboundRoomKey._(k)).toList(),
+      skipped: bundle.skipped,
+    );
+  }
+}
+
+/// The attempts to unwedge Olm sessions per device, pickled next to the
+/// account.
+///
+/// Reference: https://spec.matrix.org/latest/client-server-api/#recovering-from-undecryptable-messages
+final class UnwedgeTracker {
+  final vodozemac.UnwedgeTracker _tracker;
+
+  UnwedgeTracker._(this._tracker);
+
+  factory UnwedgeTracker() => UnwedgeTracker._(vodozemac.UnwedgeTracker());
+
+  int get cooldownMs => _tracker.cooldownMs().toInt();
+
+  set cooldownMs(int value) =>
+      _tracker.setCooldownMs(cooldownMs: BigInt.from(value));
+
+  /// Whether a new session may be created for the device with [senderKey].
+  bool canUnwedge({required String senderKey, required int nowMs}) =>
+      _tracker.canUnwedge(senderKey: senderKey, nowMs: BigInt.from(nowMs));
+
+  /// Record an attempt to unwedge the device with [senderKey], before a
+  /// one-time key is claimed for it.
+  ///
+  /// Throws [vodozemac.UnwedgeError.rateLimited] if the device was already
+  /// unwedged within the cooldown, in which case no key must be claimed.
+  void startAttempt({required String senderKey, required int nowMs}) =>
+      _tracker.startAttempt(senderKey: senderKey, nowMs: BigInt.from(nowMs));
+
+  /// Serialize the tracker with encryption for storage.
+  String toPickleEncrypted(Uint8List pickleKey) =>
+      _tracker.pickleEncrypted(pickleKey: vodozemac.U8Array32(pickleKey));
+
+  /// Deserialize a tracker from an encrypted pickle.
+  static UnwedgeTracker fromPickleEncrypted({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) =>
+      UnwedgeTracker._(vodozemac.UnwedgeTracker.fromPickleEncrypted(
+          pickle: pickle, pickleKey: vodozemac.U8Array32(pickleKey)));
+}
+
+/// Room events that couldn't be decrypted yet, retried when their room key
+/// arrives.
+final class UtdQueue {
+  final vodozemac.UtdQueue _queue;
+
+  UtdQueue._(this._queue);
+
+  factory UtdQueue() => UtdQueue._(vodozemac.UtdQueue());
+
+  int get length => _queue.len().toInt();
+
+  bool get isEmpty => _queue.isEmpty();
+
+  int get maxEvents => _queue.maxEvents();
+
+  /// Change the limit, returning the ids of the oldest events dropped.
+  List<String> setMaxEvents(int maxEvents) =>
+      _queue.setMaxEvents(maxEvents: maxEvents);
+
+  /// Queue an `m.room.encrypted` content that couldn't be decrypted.
+  ///
+  /// Returns the ids of the oldest events dropped to stay within the limit.
+  List<String> addEvent({
+    required String roomId,
+    required String eventId,
+    required String encryptedContent,
+  }) =>
+      _queue.addEvent(
+          roomId: roomId, eventId: eventId, encryptedContent: encryptedContent);
+
+  /// Drop an event, e.g. because it was redacted.
+  bool removeEvent(String eventId) => _queue.removeEvent(eventId: eventId);
+
+  /// The ids of the queued events waiting for a session.
+  List<String> pendingEvents({
+    required String roomId,
+    required String sessionId,
+  }) =>
+      _queue.pendingEvents(roomId: roomId, sessionId: sessionId);
+
+  /// Retry the events waiting for the session of [roomKey].
+  ///
+  /// The events that could be decrypted are removed and returned.
+  List<vodozemac.RetriedEvent> addRoomKey(InboundRoomKey roomKey) =>
+      _queue.addRoomKey(roomKey: roomKey._key);
+
+  /// Merge [other] into [existing], another copy of the same session, and
+  /// retry the events waiting for it.
+  List<vodozemac.RetriedEvent> mergeRoomKey({
+    required InboundRoomKey existing,
+    required InboundRoomKey other,
+  }) =>
+      _queue.mergeRoomKey(existing: existing._key, other: other._key);
+
+  /// Serialize the queue with encryption for storage.
+  String toPickleEncrypted(Uint8List pickleKey) =>
+      _queue.pickleEncrypted(pickleKey: vodozemac.U8Array32(pickleKey));
+
+  /// Deserialize a queue from an encrypted pickle.
+  static UtdQueue fromPickleEncrypted({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) =>
+      UtdQueue._(vodozemac.UtdQueue.fromPickleEncrypted(
+          pickle: pickle, pickleKey: vodozemac.U8Array32(pickleKey)));
+
+  /// Figure out why the `m.room.encrypted` content of an event received in
+  /// [roomId] can't be decrypted.
+  ///
+  /// [candidates] are the known keys with the session id of the event.
+  /// Returns null if one of them can decrypt the event after all.
+  static vodozemac.UtdClassification? classifyUtd({
+    required String roomId,
+    required String encryptedContent,
+    required List<InboundRoomKey> candidates,
+    vodozemac.WithheldCode? withheldCode,
+    required int originServerTs,
+    required int deviceCreatedAtMs,
+  }) =>
+      vodozemac.classifyUtd(
+          roomId: roomId,
+          encryptedContent: encryptedContent,
+          candidates: candidates.map((k) => k._key).toList(),
+          withheldCode: withheldCode,
+          originServerTs: BigInt.from(originServerTs),
+          deviceCreatedAtMs: BigInt.from(deviceCreatedAtMs));
+}
+
 abstract class CryptoUtils {
   static Uint8List sha256({required List<int> input}) =>
       vodozemac.sha256(input: input);
@@ -656,3 +1780,60 @@ abstract class CryptoUtils {
         iterations: iterations,
       );
 }
+
+abstract class PickleUtils {
+  /// Find out the format and object type of an encrypted pickle.
+  ///
+  /// Throws a [vodozemac.PickleFormatError] if the pickle can't be decrypted
+  /// with [pickleKey].
+  static vodozemac.PickleInfo detectPickle({
+    required String pickle,
+    required Uint8List pickleKey,
+  }) =>
+      vodozemac.detectPickle(pickle: pickle, pickleKey: pickleKey);
+
+  /// The key derivation parameters of a pickle protected with a passphrase,
+  /// e.g. to find out whether it should be pickled again with stronger ones.
+  static vodozemac.PassphraseKdfParams passphrasePickleParams(String pickle) =>
+      vodozemac.passphrasePickleParams(pickle: pickle);
+
+  /// Migrate all pickles of a libolm store to vodozemac pickles under
+  /// [newPickleKey].
+  ///
+  /// [oldPickleKey] can have any length, like libolm allowed. Objects that fail
+  /// are listed in the report, the others are still migrated.
+  static vodozemac.MigrationReport migrateLibolmStore({
+    required vodozemac.LibolmStore store,
+    required Uint8List oldPickleKey,
+    required Uint8List newPickleKey,
+  }) =>
+      vodozemac.migrateLibolmStore(
+          store: store,
+          oldPickleKey: oldPickleKey,
+          newPickleKey: vodozemac.U8Array32(newPickleKey));
+
+  /// Encrypt pickles of any kind and format with [newPickleKey], e.g. after
+  /// the passcode of the app changed.
+  ///
+  /// Runs in the background and calls [onProgress] with the number of pickles
+  /// done so far. The results are in the order of [pickles]; a pickle that
+  /// fails doesn't stop the others.
+  static Future<List<vodozemac.RekeyResult>> rekeyPickles({
+    required List<String> pickles,
+    required Uint8List oldPickleKey,
+    required Uint8List newPickleKey,
+    void Function(int done)? onProgress,
+  }) async {
+    await for (final progress in vodozemac.rekeyPickles(
+        pickles: pickles,
+        oldPickleKey: vodozemac.U8Array32(oldPickleKey),
+        newPickleKey: vodozemac.U8Array32(newPickleKey))) {
+      final results = progress.results;
+      if (results != null) return results;
+      onProgress?.call(progress.done);
+    }
+    throw StateError('Rekeying ended without results');
+  }
+}
+
+BigInt? _bigInt(int? value) => value == null ? null : BigInt.from(value);
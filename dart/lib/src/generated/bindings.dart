@@ -3,20 +3,29 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import 'bindings/device_keys.dart';
+import 'bindings/key_claim.dart';
+import 'bindings/key_maintenance.dart';
+import 'bindings/megolm_signing.dart';
+import 'bindings/passphrase_pickle.dart';
+import 'bindings/pickle_format.dart';
+import 'bindings/unwedge.dart';
 import 'frb_generated.dart';
 import 'lib.dart';
 import 'package:collection/collection.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `into`
 
-Uint8List sha256({required List<int> input}) => RustLib.instance.api.crateBindingsSha256(input: input);
+            // These functions are ignored because they are not marked as `pub`: `from_session`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
 
-Uint8List sha512({required List<int> input}) => RustLib.instance.api.crateBindingsSha512(input: input);
+
+            Uint8List  sha256({required List<int> input }) => RustLib.instance.api.crateBindingsSha256(input: input);
+
+Uint8List  sha512({required List<int> input }) => RustLib.instance.api.crateBindingsSha512(input: input);
 
 /// Calculate HMAC with sha256.
-Uint8List hmac({required List<int> key, required List<int> input}) =>
-    RustLib.instance.api.crateBindingsHmac(key: key, input: input);
+Uint8List  hmac({required List<int> key , required List<int> input }) => RustLib.instance.api.crateBindingsHmac(key: key, input: input);
 
 /// For sending encrypted attachments.
 /// https://spec.matrix.org/v1.16/client-server-api/#sending-encrypted-attachments
@@ -24,685 +33,850 @@ Uint8List hmac({required List<int> key, required List<int> input}) =>
 /// and encrypt the file using AES-CTR.
 /// The counter should be 64-bit long, starting at 0 and prefixed by a random 64-bit
 /// Initialization Vector (IV), which together form a 128-bit unique counter block.
-Uint8List aesCtr({required List<int> input, required List<int> key, required List<int> iv}) =>
-    RustLib.instance.api.crateBindingsAesCtr(input: input, key: key, iv: iv);
+Uint8List  aesCtr({required List<int> input , required List<int> key , required List<int> iv }) => RustLib.instance.api.crateBindingsAesCtr(input: input, key: key, iv: iv);
 
 /// Calculate pbkdf2 with fixes length of 256:
-Uint8List pbkdf2({required List<int> passphrase, required List<int> salt, required int iterations}) =>
-    RustLib.instance.api.crateBindingsPbkdf2(passphrase: passphrase, salt: salt, iterations: iterations);
+Uint8List  pbkdf2({required List<int> passphrase , required List<int> salt , required int iterations }) => RustLib.instance.api.crateBindingsPbkdf2(passphrase: passphrase, salt: salt, iterations: iterations);
+
+            
+                // Rust type: RustOpaqueNom<EstablishedSas>
+                abstract class EstablishedSas implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<PkEncryption>
+                abstract class PkEncryption implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PkSigning>>
+                abstract class PkSigning implements RustOpaqueInterface {
+                    static PkSigning  fromSecretKey({required String key })=>RustLib.instance.api.crateBindingsPkSigningFromSecretKey(key: key);
+
+
+factory PkSigning()=>RustLib.instance.api.crateBindingsPkSigningNew();
+
+
+ VodozemacEd25519PublicKey  publicKey();
+
+
+ String  secretKey();
+
+
+ VodozemacEd25519Signature  sign({required String message });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VodozemacSas>>
+                abstract class VodozemacSas implements RustOpaqueInterface {
+                     VodozemacEstablishedSas  establishSasSecret({required String otherPublicKey });
+
+
+factory VodozemacSas()=>RustLib.instance.api.crateBindingsVodozemacSasNew();
+
+
+ String  publicKey();
+
+
+
+                    
+                }
+                
+
+class DecryptResult  {
+                final String field0;
+final int field1;
+
+                const DecryptResult({required this.field0 ,required this.field1 ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => field0.hashCode^field1.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DecryptResult &&
+                runtimeType == other.runtimeType
+                && field0 == other.field0&& field1 == other.field1;
+        
+            }
+
+
+            class U8Array64 extends NonGrowableListView<int> {
+                static const arraySize = 64;
+
+                @internal
+                Uint8List get inner => _inner;
+                final Uint8List _inner;
+
+                U8Array64(this._inner)
+                    : assert(_inner.length == arraySize),
+                      super(_inner);
+  
+                U8Array64.init(): this(Uint8List(arraySize));
+              }
+            
+
+class VodozemacAccount  {
+                final RwLockAccount account;
+
+                const VodozemacAccount.raw({required this.account ,});
+
+                 VodozemacOlmSessionCreationResult  createInboundSession({required VodozemacCurve25519PublicKey theirIdentityKey , required String preKeyMessageBase64 })=>RustLib.instance.api.crateBindingsVodozemacAccountCreateInboundSession(that: this, theirIdentityKey: theirIdentityKey, preKeyMessageBase64: preKeyMessageBase64);
+
+
+ VodozemacSession  createOutboundSession({required VodozemacOlmSessionConfig config , required VodozemacCurve25519PublicKey identityKey , required VodozemacCurve25519PublicKey oneTimeKey })=>RustLib.instance.api.crateBindingsVodozemacAccountCreateOutboundSession(that: this, config: config, identityKey: identityKey, oneTimeKey: oneTimeKey);
+
+
+/// Create an outbound session from a one-time or fallback key returned by `/keys/claim`.
+///
+/// `claimed_key_json` is the object returned for the device, mapping the key id to the
+/// signed key, e.g. `{"signed_curve25519:AAAAHg": {"key": "...", "signatures": {...}}}`.
+/// The signature is checked against the device's Ed25519 key before the key is used.
+ ClaimedSession  createOutboundSessionFromClaim({required VodozemacOlmSessionConfig config , required DeviceKeys deviceKeys , required String claimedKeyJson })=>RustLib.instance.api.crateBindingsVodozemacAccountCreateOutboundSessionFromClaim(that: this, config: config, deviceKeys: deviceKeys, claimedKeyJson: claimedKeyJson);
+
+
+ VodozemacCurve25519PublicKey  curve25519Key()=>RustLib.instance.api.crateBindingsVodozemacAccountCurve25519Key(that: this, );
+
+
+ VodozemacEd25519PublicKey  ed25519Key()=>RustLib.instance.api.crateBindingsVodozemacAccountEd25519Key(that: this, );
+
+
+ List<VodozemacOneTimeKey>  fallbackKey()=>RustLib.instance.api.crateBindingsVodozemacAccountFallbackKey(that: this, );
+
+
+ bool  forgetFallbackKey()=>RustLib.instance.api.crateBindingsVodozemacAccountForgetFallbackKey(that: this, );
+
+
+/// Unpickle an account from either a vodozemac or a libolm pickle.
+static UnpickledAccount  fromAnyPickle({required String pickle , required List<int> pickleKey })=>RustLib.instance.api.crateBindingsVodozemacAccountFromAnyPickle(pickle: pickle, pickleKey: pickleKey);
+
+
+static VodozemacAccount  fromOlmPickleEncrypted({required String pickle , required List<int> pickleKey })=>RustLib.instance.api.crateBindingsVodozemacAccountFromOlmPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+
+
+static VodozemacAccount  fromPickleEncrypted({required String pickle , required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsVodozemacAccountFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+
+
+static VodozemacAccount  fromPickleWithPassphrase({required String pickle , required String passphrase })=>RustLib.instance.api.crateBindingsVodozemacAccountFromPickleWithPassphrase(pickle: pickle, passphrase: passphrase);
+
+
+ String?  generateFallbackKey()=>RustLib.instance.api.crateBindingsVodozemacAccountGenerateFallbackKey(that: this, );
+
+
+ void  generateOneTimeKeys({required BigInt count })=>RustLib.instance.api.crateBindingsVodozemacAccountGenerateOneTimeKeys(that: this, count: count);
+
+
+ VodozemacIdentityKeys  identityKeys()=>RustLib.instance.api.crateBindingsVodozemacAccountIdentityKeys(that: this, );
+
+
+ void  markKeysAsPublished()=>RustLib.instance.api.crateBindingsVodozemacAccountMarkKeysAsPublished(that: this, );
+
+
+ BigInt  maxNumberOfOneTimeKeys()=>RustLib.instance.api.crateBindingsVodozemacAccountMaxNumberOfOneTimeKeys(that: this, );
+
+
+factory VodozemacAccount()=>RustLib.instance.api.crateBindingsVodozemacAccountNew();
+
+
+ List<VodozemacOneTimeKey>  oneTimeKeys()=>RustLib.instance.api.crateBindingsVodozemacAccountOneTimeKeys(that: this, );
+
+
+ String  pickleEncrypted({required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsVodozemacAccountPickleEncrypted(that: this, pickleKey: pickleKey);
+
+
+ String  pickleWithPassphrase({required String passphrase , required PassphraseKdfParams params })=>RustLib.instance.api.crateBindingsVodozemacAccountPickleWithPassphrase(that: this, passphrase: passphrase, params: params);
+
+
+/// Decide which keys need to be generated after a sync and apply that to the account.
+///
+/// `server_counts` is the `device_one_time_keys_count` of the sync response and
+/// `unused_fallback_types` its `device_unused_fallback_key_types`, which is `None` if the
+/// server doesn't support fallback keys.
+///
+/// Following the spec, we keep half of the one-time keys the account can hold on the
+/// server, counting the keys that were generated but not published yet. The fallback key is
+/// rotated once the server reports it as used. The previous one is forgotten once the
+/// rotated key has been on the server for the grace period of `tracker`, counted from the
+/// first sync reporting it as unused.
+ KeyMaintenancePlan  planKeyMaintenance({required FallbackKeyTracker tracker , required Map<String, int> serverCounts , List<String>? unusedFallbackTypes , required BigInt nowMs })=>RustLib.instance.api.crateBindingsVodozemacAccountPlanKeyMaintenance(that: this, tracker: tracker, serverCounts: serverCounts, unusedFallbackTypes: unusedFallbackTypes, nowMs: nowMs);
+
+
+ Uint8List  removeOneTimeKey({required String publicKey })=>RustLib.instance.api.crateBindingsVodozemacAccountRemoveOneTimeKey(that: this, publicKey: publicKey);
+
+
+ VodozemacEd25519Signature  sign({required String message })=>RustLib.instance.api.crateBindingsVodozemacAccountSign(that: this, message: message);
+
+
+/// Create a new outbound session with a device we failed to decrypt a message from.
+///
+/// `claimed_key_json` is the one-time key claimed for the device after
+/// `tracker.start_attempt` succeeded, see `create_outbound_session_from_claim`. Each call
+/// consumes the started attempt, even if the claimed key is rejected, and fails with
+/// `NotStarted` if there is none. The returned session has to be stored like any other.
+ UnwedgeResult  unwedgeSession({required UnwedgeTracker tracker , required VodozemacOlmSessionConfig config , required String ownUserId , required String ownDeviceId , required DeviceKeys deviceKeys , required String claimedKeyJson })=>RustLib.instance.api.crateBindingsVodozemacAccountUnwedgeSession(that: this, tracker: tracker, config: config, ownUserId: ownUserId, ownDeviceId: ownDeviceId, deviceKeys: deviceKeys, claimedKeyJson: claimedKeyJson);
+
+
+                
+
+                
+        @override
+        int get hashCode => account.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacAccount &&
+                runtimeType == other.runtimeType
+                && account == other.account;
+        
+            }
+
+class VodozemacCurve25519PublicKey  {
+                final Curve25519PublicKey key;
+
+                const VodozemacCurve25519PublicKey({required this.key ,});
+
+                 U8Array32  asBytes()=>RustLib.instance.api.crateBindingsVodozemacCurve25519PublicKeyAsBytes(that: this, );
+
+
+static VodozemacCurve25519PublicKey  fromBase64({required String base64Key })=>RustLib.instance.api.crateBindingsVodozemacCurve25519PublicKeyFromBase64(base64Key: base64Key);
+
+
+static VodozemacCurve25519PublicKey  fromSlice({required U8Array32 bytes })=>RustLib.instance.api.crateBindingsVodozemacCurve25519PublicKeyFromSlice(bytes: bytes);
+
+
+ String  toBase64()=>RustLib.instance.api.crateBindingsVodozemacCurve25519PublicKeyToBase64(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => key.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacCurve25519PublicKey &&
+                runtimeType == other.runtimeType
+                && key == other.key;
+        
+            }
+
+class VodozemacEd25519PublicKey  {
+                final Ed25519PublicKey key;
+
+                const VodozemacEd25519PublicKey({required this.key ,});
+
+                 U8Array32  asBytes()=>RustLib.instance.api.crateBindingsVodozemacEd25519PublicKeyAsBytes(that: this, );
+
+
+static VodozemacEd25519PublicKey  fromBase64({required String base64Key })=>RustLib.instance.api.crateBindingsVodozemacEd25519PublicKeyFromBase64(base64Key: base64Key);
+
+
+static VodozemacEd25519PublicKey  fromSlice({required U8Array32 bytes })=>RustLib.instance.api.crateBindingsVodozemacEd25519PublicKeyFromSlice(bytes: bytes);
+
+
+ String  toBase64()=>RustLib.instance.api.crateBindingsVodozemacEd25519PublicKeyToBase64(that: this, );
+
+
+/// Throws on mismatched signatures
+ void  verify({required String message , required VodozemacEd25519Signature signature })=>RustLib.instance.api.crateBindingsVodozemacEd25519PublicKeyVerify(that: this, message: message, signature: signature);
+
+
+                
+
+                
+        @override
+        int get hashCode => key.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacEd25519PublicKey &&
+                runtimeType == other.runtimeType
+                && key == other.key;
+        
+            }
+
+class VodozemacEd25519Signature  {
+                final Ed25519Signature signature;
+
+                const VodozemacEd25519Signature({required this.signature ,});
+
+                static VodozemacEd25519Signature  fromBase64({required String signature })=>RustLib.instance.api.crateBindingsVodozemacEd25519SignatureFromBase64(signature: signature);
+
+
+static VodozemacEd25519Signature  fromSlice({required U8Array64 bytes })=>RustLib.instance.api.crateBindingsVodozemacEd25519SignatureFromSlice(bytes: bytes);
+
+
+ String  toBase64()=>RustLib.instance.api.crateBindingsVodozemacEd25519SignatureToBase64(that: this, );
+
+
+ U8Array64  toBytes()=>RustLib.instance.api.crateBindingsVodozemacEd25519SignatureToBytes(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => signature.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacEd25519Signature &&
+                runtimeType == other.runtimeType
+                && signature == other.signature;
+        
+            }
+
+class VodozemacEstablishedSas  {
+                final EstablishedSas establishedSas;
+
+                const VodozemacEstablishedSas({required this.establishedSas ,});
+
+                 String  calculateMac({required String input , required String info })=>RustLib.instance.api.crateBindingsVodozemacEstablishedSasCalculateMac(that: this, input: input, info: info);
+
+
+ String  calculateMacDeprecated({required String input , required String info })=>RustLib.instance.api.crateBindingsVodozemacEstablishedSasCalculateMacDeprecated(that: this, input: input, info: info);
+
+
+ Uint8List  generateBytes({required String info , required int length })=>RustLib.instance.api.crateBindingsVodozemacEstablishedSasGenerateBytes(that: this, info: info, length: length);
+
+
+ void  verifyMac({required String input , required String info , required String mac })=>RustLib.instance.api.crateBindingsVodozemacEstablishedSasVerifyMac(that: this, input: input, info: info, mac: mac);
+
+
+                
+
+                
+        @override
+        int get hashCode => establishedSas.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacEstablishedSas &&
+                runtimeType == other.runtimeType
+                && establishedSas == other.establishedSas;
+        
+            }
+
+class VodozemacGroupSession  {
+                final RwLockGroupSession session;
+
+                const VodozemacGroupSession.raw({required this.session ,});
+
+                 String  encrypt({required String plaintext })=>RustLib.instance.api.crateBindingsVodozemacGroupSessionEncrypt(that: this, plaintext: plaintext);
+
+
+/// Unpickle a group session from either a vodozemac or a libolm pickle.
+static UnpickledGroupSession  fromAnyPickle({required String pickle , required List<int> pickleKey })=>RustLib.instance.api.crateBindingsVodozemacGroupSessionFromAnyPickle(pickle: pickle, pickleKey: pickleKey);
+
+
+static VodozemacGroupSession  fromOlmPickleEncrypted({required String pickle , required List<int> pickleKey })=>RustLib.instance.api.crateBindingsVodozemacGroupSessionFromOlmPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+
+
+static VodozemacGroupSession  fromPickleEncrypted({required String pickle , required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsVodozemacGroupSessionFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+
+
+static VodozemacGroupSession  fromPickleWithPassphrase({required String pickle , required String passphrase })=>RustLib.instance.api.crateBindingsVodozemacGroupSessionFromPickleWithPassphrase(pickle: pickle, passphrase: passphrase);
+
+
+ int  messageIndex()=>RustLib.instance.api.crateBindingsVodozemacGroupSessionMessageIndex(that: this, );
+
+
+factory VodozemacGroupSession({required VodozemacMegolmSessionConfig config })=>RustLib.instance.api.crateBindingsVodozemacGroupSessionNew(config: config);
+
+
+ String  pickleEncrypted({required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsVodozemacGroupSessionPickleEncrypted(that: this, pickleKey: pickleKey);
+
+
+ String  pickleWithPassphrase({required String passphrase , required PassphraseKdfParams params })=>RustLib.instance.api.crateBindingsVodozemacGroupSessionPickleWithPassphrase(that: this, passphrase: passphrase, params: params);
+
+
+ VodozemacMegolmSessionConfig  sessionConfig()=>RustLib.instance.api.crateBindingsVodozemacGroupSessionSessionConfig(that: this, );
+
+
+ String  sessionId()=>RustLib.instance.api.crateBindingsVodozemacGroupSessionSessionId(that: this, );
+
+
+ String  sessionKey()=>RustLib.instance.api.crateBindingsVodozemacGroupSessionSessionKey(that: this, );
+
+
+ VodozemacInboundGroupSession  toInbound()=>RustLib.instance.api.crateBindingsVodozemacGroupSessionToInbound(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => session.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacGroupSession &&
+                runtimeType == other.runtimeType
+                && session == other.session;
+        
+            }
 
-// Rust type: RustOpaqueNom<EstablishedSas>
-abstract class EstablishedSas implements RustOpaqueInterface {}
+class VodozemacIdentityKeys  {
+                final VodozemacEd25519PublicKey ed25519;
+final VodozemacCurve25519PublicKey curve25519;
 
-// Rust type: RustOpaqueNom<PkDecryption>
-abstract class PkDecryption implements RustOpaqueInterface {}
+                const VodozemacIdentityKeys({required this.ed25519 ,required this.curve25519 ,});
 
-// Rust type: RustOpaqueNom<PkEncryption>
-abstract class PkEncryption implements RustOpaqueInterface {}
+                
+                
 
-// Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PkSigning>>
-abstract class PkSigning implements RustOpaqueInterface {
-  static PkSigning fromSecretKey({required String key}) =>
-      RustLib.instance.api.crateBindingsPkSigningFromSecretKey(key: key);
+                
+        @override
+        int get hashCode => ed25519.hashCode^curve25519.hashCode;
+        
 
-  factory PkSigning() => RustLib.instance.api.crateBindingsPkSigningNew();
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacIdentityKeys &&
+                runtimeType == other.runtimeType
+                && ed25519 == other.ed25519&& curve25519 == other.curve25519;
+        
+            }
 
-  VodozemacEd25519PublicKey publicKey();
+class VodozemacInboundGroupSession  {
+                final RwLockInboundGroupSession session;
+final RwLockSessionKeySource source;
 
-  String secretKey();
+                const VodozemacInboundGroupSession.raw({required this.session ,required this.source ,});
 
-  VodozemacEd25519Signature sign({required String message});
-}
+                 DecryptResult  decrypt({required String encrypted })=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionDecrypt(that: this, encrypted: encrypted);
 
-// Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VodozemacSas>>
-abstract class VodozemacSas implements RustOpaqueInterface {
-  VodozemacEstablishedSas establishSasSecret({required String otherPublicKey});
 
-  factory VodozemacSas() => RustLib.instance.api.crateBindingsVodozemacSasNew();
+/// Like `decrypt`, but also return the signing key and where the session came from.
+ ExtendedDecryptResult  decryptExtended({required String encrypted })=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionDecryptExtended(that: this, encrypted: encrypted);
 
-  String publicKey();
-}
 
-// Rust type: RustOpaqueNom<std :: sync :: RwLock < Account >>
-abstract class RwLockAccount implements RustOpaqueInterface {}
+/// Permanently forget the message keys before `index`, so only messages from `index` on can
+/// be decrypted.
+///
+/// Returns false if the session doesn't know any message before `index`.
+ bool  discardBefore({required int index })=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionDiscardBefore(that: this, index: index);
 
-class DecryptResult {
-  final String field0;
-  final int field1;
 
-  const DecryptResult({
-    required this.field0,
-    required this.field1,
-  });
+ String?  exportAt({required int index })=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionExportAt(that: this, index: index);
 
-  @override
-  int get hashCode => field0.hashCode ^ field1.hashCode;
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is DecryptResult && runtimeType == other.runtimeType && field0 == other.field0 && field1 == other.field1;
-}
+ String  exportAtFirstKnownIndex()=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionExportAtFirstKnownIndex(that: this, );
 
-class U8Array32 extends NonGrowableListView<int> {
-  static const arraySize = 32;
 
-  @internal
-  Uint8List get inner => _inner;
-  final Uint8List _inner;
+ int  firstKnownIndex()=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionFirstKnownIndex(that: this, );
 
-  U8Array32(this._inner)
-      : assert(_inner.length == arraySize),
-        super(_inner);
 
-  U8Array32.init() : this(Uint8List(arraySize));
-}
+/// Unpickle an inbound group session from either a vodozemac or a libolm pickle.
+static UnpickledInboundGroupSession  fromAnyPickle({required String pickle , required List<int> pickleKey })=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionFromAnyPickle(pickle: pickle, pickleKey: pickleKey);
 
-class U8Array64 extends NonGrowableListView<int> {
-  static const arraySize = 64;
 
-  @internal
-  Uint8List get inner => _inner;
-  final Uint8List _inner;
+static VodozemacInboundGroupSession  fromOlmPickleEncrypted({required String pickle , required List<int> pickleKey })=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionFromOlmPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
 
-  U8Array64(this._inner)
-      : assert(_inner.length == arraySize),
-        super(_inner);
 
-  U8Array64.init() : this(Uint8List(arraySize));
-}
+static VodozemacInboundGroupSession  fromPickleEncrypted({required String pickle , required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
 
-class VodozemacAccount {
-  final RwLockAccount account;
 
-  const VodozemacAccount.raw({
-    required this.account,
-  });
+static VodozemacInboundGroupSession  fromPickleWithPassphrase({required String pickle , required String passphrase })=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionFromPickleWithPassphrase(pickle: pickle, passphrase: passphrase);
 
-  VodozemacOlmSessionCreationResult createInboundSession(
-          {required VodozemacCurve25519PublicKey theirIdentityKey, required String preKeyMessageBase64}) =>
-      RustLib.instance.api.crateBindingsVodozemacAccountCreateInboundSession(
-          that: this, theirIdentityKey: theirIdentityKey, preKeyMessageBase64: preKeyMessageBase64);
 
-  VodozemacSession createOutboundSession(
-          {required VodozemacOlmSessionConfig config,
-          required VodozemacCurve25519PublicKey identityKey,
-          required VodozemacCurve25519PublicKey oneTimeKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacAccountCreateOutboundSession(
-          that: this, config: config, identityKey: identityKey, oneTimeKey: oneTimeKey);
+static VodozemacInboundGroupSession  import_({required String exportedSessionKey , required VodozemacMegolmSessionConfig config })=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionImport(exportedSessionKey: exportedSessionKey, config: config);
 
-  VodozemacCurve25519PublicKey curve25519Key() => RustLib.instance.api.crateBindingsVodozemacAccountCurve25519Key(
-        that: this,
-      );
 
-  VodozemacEd25519PublicKey ed25519Key() => RustLib.instance.api.crateBindingsVodozemacAccountEd25519Key(
-        that: this,
-      );
+/// Whether the session was created from a `SessionKey` or merged with one, as opposed to
+/// only ever being imported from an `ExportedSessionKey`.
+ SessionKeySource  keySource()=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionKeySource(that: this, );
 
-  List<VodozemacOneTimeKey> fallbackKey() => RustLib.instance.api.crateBindingsVodozemacAccountFallbackKey(
-        that: this,
-      );
 
-  bool forgetFallbackKey() => RustLib.instance.api.crateBindingsVodozemacAccountForgetFallbackKey(
-        that: this,
-      );
+factory VodozemacInboundGroupSession({required String sessionKey , required VodozemacMegolmSessionConfig config })=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionNew(sessionKey: sessionKey, config: config);
 
-  static VodozemacAccount fromOlmPickleEncrypted({required String pickle, required List<int> pickleKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacAccountFromOlmPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
 
-  static VodozemacAccount fromPickleEncrypted({required String pickle, required U8Array32 pickleKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacAccountFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+ String  pickleEncrypted({required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionPickleEncrypted(that: this, pickleKey: pickleKey);
 
-  String? generateFallbackKey() => RustLib.instance.api.crateBindingsVodozemacAccountGenerateFallbackKey(
-        that: this,
-      );
 
-  void generateOneTimeKeys({required BigInt count}) =>
-      RustLib.instance.api.crateBindingsVodozemacAccountGenerateOneTimeKeys(that: this, count: count);
+ String  pickleWithPassphrase({required String passphrase , required PassphraseKdfParams params })=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionPickleWithPassphrase(that: this, passphrase: passphrase, params: params);
 
-  VodozemacIdentityKeys identityKeys() => RustLib.instance.api.crateBindingsVodozemacAccountIdentityKeys(
-        that: this,
-      );
 
-  void markKeysAsPublished() => RustLib.instance.api.crateBindingsVodozemacAccountMarkKeysAsPublished(
-        that: this,
-      );
+ String  sessionId()=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionSessionId(that: this, );
 
-  BigInt maxNumberOfOneTimeKeys() => RustLib.instance.api.crateBindingsVodozemacAccountMaxNumberOfOneTimeKeys(
-        that: this,
-      );
 
-  factory VodozemacAccount() => RustLib.instance.api.crateBindingsVodozemacAccountNew();
+/// The public key messages of this session are signed with.
+ VodozemacEd25519PublicKey  signingKey()=>RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionSigningKey(that: this, );
 
-  List<VodozemacOneTimeKey> oneTimeKeys() => RustLib.instance.api.crateBindingsVodozemacAccountOneTimeKeys(
-        that: this,
-      );
 
-  String pickleEncrypted({required U8Array32 pickleKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacAccountPickleEncrypted(that: this, pickleKey: pickleKey);
+                
 
-  Uint8List removeOneTimeKey({required String publicKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacAccountRemoveOneTimeKey(that: this, publicKey: publicKey);
+                
+        @override
+        int get hashCode => session.hashCode^source.hashCode;
+        
 
-  VodozemacEd25519Signature sign({required String message}) =>
-      RustLib.instance.api.crateBindingsVodozemacAccountSign(that: this, message: message);
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacInboundGroupSession &&
+                runtimeType == other.runtimeType
+                && session == other.session&& source == other.source;
+        
+            }
 
-  @override
-  int get hashCode => account.hashCode;
+class VodozemacMegolmSessionConfig  {
+                final MegolmSessionConfig config;
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacAccount && runtimeType == other.runtimeType && account == other.account;
-}
+                const VodozemacMegolmSessionConfig({required this.config ,});
 
-class VodozemacCurve25519PublicKey {
-  final Curve25519PublicKey key;
+                static VodozemacMegolmSessionConfig  def()=>RustLib.instance.api.crateBindingsVodozemacMegolmSessionConfigDef();
 
-  const VodozemacCurve25519PublicKey({
-    required this.key,
-  });
 
-  U8Array32 asBytes() => RustLib.instance.api.crateBindingsVodozemacCurve25519PublicKeyAsBytes(
-        that: this,
-      );
+ int  version()=>RustLib.instance.api.crateBindingsVodozemacMegolmSessionConfigVersion(that: this, );
 
-  static VodozemacCurve25519PublicKey fromBase64({required String base64Key}) =>
-      RustLib.instance.api.crateBindingsVodozemacCurve25519PublicKeyFromBase64(base64Key: base64Key);
 
-  static VodozemacCurve25519PublicKey fromSlice({required U8Array32 bytes}) =>
-      RustLib.instance.api.crateBindingsVodozemacCurve25519PublicKeyFromSlice(bytes: bytes);
+static VodozemacMegolmSessionConfig  version1()=>RustLib.instance.api.crateBindingsVodozemacMegolmSessionConfigVersion1();
 
-  String toBase64() => RustLib.instance.api.crateBindingsVodozemacCurve25519PublicKeyToBase64(
-        that: this,
-      );
 
-  @override
-  int get hashCode => key.hashCode;
+static VodozemacMegolmSessionConfig  version2()=>RustLib.instance.api.crateBindingsVodozemacMegolmSessionConfigVersion2();
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacCurve25519PublicKey && runtimeType == other.runtimeType && key == other.key;
-}
 
-class VodozemacEd25519PublicKey {
-  final Ed25519PublicKey key;
+                
 
-  const VodozemacEd25519PublicKey({
-    required this.key,
-  });
+                
+        @override
+        int get hashCode => config.hashCode;
+        
 
-  U8Array32 asBytes() => RustLib.instance.api.crateBindingsVodozemacEd25519PublicKeyAsBytes(
-        that: this,
-      );
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacMegolmSessionConfig &&
+                runtimeType == other.runtimeType
+                && config == other.config;
+        
+            }
 
-  static VodozemacEd25519PublicKey fromBase64({required String base64Key}) =>
-      RustLib.instance.api.crateBindingsVodozemacEd25519PublicKeyFromBase64(base64Key: base64Key);
+class VodozemacOlmMessage  {
+                final OlmMessage msg;
 
-  static VodozemacEd25519PublicKey fromSlice({required U8Array32 bytes}) =>
-      RustLib.instance.api.crateBindingsVodozemacEd25519PublicKeyFromSlice(bytes: bytes);
+                const VodozemacOlmMessage({required this.msg ,});
 
-  String toBase64() => RustLib.instance.api.crateBindingsVodozemacEd25519PublicKeyToBase64(
-        that: this,
-      );
+                static VodozemacOlmMessage  fromParts({required BigInt messageType , required String ciphertext })=>RustLib.instance.api.crateBindingsVodozemacOlmMessageFromParts(messageType: messageType, ciphertext: ciphertext);
 
-  /// Throws on mismatched signatures
-  void verify({required String message, required VodozemacEd25519Signature signature}) => RustLib.instance.api
-      .crateBindingsVodozemacEd25519PublicKeyVerify(that: this, message: message, signature: signature);
 
-  @override
-  int get hashCode => key.hashCode;
+ String  message()=>RustLib.instance.api.crateBindingsVodozemacOlmMessageMessage(that: this, );
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacEd25519PublicKey && runtimeType == other.runtimeType && key == other.key;
-}
 
-class VodozemacEd25519Signature {
-  final Ed25519Signature signature;
+ BigInt  messageType()=>RustLib.instance.api.crateBindingsVodozemacOlmMessageMessageType(that: this, );
 
-  const VodozemacEd25519Signature({
-    required this.signature,
-  });
 
-  static VodozemacEd25519Signature fromBase64({required String signature}) =>
-      RustLib.instance.api.crateBindingsVodozemacEd25519SignatureFromBase64(signature: signature);
+                
 
-  static VodozemacEd25519Signature fromSlice({required U8Array64 bytes}) =>
-      RustLib.instance.api.crateBindingsVodozemacEd25519SignatureFromSlice(bytes: bytes);
+                
+        @override
+        int get hashCode => msg.hashCode;
+        
 
-  String toBase64() => RustLib.instance.api.crateBindingsVodozemacEd25519SignatureToBase64(
-        that: this,
-      );
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacOlmMessage &&
+                runtimeType == other.runtimeType
+                && msg == other.msg;
+        
+            }
 
-  U8Array64 toBytes() => RustLib.instance.api.crateBindingsVodozemacEd25519SignatureToBytes(
-        that: this,
-      );
+class VodozemacOlmSessionConfig  {
+                final OlmSessionConfig config;
 
-  @override
-  int get hashCode => signature.hashCode;
+                const VodozemacOlmSessionConfig({required this.config ,});
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacEd25519Signature && runtimeType == other.runtimeType && signature == other.signature;
-}
+                static VodozemacOlmSessionConfig  def()=>RustLib.instance.api.crateBindingsVodozemacOlmSessionConfigDef();
 
-class VodozemacEstablishedSas {
-  final EstablishedSas establishedSas;
 
-  const VodozemacEstablishedSas({
-    required this.establishedSas,
-  });
+ int  version()=>RustLib.instance.api.crateBindingsVodozemacOlmSessionConfigVersion(that: this, );
 
-  String calculateMac({required String input, required String info}) =>
-      RustLib.instance.api.crateBindingsVodozemacEstablishedSasCalculateMac(that: this, input: input, info: info);
 
-  String calculateMacDeprecated({required String input, required String info}) => RustLib.instance.api
-      .crateBindingsVodozemacEstablishedSasCalculateMacDeprecated(that: this, input: input, info: info);
+static VodozemacOlmSessionConfig  version1()=>RustLib.instance.api.crateBindingsVodozemacOlmSessionConfigVersion1();
 
-  Uint8List generateBytes({required String info, required int length}) =>
-      RustLib.instance.api.crateBindingsVodozemacEstablishedSasGenerateBytes(that: this, info: info, length: length);
 
-  void verifyMac({required String input, required String info, required String mac}) => RustLib.instance.api
-      .crateBindingsVodozemacEstablishedSasVerifyMac(that: this, input: input, info: info, mac: mac);
+static VodozemacOlmSessionConfig  version2()=>RustLib.instance.api.crateBindingsVodozemacOlmSessionConfigVersion2();
 
-  @override
-  int get hashCode => establishedSas.hashCode;
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacEstablishedSas && runtimeType == other.runtimeType && establishedSas == other.establishedSas;
-}
+                
 
-class VodozemacGroupSession {
-  final RwLockGroupSession session;
+                
+        @override
+        int get hashCode => config.hashCode;
+        
 
-  const VodozemacGroupSession.raw({
-    required this.session,
-  });
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacOlmSessionConfig &&
+                runtimeType == other.runtimeType
+                && config == other.config;
+        
+            }
 
-  String encrypt({required String plaintext}) =>
-      RustLib.instance.api.crateBindingsVodozemacGroupSessionEncrypt(that: this, plaintext: plaintext);
+class VodozemacOlmSessionCreationResult  {
+                final VodozemacSession session;
+final String plaintext;
 
-  static VodozemacGroupSession fromOlmPickleEncrypted({required String pickle, required List<int> pickleKey}) =>
-      RustLib.instance.api
-          .crateBindingsVodozemacGroupSessionFromOlmPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+                const VodozemacOlmSessionCreationResult({required this.session ,required this.plaintext ,});
 
-  static VodozemacGroupSession fromPickleEncrypted({required String pickle, required U8Array32 pickleKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacGroupSessionFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+                
+                
 
-  int messageIndex() => RustLib.instance.api.crateBindingsVodozemacGroupSessionMessageIndex(
-        that: this,
-      );
+                
+        @override
+        int get hashCode => session.hashCode^plaintext.hashCode;
+        
 
-  factory VodozemacGroupSession({required VodozemacMegolmSessionConfig config}) =>
-      RustLib.instance.api.crateBindingsVodozemacGroupSessionNew(config: config);
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacOlmSessionCreationResult &&
+                runtimeType == other.runtimeType
+                && session == other.session&& plaintext == other.plaintext;
+        
+            }
 
-  String pickleEncrypted({required U8Array32 pickleKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacGroupSessionPickleEncrypted(that: this, pickleKey: pickleKey);
+class VodozemacOneTimeKey  {
+                final String keyid;
+final VodozemacCurve25519PublicKey key;
 
-  VodozemacMegolmSessionConfig sessionConfig() => RustLib.instance.api.crateBindingsVodozemacGroupSessionSessionConfig(
-        that: this,
-      );
+                const VodozemacOneTimeKey({required this.keyid ,required this.key ,});
 
-  String sessionId() => RustLib.instance.api.crateBindingsVodozemacGroupSessionSessionId(
-        that: this,
-      );
+                
+                
 
-  String sessionKey() => RustLib.instance.api.crateBindingsVodozemacGroupSessionSessionKey(
-        that: this,
-      );
+                
+        @override
+        int get hashCode => keyid.hashCode^key.hashCode;
+        
 
-  VodozemacInboundGroupSession toInbound() => RustLib.instance.api.crateBindingsVodozemacGroupSessionToInbound(
-        that: this,
-      );
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacOneTimeKey &&
+                runtimeType == other.runtimeType
+                && keyid == other.keyid&& key == other.key;
+        
+            }
 
-  @override
-  int get hashCode => session.hashCode;
+class VodozemacPkDecryption  {
+                final PkDecryption pkDecryption;
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacGroupSession && runtimeType == other.runtimeType && session == other.session;
-}
+                const VodozemacPkDecryption.raw({required this.pkDecryption ,});
 
-class VodozemacIdentityKeys {
-  final VodozemacEd25519PublicKey ed25519;
-  final VodozemacCurve25519PublicKey curve25519;
+                 String  decrypt({required VodozemacPkMessage message })=>RustLib.instance.api.crateBindingsVodozemacPkDecryptionDecrypt(that: this, message: message);
 
-  const VodozemacIdentityKeys({
-    required this.ed25519,
-    required this.curve25519,
-  });
 
-  @override
-  int get hashCode => ed25519.hashCode ^ curve25519.hashCode;
+/// Unpickle a `PkDecryption`, which only exists in the libolm format.
+static UnpickledPkDecryption  fromAnyPickle({required String pickle , required List<int> pickleKey })=>RustLib.instance.api.crateBindingsVodozemacPkDecryptionFromAnyPickle(pickle: pickle, pickleKey: pickleKey);
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacIdentityKeys &&
-          runtimeType == other.runtimeType &&
-          ed25519 == other.ed25519 &&
-          curve25519 == other.curve25519;
-}
 
-class VodozemacInboundGroupSession {
-  final RwLockInboundGroupSession session;
+static VodozemacPkDecryption  fromKey({required U8Array32 secretKey })=>RustLib.instance.api.crateBindingsVodozemacPkDecryptionFromKey(secretKey: secretKey);
 
-  const VodozemacInboundGroupSession.raw({
-    required this.session,
-  });
 
-  DecryptResult decrypt({required String encrypted}) =>
-      RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionDecrypt(that: this, encrypted: encrypted);
+static VodozemacPkDecryption  fromLibolmPickle({required String pickle , required List<int> pickleKey })=>RustLib.instance.api.crateBindingsVodozemacPkDecryptionFromLibolmPickle(pickle: pickle, pickleKey: pickleKey);
 
-  String? exportAt({required int index}) =>
-      RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionExportAt(that: this, index: index);
 
-  String exportAtFirstKnownIndex() =>
-      RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionExportAtFirstKnownIndex(
-        that: this,
-      );
+static VodozemacPkDecryption  fromPickleWithPassphrase({required String pickle , required String passphrase })=>RustLib.instance.api.crateBindingsVodozemacPkDecryptionFromPickleWithPassphrase(pickle: pickle, passphrase: passphrase);
 
-  int firstKnownIndex() => RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionFirstKnownIndex(
-        that: this,
-      );
 
-  static VodozemacInboundGroupSession fromOlmPickleEncrypted({required String pickle, required List<int> pickleKey}) =>
-      RustLib.instance.api
-          .crateBindingsVodozemacInboundGroupSessionFromOlmPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+factory VodozemacPkDecryption()=>RustLib.instance.api.crateBindingsVodozemacPkDecryptionNew();
 
-  static VodozemacInboundGroupSession fromPickleEncrypted({required String pickle, required U8Array32 pickleKey}) =>
-      RustLib.instance.api
-          .crateBindingsVodozemacInboundGroupSessionFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
 
-  static VodozemacInboundGroupSession import_(
-          {required String exportedSessionKey, required VodozemacMegolmSessionConfig config}) =>
-      RustLib.instance.api
-          .crateBindingsVodozemacInboundGroupSessionImport(exportedSessionKey: exportedSessionKey, config: config);
+/// The pickle inside the envelope is a libolm pickle, the only format of a `PkDecryption`.
+ String  pickleWithPassphrase({required String passphrase , required PassphraseKdfParams params })=>RustLib.instance.api.crateBindingsVodozemacPkDecryptionPickleWithPassphrase(that: this, passphrase: passphrase, params: params);
 
-  factory VodozemacInboundGroupSession({required String sessionKey, required VodozemacMegolmSessionConfig config}) =>
-      RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionNew(sessionKey: sessionKey, config: config);
 
-  String pickleEncrypted({required U8Array32 pickleKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionPickleEncrypted(that: this, pickleKey: pickleKey);
+ Uint8List  privateKey()=>RustLib.instance.api.crateBindingsVodozemacPkDecryptionPrivateKey(that: this, );
 
-  String sessionId() => RustLib.instance.api.crateBindingsVodozemacInboundGroupSessionSessionId(
-        that: this,
-      );
 
-  @override
-  int get hashCode => session.hashCode;
+ String  publicKey()=>RustLib.instance.api.crateBindingsVodozemacPkDecryptionPublicKey(that: this, );
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacInboundGroupSession && runtimeType == other.runtimeType && session == other.session;
-}
 
-class VodozemacMegolmSessionConfig {
-  final MegolmSessionConfig config;
+ String  toLibolmPickle({required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsVodozemacPkDecryptionToLibolmPickle(that: this, pickleKey: pickleKey);
 
-  const VodozemacMegolmSessionConfig({
-    required this.config,
-  });
 
-  static VodozemacMegolmSessionConfig def() => RustLib.instance.api.crateBindingsVodozemacMegolmSessionConfigVersion1();
+                
 
-  int version() => RustLib.instance.api.crateBindingsVodozemacMegolmSessionConfigVersion(
-        that: this,
-      );
+                
+        @override
+        int get hashCode => pkDecryption.hashCode;
+        
 
-  static VodozemacMegolmSessionConfig version1() =>
-      RustLib.instance.api.crateBindingsVodozemacMegolmSessionConfigVersion1();
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacPkDecryption &&
+                runtimeType == other.runtimeType
+                && pkDecryption == other.pkDecryption;
+        
+            }
 
-  static VodozemacMegolmSessionConfig version2() =>
-      RustLib.instance.api.crateBindingsVodozemacMegolmSessionConfigVersion2();
+class VodozemacPkEncryption  {
+                final PkEncryption pkEncryption;
 
-  @override
-  int get hashCode => config.hashCode;
+                const VodozemacPkEncryption({required this.pkEncryption ,});
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacMegolmSessionConfig && runtimeType == other.runtimeType && config == other.config;
-}
+                 VodozemacPkMessage  encrypt({required String message })=>RustLib.instance.api.crateBindingsVodozemacPkEncryptionEncrypt(that: this, message: message);
 
-class VodozemacOlmMessage {
-  final OlmMessage msg;
 
-  const VodozemacOlmMessage({
-    required this.msg,
-  });
+static VodozemacPkEncryption  fromKey({required VodozemacCurve25519PublicKey publicKey })=>RustLib.instance.api.crateBindingsVodozemacPkEncryptionFromKey(publicKey: publicKey);
 
-  static VodozemacOlmMessage fromParts({required BigInt messageType, required String ciphertext}) =>
-      RustLib.instance.api.crateBindingsVodozemacOlmMessageFromParts(messageType: messageType, ciphertext: ciphertext);
 
-  String message() => RustLib.instance.api.crateBindingsVodozemacOlmMessageMessage(
-        that: this,
-      );
+                
 
-  BigInt messageType() => RustLib.instance.api.crateBindingsVodozemacOlmMessageMessageType(
-        that: this,
-      );
+                
+        @override
+        int get hashCode => pkEncryption.hashCode;
+        
 
-  @override
-  int get hashCode => msg.hashCode;
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacPkEncryption &&
+                runtimeType == other.runtimeType
+                && pkEncryption == other.pkEncryption;
+        
+            }
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) || other is VodozemacOlmMessage && runtimeType == other.runtimeType && msg == other.msg;
-}
+class VodozemacPkMessage  {
+                final Uint8List ciphertext;
+final Uint8List mac;
+final VodozemacCurve25519PublicKey ephemeralKey;
 
-class VodozemacOlmSessionConfig {
-  final OlmSessionConfig config;
+                const VodozemacPkMessage.raw({required this.ciphertext ,required this.mac ,required this.ephemeralKey ,});
 
-  const VodozemacOlmSessionConfig({
-    required this.config,
-  });
+                static VodozemacPkMessage  fromBase64({required String ciphertext , required String mac , required String ephemeralKey })=>RustLib.instance.api.crateBindingsVodozemacPkMessageFromBase64(ciphertext: ciphertext, mac: mac, ephemeralKey: ephemeralKey);
 
-  static VodozemacOlmSessionConfig def() => RustLib.instance.api.crateBindingsVodozemacOlmSessionConfigDef();
 
-  int version() => RustLib.instance.api.crateBindingsVodozemacOlmSessionConfigVersion(
-        that: this,
-      );
+factory VodozemacPkMessage({required List<int> ciphertext , required List<int> mac , required VodozemacCurve25519PublicKey ephemeralKey })=>RustLib.instance.api.crateBindingsVodozemacPkMessageNew(ciphertext: ciphertext, mac: mac, ephemeralKey: ephemeralKey);
 
-  static VodozemacOlmSessionConfig version1() => RustLib.instance.api.crateBindingsVodozemacOlmSessionConfigVersion1();
 
-  static VodozemacOlmSessionConfig version2() => RustLib.instance.api.crateBindingsVodozemacOlmSessionConfigVersion2();
+ (String,String,String)  toBase64()=>RustLib.instance.api.crateBindingsVodozemacPkMessageToBase64(that: this, );
 
-  @override
-  int get hashCode => config.hashCode;
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacOlmSessionConfig && runtimeType == other.runtimeType && config == other.config;
-}
+                
 
-class VodozemacOlmSessionCreationResult {
-  final VodozemacSession session;
-  final String plaintext;
+                
+        @override
+        int get hashCode => ciphertext.hashCode^mac.hashCode^ephemeralKey.hashCode;
+        
 
-  const VodozemacOlmSessionCreationResult({
-    required this.session,
-    required this.plaintext,
-  });
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacPkMessage &&
+                runtimeType == other.runtimeType
+                && ciphertext == other.ciphertext&& mac == other.mac&& ephemeralKey == other.ephemeralKey;
+        
+            }
 
-  @override
-  int get hashCode => session.hashCode ^ plaintext.hashCode;
+class VodozemacSession  {
+                final RwLockSession session;
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacOlmSessionCreationResult &&
-          runtimeType == other.runtimeType &&
-          session == other.session &&
-          plaintext == other.plaintext;
-}
+                const VodozemacSession({required this.session ,});
 
-class VodozemacOneTimeKey {
-  final String keyid;
-  final VodozemacCurve25519PublicKey key;
+                 String  decrypt({required VodozemacOlmMessage message })=>RustLib.instance.api.crateBindingsVodozemacSessionDecrypt(that: this, message: message);
 
-  const VodozemacOneTimeKey({
-    required this.keyid,
-    required this.key,
-  });
 
-  @override
-  int get hashCode => keyid.hashCode ^ key.hashCode;
+ VodozemacOlmMessage  encrypt({required String plaintext })=>RustLib.instance.api.crateBindingsVodozemacSessionEncrypt(that: this, plaintext: plaintext);
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacOneTimeKey && runtimeType == other.runtimeType && keyid == other.keyid && key == other.key;
-}
 
-class VodozemacPkDecryption {
-  final PkDecryption pkDecryption;
+/// Unpickle a session from either a vodozemac or a libolm pickle.
+static UnpickledSession  fromAnyPickle({required String pickle , required List<int> pickleKey })=>RustLib.instance.api.crateBindingsVodozemacSessionFromAnyPickle(pickle: pickle, pickleKey: pickleKey);
 
-  const VodozemacPkDecryption.raw({
-    required this.pkDecryption,
-  });
 
-  String decrypt({required VodozemacPkMessage message}) =>
-      RustLib.instance.api.crateBindingsVodozemacPkDecryptionDecrypt(that: this, message: message);
+static VodozemacSession  fromOlmPickleEncrypted({required String pickle , required List<int> pickleKey })=>RustLib.instance.api.crateBindingsVodozemacSessionFromOlmPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
 
-  static VodozemacPkDecryption fromKey({required U8Array32 secretKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacPkDecryptionFromKey(secretKey: secretKey);
 
-  static VodozemacPkDecryption fromLibolmPickle({required String pickle, required List<int> pickleKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacPkDecryptionFromLibolmPickle(pickle: pickle, pickleKey: pickleKey);
+static VodozemacSession  fromPickleEncrypted({required String pickle , required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsVodozemacSessionFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
 
-  factory VodozemacPkDecryption() => RustLib.instance.api.crateBindingsVodozemacPkDecryptionNew();
 
-  Uint8List privateKey() => RustLib.instance.api.crateBindingsVodozemacPkDecryptionPrivateKey(
-        that: this,
-      );
+static VodozemacSession  fromPickleWithPassphrase({required String pickle , required String passphrase })=>RustLib.instance.api.crateBindingsVodozemacSessionFromPickleWithPassphrase(pickle: pickle, passphrase: passphrase);
 
-  String publicKey() => RustLib.instance.api.crateBindingsVodozemacPkDecryptionPublicKey(
-        that: this,
-      );
 
-  String toLibolmPickle({required U8Array32 pickleKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacPkDecryptionToLibolmPickle(that: this, pickleKey: pickleKey);
+ bool  hasReceivedMessage()=>RustLib.instance.api.crateBindingsVodozemacSessionHasReceivedMessage(that: this, );
 
-  @override
-  int get hashCode => pkDecryption.hashCode;
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacPkDecryption && runtimeType == other.runtimeType && pkDecryption == other.pkDecryption;
-}
+ String  pickleEncrypted({required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsVodozemacSessionPickleEncrypted(that: this, pickleKey: pickleKey);
 
-class VodozemacPkEncryption {
-  final PkEncryption pkEncryption;
 
-  const VodozemacPkEncryption({
-    required this.pkEncryption,
-  });
-
-  VodozemacPkMessage encrypt({required String message}) =>
-      RustLib.instance.api.crateBindingsVodozemacPkEncryptionEncrypt(that: this, message: message);
+ String  pickleWithPassphrase({required String passphrase , required PassphraseKdfParams params })=>RustLib.instance.api.crateBindingsVodozemacSessionPickleWithPassphrase(that: this, passphrase: passphrase, params: params);
 
-  static VodozemacPkEncryption fromKey({required VodozemacCurve25519PublicKey publicKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacPkEncryptionFromKey(publicKey: publicKey);
-
-  @override
-  int get hashCode => pkEncryption.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacPkEncryption && runtimeType == other.runtimeType && pkEncryption == other.pkEncryption;
-}
-
-class VodozemacPkMessage {
-  final Uint8List ciphertext;
-  final Uint8List mac;
-  final VodozemacCurve25519PublicKey ephemeralKey;
-
-  const VodozemacPkMessage.raw({
-    required this.ciphertext,
-    required this.mac,
-    required this.ephemeralKey,
-  });
-
-  static VodozemacPkMessage fromBase64(
-          {required String ciphertext, required String mac, required String ephemeralKey}) =>
-      RustLib.instance.api
-          .crateBindingsVodozemacPkMessageFromBase64(ciphertext: ciphertext, mac: mac, ephemeralKey: ephemeralKey);
-
-  factory VodozemacPkMessage(
-          {required List<int> ciphertext,
-          required List<int> mac,
-          required VodozemacCurve25519PublicKey ephemeralKey}) =>
-      RustLib.instance.api
-          .crateBindingsVodozemacPkMessageNew(ciphertext: ciphertext, mac: mac, ephemeralKey: ephemeralKey);
-
-  (String, String, String) toBase64() => RustLib.instance.api.crateBindingsVodozemacPkMessageToBase64(
-        that: this,
-      );
-
-  @override
-  int get hashCode => ciphertext.hashCode ^ mac.hashCode ^ ephemeralKey.hashCode;
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacPkMessage &&
-          runtimeType == other.runtimeType &&
-          ciphertext == other.ciphertext &&
-          mac == other.mac &&
-          ephemeralKey == other.ephemeralKey;
-}
+ VodozemacOlmSessionConfig  sessionConfig()=>RustLib.instance.api.crateBindingsVodozemacSessionSessionConfig(that: this, );
 
-class VodozemacSession {
-  final RwLockSession session;
 
-  const VodozemacSession({
-    required this.session,
-  });
+ String  sessionId()=>RustLib.instance.api.crateBindingsVodozemacSessionSessionId(that: this, );
 
-  String decrypt({required VodozemacOlmMessage message}) =>
-      RustLib.instance.api.crateBindingsVodozemacSessionDecrypt(that: this, message: message);
 
-  VodozemacOlmMessage encrypt({required String plaintext}) =>
-      RustLib.instance.api.crateBindingsVodozemacSessionEncrypt(that: this, plaintext: plaintext);
+                
 
-  static VodozemacSession fromOlmPickleEncrypted({required String pickle, required List<int> pickleKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacSessionFromOlmPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+                
+        @override
+        int get hashCode => session.hashCode;
+        
 
-  static VodozemacSession fromPickleEncrypted({required String pickle, required U8Array32 pickleKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacSessionFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
-
-  bool hasReceivedMessage() => RustLib.instance.api.crateBindingsVodozemacSessionHasReceivedMessage(
-        that: this,
-      );
-
-  String pickleEncrypted({required U8Array32 pickleKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacSessionPickleEncrypted(that: this, pickleKey: pickleKey);
-
-  VodozemacOlmSessionConfig sessionConfig() => RustLib.instance.api.crateBindingsVodozemacSessionSessionConfig(
-        that: this,
-      );
-
-  String sessionId() => RustLib.instance.api.crateBindingsVodozemacSessionSessionId(
-        that: this,
-      );
-
-  @override
-  int get hashCode => session.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is VodozemacSession && runtimeType == other.runtimeType && session == other.session;
-}
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VodozemacSession &&
+                runtimeType == other.runtimeType
+                && session == other.session;
+        
+            }
+            
\ No newline at end of file
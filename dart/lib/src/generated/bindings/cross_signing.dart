@@ -0,0 +1,76 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `key_object`, `sign_value`, `string_field`
+
+
+            
+
+            
+                // Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CrossSigningIdentity>>
+                abstract class CrossSigningIdentity implements RustOpaqueInterface {
+                    /// The request body for `/keys/device_signing/upload`, with the self-signing and
+/// user-signing keys signed by the master key.
+///
+/// The body does not contain the `auth` field, which the caller has to add for
+/// user-interactive authentication.
+ String  deviceSigningUpload();
+
+
+/// Import existing keys from their base64 encoded secrets, as used by
+/// `PkSigning::from_secret_key`.
+static CrossSigningIdentity  fromSecretKeys({required String userId , required String masterKey , required String selfSigningKey , required String userSigningKey })=>RustLib.instance.api.crateBindingsCrossSigningCrossSigningIdentityFromSecretKeys(userId: userId, masterKey: masterKey, selfSigningKey: selfSigningKey, userSigningKey: userSigningKey);
+
+
+ VodozemacEd25519PublicKey  masterKey();
+
+
+ String  masterSecretKey();
+
+
+/// Generate a fresh set of cross-signing keys for `user_id`.
+factory CrossSigningIdentity({required String userId })=>RustLib.instance.api.crateBindingsCrossSigningCrossSigningIdentityNew(userId: userId);
+
+
+ VodozemacEd25519PublicKey  selfSigningKey();
+
+
+ String  selfSigningSecretKey();
+
+
+/// Sign one of our own devices with the self-signing key.
+///
+/// Takes the device keys as returned by `/keys/query` and returns the request body for
+/// `/keys/signatures/upload`.
+ String  signDevice({required String deviceKeys });
+
+
+/// Sign the master key of another user with the user-signing key.
+///
+/// Takes the master key as returned by `/keys/query` and returns the request body for
+/// `/keys/signatures/upload`.
+ String  signUser({required String masterKey });
+
+
+ String  userId();
+
+
+ VodozemacEd25519PublicKey  userSigningKey();
+
+
+ String  userSigningSecretKey();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
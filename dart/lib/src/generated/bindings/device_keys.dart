@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `verify_self_signature`, `verify_value`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// Device keys whose self-signature was checked.
+class DeviceKeys  {
+                final String userId;
+final String deviceId;
+final List<String> algorithms;
+final VodozemacEd25519PublicKey ed25519;
+final VodozemacCurve25519PublicKey curve25519;
+final String? displayName;
+
+                const DeviceKeys({required this.userId ,required this.deviceId ,required this.algorithms ,required this.ed25519 ,required this.curve25519 ,this.displayName ,});
+
+                /// Parse a single device keys object from a `/keys/query` response and check it against the
+/// user and device id it was listed under.
+static DeviceKeys  parseAndVerify({required String json , required String expectedUser , required String expectedDevice })=>RustLib.instance.api.crateBindingsDeviceKeysDeviceKeysParseAndVerify(json: json, expectedUser: expectedUser, expectedDevice: expectedDevice);
+
+
+                
+
+                
+        @override
+        int get hashCode => userId.hashCode^deviceId.hashCode^algorithms.hashCode^ed25519.hashCode^curve25519.hashCode^displayName.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DeviceKeys &&
+                runtimeType == other.runtimeType
+                && userId == other.userId&& deviceId == other.deviceId&& algorithms == other.algorithms&& ed25519 == other.ed25519&& curve25519 == other.curve25519&& displayName == other.displayName;
+        
+            }
+
+/// Why a device keys object was rejected.
+enum DeviceKeysError {
+                    malformedJson,
+userIdMismatch,
+deviceIdMismatch,
+unsupportedAlgorithms,
+invalidEd25519Key,
+invalidCurve25519Key,
+missingSignature,
+invalidSignature,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'device_keys.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'room_key.dart';
+import 'room_key_info.dart';
+import 'to_device.dart';
+
+
+            
+
+            /// Build the `m.forwarded_room_key` content to forward `room_key`.
+///
+/// The session is exported at `message_index`, or at its first known index if that is
+/// `None`. Returns `None` if the session doesn't know the requested index anymore.
+String?  forwardedRoomKeyContent({required InboundRoomKey roomKey , int? messageIndex }) => RustLib.instance.api.crateBindingsForwardedRoomKeyForwardedRoomKeyContent(roomKey: roomKey, messageIndex: messageIndex);
+
+/// Import the session of a decrypted `m.forwarded_room_key` to-device event.
+///
+/// `forwarder_key` is the Curve25519 key of the Olm session that decrypted `payload` and
+/// `forwarder_device` the device that sent it. The forwarder is appended to the forwarding
+/// chain of the returned info. The session is never authenticated, the forwarder could have
+/// created it itself.
+InboundRoomKey  receiveForwardedRoomKey({required OlmPayload payload , required VodozemacCurve25519PublicKey forwarderKey , required DeviceKeys forwarderDevice }) => RustLib.instance.api.crateBindingsForwardedRoomKeyReceiveForwardedRoomKey(payload: payload, forwarderKey: forwarderKey, forwarderDevice: forwarderDevice);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `verify_claimed_key`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ClaimedKey`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// A session created from a claimed key.
+class ClaimedSession  {
+                final VodozemacSession session;
+/// Whether the claimed key was the device's fallback key rather than a one-time key. The
+/// device has run out of one-time keys and will reuse the key for other senders.
+final bool fallback;
+
+                const ClaimedSession({required this.session ,required this.fallback ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => session.hashCode^fallback.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ClaimedSession &&
+                runtimeType == other.runtimeType
+                && session == other.session&& fallback == other.fallback;
+        
+            }
+
+/// Why a claimed one-time key was rejected.
+enum KeyClaimError {
+                    malformedJson,
+unsupportedAlgorithm,
+invalidKey,
+missingSignature,
+invalidSignature,
+                    ;
+                    
+                }
+            
\ No newline at end of file
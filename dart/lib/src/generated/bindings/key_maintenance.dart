@@ -0,0 +1,104 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:collection/collection.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `from`
+
+
+            
+
+            /// When the current fallback key was published, to be pickled next to the account.
+///
+/// Senders may have claimed the previous fallback key shortly before it was rotated, so it
+/// is kept for a grace period to decrypt their pre-key messages.
+class FallbackKeyTracker  {
+                final RwLockFallbackKeyState state;
+
+                const FallbackKeyTracker.raw({required this.state ,});
+
+                static FallbackKeyTracker  default_()=>RustLib.instance.api.crateBindingsKeyMaintenanceFallbackKeyTrackerDefault();
+
+
+static FallbackKeyTracker  fromPickleEncrypted({required String pickle , required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsKeyMaintenanceFallbackKeyTrackerFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+
+
+ BigInt  gracePeriodMs()=>RustLib.instance.api.crateBindingsKeyMaintenanceFallbackKeyTrackerGracePeriodMs(that: this, );
+
+
+factory FallbackKeyTracker()=>RustLib.instance.api.crateBindingsKeyMaintenanceFallbackKeyTrackerNew();
+
+
+ String  pickleEncrypted({required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsKeyMaintenanceFallbackKeyTrackerPickleEncrypted(that: this, pickleKey: pickleKey);
+
+
+ void  setGracePeriodMs({required BigInt gracePeriodMs })=>RustLib.instance.api.crateBindingsKeyMaintenanceFallbackKeyTrackerSetGracePeriodMs(that: this, gracePeriodMs: gracePeriodMs);
+
+
+                
+
+                
+        @override
+        int get hashCode => state.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FallbackKeyTracker &&
+                runtimeType == other.runtimeType
+                && state == other.state;
+        
+            }
+
+/// The work done by `VodozemacAccount::plan_key_maintenance`.
+///
+/// If any keys were generated, they have to be uploaded and then marked as published.
+class KeyMaintenancePlan  {
+                final BigInt generateOneTimeKeys;
+final bool rotateFallbackKey;
+final bool forgetOldFallbackKey;
+
+                const KeyMaintenancePlan({required this.generateOneTimeKeys ,required this.rotateFallbackKey ,required this.forgetOldFallbackKey ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => generateOneTimeKeys.hashCode^rotateFallbackKey.hashCode^forgetOldFallbackKey.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is KeyMaintenancePlan &&
+                runtimeType == other.runtimeType
+                && generateOneTimeKeys == other.generateOneTimeKeys&& rotateFallbackKey == other.rotateFallbackKey&& forgetOldFallbackKey == other.forgetOldFallbackKey;
+        
+            }
+
+
+            class U8Array32 extends NonGrowableListView<int> {
+                static const arraySize = 32;
+
+                @internal
+                Uint8List get inner => _inner;
+                final Uint8List _inner;
+
+                U8Array32(this._inner)
+                    : assert(_inner.length == arraySize),
+                      super(_inner);
+  
+                U8Array32.init(): this(Uint8List(arraySize));
+              }
+            
+            
\ No newline at end of file
@@ -0,0 +1,59 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `merged`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            
+
+            /// A decrypted Megolm message with the information needed to judge where it came from.
+class ExtendedDecryptResult  {
+                final String plaintext;
+final int messageIndex;
+/// The key the message was signed with, which is also the session id.
+final VodozemacEd25519PublicKey signingKey;
+final SessionKeySource keySource;
+
+                const ExtendedDecryptResult({required this.plaintext ,required this.messageIndex ,required this.signingKey ,required this.keySource ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => plaintext.hashCode^messageIndex.hashCode^signingKey.hashCode^keySource.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExtendedDecryptResult &&
+                runtimeType == other.runtimeType
+                && plaintext == other.plaintext&& messageIndex == other.messageIndex&& signingKey == other.signingKey&& keySource == other.keySource;
+        
+            }
+
+/// What an inbound group session was created from.
+enum SessionKeySource {
+                    /// A `SessionKey`, which is signed by the creator of the session, e.g. from `m.room_key`.
+sessionKey,
+/// An `ExportedSessionKey`, which anyone knowing the session could have produced, e.g.
+/// from a forwarded key, backup or key export.
+///
+/// Sessions restored from a vodozemac or libolm pickle report this too, as those pickles
+/// don't record the source in a form we can read. The pickle of an `InboundRoomKey` does.
+exportedSessionKey,
+                    ;
+                    
+                }
+            
\ No newline at end of file
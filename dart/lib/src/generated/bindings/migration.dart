@@ -0,0 +1,153 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'key_maintenance.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'pickle_format.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `id`, `migrate_all`, `migrate`, `mismatch`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Fingerprint`, `Migration`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `fingerprint`, `fingerprint`, `fingerprint`, `fingerprint`, `fingerprint`, `from_libolm`, `from_libolm`, `from_libolm`, `from_libolm`, `from_libolm`, `pickle`, `pickle`, `pickle`, `pickle`, `pickle`, `unpickle`, `unpickle`, `unpickle`, `unpickle`, `unpickle`
+
+
+            /// Migrate all pickles of a libolm store to vodozemac pickles under `new_pickle_key`.
+///
+/// libolm accepted pickle keys of any length, so `old_pickle_key` can have any length too.
+/// Objects that fail are listed in the report and left out, the others are still migrated.
+MigrationReport  migrateLibolmStore({required LibolmStore store , required List<int> oldPickleKey , required U8Array32 newPickleKey }) => RustLib.instance.api.crateBindingsMigrationMigrateLibolmStore(store: store, oldPickleKey: oldPickleKey, newPickleKey: newPickleKey);
+
+            /// The pickles of a libolm store.
+class LibolmStore  {
+                final String? account;
+final List<String> sessions;
+final List<String> inboundGroupSessions;
+/// The key of the server-side key backup.
+final String? pkDecryption;
+
+                const LibolmStore({this.account ,required this.sessions ,required this.inboundGroupSessions ,this.pkDecryption ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => account.hashCode^sessions.hashCode^inboundGroupSessions.hashCode^pkDecryption.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibolmStore &&
+                runtimeType == other.runtimeType
+                && account == other.account&& sessions == other.sessions&& inboundGroupSessions == other.inboundGroupSessions&& pkDecryption == other.pkDecryption;
+        
+            }
+
+/// An object that was migrated successfully.
+class MigratedPickle  {
+                /// The position of the object in the `LibolmStore`.
+final int index;
+/// The session id, the Curve25519 key for the account or the public key for the
+/// `PkDecryption`, read from the libolm pickle.
+final String idBefore;
+/// The same id read from the migrated pickle. The migration fails if they differ.
+final String idAfter;
+final String pickle;
+
+                const MigratedPickle({required this.index ,required this.idBefore ,required this.idAfter ,required this.pickle ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^idBefore.hashCode^idAfter.hashCode^pickle.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MigratedPickle &&
+                runtimeType == other.runtimeType
+                && index == other.index&& idBefore == other.idBefore&& idAfter == other.idAfter&& pickle == other.pickle;
+        
+            }
+
+enum MigrationError {
+                    invalidLibolmPickle,
+invalidMigratedPickle,
+idMismatch,
+identityKeyMismatch,
+firstKnownIndexMismatch,
+                    ;
+                    
+                }
+
+class MigrationFailure  {
+                final PickleObject object;
+final int index;
+/// The id before the migration, if the libolm pickle could be unpickled.
+final String? idBefore;
+/// The id after the migration, if the migrated pickle could be unpickled.
+final String? idAfter;
+final MigrationError error;
+
+                const MigrationFailure({required this.object ,required this.index ,this.idBefore ,this.idAfter ,required this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => object.hashCode^index.hashCode^idBefore.hashCode^idAfter.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MigrationFailure &&
+                runtimeType == other.runtimeType
+                && object == other.object&& index == other.index&& idBefore == other.idBefore&& idAfter == other.idAfter&& error == other.error;
+        
+            }
+
+class MigrationReport  {
+                final MigratedPickle? account;
+final List<MigratedPickle> sessions;
+final List<MigratedPickle> inboundGroupSessions;
+/// vodozemac can only pickle a `PkDecryption` in the libolm format, so this is a libolm
+/// pickle under the new key.
+final MigratedPickle? pkDecryption;
+final List<MigrationFailure> failures;
+
+                const MigrationReport({this.account ,required this.sessions ,required this.inboundGroupSessions ,this.pkDecryption ,required this.failures ,});
+
+                /// Whether every object was migrated, so the old pickles can be replaced.
+ bool  isComplete()=>RustLib.instance.api.crateBindingsMigrationMigrationReportIsComplete(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => account.hashCode^sessions.hashCode^inboundGroupSessions.hashCode^pkDecryption.hashCode^failures.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MigrationReport &&
+                runtimeType == other.runtimeType
+                && account == other.account&& sessions == other.sessions&& inboundGroupSessions == other.inboundGroupSessions&& pkDecryption == other.pkDecryption&& failures == other.failures;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,94 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'key_maintenance.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `handle`, `has_received_message`, `prune`, `session_id`, `sort`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SessionStorePickle`, `StoredSessionPickle`, `StoredSession`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`
+
+
+            
+
+            /// The Olm sessions with other devices, keyed by the Curve25519 key of the device.
+///
+/// The sessions handed out share their state with the store, so using them to encrypt or
+/// decrypt updates the sessions that get pickled with the store.
+class OlmSessionStore  {
+                final RwLockSessionStoreState state;
+
+                const OlmSessionStore.raw({required this.state ,});
+
+                /// Add a newly created session with the device owning `sender_key`.
+///
+/// Returns the ids of the sessions that were pruned to stay within the limit. Adding a
+/// session that is already stored does nothing.
+ List<String>  addSession({required String senderKey , required VodozemacSession session , required BigInt nowMs })=>RustLib.instance.api.crateBindingsOlmSessionsOlmSessionStoreAddSession(that: this, senderKey: senderKey, session: session, nowMs: nowMs);
+
+
+static OlmSessionStore  default_()=>RustLib.instance.api.crateBindingsOlmSessionsOlmSessionStoreDefault();
+
+
+static OlmSessionStore  fromPickleEncrypted({required String pickle , required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsOlmSessionsOlmSessionStoreFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+
+
+/// Record that a session was used to encrypt or decrypt a message.
+///
+/// Returns false if the session is not in the store.
+ bool  markUsed({required String senderKey , required String sessionId , required BigInt nowMs })=>RustLib.instance.api.crateBindingsOlmSessionsOlmSessionStoreMarkUsed(that: this, senderKey: senderKey, sessionId: sessionId, nowMs: nowMs);
+
+
+ int  maxSessionsPerDevice()=>RustLib.instance.api.crateBindingsOlmSessionsOlmSessionStoreMaxSessionsPerDevice(that: this, );
+
+
+factory OlmSessionStore()=>RustLib.instance.api.crateBindingsOlmSessionsOlmSessionStoreNew();
+
+
+ String  pickleEncrypted({required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsOlmSessionsOlmSessionStorePickleEncrypted(that: this, pickleKey: pickleKey);
+
+
+ bool  removeSession({required String senderKey , required String sessionId })=>RustLib.instance.api.crateBindingsOlmSessionsOlmSessionStoreRemoveSession(that: this, senderKey: senderKey, sessionId: sessionId);
+
+
+ List<String>  senderKeys()=>RustLib.instance.api.crateBindingsOlmSessionsOlmSessionStoreSenderKeys(that: this, );
+
+
+/// The session to encrypt the next message to the device with.
+///
+/// This is the most recently used session that received a message, or the most recently
+/// used one if we never heard back from the device.
+ VodozemacSession?  sessionForEncryption({required String senderKey })=>RustLib.instance.api.crateBindingsOlmSessionsOlmSessionStoreSessionForEncryption(that: this, senderKey: senderKey);
+
+
+/// All sessions with the device, in the order they should be tried for decryption.
+ List<VodozemacSession>  sessionsForDevice({required String senderKey })=>RustLib.instance.api.crateBindingsOlmSessionsOlmSessionStoreSessionsForDevice(that: this, senderKey: senderKey);
+
+
+/// Change the session limit, returning the ids of the sessions pruned because of it.
+ List<String>  setMaxSessionsPerDevice({required int maxSessionsPerDevice })=>RustLib.instance.api.crateBindingsOlmSessionsOlmSessionStoreSetMaxSessionsPerDevice(that: this, maxSessionsPerDevice: maxSessionsPerDevice);
+
+
+                
+
+                
+        @override
+        int get hashCode => state.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OlmSessionStore &&
+                runtimeType == other.runtimeType
+                && state == other.state;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,144 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'key_maintenance.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'share_tracking.dart';
+
+
+            // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `OutboundGroupSessionPickle`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueNom<RwLock < RotationState >>
+                abstract class RwLockRotationState implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<RwLock < ShareState >>
+                abstract class RwLockShareState implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+/// An outbound group session for a room.
+///
+/// `session` is shared with the `VodozemacGroupSession` returned by `group_session`, so
+/// messages encrypted with it count towards `rotation_period_msgs`.
+class OutboundGroupSession  {
+                final RwLockGroupSession session;
+final RwLockRotationState rotation;
+final RwLockShareState shares;
+
+                const OutboundGroupSession.raw({required this.session ,required this.rotation ,required this.shares ,});
+
+                 BigInt  createdAtMs()=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionCreatedAtMs(that: this, );
+
+
+/// The devices of `devices` that neither received the key nor were told it was withheld.
+ List<ShareRecipient>  devicesMissingKey({required List<ShareRecipient> devices })=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionDevicesMissingKey(that: this, devices: devices);
+
+
+/// Why the session has to be replaced before encrypting the next message, if it has to.
+ RotationReason?  expiredReason({required BigInt nowMs })=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionExpiredReason(that: this, nowMs: nowMs);
+
+
+/// Track the rotation of an existing session, e.g. one unpickled from the plain
+/// `VodozemacGroupSession` pickle.
+static OutboundGroupSession  fromGroupSession({required VodozemacGroupSession session , required BigInt createdAtMs , BigInt? rotationPeriodMs , BigInt? rotationPeriodMsgs })=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionFromGroupSession(session: session, createdAtMs: createdAtMs, rotationPeriodMs: rotationPeriodMs, rotationPeriodMsgs: rotationPeriodMsgs);
+
+
+static OutboundGroupSession  fromPickleEncrypted({required String pickle , required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+
+
+ VodozemacGroupSession  groupSession()=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionGroupSession(that: this, );
+
+
+/// Force a rotation before the next message, e.g. because a member left the room or
+/// removed a device.
+ void  invalidate()=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionInvalidate(that: this, );
+
+
+ bool  needsRotation({required BigInt nowMs })=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionNeedsRotation(that: this, nowMs: nowMs);
+
+
+/// Create a new session for a room whose `m.room.encryption` event contains the given
+/// rotation periods. Missing periods fall back to the defaults of the spec.
+factory OutboundGroupSession({required VodozemacMegolmSessionConfig config , required BigInt createdAtMs , BigInt? rotationPeriodMs , BigInt? rotationPeriodMsgs })=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionNew(config: config, createdAtMs: createdAtMs, rotationPeriodMs: rotationPeriodMs, rotationPeriodMsgs: rotationPeriodMsgs);
+
+
+ String  pickleEncrypted({required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionPickleEncrypted(that: this, pickleKey: pickleKey);
+
+
+/// Record that the room key was sent to a device at the current message index.
+ void  recordShare({required ShareRecipient recipient })=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionRecordShare(that: this, recipient: recipient);
+
+
+/// Record that an `m.room_key.withheld` was sent to a device instead of the room key.
+ void  recordWithheld({required ShareRecipient recipient , required WithheldCode code })=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionRecordWithheld(that: this, recipient: recipient, code: code);
+
+
+/// The devices that received the key but are not part of `devices` anymore.
+///
+/// `devices` are all current devices of the room members. If any device is returned, the
+/// session has to be rotated, as those devices could decrypt future messages.
+ List<ShareRecipient>  removedDevices({required List<ShareRecipient> devices })=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionRemovedDevices(that: this, devices: devices);
+
+
+ BigInt  rotationPeriodMs()=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionRotationPeriodMs(that: this, );
+
+
+ BigInt  rotationPeriodMsgs()=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionRotationPeriodMsgs(that: this, );
+
+
+ String  sessionId()=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionSessionId(that: this, );
+
+
+/// The message index the key was shared at with the device, if it was.
+ int?  sharedMessageIndex({required ShareRecipient recipient })=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionSharedMessageIndex(that: this, recipient: recipient);
+
+
+/// The reason the key was withheld from the device, if it was.
+ WithheldCode?  withheldCode({required ShareRecipient recipient })=>RustLib.instance.api.crateBindingsOutboundGroupSessionOutboundGroupSessionWithheldCode(that: this, recipient: recipient);
+
+
+                
+
+                
+        @override
+        int get hashCode => session.hashCode^rotation.hashCode^shares.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OutboundGroupSession &&
+                runtimeType == other.runtimeType
+                && session == other.session&& rotation == other.rotation&& shares == other.shares;
+        
+            }
+
+/// Why an outbound session has to be replaced.
+enum RotationReason {
+                    age,
+messageCount,
+invalidated,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `derive_key`, `from_pickle_with_passphrase`, `params`, `parse`, `pickle_with_passphrase`, `validate`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Envelope`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// The KDF parameters a passphrase pickle was created with, e.g. to pickle it again if they
+/// are weaker than `PassphraseKdfParams::new()`.
+PassphraseKdfParams  passphrasePickleParams({required String pickle }) => RustLib.instance.api.crateBindingsPassphrasePicklePassphrasePickleParams(pickle: pickle);
+
+            enum PassphraseKdf {
+                    pbkdf2Sha512,
+/// Only suitable for passphrases with high entropy, e.g. generated ones.
+hkdfSha256,
+                    ;
+                    
+                }
+
+class PassphraseKdfParams  {
+                final PassphraseKdf kdf;
+/// Ignored for HKDF.
+final int iterations;
+
+                const PassphraseKdfParams.raw({required this.kdf ,required this.iterations ,});
+
+                static PassphraseKdfParams  default_()=>RustLib.instance.api.crateBindingsPassphrasePicklePassphraseKdfParamsDefault();
+
+
+/// PBKDF2-HMAC-SHA-512 with the currently recommended number of iterations.
+factory PassphraseKdfParams()=>RustLib.instance.api.crateBindingsPassphrasePicklePassphraseKdfParamsNew();
+
+
+                
+
+                
+        @override
+        int get hashCode => kdf.hashCode^iterations.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PassphraseKdfParams &&
+                runtimeType == other.runtimeType
+                && kdf == other.kdf&& iterations == other.iterations;
+        
+            }
+
+enum PassphrasePickleError {
+                    malformedEnvelope,
+unsupportedVersion,
+tooFewIterations,
+tooManyIterations,
+invalidPickle,
+                    ;
+                    
+                }
+            
\ No newline at end of file
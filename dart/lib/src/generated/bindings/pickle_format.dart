@@ -0,0 +1,205 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `from_any_pickle`, `has_keys`, `libolm_object`, `vodozemac_object`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Find out what a pickle contains and in which format.
+///
+/// libolm pickle keys can have any length, all other formats use 32 byte keys.
+PickleInfo  detectPickle({required String pickle , required List<int> pickleKey }) => RustLib.instance.api.crateBindingsPickleFormatDetectPickle(pickle: pickle, pickleKey: pickleKey);
+
+            enum PickleFormat {
+                    libolm,
+/// Also used for the state these bindings keep next to the vodozemac objects.
+vodozemac,
+                    ;
+                    
+                }
+
+enum PickleFormatError {
+                    notBase64,
+wrongKey,
+unknownObject,
+objectMismatch,
+invalidPickle,
+                    ;
+                    
+                }
+
+class PickleInfo  {
+                final PickleFormat format;
+final PickleObject object;
+/// The version of a libolm pickle. vodozemac pickles aren't versioned.
+final int? version;
+
+                const PickleInfo({required this.format ,required this.object ,this.version ,});
+
+                /// Whether the object should be pickled again to move it to the vodozemac format.
+///
+/// vodozemac can only pickle a `PkDecryption` in the libolm format, so it stays there.
+ bool  needsRepickle()=>RustLib.instance.api.crateBindingsPickleFormatPickleInfoNeedsRepickle(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => format.hashCode^object.hashCode^version.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PickleInfo &&
+                runtimeType == other.runtimeType
+                && format == other.format&& object == other.object&& version == other.version;
+        
+            }
+
+enum PickleObject {
+                    account,
+session,
+groupSession,
+inboundGroupSession,
+pkDecryption,
+inboundRoomKey,
+outboundGroupSession,
+/// Any other state of these bindings, e.g. an `OlmSessionStore`.
+bindingsState,
+                    ;
+                    
+                }
+
+class UnpickledAccount  {
+                final VodozemacAccount account;
+/// Whether the account should be pickled again to move it to the vodozemac format.
+final bool needsRepickle;
+
+                const UnpickledAccount({required this.account ,required this.needsRepickle ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => account.hashCode^needsRepickle.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UnpickledAccount &&
+                runtimeType == other.runtimeType
+                && account == other.account&& needsRepickle == other.needsRepickle;
+        
+            }
+
+class UnpickledGroupSession  {
+                final VodozemacGroupSession session;
+final bool needsRepickle;
+
+                const UnpickledGroupSession({required this.session ,required this.needsRepickle ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => session.hashCode^needsRepickle.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UnpickledGroupSession &&
+                runtimeType == other.runtimeType
+                && session == other.session&& needsRepickle == other.needsRepickle;
+        
+            }
+
+class UnpickledInboundGroupSession  {
+                final VodozemacInboundGroupSession session;
+final bool needsRepickle;
+
+                const UnpickledInboundGroupSession({required this.session ,required this.needsRepickle ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => session.hashCode^needsRepickle.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UnpickledInboundGroupSession &&
+                runtimeType == other.runtimeType
+                && session == other.session&& needsRepickle == other.needsRepickle;
+        
+            }
+
+class UnpickledPkDecryption  {
+                final VodozemacPkDecryption pkDecryption;
+/// Always false, a `PkDecryption` can only be pickled in the libolm format.
+final bool needsRepickle;
+
+                const UnpickledPkDecryption({required this.pkDecryption ,required this.needsRepickle ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pkDecryption.hashCode^needsRepickle.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UnpickledPkDecryption &&
+                runtimeType == other.runtimeType
+                && pkDecryption == other.pkDecryption&& needsRepickle == other.needsRepickle;
+        
+            }
+
+class UnpickledSession  {
+                final VodozemacSession session;
+final bool needsRepickle;
+
+                const UnpickledSession({required this.session ,required this.needsRepickle ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => session.hashCode^needsRepickle.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UnpickledSession &&
+                runtimeType == other.runtimeType
+                && session == other.session&& needsRepickle == other.needsRepickle;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'key_maintenance.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'pickle_format.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `available_threads`, `rekey_in_parallel`, `rekey_result`, `rekey`
+
+
+            /// Encrypt pickles of any kind and format with a new pickle key.
+///
+/// Runs off the calling isolate. The number of pickles done so far is sent to `progress` after
+/// each one, and the last update carries the results in the order of `pickles`. A pickle that
+/// fails, e.g. because it was encrypted with a different key, doesn't stop the others.
+Stream<RekeyProgress>  rekeyPickles({required List<String> pickles , required U8Array32 oldPickleKey , required U8Array32 newPickleKey }) => RustLib.instance.api.crateBindingsRekeyRekeyPickles(pickles: pickles, oldPickleKey: oldPickleKey, newPickleKey: newPickleKey);
+
+            /// An update sent by `rekey_pickles`.
+class RekeyProgress  {
+                /// The number of pickles done so far.
+final int done;
+/// Only set on the last update, in the order of the pickles passed in.
+final List<RekeyResult>? results;
+
+                const RekeyProgress({required this.done ,this.results ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => done.hashCode^results.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RekeyProgress &&
+                runtimeType == other.runtimeType
+                && done == other.done&& results == other.results;
+        
+            }
+
+/// The outcome for one pickle passed to `rekey_pickles`.
+class RekeyResult  {
+                /// The pickle encrypted with the new key.
+final String? pickle;
+final PickleFormatError? error;
+
+                const RekeyResult({this.pickle ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pickle.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RekeyResult &&
+                runtimeType == other.runtimeType
+                && pickle == other.pickle&& error == other.error;
+        
+            }
+            
\ No newline at end of file
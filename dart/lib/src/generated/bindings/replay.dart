@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'key_maintenance.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'room_event.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `last_index_before`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SeenEvent`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `from`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Like `decrypt_room_event`, but fail with `RoomEventError::Replayed` if the message index was
+/// already used by a different event.
+RoomEventDecryptionResult  decryptRoomEventChecked({required VodozemacInboundGroupSession session , required MegolmReplayGuard guard , required String roomId , required String eventId , required BigInt originServerTs , required String encryptedContent }) => RustLib.instance.api.crateBindingsReplayDecryptRoomEventChecked(session: session, guard: guard, roomId: roomId, eventId: eventId, originServerTs: originServerTs, encryptedContent: encryptedContent);
+
+            /// Remembers which event used which message index of an inbound group session.
+class MegolmReplayGuard  {
+                final RwLockReplayState state;
+
+                const MegolmReplayGuard.raw({required this.state ,});
+
+                static MegolmReplayGuard  default_()=>RustLib.instance.api.crateBindingsReplayMegolmReplayGuardDefault();
+
+
+/// Forget the events recorded for the message indices of a session before `index`, e.g.
+/// after `discard_before(index)` made those messages undecryptable anyway.
+ void  forgetBefore({required String sessionId , required int index })=>RustLib.instance.api.crateBindingsReplayMegolmReplayGuardForgetBefore(that: this, sessionId: sessionId, index: index);
+
+
+/// Forget everything recorded for a session, e.g. after the session was deleted.
+ void  forgetSession({required String sessionId })=>RustLib.instance.api.crateBindingsReplayMegolmReplayGuardForgetSession(that: this, sessionId: sessionId);
+
+
+static MegolmReplayGuard  fromPickleEncrypted({required String pickle , required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsReplayMegolmReplayGuardFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+
+
+factory MegolmReplayGuard()=>RustLib.instance.api.crateBindingsReplayMegolmReplayGuardNew();
+
+
+ String  pickleEncrypted({required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsReplayMegolmReplayGuardPickleEncrypted(that: this, pickleKey: pickleKey);
+
+
+/// Record that an event used a message index of a session.
+///
+/// Returns false, without recording anything, if a different event already used the
+/// index. Decrypting the same event again is fine.
+ bool  record({required String sessionId , required int messageIndex , required String eventId , required BigInt originServerTs })=>RustLib.instance.api.crateBindingsReplayMegolmReplayGuardRecord(that: this, sessionId: sessionId, messageIndex: messageIndex, eventId: eventId, originServerTs: originServerTs);
+
+
+                
+
+                
+        @override
+        int get hashCode => state.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MegolmReplayGuard &&
+                runtimeType == other.runtimeType
+                && state == other.state;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,105 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'key_maintenance.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'pickle_format.dart';
+import 'replay.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_retention`
+
+
+            /// Discard the messages older than the retention of their room from pickled `InboundRoomKey`s.
+///
+/// The message indices of the sessions are mapped to time with the events recorded in `guard`,
+/// i.e. the events decrypted through `decrypt_room_event_checked`. A message is only discarded
+/// together with a later recorded message that is older than the retention, so old messages
+/// that were never decrypted through the guard, or only followed by such messages, are kept.
+/// The events recorded in `guard` for the discarded messages are forgotten. Sessions of rooms
+/// without a policy are returned unchanged. The results are in the order of `pickles`, a
+/// pickle that can't be unpickled doesn't stop the others.
+List<RetentionResult>  applyRoomRetention({required List<String> pickles , required U8Array32 pickleKey , required List<RoomRetention> policies , required MegolmReplayGuard guard , required BigInt nowMs }) => RustLib.instance.api.crateBindingsRetentionApplyRoomRetention(pickles: pickles, pickleKey: pickleKey, policies: policies, guard: guard, nowMs: nowMs);
+
+            /// A pickled `InboundRoomKey` after applying the retention policy.
+class RetainedRoomKey  {
+                final String sessionId;
+final String pickle;
+final int firstKnownIndex;
+/// Whether older messages were discarded, i.e. the pickle changed.
+final bool discarded;
+
+                const RetainedRoomKey({required this.sessionId ,required this.pickle ,required this.firstKnownIndex ,required this.discarded ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sessionId.hashCode^pickle.hashCode^firstKnownIndex.hashCode^discarded.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RetainedRoomKey &&
+                runtimeType == other.runtimeType
+                && sessionId == other.sessionId&& pickle == other.pickle&& firstKnownIndex == other.firstKnownIndex&& discarded == other.discarded;
+        
+            }
+
+/// The outcome for one pickle passed to `apply_room_retention`.
+class RetentionResult  {
+                final RetainedRoomKey? roomKey;
+final PickleFormatError? error;
+
+                const RetentionResult({this.roomKey ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => roomKey.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RetentionResult &&
+                runtimeType == other.runtimeType
+                && roomKey == other.roomKey&& error == other.error;
+        
+            }
+
+/// How long messages of a room stay decryptable.
+class RoomRetention  {
+                final String roomId;
+final BigInt maxAgeMs;
+
+                const RoomRetention({required this.roomId ,required this.maxAgeMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => roomId.hashCode^maxAgeMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RoomRetention &&
+                runtimeType == other.runtimeType
+                && roomId == other.roomId&& maxAgeMs == other.maxAgeMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `megolm_algorithm`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Encrypt an event for a room and return the `m.room.encrypted` content to send.
+///
+/// `sender_key` is the Curve25519 key of the sending account and `device_id` its device. Both
+/// are deprecated in the content, but older clients still need them to find the session.
+String  encryptRoomEvent({required VodozemacGroupSession session , required VodozemacCurve25519PublicKey senderKey , required String deviceId , required String roomId , required String eventType , required String content }) => RustLib.instance.api.crateBindingsRoomEventEncryptRoomEvent(session: session, senderKey: senderKey, deviceId: deviceId, roomId: roomId, eventType: eventType, content: content);
+
+/// Decrypt the content of an `m.room.encrypted` event received in `room_id`.
+///
+/// The `room_id` inside the encrypted payload has to match the room the event was received in,
+/// otherwise the event could have been replayed from another room.
+/// The `algorithm` of the content has to match the version of the session, so a v2 session
+/// can't be used to decrypt content claiming v1 and the other way around.
+RoomEventDecryptionResult  decryptRoomEvent({required VodozemacInboundGroupSession session , required String roomId , required String encryptedContent }) => RustLib.instance.api.crateBindingsRoomEventDecryptRoomEvent(session: session, roomId: roomId, encryptedContent: encryptedContent);
+
+            /// A decrypted room event.
+class RoomEventDecryptionResult  {
+                final String eventType;
+/// The decrypted content as JSON.
+final String content;
+final int messageIndex;
+
+                const RoomEventDecryptionResult({required this.eventType ,required this.content ,required this.messageIndex ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => eventType.hashCode^content.hashCode^messageIndex.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RoomEventDecryptionResult &&
+                runtimeType == other.runtimeType
+                && eventType == other.eventType&& content == other.content&& messageIndex == other.messageIndex;
+        
+            }
+
+/// Why an `m.room.encrypted` room event couldn't be decrypted.
+enum RoomEventError {
+                    malformedEvent,
+unknownAlgorithm,
+sessionMismatch,
+decryptionFailed,
+malformedPayload,
+roomMismatch,
+replayed,
+algorithmMismatch,
+                    ;
+                    
+                }
+            
\ No newline at end of file
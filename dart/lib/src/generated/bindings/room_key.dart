@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'device_keys.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'room_key_info.dart';
+import 'to_device.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `checked_content`, `megolm_config`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Build the `m.room_key` content to send the current state of `session` to the room members.
+String  roomKeyContent({required VodozemacGroupSession session , required String roomId , required bool sharedHistory }) => RustLib.instance.api.crateBindingsRoomKeyRoomKeyContent(session: session, roomId: roomId, sharedHistory: sharedHistory);
+
+/// Create the inbound session from a decrypted `m.room_key` to-device event.
+///
+/// `payload` is the validated Olm payload, `sender_key` the Curve25519 key of the Olm session
+/// that decrypted it and `sender_device` the device of the sender. Both keys of the payload
+/// have to belong to that device, otherwise the key could have been sent by anyone claiming
+/// the device's Ed25519 key.
+InboundRoomKey  receiveRoomKey({required OlmPayload payload , required VodozemacCurve25519PublicKey senderKey , required DeviceKeys senderDevice }) => RustLib.instance.api.crateBindingsRoomKeyReceiveRoomKey(payload: payload, senderKey: senderKey, senderDevice: senderDevice);
+
+            /// Why a received `m.room_key` was rejected.
+enum RoomKeyError {
+                    notARoomKey,
+malformedContent,
+unknownAlgorithm,
+senderKeyMismatch,
+claimedKeyMismatch,
+invalidSessionKey,
+sessionIdMismatch,
+                    ;
+                    
+                }
+            
\ No newline at end of file
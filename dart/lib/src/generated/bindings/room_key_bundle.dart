@@ -0,0 +1,131 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'device_keys.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'room_key.dart';
+import 'room_key_info.dart';
+import 'to_device.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `base64url_decode`, `base64url_encode`, `import`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Export all sessions of `room_keys` that belong to `room_id` and may be shared with new
+/// members, and encrypt them as an attachment with a fresh key.
+EncryptedRoomKeyBundle  createRoomKeyBundle({required String roomId , required List<InboundRoomKey> roomKeys }) => RustLib.instance.api.crateBindingsRoomKeyBundleCreateRoomKeyBundle(roomId: roomId, roomKeys: roomKeys);
+
+/// Read the location and key of a room key bundle from a decrypted to-device payload.
+///
+/// Like room keys, the payload has to be sent over an Olm session with `sender_device`.
+RoomKeyBundleDownload  receiveRoomKeyBundle({required OlmPayload payload , required VodozemacCurve25519PublicKey senderKey , required DeviceKeys senderDevice }) => RustLib.instance.api.crateBindingsRoomKeyBundleReceiveRoomKeyBundle(payload: payload, senderKey: senderKey, senderDevice: senderDevice);
+
+            /// A room key bundle encrypted as an attachment, ready to be uploaded.
+class EncryptedRoomKeyBundle  {
+                final String roomId;
+final Uint8List ciphertext;
+/// The `EncryptedFile` without the `url`, which is only known after the upload.
+final String file;
+
+                const EncryptedRoomKeyBundle({required this.roomId ,required this.ciphertext ,required this.file ,});
+
+                /// Encrypt the location and key of the uploaded bundle for the invitee's device.
+///
+/// `url` is the `mxc://` URI the ciphertext was uploaded to. Returns the
+/// `m.room.encrypted` content to send to the device.
+ String  encryptForDevice({required String url , required VodozemacAccount account , required VodozemacSession session , required String sender , required String senderDevice , required DeviceKeys recipient })=>RustLib.instance.api.crateBindingsRoomKeyBundleEncryptedRoomKeyBundleEncryptForDevice(that: this, url: url, account: account, session: session, sender: sender, senderDevice: senderDevice, recipient: recipient);
+
+
+                
+
+                
+        @override
+        int get hashCode => roomId.hashCode^ciphertext.hashCode^file.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EncryptedRoomKeyBundle &&
+                runtimeType == other.runtimeType
+                && roomId == other.roomId&& ciphertext == other.ciphertext&& file == other.file;
+        
+            }
+
+/// The sessions imported from a room key bundle.
+class ImportedRoomKeyBundle  {
+                final List<InboundRoomKey> roomKeys;
+/// How many sessions of the bundle were skipped because they were invalid, for a different
+/// room or not marked as `shared_history`.
+final int skipped;
+
+                const ImportedRoomKeyBundle({required this.roomKeys ,required this.skipped ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => roomKeys.hashCode^skipped.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImportedRoomKeyBundle &&
+                runtimeType == other.runtimeType
+                && roomKeys == other.roomKeys&& skipped == other.skipped;
+        
+            }
+
+/// The location and decryption info of a room key bundle, as received from the inviter.
+class RoomKeyBundleDownload  {
+                final String roomId;
+final String url;
+/// The `EncryptedFile` needed to decrypt the download.
+final String file;
+/// The Curve25519 key of the device that sent the bundle.
+final String senderKey;
+
+                const RoomKeyBundleDownload({required this.roomId ,required this.url ,required this.file ,required this.senderKey ,});
+
+                /// Decrypt the downloaded bundle and import the sessions in it.
+///
+/// Only sessions of the bundle's room that are marked as `shared_history` are imported,
+/// as forwarded keys with the sender of the bundle appended to the forwarding chain.
+ ImportedRoomKeyBundle  decrypt({required List<int> ciphertext })=>RustLib.instance.api.crateBindingsRoomKeyBundleRoomKeyBundleDownloadDecrypt(that: this, ciphertext: ciphertext);
+
+
+                
+
+                
+        @override
+        int get hashCode => roomId.hashCode^url.hashCode^file.hashCode^senderKey.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RoomKeyBundleDownload &&
+                runtimeType == other.runtimeType
+                && roomId == other.roomId&& url == other.url&& file == other.file&& senderKey == other.senderKey;
+        
+            }
+
+/// Why a downloaded room key bundle couldn't be imported.
+enum RoomKeyBundleError {
+                    hashMismatch,
+malformedBundle,
+                    ;
+                    
+                }
+            
\ No newline at end of file
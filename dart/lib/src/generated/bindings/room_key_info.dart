@@ -0,0 +1,179 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'key_maintenance.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'room_event.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `from_session`, `unpickle`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `InboundRoomKeyPickle`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `from`
+
+
+            
+
+            
+                // Rust type: RustOpaqueNom<RwLock < RoomKeyInfo >>
+                abstract class RwLockRoomKeyInfo implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+/// An inbound group session together with its `RoomKeyInfo`.
+class InboundRoomKey  {
+                final RwLockInboundGroupSession session;
+final RwLockSessionKeySource source;
+final RwLockRoomKeyInfo keyInfo;
+
+                const InboundRoomKey.raw({required this.session ,required this.source ,required this.keyInfo ,});
+
+                /// Decrypt an `m.room.encrypted` content received in `room_id`.
+///
+/// Besides the checks of `decrypt_room_event`, the room has to match the room the session
+/// was shared for.
+ RoomKeyDecryptionResult  decryptRoomEvent({required String roomId , required String encryptedContent })=>RustLib.instance.api.crateBindingsRoomKeyInfoInboundRoomKeyDecryptRoomEvent(that: this, roomId: roomId, encryptedContent: encryptedContent);
+
+
+ int  firstKnownIndex()=>RustLib.instance.api.crateBindingsRoomKeyInfoInboundRoomKeyFirstKnownIndex(that: this, );
+
+
+static InboundRoomKey  fromPickleEncrypted({required String pickle , required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsRoomKeyInfoInboundRoomKeyFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+
+
+ VodozemacInboundGroupSession  inboundGroupSession()=>RustLib.instance.api.crateBindingsRoomKeyInfoInboundRoomKeyInboundGroupSession(that: this, );
+
+
+ RoomKeyInfo  info()=>RustLib.instance.api.crateBindingsRoomKeyInfoInboundRoomKeyInfo(that: this, );
+
+
+ void  markBackedUp({required bool backedUp })=>RustLib.instance.api.crateBindingsRoomKeyInfoInboundRoomKeyMarkBackedUp(that: this, backedUp: backedUp);
+
+
+factory InboundRoomKey({required VodozemacInboundGroupSession session , required RoomKeyInfo info })=>RustLib.instance.api.crateBindingsRoomKeyInfoInboundRoomKeyNew(session: session, info: info);
+
+
+ String  pickleEncrypted({required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsRoomKeyInfoInboundRoomKeyPickleEncrypted(that: this, pickleKey: pickleKey);
+
+
+ String  sessionId()=>RustLib.instance.api.crateBindingsRoomKeyInfoInboundRoomKeySessionId(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => session.hashCode^source.hashCode^keyInfo.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is InboundRoomKey &&
+                runtimeType == other.runtimeType
+                && session == other.session&& source == other.source&& keyInfo == other.keyInfo;
+        
+            }
+
+/// How much a decrypted message can be trusted to come from the claimed sender.
+///
+/// Only messages of sessions received directly from their creator are authenticated. The
+/// caller still has to check whether the sending device is verified.
+enum KeyAuthenticity {
+                    authenticated,
+forwarded,
+imported,
+                    ;
+                    
+                }
+
+/// A room event decrypted with an `InboundRoomKey`.
+class RoomKeyDecryptionResult  {
+                final String eventType;
+/// The decrypted content as JSON.
+final String content;
+final int messageIndex;
+final KeyAuthenticity authenticity;
+final String senderKey;
+final String senderClaimedEd25519;
+
+                const RoomKeyDecryptionResult({required this.eventType ,required this.content ,required this.messageIndex ,required this.authenticity ,required this.senderKey ,required this.senderClaimedEd25519 ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => eventType.hashCode^content.hashCode^messageIndex.hashCode^authenticity.hashCode^senderKey.hashCode^senderClaimedEd25519.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RoomKeyDecryptionResult &&
+                runtimeType == other.runtimeType
+                && eventType == other.eventType&& content == other.content&& messageIndex == other.messageIndex&& authenticity == other.authenticity&& senderKey == other.senderKey&& senderClaimedEd25519 == other.senderClaimedEd25519;
+        
+            }
+
+/// Where a Megolm session came from.
+class RoomKeyInfo  {
+                final String algorithm;
+final String roomId;
+/// The Curve25519 key of the device that created the session.
+final String senderKey;
+/// The Ed25519 key the creator of the session claims to own.
+final String senderClaimedEd25519;
+/// The Curve25519 keys of the devices that forwarded the session, oldest first.
+final List<String> forwardingCurve25519KeyChain;
+final RoomKeyOrigin origin;
+/// Whether the sender allows sharing the key with users invited later, see MSC3061.
+final bool sharedHistory;
+/// Whether the session was uploaded to the server-side key backup.
+final bool backedUp;
+
+                const RoomKeyInfo({required this.algorithm ,required this.roomId ,required this.senderKey ,required this.senderClaimedEd25519 ,required this.forwardingCurve25519KeyChain ,required this.origin ,required this.sharedHistory ,required this.backedUp ,});
+
+                 KeyAuthenticity  authenticity()=>RustLib.instance.api.crateBindingsRoomKeyInfoRoomKeyInfoAuthenticity(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => algorithm.hashCode^roomId.hashCode^senderKey.hashCode^senderClaimedEd25519.hashCode^forwardingCurve25519KeyChain.hashCode^origin.hashCode^sharedHistory.hashCode^backedUp.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RoomKeyInfo &&
+                runtimeType == other.runtimeType
+                && algorithm == other.algorithm&& roomId == other.roomId&& senderKey == other.senderKey&& senderClaimedEd25519 == other.senderClaimedEd25519&& forwardingCurve25519KeyChain == other.forwardingCurve25519KeyChain&& origin == other.origin&& sharedHistory == other.sharedHistory&& backedUp == other.backedUp;
+        
+            }
+
+/// How a Megolm session ended up on this device.
+enum RoomKeyOrigin {
+                    /// Received in an `m.room_key` from the device that created it.
+direct,
+/// Received in an `m.forwarded_room_key` from another device.
+forwarded,
+/// Downloaded from the server-side key backup.
+backup,
+/// Imported from a key export file.
+export_,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `get`, `insert`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DeviceShare`, `ShareOutcome`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            /// A device the room key is shared with.
+class ShareRecipient  {
+                final String userId;
+final String deviceId;
+final String curve25519Key;
+
+                const ShareRecipient({required this.userId ,required this.deviceId ,required this.curve25519Key ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => userId.hashCode^deviceId.hashCode^curve25519Key.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ShareRecipient &&
+                runtimeType == other.runtimeType
+                && userId == other.userId&& deviceId == other.deviceId&& curve25519Key == other.curve25519Key;
+        
+            }
+
+/// The reason a room key was withheld from a device, as sent in `m.room_key.withheld`.
+enum WithheldCode {
+                    blacklisted,
+unverified,
+unauthorised,
+unavailable,
+noOlm,
+                    ;
+                    /// The `code` of the `m.room_key.withheld` content.
+ String  code()=>RustLib.instance.api.crateBindingsShareTrackingWithheldCodeCode(that: this, );
+
+
+static WithheldCode?  fromCode({required String code })=>RustLib.instance.api.crateBindingsShareTrackingWithheldCodeFromCode(code: code);
+
+
+                }
+            
\ No newline at end of file
@@ -0,0 +1,120 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'device_keys.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `olm_message`, `payload_from_value`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// Encrypt a to-device event for `recipient` and return the `m.room.encrypted` content.
+///
+/// `content` is the JSON content of the event of type `event_type` that is wrapped in the
+/// payload envelope before being encrypted with `session`.
+String  encryptToDevice({required VodozemacAccount account , required VodozemacSession session , required String sender , required String senderDevice , required DeviceKeys recipient , required String eventType , required String content }) => RustLib.instance.api.crateBindingsToDeviceEncryptToDevice(account: account, session: session, sender: sender, senderDevice: senderDevice, recipient: recipient, eventType: eventType, content: content);
+
+/// Validate the decrypted payload of a to-device event sent by `event_sender` to us.
+OlmPayload  validateToDevicePayload({required VodozemacAccount account , required String ownUserId , required String eventSender , required String plaintext }) => RustLib.instance.api.crateBindingsToDeviceValidateToDevicePayload(account: account, ownUserId: ownUserId, eventSender: eventSender, plaintext: plaintext);
+
+/// Decrypt an `m.room.encrypted` to-device event.
+///
+/// `sessions_for_sender` are the known sessions with the sender's Curve25519 key, all of which
+/// are tried before a pre-key message is used to create a new inbound session.
+ToDeviceDecryptionResult  decryptToDevice({required VodozemacAccount account , required List<VodozemacSession> sessionsForSender , required String eventJson }) => RustLib.instance.api.crateBindingsToDeviceDecryptToDevice(account: account, sessionsForSender: sessionsForSender, eventJson: eventJson);
+
+            /// The decrypted and validated payload of an Olm encrypted to-device event.
+class OlmPayload  {
+                final String eventType;
+/// The event content as JSON.
+final String content;
+final String sender;
+final String? senderDevice;
+/// The Ed25519 key the sender claims to own. It is only authenticated once the sender's
+/// Curve25519 key was matched to a device with this Ed25519 key.
+final VodozemacEd25519PublicKey senderClaimedEd25519;
+
+                const OlmPayload({required this.eventType ,required this.content ,required this.sender ,this.senderDevice ,required this.senderClaimedEd25519 ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => eventType.hashCode^content.hashCode^sender.hashCode^senderDevice.hashCode^senderClaimedEd25519.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OlmPayload &&
+                runtimeType == other.runtimeType
+                && eventType == other.eventType&& content == other.content&& sender == other.sender&& senderDevice == other.senderDevice&& senderClaimedEd25519 == other.senderClaimedEd25519;
+        
+            }
+
+/// Why a decrypted Olm payload was rejected.
+enum OlmPayloadError {
+                    malformedPayload,
+recipientMismatch,
+recipientKeyMismatch,
+senderMismatch,
+                    ;
+                    
+                }
+
+/// Why an Olm encrypted to-device event couldn't be decrypted.
+enum ToDeviceDecryptionError {
+                    malformedEvent,
+unsupportedAlgorithm,
+notForThisDevice,
+identityKeyMismatch,
+duplicateMessage,
+sessionWedged,
+malformedPlaintext,
+                    ;
+                    
+                }
+
+/// A decrypted Olm to-device event.
+///
+/// Whichever session decrypted the message advanced its ratchet and has to be pickled again.
+/// If a new session was created, the account used up a one-time key and has to be pickled
+/// again as well.
+class ToDeviceDecryptionResult  {
+                /// The decrypted payload, see `validate_to_device_payload`.
+final String plaintext;
+final VodozemacCurve25519PublicKey senderKey;
+/// The id of the session that decrypted the message.
+final String sessionId;
+/// The session created from a pre-key message, if none of the known sessions could decrypt
+/// it.
+final VodozemacSession? newSession;
+
+                const ToDeviceDecryptionResult({required this.plaintext ,required this.senderKey ,required this.sessionId ,this.newSession ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => plaintext.hashCode^senderKey.hashCode^sessionId.hashCode^newSession.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ToDeviceDecryptionResult &&
+                runtimeType == other.runtimeType
+                && plaintext == other.plaintext&& senderKey == other.senderKey&& sessionId == other.sessionId&& newSession == other.newSession;
+        
+            }
+            
\ No newline at end of file
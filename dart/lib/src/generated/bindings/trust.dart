@@ -0,0 +1,76 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `device_trust`, `identity`, `invalid`, `master_key_verified`, `new`, `parse`, `signed_by`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CrossSigningKey`, `TrustEvaluator`, `UserIdentity`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            /// Evaluate the trust of every device in a `/keys/query` response.
+///
+/// `own_master_key` and `own_user_signing_key` must come from a trusted source, for example
+/// our `CrossSigningIdentity`, and not from the response itself.
+List<DeviceTrust>  evaluateDeviceTrust({required String ownUserId , required VodozemacEd25519PublicKey ownMasterKey , VodozemacEd25519PublicKey? ownUserSigningKey , required String keysQueryResponse }) => RustLib.instance.api.crateBindingsTrustEvaluateDeviceTrust(ownUserId: ownUserId, ownMasterKey: ownMasterKey, ownUserSigningKey: ownUserSigningKey, keysQueryResponse: keysQueryResponse);
+
+            class DeviceTrust  {
+                final String userId;
+final String deviceId;
+final DeviceTrustLevel level;
+/// Set when `level` is `SignatureInvalid`.
+final TrustLink? failedLink;
+
+                const DeviceTrust({required this.userId ,required this.deviceId ,required this.level ,this.failedLink ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => userId.hashCode^deviceId.hashCode^level.hashCode^failedLink.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DeviceTrust &&
+                runtimeType == other.runtimeType
+                && userId == other.userId&& deviceId == other.deviceId&& level == other.level&& failedLink == other.failedLink;
+        
+            }
+
+enum DeviceTrustLevel {
+                    /// Signed by its owner's self-signing key, whose master key we verified.
+crossSignedVerified,
+/// Signed by its owner's self-signing key, but we haven't verified the owner's master key.
+crossSignedUnverified,
+/// Only carries a valid signature by its own device key.
+selfSigned,
+/// One of the signatures in the chain is invalid, see `DeviceTrust::failed_link`.
+signatureInvalid,
+                    ;
+                    
+                }
+
+/// A single signature in the chain from our master key down to a device.
+enum TrustLink {
+                    /// The device keys signed by the device's own Ed25519 key.
+deviceSelfSignature,
+/// The device keys signed by the owner's self-signing key.
+deviceBySelfSigningKey,
+/// The owner's self-signing key signed by the owner's master key.
+selfSigningKeyByMasterKey,
+/// The owner's master key signed by our user-signing key.
+masterKeyByUserSigningKey,
+                    ;
+                    
+                }
+            
\ No newline at end of file
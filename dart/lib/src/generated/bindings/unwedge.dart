@@ -0,0 +1,105 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../bindings.dart';
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'key_maintenance.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `can_unwedge`, `record_attempt`, `take_attempt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `fmt`, `from`
+
+
+            
+
+            enum UnwedgeError {
+                    rateLimited,
+notStarted,
+invalidClaimedKey,
+                    ;
+                    
+                }
+
+/// A new session with a device and the `m.room.encrypted` content of the `m.dummy` event that
+/// has to be sent to it.
+class UnwedgeResult  {
+                final VodozemacSession session;
+final String encryptedContent;
+
+                const UnwedgeResult({required this.session ,required this.encryptedContent ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => session.hashCode^encryptedContent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UnwedgeResult &&
+                runtimeType == other.runtimeType
+                && session == other.session&& encryptedContent == other.encryptedContent;
+        
+            }
+
+/// The unwedging attempts made per device, to be pickled next to the account.
+class UnwedgeTracker  {
+                final RwLockUnwedgeState state;
+
+                const UnwedgeTracker.raw({required this.state ,});
+
+                /// Whether a new session may be created for the device with the given Curve25519 key.
+ bool  canUnwedge({required String senderKey , required BigInt nowMs })=>RustLib.instance.api.crateBindingsUnwedgeUnwedgeTrackerCanUnwedge(that: this, senderKey: senderKey, nowMs: nowMs);
+
+
+ BigInt  cooldownMs()=>RustLib.instance.api.crateBindingsUnwedgeUnwedgeTrackerCooldownMs(that: this, );
+
+
+static UnwedgeTracker  default_()=>RustLib.instance.api.crateBindingsUnwedgeUnwedgeTrackerDefault();
+
+
+static UnwedgeTracker  fromPickleEncrypted({required String pickle , required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsUnwedgeUnwedgeTrackerFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+
+
+factory UnwedgeTracker()=>RustLib.instance.api.crateBindingsUnwedgeUnwedgeTrackerNew();
+
+
+ String  pickleEncrypted({required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsUnwedgeUnwedgeTrackerPickleEncrypted(that: this, pickleKey: pickleKey);
+
+
+ void  setCooldownMs({required BigInt cooldownMs })=>RustLib.instance.api.crateBindingsUnwedgeUnwedgeTrackerSetCooldownMs(that: this, cooldownMs: cooldownMs);
+
+
+/// Record an attempt to unwedge the device with the given Curve25519 key, before claiming a
+/// one-time key for it. The attempt allows a single call to `unwedge_session`.
+///
+/// Fails with `RateLimited` if the device was already unwedged within the cooldown, in
+/// which case no key must be claimed.
+ void  startAttempt({required String senderKey , required BigInt nowMs })=>RustLib.instance.api.crateBindingsUnwedgeUnwedgeTrackerStartAttempt(that: this, senderKey: senderKey, nowMs: nowMs);
+
+
+                
+
+                
+        @override
+        int get hashCode => state.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UnwedgeTracker &&
+                runtimeType == other.runtimeType
+                && state == other.state;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'room_key_info.dart';
+import 'share_tracking.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `parse`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `from`
+
+
+            /// Figure out why the `m.room.encrypted` content of an event received in `room_id` can't be
+/// decrypted.
+///
+/// `candidates` are the sessions we know with the session id of the event, `withheld_code` the
+/// code of an `m.room_key.withheld` we received for it. Returns `None` if one of the candidates
+/// can decrypt the event after all.
+UtdClassification?  classifyUtd({required String roomId , required String encryptedContent , required List<InboundRoomKey> candidates , WithheldCode? withheldCode , required BigInt originServerTs , required BigInt deviceCreatedAtMs }) => RustLib.instance.api.crateBindingsUtdCauseClassifyUtd(roomId: roomId, encryptedContent: encryptedContent, candidates: candidates, withheldCode: withheldCode, originServerTs: originServerTs, deviceCreatedAtMs: deviceCreatedAtMs);
+
+            /// Why a room event couldn't be decrypted.
+enum UtdCause {
+                    /// We don't have the session the event was encrypted with.
+sessionUnknown,
+/// We have the session, but only from a later message index on.
+indexTooEarly,
+/// The MAC or signature of the message doesn't match the session.
+macFailure,
+/// The sender told us that it won't share the session with us.
+withheld,
+/// The event was sent before this device existed, so it was never shared with it.
+sentBeforeDeviceCreated,
+/// The content or the ciphertext can't be parsed.
+malformedCiphertext,
+/// The session or the decrypted event belong to a different room.
+roomMismatch,
+                    ;
+                    
+                }
+
+class UtdClassification  {
+                final UtdCause cause;
+/// The code of the `m.room_key.withheld` if the cause is `Withheld`.
+final WithheldCode? withheldCode;
+
+                const UtdClassification({required this.cause ,this.withheldCode ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => cause.hashCode^withheldCode.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UtdClassification &&
+                runtimeType == other.runtimeType
+                && cause == other.cause&& withheldCode == other.withheldCode;
+        
+            }
+            
\ No newline at end of file
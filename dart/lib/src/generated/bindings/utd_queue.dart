@@ -0,0 +1,125 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../lib.dart';
+import 'key_maintenance.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'room_event.dart';
+import 'room_key_info.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `evict`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PendingEvent`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`
+
+
+            
+
+            /// An event of the queue that could be decrypted with a new session.
+class RetriedEvent  {
+                final String eventId;
+final RoomKeyDecryptionResult result;
+
+                const RetriedEvent({required this.eventId ,required this.result ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => eventId.hashCode^result.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RetriedEvent &&
+                runtimeType == other.runtimeType
+                && eventId == other.eventId&& result == other.result;
+        
+            }
+
+/// Undecryptable room events, keyed by room and session id.
+class UtdQueue  {
+                final RwLockUtdQueueState state;
+
+                const UtdQueue.raw({required this.state ,});
+
+                /// Queue an `m.room.encrypted` content that couldn't be decrypted.
+///
+/// Returns the ids of the oldest events that were dropped to stay within the limit. Adding
+/// an event that is already queued does nothing.
+ List<String>  addEvent({required String roomId , required String eventId , required String encryptedContent })=>RustLib.instance.api.crateBindingsUtdQueueUtdQueueAddEvent(that: this, roomId: roomId, eventId: eventId, encryptedContent: encryptedContent);
+
+
+/// Retry the events waiting for the session of `room_key`.
+///
+/// The events that could be decrypted are removed from the queue and returned. Events the
+/// session can't decrypt, e.g. because it only knows later message indices, stay queued.
+ List<RetriedEvent>  addRoomKey({required InboundRoomKey roomKey })=>RustLib.instance.api.crateBindingsUtdQueueUtdQueueAddRoomKey(that: this, roomKey: roomKey);
+
+
+static UtdQueue  default_()=>RustLib.instance.api.crateBindingsUtdQueueUtdQueueDefault();
+
+
+static UtdQueue  fromPickleEncrypted({required String pickle , required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsUtdQueueUtdQueueFromPickleEncrypted(pickle: pickle, pickleKey: pickleKey);
+
+
+ bool  isEmpty()=>RustLib.instance.api.crateBindingsUtdQueueUtdQueueIsEmpty(that: this, );
+
+
+ BigInt  len()=>RustLib.instance.api.crateBindingsUtdQueueUtdQueueLen(that: this, );
+
+
+ int  maxEvents()=>RustLib.instance.api.crateBindingsUtdQueueUtdQueueMaxEvents(that: this, );
+
+
+/// Merge `other` into `existing`, another copy of the same session, and retry the events
+/// waiting for it.
+///
+/// The merged session knows the earliest message index of both, and comes from a
+/// `SessionKey` if either copy did. If `other` was received directly from the creator of
+/// the session, its info replaces the one of `existing`.
+/// Merging a session with itself changes nothing.
+ List<RetriedEvent>  mergeRoomKey({required InboundRoomKey existing , required InboundRoomKey other })=>RustLib.instance.api.crateBindingsUtdQueueUtdQueueMergeRoomKey(that: this, existing: existing, other: other);
+
+
+factory UtdQueue()=>RustLib.instance.api.crateBindingsUtdQueueUtdQueueNew();
+
+
+/// The ids of the queued events waiting for a session.
+ List<String>  pendingEvents({required String roomId , required String sessionId })=>RustLib.instance.api.crateBindingsUtdQueueUtdQueuePendingEvents(that: this, roomId: roomId, sessionId: sessionId);
+
+
+ String  pickleEncrypted({required U8Array32 pickleKey })=>RustLib.instance.api.crateBindingsUtdQueueUtdQueuePickleEncrypted(that: this, pickleKey: pickleKey);
+
+
+/// Drop an event, e.g. because it was redacted. Returns false if it wasn't queued.
+ bool  removeEvent({required String eventId })=>RustLib.instance.api.crateBindingsUtdQueueUtdQueueRemoveEvent(that: this, eventId: eventId);
+
+
+/// Change the limit, returning the ids of the oldest events dropped because of it.
+ List<String>  setMaxEvents({required int maxEvents })=>RustLib.instance.api.crateBindingsUtdQueueUtdQueueSetMaxEvents(that: this, maxEvents: maxEvents);
+
+
+                
+
+                
+        @override
+        int get hashCode => state.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UtdQueue &&
+                runtimeType == other.runtimeType
+                && state == other.state;
+        
+            }
+            
\ No newline at end of file
@@ -4,6 +4,30 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'bindings.dart';
+import 'bindings/cross_signing.dart';
+import 'bindings/device_keys.dart';
+import 'bindings/forwarded_room_key.dart';
+import 'bindings/key_claim.dart';
+import 'bindings/key_maintenance.dart';
+import 'bindings/megolm_signing.dart';
+import 'bindings/migration.dart';
+import 'bindings/olm_sessions.dart';
+import 'bindings/outbound_group_session.dart';
+import 'bindings/passphrase_pickle.dart';
+import 'bindings/pickle_format.dart';
+import 'bindings/rekey.dart';
+import 'bindings/replay.dart';
+import 'bindings/retention.dart';
+import 'bindings/room_event.dart';
+import 'bindings/room_key.dart';
+import 'bindings/room_key_bundle.dart';
+import 'bindings/room_key_info.dart';
+import 'bindings/share_tracking.dart';
+import 'bindings/to_device.dart';
+import 'bindings/trust.dart';
+import 'bindings/unwedge.dart';
+import 'bindings/utd_cause.dart';
+import 'bindings/utd_queue.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -11,4517 +35,9886 @@ import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.d
 import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
 
-  RustLib._();
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
+                  RustLib._();
 
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  @override
-  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  @override
-  Future<void> executeRustInitializers() async {}
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
 
-  @override
-  String get codegenVersion => '2.11.1';
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  @override
-  int get rustContentHash => 949409271;
+                  @override
+                  String get codegenVersion => '2.11.1';
 
-  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
-    stem: 'vodozemac_bindings_dart',
-    ioDirectory: '../rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
+                  @override
+                  int get rustContentHash => 1308089072;
 
-abstract class RustLibApi extends BaseApi {
-  PkSigning crateBindingsPkSigningFromSecretKey({required String key});
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'vodozemac_bindings_dart',
+                    ioDirectory: '../rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  PkSigning crateBindingsPkSigningNew();
+                abstract class RustLibApi extends BaseApi {
+                  String crateBindingsCrossSigningCrossSigningIdentityDeviceSigningUpload({required CrossSigningIdentity that });
 
-  VodozemacEd25519PublicKey crateBindingsPkSigningPublicKey({required PkSigning that});
+CrossSigningIdentity crateBindingsCrossSigningCrossSigningIdentityFromSecretKeys({required String userId , required String masterKey , required String selfSigningKey , required String userSigningKey });
 
-  String crateBindingsPkSigningSecretKey({required PkSigning that});
+VodozemacEd25519PublicKey crateBindingsCrossSigningCrossSigningIdentityMasterKey({required CrossSigningIdentity that });
 
-  VodozemacEd25519Signature crateBindingsPkSigningSign({required PkSigning that, required String message});
+String crateBindingsCrossSigningCrossSigningIdentityMasterSecretKey({required CrossSigningIdentity that });
 
-  VodozemacEstablishedSas crateBindingsVodozemacSasEstablishSasSecret(
-      {required VodozemacSas that, required String otherPublicKey});
+CrossSigningIdentity crateBindingsCrossSigningCrossSigningIdentityNew({required String userId });
 
-  VodozemacSas crateBindingsVodozemacSasNew();
+VodozemacEd25519PublicKey crateBindingsCrossSigningCrossSigningIdentitySelfSigningKey({required CrossSigningIdentity that });
 
-  String crateBindingsVodozemacSasPublicKey({required VodozemacSas that});
+String crateBindingsCrossSigningCrossSigningIdentitySelfSigningSecretKey({required CrossSigningIdentity that });
 
-  Uint8List crateBindingsAesCtr({required List<int> input, required List<int> key, required List<int> iv});
+String crateBindingsCrossSigningCrossSigningIdentitySignDevice({required CrossSigningIdentity that , required String deviceKeys });
 
-  Uint8List crateBindingsHmac({required List<int> key, required List<int> input});
+String crateBindingsCrossSigningCrossSigningIdentitySignUser({required CrossSigningIdentity that , required String masterKey });
 
-  Uint8List crateBindingsPbkdf2({required List<int> passphrase, required List<int> salt, required int iterations});
+String crateBindingsCrossSigningCrossSigningIdentityUserId({required CrossSigningIdentity that });
 
-  Uint8List crateBindingsSha256({required List<int> input});
+VodozemacEd25519PublicKey crateBindingsCrossSigningCrossSigningIdentityUserSigningKey({required CrossSigningIdentity that });
 
-  Uint8List crateBindingsSha512({required List<int> input});
+String crateBindingsCrossSigningCrossSigningIdentityUserSigningSecretKey({required CrossSigningIdentity that });
 
-  VodozemacOlmSessionCreationResult crateBindingsVodozemacAccountCreateInboundSession(
-      {required VodozemacAccount that,
-      required VodozemacCurve25519PublicKey theirIdentityKey,
-      required String preKeyMessageBase64});
+PkSigning crateBindingsPkSigningFromSecretKey({required String key });
 
-  VodozemacSession crateBindingsVodozemacAccountCreateOutboundSession(
-      {required VodozemacAccount that,
-      required VodozemacOlmSessionConfig config,
-      required VodozemacCurve25519PublicKey identityKey,
-      required VodozemacCurve25519PublicKey oneTimeKey});
+PkSigning crateBindingsPkSigningNew();
 
-  VodozemacCurve25519PublicKey crateBindingsVodozemacAccountCurve25519Key({required VodozemacAccount that});
+VodozemacEd25519PublicKey crateBindingsPkSigningPublicKey({required PkSigning that });
 
-  VodozemacEd25519PublicKey crateBindingsVodozemacAccountEd25519Key({required VodozemacAccount that});
+String crateBindingsPkSigningSecretKey({required PkSigning that });
 
-  List<VodozemacOneTimeKey> crateBindingsVodozemacAccountFallbackKey({required VodozemacAccount that});
+VodozemacEd25519Signature crateBindingsPkSigningSign({required PkSigning that , required String message });
 
-  bool crateBindingsVodozemacAccountForgetFallbackKey({required VodozemacAccount that});
+VodozemacEstablishedSas crateBindingsVodozemacSasEstablishSasSecret({required VodozemacSas that , required String otherPublicKey });
 
-  VodozemacAccount crateBindingsVodozemacAccountFromOlmPickleEncrypted(
-      {required String pickle, required List<int> pickleKey});
+VodozemacSas crateBindingsVodozemacSasNew();
 
-  VodozemacAccount crateBindingsVodozemacAccountFromPickleEncrypted(
-      {required String pickle, required U8Array32 pickleKey});
+String crateBindingsVodozemacSasPublicKey({required VodozemacSas that });
 
-  String? crateBindingsVodozemacAccountGenerateFallbackKey({required VodozemacAccount that});
+Uint8List crateBindingsAesCtr({required List<int> input , required List<int> key , required List<int> iv });
 
-  void crateBindingsVodozemacAccountGenerateOneTimeKeys({required VodozemacAccount that, required BigInt count});
+List<RetentionResult> crateBindingsRetentionApplyRoomRetention({required List<String> pickles , required U8Array32 pickleKey , required List<RoomRetention> policies , required MegolmReplayGuard guard , required BigInt nowMs });
 
-  VodozemacIdentityKeys crateBindingsVodozemacAccountIdentityKeys({required VodozemacAccount that});
+UtdClassification? crateBindingsUtdCauseClassifyUtd({required String roomId , required String encryptedContent , required List<InboundRoomKey> candidates , WithheldCode? withheldCode , required BigInt originServerTs , required BigInt deviceCreatedAtMs });
 
-  void crateBindingsVodozemacAccountMarkKeysAsPublished({required VodozemacAccount that});
+EncryptedRoomKeyBundle crateBindingsRoomKeyBundleCreateRoomKeyBundle({required String roomId , required List<InboundRoomKey> roomKeys });
 
-  BigInt crateBindingsVodozemacAccountMaxNumberOfOneTimeKeys({required VodozemacAccount that});
+RoomEventDecryptionResult crateBindingsRoomEventDecryptRoomEvent({required VodozemacInboundGroupSession session , required String roomId , required String encryptedContent });
 
-  VodozemacAccount crateBindingsVodozemacAccountNew();
+RoomEventDecryptionResult crateBindingsReplayDecryptRoomEventChecked({required VodozemacInboundGroupSession session , required MegolmReplayGuard guard , required String roomId , required String eventId , required BigInt originServerTs , required String encryptedContent });
 
-  List<VodozemacOneTimeKey> crateBindingsVodozemacAccountOneTimeKeys({required VodozemacAccount that});
+ToDeviceDecryptionResult crateBindingsToDeviceDecryptToDevice({required VodozemacAccount account , required List<VodozemacSession> sessionsForSender , required String eventJson });
 
-  String crateBindingsVodozemacAccountPickleEncrypted({required VodozemacAccount that, required U8Array32 pickleKey});
+PickleInfo crateBindingsPickleFormatDetectPickle({required String pickle , required List<int> pickleKey });
 
-  Uint8List crateBindingsVodozemacAccountRemoveOneTimeKey({required VodozemacAccount that, required String publicKey});
+DeviceKeys crateBindingsDeviceKeysDeviceKeysParseAndVerify({required String json , required String expectedUser , required String expectedDevice });
 
-  VodozemacEd25519Signature crateBindingsVodozemacAccountSign(
-      {required VodozemacAccount that, required String message});
+String crateBindingsRoomEventEncryptRoomEvent({required VodozemacGroupSession session , required VodozemacCurve25519PublicKey senderKey , required String deviceId , required String roomId , required String eventType , required String content });
 
-  U8Array32 crateBindingsVodozemacCurve25519PublicKeyAsBytes({required VodozemacCurve25519PublicKey that});
+String crateBindingsToDeviceEncryptToDevice({required VodozemacAccount account , required VodozemacSession session , required String sender , required String senderDevice , required DeviceKeys recipient , required String eventType , required String content });
 
-  VodozemacCurve25519PublicKey crateBindingsVodozemacCurve25519PublicKeyFromBase64({required String base64Key});
+String crateBindingsRoomKeyBundleEncryptedRoomKeyBundleEncryptForDevice({required EncryptedRoomKeyBundle that , required String url , required VodozemacAccount account , required VodozemacSession session , required String sender , required String senderDevice , required DeviceKeys recipient });
 
-  VodozemacCurve25519PublicKey crateBindingsVodozemacCurve25519PublicKeyFromSlice({required U8Array32 bytes});
+List<DeviceTrust> crateBindingsTrustEvaluateDeviceTrust({required String ownUserId , required VodozemacEd25519PublicKey ownMasterKey , VodozemacEd25519PublicKey? ownUserSigningKey , required String keysQueryResponse });
 
-  String crateBindingsVodozemacCurve25519PublicKeyToBase64({required VodozemacCurve25519PublicKey that});
+FallbackKeyTracker crateBindingsKeyMaintenanceFallbackKeyTrackerDefault();
 
-  U8Array32 crateBindingsVodozemacEd25519PublicKeyAsBytes({required VodozemacEd25519PublicKey that});
+FallbackKeyTracker crateBindingsKeyMaintenanceFallbackKeyTrackerFromPickleEncrypted({required String pickle , required U8Array32 pickleKey });
 
-  VodozemacEd25519PublicKey crateBindingsVodozemacEd25519PublicKeyFromBase64({required String base64Key});
+BigInt crateBindingsKeyMaintenanceFallbackKeyTrackerGracePeriodMs({required FallbackKeyTracker that });
 
-  VodozemacEd25519PublicKey crateBindingsVodozemacEd25519PublicKeyFromSlice({required U8Array32 bytes});
+FallbackKeyTracker crateBindingsKeyMaintenanceFallbackKeyTrackerNew();
 
-  String crateBindingsVodozemacEd25519PublicKeyToBase64({required VodozemacEd25519PublicKey that});
+String crateBindingsKeyMaintenanceFallbackKeyTrackerPickleEncrypted({required FallbackKeyTracker that , required U8Array32 pickleKey });
 
-  void crateBindingsVodozemacEd25519PublicKeyVerify(
-      {required VodozemacEd25519PublicKey that, required String message, required VodozemacEd25519Signature signature});
+void crateBindingsKeyMaintenanceFallbackKeyTrackerSetGracePeriodMs({required FallbackKeyTracker that , required BigInt gracePeriodMs });
 
-  VodozemacEd25519Signature crateBindingsVodozemacEd25519SignatureFromBase64({required String signature});
+String? crateBindingsForwardedRoomKeyForwardedRoomKeyContent({required InboundRoomKey roomKey , int? messageIndex });
 
-  VodozemacEd25519Signature crateBindingsVodozemacEd25519SignatureFromSlice({required U8Array64 bytes});
+Uint8List crateBindingsHmac({required List<int> key , required List<int> input });
 
-  String crateBindingsVodozemacEd25519SignatureToBase64({required VodozemacEd25519Signature that});
+RoomKeyDecryptionResult crateBindingsRoomKeyInfoInboundRoomKeyDecryptRoomEvent({required InboundRoomKey that , required String roomId , required String encryptedContent });
 
-  U8Array64 crateBindingsVodozemacEd25519SignatureToBytes({required VodozemacEd25519Signature that});
+int crateBindingsRoomKeyInfoInboundRoomKeyFirstKnownIndex({required InboundRoomKey that });
 
-  String crateBindingsVodozemacEstablishedSasCalculateMac(
-      {required VodozemacEstablishedSas that, required String input, required String info});
+InboundRoomKey crateBindingsRoomKeyInfoInboundRoomKeyFromPickleEncrypted({required String pickle , required U8Array32 pickleKey });
 
-  String crateBindingsVodozemacEstablishedSasCalculateMacDeprecated(
-      {required VodozemacEstablishedSas that, required String input, required String info});
+VodozemacInboundGroupSession crateBindingsRoomKeyInfoInboundRoomKeyInboundGroupSession({required InboundRoomKey that });
 
-  Uint8List crateBindingsVodozemacEstablishedSasGenerateBytes(
-      {required VodozemacEstablishedSas that, required String info, required int length});
+RoomKeyInfo crateBindingsRoomKeyInfoInboundRoomKeyInfo({required InboundRoomKey that });
 
-  void crateBindingsVodozemacEstablishedSasVerifyMac(
-      {required VodozemacEstablishedSas that, required String input, required String info, required String mac});
+void crateBindingsRoomKeyInfoInboundRoomKeyMarkBackedUp({required InboundRoomKey that , required bool backedUp });
 
-  String crateBindingsVodozemacGroupSessionEncrypt({required VodozemacGroupSession that, required String plaintext});
+InboundRoomKey crateBindingsRoomKeyInfoInboundRoomKeyNew({required VodozemacInboundGroupSession session , required RoomKeyInfo info });
 
-  VodozemacGroupSession crateBindingsVodozemacGroupSessionFromOlmPickleEncrypted(
-      {required String pickle, required List<int> pickleKey});
+String crateBindingsRoomKeyInfoInboundRoomKeyPickleEncrypted({required InboundRoomKey that , required U8Array32 pickleKey });
 
-  VodozemacGroupSession crateBindingsVodozemacGroupSessionFromPickleEncrypted(
-      {required String pickle, required U8Array32 pickleKey});
+String crateBindingsRoomKeyInfoInboundRoomKeySessionId({required InboundRoomKey that });
 
-  int crateBindingsVodozemacGroupSessionMessageIndex({required VodozemacGroupSession that});
+MegolmReplayGuard crateBindingsReplayMegolmReplayGuardDefault();
 
-  VodozemacGroupSession crateBindingsVodozemacGroupSessionNew({required VodozemacMegolmSessionConfig config});
+void crateBindingsReplayMegolmReplayGuardForgetBefore({required MegolmReplayGuard that , required String sessionId , required int index });
 
-  String crateBindingsVodozemacGroupSessionPickleEncrypted(
-      {required VodozemacGroupSession that, required U8Array32 pickleKey});
+void crateBindingsReplayMegolmReplayGuardForgetSession({required MegolmReplayGuard that , required String sessionId });
 
-  VodozemacMegolmSessionConfig crateBindingsVodozemacGroupSessionSessionConfig({required VodozemacGroupSession that});
+MegolmReplayGuard crateBindingsReplayMegolmReplayGuardFromPickleEncrypted({required String pickle , required U8Array32 pickleKey });
 
-  String crateBindingsVodozemacGroupSessionSessionId({required VodozemacGroupSession that});
+MegolmReplayGuard crateBindingsReplayMegolmReplayGuardNew();
 
-  String crateBindingsVodozemacGroupSessionSessionKey({required VodozemacGroupSession that});
+String crateBindingsReplayMegolmReplayGuardPickleEncrypted({required MegolmReplayGuard that , required U8Array32 pickleKey });
 
-  VodozemacInboundGroupSession crateBindingsVodozemacGroupSessionToInbound({required VodozemacGroupSession that});
+bool crateBindingsReplayMegolmReplayGuardRecord({required MegolmReplayGuard that , required String sessionId , required int messageIndex , required String eventId , required BigInt originServerTs });
 
-  DecryptResult crateBindingsVodozemacInboundGroupSessionDecrypt(
-      {required VodozemacInboundGroupSession that, required String encrypted});
+MigrationReport crateBindingsMigrationMigrateLibolmStore({required LibolmStore store , required List<int> oldPickleKey , required U8Array32 newPickleKey });
 
-  String? crateBindingsVodozemacInboundGroupSessionExportAt(
-      {required VodozemacInboundGroupSession that, required int index});
+bool crateBindingsMigrationMigrationReportIsComplete({required MigrationReport that });
 
-  String crateBindingsVodozemacInboundGroupSessionExportAtFirstKnownIndex({required VodozemacInboundGroupSession that});
+List<String> crateBindingsOlmSessionsOlmSessionStoreAddSession({required OlmSessionStore that , required String senderKey , required VodozemacSession session , required BigInt nowMs });
 
-  int crateBindingsVodozemacInboundGroupSessionFirstKnownIndex({required VodozemacInboundGroupSession that});
+OlmSessionStore crateBindingsOlmSessionsOlmSessionStoreDefault();
 
-  VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionFromOlmPickleEncrypted(
-      {required String pickle, required List<int> pickleKey});
+OlmSessionStore crateBindingsOlmSessionsOlmSessionStoreFromPickleEncrypted({required String pickle , required U8Array32 pickleKey });
 
-  VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionFromPickleEncrypted(
-      {required String pickle, required U8Array32 pickleKey});
+bool crateBindingsOlmSessionsOlmSessionStoreMarkUsed({required OlmSessionStore that , required String senderKey , required String sessionId , required BigInt nowMs });
 
-  VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionImport(
-      {required String exportedSessionKey, required VodozemacMegolmSessionConfig config});
+int crateBindingsOlmSessionsOlmSessionStoreMaxSessionsPerDevice({required OlmSessionStore that });
 
-  VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionNew(
-      {required String sessionKey, required VodozemacMegolmSessionConfig config});
+OlmSessionStore crateBindingsOlmSessionsOlmSessionStoreNew();
 
-  String crateBindingsVodozemacInboundGroupSessionPickleEncrypted(
-      {required VodozemacInboundGroupSession that, required U8Array32 pickleKey});
+String crateBindingsOlmSessionsOlmSessionStorePickleEncrypted({required OlmSessionStore that , required U8Array32 pickleKey });
 
-  String crateBindingsVodozemacInboundGroupSessionSessionId({required VodozemacInboundGroupSession that});
+bool crateBindingsOlmSessionsOlmSessionStoreRemoveSession({required OlmSessionStore that , required String senderKey , required String sessionId });
 
-  VodozemacMegolmSessionConfig crateBindingsVodozemacMegolmSessionConfigDef();
+List<String> crateBindingsOlmSessionsOlmSessionStoreSenderKeys({required OlmSessionStore that });
 
-  int crateBindingsVodozemacMegolmSessionConfigVersion({required VodozemacMegolmSessionConfig that});
+VodozemacSession? crateBindingsOlmSessionsOlmSessionStoreSessionForEncryption({required OlmSessionStore that , required String senderKey });
 
-  VodozemacMegolmSessionConfig crateBindingsVodozemacMegolmSessionConfigVersion1();
+List<VodozemacSession> crateBindingsOlmSessionsOlmSessionStoreSessionsForDevice({required OlmSessionStore that , required String senderKey });
 
-  VodozemacMegolmSessionConfig crateBindingsVodozemacMegolmSessionConfigVersion2();
+List<String> crateBindingsOlmSessionsOlmSessionStoreSetMaxSessionsPerDevice({required OlmSessionStore that , required int maxSessionsPerDevice });
 
-  VodozemacOlmMessage crateBindingsVodozemacOlmMessageFromParts(
-      {required BigInt messageType, required String ciphertext});
+BigInt crateBindingsOutboundGroupSessionOutboundGroupSessionCreatedAtMs({required OutboundGroupSession that });
 
-  String crateBindingsVodozemacOlmMessageMessage({required VodozemacOlmMessage that});
+List<ShareRecipient> crateBindingsOutboundGroupSessionOutboundGroupSessionDevicesMissingKey({required OutboundGroupSession that , required List<ShareRecipient> devices });
 
-  BigInt crateBindingsVodozemacOlmMessageMessageType({required VodozemacOlmMessage that});
+RotationReason? crateBindingsOutboundGroupSessionOutboundGroupSessionExpiredReason({required OutboundGroupSession that , required BigInt nowMs });
 
-  VodozemacOlmSessionConfig crateBindingsVodozemacOlmSessionConfigDef();
+OutboundGroupSession crateBindingsOutboundGroupSessionOutboundGroupSessionFromGroupSession({required VodozemacGroupSession session , required BigInt createdAtMs , BigInt? rotationPeriodMs , BigInt? rotationPeriodMsgs });
 
-  int crateBindingsVodozemacOlmSessionConfigVersion({required VodozemacOlmSessionConfig that});
+OutboundGroupSession crateBindingsOutboundGroupSessionOutboundGroupSessionFromPickleEncrypted({required String pickle , required U8Array32 pickleKey });
 
-  VodozemacOlmSessionConfig crateBindingsVodozemacOlmSessionConfigVersion1();
+VodozemacGroupSession crateBindingsOutboundGroupSessionOutboundGroupSessionGroupSession({required OutboundGroupSession that });
 
-  VodozemacOlmSessionConfig crateBindingsVodozemacOlmSessionConfigVersion2();
+void crateBindingsOutboundGroupSessionOutboundGroupSessionInvalidate({required OutboundGroupSession that });
 
-  String crateBindingsVodozemacPkDecryptionDecrypt(
-      {required VodozemacPkDecryption that, required VodozemacPkMessage message});
+bool crateBindingsOutboundGroupSessionOutboundGroupSessionNeedsRotation({required OutboundGroupSession that , required BigInt nowMs });
 
-  VodozemacPkDecryption crateBindingsVodozemacPkDecryptionFromKey({required U8Array32 secretKey});
+OutboundGroupSession crateBindingsOutboundGroupSessionOutboundGroupSessionNew({required VodozemacMegolmSessionConfig config , required BigInt createdAtMs , BigInt? rotationPeriodMs , BigInt? rotationPeriodMsgs });
 
-  VodozemacPkDecryption crateBindingsVodozemacPkDecryptionFromLibolmPickle(
-      {required String pickle, required List<int> pickleKey});
+String crateBindingsOutboundGroupSessionOutboundGroupSessionPickleEncrypted({required OutboundGroupSession that , required U8Array32 pickleKey });
 
-  VodozemacPkDecryption crateBindingsVodozemacPkDecryptionNew();
+void crateBindingsOutboundGroupSessionOutboundGroupSessionRecordShare({required OutboundGroupSession that , required ShareRecipient recipient });
 
-  Uint8List crateBindingsVodozemacPkDecryptionPrivateKey({required VodozemacPkDecryption that});
+void crateBindingsOutboundGroupSessionOutboundGroupSessionRecordWithheld({required OutboundGroupSession that , required ShareRecipient recipient , required WithheldCode code });
 
-  String crateBindingsVodozemacPkDecryptionPublicKey({required VodozemacPkDecryption that});
+List<ShareRecipient> crateBindingsOutboundGroupSessionOutboundGroupSessionRemovedDevices({required OutboundGroupSession that , required List<ShareRecipient> devices });
 
-  String crateBindingsVodozemacPkDecryptionToLibolmPickle(
-      {required VodozemacPkDecryption that, required U8Array32 pickleKey});
+BigInt crateBindingsOutboundGroupSessionOutboundGroupSessionRotationPeriodMs({required OutboundGroupSession that });
 
-  VodozemacPkMessage crateBindingsVodozemacPkEncryptionEncrypt(
-      {required VodozemacPkEncryption that, required String message});
+BigInt crateBindingsOutboundGroupSessionOutboundGroupSessionRotationPeriodMsgs({required OutboundGroupSession that });
 
-  VodozemacPkEncryption crateBindingsVodozemacPkEncryptionFromKey({required VodozemacCurve25519PublicKey publicKey});
+String crateBindingsOutboundGroupSessionOutboundGroupSessionSessionId({required OutboundGroupSession that });
 
-  VodozemacPkMessage crateBindingsVodozemacPkMessageFromBase64(
-      {required String ciphertext, required String mac, required String ephemeralKey});
+int? crateBindingsOutboundGroupSessionOutboundGroupSessionSharedMessageIndex({required OutboundGroupSession that , required ShareRecipient recipient });
 
-  VodozemacPkMessage crateBindingsVodozemacPkMessageNew(
-      {required List<int> ciphertext, required List<int> mac, required VodozemacCurve25519PublicKey ephemeralKey});
+WithheldCode? crateBindingsOutboundGroupSessionOutboundGroupSessionWithheldCode({required OutboundGroupSession that , required ShareRecipient recipient });
 
-  (String, String, String) crateBindingsVodozemacPkMessageToBase64({required VodozemacPkMessage that});
+PassphraseKdfParams crateBindingsPassphrasePicklePassphraseKdfParamsDefault();
 
-  String crateBindingsVodozemacSessionDecrypt({required VodozemacSession that, required VodozemacOlmMessage message});
+PassphraseKdfParams crateBindingsPassphrasePicklePassphraseKdfParamsNew();
 
-  VodozemacOlmMessage crateBindingsVodozemacSessionEncrypt({required VodozemacSession that, required String plaintext});
+PassphraseKdfParams crateBindingsPassphrasePicklePassphrasePickleParams({required String pickle });
 
-  VodozemacSession crateBindingsVodozemacSessionFromOlmPickleEncrypted(
-      {required String pickle, required List<int> pickleKey});
+Uint8List crateBindingsPbkdf2({required List<int> passphrase , required List<int> salt , required int iterations });
 
-  VodozemacSession crateBindingsVodozemacSessionFromPickleEncrypted(
-      {required String pickle, required U8Array32 pickleKey});
+bool crateBindingsPickleFormatPickleInfoNeedsRepickle({required PickleInfo that });
 
-  bool crateBindingsVodozemacSessionHasReceivedMessage({required VodozemacSession that});
+InboundRoomKey crateBindingsForwardedRoomKeyReceiveForwardedRoomKey({required OlmPayload payload , required VodozemacCurve25519PublicKey forwarderKey , required DeviceKeys forwarderDevice });
 
-  String crateBindingsVodozemacSessionPickleEncrypted({required VodozemacSession that, required U8Array32 pickleKey});
+InboundRoomKey crateBindingsRoomKeyReceiveRoomKey({required OlmPayload payload , required VodozemacCurve25519PublicKey senderKey , required DeviceKeys senderDevice });
 
-  VodozemacOlmSessionConfig crateBindingsVodozemacSessionSessionConfig({required VodozemacSession that});
+RoomKeyBundleDownload crateBindingsRoomKeyBundleReceiveRoomKeyBundle({required OlmPayload payload , required VodozemacCurve25519PublicKey senderKey , required DeviceKeys senderDevice });
 
-  String crateBindingsVodozemacSessionSessionId({required VodozemacSession that});
+Stream<RekeyProgress> crateBindingsRekeyRekeyPickles({required List<String> pickles , required U8Array32 oldPickleKey , required U8Array32 newPickleKey });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Curve25519PublicKey;
+ImportedRoomKeyBundle crateBindingsRoomKeyBundleRoomKeyBundleDownloadDecrypt({required RoomKeyBundleDownload that , required List<int> ciphertext });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Curve25519PublicKey;
+String crateBindingsRoomKeyRoomKeyContent({required VodozemacGroupSession session , required String roomId , required bool sharedHistory });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Curve25519PublicKeyPtr;
+KeyAuthenticity crateBindingsRoomKeyInfoRoomKeyInfoAuthenticity({required RoomKeyInfo that });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Ed25519PublicKey;
+Uint8List crateBindingsSha256({required List<int> input });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Ed25519PublicKey;
+Uint8List crateBindingsSha512({required List<int> input });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Ed25519PublicKeyPtr;
+bool crateBindingsUnwedgeUnwedgeTrackerCanUnwedge({required UnwedgeTracker that , required String senderKey , required BigInt nowMs });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Ed25519Signature;
+BigInt crateBindingsUnwedgeUnwedgeTrackerCooldownMs({required UnwedgeTracker that });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Ed25519Signature;
+UnwedgeTracker crateBindingsUnwedgeUnwedgeTrackerDefault();
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Ed25519SignaturePtr;
+UnwedgeTracker crateBindingsUnwedgeUnwedgeTrackerFromPickleEncrypted({required String pickle , required U8Array32 pickleKey });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_EstablishedSas;
+UnwedgeTracker crateBindingsUnwedgeUnwedgeTrackerNew();
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_EstablishedSas;
+String crateBindingsUnwedgeUnwedgeTrackerPickleEncrypted({required UnwedgeTracker that , required U8Array32 pickleKey });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EstablishedSasPtr;
+void crateBindingsUnwedgeUnwedgeTrackerSetCooldownMs({required UnwedgeTracker that , required BigInt cooldownMs });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MegolmSessionConfig;
+void crateBindingsUnwedgeUnwedgeTrackerStartAttempt({required UnwedgeTracker that , required String senderKey , required BigInt nowMs });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MegolmSessionConfig;
+List<String> crateBindingsUtdQueueUtdQueueAddEvent({required UtdQueue that , required String roomId , required String eventId , required String encryptedContent });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MegolmSessionConfigPtr;
+List<RetriedEvent> crateBindingsUtdQueueUtdQueueAddRoomKey({required UtdQueue that , required InboundRoomKey roomKey });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_OlmMessage;
+UtdQueue crateBindingsUtdQueueUtdQueueDefault();
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_OlmMessage;
+UtdQueue crateBindingsUtdQueueUtdQueueFromPickleEncrypted({required String pickle , required U8Array32 pickleKey });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OlmMessagePtr;
+bool crateBindingsUtdQueueUtdQueueIsEmpty({required UtdQueue that });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_OlmSessionConfig;
+BigInt crateBindingsUtdQueueUtdQueueLen({required UtdQueue that });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_OlmSessionConfig;
+int crateBindingsUtdQueueUtdQueueMaxEvents({required UtdQueue that });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OlmSessionConfigPtr;
+List<RetriedEvent> crateBindingsUtdQueueUtdQueueMergeRoomKey({required UtdQueue that , required InboundRoomKey existing , required InboundRoomKey other });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PkDecryption;
+UtdQueue crateBindingsUtdQueueUtdQueueNew();
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PkDecryption;
+List<String> crateBindingsUtdQueueUtdQueuePendingEvents({required UtdQueue that , required String roomId , required String sessionId });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkDecryptionPtr;
+String crateBindingsUtdQueueUtdQueuePickleEncrypted({required UtdQueue that , required U8Array32 pickleKey });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PkEncryption;
+bool crateBindingsUtdQueueUtdQueueRemoveEvent({required UtdQueue that , required String eventId });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PkEncryption;
+List<String> crateBindingsUtdQueueUtdQueueSetMaxEvents({required UtdQueue that , required int maxEvents });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkEncryptionPtr;
+OlmPayload crateBindingsToDeviceValidateToDevicePayload({required VodozemacAccount account , required String ownUserId , required String eventSender , required String plaintext });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockGroupSession;
+VodozemacOlmSessionCreationResult crateBindingsVodozemacAccountCreateInboundSession({required VodozemacAccount that , required VodozemacCurve25519PublicKey theirIdentityKey , required String preKeyMessageBase64 });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockGroupSession;
+VodozemacSession crateBindingsVodozemacAccountCreateOutboundSession({required VodozemacAccount that , required VodozemacOlmSessionConfig config , required VodozemacCurve25519PublicKey identityKey , required VodozemacCurve25519PublicKey oneTimeKey });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockGroupSessionPtr;
+ClaimedSession crateBindingsVodozemacAccountCreateOutboundSessionFromClaim({required VodozemacAccount that , required VodozemacOlmSessionConfig config , required DeviceKeys deviceKeys , required String claimedKeyJson });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockInboundGroupSession;
+VodozemacCurve25519PublicKey crateBindingsVodozemacAccountCurve25519Key({required VodozemacAccount that });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockInboundGroupSession;
+VodozemacEd25519PublicKey crateBindingsVodozemacAccountEd25519Key({required VodozemacAccount that });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockInboundGroupSessionPtr;
+List<VodozemacOneTimeKey> crateBindingsVodozemacAccountFallbackKey({required VodozemacAccount that });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockSession;
+bool crateBindingsVodozemacAccountForgetFallbackKey({required VodozemacAccount that });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockSession;
+UnpickledAccount crateBindingsVodozemacAccountFromAnyPickle({required String pickle , required List<int> pickleKey });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockSessionPtr;
+VodozemacAccount crateBindingsVodozemacAccountFromOlmPickleEncrypted({required String pickle , required List<int> pickleKey });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PkSigning;
+VodozemacAccount crateBindingsVodozemacAccountFromPickleEncrypted({required String pickle , required U8Array32 pickleKey });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PkSigning;
+VodozemacAccount crateBindingsVodozemacAccountFromPickleWithPassphrase({required String pickle , required String passphrase });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkSigningPtr;
+String? crateBindingsVodozemacAccountGenerateFallbackKey({required VodozemacAccount that });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_VodozemacSas;
+void crateBindingsVodozemacAccountGenerateOneTimeKeys({required VodozemacAccount that , required BigInt count });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_VodozemacSas;
+VodozemacIdentityKeys crateBindingsVodozemacAccountIdentityKeys({required VodozemacAccount that });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_VodozemacSasPtr;
+void crateBindingsVodozemacAccountMarkKeysAsPublished({required VodozemacAccount that });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockAccount;
+BigInt crateBindingsVodozemacAccountMaxNumberOfOneTimeKeys({required VodozemacAccount that });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockAccount;
+VodozemacAccount crateBindingsVodozemacAccountNew();
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockAccountPtr;
-}
+List<VodozemacOneTimeKey> crateBindingsVodozemacAccountOneTimeKeys({required VodozemacAccount that });
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+String crateBindingsVodozemacAccountPickleEncrypted({required VodozemacAccount that , required U8Array32 pickleKey });
 
-  @override
-  PkSigning crateBindingsPkSigningFromSecretKey({required String key}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(key);
-        return wire.wire__crate__bindings__PkSigning_from_secret_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData:
-            dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsPkSigningFromSecretKeyConstMeta,
-      argValues: [key],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsPkSigningFromSecretKeyConstMeta => const TaskConstMeta(
-        debugName: "PkSigning_from_secret_key",
-        argNames: ["key"],
-      );
-
-  @override
-  PkSigning crateBindingsPkSigningNew() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        return wire.wire__crate__bindings__PkSigning_new();
-      },
-      codec: DcoCodec(
-        decodeSuccessData:
-            dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsPkSigningNewConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsPkSigningNewConstMeta => const TaskConstMeta(
-        debugName: "PkSigning_new",
-        argNames: [],
-      );
-
-  @override
-  VodozemacEd25519PublicKey crateBindingsPkSigningPublicKey({required PkSigning that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(that);
-        return wire.wire__crate__bindings__PkSigning_public_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_ed_25519_public_key,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsPkSigningPublicKeyConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsPkSigningPublicKeyConstMeta => const TaskConstMeta(
-        debugName: "PkSigning_public_key",
-        argNames: ["that"],
-      );
-
-  @override
-  String crateBindingsPkSigningSecretKey({required PkSigning that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(that);
-        return wire.wire__crate__bindings__PkSigning_secret_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsPkSigningSecretKeyConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsPkSigningSecretKeyConstMeta => const TaskConstMeta(
-        debugName: "PkSigning_secret_key",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacEd25519Signature crateBindingsPkSigningSign({required PkSigning that, required String message}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(that);
-        var arg1 = cst_encode_String(message);
-        return wire.wire__crate__bindings__PkSigning_sign(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_ed_25519_signature,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsPkSigningSignConstMeta,
-      argValues: [that, message],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsPkSigningSignConstMeta => const TaskConstMeta(
-        debugName: "PkSigning_sign",
-        argNames: ["that", "message"],
-      );
-
-  @override
-  VodozemacEstablishedSas crateBindingsVodozemacSasEstablishSasSecret(
-      {required VodozemacSas that, required String otherPublicKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 =
-            cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(that);
-        var arg1 = cst_encode_String(otherPublicKey);
-        return wire.wire__crate__bindings__VodozemacSas_establish_sas_secret(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_established_sas,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacSasEstablishSasSecretConstMeta,
-      argValues: [that, otherPublicKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacSasEstablishSasSecretConstMeta => const TaskConstMeta(
-        debugName: "VodozemacSas_establish_sas_secret",
-        argNames: ["that", "otherPublicKey"],
-      );
-
-  @override
-  VodozemacSas crateBindingsVodozemacSasNew() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        return wire.wire__crate__bindings__VodozemacSas_new();
-      },
-      codec: DcoCodec(
-        decodeSuccessData:
-            dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacSasNewConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacSasNewConstMeta => const TaskConstMeta(
-        debugName: "VodozemacSas_new",
-        argNames: [],
-      );
-
-  @override
-  String crateBindingsVodozemacSasPublicKey({required VodozemacSas that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(that);
-        return wire.wire__crate__bindings__VodozemacSas_public_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacSasPublicKeyConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacSasPublicKeyConstMeta => const TaskConstMeta(
-        debugName: "VodozemacSas_public_key",
-        argNames: ["that"],
-      );
-
-  @override
-  Uint8List crateBindingsAesCtr({required List<int> input, required List<int> key, required List<int> iv}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_list_prim_u_8_loose(input);
-        var arg1 = cst_encode_list_prim_u_8_loose(key);
-        var arg2 = cst_encode_list_prim_u_8_loose(iv);
-        return wire.wire__crate__bindings__aes_ctr(arg0, arg1, arg2);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_list_prim_u_8_strict,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsAesCtrConstMeta,
-      argValues: [input, key, iv],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsAesCtrConstMeta => const TaskConstMeta(
-        debugName: "aes_ctr",
-        argNames: ["input", "key", "iv"],
-      );
-
-  @override
-  Uint8List crateBindingsHmac({required List<int> key, required List<int> input}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_list_prim_u_8_loose(key);
-        var arg1 = cst_encode_list_prim_u_8_loose(input);
-        return wire.wire__crate__bindings__hmac(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_list_prim_u_8_strict,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsHmacConstMeta,
-      argValues: [key, input],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsHmacConstMeta => const TaskConstMeta(
-        debugName: "hmac",
-        argNames: ["key", "input"],
-      );
-
-  @override
-  Uint8List crateBindingsPbkdf2({required List<int> passphrase, required List<int> salt, required int iterations}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_list_prim_u_8_loose(passphrase);
-        var arg1 = cst_encode_list_prim_u_8_loose(salt);
-        var arg2 = cst_encode_u_32(iterations);
-        return wire.wire__crate__bindings__pbkdf2(arg0, arg1, arg2);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_list_prim_u_8_strict,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsPbkdf2ConstMeta,
-      argValues: [passphrase, salt, iterations],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsPbkdf2ConstMeta => const TaskConstMeta(
-        debugName: "pbkdf2",
-        argNames: ["passphrase", "salt", "iterations"],
-      );
-
-  @override
-  Uint8List crateBindingsSha256({required List<int> input}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_list_prim_u_8_loose(input);
-        return wire.wire__crate__bindings__sha256(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_list_prim_u_8_strict,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsSha256ConstMeta,
-      argValues: [input],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsSha256ConstMeta => const TaskConstMeta(
-        debugName: "sha256",
-        argNames: ["input"],
-      );
-
-  @override
-  Uint8List crateBindingsSha512({required List<int> input}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_list_prim_u_8_loose(input);
-        return wire.wire__crate__bindings__sha512(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_list_prim_u_8_strict,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsSha512ConstMeta,
-      argValues: [input],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsSha512ConstMeta => const TaskConstMeta(
-        debugName: "sha512",
-        argNames: ["input"],
-      );
-
-  @override
-  VodozemacOlmSessionCreationResult crateBindingsVodozemacAccountCreateInboundSession(
-      {required VodozemacAccount that,
-      required VodozemacCurve25519PublicKey theirIdentityKey,
-      required String preKeyMessageBase64}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        var arg1 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(theirIdentityKey);
-        var arg2 = cst_encode_String(preKeyMessageBase64);
-        return wire.wire__crate__bindings__vodozemac_account_create_inbound_session(arg0, arg1, arg2);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_olm_session_creation_result,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountCreateInboundSessionConstMeta,
-      argValues: [that, theirIdentityKey, preKeyMessageBase64],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountCreateInboundSessionConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_create_inbound_session",
-        argNames: ["that", "theirIdentityKey", "preKeyMessageBase64"],
-      );
-
-  @override
-  VodozemacSession crateBindingsVodozemacAccountCreateOutboundSession(
-      {required VodozemacAccount that,
-      required VodozemacOlmSessionConfig config,
-      required VodozemacCurve25519PublicKey identityKey,
-      required VodozemacCurve25519PublicKey oneTimeKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        var arg1 = cst_encode_box_autoadd_vodozemac_olm_session_config(config);
-        var arg2 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(identityKey);
-        var arg3 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(oneTimeKey);
-        return wire.wire__crate__bindings__vodozemac_account_create_outbound_session(arg0, arg1, arg2, arg3);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_session,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountCreateOutboundSessionConstMeta,
-      argValues: [that, config, identityKey, oneTimeKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountCreateOutboundSessionConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_create_outbound_session",
-        argNames: ["that", "config", "identityKey", "oneTimeKey"],
-      );
-
-  @override
-  VodozemacCurve25519PublicKey crateBindingsVodozemacAccountCurve25519Key({required VodozemacAccount that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        return wire.wire__crate__bindings__vodozemac_account_curve25519_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_curve_25519_public_key,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountCurve25519KeyConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountCurve25519KeyConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_curve25519_key",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacEd25519PublicKey crateBindingsVodozemacAccountEd25519Key({required VodozemacAccount that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        return wire.wire__crate__bindings__vodozemac_account_ed25519_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_ed_25519_public_key,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountEd25519KeyConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountEd25519KeyConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_ed25519_key",
-        argNames: ["that"],
-      );
-
-  @override
-  List<VodozemacOneTimeKey> crateBindingsVodozemacAccountFallbackKey({required VodozemacAccount that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        return wire.wire__crate__bindings__vodozemac_account_fallback_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_list_vodozemac_one_time_key,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountFallbackKeyConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountFallbackKeyConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_fallback_key",
-        argNames: ["that"],
-      );
-
-  @override
-  bool crateBindingsVodozemacAccountForgetFallbackKey({required VodozemacAccount that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        return wire.wire__crate__bindings__vodozemac_account_forget_fallback_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_bool,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountForgetFallbackKeyConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountForgetFallbackKeyConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_forget_fallback_key",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacAccount crateBindingsVodozemacAccountFromOlmPickleEncrypted(
-      {required String pickle, required List<int> pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(pickle);
-        var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_account,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountFromOlmPickleEncryptedConstMeta,
-      argValues: [pickle, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountFromOlmPickleEncryptedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_from_olm_pickle_encrypted",
-        argNames: ["pickle", "pickleKey"],
-      );
-
-  @override
-  VodozemacAccount crateBindingsVodozemacAccountFromPickleEncrypted(
-      {required String pickle, required U8Array32 pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(pickle);
-        var arg1 = cst_encode_u_8_array_32(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_account_from_pickle_encrypted(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_account,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountFromPickleEncryptedConstMeta,
-      argValues: [pickle, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountFromPickleEncryptedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_from_pickle_encrypted",
-        argNames: ["pickle", "pickleKey"],
-      );
-
-  @override
-  String? crateBindingsVodozemacAccountGenerateFallbackKey({required VodozemacAccount that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        return wire.wire__crate__bindings__vodozemac_account_generate_fallback_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_opt_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountGenerateFallbackKeyConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountGenerateFallbackKeyConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_generate_fallback_key",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateBindingsVodozemacAccountGenerateOneTimeKeys({required VodozemacAccount that, required BigInt count}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        var arg1 = cst_encode_usize(count);
-        return wire.wire__crate__bindings__vodozemac_account_generate_one_time_keys(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountGenerateOneTimeKeysConstMeta,
-      argValues: [that, count],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountGenerateOneTimeKeysConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_generate_one_time_keys",
-        argNames: ["that", "count"],
-      );
-
-  @override
-  VodozemacIdentityKeys crateBindingsVodozemacAccountIdentityKeys({required VodozemacAccount that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        return wire.wire__crate__bindings__vodozemac_account_identity_keys(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_identity_keys,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountIdentityKeysConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountIdentityKeysConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_identity_keys",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateBindingsVodozemacAccountMarkKeysAsPublished({required VodozemacAccount that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        return wire.wire__crate__bindings__vodozemac_account_mark_keys_as_published(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountMarkKeysAsPublishedConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountMarkKeysAsPublishedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_mark_keys_as_published",
-        argNames: ["that"],
-      );
-
-  @override
-  BigInt crateBindingsVodozemacAccountMaxNumberOfOneTimeKeys({required VodozemacAccount that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        return wire.wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_usize,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountMaxNumberOfOneTimeKeysConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountMaxNumberOfOneTimeKeysConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_max_number_of_one_time_keys",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacAccount crateBindingsVodozemacAccountNew() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        return wire.wire__crate__bindings__vodozemac_account_new();
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_account,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountNewConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountNewConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_new",
-        argNames: [],
-      );
-
-  @override
-  List<VodozemacOneTimeKey> crateBindingsVodozemacAccountOneTimeKeys({required VodozemacAccount that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        return wire.wire__crate__bindings__vodozemac_account_one_time_keys(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_list_vodozemac_one_time_key,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountOneTimeKeysConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountOneTimeKeysConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_one_time_keys",
-        argNames: ["that"],
-      );
-
-  @override
-  String crateBindingsVodozemacAccountPickleEncrypted({required VodozemacAccount that, required U8Array32 pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        var arg1 = cst_encode_u_8_array_32(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_account_pickle_encrypted(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountPickleEncryptedConstMeta,
-      argValues: [that, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountPickleEncryptedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_pickle_encrypted",
-        argNames: ["that", "pickleKey"],
-      );
-
-  @override
-  Uint8List crateBindingsVodozemacAccountRemoveOneTimeKey({required VodozemacAccount that, required String publicKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        var arg1 = cst_encode_String(publicKey);
-        return wire.wire__crate__bindings__vodozemac_account_remove_one_time_key(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_list_prim_u_8_strict,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountRemoveOneTimeKeyConstMeta,
-      argValues: [that, publicKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountRemoveOneTimeKeyConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_remove_one_time_key",
-        argNames: ["that", "publicKey"],
-      );
-
-  @override
-  VodozemacEd25519Signature crateBindingsVodozemacAccountSign(
-      {required VodozemacAccount that, required String message}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
-        var arg1 = cst_encode_String(message);
-        return wire.wire__crate__bindings__vodozemac_account_sign(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_ed_25519_signature,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacAccountSignConstMeta,
-      argValues: [that, message],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacAccountSignConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_account_sign",
-        argNames: ["that", "message"],
-      );
-
-  @override
-  U8Array32 crateBindingsVodozemacCurve25519PublicKeyAsBytes({required VodozemacCurve25519PublicKey that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(that);
-        return wire.wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_u_8_array_32,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacCurve25519PublicKeyAsBytesConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacCurve25519PublicKeyAsBytesConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_curve_25519_public_key_as_bytes",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacCurve25519PublicKey crateBindingsVodozemacCurve25519PublicKeyFromBase64({required String base64Key}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(base64Key);
-        return wire.wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_curve_25519_public_key,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacCurve25519PublicKeyFromBase64ConstMeta,
-      argValues: [base64Key],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacCurve25519PublicKeyFromBase64ConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_curve_25519_public_key_from_base64",
-        argNames: ["base64Key"],
-      );
-
-  @override
-  VodozemacCurve25519PublicKey crateBindingsVodozemacCurve25519PublicKeyFromSlice({required U8Array32 bytes}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_u_8_array_32(bytes);
-        return wire.wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_curve_25519_public_key,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacCurve25519PublicKeyFromSliceConstMeta,
-      argValues: [bytes],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacCurve25519PublicKeyFromSliceConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_curve_25519_public_key_from_slice",
-        argNames: ["bytes"],
-      );
-
-  @override
-  String crateBindingsVodozemacCurve25519PublicKeyToBase64({required VodozemacCurve25519PublicKey that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(that);
-        return wire.wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacCurve25519PublicKeyToBase64ConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacCurve25519PublicKeyToBase64ConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_curve_25519_public_key_to_base64",
-        argNames: ["that"],
-      );
-
-  @override
-  U8Array32 crateBindingsVodozemacEd25519PublicKeyAsBytes({required VodozemacEd25519PublicKey that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_ed_25519_public_key(that);
-        return wire.wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_u_8_array_32,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacEd25519PublicKeyAsBytesConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEd25519PublicKeyAsBytesConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_ed_25519_public_key_as_bytes",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacEd25519PublicKey crateBindingsVodozemacEd25519PublicKeyFromBase64({required String base64Key}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(base64Key);
-        return wire.wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_ed_25519_public_key,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacEd25519PublicKeyFromBase64ConstMeta,
-      argValues: [base64Key],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEd25519PublicKeyFromBase64ConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_ed_25519_public_key_from_base64",
-        argNames: ["base64Key"],
-      );
-
-  @override
-  VodozemacEd25519PublicKey crateBindingsVodozemacEd25519PublicKeyFromSlice({required U8Array32 bytes}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_u_8_array_32(bytes);
-        return wire.wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_ed_25519_public_key,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacEd25519PublicKeyFromSliceConstMeta,
-      argValues: [bytes],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEd25519PublicKeyFromSliceConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_ed_25519_public_key_from_slice",
-        argNames: ["bytes"],
-      );
-
-  @override
-  String crateBindingsVodozemacEd25519PublicKeyToBase64({required VodozemacEd25519PublicKey that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_ed_25519_public_key(that);
-        return wire.wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacEd25519PublicKeyToBase64ConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEd25519PublicKeyToBase64ConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_ed_25519_public_key_to_base64",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateBindingsVodozemacEd25519PublicKeyVerify(
-      {required VodozemacEd25519PublicKey that,
-      required String message,
-      required VodozemacEd25519Signature signature}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_ed_25519_public_key(that);
-        var arg1 = cst_encode_String(message);
-        var arg2 = cst_encode_box_autoadd_vodozemac_ed_25519_signature(signature);
-        return wire.wire__crate__bindings__vodozemac_ed_25519_public_key_verify(arg0, arg1, arg2);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_unit,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacEd25519PublicKeyVerifyConstMeta,
-      argValues: [that, message, signature],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEd25519PublicKeyVerifyConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_ed_25519_public_key_verify",
-        argNames: ["that", "message", "signature"],
-      );
-
-  @override
-  VodozemacEd25519Signature crateBindingsVodozemacEd25519SignatureFromBase64({required String signature}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(signature);
-        return wire.wire__crate__bindings__vodozemac_ed_25519_signature_from_base64(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_ed_25519_signature,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacEd25519SignatureFromBase64ConstMeta,
-      argValues: [signature],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEd25519SignatureFromBase64ConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_ed_25519_signature_from_base64",
-        argNames: ["signature"],
-      );
-
-  @override
-  VodozemacEd25519Signature crateBindingsVodozemacEd25519SignatureFromSlice({required U8Array64 bytes}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_u_8_array_64(bytes);
-        return wire.wire__crate__bindings__vodozemac_ed_25519_signature_from_slice(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_ed_25519_signature,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacEd25519SignatureFromSliceConstMeta,
-      argValues: [bytes],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEd25519SignatureFromSliceConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_ed_25519_signature_from_slice",
-        argNames: ["bytes"],
-      );
-
-  @override
-  String crateBindingsVodozemacEd25519SignatureToBase64({required VodozemacEd25519Signature that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_ed_25519_signature(that);
-        return wire.wire__crate__bindings__vodozemac_ed_25519_signature_to_base64(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacEd25519SignatureToBase64ConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEd25519SignatureToBase64ConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_ed_25519_signature_to_base64",
-        argNames: ["that"],
-      );
-
-  @override
-  U8Array64 crateBindingsVodozemacEd25519SignatureToBytes({required VodozemacEd25519Signature that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_ed_25519_signature(that);
-        return wire.wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_u_8_array_64,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacEd25519SignatureToBytesConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEd25519SignatureToBytesConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_ed_25519_signature_to_bytes",
-        argNames: ["that"],
-      );
-
-  @override
-  String crateBindingsVodozemacEstablishedSasCalculateMac(
-      {required VodozemacEstablishedSas that, required String input, required String info}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_established_sas(that);
-        var arg1 = cst_encode_String(input);
-        var arg2 = cst_encode_String(info);
-        return wire.wire__crate__bindings__vodozemac_established_sas_calculate_mac(arg0, arg1, arg2);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacEstablishedSasCalculateMacConstMeta,
-      argValues: [that, input, info],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEstablishedSasCalculateMacConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_established_sas_calculate_mac",
-        argNames: ["that", "input", "info"],
-      );
-
-  @override
-  String crateBindingsVodozemacEstablishedSasCalculateMacDeprecated(
-      {required VodozemacEstablishedSas that, required String input, required String info}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_established_sas(that);
-        var arg1 = cst_encode_String(input);
-        var arg2 = cst_encode_String(info);
-        return wire.wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated(arg0, arg1, arg2);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacEstablishedSasCalculateMacDeprecatedConstMeta,
-      argValues: [that, input, info],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEstablishedSasCalculateMacDeprecatedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_established_sas_calculate_mac_deprecated",
-        argNames: ["that", "input", "info"],
-      );
-
-  @override
-  Uint8List crateBindingsVodozemacEstablishedSasGenerateBytes(
-      {required VodozemacEstablishedSas that, required String info, required int length}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_established_sas(that);
-        var arg1 = cst_encode_String(info);
-        var arg2 = cst_encode_u_32(length);
-        return wire.wire__crate__bindings__vodozemac_established_sas_generate_bytes(arg0, arg1, arg2);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_list_prim_u_8_strict,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacEstablishedSasGenerateBytesConstMeta,
-      argValues: [that, info, length],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEstablishedSasGenerateBytesConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_established_sas_generate_bytes",
-        argNames: ["that", "info", "length"],
-      );
-
-  @override
-  void crateBindingsVodozemacEstablishedSasVerifyMac(
-      {required VodozemacEstablishedSas that, required String input, required String info, required String mac}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_established_sas(that);
-        var arg1 = cst_encode_String(input);
-        var arg2 = cst_encode_String(info);
-        var arg3 = cst_encode_String(mac);
-        return wire.wire__crate__bindings__vodozemac_established_sas_verify_mac(arg0, arg1, arg2, arg3);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_unit,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacEstablishedSasVerifyMacConstMeta,
-      argValues: [that, input, info, mac],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacEstablishedSasVerifyMacConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_established_sas_verify_mac",
-        argNames: ["that", "input", "info", "mac"],
-      );
-
-  @override
-  String crateBindingsVodozemacGroupSessionEncrypt({required VodozemacGroupSession that, required String plaintext}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
-        var arg1 = cst_encode_String(plaintext);
-        return wire.wire__crate__bindings__vodozemac_group_session_encrypt(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacGroupSessionEncryptConstMeta,
-      argValues: [that, plaintext],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacGroupSessionEncryptConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_group_session_encrypt",
-        argNames: ["that", "plaintext"],
-      );
-
-  @override
-  VodozemacGroupSession crateBindingsVodozemacGroupSessionFromOlmPickleEncrypted(
-      {required String pickle, required List<int> pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(pickle);
-        var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_group_session,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacGroupSessionFromOlmPickleEncryptedConstMeta,
-      argValues: [pickle, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacGroupSessionFromOlmPickleEncryptedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_group_session_from_olm_pickle_encrypted",
-        argNames: ["pickle", "pickleKey"],
-      );
-
-  @override
-  VodozemacGroupSession crateBindingsVodozemacGroupSessionFromPickleEncrypted(
-      {required String pickle, required U8Array32 pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(pickle);
-        var arg1 = cst_encode_u_8_array_32(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_group_session,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacGroupSessionFromPickleEncryptedConstMeta,
-      argValues: [pickle, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacGroupSessionFromPickleEncryptedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_group_session_from_pickle_encrypted",
-        argNames: ["pickle", "pickleKey"],
-      );
-
-  @override
-  int crateBindingsVodozemacGroupSessionMessageIndex({required VodozemacGroupSession that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
-        return wire.wire__crate__bindings__vodozemac_group_session_message_index(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_u_32,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacGroupSessionMessageIndexConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacGroupSessionMessageIndexConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_group_session_message_index",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacGroupSession crateBindingsVodozemacGroupSessionNew({required VodozemacMegolmSessionConfig config}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_megolm_session_config(config);
-        return wire.wire__crate__bindings__vodozemac_group_session_new(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_group_session,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacGroupSessionNewConstMeta,
-      argValues: [config],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacGroupSessionNewConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_group_session_new",
-        argNames: ["config"],
-      );
-
-  @override
-  String crateBindingsVodozemacGroupSessionPickleEncrypted(
-      {required VodozemacGroupSession that, required U8Array32 pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
-        var arg1 = cst_encode_u_8_array_32(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_group_session_pickle_encrypted(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacGroupSessionPickleEncryptedConstMeta,
-      argValues: [that, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacGroupSessionPickleEncryptedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_group_session_pickle_encrypted",
-        argNames: ["that", "pickleKey"],
-      );
-
-  @override
-  VodozemacMegolmSessionConfig crateBindingsVodozemacGroupSessionSessionConfig({required VodozemacGroupSession that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
-        return wire.wire__crate__bindings__vodozemac_group_session_session_config(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_megolm_session_config,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacGroupSessionSessionConfigConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacGroupSessionSessionConfigConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_group_session_session_config",
-        argNames: ["that"],
-      );
-
-  @override
-  String crateBindingsVodozemacGroupSessionSessionId({required VodozemacGroupSession that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
-        return wire.wire__crate__bindings__vodozemac_group_session_session_id(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacGroupSessionSessionIdConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacGroupSessionSessionIdConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_group_session_session_id",
-        argNames: ["that"],
-      );
-
-  @override
-  String crateBindingsVodozemacGroupSessionSessionKey({required VodozemacGroupSession that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
-        return wire.wire__crate__bindings__vodozemac_group_session_session_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacGroupSessionSessionKeyConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacGroupSessionSessionKeyConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_group_session_session_key",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacInboundGroupSession crateBindingsVodozemacGroupSessionToInbound({required VodozemacGroupSession that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
-        return wire.wire__crate__bindings__vodozemac_group_session_to_inbound(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_inbound_group_session,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacGroupSessionToInboundConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacGroupSessionToInboundConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_group_session_to_inbound",
-        argNames: ["that"],
-      );
-
-  @override
-  DecryptResult crateBindingsVodozemacInboundGroupSessionDecrypt(
-      {required VodozemacInboundGroupSession that, required String encrypted}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
-        var arg1 = cst_encode_String(encrypted);
-        return wire.wire__crate__bindings__vodozemac_inbound_group_session_decrypt(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_decrypt_result,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacInboundGroupSessionDecryptConstMeta,
-      argValues: [that, encrypted],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionDecryptConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_inbound_group_session_decrypt",
-        argNames: ["that", "encrypted"],
-      );
-
-  @override
-  String? crateBindingsVodozemacInboundGroupSessionExportAt(
-      {required VodozemacInboundGroupSession that, required int index}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
-        var arg1 = cst_encode_u_32(index);
-        return wire.wire__crate__bindings__vodozemac_inbound_group_session_export_at(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_opt_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacInboundGroupSessionExportAtConstMeta,
-      argValues: [that, index],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionExportAtConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_inbound_group_session_export_at",
-        argNames: ["that", "index"],
-      );
-
-  @override
-  String crateBindingsVodozemacInboundGroupSessionExportAtFirstKnownIndex(
-      {required VodozemacInboundGroupSession that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
-        return wire.wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacInboundGroupSessionExportAtFirstKnownIndexConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionExportAtFirstKnownIndexConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_inbound_group_session_export_at_first_known_index",
-        argNames: ["that"],
-      );
-
-  @override
-  int crateBindingsVodozemacInboundGroupSessionFirstKnownIndex({required VodozemacInboundGroupSession that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
-        return wire.wire__crate__bindings__vodozemac_inbound_group_session_first_known_index(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_u_32,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacInboundGroupSessionFirstKnownIndexConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionFirstKnownIndexConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_inbound_group_session_first_known_index",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionFromOlmPickleEncrypted(
-      {required String pickle, required List<int> pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(pickle);
-        var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_inbound_group_session,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacInboundGroupSessionFromOlmPickleEncryptedConstMeta,
-      argValues: [pickle, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionFromOlmPickleEncryptedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_inbound_group_session_from_olm_pickle_encrypted",
-        argNames: ["pickle", "pickleKey"],
-      );
-
-  @override
-  VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionFromPickleEncrypted(
-      {required String pickle, required U8Array32 pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(pickle);
-        var arg1 = cst_encode_u_8_array_32(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_inbound_group_session,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacInboundGroupSessionFromPickleEncryptedConstMeta,
-      argValues: [pickle, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionFromPickleEncryptedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_inbound_group_session_from_pickle_encrypted",
-        argNames: ["pickle", "pickleKey"],
-      );
-
-  @override
-  VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionImport(
-      {required String exportedSessionKey, required VodozemacMegolmSessionConfig config}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(exportedSessionKey);
-        var arg1 = cst_encode_box_autoadd_vodozemac_megolm_session_config(config);
-        return wire.wire__crate__bindings__vodozemac_inbound_group_session_import(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_inbound_group_session,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacInboundGroupSessionImportConstMeta,
-      argValues: [exportedSessionKey, config],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionImportConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_inbound_group_session_import",
-        argNames: ["exportedSessionKey", "config"],
-      );
-
-  @override
-  VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionNew(
-      {required String sessionKey, required VodozemacMegolmSessionConfig config}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(sessionKey);
-        var arg1 = cst_encode_box_autoadd_vodozemac_megolm_session_config(config);
-        return wire.wire__crate__bindings__vodozemac_inbound_group_session_new(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_inbound_group_session,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacInboundGroupSessionNewConstMeta,
-      argValues: [sessionKey, config],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionNewConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_inbound_group_session_new",
-        argNames: ["sessionKey", "config"],
-      );
-
-  @override
-  String crateBindingsVodozemacInboundGroupSessionPickleEncrypted(
-      {required VodozemacInboundGroupSession that, required U8Array32 pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
-        var arg1 = cst_encode_u_8_array_32(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacInboundGroupSessionPickleEncryptedConstMeta,
-      argValues: [that, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionPickleEncryptedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_inbound_group_session_pickle_encrypted",
-        argNames: ["that", "pickleKey"],
-      );
-
-  @override
-  String crateBindingsVodozemacInboundGroupSessionSessionId({required VodozemacInboundGroupSession that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
-        return wire.wire__crate__bindings__vodozemac_inbound_group_session_session_id(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacInboundGroupSessionSessionIdConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionSessionIdConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_inbound_group_session_session_id",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacMegolmSessionConfig crateBindingsVodozemacMegolmSessionConfigDef() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        return wire.wire__crate__bindings__vodozemac_megolm_session_config_def();
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_megolm_session_config,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacMegolmSessionConfigDefConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacMegolmSessionConfigDefConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_megolm_session_config_def",
-        argNames: [],
-      );
-
-  @override
-  int crateBindingsVodozemacMegolmSessionConfigVersion({required VodozemacMegolmSessionConfig that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_megolm_session_config(that);
-        return wire.wire__crate__bindings__vodozemac_megolm_session_config_version(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_u_8,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacMegolmSessionConfigVersionConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacMegolmSessionConfigVersionConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_megolm_session_config_version",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacMegolmSessionConfig crateBindingsVodozemacMegolmSessionConfigVersion1() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        return wire.wire__crate__bindings__vodozemac_megolm_session_config_version_1();
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_megolm_session_config,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacMegolmSessionConfigVersion1ConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacMegolmSessionConfigVersion1ConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_megolm_session_config_version_1",
-        argNames: [],
-      );
-
-  @override
-  VodozemacMegolmSessionConfig crateBindingsVodozemacMegolmSessionConfigVersion2() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        return wire.wire__crate__bindings__vodozemac_megolm_session_config_version_2();
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_megolm_session_config,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacMegolmSessionConfigVersion2ConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacMegolmSessionConfigVersion2ConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_megolm_session_config_version_2",
-        argNames: [],
-      );
-
-  @override
-  VodozemacOlmMessage crateBindingsVodozemacOlmMessageFromParts(
-      {required BigInt messageType, required String ciphertext}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_usize(messageType);
-        var arg1 = cst_encode_String(ciphertext);
-        return wire.wire__crate__bindings__vodozemac_olm_message_from_parts(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_olm_message,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacOlmMessageFromPartsConstMeta,
-      argValues: [messageType, ciphertext],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacOlmMessageFromPartsConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_olm_message_from_parts",
-        argNames: ["messageType", "ciphertext"],
-      );
-
-  @override
-  String crateBindingsVodozemacOlmMessageMessage({required VodozemacOlmMessage that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_olm_message(that);
-        return wire.wire__crate__bindings__vodozemac_olm_message_message(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacOlmMessageMessageConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacOlmMessageMessageConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_olm_message_message",
-        argNames: ["that"],
-      );
-
-  @override
-  BigInt crateBindingsVodozemacOlmMessageMessageType({required VodozemacOlmMessage that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_olm_message(that);
-        return wire.wire__crate__bindings__vodozemac_olm_message_message_type(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_usize,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacOlmMessageMessageTypeConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacOlmMessageMessageTypeConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_olm_message_message_type",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacOlmSessionConfig crateBindingsVodozemacOlmSessionConfigDef() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        return wire.wire__crate__bindings__vodozemac_olm_session_config_def();
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_olm_session_config,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacOlmSessionConfigDefConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacOlmSessionConfigDefConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_olm_session_config_def",
-        argNames: [],
-      );
-
-  @override
-  int crateBindingsVodozemacOlmSessionConfigVersion({required VodozemacOlmSessionConfig that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_olm_session_config(that);
-        return wire.wire__crate__bindings__vodozemac_olm_session_config_version(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_u_8,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacOlmSessionConfigVersionConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacOlmSessionConfigVersionConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_olm_session_config_version",
-        argNames: ["that"],
-      );
-
-  @override
-  VodozemacOlmSessionConfig crateBindingsVodozemacOlmSessionConfigVersion1() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        return wire.wire__crate__bindings__vodozemac_olm_session_config_version_1();
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_olm_session_config,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacOlmSessionConfigVersion1ConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacOlmSessionConfigVersion1ConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_olm_session_config_version_1",
-        argNames: [],
-      );
-
-  @override
-  VodozemacOlmSessionConfig crateBindingsVodozemacOlmSessionConfigVersion2() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        return wire.wire__crate__bindings__vodozemac_olm_session_config_version_2();
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_olm_session_config,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacOlmSessionConfigVersion2ConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacOlmSessionConfigVersion2ConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_olm_session_config_version_2",
-        argNames: [],
-      );
-
-  @override
-  String crateBindingsVodozemacPkDecryptionDecrypt(
-      {required VodozemacPkDecryption that, required VodozemacPkMessage message}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_pk_decryption(that);
-        var arg1 = cst_encode_box_autoadd_vodozemac_pk_message(message);
-        return wire.wire__crate__bindings__vodozemac_pk_decryption_decrypt(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacPkDecryptionDecryptConstMeta,
-      argValues: [that, message],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacPkDecryptionDecryptConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_pk_decryption_decrypt",
-        argNames: ["that", "message"],
-      );
-
-  @override
-  VodozemacPkDecryption crateBindingsVodozemacPkDecryptionFromKey({required U8Array32 secretKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_u_8_array_32(secretKey);
-        return wire.wire__crate__bindings__vodozemac_pk_decryption_from_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_pk_decryption,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacPkDecryptionFromKeyConstMeta,
-      argValues: [secretKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacPkDecryptionFromKeyConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_pk_decryption_from_key",
-        argNames: ["secretKey"],
-      );
-
-  @override
-  VodozemacPkDecryption crateBindingsVodozemacPkDecryptionFromLibolmPickle(
-      {required String pickle, required List<int> pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(pickle);
-        var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_pk_decryption,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacPkDecryptionFromLibolmPickleConstMeta,
-      argValues: [pickle, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacPkDecryptionFromLibolmPickleConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_pk_decryption_from_libolm_pickle",
-        argNames: ["pickle", "pickleKey"],
-      );
-
-  @override
-  VodozemacPkDecryption crateBindingsVodozemacPkDecryptionNew() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        return wire.wire__crate__bindings__vodozemac_pk_decryption_new();
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_pk_decryption,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacPkDecryptionNewConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacPkDecryptionNewConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_pk_decryption_new",
-        argNames: [],
-      );
-
-  @override
-  Uint8List crateBindingsVodozemacPkDecryptionPrivateKey({required VodozemacPkDecryption that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_pk_decryption(that);
-        return wire.wire__crate__bindings__vodozemac_pk_decryption_private_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_list_prim_u_8_strict,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacPkDecryptionPrivateKeyConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacPkDecryptionPrivateKeyConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_pk_decryption_private_key",
-        argNames: ["that"],
-      );
-
-  @override
-  String crateBindingsVodozemacPkDecryptionPublicKey({required VodozemacPkDecryption that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_pk_decryption(that);
-        return wire.wire__crate__bindings__vodozemac_pk_decryption_public_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacPkDecryptionPublicKeyConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacPkDecryptionPublicKeyConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_pk_decryption_public_key",
-        argNames: ["that"],
-      );
-
-  @override
-  String crateBindingsVodozemacPkDecryptionToLibolmPickle(
-      {required VodozemacPkDecryption that, required U8Array32 pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_pk_decryption(that);
-        var arg1 = cst_encode_u_8_array_32(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacPkDecryptionToLibolmPickleConstMeta,
-      argValues: [that, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacPkDecryptionToLibolmPickleConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_pk_decryption_to_libolm_pickle",
-        argNames: ["that", "pickleKey"],
-      );
-
-  @override
-  VodozemacPkMessage crateBindingsVodozemacPkEncryptionEncrypt(
-      {required VodozemacPkEncryption that, required String message}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_pk_encryption(that);
-        var arg1 = cst_encode_String(message);
-        return wire.wire__crate__bindings__vodozemac_pk_encryption_encrypt(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_pk_message,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacPkEncryptionEncryptConstMeta,
-      argValues: [that, message],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacPkEncryptionEncryptConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_pk_encryption_encrypt",
-        argNames: ["that", "message"],
-      );
-
-  @override
-  VodozemacPkEncryption crateBindingsVodozemacPkEncryptionFromKey({required VodozemacCurve25519PublicKey publicKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(publicKey);
-        return wire.wire__crate__bindings__vodozemac_pk_encryption_from_key(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_pk_encryption,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacPkEncryptionFromKeyConstMeta,
-      argValues: [publicKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacPkEncryptionFromKeyConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_pk_encryption_from_key",
-        argNames: ["publicKey"],
-      );
-
-  @override
-  VodozemacPkMessage crateBindingsVodozemacPkMessageFromBase64(
-      {required String ciphertext, required String mac, required String ephemeralKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(ciphertext);
-        var arg1 = cst_encode_String(mac);
-        var arg2 = cst_encode_String(ephemeralKey);
-        return wire.wire__crate__bindings__vodozemac_pk_message_from_base64(arg0, arg1, arg2);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_pk_message,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacPkMessageFromBase64ConstMeta,
-      argValues: [ciphertext, mac, ephemeralKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacPkMessageFromBase64ConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_pk_message_from_base64",
-        argNames: ["ciphertext", "mac", "ephemeralKey"],
-      );
-
-  @override
-  VodozemacPkMessage crateBindingsVodozemacPkMessageNew(
-      {required List<int> ciphertext, required List<int> mac, required VodozemacCurve25519PublicKey ephemeralKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_list_prim_u_8_loose(ciphertext);
-        var arg1 = cst_encode_list_prim_u_8_loose(mac);
-        var arg2 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(ephemeralKey);
-        return wire.wire__crate__bindings__vodozemac_pk_message_new(arg0, arg1, arg2);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_pk_message,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacPkMessageNewConstMeta,
-      argValues: [ciphertext, mac, ephemeralKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacPkMessageNewConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_pk_message_new",
-        argNames: ["ciphertext", "mac", "ephemeralKey"],
-      );
-
-  @override
-  (String, String, String) crateBindingsVodozemacPkMessageToBase64({required VodozemacPkMessage that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_pk_message(that);
-        return wire.wire__crate__bindings__vodozemac_pk_message_to_base64(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_record_string_string_string,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacPkMessageToBase64ConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacPkMessageToBase64ConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_pk_message_to_base64",
-        argNames: ["that"],
-      );
-
-  @override
-  String crateBindingsVodozemacSessionDecrypt({required VodozemacSession that, required VodozemacOlmMessage message}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
-        var arg1 = cst_encode_box_autoadd_vodozemac_olm_message(message);
-        return wire.wire__crate__bindings__vodozemac_session_decrypt(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacSessionDecryptConstMeta,
-      argValues: [that, message],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacSessionDecryptConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_session_decrypt",
-        argNames: ["that", "message"],
-      );
-
-  @override
-  VodozemacOlmMessage crateBindingsVodozemacSessionEncrypt(
-      {required VodozemacSession that, required String plaintext}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
-        var arg1 = cst_encode_String(plaintext);
-        return wire.wire__crate__bindings__vodozemac_session_encrypt(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_olm_message,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacSessionEncryptConstMeta,
-      argValues: [that, plaintext],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacSessionEncryptConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_session_encrypt",
-        argNames: ["that", "plaintext"],
-      );
-
-  @override
-  VodozemacSession crateBindingsVodozemacSessionFromOlmPickleEncrypted(
-      {required String pickle, required List<int> pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(pickle);
-        var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_session,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacSessionFromOlmPickleEncryptedConstMeta,
-      argValues: [pickle, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacSessionFromOlmPickleEncryptedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_session_from_olm_pickle_encrypted",
-        argNames: ["pickle", "pickleKey"],
-      );
-
-  @override
-  VodozemacSession crateBindingsVodozemacSessionFromPickleEncrypted(
-      {required String pickle, required U8Array32 pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(pickle);
-        var arg1 = cst_encode_u_8_array_32(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_session_from_pickle_encrypted(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_session,
-        decodeErrorData: dco_decode_AnyhowException,
-      ),
-      constMeta: kCrateBindingsVodozemacSessionFromPickleEncryptedConstMeta,
-      argValues: [pickle, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacSessionFromPickleEncryptedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_session_from_pickle_encrypted",
-        argNames: ["pickle", "pickleKey"],
-      );
-
-  @override
-  bool crateBindingsVodozemacSessionHasReceivedMessage({required VodozemacSession that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
-        return wire.wire__crate__bindings__vodozemac_session_has_received_message(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_bool,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacSessionHasReceivedMessageConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacSessionHasReceivedMessageConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_session_has_received_message",
-        argNames: ["that"],
-      );
-
-  @override
-  String crateBindingsVodozemacSessionPickleEncrypted({required VodozemacSession that, required U8Array32 pickleKey}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
-        var arg1 = cst_encode_u_8_array_32(pickleKey);
-        return wire.wire__crate__bindings__vodozemac_session_pickle_encrypted(arg0, arg1);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacSessionPickleEncryptedConstMeta,
-      argValues: [that, pickleKey],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacSessionPickleEncryptedConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_session_pickle_encrypted",
-        argNames: ["that", "pickleKey"],
-      );
-
-  @override
-  VodozemacOlmSessionConfig crateBindingsVodozemacSessionSessionConfig({required VodozemacSession that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
-        return wire.wire__crate__bindings__vodozemac_session_session_config(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_vodozemac_olm_session_config,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacSessionSessionConfigConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacSessionSessionConfigConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_session_session_config",
-        argNames: ["that"],
-      );
-
-  @override
-  String crateBindingsVodozemacSessionSessionId({required VodozemacSession that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
-        return wire.wire__crate__bindings__vodozemac_session_session_id(arg0);
-      },
-      codec: DcoCodec(
-        decodeSuccessData: dco_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateBindingsVodozemacSessionSessionIdConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateBindingsVodozemacSessionSessionIdConstMeta => const TaskConstMeta(
-        debugName: "vodozemac_session_session_id",
-        argNames: ["that"],
-      );
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Curve25519PublicKey =>
-      wire.rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Curve25519PublicKey =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Ed25519PublicKey =>
-      wire.rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Ed25519PublicKey =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Ed25519Signature =>
-      wire.rust_arc_increment_strong_count_RustOpaque_Ed25519Signature;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Ed25519Signature =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_EstablishedSas =>
-      wire.rust_arc_increment_strong_count_RustOpaque_EstablishedSas;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_EstablishedSas =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_EstablishedSas;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MegolmSessionConfig =>
-      wire.rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MegolmSessionConfig =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_OlmMessage =>
-      wire.rust_arc_increment_strong_count_RustOpaque_OlmMessage;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_OlmMessage =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_OlmMessage;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_OlmSessionConfig =>
-      wire.rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_OlmSessionConfig =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PkDecryption =>
-      wire.rust_arc_increment_strong_count_RustOpaque_PkDecryption;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PkDecryption =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_PkDecryption;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PkEncryption =>
-      wire.rust_arc_increment_strong_count_RustOpaque_PkEncryption;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PkEncryption =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_PkEncryption;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockGroupSession =>
-      wire.rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockGroupSession =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockInboundGroupSession =>
-      wire.rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockInboundGroupSession =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockSession =>
-      wire.rust_arc_increment_strong_count_RustOpaque_RwLockSession;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockSession =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_RwLockSession;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PkSigning =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PkSigning =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_VodozemacSas =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_VodozemacSas =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockAccount =>
-      wire.rust_arc_increment_strong_count_RustOpaque_stdsyncRwLockAccount;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockAccount =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  PkSigning dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PkSigningImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  VodozemacSas dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return VodozemacSasImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  PkSigning dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PkSigningImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  VodozemacSas dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return VodozemacSasImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Curve25519PublicKey dco_decode_RustOpaque_Curve25519PublicKey(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Curve25519PublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Ed25519PublicKey dco_decode_RustOpaque_Ed25519PublicKey(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Ed25519PublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Ed25519Signature dco_decode_RustOpaque_Ed25519Signature(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Ed25519SignatureImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  EstablishedSas dco_decode_RustOpaque_EstablishedSas(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return EstablishedSasImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  MegolmSessionConfig dco_decode_RustOpaque_MegolmSessionConfig(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MegolmSessionConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  OlmMessage dco_decode_RustOpaque_OlmMessage(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return OlmMessageImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  OlmSessionConfig dco_decode_RustOpaque_OlmSessionConfig(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return OlmSessionConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  PkDecryption dco_decode_RustOpaque_PkDecryption(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PkDecryptionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  PkEncryption dco_decode_RustOpaque_PkEncryption(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PkEncryptionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  RwLockGroupSession dco_decode_RustOpaque_RwLockGroupSession(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RwLockGroupSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  RwLockInboundGroupSession dco_decode_RustOpaque_RwLockInboundGroupSession(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RwLockInboundGroupSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  RwLockSession dco_decode_RustOpaque_RwLockSession(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RwLockSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  PkSigning dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PkSigningImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  VodozemacSas dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return VodozemacSasImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  RwLockAccount dco_decode_RustOpaque_stdsyncRwLockAccount(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RwLockAccountImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  VodozemacAccount dco_decode_box_autoadd_vodozemac_account(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_account(raw);
-  }
-
-  @protected
-  VodozemacCurve25519PublicKey dco_decode_box_autoadd_vodozemac_curve_25519_public_key(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_curve_25519_public_key(raw);
-  }
-
-  @protected
-  VodozemacEd25519PublicKey dco_decode_box_autoadd_vodozemac_ed_25519_public_key(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_ed_25519_public_key(raw);
-  }
-
-  @protected
-  VodozemacEd25519Signature dco_decode_box_autoadd_vodozemac_ed_25519_signature(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_ed_25519_signature(raw);
-  }
-
-  @protected
-  VodozemacEstablishedSas dco_decode_box_autoadd_vodozemac_established_sas(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_established_sas(raw);
-  }
-
-  @protected
-  VodozemacGroupSession dco_decode_box_autoadd_vodozemac_group_session(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_group_session(raw);
-  }
-
-  @protected
-  VodozemacInboundGroupSession dco_decode_box_autoadd_vodozemac_inbound_group_session(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_inbound_group_session(raw);
-  }
-
-  @protected
-  VodozemacMegolmSessionConfig dco_decode_box_autoadd_vodozemac_megolm_session_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_megolm_session_config(raw);
-  }
-
-  @protected
-  VodozemacOlmMessage dco_decode_box_autoadd_vodozemac_olm_message(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_olm_message(raw);
-  }
-
-  @protected
-  VodozemacOlmSessionConfig dco_decode_box_autoadd_vodozemac_olm_session_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_olm_session_config(raw);
-  }
-
-  @protected
-  VodozemacPkDecryption dco_decode_box_autoadd_vodozemac_pk_decryption(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_pk_decryption(raw);
-  }
-
-  @protected
-  VodozemacPkEncryption dco_decode_box_autoadd_vodozemac_pk_encryption(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_pk_encryption(raw);
-  }
-
-  @protected
-  VodozemacPkMessage dco_decode_box_autoadd_vodozemac_pk_message(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_pk_message(raw);
-  }
-
-  @protected
-  VodozemacSession dco_decode_box_autoadd_vodozemac_session(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_vodozemac_session(raw);
-  }
-
-  @protected
-  DecryptResult dco_decode_decrypt_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return DecryptResult(
-      field0: dco_decode_String(arr[0]),
-      field1: dco_decode_u_32(arr[1]),
-    );
-  }
-
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<VodozemacOneTimeKey> dco_decode_list_vodozemac_one_time_key(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_vodozemac_one_time_key).toList();
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  (String, String, String) dco_decode_record_string_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3) {
-      throw Exception('Expected 3 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_String(arr[0]),
-      dco_decode_String(arr[1]),
-      dco_decode_String(arr[2]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  U8Array32 dco_decode_u_8_array_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return U8Array32(dco_decode_list_prim_u_8_strict(raw));
-  }
-
-  @protected
-  U8Array64 dco_decode_u_8_array_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return U8Array64(dco_decode_list_prim_u_8_strict(raw));
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  VodozemacAccount dco_decode_vodozemac_account(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacAccount.raw(
-      account: dco_decode_RustOpaque_stdsyncRwLockAccount(arr[0]),
-    );
-  }
-
-  @protected
-  VodozemacCurve25519PublicKey dco_decode_vodozemac_curve_25519_public_key(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacCurve25519PublicKey(
-      key: dco_decode_RustOpaque_Curve25519PublicKey(arr[0]),
-    );
-  }
-
-  @protected
-  VodozemacEd25519PublicKey dco_decode_vodozemac_ed_25519_public_key(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacEd25519PublicKey(
-      key: dco_decode_RustOpaque_Ed25519PublicKey(arr[0]),
-    );
-  }
-
-  @protected
-  VodozemacEd25519Signature dco_decode_vodozemac_ed_25519_signature(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacEd25519Signature(
-      signature: dco_decode_RustOpaque_Ed25519Signature(arr[0]),
-    );
-  }
-
-  @protected
-  VodozemacEstablishedSas dco_decode_vodozemac_established_sas(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacEstablishedSas(
-      establishedSas: dco_decode_RustOpaque_EstablishedSas(arr[0]),
-    );
-  }
-
-  @protected
-  VodozemacGroupSession dco_decode_vodozemac_group_session(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacGroupSession.raw(
-      session: dco_decode_RustOpaque_RwLockGroupSession(arr[0]),
-    );
-  }
-
-  @protected
-  VodozemacIdentityKeys dco_decode_vodozemac_identity_keys(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return VodozemacIdentityKeys(
-      ed25519: dco_decode_vodozemac_ed_25519_public_key(arr[0]),
-      curve25519: dco_decode_vodozemac_curve_25519_public_key(arr[1]),
-    );
-  }
-
-  @protected
-  VodozemacInboundGroupSession dco_decode_vodozemac_inbound_group_session(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacInboundGroupSession.raw(
-      session: dco_decode_RustOpaque_RwLockInboundGroupSession(arr[0]),
-    );
-  }
-
-  @protected
-  VodozemacMegolmSessionConfig dco_decode_vodozemac_megolm_session_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacMegolmSessionConfig(
-      config: dco_decode_RustOpaque_MegolmSessionConfig(arr[0]),
-    );
-  }
-
-  @protected
-  VodozemacOlmMessage dco_decode_vodozemac_olm_message(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacOlmMessage(
-      msg: dco_decode_RustOpaque_OlmMessage(arr[0]),
-    );
-  }
-
-  @protected
-  VodozemacOlmSessionConfig dco_decode_vodozemac_olm_session_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacOlmSessionConfig(
-      config: dco_decode_RustOpaque_OlmSessionConfig(arr[0]),
-    );
-  }
-
-  @protected
-  VodozemacOlmSessionCreationResult dco_decode_vodozemac_olm_session_creation_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return VodozemacOlmSessionCreationResult(
-      session: dco_decode_vodozemac_session(arr[0]),
-      plaintext: dco_decode_String(arr[1]),
-    );
-  }
-
-  @protected
-  VodozemacOneTimeKey dco_decode_vodozemac_one_time_key(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return VodozemacOneTimeKey(
-      keyid: dco_decode_String(arr[0]),
-      key: dco_decode_vodozemac_curve_25519_public_key(arr[1]),
-    );
-  }
-
-  @protected
-  VodozemacPkDecryption dco_decode_vodozemac_pk_decryption(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacPkDecryption.raw(
-      pkDecryption: dco_decode_RustOpaque_PkDecryption(arr[0]),
-    );
-  }
-
-  @protected
-  VodozemacPkEncryption dco_decode_vodozemac_pk_encryption(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacPkEncryption(
-      pkEncryption: dco_decode_RustOpaque_PkEncryption(arr[0]),
-    );
-  }
-
-  @protected
-  VodozemacPkMessage dco_decode_vodozemac_pk_message(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return VodozemacPkMessage.raw(
-      ciphertext: dco_decode_list_prim_u_8_strict(arr[0]),
-      mac: dco_decode_list_prim_u_8_strict(arr[1]),
-      ephemeralKey: dco_decode_vodozemac_curve_25519_public_key(arr[2]),
-    );
-  }
-
-  @protected
-  VodozemacSession dco_decode_vodozemac_session(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return VodozemacSession(
-      session: dco_decode_RustOpaque_RwLockSession(arr[0]),
-    );
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  PkSigning sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return PkSigningImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  VodozemacSas sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return VodozemacSasImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  PkSigning sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return PkSigningImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  VodozemacSas sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return VodozemacSasImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Curve25519PublicKey sse_decode_RustOpaque_Curve25519PublicKey(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Curve25519PublicKeyImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Ed25519PublicKey sse_decode_RustOpaque_Ed25519PublicKey(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Ed25519PublicKeyImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Ed25519Signature sse_decode_RustOpaque_Ed25519Signature(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Ed25519SignatureImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  EstablishedSas sse_decode_RustOpaque_EstablishedSas(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return EstablishedSasImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  MegolmSessionConfig sse_decode_RustOpaque_MegolmSessionConfig(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return MegolmSessionConfigImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  OlmMessage sse_decode_RustOpaque_OlmMessage(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return OlmMessageImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  OlmSessionConfig sse_decode_RustOpaque_OlmSessionConfig(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return OlmSessionConfigImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  PkDecryption sse_decode_RustOpaque_PkDecryption(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return PkDecryptionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  PkEncryption sse_decode_RustOpaque_PkEncryption(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return PkEncryptionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  RwLockGroupSession sse_decode_RustOpaque_RwLockGroupSession(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return RwLockGroupSessionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  RwLockInboundGroupSession sse_decode_RustOpaque_RwLockInboundGroupSession(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return RwLockInboundGroupSessionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  RwLockSession sse_decode_RustOpaque_RwLockSession(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return RwLockSessionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  PkSigning sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return PkSigningImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  VodozemacSas sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return VodozemacSasImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  RwLockAccount sse_decode_RustOpaque_stdsyncRwLockAccount(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return RwLockAccountImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  VodozemacAccount sse_decode_box_autoadd_vodozemac_account(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_account(deserializer));
-  }
-
-  @protected
-  VodozemacCurve25519PublicKey sse_decode_box_autoadd_vodozemac_curve_25519_public_key(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_curve_25519_public_key(deserializer));
-  }
-
-  @protected
-  VodozemacEd25519PublicKey sse_decode_box_autoadd_vodozemac_ed_25519_public_key(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_ed_25519_public_key(deserializer));
-  }
-
-  @protected
-  VodozemacEd25519Signature sse_decode_box_autoadd_vodozemac_ed_25519_signature(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_ed_25519_signature(deserializer));
-  }
-
-  @protected
-  VodozemacEstablishedSas sse_decode_box_autoadd_vodozemac_established_sas(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_established_sas(deserializer));
-  }
-
-  @protected
-  VodozemacGroupSession sse_decode_box_autoadd_vodozemac_group_session(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_group_session(deserializer));
-  }
-
-  @protected
-  VodozemacInboundGroupSession sse_decode_box_autoadd_vodozemac_inbound_group_session(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_inbound_group_session(deserializer));
-  }
-
-  @protected
-  VodozemacMegolmSessionConfig sse_decode_box_autoadd_vodozemac_megolm_session_config(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_megolm_session_config(deserializer));
-  }
-
-  @protected
-  VodozemacOlmMessage sse_decode_box_autoadd_vodozemac_olm_message(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_olm_message(deserializer));
-  }
-
-  @protected
-  VodozemacOlmSessionConfig sse_decode_box_autoadd_vodozemac_olm_session_config(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_olm_session_config(deserializer));
-  }
-
-  @protected
-  VodozemacPkDecryption sse_decode_box_autoadd_vodozemac_pk_decryption(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_pk_decryption(deserializer));
-  }
-
-  @protected
-  VodozemacPkEncryption sse_decode_box_autoadd_vodozemac_pk_encryption(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_pk_encryption(deserializer));
-  }
-
-  @protected
-  VodozemacPkMessage sse_decode_box_autoadd_vodozemac_pk_message(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_pk_message(deserializer));
-  }
-
-  @protected
-  VodozemacSession sse_decode_box_autoadd_vodozemac_session(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_vodozemac_session(deserializer));
-  }
-
-  @protected
-  DecryptResult sse_decode_decrypt_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_u_32(deserializer);
-    return DecryptResult(field0: var_field0, field1: var_field1);
-  }
-
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<VodozemacOneTimeKey> sse_decode_list_vodozemac_one_time_key(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <VodozemacOneTimeKey>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_vodozemac_one_time_key(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  (String, String, String) sse_decode_record_string_string_string(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_String(deserializer);
-    var var_field2 = sse_decode_String(deserializer);
-    return (var_field0, var_field1, var_field2);
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  U8Array32 sse_decode_u_8_array_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return U8Array32(inner);
-  }
-
-  @protected
-  U8Array64 sse_decode_u_8_array_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return U8Array64(inner);
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  VodozemacAccount sse_decode_vodozemac_account(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_account = sse_decode_RustOpaque_stdsyncRwLockAccount(deserializer);
-    return VodozemacAccount.raw(account: var_account);
-  }
-
-  @protected
-  VodozemacCurve25519PublicKey sse_decode_vodozemac_curve_25519_public_key(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_key = sse_decode_RustOpaque_Curve25519PublicKey(deserializer);
-    return VodozemacCurve25519PublicKey(key: var_key);
-  }
-
-  @protected
-  VodozemacEd25519PublicKey sse_decode_vodozemac_ed_25519_public_key(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_key = sse_decode_RustOpaque_Ed25519PublicKey(deserializer);
-    return VodozemacEd25519PublicKey(key: var_key);
-  }
-
-  @protected
-  VodozemacEd25519Signature sse_decode_vodozemac_ed_25519_signature(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_signature = sse_decode_RustOpaque_Ed25519Signature(deserializer);
-    return VodozemacEd25519Signature(signature: var_signature);
-  }
-
-  @protected
-  VodozemacEstablishedSas sse_decode_vodozemac_established_sas(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_establishedSas = sse_decode_RustOpaque_EstablishedSas(deserializer);
-    return VodozemacEstablishedSas(establishedSas: var_establishedSas);
-  }
-
-  @protected
-  VodozemacGroupSession sse_decode_vodozemac_group_session(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_session = sse_decode_RustOpaque_RwLockGroupSession(deserializer);
-    return VodozemacGroupSession.raw(session: var_session);
-  }
-
-  @protected
-  VodozemacIdentityKeys sse_decode_vodozemac_identity_keys(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ed25519 = sse_decode_vodozemac_ed_25519_public_key(deserializer);
-    var var_curve25519 = sse_decode_vodozemac_curve_25519_public_key(deserializer);
-    return VodozemacIdentityKeys(ed25519: var_ed25519, curve25519: var_curve25519);
-  }
-
-  @protected
-  VodozemacInboundGroupSession sse_decode_vodozemac_inbound_group_session(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_session = sse_decode_RustOpaque_RwLockInboundGroupSession(deserializer);
-    return VodozemacInboundGroupSession.raw(session: var_session);
-  }
-
-  @protected
-  VodozemacMegolmSessionConfig sse_decode_vodozemac_megolm_session_config(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_config = sse_decode_RustOpaque_MegolmSessionConfig(deserializer);
-    return VodozemacMegolmSessionConfig(config: var_config);
-  }
-
-  @protected
-  VodozemacOlmMessage sse_decode_vodozemac_olm_message(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_msg = sse_decode_RustOpaque_OlmMessage(deserializer);
-    return VodozemacOlmMessage(msg: var_msg);
-  }
-
-  @protected
-  VodozemacOlmSessionConfig sse_decode_vodozemac_olm_session_config(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_config = sse_decode_RustOpaque_OlmSessionConfig(deserializer);
-    return VodozemacOlmSessionConfig(config: var_config);
-  }
-
-  @protected
-  VodozemacOlmSessionCreationResult sse_decode_vodozemac_olm_session_creation_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_session = sse_decode_vodozemac_session(deserializer);
-    var var_plaintext = sse_decode_String(deserializer);
-    return VodozemacOlmSessionCreationResult(session: var_session, plaintext: var_plaintext);
-  }
-
-  @protected
-  VodozemacOneTimeKey sse_decode_vodozemac_one_time_key(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_keyid = sse_decode_String(deserializer);
-    var var_key = sse_decode_vodozemac_curve_25519_public_key(deserializer);
-    return VodozemacOneTimeKey(keyid: var_keyid, key: var_key);
-  }
-
-  @protected
-  VodozemacPkDecryption sse_decode_vodozemac_pk_decryption(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_pkDecryption = sse_decode_RustOpaque_PkDecryption(deserializer);
-    return VodozemacPkDecryption.raw(pkDecryption: var_pkDecryption);
-  }
-
-  @protected
-  VodozemacPkEncryption sse_decode_vodozemac_pk_encryption(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_pkEncryption = sse_decode_RustOpaque_PkEncryption(deserializer);
-    return VodozemacPkEncryption(pkEncryption: var_pkEncryption);
-  }
-
-  @protected
-  VodozemacPkMessage sse_decode_vodozemac_pk_message(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ciphertext = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_mac = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_ephemeralKey = sse_decode_vodozemac_curve_25519_public_key(deserializer);
-    return VodozemacPkMessage.raw(ciphertext: var_ciphertext, mac: var_mac, ephemeralKey: var_ephemeralKey);
-  }
-
-  @protected
-  VodozemacSession sse_decode_vodozemac_session(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_session = sse_decode_RustOpaque_RwLockSession(deserializer);
-    return VodozemacSession(session: var_session);
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+String crateBindingsVodozemacAccountPickleWithPassphrase({required VodozemacAccount that , required String passphrase , required PassphraseKdfParams params });
+
+KeyMaintenancePlan crateBindingsVodozemacAccountPlanKeyMaintenance({required VodozemacAccount that , required FallbackKeyTracker tracker , required Map<String, int> serverCounts , List<String>? unusedFallbackTypes , required BigInt nowMs });
+
+Uint8List crateBindingsVodozemacAccountRemoveOneTimeKey({required VodozemacAccount that , required String publicKey });
+
+VodozemacEd25519Signature crateBindingsVodozemacAccountSign({required VodozemacAccount that , required String message });
+
+UnwedgeResult crateBindingsVodozemacAccountUnwedgeSession({required VodozemacAccount that , required UnwedgeTracker tracker , required VodozemacOlmSessionConfig config , required String ownUserId , required String ownDeviceId , required DeviceKeys deviceKeys , required String claimedKeyJson });
+
+U8Array32 crateBindingsVodozemacCurve25519PublicKeyAsBytes({required VodozemacCurve25519PublicKey that });
+
+VodozemacCurve25519PublicKey crateBindingsVodozemacCurve25519PublicKeyFromBase64({required String base64Key });
+
+VodozemacCurve25519PublicKey crateBindingsVodozemacCurve25519PublicKeyFromSlice({required U8Array32 bytes });
+
+String crateBindingsVodozemacCurve25519PublicKeyToBase64({required VodozemacCurve25519PublicKey that });
+
+U8Array32 crateBindingsVodozemacEd25519PublicKeyAsBytes({required VodozemacEd25519PublicKey that });
+
+VodozemacEd25519PublicKey crateBindingsVodozemacEd25519PublicKeyFromBase64({required String base64Key });
+
+VodozemacEd25519PublicKey crateBindingsVodozemacEd25519PublicKeyFromSlice({required U8Array32 bytes });
+
+String crateBindingsVodozemacEd25519PublicKeyToBase64({required VodozemacEd25519PublicKey that });
+
+void crateBindingsVodozemacEd25519PublicKeyVerify({required VodozemacEd25519PublicKey that , required String message , required VodozemacEd25519Signature signature });
+
+VodozemacEd25519Signature crateBindingsVodozemacEd25519SignatureFromBase64({required String signature });
+
+VodozemacEd25519Signature crateBindingsVodozemacEd25519SignatureFromSlice({required U8Array64 bytes });
+
+String crateBindingsVodozemacEd25519SignatureToBase64({required VodozemacEd25519Signature that });
+
+U8Array64 crateBindingsVodozemacEd25519SignatureToBytes({required VodozemacEd25519Signature that });
+
+String crateBindingsVodozemacEstablishedSasCalculateMac({required VodozemacEstablishedSas that , required String input , required String info });
+
+String crateBindingsVodozemacEstablishedSasCalculateMacDeprecated({required VodozemacEstablishedSas that , required String input , required String info });
+
+Uint8List crateBindingsVodozemacEstablishedSasGenerateBytes({required VodozemacEstablishedSas that , required String info , required int length });
+
+void crateBindingsVodozemacEstablishedSasVerifyMac({required VodozemacEstablishedSas that , required String input , required String info , required String mac });
+
+String crateBindingsVodozemacGroupSessionEncrypt({required VodozemacGroupSession that , required String plaintext });
+
+UnpickledGroupSession crateBindingsVodozemacGroupSessionFromAnyPickle({required String pickle , required List<int> pickleKey });
+
+VodozemacGroupSession crateBindingsVodozemacGroupSessionFromOlmPickleEncrypted({required String pickle , required List<int> pickleKey });
+
+VodozemacGroupSession crateBindingsVodozemacGroupSessionFromPickleEncrypted({required String pickle , required U8Array32 pickleKey });
+
+VodozemacGroupSession crateBindingsVodozemacGroupSessionFromPickleWithPassphrase({required String pickle , required String passphrase });
+
+int crateBindingsVodozemacGroupSessionMessageIndex({required VodozemacGroupSession that });
+
+VodozemacGroupSession crateBindingsVodozemacGroupSessionNew({required VodozemacMegolmSessionConfig config });
+
+String crateBindingsVodozemacGroupSessionPickleEncrypted({required VodozemacGroupSession that , required U8Array32 pickleKey });
+
+String crateBindingsVodozemacGroupSessionPickleWithPassphrase({required VodozemacGroupSession that , required String passphrase , required PassphraseKdfParams params });
+
+VodozemacMegolmSessionConfig crateBindingsVodozemacGroupSessionSessionConfig({required VodozemacGroupSession that });
+
+String crateBindingsVodozemacGroupSessionSessionId({required VodozemacGroupSession that });
+
+String crateBindingsVodozemacGroupSessionSessionKey({required VodozemacGroupSession that });
+
+VodozemacInboundGroupSession crateBindingsVodozemacGroupSessionToInbound({required VodozemacGroupSession that });
+
+DecryptResult crateBindingsVodozemacInboundGroupSessionDecrypt({required VodozemacInboundGroupSession that , required String encrypted });
+
+ExtendedDecryptResult crateBindingsVodozemacInboundGroupSessionDecryptExtended({required VodozemacInboundGroupSession that , required String encrypted });
+
+bool crateBindingsVodozemacInboundGroupSessionDiscardBefore({required VodozemacInboundGroupSession that , required int index });
+
+String? crateBindingsVodozemacInboundGroupSessionExportAt({required VodozemacInboundGroupSession that , required int index });
+
+String crateBindingsVodozemacInboundGroupSessionExportAtFirstKnownIndex({required VodozemacInboundGroupSession that });
+
+int crateBindingsVodozemacInboundGroupSessionFirstKnownIndex({required VodozemacInboundGroupSession that });
+
+UnpickledInboundGroupSession crateBindingsVodozemacInboundGroupSessionFromAnyPickle({required String pickle , required List<int> pickleKey });
+
+VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionFromOlmPickleEncrypted({required String pickle , required List<int> pickleKey });
+
+VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionFromPickleEncrypted({required String pickle , required U8Array32 pickleKey });
+
+VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionFromPickleWithPassphrase({required String pickle , required String passphrase });
+
+VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionImport({required String exportedSessionKey , required VodozemacMegolmSessionConfig config });
+
+SessionKeySource crateBindingsVodozemacInboundGroupSessionKeySource({required VodozemacInboundGroupSession that });
+
+VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionNew({required String sessionKey , required VodozemacMegolmSessionConfig config });
+
+String crateBindingsVodozemacInboundGroupSessionPickleEncrypted({required VodozemacInboundGroupSession that , required U8Array32 pickleKey });
+
+String crateBindingsVodozemacInboundGroupSessionPickleWithPassphrase({required VodozemacInboundGroupSession that , required String passphrase , required PassphraseKdfParams params });
+
+String crateBindingsVodozemacInboundGroupSessionSessionId({required VodozemacInboundGroupSession that });
+
+VodozemacEd25519PublicKey crateBindingsVodozemacInboundGroupSessionSigningKey({required VodozemacInboundGroupSession that });
+
+VodozemacMegolmSessionConfig crateBindingsVodozemacMegolmSessionConfigDef();
+
+int crateBindingsVodozemacMegolmSessionConfigVersion({required VodozemacMegolmSessionConfig that });
+
+VodozemacMegolmSessionConfig crateBindingsVodozemacMegolmSessionConfigVersion1();
+
+VodozemacMegolmSessionConfig crateBindingsVodozemacMegolmSessionConfigVersion2();
+
+VodozemacOlmMessage crateBindingsVodozemacOlmMessageFromParts({required BigInt messageType , required String ciphertext });
+
+String crateBindingsVodozemacOlmMessageMessage({required VodozemacOlmMessage that });
+
+BigInt crateBindingsVodozemacOlmMessageMessageType({required VodozemacOlmMessage that });
+
+VodozemacOlmSessionConfig crateBindingsVodozemacOlmSessionConfigDef();
+
+int crateBindingsVodozemacOlmSessionConfigVersion({required VodozemacOlmSessionConfig that });
+
+VodozemacOlmSessionConfig crateBindingsVodozemacOlmSessionConfigVersion1();
+
+VodozemacOlmSessionConfig crateBindingsVodozemacOlmSessionConfigVersion2();
+
+String crateBindingsVodozemacPkDecryptionDecrypt({required VodozemacPkDecryption that , required VodozemacPkMessage message });
+
+UnpickledPkDecryption crateBindingsVodozemacPkDecryptionFromAnyPickle({required String pickle , required List<int> pickleKey });
+
+VodozemacPkDecryption crateBindingsVodozemacPkDecryptionFromKey({required U8Array32 secretKey });
+
+VodozemacPkDecryption crateBindingsVodozemacPkDecryptionFromLibolmPickle({required String pickle , required List<int> pickleKey });
+
+VodozemacPkDecryption crateBindingsVodozemacPkDecryptionFromPickleWithPassphrase({required String pickle , required String passphrase });
+
+VodozemacPkDecryption crateBindingsVodozemacPkDecryptionNew();
+
+String crateBindingsVodozemacPkDecryptionPickleWithPassphrase({required VodozemacPkDecryption that , required String passphrase , required PassphraseKdfParams params });
+
+Uint8List crateBindingsVodozemacPkDecryptionPrivateKey({required VodozemacPkDecryption that });
+
+String crateBindingsVodozemacPkDecryptionPublicKey({required VodozemacPkDecryption that });
+
+String crateBindingsVodozemacPkDecryptionToLibolmPickle({required VodozemacPkDecryption that , required U8Array32 pickleKey });
+
+VodozemacPkMessage crateBindingsVodozemacPkEncryptionEncrypt({required VodozemacPkEncryption that , required String message });
+
+VodozemacPkEncryption crateBindingsVodozemacPkEncryptionFromKey({required VodozemacCurve25519PublicKey publicKey });
+
+VodozemacPkMessage crateBindingsVodozemacPkMessageFromBase64({required String ciphertext , required String mac , required String ephemeralKey });
+
+VodozemacPkMessage crateBindingsVodozemacPkMessageNew({required List<int> ciphertext , required List<int> mac , required VodozemacCurve25519PublicKey ephemeralKey });
+
+(String,String,String) crateBindingsVodozemacPkMessageToBase64({required VodozemacPkMessage that });
+
+String crateBindingsVodozemacSessionDecrypt({required VodozemacSession that , required VodozemacOlmMessage message });
+
+VodozemacOlmMessage crateBindingsVodozemacSessionEncrypt({required VodozemacSession that , required String plaintext });
+
+UnpickledSession crateBindingsVodozemacSessionFromAnyPickle({required String pickle , required List<int> pickleKey });
+
+VodozemacSession crateBindingsVodozemacSessionFromOlmPickleEncrypted({required String pickle , required List<int> pickleKey });
+
+VodozemacSession crateBindingsVodozemacSessionFromPickleEncrypted({required String pickle , required U8Array32 pickleKey });
+
+VodozemacSession crateBindingsVodozemacSessionFromPickleWithPassphrase({required String pickle , required String passphrase });
+
+bool crateBindingsVodozemacSessionHasReceivedMessage({required VodozemacSession that });
+
+String crateBindingsVodozemacSessionPickleEncrypted({required VodozemacSession that , required U8Array32 pickleKey });
+
+String crateBindingsVodozemacSessionPickleWithPassphrase({required VodozemacSession that , required String passphrase , required PassphraseKdfParams params });
+
+VodozemacOlmSessionConfig crateBindingsVodozemacSessionSessionConfig({required VodozemacSession that });
+
+String crateBindingsVodozemacSessionSessionId({required VodozemacSession that });
+
+String crateBindingsShareTrackingWithheldCodeCode({required WithheldCode that });
+
+WithheldCode? crateBindingsShareTrackingWithheldCodeFromCode({required String code });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Curve25519PublicKey;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Curve25519PublicKey;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Curve25519PublicKeyPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Ed25519PublicKey;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Ed25519PublicKey;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Ed25519PublicKeyPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Ed25519Signature;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Ed25519Signature;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Ed25519SignaturePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_EstablishedSas;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_EstablishedSas;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EstablishedSasPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MegolmSessionConfig;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MegolmSessionConfig;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MegolmSessionConfigPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_OlmMessage;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_OlmMessage;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OlmMessagePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_OlmSessionConfig;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_OlmSessionConfig;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OlmSessionConfigPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PkDecryption;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PkDecryption;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkDecryptionPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PkEncryption;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PkEncryption;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkEncryptionPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockFallbackKeyState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockFallbackKeyState;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockFallbackKeyStatePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockGroupSession;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockGroupSession;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockGroupSessionPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockInboundGroupSession;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockInboundGroupSession;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockInboundGroupSessionPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockReplayState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockReplayState;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockReplayStatePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockRoomKeyInfo;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockRoomKeyInfo;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockRoomKeyInfoPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockRotationState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockRotationState;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockRotationStatePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockSession;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockSession;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockSessionPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockSessionKeySource;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockSessionKeySource;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockSessionKeySourcePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockSessionStoreState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockSessionStoreState;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockSessionStoreStatePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockShareState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockShareState;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockShareStatePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockUnwedgeState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockUnwedgeState;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockUnwedgeStatePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockUtdQueueState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockUtdQueueState;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockUtdQueueStatePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CrossSigningIdentity;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CrossSigningIdentity;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CrossSigningIdentityPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PkSigning;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PkSigning;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkSigningPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_VodozemacSas;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_VodozemacSas;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_VodozemacSasPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockAccount;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockAccount;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockAccountPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override String crateBindingsCrossSigningCrossSigningIdentityDeviceSigningUpload({required CrossSigningIdentity that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(that);
+            return wire.wire__crate__bindings__cross_signing__CrossSigningIdentity_device_signing_upload(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsCrossSigningCrossSigningIdentityDeviceSigningUploadConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsCrossSigningCrossSigningIdentityDeviceSigningUploadConstMeta => const TaskConstMeta(
+            debugName: "CrossSigningIdentity_device_signing_upload",
+            argNames: ["that"],
+        );
+        
+
+@override CrossSigningIdentity crateBindingsCrossSigningCrossSigningIdentityFromSecretKeys({required String userId , required String masterKey , required String selfSigningKey , required String userSigningKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(userId);
+var arg1 = cst_encode_String(masterKey);
+var arg2 = cst_encode_String(selfSigningKey);
+var arg3 = cst_encode_String(userSigningKey);
+            return wire.wire__crate__bindings__cross_signing__CrossSigningIdentity_from_secret_keys(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsCrossSigningCrossSigningIdentityFromSecretKeysConstMeta,
+            argValues: [userId, masterKey, selfSigningKey, userSigningKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsCrossSigningCrossSigningIdentityFromSecretKeysConstMeta => const TaskConstMeta(
+            debugName: "CrossSigningIdentity_from_secret_keys",
+            argNames: ["userId", "masterKey", "selfSigningKey", "userSigningKey"],
+        );
+        
+
+@override VodozemacEd25519PublicKey crateBindingsCrossSigningCrossSigningIdentityMasterKey({required CrossSigningIdentity that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(that);
+            return wire.wire__crate__bindings__cross_signing__CrossSigningIdentity_master_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_ed_25519_public_key,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsCrossSigningCrossSigningIdentityMasterKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsCrossSigningCrossSigningIdentityMasterKeyConstMeta => const TaskConstMeta(
+            debugName: "CrossSigningIdentity_master_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsCrossSigningCrossSigningIdentityMasterSecretKey({required CrossSigningIdentity that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(that);
+            return wire.wire__crate__bindings__cross_signing__CrossSigningIdentity_master_secret_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsCrossSigningCrossSigningIdentityMasterSecretKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsCrossSigningCrossSigningIdentityMasterSecretKeyConstMeta => const TaskConstMeta(
+            debugName: "CrossSigningIdentity_master_secret_key",
+            argNames: ["that"],
+        );
+        
+
+@override CrossSigningIdentity crateBindingsCrossSigningCrossSigningIdentityNew({required String userId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(userId);
+            return wire.wire__crate__bindings__cross_signing__CrossSigningIdentity_new(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsCrossSigningCrossSigningIdentityNewConstMeta,
+            argValues: [userId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsCrossSigningCrossSigningIdentityNewConstMeta => const TaskConstMeta(
+            debugName: "CrossSigningIdentity_new",
+            argNames: ["userId"],
+        );
+        
+
+@override VodozemacEd25519PublicKey crateBindingsCrossSigningCrossSigningIdentitySelfSigningKey({required CrossSigningIdentity that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(that);
+            return wire.wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_ed_25519_public_key,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsCrossSigningCrossSigningIdentitySelfSigningKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsCrossSigningCrossSigningIdentitySelfSigningKeyConstMeta => const TaskConstMeta(
+            debugName: "CrossSigningIdentity_self_signing_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsCrossSigningCrossSigningIdentitySelfSigningSecretKey({required CrossSigningIdentity that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(that);
+            return wire.wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_secret_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsCrossSigningCrossSigningIdentitySelfSigningSecretKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsCrossSigningCrossSigningIdentitySelfSigningSecretKeyConstMeta => const TaskConstMeta(
+            debugName: "CrossSigningIdentity_self_signing_secret_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsCrossSigningCrossSigningIdentitySignDevice({required CrossSigningIdentity that , required String deviceKeys })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(that);
+var arg1 = cst_encode_String(deviceKeys);
+            return wire.wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_device(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsCrossSigningCrossSigningIdentitySignDeviceConstMeta,
+            argValues: [that, deviceKeys],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsCrossSigningCrossSigningIdentitySignDeviceConstMeta => const TaskConstMeta(
+            debugName: "CrossSigningIdentity_sign_device",
+            argNames: ["that", "deviceKeys"],
+        );
+        
+
+@override String crateBindingsCrossSigningCrossSigningIdentitySignUser({required CrossSigningIdentity that , required String masterKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(that);
+var arg1 = cst_encode_String(masterKey);
+            return wire.wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_user(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsCrossSigningCrossSigningIdentitySignUserConstMeta,
+            argValues: [that, masterKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsCrossSigningCrossSigningIdentitySignUserConstMeta => const TaskConstMeta(
+            debugName: "CrossSigningIdentity_sign_user",
+            argNames: ["that", "masterKey"],
+        );
+        
+
+@override String crateBindingsCrossSigningCrossSigningIdentityUserId({required CrossSigningIdentity that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(that);
+            return wire.wire__crate__bindings__cross_signing__CrossSigningIdentity_user_id(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsCrossSigningCrossSigningIdentityUserIdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsCrossSigningCrossSigningIdentityUserIdConstMeta => const TaskConstMeta(
+            debugName: "CrossSigningIdentity_user_id",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacEd25519PublicKey crateBindingsCrossSigningCrossSigningIdentityUserSigningKey({required CrossSigningIdentity that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(that);
+            return wire.wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_ed_25519_public_key,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsCrossSigningCrossSigningIdentityUserSigningKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsCrossSigningCrossSigningIdentityUserSigningKeyConstMeta => const TaskConstMeta(
+            debugName: "CrossSigningIdentity_user_signing_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsCrossSigningCrossSigningIdentityUserSigningSecretKey({required CrossSigningIdentity that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(that);
+            return wire.wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_secret_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsCrossSigningCrossSigningIdentityUserSigningSecretKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsCrossSigningCrossSigningIdentityUserSigningSecretKeyConstMeta => const TaskConstMeta(
+            debugName: "CrossSigningIdentity_user_signing_secret_key",
+            argNames: ["that"],
+        );
+        
+
+@override PkSigning crateBindingsPkSigningFromSecretKey({required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(key);
+            return wire.wire__crate__bindings__PkSigning_from_secret_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsPkSigningFromSecretKeyConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsPkSigningFromSecretKeyConstMeta => const TaskConstMeta(
+            debugName: "PkSigning_from_secret_key",
+            argNames: ["key"],
+        );
+        
+
+@override PkSigning crateBindingsPkSigningNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__PkSigning_new();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsPkSigningNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsPkSigningNewConstMeta => const TaskConstMeta(
+            debugName: "PkSigning_new",
+            argNames: [],
+        );
+        
+
+@override VodozemacEd25519PublicKey crateBindingsPkSigningPublicKey({required PkSigning that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(that);
+            return wire.wire__crate__bindings__PkSigning_public_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_ed_25519_public_key,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsPkSigningPublicKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsPkSigningPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "PkSigning_public_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsPkSigningSecretKey({required PkSigning that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(that);
+            return wire.wire__crate__bindings__PkSigning_secret_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsPkSigningSecretKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsPkSigningSecretKeyConstMeta => const TaskConstMeta(
+            debugName: "PkSigning_secret_key",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacEd25519Signature crateBindingsPkSigningSign({required PkSigning that , required String message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(that);
+var arg1 = cst_encode_String(message);
+            return wire.wire__crate__bindings__PkSigning_sign(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_ed_25519_signature,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsPkSigningSignConstMeta,
+            argValues: [that, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsPkSigningSignConstMeta => const TaskConstMeta(
+            debugName: "PkSigning_sign",
+            argNames: ["that", "message"],
+        );
+        
+
+@override VodozemacEstablishedSas crateBindingsVodozemacSasEstablishSasSecret({required VodozemacSas that , required String otherPublicKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(that);
+var arg1 = cst_encode_String(otherPublicKey);
+            return wire.wire__crate__bindings__VodozemacSas_establish_sas_secret(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_established_sas,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSasEstablishSasSecretConstMeta,
+            argValues: [that, otherPublicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSasEstablishSasSecretConstMeta => const TaskConstMeta(
+            debugName: "VodozemacSas_establish_sas_secret",
+            argNames: ["that", "otherPublicKey"],
+        );
+        
+
+@override VodozemacSas crateBindingsVodozemacSasNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__VodozemacSas_new();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSasNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSasNewConstMeta => const TaskConstMeta(
+            debugName: "VodozemacSas_new",
+            argNames: [],
+        );
+        
+
+@override String crateBindingsVodozemacSasPublicKey({required VodozemacSas that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(that);
+            return wire.wire__crate__bindings__VodozemacSas_public_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSasPublicKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSasPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "VodozemacSas_public_key",
+            argNames: ["that"],
+        );
+        
+
+@override Uint8List crateBindingsAesCtr({required List<int> input , required List<int> key , required List<int> iv })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_list_prim_u_8_loose(input);
+var arg1 = cst_encode_list_prim_u_8_loose(key);
+var arg2 = cst_encode_list_prim_u_8_loose(iv);
+            return wire.wire__crate__bindings__aes_ctr(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsAesCtrConstMeta,
+            argValues: [input, key, iv],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsAesCtrConstMeta => const TaskConstMeta(
+            debugName: "aes_ctr",
+            argNames: ["input", "key", "iv"],
+        );
+        
+
+@override List<RetentionResult> crateBindingsRetentionApplyRoomRetention({required List<String> pickles , required U8Array32 pickleKey , required List<RoomRetention> policies , required MegolmReplayGuard guard , required BigInt nowMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_list_String(pickles);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+var arg2 = cst_encode_list_room_retention(policies);
+var arg3 = cst_encode_box_autoadd_megolm_replay_guard(guard);
+var arg4 = cst_encode_u_64(nowMs);
+            return wire.wire__crate__bindings__retention__apply_room_retention(arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_retention_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsRetentionApplyRoomRetentionConstMeta,
+            argValues: [pickles, pickleKey, policies, guard, nowMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRetentionApplyRoomRetentionConstMeta => const TaskConstMeta(
+            debugName: "apply_room_retention",
+            argNames: ["pickles", "pickleKey", "policies", "guard", "nowMs"],
+        );
+        
+
+@override UtdClassification? crateBindingsUtdCauseClassifyUtd({required String roomId , required String encryptedContent , required List<InboundRoomKey> candidates , WithheldCode? withheldCode , required BigInt originServerTs , required BigInt deviceCreatedAtMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(roomId);
+var arg1 = cst_encode_String(encryptedContent);
+var arg2 = cst_encode_list_inbound_room_key(candidates);
+var arg3 = cst_encode_opt_box_autoadd_withheld_code(withheldCode);
+var arg4 = cst_encode_u_64(originServerTs);
+var arg5 = cst_encode_u_64(deviceCreatedAtMs);
+            return wire.wire__crate__bindings__utd_cause__classify_utd(arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_utd_classification,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUtdCauseClassifyUtdConstMeta,
+            argValues: [roomId, encryptedContent, candidates, withheldCode, originServerTs, deviceCreatedAtMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdCauseClassifyUtdConstMeta => const TaskConstMeta(
+            debugName: "classify_utd",
+            argNames: ["roomId", "encryptedContent", "candidates", "withheldCode", "originServerTs", "deviceCreatedAtMs"],
+        );
+        
+
+@override EncryptedRoomKeyBundle crateBindingsRoomKeyBundleCreateRoomKeyBundle({required String roomId , required List<InboundRoomKey> roomKeys })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(roomId);
+var arg1 = cst_encode_list_inbound_room_key(roomKeys);
+            return wire.wire__crate__bindings__room_key_bundle__create_room_key_bundle(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_encrypted_room_key_bundle,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyBundleCreateRoomKeyBundleConstMeta,
+            argValues: [roomId, roomKeys],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyBundleCreateRoomKeyBundleConstMeta => const TaskConstMeta(
+            debugName: "create_room_key_bundle",
+            argNames: ["roomId", "roomKeys"],
+        );
+        
+
+@override RoomEventDecryptionResult crateBindingsRoomEventDecryptRoomEvent({required VodozemacInboundGroupSession session , required String roomId , required String encryptedContent })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(session);
+var arg1 = cst_encode_String(roomId);
+var arg2 = cst_encode_String(encryptedContent);
+            return wire.wire__crate__bindings__room_event__decrypt_room_event(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_room_event_decryption_result,
+          decodeErrorData: dco_decode_room_event_error,
+        )
+        ,
+            constMeta: kCrateBindingsRoomEventDecryptRoomEventConstMeta,
+            argValues: [session, roomId, encryptedContent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomEventDecryptRoomEventConstMeta => const TaskConstMeta(
+            debugName: "decrypt_room_event",
+            argNames: ["session", "roomId", "encryptedContent"],
+        );
+        
+
+@override RoomEventDecryptionResult crateBindingsReplayDecryptRoomEventChecked({required VodozemacInboundGroupSession session , required MegolmReplayGuard guard , required String roomId , required String eventId , required BigInt originServerTs , required String encryptedContent })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(session);
+var arg1 = cst_encode_box_autoadd_megolm_replay_guard(guard);
+var arg2 = cst_encode_String(roomId);
+var arg3 = cst_encode_String(eventId);
+var arg4 = cst_encode_u_64(originServerTs);
+var arg5 = cst_encode_String(encryptedContent);
+            return wire.wire__crate__bindings__replay__decrypt_room_event_checked(arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_room_event_decryption_result,
+          decodeErrorData: dco_decode_room_event_error,
+        )
+        ,
+            constMeta: kCrateBindingsReplayDecryptRoomEventCheckedConstMeta,
+            argValues: [session, guard, roomId, eventId, originServerTs, encryptedContent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsReplayDecryptRoomEventCheckedConstMeta => const TaskConstMeta(
+            debugName: "decrypt_room_event_checked",
+            argNames: ["session", "guard", "roomId", "eventId", "originServerTs", "encryptedContent"],
+        );
+        
+
+@override ToDeviceDecryptionResult crateBindingsToDeviceDecryptToDevice({required VodozemacAccount account , required List<VodozemacSession> sessionsForSender , required String eventJson })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(account);
+var arg1 = cst_encode_list_vodozemac_session(sessionsForSender);
+var arg2 = cst_encode_String(eventJson);
+            return wire.wire__crate__bindings__to_device__decrypt_to_device(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_to_device_decryption_result,
+          decodeErrorData: dco_decode_to_device_decryption_error,
+        )
+        ,
+            constMeta: kCrateBindingsToDeviceDecryptToDeviceConstMeta,
+            argValues: [account, sessionsForSender, eventJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsToDeviceDecryptToDeviceConstMeta => const TaskConstMeta(
+            debugName: "decrypt_to_device",
+            argNames: ["account", "sessionsForSender", "eventJson"],
+        );
+        
+
+@override PickleInfo crateBindingsPickleFormatDetectPickle({required String pickle , required List<int> pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
+            return wire.wire__crate__bindings__pickle_format__detect_pickle(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_pickle_info,
+          decodeErrorData: dco_decode_pickle_format_error,
+        )
+        ,
+            constMeta: kCrateBindingsPickleFormatDetectPickleConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsPickleFormatDetectPickleConstMeta => const TaskConstMeta(
+            debugName: "detect_pickle",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override DeviceKeys crateBindingsDeviceKeysDeviceKeysParseAndVerify({required String json , required String expectedUser , required String expectedDevice })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(json);
+var arg1 = cst_encode_String(expectedUser);
+var arg2 = cst_encode_String(expectedDevice);
+            return wire.wire__crate__bindings__device_keys__device_keys_parse_and_verify(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_device_keys,
+          decodeErrorData: dco_decode_device_keys_error,
+        )
+        ,
+            constMeta: kCrateBindingsDeviceKeysDeviceKeysParseAndVerifyConstMeta,
+            argValues: [json, expectedUser, expectedDevice],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsDeviceKeysDeviceKeysParseAndVerifyConstMeta => const TaskConstMeta(
+            debugName: "device_keys_parse_and_verify",
+            argNames: ["json", "expectedUser", "expectedDevice"],
+        );
+        
+
+@override String crateBindingsRoomEventEncryptRoomEvent({required VodozemacGroupSession session , required VodozemacCurve25519PublicKey senderKey , required String deviceId , required String roomId , required String eventType , required String content })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_group_session(session);
+var arg1 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(senderKey);
+var arg2 = cst_encode_String(deviceId);
+var arg3 = cst_encode_String(roomId);
+var arg4 = cst_encode_String(eventType);
+var arg5 = cst_encode_String(content);
+            return wire.wire__crate__bindings__room_event__encrypt_room_event(arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsRoomEventEncryptRoomEventConstMeta,
+            argValues: [session, senderKey, deviceId, roomId, eventType, content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomEventEncryptRoomEventConstMeta => const TaskConstMeta(
+            debugName: "encrypt_room_event",
+            argNames: ["session", "senderKey", "deviceId", "roomId", "eventType", "content"],
+        );
+        
+
+@override String crateBindingsToDeviceEncryptToDevice({required VodozemacAccount account , required VodozemacSession session , required String sender , required String senderDevice , required DeviceKeys recipient , required String eventType , required String content })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(account);
+var arg1 = cst_encode_box_autoadd_vodozemac_session(session);
+var arg2 = cst_encode_String(sender);
+var arg3 = cst_encode_String(senderDevice);
+var arg4 = cst_encode_box_autoadd_device_keys(recipient);
+var arg5 = cst_encode_String(eventType);
+var arg6 = cst_encode_String(content);
+            return wire.wire__crate__bindings__to_device__encrypt_to_device(arg0, arg1, arg2, arg3, arg4, arg5, arg6);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsToDeviceEncryptToDeviceConstMeta,
+            argValues: [account, session, sender, senderDevice, recipient, eventType, content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsToDeviceEncryptToDeviceConstMeta => const TaskConstMeta(
+            debugName: "encrypt_to_device",
+            argNames: ["account", "session", "sender", "senderDevice", "recipient", "eventType", "content"],
+        );
+        
+
+@override String crateBindingsRoomKeyBundleEncryptedRoomKeyBundleEncryptForDevice({required EncryptedRoomKeyBundle that , required String url , required VodozemacAccount account , required VodozemacSession session , required String sender , required String senderDevice , required DeviceKeys recipient })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_encrypted_room_key_bundle(that);
+var arg1 = cst_encode_String(url);
+var arg2 = cst_encode_box_autoadd_vodozemac_account(account);
+var arg3 = cst_encode_box_autoadd_vodozemac_session(session);
+var arg4 = cst_encode_String(sender);
+var arg5 = cst_encode_String(senderDevice);
+var arg6 = cst_encode_box_autoadd_device_keys(recipient);
+            return wire.wire__crate__bindings__room_key_bundle__encrypted_room_key_bundle_encrypt_for_device(arg0, arg1, arg2, arg3, arg4, arg5, arg6);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyBundleEncryptedRoomKeyBundleEncryptForDeviceConstMeta,
+            argValues: [that, url, account, session, sender, senderDevice, recipient],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyBundleEncryptedRoomKeyBundleEncryptForDeviceConstMeta => const TaskConstMeta(
+            debugName: "encrypted_room_key_bundle_encrypt_for_device",
+            argNames: ["that", "url", "account", "session", "sender", "senderDevice", "recipient"],
+        );
+        
+
+@override List<DeviceTrust> crateBindingsTrustEvaluateDeviceTrust({required String ownUserId , required VodozemacEd25519PublicKey ownMasterKey , VodozemacEd25519PublicKey? ownUserSigningKey , required String keysQueryResponse })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(ownUserId);
+var arg1 = cst_encode_box_autoadd_vodozemac_ed_25519_public_key(ownMasterKey);
+var arg2 = cst_encode_opt_box_autoadd_vodozemac_ed_25519_public_key(ownUserSigningKey);
+var arg3 = cst_encode_String(keysQueryResponse);
+            return wire.wire__crate__bindings__trust__evaluate_device_trust(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_device_trust,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsTrustEvaluateDeviceTrustConstMeta,
+            argValues: [ownUserId, ownMasterKey, ownUserSigningKey, keysQueryResponse],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsTrustEvaluateDeviceTrustConstMeta => const TaskConstMeta(
+            debugName: "evaluate_device_trust",
+            argNames: ["ownUserId", "ownMasterKey", "ownUserSigningKey", "keysQueryResponse"],
+        );
+        
+
+@override FallbackKeyTracker crateBindingsKeyMaintenanceFallbackKeyTrackerDefault()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__key_maintenance__fallback_key_tracker_default();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_fallback_key_tracker,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsKeyMaintenanceFallbackKeyTrackerDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsKeyMaintenanceFallbackKeyTrackerDefaultConstMeta => const TaskConstMeta(
+            debugName: "fallback_key_tracker_default",
+            argNames: [],
+        );
+        
+
+@override FallbackKeyTracker crateBindingsKeyMaintenanceFallbackKeyTrackerFromPickleEncrypted({required String pickle , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__key_maintenance__fallback_key_tracker_from_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_fallback_key_tracker,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsKeyMaintenanceFallbackKeyTrackerFromPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsKeyMaintenanceFallbackKeyTrackerFromPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "fallback_key_tracker_from_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override BigInt crateBindingsKeyMaintenanceFallbackKeyTrackerGracePeriodMs({required FallbackKeyTracker that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_fallback_key_tracker(that);
+            return wire.wire__crate__bindings__key_maintenance__fallback_key_tracker_grace_period_ms(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsKeyMaintenanceFallbackKeyTrackerGracePeriodMsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsKeyMaintenanceFallbackKeyTrackerGracePeriodMsConstMeta => const TaskConstMeta(
+            debugName: "fallback_key_tracker_grace_period_ms",
+            argNames: ["that"],
+        );
+        
+
+@override FallbackKeyTracker crateBindingsKeyMaintenanceFallbackKeyTrackerNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__key_maintenance__fallback_key_tracker_new();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_fallback_key_tracker,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsKeyMaintenanceFallbackKeyTrackerNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsKeyMaintenanceFallbackKeyTrackerNewConstMeta => const TaskConstMeta(
+            debugName: "fallback_key_tracker_new",
+            argNames: [],
+        );
+        
+
+@override String crateBindingsKeyMaintenanceFallbackKeyTrackerPickleEncrypted({required FallbackKeyTracker that , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_fallback_key_tracker(that);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__key_maintenance__fallback_key_tracker_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsKeyMaintenanceFallbackKeyTrackerPickleEncryptedConstMeta,
+            argValues: [that, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsKeyMaintenanceFallbackKeyTrackerPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "fallback_key_tracker_pickle_encrypted",
+            argNames: ["that", "pickleKey"],
+        );
+        
+
+@override void crateBindingsKeyMaintenanceFallbackKeyTrackerSetGracePeriodMs({required FallbackKeyTracker that , required BigInt gracePeriodMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_fallback_key_tracker(that);
+var arg1 = cst_encode_u_64(gracePeriodMs);
+            return wire.wire__crate__bindings__key_maintenance__fallback_key_tracker_set_grace_period_ms(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsKeyMaintenanceFallbackKeyTrackerSetGracePeriodMsConstMeta,
+            argValues: [that, gracePeriodMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsKeyMaintenanceFallbackKeyTrackerSetGracePeriodMsConstMeta => const TaskConstMeta(
+            debugName: "fallback_key_tracker_set_grace_period_ms",
+            argNames: ["that", "gracePeriodMs"],
+        );
+        
+
+@override String? crateBindingsForwardedRoomKeyForwardedRoomKeyContent({required InboundRoomKey roomKey , int? messageIndex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_inbound_room_key(roomKey);
+var arg1 = cst_encode_opt_box_autoadd_u_32(messageIndex);
+            return wire.wire__crate__bindings__forwarded_room_key__forwarded_room_key_content(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsForwardedRoomKeyForwardedRoomKeyContentConstMeta,
+            argValues: [roomKey, messageIndex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsForwardedRoomKeyForwardedRoomKeyContentConstMeta => const TaskConstMeta(
+            debugName: "forwarded_room_key_content",
+            argNames: ["roomKey", "messageIndex"],
+        );
+        
+
+@override Uint8List crateBindingsHmac({required List<int> key , required List<int> input })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_list_prim_u_8_loose(key);
+var arg1 = cst_encode_list_prim_u_8_loose(input);
+            return wire.wire__crate__bindings__hmac(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_u_8_strict,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsHmacConstMeta,
+            argValues: [key, input],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsHmacConstMeta => const TaskConstMeta(
+            debugName: "hmac",
+            argNames: ["key", "input"],
+        );
+        
+
+@override RoomKeyDecryptionResult crateBindingsRoomKeyInfoInboundRoomKeyDecryptRoomEvent({required InboundRoomKey that , required String roomId , required String encryptedContent })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_inbound_room_key(that);
+var arg1 = cst_encode_String(roomId);
+var arg2 = cst_encode_String(encryptedContent);
+            return wire.wire__crate__bindings__room_key_info__inbound_room_key_decrypt_room_event(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_room_key_decryption_result,
+          decodeErrorData: dco_decode_room_event_error,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyInfoInboundRoomKeyDecryptRoomEventConstMeta,
+            argValues: [that, roomId, encryptedContent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyInfoInboundRoomKeyDecryptRoomEventConstMeta => const TaskConstMeta(
+            debugName: "inbound_room_key_decrypt_room_event",
+            argNames: ["that", "roomId", "encryptedContent"],
+        );
+        
+
+@override int crateBindingsRoomKeyInfoInboundRoomKeyFirstKnownIndex({required InboundRoomKey that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_inbound_room_key(that);
+            return wire.wire__crate__bindings__room_key_info__inbound_room_key_first_known_index(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyInfoInboundRoomKeyFirstKnownIndexConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyInfoInboundRoomKeyFirstKnownIndexConstMeta => const TaskConstMeta(
+            debugName: "inbound_room_key_first_known_index",
+            argNames: ["that"],
+        );
+        
+
+@override InboundRoomKey crateBindingsRoomKeyInfoInboundRoomKeyFromPickleEncrypted({required String pickle , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__room_key_info__inbound_room_key_from_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_inbound_room_key,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyInfoInboundRoomKeyFromPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyInfoInboundRoomKeyFromPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "inbound_room_key_from_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacInboundGroupSession crateBindingsRoomKeyInfoInboundRoomKeyInboundGroupSession({required InboundRoomKey that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_inbound_room_key(that);
+            return wire.wire__crate__bindings__room_key_info__inbound_room_key_inbound_group_session(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_inbound_group_session,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyInfoInboundRoomKeyInboundGroupSessionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyInfoInboundRoomKeyInboundGroupSessionConstMeta => const TaskConstMeta(
+            debugName: "inbound_room_key_inbound_group_session",
+            argNames: ["that"],
+        );
+        
+
+@override RoomKeyInfo crateBindingsRoomKeyInfoInboundRoomKeyInfo({required InboundRoomKey that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_inbound_room_key(that);
+            return wire.wire__crate__bindings__room_key_info__inbound_room_key_info(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_room_key_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyInfoInboundRoomKeyInfoConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyInfoInboundRoomKeyInfoConstMeta => const TaskConstMeta(
+            debugName: "inbound_room_key_info",
+            argNames: ["that"],
+        );
+        
+
+@override void crateBindingsRoomKeyInfoInboundRoomKeyMarkBackedUp({required InboundRoomKey that , required bool backedUp })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_inbound_room_key(that);
+var arg1 = cst_encode_bool(backedUp);
+            return wire.wire__crate__bindings__room_key_info__inbound_room_key_mark_backed_up(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyInfoInboundRoomKeyMarkBackedUpConstMeta,
+            argValues: [that, backedUp],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyInfoInboundRoomKeyMarkBackedUpConstMeta => const TaskConstMeta(
+            debugName: "inbound_room_key_mark_backed_up",
+            argNames: ["that", "backedUp"],
+        );
+        
+
+@override InboundRoomKey crateBindingsRoomKeyInfoInboundRoomKeyNew({required VodozemacInboundGroupSession session , required RoomKeyInfo info })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(session);
+var arg1 = cst_encode_box_autoadd_room_key_info(info);
+            return wire.wire__crate__bindings__room_key_info__inbound_room_key_new(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_inbound_room_key,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyInfoInboundRoomKeyNewConstMeta,
+            argValues: [session, info],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyInfoInboundRoomKeyNewConstMeta => const TaskConstMeta(
+            debugName: "inbound_room_key_new",
+            argNames: ["session", "info"],
+        );
+        
+
+@override String crateBindingsRoomKeyInfoInboundRoomKeyPickleEncrypted({required InboundRoomKey that , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_inbound_room_key(that);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__room_key_info__inbound_room_key_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyInfoInboundRoomKeyPickleEncryptedConstMeta,
+            argValues: [that, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyInfoInboundRoomKeyPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "inbound_room_key_pickle_encrypted",
+            argNames: ["that", "pickleKey"],
+        );
+        
+
+@override String crateBindingsRoomKeyInfoInboundRoomKeySessionId({required InboundRoomKey that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_inbound_room_key(that);
+            return wire.wire__crate__bindings__room_key_info__inbound_room_key_session_id(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyInfoInboundRoomKeySessionIdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyInfoInboundRoomKeySessionIdConstMeta => const TaskConstMeta(
+            debugName: "inbound_room_key_session_id",
+            argNames: ["that"],
+        );
+        
+
+@override MegolmReplayGuard crateBindingsReplayMegolmReplayGuardDefault()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__replay__megolm_replay_guard_default();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_megolm_replay_guard,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsReplayMegolmReplayGuardDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsReplayMegolmReplayGuardDefaultConstMeta => const TaskConstMeta(
+            debugName: "megolm_replay_guard_default",
+            argNames: [],
+        );
+        
+
+@override void crateBindingsReplayMegolmReplayGuardForgetBefore({required MegolmReplayGuard that , required String sessionId , required int index })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_megolm_replay_guard(that);
+var arg1 = cst_encode_String(sessionId);
+var arg2 = cst_encode_u_32(index);
+            return wire.wire__crate__bindings__replay__megolm_replay_guard_forget_before(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsReplayMegolmReplayGuardForgetBeforeConstMeta,
+            argValues: [that, sessionId, index],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsReplayMegolmReplayGuardForgetBeforeConstMeta => const TaskConstMeta(
+            debugName: "megolm_replay_guard_forget_before",
+            argNames: ["that", "sessionId", "index"],
+        );
+        
+
+@override void crateBindingsReplayMegolmReplayGuardForgetSession({required MegolmReplayGuard that , required String sessionId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_megolm_replay_guard(that);
+var arg1 = cst_encode_String(sessionId);
+            return wire.wire__crate__bindings__replay__megolm_replay_guard_forget_session(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsReplayMegolmReplayGuardForgetSessionConstMeta,
+            argValues: [that, sessionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsReplayMegolmReplayGuardForgetSessionConstMeta => const TaskConstMeta(
+            debugName: "megolm_replay_guard_forget_session",
+            argNames: ["that", "sessionId"],
+        );
+        
+
+@override MegolmReplayGuard crateBindingsReplayMegolmReplayGuardFromPickleEncrypted({required String pickle , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__replay__megolm_replay_guard_from_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_megolm_replay_guard,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsReplayMegolmReplayGuardFromPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsReplayMegolmReplayGuardFromPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "megolm_replay_guard_from_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override MegolmReplayGuard crateBindingsReplayMegolmReplayGuardNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__replay__megolm_replay_guard_new();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_megolm_replay_guard,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsReplayMegolmReplayGuardNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsReplayMegolmReplayGuardNewConstMeta => const TaskConstMeta(
+            debugName: "megolm_replay_guard_new",
+            argNames: [],
+        );
+        
+
+@override String crateBindingsReplayMegolmReplayGuardPickleEncrypted({required MegolmReplayGuard that , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_megolm_replay_guard(that);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__replay__megolm_replay_guard_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsReplayMegolmReplayGuardPickleEncryptedConstMeta,
+            argValues: [that, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsReplayMegolmReplayGuardPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "megolm_replay_guard_pickle_encrypted",
+            argNames: ["that", "pickleKey"],
+        );
+        
+
+@override bool crateBindingsReplayMegolmReplayGuardRecord({required MegolmReplayGuard that , required String sessionId , required int messageIndex , required String eventId , required BigInt originServerTs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_megolm_replay_guard(that);
+var arg1 = cst_encode_String(sessionId);
+var arg2 = cst_encode_u_32(messageIndex);
+var arg3 = cst_encode_String(eventId);
+var arg4 = cst_encode_u_64(originServerTs);
+            return wire.wire__crate__bindings__replay__megolm_replay_guard_record(arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsReplayMegolmReplayGuardRecordConstMeta,
+            argValues: [that, sessionId, messageIndex, eventId, originServerTs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsReplayMegolmReplayGuardRecordConstMeta => const TaskConstMeta(
+            debugName: "megolm_replay_guard_record",
+            argNames: ["that", "sessionId", "messageIndex", "eventId", "originServerTs"],
+        );
+        
+
+@override MigrationReport crateBindingsMigrationMigrateLibolmStore({required LibolmStore store , required List<int> oldPickleKey , required U8Array32 newPickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_libolm_store(store);
+var arg1 = cst_encode_list_prim_u_8_loose(oldPickleKey);
+var arg2 = cst_encode_u_8_array_32(newPickleKey);
+            return wire.wire__crate__bindings__migration__migrate_libolm_store(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_migration_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsMigrationMigrateLibolmStoreConstMeta,
+            argValues: [store, oldPickleKey, newPickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsMigrationMigrateLibolmStoreConstMeta => const TaskConstMeta(
+            debugName: "migrate_libolm_store",
+            argNames: ["store", "oldPickleKey", "newPickleKey"],
+        );
+        
+
+@override bool crateBindingsMigrationMigrationReportIsComplete({required MigrationReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_migration_report(that);
+            return wire.wire__crate__bindings__migration__migration_report_is_complete(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsMigrationMigrationReportIsCompleteConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsMigrationMigrationReportIsCompleteConstMeta => const TaskConstMeta(
+            debugName: "migration_report_is_complete",
+            argNames: ["that"],
+        );
+        
+
+@override List<String> crateBindingsOlmSessionsOlmSessionStoreAddSession({required OlmSessionStore that , required String senderKey , required VodozemacSession session , required BigInt nowMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_olm_session_store(that);
+var arg1 = cst_encode_String(senderKey);
+var arg2 = cst_encode_box_autoadd_vodozemac_session(session);
+var arg3 = cst_encode_u_64(nowMs);
+            return wire.wire__crate__bindings__olm_sessions__olm_session_store_add_session(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOlmSessionsOlmSessionStoreAddSessionConstMeta,
+            argValues: [that, senderKey, session, nowMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOlmSessionsOlmSessionStoreAddSessionConstMeta => const TaskConstMeta(
+            debugName: "olm_session_store_add_session",
+            argNames: ["that", "senderKey", "session", "nowMs"],
+        );
+        
+
+@override OlmSessionStore crateBindingsOlmSessionsOlmSessionStoreDefault()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__olm_sessions__olm_session_store_default();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_olm_session_store,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOlmSessionsOlmSessionStoreDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOlmSessionsOlmSessionStoreDefaultConstMeta => const TaskConstMeta(
+            debugName: "olm_session_store_default",
+            argNames: [],
+        );
+        
+
+@override OlmSessionStore crateBindingsOlmSessionsOlmSessionStoreFromPickleEncrypted({required String pickle , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__olm_sessions__olm_session_store_from_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_olm_session_store,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsOlmSessionsOlmSessionStoreFromPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOlmSessionsOlmSessionStoreFromPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "olm_session_store_from_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override bool crateBindingsOlmSessionsOlmSessionStoreMarkUsed({required OlmSessionStore that , required String senderKey , required String sessionId , required BigInt nowMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_olm_session_store(that);
+var arg1 = cst_encode_String(senderKey);
+var arg2 = cst_encode_String(sessionId);
+var arg3 = cst_encode_u_64(nowMs);
+            return wire.wire__crate__bindings__olm_sessions__olm_session_store_mark_used(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOlmSessionsOlmSessionStoreMarkUsedConstMeta,
+            argValues: [that, senderKey, sessionId, nowMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOlmSessionsOlmSessionStoreMarkUsedConstMeta => const TaskConstMeta(
+            debugName: "olm_session_store_mark_used",
+            argNames: ["that", "senderKey", "sessionId", "nowMs"],
+        );
+        
+
+@override int crateBindingsOlmSessionsOlmSessionStoreMaxSessionsPerDevice({required OlmSessionStore that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_olm_session_store(that);
+            return wire.wire__crate__bindings__olm_sessions__olm_session_store_max_sessions_per_device(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOlmSessionsOlmSessionStoreMaxSessionsPerDeviceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOlmSessionsOlmSessionStoreMaxSessionsPerDeviceConstMeta => const TaskConstMeta(
+            debugName: "olm_session_store_max_sessions_per_device",
+            argNames: ["that"],
+        );
+        
+
+@override OlmSessionStore crateBindingsOlmSessionsOlmSessionStoreNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__olm_sessions__olm_session_store_new();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_olm_session_store,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOlmSessionsOlmSessionStoreNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOlmSessionsOlmSessionStoreNewConstMeta => const TaskConstMeta(
+            debugName: "olm_session_store_new",
+            argNames: [],
+        );
+        
+
+@override String crateBindingsOlmSessionsOlmSessionStorePickleEncrypted({required OlmSessionStore that , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_olm_session_store(that);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__olm_sessions__olm_session_store_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOlmSessionsOlmSessionStorePickleEncryptedConstMeta,
+            argValues: [that, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOlmSessionsOlmSessionStorePickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "olm_session_store_pickle_encrypted",
+            argNames: ["that", "pickleKey"],
+        );
+        
+
+@override bool crateBindingsOlmSessionsOlmSessionStoreRemoveSession({required OlmSessionStore that , required String senderKey , required String sessionId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_olm_session_store(that);
+var arg1 = cst_encode_String(senderKey);
+var arg2 = cst_encode_String(sessionId);
+            return wire.wire__crate__bindings__olm_sessions__olm_session_store_remove_session(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOlmSessionsOlmSessionStoreRemoveSessionConstMeta,
+            argValues: [that, senderKey, sessionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOlmSessionsOlmSessionStoreRemoveSessionConstMeta => const TaskConstMeta(
+            debugName: "olm_session_store_remove_session",
+            argNames: ["that", "senderKey", "sessionId"],
+        );
+        
+
+@override List<String> crateBindingsOlmSessionsOlmSessionStoreSenderKeys({required OlmSessionStore that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_olm_session_store(that);
+            return wire.wire__crate__bindings__olm_sessions__olm_session_store_sender_keys(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOlmSessionsOlmSessionStoreSenderKeysConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOlmSessionsOlmSessionStoreSenderKeysConstMeta => const TaskConstMeta(
+            debugName: "olm_session_store_sender_keys",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacSession? crateBindingsOlmSessionsOlmSessionStoreSessionForEncryption({required OlmSessionStore that , required String senderKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_olm_session_store(that);
+var arg1 = cst_encode_String(senderKey);
+            return wire.wire__crate__bindings__olm_sessions__olm_session_store_session_for_encryption(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_vodozemac_session,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOlmSessionsOlmSessionStoreSessionForEncryptionConstMeta,
+            argValues: [that, senderKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOlmSessionsOlmSessionStoreSessionForEncryptionConstMeta => const TaskConstMeta(
+            debugName: "olm_session_store_session_for_encryption",
+            argNames: ["that", "senderKey"],
+        );
+        
+
+@override List<VodozemacSession> crateBindingsOlmSessionsOlmSessionStoreSessionsForDevice({required OlmSessionStore that , required String senderKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_olm_session_store(that);
+var arg1 = cst_encode_String(senderKey);
+            return wire.wire__crate__bindings__olm_sessions__olm_session_store_sessions_for_device(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_vodozemac_session,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOlmSessionsOlmSessionStoreSessionsForDeviceConstMeta,
+            argValues: [that, senderKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOlmSessionsOlmSessionStoreSessionsForDeviceConstMeta => const TaskConstMeta(
+            debugName: "olm_session_store_sessions_for_device",
+            argNames: ["that", "senderKey"],
+        );
+        
+
+@override List<String> crateBindingsOlmSessionsOlmSessionStoreSetMaxSessionsPerDevice({required OlmSessionStore that , required int maxSessionsPerDevice })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_olm_session_store(that);
+var arg1 = cst_encode_u_32(maxSessionsPerDevice);
+            return wire.wire__crate__bindings__olm_sessions__olm_session_store_set_max_sessions_per_device(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOlmSessionsOlmSessionStoreSetMaxSessionsPerDeviceConstMeta,
+            argValues: [that, maxSessionsPerDevice],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOlmSessionsOlmSessionStoreSetMaxSessionsPerDeviceConstMeta => const TaskConstMeta(
+            debugName: "olm_session_store_set_max_sessions_per_device",
+            argNames: ["that", "maxSessionsPerDevice"],
+        );
+        
+
+@override BigInt crateBindingsOutboundGroupSessionOutboundGroupSessionCreatedAtMs({required OutboundGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_created_at_ms(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionCreatedAtMsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionCreatedAtMsConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_created_at_ms",
+            argNames: ["that"],
+        );
+        
+
+@override List<ShareRecipient> crateBindingsOutboundGroupSessionOutboundGroupSessionDevicesMissingKey({required OutboundGroupSession that , required List<ShareRecipient> devices })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+var arg1 = cst_encode_list_share_recipient(devices);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_devices_missing_key(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_share_recipient,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionDevicesMissingKeyConstMeta,
+            argValues: [that, devices],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionDevicesMissingKeyConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_devices_missing_key",
+            argNames: ["that", "devices"],
+        );
+        
+
+@override RotationReason? crateBindingsOutboundGroupSessionOutboundGroupSessionExpiredReason({required OutboundGroupSession that , required BigInt nowMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+var arg1 = cst_encode_u_64(nowMs);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_expired_reason(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_rotation_reason,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionExpiredReasonConstMeta,
+            argValues: [that, nowMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionExpiredReasonConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_expired_reason",
+            argNames: ["that", "nowMs"],
+        );
+        
+
+@override OutboundGroupSession crateBindingsOutboundGroupSessionOutboundGroupSessionFromGroupSession({required VodozemacGroupSession session , required BigInt createdAtMs , BigInt? rotationPeriodMs , BigInt? rotationPeriodMsgs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_group_session(session);
+var arg1 = cst_encode_u_64(createdAtMs);
+var arg2 = cst_encode_opt_box_autoadd_u_64(rotationPeriodMs);
+var arg3 = cst_encode_opt_box_autoadd_u_64(rotationPeriodMsgs);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_from_group_session(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_outbound_group_session,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionFromGroupSessionConstMeta,
+            argValues: [session, createdAtMs, rotationPeriodMs, rotationPeriodMsgs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionFromGroupSessionConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_from_group_session",
+            argNames: ["session", "createdAtMs", "rotationPeriodMs", "rotationPeriodMsgs"],
+        );
+        
+
+@override OutboundGroupSession crateBindingsOutboundGroupSessionOutboundGroupSessionFromPickleEncrypted({required String pickle , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_from_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_outbound_group_session,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionFromPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionFromPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_from_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacGroupSession crateBindingsOutboundGroupSessionOutboundGroupSessionGroupSession({required OutboundGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_group_session(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_group_session,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionGroupSessionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionGroupSessionConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_group_session",
+            argNames: ["that"],
+        );
+        
+
+@override void crateBindingsOutboundGroupSessionOutboundGroupSessionInvalidate({required OutboundGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_invalidate(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionInvalidateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionInvalidateConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_invalidate",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateBindingsOutboundGroupSessionOutboundGroupSessionNeedsRotation({required OutboundGroupSession that , required BigInt nowMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+var arg1 = cst_encode_u_64(nowMs);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_needs_rotation(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionNeedsRotationConstMeta,
+            argValues: [that, nowMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionNeedsRotationConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_needs_rotation",
+            argNames: ["that", "nowMs"],
+        );
+        
+
+@override OutboundGroupSession crateBindingsOutboundGroupSessionOutboundGroupSessionNew({required VodozemacMegolmSessionConfig config , required BigInt createdAtMs , BigInt? rotationPeriodMs , BigInt? rotationPeriodMsgs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_megolm_session_config(config);
+var arg1 = cst_encode_u_64(createdAtMs);
+var arg2 = cst_encode_opt_box_autoadd_u_64(rotationPeriodMs);
+var arg3 = cst_encode_opt_box_autoadd_u_64(rotationPeriodMsgs);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_new(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_outbound_group_session,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionNewConstMeta,
+            argValues: [config, createdAtMs, rotationPeriodMs, rotationPeriodMsgs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionNewConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_new",
+            argNames: ["config", "createdAtMs", "rotationPeriodMs", "rotationPeriodMsgs"],
+        );
+        
+
+@override String crateBindingsOutboundGroupSessionOutboundGroupSessionPickleEncrypted({required OutboundGroupSession that , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionPickleEncryptedConstMeta,
+            argValues: [that, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_pickle_encrypted",
+            argNames: ["that", "pickleKey"],
+        );
+        
+
+@override void crateBindingsOutboundGroupSessionOutboundGroupSessionRecordShare({required OutboundGroupSession that , required ShareRecipient recipient })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+var arg1 = cst_encode_box_autoadd_share_recipient(recipient);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_record_share(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionRecordShareConstMeta,
+            argValues: [that, recipient],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionRecordShareConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_record_share",
+            argNames: ["that", "recipient"],
+        );
+        
+
+@override void crateBindingsOutboundGroupSessionOutboundGroupSessionRecordWithheld({required OutboundGroupSession that , required ShareRecipient recipient , required WithheldCode code })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+var arg1 = cst_encode_box_autoadd_share_recipient(recipient);
+var arg2 = cst_encode_withheld_code(code);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_record_withheld(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionRecordWithheldConstMeta,
+            argValues: [that, recipient, code],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionRecordWithheldConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_record_withheld",
+            argNames: ["that", "recipient", "code"],
+        );
+        
+
+@override List<ShareRecipient> crateBindingsOutboundGroupSessionOutboundGroupSessionRemovedDevices({required OutboundGroupSession that , required List<ShareRecipient> devices })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+var arg1 = cst_encode_list_share_recipient(devices);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_removed_devices(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_share_recipient,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionRemovedDevicesConstMeta,
+            argValues: [that, devices],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionRemovedDevicesConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_removed_devices",
+            argNames: ["that", "devices"],
+        );
+        
+
+@override BigInt crateBindingsOutboundGroupSessionOutboundGroupSessionRotationPeriodMs({required OutboundGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_ms(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionRotationPeriodMsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionRotationPeriodMsConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_rotation_period_ms",
+            argNames: ["that"],
+        );
+        
+
+@override BigInt crateBindingsOutboundGroupSessionOutboundGroupSessionRotationPeriodMsgs({required OutboundGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_msgs(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionRotationPeriodMsgsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionRotationPeriodMsgsConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_rotation_period_msgs",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsOutboundGroupSessionOutboundGroupSessionSessionId({required OutboundGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_session_id(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionSessionIdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionSessionIdConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_session_id",
+            argNames: ["that"],
+        );
+        
+
+@override int? crateBindingsOutboundGroupSessionOutboundGroupSessionSharedMessageIndex({required OutboundGroupSession that , required ShareRecipient recipient })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+var arg1 = cst_encode_box_autoadd_share_recipient(recipient);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_shared_message_index(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionSharedMessageIndexConstMeta,
+            argValues: [that, recipient],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionSharedMessageIndexConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_shared_message_index",
+            argNames: ["that", "recipient"],
+        );
+        
+
+@override WithheldCode? crateBindingsOutboundGroupSessionOutboundGroupSessionWithheldCode({required OutboundGroupSession that , required ShareRecipient recipient })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_outbound_group_session(that);
+var arg1 = cst_encode_box_autoadd_share_recipient(recipient);
+            return wire.wire__crate__bindings__outbound_group_session__outbound_group_session_withheld_code(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_withheld_code,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsOutboundGroupSessionOutboundGroupSessionWithheldCodeConstMeta,
+            argValues: [that, recipient],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsOutboundGroupSessionOutboundGroupSessionWithheldCodeConstMeta => const TaskConstMeta(
+            debugName: "outbound_group_session_withheld_code",
+            argNames: ["that", "recipient"],
+        );
+        
+
+@override PassphraseKdfParams crateBindingsPassphrasePicklePassphraseKdfParamsDefault()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_default();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_passphrase_kdf_params,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsPassphrasePicklePassphraseKdfParamsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsPassphrasePicklePassphraseKdfParamsDefaultConstMeta => const TaskConstMeta(
+            debugName: "passphrase_kdf_params_default",
+            argNames: [],
+        );
+        
+
+@override PassphraseKdfParams crateBindingsPassphrasePicklePassphraseKdfParamsNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_new();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_passphrase_kdf_params,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsPassphrasePicklePassphraseKdfParamsNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsPassphrasePicklePassphraseKdfParamsNewConstMeta => const TaskConstMeta(
+            debugName: "passphrase_kdf_params_new",
+            argNames: [],
+        );
+        
+
+@override PassphraseKdfParams crateBindingsPassphrasePicklePassphrasePickleParams({required String pickle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+            return wire.wire__crate__bindings__passphrase_pickle__passphrase_pickle_params(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_passphrase_kdf_params,
+          decodeErrorData: dco_decode_passphrase_pickle_error,
+        )
+        ,
+            constMeta: kCrateBindingsPassphrasePicklePassphrasePickleParamsConstMeta,
+            argValues: [pickle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsPassphrasePicklePassphrasePickleParamsConstMeta => const TaskConstMeta(
+            debugName: "passphrase_pickle_params",
+            argNames: ["pickle"],
+        );
+        
+
+@override Uint8List crateBindingsPbkdf2({required List<int> passphrase , required List<int> salt , required int iterations })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_list_prim_u_8_loose(passphrase);
+var arg1 = cst_encode_list_prim_u_8_loose(salt);
+var arg2 = cst_encode_u_32(iterations);
+            return wire.wire__crate__bindings__pbkdf2(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_u_8_strict,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsPbkdf2ConstMeta,
+            argValues: [passphrase, salt, iterations],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsPbkdf2ConstMeta => const TaskConstMeta(
+            debugName: "pbkdf2",
+            argNames: ["passphrase", "salt", "iterations"],
+        );
+        
+
+@override bool crateBindingsPickleFormatPickleInfoNeedsRepickle({required PickleInfo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_pickle_info(that);
+            return wire.wire__crate__bindings__pickle_format__pickle_info_needs_repickle(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsPickleFormatPickleInfoNeedsRepickleConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsPickleFormatPickleInfoNeedsRepickleConstMeta => const TaskConstMeta(
+            debugName: "pickle_info_needs_repickle",
+            argNames: ["that"],
+        );
+        
+
+@override InboundRoomKey crateBindingsForwardedRoomKeyReceiveForwardedRoomKey({required OlmPayload payload , required VodozemacCurve25519PublicKey forwarderKey , required DeviceKeys forwarderDevice })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_olm_payload(payload);
+var arg1 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(forwarderKey);
+var arg2 = cst_encode_box_autoadd_device_keys(forwarderDevice);
+            return wire.wire__crate__bindings__forwarded_room_key__receive_forwarded_room_key(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_inbound_room_key,
+          decodeErrorData: dco_decode_room_key_error,
+        )
+        ,
+            constMeta: kCrateBindingsForwardedRoomKeyReceiveForwardedRoomKeyConstMeta,
+            argValues: [payload, forwarderKey, forwarderDevice],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsForwardedRoomKeyReceiveForwardedRoomKeyConstMeta => const TaskConstMeta(
+            debugName: "receive_forwarded_room_key",
+            argNames: ["payload", "forwarderKey", "forwarderDevice"],
+        );
+        
+
+@override InboundRoomKey crateBindingsRoomKeyReceiveRoomKey({required OlmPayload payload , required VodozemacCurve25519PublicKey senderKey , required DeviceKeys senderDevice })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_olm_payload(payload);
+var arg1 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(senderKey);
+var arg2 = cst_encode_box_autoadd_device_keys(senderDevice);
+            return wire.wire__crate__bindings__room_key__receive_room_key(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_inbound_room_key,
+          decodeErrorData: dco_decode_room_key_error,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyReceiveRoomKeyConstMeta,
+            argValues: [payload, senderKey, senderDevice],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyReceiveRoomKeyConstMeta => const TaskConstMeta(
+            debugName: "receive_room_key",
+            argNames: ["payload", "senderKey", "senderDevice"],
+        );
+        
+
+@override RoomKeyBundleDownload crateBindingsRoomKeyBundleReceiveRoomKeyBundle({required OlmPayload payload , required VodozemacCurve25519PublicKey senderKey , required DeviceKeys senderDevice })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_olm_payload(payload);
+var arg1 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(senderKey);
+var arg2 = cst_encode_box_autoadd_device_keys(senderDevice);
+            return wire.wire__crate__bindings__room_key_bundle__receive_room_key_bundle(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_room_key_bundle_download,
+          decodeErrorData: dco_decode_room_key_error,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyBundleReceiveRoomKeyBundleConstMeta,
+            argValues: [payload, senderKey, senderDevice],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyBundleReceiveRoomKeyBundleConstMeta => const TaskConstMeta(
+            debugName: "receive_room_key_bundle",
+            argNames: ["payload", "senderKey", "senderDevice"],
+        );
+        
+
+@override Stream<RekeyProgress> crateBindingsRekeyRekeyPickles({required List<String> pickles , required U8Array32 oldPickleKey , required U8Array32 newPickleKey })  { 
+            final progress = RustStreamSink<RekeyProgress>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_list_String(pickles);
+var arg1 = cst_encode_u_8_array_32(oldPickleKey);
+var arg2 = cst_encode_u_8_array_32(newPickleKey);
+var arg3 = cst_encode_StreamSink_rekey_progress_Dco(progress);
+            return wire.wire__crate__bindings__rekey__rekey_pickles(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsRekeyRekeyPicklesConstMeta,
+            argValues: [pickles, oldPickleKey, newPickleKey, progress],
+            apiImpl: this,
+        )));
+            return progress.stream;
+             }
+
+
+        TaskConstMeta get kCrateBindingsRekeyRekeyPicklesConstMeta => const TaskConstMeta(
+            debugName: "rekey_pickles",
+            argNames: ["pickles", "oldPickleKey", "newPickleKey", "progress"],
+        );
+        
+
+@override ImportedRoomKeyBundle crateBindingsRoomKeyBundleRoomKeyBundleDownloadDecrypt({required RoomKeyBundleDownload that , required List<int> ciphertext })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_room_key_bundle_download(that);
+var arg1 = cst_encode_list_prim_u_8_loose(ciphertext);
+            return wire.wire__crate__bindings__room_key_bundle__room_key_bundle_download_decrypt(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_imported_room_key_bundle,
+          decodeErrorData: dco_decode_room_key_bundle_error,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyBundleRoomKeyBundleDownloadDecryptConstMeta,
+            argValues: [that, ciphertext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyBundleRoomKeyBundleDownloadDecryptConstMeta => const TaskConstMeta(
+            debugName: "room_key_bundle_download_decrypt",
+            argNames: ["that", "ciphertext"],
+        );
+        
+
+@override String crateBindingsRoomKeyRoomKeyContent({required VodozemacGroupSession session , required String roomId , required bool sharedHistory })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_group_session(session);
+var arg1 = cst_encode_String(roomId);
+var arg2 = cst_encode_bool(sharedHistory);
+            return wire.wire__crate__bindings__room_key__room_key_content(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyRoomKeyContentConstMeta,
+            argValues: [session, roomId, sharedHistory],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyRoomKeyContentConstMeta => const TaskConstMeta(
+            debugName: "room_key_content",
+            argNames: ["session", "roomId", "sharedHistory"],
+        );
+        
+
+@override KeyAuthenticity crateBindingsRoomKeyInfoRoomKeyInfoAuthenticity({required RoomKeyInfo that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_room_key_info(that);
+            return wire.wire__crate__bindings__room_key_info__room_key_info_authenticity(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_key_authenticity,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsRoomKeyInfoRoomKeyInfoAuthenticityConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsRoomKeyInfoRoomKeyInfoAuthenticityConstMeta => const TaskConstMeta(
+            debugName: "room_key_info_authenticity",
+            argNames: ["that"],
+        );
+        
+
+@override Uint8List crateBindingsSha256({required List<int> input })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_list_prim_u_8_loose(input);
+            return wire.wire__crate__bindings__sha256(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsSha256ConstMeta,
+            argValues: [input],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsSha256ConstMeta => const TaskConstMeta(
+            debugName: "sha256",
+            argNames: ["input"],
+        );
+        
+
+@override Uint8List crateBindingsSha512({required List<int> input })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_list_prim_u_8_loose(input);
+            return wire.wire__crate__bindings__sha512(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsSha512ConstMeta,
+            argValues: [input],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsSha512ConstMeta => const TaskConstMeta(
+            debugName: "sha512",
+            argNames: ["input"],
+        );
+        
+
+@override bool crateBindingsUnwedgeUnwedgeTrackerCanUnwedge({required UnwedgeTracker that , required String senderKey , required BigInt nowMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_unwedge_tracker(that);
+var arg1 = cst_encode_String(senderKey);
+var arg2 = cst_encode_u_64(nowMs);
+            return wire.wire__crate__bindings__unwedge__unwedge_tracker_can_unwedge(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUnwedgeUnwedgeTrackerCanUnwedgeConstMeta,
+            argValues: [that, senderKey, nowMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUnwedgeUnwedgeTrackerCanUnwedgeConstMeta => const TaskConstMeta(
+            debugName: "unwedge_tracker_can_unwedge",
+            argNames: ["that", "senderKey", "nowMs"],
+        );
+        
+
+@override BigInt crateBindingsUnwedgeUnwedgeTrackerCooldownMs({required UnwedgeTracker that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_unwedge_tracker(that);
+            return wire.wire__crate__bindings__unwedge__unwedge_tracker_cooldown_ms(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUnwedgeUnwedgeTrackerCooldownMsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUnwedgeUnwedgeTrackerCooldownMsConstMeta => const TaskConstMeta(
+            debugName: "unwedge_tracker_cooldown_ms",
+            argNames: ["that"],
+        );
+        
+
+@override UnwedgeTracker crateBindingsUnwedgeUnwedgeTrackerDefault()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__unwedge__unwedge_tracker_default();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unwedge_tracker,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUnwedgeUnwedgeTrackerDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUnwedgeUnwedgeTrackerDefaultConstMeta => const TaskConstMeta(
+            debugName: "unwedge_tracker_default",
+            argNames: [],
+        );
+        
+
+@override UnwedgeTracker crateBindingsUnwedgeUnwedgeTrackerFromPickleEncrypted({required String pickle , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__unwedge__unwedge_tracker_from_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unwedge_tracker,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsUnwedgeUnwedgeTrackerFromPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUnwedgeUnwedgeTrackerFromPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "unwedge_tracker_from_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override UnwedgeTracker crateBindingsUnwedgeUnwedgeTrackerNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__unwedge__unwedge_tracker_new();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unwedge_tracker,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUnwedgeUnwedgeTrackerNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUnwedgeUnwedgeTrackerNewConstMeta => const TaskConstMeta(
+            debugName: "unwedge_tracker_new",
+            argNames: [],
+        );
+        
+
+@override String crateBindingsUnwedgeUnwedgeTrackerPickleEncrypted({required UnwedgeTracker that , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_unwedge_tracker(that);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__unwedge__unwedge_tracker_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUnwedgeUnwedgeTrackerPickleEncryptedConstMeta,
+            argValues: [that, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUnwedgeUnwedgeTrackerPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "unwedge_tracker_pickle_encrypted",
+            argNames: ["that", "pickleKey"],
+        );
+        
+
+@override void crateBindingsUnwedgeUnwedgeTrackerSetCooldownMs({required UnwedgeTracker that , required BigInt cooldownMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_unwedge_tracker(that);
+var arg1 = cst_encode_u_64(cooldownMs);
+            return wire.wire__crate__bindings__unwedge__unwedge_tracker_set_cooldown_ms(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUnwedgeUnwedgeTrackerSetCooldownMsConstMeta,
+            argValues: [that, cooldownMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUnwedgeUnwedgeTrackerSetCooldownMsConstMeta => const TaskConstMeta(
+            debugName: "unwedge_tracker_set_cooldown_ms",
+            argNames: ["that", "cooldownMs"],
+        );
+        
+
+@override void crateBindingsUnwedgeUnwedgeTrackerStartAttempt({required UnwedgeTracker that , required String senderKey , required BigInt nowMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_unwedge_tracker(that);
+var arg1 = cst_encode_String(senderKey);
+var arg2 = cst_encode_u_64(nowMs);
+            return wire.wire__crate__bindings__unwedge__unwedge_tracker_start_attempt(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_unwedge_error,
+        )
+        ,
+            constMeta: kCrateBindingsUnwedgeUnwedgeTrackerStartAttemptConstMeta,
+            argValues: [that, senderKey, nowMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUnwedgeUnwedgeTrackerStartAttemptConstMeta => const TaskConstMeta(
+            debugName: "unwedge_tracker_start_attempt",
+            argNames: ["that", "senderKey", "nowMs"],
+        );
+        
+
+@override List<String> crateBindingsUtdQueueUtdQueueAddEvent({required UtdQueue that , required String roomId , required String eventId , required String encryptedContent })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_utd_queue(that);
+var arg1 = cst_encode_String(roomId);
+var arg2 = cst_encode_String(eventId);
+var arg3 = cst_encode_String(encryptedContent);
+            return wire.wire__crate__bindings__utd_queue__utd_queue_add_event(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_room_event_error,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueueAddEventConstMeta,
+            argValues: [that, roomId, eventId, encryptedContent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueueAddEventConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_add_event",
+            argNames: ["that", "roomId", "eventId", "encryptedContent"],
+        );
+        
+
+@override List<RetriedEvent> crateBindingsUtdQueueUtdQueueAddRoomKey({required UtdQueue that , required InboundRoomKey roomKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_utd_queue(that);
+var arg1 = cst_encode_box_autoadd_inbound_room_key(roomKey);
+            return wire.wire__crate__bindings__utd_queue__utd_queue_add_room_key(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_retried_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueueAddRoomKeyConstMeta,
+            argValues: [that, roomKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueueAddRoomKeyConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_add_room_key",
+            argNames: ["that", "roomKey"],
+        );
+        
+
+@override UtdQueue crateBindingsUtdQueueUtdQueueDefault()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__utd_queue__utd_queue_default();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_utd_queue,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueueDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueueDefaultConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_default",
+            argNames: [],
+        );
+        
+
+@override UtdQueue crateBindingsUtdQueueUtdQueueFromPickleEncrypted({required String pickle , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__utd_queue__utd_queue_from_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_utd_queue,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueueFromPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueueFromPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_from_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override bool crateBindingsUtdQueueUtdQueueIsEmpty({required UtdQueue that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_utd_queue(that);
+            return wire.wire__crate__bindings__utd_queue__utd_queue_is_empty(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueueIsEmptyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueueIsEmptyConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_is_empty",
+            argNames: ["that"],
+        );
+        
+
+@override BigInt crateBindingsUtdQueueUtdQueueLen({required UtdQueue that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_utd_queue(that);
+            return wire.wire__crate__bindings__utd_queue__utd_queue_len(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_usize,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueueLenConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueueLenConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_len",
+            argNames: ["that"],
+        );
+        
+
+@override int crateBindingsUtdQueueUtdQueueMaxEvents({required UtdQueue that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_utd_queue(that);
+            return wire.wire__crate__bindings__utd_queue__utd_queue_max_events(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueueMaxEventsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueueMaxEventsConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_max_events",
+            argNames: ["that"],
+        );
+        
+
+@override List<RetriedEvent> crateBindingsUtdQueueUtdQueueMergeRoomKey({required UtdQueue that , required InboundRoomKey existing , required InboundRoomKey other })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_utd_queue(that);
+var arg1 = cst_encode_box_autoadd_inbound_room_key(existing);
+var arg2 = cst_encode_box_autoadd_inbound_room_key(other);
+            return wire.wire__crate__bindings__utd_queue__utd_queue_merge_room_key(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_retried_event,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueueMergeRoomKeyConstMeta,
+            argValues: [that, existing, other],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueueMergeRoomKeyConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_merge_room_key",
+            argNames: ["that", "existing", "other"],
+        );
+        
+
+@override UtdQueue crateBindingsUtdQueueUtdQueueNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__utd_queue__utd_queue_new();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_utd_queue,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueueNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueueNewConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_new",
+            argNames: [],
+        );
+        
+
+@override List<String> crateBindingsUtdQueueUtdQueuePendingEvents({required UtdQueue that , required String roomId , required String sessionId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_utd_queue(that);
+var arg1 = cst_encode_String(roomId);
+var arg2 = cst_encode_String(sessionId);
+            return wire.wire__crate__bindings__utd_queue__utd_queue_pending_events(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueuePendingEventsConstMeta,
+            argValues: [that, roomId, sessionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueuePendingEventsConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_pending_events",
+            argNames: ["that", "roomId", "sessionId"],
+        );
+        
+
+@override String crateBindingsUtdQueueUtdQueuePickleEncrypted({required UtdQueue that , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_utd_queue(that);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__utd_queue__utd_queue_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueuePickleEncryptedConstMeta,
+            argValues: [that, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueuePickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_pickle_encrypted",
+            argNames: ["that", "pickleKey"],
+        );
+        
+
+@override bool crateBindingsUtdQueueUtdQueueRemoveEvent({required UtdQueue that , required String eventId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_utd_queue(that);
+var arg1 = cst_encode_String(eventId);
+            return wire.wire__crate__bindings__utd_queue__utd_queue_remove_event(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueueRemoveEventConstMeta,
+            argValues: [that, eventId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueueRemoveEventConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_remove_event",
+            argNames: ["that", "eventId"],
+        );
+        
+
+@override List<String> crateBindingsUtdQueueUtdQueueSetMaxEvents({required UtdQueue that , required int maxEvents })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_utd_queue(that);
+var arg1 = cst_encode_u_32(maxEvents);
+            return wire.wire__crate__bindings__utd_queue__utd_queue_set_max_events(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsUtdQueueUtdQueueSetMaxEventsConstMeta,
+            argValues: [that, maxEvents],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsUtdQueueUtdQueueSetMaxEventsConstMeta => const TaskConstMeta(
+            debugName: "utd_queue_set_max_events",
+            argNames: ["that", "maxEvents"],
+        );
+        
+
+@override OlmPayload crateBindingsToDeviceValidateToDevicePayload({required VodozemacAccount account , required String ownUserId , required String eventSender , required String plaintext })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(account);
+var arg1 = cst_encode_String(ownUserId);
+var arg2 = cst_encode_String(eventSender);
+var arg3 = cst_encode_String(plaintext);
+            return wire.wire__crate__bindings__to_device__validate_to_device_payload(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_olm_payload,
+          decodeErrorData: dco_decode_olm_payload_error,
+        )
+        ,
+            constMeta: kCrateBindingsToDeviceValidateToDevicePayloadConstMeta,
+            argValues: [account, ownUserId, eventSender, plaintext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsToDeviceValidateToDevicePayloadConstMeta => const TaskConstMeta(
+            debugName: "validate_to_device_payload",
+            argNames: ["account", "ownUserId", "eventSender", "plaintext"],
+        );
+        
+
+@override VodozemacOlmSessionCreationResult crateBindingsVodozemacAccountCreateInboundSession({required VodozemacAccount that , required VodozemacCurve25519PublicKey theirIdentityKey , required String preKeyMessageBase64 })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+var arg1 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(theirIdentityKey);
+var arg2 = cst_encode_String(preKeyMessageBase64);
+            return wire.wire__crate__bindings__vodozemac_account_create_inbound_session(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_olm_session_creation_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountCreateInboundSessionConstMeta,
+            argValues: [that, theirIdentityKey, preKeyMessageBase64],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountCreateInboundSessionConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_create_inbound_session",
+            argNames: ["that", "theirIdentityKey", "preKeyMessageBase64"],
+        );
+        
+
+@override VodozemacSession crateBindingsVodozemacAccountCreateOutboundSession({required VodozemacAccount that , required VodozemacOlmSessionConfig config , required VodozemacCurve25519PublicKey identityKey , required VodozemacCurve25519PublicKey oneTimeKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+var arg1 = cst_encode_box_autoadd_vodozemac_olm_session_config(config);
+var arg2 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(identityKey);
+var arg3 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(oneTimeKey);
+            return wire.wire__crate__bindings__vodozemac_account_create_outbound_session(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_session,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountCreateOutboundSessionConstMeta,
+            argValues: [that, config, identityKey, oneTimeKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountCreateOutboundSessionConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_create_outbound_session",
+            argNames: ["that", "config", "identityKey", "oneTimeKey"],
+        );
+        
+
+@override ClaimedSession crateBindingsVodozemacAccountCreateOutboundSessionFromClaim({required VodozemacAccount that , required VodozemacOlmSessionConfig config , required DeviceKeys deviceKeys , required String claimedKeyJson })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+var arg1 = cst_encode_box_autoadd_vodozemac_olm_session_config(config);
+var arg2 = cst_encode_box_autoadd_device_keys(deviceKeys);
+var arg3 = cst_encode_String(claimedKeyJson);
+            return wire.wire__crate__bindings__vodozemac_account_create_outbound_session_from_claim(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_claimed_session,
+          decodeErrorData: dco_decode_key_claim_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountCreateOutboundSessionFromClaimConstMeta,
+            argValues: [that, config, deviceKeys, claimedKeyJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountCreateOutboundSessionFromClaimConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_create_outbound_session_from_claim",
+            argNames: ["that", "config", "deviceKeys", "claimedKeyJson"],
+        );
+        
+
+@override VodozemacCurve25519PublicKey crateBindingsVodozemacAccountCurve25519Key({required VodozemacAccount that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+            return wire.wire__crate__bindings__vodozemac_account_curve25519_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_curve_25519_public_key,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountCurve25519KeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountCurve25519KeyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_curve25519_key",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacEd25519PublicKey crateBindingsVodozemacAccountEd25519Key({required VodozemacAccount that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+            return wire.wire__crate__bindings__vodozemac_account_ed25519_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_ed_25519_public_key,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountEd25519KeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountEd25519KeyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_ed25519_key",
+            argNames: ["that"],
+        );
+        
+
+@override List<VodozemacOneTimeKey> crateBindingsVodozemacAccountFallbackKey({required VodozemacAccount that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+            return wire.wire__crate__bindings__vodozemac_account_fallback_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_vodozemac_one_time_key,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountFallbackKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountFallbackKeyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_fallback_key",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateBindingsVodozemacAccountForgetFallbackKey({required VodozemacAccount that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+            return wire.wire__crate__bindings__vodozemac_account_forget_fallback_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountForgetFallbackKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountForgetFallbackKeyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_forget_fallback_key",
+            argNames: ["that"],
+        );
+        
+
+@override UnpickledAccount crateBindingsVodozemacAccountFromAnyPickle({required String pickle , required List<int> pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_account_from_any_pickle(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unpickled_account,
+          decodeErrorData: dco_decode_pickle_format_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountFromAnyPickleConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountFromAnyPickleConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_from_any_pickle",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacAccount crateBindingsVodozemacAccountFromOlmPickleEncrypted({required String pickle , required List<int> pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_account,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountFromOlmPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountFromOlmPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_from_olm_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacAccount crateBindingsVodozemacAccountFromPickleEncrypted({required String pickle , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_account_from_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_account,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountFromPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountFromPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_from_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacAccount crateBindingsVodozemacAccountFromPickleWithPassphrase({required String pickle , required String passphrase })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_String(passphrase);
+            return wire.wire__crate__bindings__vodozemac_account_from_pickle_with_passphrase(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_account,
+          decodeErrorData: dco_decode_passphrase_pickle_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountFromPickleWithPassphraseConstMeta,
+            argValues: [pickle, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountFromPickleWithPassphraseConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_from_pickle_with_passphrase",
+            argNames: ["pickle", "passphrase"],
+        );
+        
+
+@override String? crateBindingsVodozemacAccountGenerateFallbackKey({required VodozemacAccount that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+            return wire.wire__crate__bindings__vodozemac_account_generate_fallback_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountGenerateFallbackKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountGenerateFallbackKeyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_generate_fallback_key",
+            argNames: ["that"],
+        );
+        
+
+@override void crateBindingsVodozemacAccountGenerateOneTimeKeys({required VodozemacAccount that , required BigInt count })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+var arg1 = cst_encode_usize(count);
+            return wire.wire__crate__bindings__vodozemac_account_generate_one_time_keys(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountGenerateOneTimeKeysConstMeta,
+            argValues: [that, count],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountGenerateOneTimeKeysConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_generate_one_time_keys",
+            argNames: ["that", "count"],
+        );
+        
+
+@override VodozemacIdentityKeys crateBindingsVodozemacAccountIdentityKeys({required VodozemacAccount that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+            return wire.wire__crate__bindings__vodozemac_account_identity_keys(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_identity_keys,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountIdentityKeysConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountIdentityKeysConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_identity_keys",
+            argNames: ["that"],
+        );
+        
+
+@override void crateBindingsVodozemacAccountMarkKeysAsPublished({required VodozemacAccount that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+            return wire.wire__crate__bindings__vodozemac_account_mark_keys_as_published(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountMarkKeysAsPublishedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountMarkKeysAsPublishedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_mark_keys_as_published",
+            argNames: ["that"],
+        );
+        
+
+@override BigInt crateBindingsVodozemacAccountMaxNumberOfOneTimeKeys({required VodozemacAccount that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+            return wire.wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_usize,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountMaxNumberOfOneTimeKeysConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountMaxNumberOfOneTimeKeysConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_max_number_of_one_time_keys",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacAccount crateBindingsVodozemacAccountNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__vodozemac_account_new();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_account,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountNewConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_new",
+            argNames: [],
+        );
+        
+
+@override List<VodozemacOneTimeKey> crateBindingsVodozemacAccountOneTimeKeys({required VodozemacAccount that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+            return wire.wire__crate__bindings__vodozemac_account_one_time_keys(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_vodozemac_one_time_key,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountOneTimeKeysConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountOneTimeKeysConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_one_time_keys",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsVodozemacAccountPickleEncrypted({required VodozemacAccount that , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_account_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountPickleEncryptedConstMeta,
+            argValues: [that, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_pickle_encrypted",
+            argNames: ["that", "pickleKey"],
+        );
+        
+
+@override String crateBindingsVodozemacAccountPickleWithPassphrase({required VodozemacAccount that , required String passphrase , required PassphraseKdfParams params })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+var arg1 = cst_encode_String(passphrase);
+var arg2 = cst_encode_box_autoadd_passphrase_kdf_params(params);
+            return wire.wire__crate__bindings__vodozemac_account_pickle_with_passphrase(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_passphrase_pickle_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountPickleWithPassphraseConstMeta,
+            argValues: [that, passphrase, params],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountPickleWithPassphraseConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_pickle_with_passphrase",
+            argNames: ["that", "passphrase", "params"],
+        );
+        
+
+@override KeyMaintenancePlan crateBindingsVodozemacAccountPlanKeyMaintenance({required VodozemacAccount that , required FallbackKeyTracker tracker , required Map<String, int> serverCounts , List<String>? unusedFallbackTypes , required BigInt nowMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+var arg1 = cst_encode_box_autoadd_fallback_key_tracker(tracker);
+var arg2 = cst_encode_Map_String_u_32_None(serverCounts);
+var arg3 = cst_encode_opt_list_String(unusedFallbackTypes);
+var arg4 = cst_encode_u_64(nowMs);
+            return wire.wire__crate__bindings__vodozemac_account_plan_key_maintenance(arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_key_maintenance_plan,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountPlanKeyMaintenanceConstMeta,
+            argValues: [that, tracker, serverCounts, unusedFallbackTypes, nowMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountPlanKeyMaintenanceConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_plan_key_maintenance",
+            argNames: ["that", "tracker", "serverCounts", "unusedFallbackTypes", "nowMs"],
+        );
+        
+
+@override Uint8List crateBindingsVodozemacAccountRemoveOneTimeKey({required VodozemacAccount that , required String publicKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+var arg1 = cst_encode_String(publicKey);
+            return wire.wire__crate__bindings__vodozemac_account_remove_one_time_key(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountRemoveOneTimeKeyConstMeta,
+            argValues: [that, publicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountRemoveOneTimeKeyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_remove_one_time_key",
+            argNames: ["that", "publicKey"],
+        );
+        
+
+@override VodozemacEd25519Signature crateBindingsVodozemacAccountSign({required VodozemacAccount that , required String message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+var arg1 = cst_encode_String(message);
+            return wire.wire__crate__bindings__vodozemac_account_sign(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_ed_25519_signature,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountSignConstMeta,
+            argValues: [that, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountSignConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_sign",
+            argNames: ["that", "message"],
+        );
+        
+
+@override UnwedgeResult crateBindingsVodozemacAccountUnwedgeSession({required VodozemacAccount that , required UnwedgeTracker tracker , required VodozemacOlmSessionConfig config , required String ownUserId , required String ownDeviceId , required DeviceKeys deviceKeys , required String claimedKeyJson })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_account(that);
+var arg1 = cst_encode_box_autoadd_unwedge_tracker(tracker);
+var arg2 = cst_encode_box_autoadd_vodozemac_olm_session_config(config);
+var arg3 = cst_encode_String(ownUserId);
+var arg4 = cst_encode_String(ownDeviceId);
+var arg5 = cst_encode_box_autoadd_device_keys(deviceKeys);
+var arg6 = cst_encode_String(claimedKeyJson);
+            return wire.wire__crate__bindings__vodozemac_account_unwedge_session(arg0, arg1, arg2, arg3, arg4, arg5, arg6);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unwedge_result,
+          decodeErrorData: dco_decode_unwedge_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacAccountUnwedgeSessionConstMeta,
+            argValues: [that, tracker, config, ownUserId, ownDeviceId, deviceKeys, claimedKeyJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacAccountUnwedgeSessionConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_account_unwedge_session",
+            argNames: ["that", "tracker", "config", "ownUserId", "ownDeviceId", "deviceKeys", "claimedKeyJson"],
+        );
+        
+
+@override U8Array32 crateBindingsVodozemacCurve25519PublicKeyAsBytes({required VodozemacCurve25519PublicKey that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(that);
+            return wire.wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacCurve25519PublicKeyAsBytesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacCurve25519PublicKeyAsBytesConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_curve_25519_public_key_as_bytes",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacCurve25519PublicKey crateBindingsVodozemacCurve25519PublicKeyFromBase64({required String base64Key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(base64Key);
+            return wire.wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_curve_25519_public_key,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacCurve25519PublicKeyFromBase64ConstMeta,
+            argValues: [base64Key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacCurve25519PublicKeyFromBase64ConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_curve_25519_public_key_from_base64",
+            argNames: ["base64Key"],
+        );
+        
+
+@override VodozemacCurve25519PublicKey crateBindingsVodozemacCurve25519PublicKeyFromSlice({required U8Array32 bytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_8_array_32(bytes);
+            return wire.wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_curve_25519_public_key,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacCurve25519PublicKeyFromSliceConstMeta,
+            argValues: [bytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacCurve25519PublicKeyFromSliceConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_curve_25519_public_key_from_slice",
+            argNames: ["bytes"],
+        );
+        
+
+@override String crateBindingsVodozemacCurve25519PublicKeyToBase64({required VodozemacCurve25519PublicKey that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(that);
+            return wire.wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacCurve25519PublicKeyToBase64ConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacCurve25519PublicKeyToBase64ConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_curve_25519_public_key_to_base64",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array32 crateBindingsVodozemacEd25519PublicKeyAsBytes({required VodozemacEd25519PublicKey that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_ed_25519_public_key(that);
+            return wire.wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEd25519PublicKeyAsBytesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEd25519PublicKeyAsBytesConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_ed_25519_public_key_as_bytes",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacEd25519PublicKey crateBindingsVodozemacEd25519PublicKeyFromBase64({required String base64Key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(base64Key);
+            return wire.wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_ed_25519_public_key,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEd25519PublicKeyFromBase64ConstMeta,
+            argValues: [base64Key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEd25519PublicKeyFromBase64ConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_ed_25519_public_key_from_base64",
+            argNames: ["base64Key"],
+        );
+        
+
+@override VodozemacEd25519PublicKey crateBindingsVodozemacEd25519PublicKeyFromSlice({required U8Array32 bytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_8_array_32(bytes);
+            return wire.wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_ed_25519_public_key,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEd25519PublicKeyFromSliceConstMeta,
+            argValues: [bytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEd25519PublicKeyFromSliceConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_ed_25519_public_key_from_slice",
+            argNames: ["bytes"],
+        );
+        
+
+@override String crateBindingsVodozemacEd25519PublicKeyToBase64({required VodozemacEd25519PublicKey that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_ed_25519_public_key(that);
+            return wire.wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEd25519PublicKeyToBase64ConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEd25519PublicKeyToBase64ConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_ed_25519_public_key_to_base64",
+            argNames: ["that"],
+        );
+        
+
+@override void crateBindingsVodozemacEd25519PublicKeyVerify({required VodozemacEd25519PublicKey that , required String message , required VodozemacEd25519Signature signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_ed_25519_public_key(that);
+var arg1 = cst_encode_String(message);
+var arg2 = cst_encode_box_autoadd_vodozemac_ed_25519_signature(signature);
+            return wire.wire__crate__bindings__vodozemac_ed_25519_public_key_verify(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEd25519PublicKeyVerifyConstMeta,
+            argValues: [that, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEd25519PublicKeyVerifyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_ed_25519_public_key_verify",
+            argNames: ["that", "message", "signature"],
+        );
+        
+
+@override VodozemacEd25519Signature crateBindingsVodozemacEd25519SignatureFromBase64({required String signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(signature);
+            return wire.wire__crate__bindings__vodozemac_ed_25519_signature_from_base64(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_ed_25519_signature,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEd25519SignatureFromBase64ConstMeta,
+            argValues: [signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEd25519SignatureFromBase64ConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_ed_25519_signature_from_base64",
+            argNames: ["signature"],
+        );
+        
+
+@override VodozemacEd25519Signature crateBindingsVodozemacEd25519SignatureFromSlice({required U8Array64 bytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_8_array_64(bytes);
+            return wire.wire__crate__bindings__vodozemac_ed_25519_signature_from_slice(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_ed_25519_signature,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEd25519SignatureFromSliceConstMeta,
+            argValues: [bytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEd25519SignatureFromSliceConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_ed_25519_signature_from_slice",
+            argNames: ["bytes"],
+        );
+        
+
+@override String crateBindingsVodozemacEd25519SignatureToBase64({required VodozemacEd25519Signature that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_ed_25519_signature(that);
+            return wire.wire__crate__bindings__vodozemac_ed_25519_signature_to_base64(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEd25519SignatureToBase64ConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEd25519SignatureToBase64ConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_ed_25519_signature_to_base64",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array64 crateBindingsVodozemacEd25519SignatureToBytes({required VodozemacEd25519Signature that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_ed_25519_signature(that);
+            return wire.wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEd25519SignatureToBytesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEd25519SignatureToBytesConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_ed_25519_signature_to_bytes",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsVodozemacEstablishedSasCalculateMac({required VodozemacEstablishedSas that , required String input , required String info })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_established_sas(that);
+var arg1 = cst_encode_String(input);
+var arg2 = cst_encode_String(info);
+            return wire.wire__crate__bindings__vodozemac_established_sas_calculate_mac(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEstablishedSasCalculateMacConstMeta,
+            argValues: [that, input, info],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEstablishedSasCalculateMacConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_established_sas_calculate_mac",
+            argNames: ["that", "input", "info"],
+        );
+        
+
+@override String crateBindingsVodozemacEstablishedSasCalculateMacDeprecated({required VodozemacEstablishedSas that , required String input , required String info })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_established_sas(that);
+var arg1 = cst_encode_String(input);
+var arg2 = cst_encode_String(info);
+            return wire.wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEstablishedSasCalculateMacDeprecatedConstMeta,
+            argValues: [that, input, info],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEstablishedSasCalculateMacDeprecatedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_established_sas_calculate_mac_deprecated",
+            argNames: ["that", "input", "info"],
+        );
+        
+
+@override Uint8List crateBindingsVodozemacEstablishedSasGenerateBytes({required VodozemacEstablishedSas that , required String info , required int length })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_established_sas(that);
+var arg1 = cst_encode_String(info);
+var arg2 = cst_encode_u_32(length);
+            return wire.wire__crate__bindings__vodozemac_established_sas_generate_bytes(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_u_8_strict,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEstablishedSasGenerateBytesConstMeta,
+            argValues: [that, info, length],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEstablishedSasGenerateBytesConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_established_sas_generate_bytes",
+            argNames: ["that", "info", "length"],
+        );
+        
+
+@override void crateBindingsVodozemacEstablishedSasVerifyMac({required VodozemacEstablishedSas that , required String input , required String info , required String mac })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_established_sas(that);
+var arg1 = cst_encode_String(input);
+var arg2 = cst_encode_String(info);
+var arg3 = cst_encode_String(mac);
+            return wire.wire__crate__bindings__vodozemac_established_sas_verify_mac(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacEstablishedSasVerifyMacConstMeta,
+            argValues: [that, input, info, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacEstablishedSasVerifyMacConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_established_sas_verify_mac",
+            argNames: ["that", "input", "info", "mac"],
+        );
+        
+
+@override String crateBindingsVodozemacGroupSessionEncrypt({required VodozemacGroupSession that , required String plaintext })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
+var arg1 = cst_encode_String(plaintext);
+            return wire.wire__crate__bindings__vodozemac_group_session_encrypt(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionEncryptConstMeta,
+            argValues: [that, plaintext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionEncryptConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_encrypt",
+            argNames: ["that", "plaintext"],
+        );
+        
+
+@override UnpickledGroupSession crateBindingsVodozemacGroupSessionFromAnyPickle({required String pickle , required List<int> pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_group_session_from_any_pickle(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unpickled_group_session,
+          decodeErrorData: dco_decode_pickle_format_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionFromAnyPickleConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionFromAnyPickleConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_from_any_pickle",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacGroupSession crateBindingsVodozemacGroupSessionFromOlmPickleEncrypted({required String pickle , required List<int> pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_group_session,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionFromOlmPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionFromOlmPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_from_olm_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacGroupSession crateBindingsVodozemacGroupSessionFromPickleEncrypted({required String pickle , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_group_session,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionFromPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionFromPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_from_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacGroupSession crateBindingsVodozemacGroupSessionFromPickleWithPassphrase({required String pickle , required String passphrase })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_String(passphrase);
+            return wire.wire__crate__bindings__vodozemac_group_session_from_pickle_with_passphrase(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_group_session,
+          decodeErrorData: dco_decode_passphrase_pickle_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionFromPickleWithPassphraseConstMeta,
+            argValues: [pickle, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionFromPickleWithPassphraseConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_from_pickle_with_passphrase",
+            argNames: ["pickle", "passphrase"],
+        );
+        
+
+@override int crateBindingsVodozemacGroupSessionMessageIndex({required VodozemacGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
+            return wire.wire__crate__bindings__vodozemac_group_session_message_index(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionMessageIndexConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionMessageIndexConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_message_index",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacGroupSession crateBindingsVodozemacGroupSessionNew({required VodozemacMegolmSessionConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_megolm_session_config(config);
+            return wire.wire__crate__bindings__vodozemac_group_session_new(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_group_session,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionNewConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionNewConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_new",
+            argNames: ["config"],
+        );
+        
+
+@override String crateBindingsVodozemacGroupSessionPickleEncrypted({required VodozemacGroupSession that , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_group_session_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionPickleEncryptedConstMeta,
+            argValues: [that, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_pickle_encrypted",
+            argNames: ["that", "pickleKey"],
+        );
+        
+
+@override String crateBindingsVodozemacGroupSessionPickleWithPassphrase({required VodozemacGroupSession that , required String passphrase , required PassphraseKdfParams params })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
+var arg1 = cst_encode_String(passphrase);
+var arg2 = cst_encode_box_autoadd_passphrase_kdf_params(params);
+            return wire.wire__crate__bindings__vodozemac_group_session_pickle_with_passphrase(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_passphrase_pickle_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionPickleWithPassphraseConstMeta,
+            argValues: [that, passphrase, params],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionPickleWithPassphraseConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_pickle_with_passphrase",
+            argNames: ["that", "passphrase", "params"],
+        );
+        
+
+@override VodozemacMegolmSessionConfig crateBindingsVodozemacGroupSessionSessionConfig({required VodozemacGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
+            return wire.wire__crate__bindings__vodozemac_group_session_session_config(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_megolm_session_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionSessionConfigConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionSessionConfigConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_session_config",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsVodozemacGroupSessionSessionId({required VodozemacGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
+            return wire.wire__crate__bindings__vodozemac_group_session_session_id(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionSessionIdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionSessionIdConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_session_id",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsVodozemacGroupSessionSessionKey({required VodozemacGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
+            return wire.wire__crate__bindings__vodozemac_group_session_session_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionSessionKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionSessionKeyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_session_key",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacInboundGroupSession crateBindingsVodozemacGroupSessionToInbound({required VodozemacGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_group_session(that);
+            return wire.wire__crate__bindings__vodozemac_group_session_to_inbound(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_inbound_group_session,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacGroupSessionToInboundConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacGroupSessionToInboundConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_group_session_to_inbound",
+            argNames: ["that"],
+        );
+        
+
+@override DecryptResult crateBindingsVodozemacInboundGroupSessionDecrypt({required VodozemacInboundGroupSession that , required String encrypted })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
+var arg1 = cst_encode_String(encrypted);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_decrypt(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_decrypt_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionDecryptConstMeta,
+            argValues: [that, encrypted],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionDecryptConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_decrypt",
+            argNames: ["that", "encrypted"],
+        );
+        
+
+@override ExtendedDecryptResult crateBindingsVodozemacInboundGroupSessionDecryptExtended({required VodozemacInboundGroupSession that , required String encrypted })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
+var arg1 = cst_encode_String(encrypted);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_decrypt_extended(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_extended_decrypt_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionDecryptExtendedConstMeta,
+            argValues: [that, encrypted],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionDecryptExtendedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_decrypt_extended",
+            argNames: ["that", "encrypted"],
+        );
+        
+
+@override bool crateBindingsVodozemacInboundGroupSessionDiscardBefore({required VodozemacInboundGroupSession that , required int index })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
+var arg1 = cst_encode_u_32(index);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_discard_before(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionDiscardBeforeConstMeta,
+            argValues: [that, index],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionDiscardBeforeConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_discard_before",
+            argNames: ["that", "index"],
+        );
+        
+
+@override String? crateBindingsVodozemacInboundGroupSessionExportAt({required VodozemacInboundGroupSession that , required int index })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
+var arg1 = cst_encode_u_32(index);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_export_at(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionExportAtConstMeta,
+            argValues: [that, index],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionExportAtConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_export_at",
+            argNames: ["that", "index"],
+        );
+        
+
+@override String crateBindingsVodozemacInboundGroupSessionExportAtFirstKnownIndex({required VodozemacInboundGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionExportAtFirstKnownIndexConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionExportAtFirstKnownIndexConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_export_at_first_known_index",
+            argNames: ["that"],
+        );
+        
+
+@override int crateBindingsVodozemacInboundGroupSessionFirstKnownIndex({required VodozemacInboundGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_first_known_index(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionFirstKnownIndexConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionFirstKnownIndexConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_first_known_index",
+            argNames: ["that"],
+        );
+        
+
+@override UnpickledInboundGroupSession crateBindingsVodozemacInboundGroupSessionFromAnyPickle({required String pickle , required List<int> pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_from_any_pickle(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unpickled_inbound_group_session,
+          decodeErrorData: dco_decode_pickle_format_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionFromAnyPickleConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionFromAnyPickleConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_from_any_pickle",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionFromOlmPickleEncrypted({required String pickle , required List<int> pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_inbound_group_session,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionFromOlmPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionFromOlmPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_from_olm_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionFromPickleEncrypted({required String pickle , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_inbound_group_session,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionFromPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionFromPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_from_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionFromPickleWithPassphrase({required String pickle , required String passphrase })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_String(passphrase);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_with_passphrase(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_inbound_group_session,
+          decodeErrorData: dco_decode_passphrase_pickle_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionFromPickleWithPassphraseConstMeta,
+            argValues: [pickle, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionFromPickleWithPassphraseConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_from_pickle_with_passphrase",
+            argNames: ["pickle", "passphrase"],
+        );
+        
+
+@override VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionImport({required String exportedSessionKey , required VodozemacMegolmSessionConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(exportedSessionKey);
+var arg1 = cst_encode_box_autoadd_vodozemac_megolm_session_config(config);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_import(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_inbound_group_session,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionImportConstMeta,
+            argValues: [exportedSessionKey, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionImportConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_import",
+            argNames: ["exportedSessionKey", "config"],
+        );
+        
+
+@override SessionKeySource crateBindingsVodozemacInboundGroupSessionKeySource({required VodozemacInboundGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_key_source(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_session_key_source,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionKeySourceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionKeySourceConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_key_source",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacInboundGroupSession crateBindingsVodozemacInboundGroupSessionNew({required String sessionKey , required VodozemacMegolmSessionConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(sessionKey);
+var arg1 = cst_encode_box_autoadd_vodozemac_megolm_session_config(config);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_new(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_inbound_group_session,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionNewConstMeta,
+            argValues: [sessionKey, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionNewConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_new",
+            argNames: ["sessionKey", "config"],
+        );
+        
+
+@override String crateBindingsVodozemacInboundGroupSessionPickleEncrypted({required VodozemacInboundGroupSession that , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionPickleEncryptedConstMeta,
+            argValues: [that, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_pickle_encrypted",
+            argNames: ["that", "pickleKey"],
+        );
+        
+
+@override String crateBindingsVodozemacInboundGroupSessionPickleWithPassphrase({required VodozemacInboundGroupSession that , required String passphrase , required PassphraseKdfParams params })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
+var arg1 = cst_encode_String(passphrase);
+var arg2 = cst_encode_box_autoadd_passphrase_kdf_params(params);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_pickle_with_passphrase(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_passphrase_pickle_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionPickleWithPassphraseConstMeta,
+            argValues: [that, passphrase, params],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionPickleWithPassphraseConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_pickle_with_passphrase",
+            argNames: ["that", "passphrase", "params"],
+        );
+        
+
+@override String crateBindingsVodozemacInboundGroupSessionSessionId({required VodozemacInboundGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_session_id(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionSessionIdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionSessionIdConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_session_id",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacEd25519PublicKey crateBindingsVodozemacInboundGroupSessionSigningKey({required VodozemacInboundGroupSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_inbound_group_session(that);
+            return wire.wire__crate__bindings__vodozemac_inbound_group_session_signing_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_ed_25519_public_key,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacInboundGroupSessionSigningKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacInboundGroupSessionSigningKeyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_inbound_group_session_signing_key",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacMegolmSessionConfig crateBindingsVodozemacMegolmSessionConfigDef()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__vodozemac_megolm_session_config_def();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_megolm_session_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacMegolmSessionConfigDefConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacMegolmSessionConfigDefConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_megolm_session_config_def",
+            argNames: [],
+        );
+        
+
+@override int crateBindingsVodozemacMegolmSessionConfigVersion({required VodozemacMegolmSessionConfig that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_megolm_session_config(that);
+            return wire.wire__crate__bindings__vodozemac_megolm_session_config_version(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_8,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacMegolmSessionConfigVersionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacMegolmSessionConfigVersionConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_megolm_session_config_version",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacMegolmSessionConfig crateBindingsVodozemacMegolmSessionConfigVersion1()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__vodozemac_megolm_session_config_version_1();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_megolm_session_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacMegolmSessionConfigVersion1ConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacMegolmSessionConfigVersion1ConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_megolm_session_config_version_1",
+            argNames: [],
+        );
+        
+
+@override VodozemacMegolmSessionConfig crateBindingsVodozemacMegolmSessionConfigVersion2()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__vodozemac_megolm_session_config_version_2();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_megolm_session_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacMegolmSessionConfigVersion2ConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacMegolmSessionConfigVersion2ConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_megolm_session_config_version_2",
+            argNames: [],
+        );
+        
+
+@override VodozemacOlmMessage crateBindingsVodozemacOlmMessageFromParts({required BigInt messageType , required String ciphertext })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_usize(messageType);
+var arg1 = cst_encode_String(ciphertext);
+            return wire.wire__crate__bindings__vodozemac_olm_message_from_parts(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_olm_message,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacOlmMessageFromPartsConstMeta,
+            argValues: [messageType, ciphertext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacOlmMessageFromPartsConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_olm_message_from_parts",
+            argNames: ["messageType", "ciphertext"],
+        );
+        
+
+@override String crateBindingsVodozemacOlmMessageMessage({required VodozemacOlmMessage that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_olm_message(that);
+            return wire.wire__crate__bindings__vodozemac_olm_message_message(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacOlmMessageMessageConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacOlmMessageMessageConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_olm_message_message",
+            argNames: ["that"],
+        );
+        
+
+@override BigInt crateBindingsVodozemacOlmMessageMessageType({required VodozemacOlmMessage that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_olm_message(that);
+            return wire.wire__crate__bindings__vodozemac_olm_message_message_type(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_usize,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacOlmMessageMessageTypeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacOlmMessageMessageTypeConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_olm_message_message_type",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacOlmSessionConfig crateBindingsVodozemacOlmSessionConfigDef()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__vodozemac_olm_session_config_def();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_olm_session_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacOlmSessionConfigDefConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacOlmSessionConfigDefConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_olm_session_config_def",
+            argNames: [],
+        );
+        
+
+@override int crateBindingsVodozemacOlmSessionConfigVersion({required VodozemacOlmSessionConfig that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_olm_session_config(that);
+            return wire.wire__crate__bindings__vodozemac_olm_session_config_version(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_8,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacOlmSessionConfigVersionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacOlmSessionConfigVersionConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_olm_session_config_version",
+            argNames: ["that"],
+        );
+        
+
+@override VodozemacOlmSessionConfig crateBindingsVodozemacOlmSessionConfigVersion1()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__vodozemac_olm_session_config_version_1();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_olm_session_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacOlmSessionConfigVersion1ConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacOlmSessionConfigVersion1ConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_olm_session_config_version_1",
+            argNames: [],
+        );
+        
+
+@override VodozemacOlmSessionConfig crateBindingsVodozemacOlmSessionConfigVersion2()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__vodozemac_olm_session_config_version_2();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_olm_session_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacOlmSessionConfigVersion2ConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacOlmSessionConfigVersion2ConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_olm_session_config_version_2",
+            argNames: [],
+        );
+        
+
+@override String crateBindingsVodozemacPkDecryptionDecrypt({required VodozemacPkDecryption that , required VodozemacPkMessage message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_pk_decryption(that);
+var arg1 = cst_encode_box_autoadd_vodozemac_pk_message(message);
+            return wire.wire__crate__bindings__vodozemac_pk_decryption_decrypt(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkDecryptionDecryptConstMeta,
+            argValues: [that, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkDecryptionDecryptConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_decryption_decrypt",
+            argNames: ["that", "message"],
+        );
+        
+
+@override UnpickledPkDecryption crateBindingsVodozemacPkDecryptionFromAnyPickle({required String pickle , required List<int> pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_pk_decryption_from_any_pickle(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unpickled_pk_decryption,
+          decodeErrorData: dco_decode_pickle_format_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkDecryptionFromAnyPickleConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkDecryptionFromAnyPickleConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_decryption_from_any_pickle",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacPkDecryption crateBindingsVodozemacPkDecryptionFromKey({required U8Array32 secretKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_8_array_32(secretKey);
+            return wire.wire__crate__bindings__vodozemac_pk_decryption_from_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_pk_decryption,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkDecryptionFromKeyConstMeta,
+            argValues: [secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkDecryptionFromKeyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_decryption_from_key",
+            argNames: ["secretKey"],
+        );
+        
+
+@override VodozemacPkDecryption crateBindingsVodozemacPkDecryptionFromLibolmPickle({required String pickle , required List<int> pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_pk_decryption,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkDecryptionFromLibolmPickleConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkDecryptionFromLibolmPickleConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_decryption_from_libolm_pickle",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacPkDecryption crateBindingsVodozemacPkDecryptionFromPickleWithPassphrase({required String pickle , required String passphrase })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_String(passphrase);
+            return wire.wire__crate__bindings__vodozemac_pk_decryption_from_pickle_with_passphrase(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_pk_decryption,
+          decodeErrorData: dco_decode_passphrase_pickle_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkDecryptionFromPickleWithPassphraseConstMeta,
+            argValues: [pickle, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkDecryptionFromPickleWithPassphraseConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_decryption_from_pickle_with_passphrase",
+            argNames: ["pickle", "passphrase"],
+        );
+        
+
+@override VodozemacPkDecryption crateBindingsVodozemacPkDecryptionNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__bindings__vodozemac_pk_decryption_new();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_pk_decryption,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkDecryptionNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkDecryptionNewConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_decryption_new",
+            argNames: [],
+        );
+        
+
+@override String crateBindingsVodozemacPkDecryptionPickleWithPassphrase({required VodozemacPkDecryption that , required String passphrase , required PassphraseKdfParams params })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_pk_decryption(that);
+var arg1 = cst_encode_String(passphrase);
+var arg2 = cst_encode_box_autoadd_passphrase_kdf_params(params);
+            return wire.wire__crate__bindings__vodozemac_pk_decryption_pickle_with_passphrase(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_passphrase_pickle_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkDecryptionPickleWithPassphraseConstMeta,
+            argValues: [that, passphrase, params],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkDecryptionPickleWithPassphraseConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_decryption_pickle_with_passphrase",
+            argNames: ["that", "passphrase", "params"],
+        );
+        
+
+@override Uint8List crateBindingsVodozemacPkDecryptionPrivateKey({required VodozemacPkDecryption that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_pk_decryption(that);
+            return wire.wire__crate__bindings__vodozemac_pk_decryption_private_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkDecryptionPrivateKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkDecryptionPrivateKeyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_decryption_private_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsVodozemacPkDecryptionPublicKey({required VodozemacPkDecryption that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_pk_decryption(that);
+            return wire.wire__crate__bindings__vodozemac_pk_decryption_public_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkDecryptionPublicKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkDecryptionPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_decryption_public_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsVodozemacPkDecryptionToLibolmPickle({required VodozemacPkDecryption that , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_pk_decryption(that);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkDecryptionToLibolmPickleConstMeta,
+            argValues: [that, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkDecryptionToLibolmPickleConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_decryption_to_libolm_pickle",
+            argNames: ["that", "pickleKey"],
+        );
+        
+
+@override VodozemacPkMessage crateBindingsVodozemacPkEncryptionEncrypt({required VodozemacPkEncryption that , required String message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_pk_encryption(that);
+var arg1 = cst_encode_String(message);
+            return wire.wire__crate__bindings__vodozemac_pk_encryption_encrypt(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_pk_message,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkEncryptionEncryptConstMeta,
+            argValues: [that, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkEncryptionEncryptConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_encryption_encrypt",
+            argNames: ["that", "message"],
+        );
+        
+
+@override VodozemacPkEncryption crateBindingsVodozemacPkEncryptionFromKey({required VodozemacCurve25519PublicKey publicKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(publicKey);
+            return wire.wire__crate__bindings__vodozemac_pk_encryption_from_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_pk_encryption,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkEncryptionFromKeyConstMeta,
+            argValues: [publicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkEncryptionFromKeyConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_encryption_from_key",
+            argNames: ["publicKey"],
+        );
+        
+
+@override VodozemacPkMessage crateBindingsVodozemacPkMessageFromBase64({required String ciphertext , required String mac , required String ephemeralKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(ciphertext);
+var arg1 = cst_encode_String(mac);
+var arg2 = cst_encode_String(ephemeralKey);
+            return wire.wire__crate__bindings__vodozemac_pk_message_from_base64(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_pk_message,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkMessageFromBase64ConstMeta,
+            argValues: [ciphertext, mac, ephemeralKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkMessageFromBase64ConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_message_from_base64",
+            argNames: ["ciphertext", "mac", "ephemeralKey"],
+        );
+        
+
+@override VodozemacPkMessage crateBindingsVodozemacPkMessageNew({required List<int> ciphertext , required List<int> mac , required VodozemacCurve25519PublicKey ephemeralKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_list_prim_u_8_loose(ciphertext);
+var arg1 = cst_encode_list_prim_u_8_loose(mac);
+var arg2 = cst_encode_box_autoadd_vodozemac_curve_25519_public_key(ephemeralKey);
+            return wire.wire__crate__bindings__vodozemac_pk_message_new(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_pk_message,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkMessageNewConstMeta,
+            argValues: [ciphertext, mac, ephemeralKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkMessageNewConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_message_new",
+            argNames: ["ciphertext", "mac", "ephemeralKey"],
+        );
+        
+
+@override (String,String,String) crateBindingsVodozemacPkMessageToBase64({required VodozemacPkMessage that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_pk_message(that);
+            return wire.wire__crate__bindings__vodozemac_pk_message_to_base64(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_record_string_string_string,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacPkMessageToBase64ConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacPkMessageToBase64ConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_pk_message_to_base64",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsVodozemacSessionDecrypt({required VodozemacSession that , required VodozemacOlmMessage message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
+var arg1 = cst_encode_box_autoadd_vodozemac_olm_message(message);
+            return wire.wire__crate__bindings__vodozemac_session_decrypt(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSessionDecryptConstMeta,
+            argValues: [that, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSessionDecryptConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_session_decrypt",
+            argNames: ["that", "message"],
+        );
+        
+
+@override VodozemacOlmMessage crateBindingsVodozemacSessionEncrypt({required VodozemacSession that , required String plaintext })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
+var arg1 = cst_encode_String(plaintext);
+            return wire.wire__crate__bindings__vodozemac_session_encrypt(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_olm_message,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSessionEncryptConstMeta,
+            argValues: [that, plaintext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSessionEncryptConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_session_encrypt",
+            argNames: ["that", "plaintext"],
+        );
+        
+
+@override UnpickledSession crateBindingsVodozemacSessionFromAnyPickle({required String pickle , required List<int> pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_session_from_any_pickle(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unpickled_session,
+          decodeErrorData: dco_decode_pickle_format_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSessionFromAnyPickleConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSessionFromAnyPickleConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_session_from_any_pickle",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacSession crateBindingsVodozemacSessionFromOlmPickleEncrypted({required String pickle , required List<int> pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_list_prim_u_8_loose(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_session,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSessionFromOlmPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSessionFromOlmPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_session_from_olm_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacSession crateBindingsVodozemacSessionFromPickleEncrypted({required String pickle , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_session_from_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_session,
+          decodeErrorData: dco_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSessionFromPickleEncryptedConstMeta,
+            argValues: [pickle, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSessionFromPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_session_from_pickle_encrypted",
+            argNames: ["pickle", "pickleKey"],
+        );
+        
+
+@override VodozemacSession crateBindingsVodozemacSessionFromPickleWithPassphrase({required String pickle , required String passphrase })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(pickle);
+var arg1 = cst_encode_String(passphrase);
+            return wire.wire__crate__bindings__vodozemac_session_from_pickle_with_passphrase(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_session,
+          decodeErrorData: dco_decode_passphrase_pickle_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSessionFromPickleWithPassphraseConstMeta,
+            argValues: [pickle, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSessionFromPickleWithPassphraseConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_session_from_pickle_with_passphrase",
+            argNames: ["pickle", "passphrase"],
+        );
+        
+
+@override bool crateBindingsVodozemacSessionHasReceivedMessage({required VodozemacSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
+            return wire.wire__crate__bindings__vodozemac_session_has_received_message(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSessionHasReceivedMessageConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSessionHasReceivedMessageConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_session_has_received_message",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsVodozemacSessionPickleEncrypted({required VodozemacSession that , required U8Array32 pickleKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
+var arg1 = cst_encode_u_8_array_32(pickleKey);
+            return wire.wire__crate__bindings__vodozemac_session_pickle_encrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSessionPickleEncryptedConstMeta,
+            argValues: [that, pickleKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSessionPickleEncryptedConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_session_pickle_encrypted",
+            argNames: ["that", "pickleKey"],
+        );
+        
+
+@override String crateBindingsVodozemacSessionPickleWithPassphrase({required VodozemacSession that , required String passphrase , required PassphraseKdfParams params })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
+var arg1 = cst_encode_String(passphrase);
+var arg2 = cst_encode_box_autoadd_passphrase_kdf_params(params);
+            return wire.wire__crate__bindings__vodozemac_session_pickle_with_passphrase(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_passphrase_pickle_error,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSessionPickleWithPassphraseConstMeta,
+            argValues: [that, passphrase, params],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSessionPickleWithPassphraseConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_session_pickle_with_passphrase",
+            argNames: ["that", "passphrase", "params"],
+        );
+        
+
+@override VodozemacOlmSessionConfig crateBindingsVodozemacSessionSessionConfig({required VodozemacSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
+            return wire.wire__crate__bindings__vodozemac_session_session_config(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_vodozemac_olm_session_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSessionSessionConfigConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSessionSessionConfigConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_session_session_config",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsVodozemacSessionSessionId({required VodozemacSession that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_vodozemac_session(that);
+            return wire.wire__crate__bindings__vodozemac_session_session_id(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsVodozemacSessionSessionIdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsVodozemacSessionSessionIdConstMeta => const TaskConstMeta(
+            debugName: "vodozemac_session_session_id",
+            argNames: ["that"],
+        );
+        
+
+@override String crateBindingsShareTrackingWithheldCodeCode({required WithheldCode that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_withheld_code(that);
+            return wire.wire__crate__bindings__share_tracking__withheld_code_code(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsShareTrackingWithheldCodeCodeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsShareTrackingWithheldCodeCodeConstMeta => const TaskConstMeta(
+            debugName: "withheld_code_code",
+            argNames: ["that"],
+        );
+        
+
+@override WithheldCode? crateBindingsShareTrackingWithheldCodeFromCode({required String code })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(code);
+            return wire.wire__crate__bindings__share_tracking__withheld_code_from_code(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_withheld_code,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBindingsShareTrackingWithheldCodeFromCodeConstMeta,
+            argValues: [code],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBindingsShareTrackingWithheldCodeFromCodeConstMeta => const TaskConstMeta(
+            debugName: "withheld_code_from_code",
+            argNames: ["code"],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Curve25519PublicKey => wire.rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Curve25519PublicKey => wire.rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Ed25519PublicKey => wire.rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Ed25519PublicKey => wire.rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Ed25519Signature => wire.rust_arc_increment_strong_count_RustOpaque_Ed25519Signature;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Ed25519Signature => wire.rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_EstablishedSas => wire.rust_arc_increment_strong_count_RustOpaque_EstablishedSas;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_EstablishedSas => wire.rust_arc_decrement_strong_count_RustOpaque_EstablishedSas;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MegolmSessionConfig => wire.rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MegolmSessionConfig => wire.rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_OlmMessage => wire.rust_arc_increment_strong_count_RustOpaque_OlmMessage;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_OlmMessage => wire.rust_arc_decrement_strong_count_RustOpaque_OlmMessage;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_OlmSessionConfig => wire.rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_OlmSessionConfig => wire.rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PkDecryption => wire.rust_arc_increment_strong_count_RustOpaque_PkDecryption;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PkDecryption => wire.rust_arc_decrement_strong_count_RustOpaque_PkDecryption;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PkEncryption => wire.rust_arc_increment_strong_count_RustOpaque_PkEncryption;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PkEncryption => wire.rust_arc_decrement_strong_count_RustOpaque_PkEncryption;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockFallbackKeyState => wire.rust_arc_increment_strong_count_RustOpaque_RwLockFallbackKeyState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockFallbackKeyState => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockFallbackKeyState;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockGroupSession => wire.rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockGroupSession => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockInboundGroupSession => wire.rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockInboundGroupSession => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockReplayState => wire.rust_arc_increment_strong_count_RustOpaque_RwLockReplayState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockReplayState => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockReplayState;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockRoomKeyInfo => wire.rust_arc_increment_strong_count_RustOpaque_RwLockRoomKeyInfo;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockRoomKeyInfo => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockRoomKeyInfo;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockRotationState => wire.rust_arc_increment_strong_count_RustOpaque_RwLockRotationState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockRotationState => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockRotationState;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockSession => wire.rust_arc_increment_strong_count_RustOpaque_RwLockSession;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockSession => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockSession;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockSessionKeySource => wire.rust_arc_increment_strong_count_RustOpaque_RwLockSessionKeySource;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockSessionKeySource => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockSessionKeySource;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockSessionStoreState => wire.rust_arc_increment_strong_count_RustOpaque_RwLockSessionStoreState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockSessionStoreState => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockSessionStoreState;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockShareState => wire.rust_arc_increment_strong_count_RustOpaque_RwLockShareState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockShareState => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockShareState;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockUnwedgeState => wire.rust_arc_increment_strong_count_RustOpaque_RwLockUnwedgeState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockUnwedgeState => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockUnwedgeState;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockUtdQueueState => wire.rust_arc_increment_strong_count_RustOpaque_RwLockUtdQueueState;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockUtdQueueState => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockUtdQueueState;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CrossSigningIdentity => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CrossSigningIdentity => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PkSigning => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PkSigning => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_VodozemacSas => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_VodozemacSas => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RwLockAccount => wire.rust_arc_increment_strong_count_RustOpaque_stdsyncRwLockAccount;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RwLockAccount => wire.rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected CrossSigningIdentity dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CrossSigningIdentityImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PkSigning dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PkSigningImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected VodozemacSas dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VodozemacSasImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CrossSigningIdentity dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CrossSigningIdentityImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PkSigning dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PkSigningImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected VodozemacSas dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VodozemacSasImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Map<String, int> dco_decode_Map_String_u_32_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_u_32(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Curve25519PublicKey dco_decode_RustOpaque_Curve25519PublicKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Curve25519PublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Ed25519PublicKey dco_decode_RustOpaque_Ed25519PublicKey(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Ed25519PublicKeyImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Ed25519Signature dco_decode_RustOpaque_Ed25519Signature(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Ed25519SignatureImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected EstablishedSas dco_decode_RustOpaque_EstablishedSas(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return EstablishedSasImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MegolmSessionConfig dco_decode_RustOpaque_MegolmSessionConfig(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MegolmSessionConfigImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected OlmMessage dco_decode_RustOpaque_OlmMessage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OlmMessageImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected OlmSessionConfig dco_decode_RustOpaque_OlmSessionConfig(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OlmSessionConfigImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PkDecryption dco_decode_RustOpaque_PkDecryption(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PkDecryptionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PkEncryption dco_decode_RustOpaque_PkEncryption(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PkEncryptionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockFallbackKeyState dco_decode_RustOpaque_RwLockFallbackKeyState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockFallbackKeyStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockGroupSession dco_decode_RustOpaque_RwLockGroupSession(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockGroupSessionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockInboundGroupSession dco_decode_RustOpaque_RwLockInboundGroupSession(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockInboundGroupSessionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockReplayState dco_decode_RustOpaque_RwLockReplayState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockReplayStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockRoomKeyInfo dco_decode_RustOpaque_RwLockRoomKeyInfo(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockRoomKeyInfoImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockRotationState dco_decode_RustOpaque_RwLockRotationState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockRotationStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockSession dco_decode_RustOpaque_RwLockSession(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockSessionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockSessionKeySource dco_decode_RustOpaque_RwLockSessionKeySource(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockSessionKeySourceImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockSessionStoreState dco_decode_RustOpaque_RwLockSessionStoreState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockSessionStoreStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockShareState dco_decode_RustOpaque_RwLockShareState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockShareStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockUnwedgeState dco_decode_RustOpaque_RwLockUnwedgeState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockUnwedgeStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockUtdQueueState dco_decode_RustOpaque_RwLockUtdQueueState(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockUtdQueueStateImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CrossSigningIdentity dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CrossSigningIdentityImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PkSigning dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PkSigningImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected VodozemacSas dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VodozemacSasImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RwLockAccount dco_decode_RustOpaque_stdsyncRwLockAccount(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RwLockAccountImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<RekeyProgress> dco_decode_StreamSink_rekey_progress_Dco(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected DeviceKeys dco_decode_box_autoadd_device_keys(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_device_keys(raw); }
+
+@protected EncryptedRoomKeyBundle dco_decode_box_autoadd_encrypted_room_key_bundle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_encrypted_room_key_bundle(raw); }
+
+@protected FallbackKeyTracker dco_decode_box_autoadd_fallback_key_tracker(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_fallback_key_tracker(raw); }
+
+@protected InboundRoomKey dco_decode_box_autoadd_inbound_room_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_inbound_room_key(raw); }
+
+@protected LibolmStore dco_decode_box_autoadd_libolm_store(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_libolm_store(raw); }
+
+@protected MegolmReplayGuard dco_decode_box_autoadd_megolm_replay_guard(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_megolm_replay_guard(raw); }
+
+@protected MigratedPickle dco_decode_box_autoadd_migrated_pickle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_migrated_pickle(raw); }
+
+@protected MigrationReport dco_decode_box_autoadd_migration_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_migration_report(raw); }
+
+@protected OlmPayload dco_decode_box_autoadd_olm_payload(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_olm_payload(raw); }
+
+@protected OlmSessionStore dco_decode_box_autoadd_olm_session_store(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_olm_session_store(raw); }
+
+@protected OutboundGroupSession dco_decode_box_autoadd_outbound_group_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_outbound_group_session(raw); }
+
+@protected PassphraseKdfParams dco_decode_box_autoadd_passphrase_kdf_params(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_passphrase_kdf_params(raw); }
+
+@protected PickleFormatError dco_decode_box_autoadd_pickle_format_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_pickle_format_error(raw); }
+
+@protected PickleInfo dco_decode_box_autoadd_pickle_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_pickle_info(raw); }
+
+@protected RetainedRoomKey dco_decode_box_autoadd_retained_room_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_retained_room_key(raw); }
+
+@protected RoomKeyBundleDownload dco_decode_box_autoadd_room_key_bundle_download(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_room_key_bundle_download(raw); }
+
+@protected RoomKeyInfo dco_decode_box_autoadd_room_key_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_room_key_info(raw); }
+
+@protected RotationReason dco_decode_box_autoadd_rotation_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_rotation_reason(raw); }
+
+@protected ShareRecipient dco_decode_box_autoadd_share_recipient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_share_recipient(raw); }
+
+@protected TrustLink dco_decode_box_autoadd_trust_link(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_trust_link(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected UnwedgeTracker dco_decode_box_autoadd_unwedge_tracker(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_unwedge_tracker(raw); }
+
+@protected UtdClassification dco_decode_box_autoadd_utd_classification(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_utd_classification(raw); }
+
+@protected UtdQueue dco_decode_box_autoadd_utd_queue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_utd_queue(raw); }
+
+@protected VodozemacAccount dco_decode_box_autoadd_vodozemac_account(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_account(raw); }
+
+@protected VodozemacCurve25519PublicKey dco_decode_box_autoadd_vodozemac_curve_25519_public_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_curve_25519_public_key(raw); }
+
+@protected VodozemacEd25519PublicKey dco_decode_box_autoadd_vodozemac_ed_25519_public_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_ed_25519_public_key(raw); }
+
+@protected VodozemacEd25519Signature dco_decode_box_autoadd_vodozemac_ed_25519_signature(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_ed_25519_signature(raw); }
+
+@protected VodozemacEstablishedSas dco_decode_box_autoadd_vodozemac_established_sas(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_established_sas(raw); }
+
+@protected VodozemacGroupSession dco_decode_box_autoadd_vodozemac_group_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_group_session(raw); }
+
+@protected VodozemacInboundGroupSession dco_decode_box_autoadd_vodozemac_inbound_group_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_inbound_group_session(raw); }
+
+@protected VodozemacMegolmSessionConfig dco_decode_box_autoadd_vodozemac_megolm_session_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_megolm_session_config(raw); }
+
+@protected VodozemacOlmMessage dco_decode_box_autoadd_vodozemac_olm_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_olm_message(raw); }
+
+@protected VodozemacOlmSessionConfig dco_decode_box_autoadd_vodozemac_olm_session_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_olm_session_config(raw); }
+
+@protected VodozemacPkDecryption dco_decode_box_autoadd_vodozemac_pk_decryption(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_pk_decryption(raw); }
+
+@protected VodozemacPkEncryption dco_decode_box_autoadd_vodozemac_pk_encryption(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_pk_encryption(raw); }
+
+@protected VodozemacPkMessage dco_decode_box_autoadd_vodozemac_pk_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_pk_message(raw); }
+
+@protected VodozemacSession dco_decode_box_autoadd_vodozemac_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vodozemac_session(raw); }
+
+@protected WithheldCode dco_decode_box_autoadd_withheld_code(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_withheld_code(raw); }
+
+@protected ClaimedSession dco_decode_claimed_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ClaimedSession(session: dco_decode_vodozemac_session(arr[0]),
+fallback: dco_decode_bool(arr[1]),); }
+
+@protected DecryptResult dco_decode_decrypt_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DecryptResult(field0: dco_decode_String(arr[0]),
+field1: dco_decode_u_32(arr[1]),); }
+
+@protected DeviceKeys dco_decode_device_keys(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return DeviceKeys(userId: dco_decode_String(arr[0]),
+deviceId: dco_decode_String(arr[1]),
+algorithms: dco_decode_list_String(arr[2]),
+ed25519: dco_decode_vodozemac_ed_25519_public_key(arr[3]),
+curve25519: dco_decode_vodozemac_curve_25519_public_key(arr[4]),
+displayName: dco_decode_opt_String(arr[5]),); }
+
+@protected DeviceKeysError dco_decode_device_keys_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DeviceKeysError.values[raw as int]; }
+
+@protected DeviceTrust dco_decode_device_trust(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DeviceTrust(userId: dco_decode_String(arr[0]),
+deviceId: dco_decode_String(arr[1]),
+level: dco_decode_device_trust_level(arr[2]),
+failedLink: dco_decode_opt_box_autoadd_trust_link(arr[3]),); }
+
+@protected DeviceTrustLevel dco_decode_device_trust_level(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DeviceTrustLevel.values[raw as int]; }
+
+@protected EncryptedRoomKeyBundle dco_decode_encrypted_room_key_bundle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return EncryptedRoomKeyBundle(roomId: dco_decode_String(arr[0]),
+ciphertext: dco_decode_list_prim_u_8_strict(arr[1]),
+file: dco_decode_String(arr[2]),); }
+
+@protected ExtendedDecryptResult dco_decode_extended_decrypt_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ExtendedDecryptResult(plaintext: dco_decode_String(arr[0]),
+messageIndex: dco_decode_u_32(arr[1]),
+signingKey: dco_decode_vodozemac_ed_25519_public_key(arr[2]),
+keySource: dco_decode_session_key_source(arr[3]),); }
+
+@protected FallbackKeyTracker dco_decode_fallback_key_tracker(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return FallbackKeyTracker.raw(state: dco_decode_RustOpaque_RwLockFallbackKeyState(arr[0]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected ImportedRoomKeyBundle dco_decode_imported_room_key_bundle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ImportedRoomKeyBundle(roomKeys: dco_decode_list_inbound_room_key(arr[0]),
+skipped: dco_decode_u_32(arr[1]),); }
+
+@protected InboundRoomKey dco_decode_inbound_room_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return InboundRoomKey.raw(session: dco_decode_RustOpaque_RwLockInboundGroupSession(arr[0]),
+source: dco_decode_RustOpaque_RwLockSessionKeySource(arr[1]),
+keyInfo: dco_decode_RustOpaque_RwLockRoomKeyInfo(arr[2]),); }
+
+@protected KeyAuthenticity dco_decode_key_authenticity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return KeyAuthenticity.values[raw as int]; }
+
+@protected KeyClaimError dco_decode_key_claim_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return KeyClaimError.values[raw as int]; }
+
+@protected KeyMaintenancePlan dco_decode_key_maintenance_plan(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return KeyMaintenancePlan(generateOneTimeKeys: dco_decode_usize(arr[0]),
+rotateFallbackKey: dco_decode_bool(arr[1]),
+forgetOldFallbackKey: dco_decode_bool(arr[2]),); }
+
+@protected LibolmStore dco_decode_libolm_store(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LibolmStore(account: dco_decode_opt_String(arr[0]),
+sessions: dco_decode_list_String(arr[1]),
+inboundGroupSessions: dco_decode_list_String(arr[2]),
+pkDecryption: dco_decode_opt_String(arr[3]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<DeviceTrust> dco_decode_list_device_trust(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_device_trust).toList(); }
+
+@protected List<InboundRoomKey> dco_decode_list_inbound_room_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_inbound_room_key).toList(); }
+
+@protected List<MigratedPickle> dco_decode_list_migrated_pickle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_migrated_pickle).toList(); }
+
+@protected List<MigrationFailure> dco_decode_list_migration_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_migration_failure).toList(); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<(String,int)> dco_decode_list_record_string_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_u_32).toList(); }
+
+@protected List<RekeyResult> dco_decode_list_rekey_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_rekey_result).toList(); }
+
+@protected List<RetentionResult> dco_decode_list_retention_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_retention_result).toList(); }
+
+@protected List<RetriedEvent> dco_decode_list_retried_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_retried_event).toList(); }
+
+@protected List<RoomRetention> dco_decode_list_room_retention(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_room_retention).toList(); }
+
+@protected List<ShareRecipient> dco_decode_list_share_recipient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_share_recipient).toList(); }
+
+@protected List<VodozemacOneTimeKey> dco_decode_list_vodozemac_one_time_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_vodozemac_one_time_key).toList(); }
+
+@protected List<VodozemacSession> dco_decode_list_vodozemac_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_vodozemac_session).toList(); }
+
+@protected MegolmReplayGuard dco_decode_megolm_replay_guard(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return MegolmReplayGuard.raw(state: dco_decode_RustOpaque_RwLockReplayState(arr[0]),); }
+
+@protected MigratedPickle dco_decode_migrated_pickle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return MigratedPickle(index: dco_decode_u_32(arr[0]),
+idBefore: dco_decode_String(arr[1]),
+idAfter: dco_decode_String(arr[2]),
+pickle: dco_decode_String(arr[3]),); }
+
+@protected MigrationError dco_decode_migration_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MigrationError.values[raw as int]; }
+
+@protected MigrationFailure dco_decode_migration_failure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return MigrationFailure(object: dco_decode_pickle_object(arr[0]),
+index: dco_decode_u_32(arr[1]),
+idBefore: dco_decode_opt_String(arr[2]),
+idAfter: dco_decode_opt_String(arr[3]),
+error: dco_decode_migration_error(arr[4]),); }
+
+@protected MigrationReport dco_decode_migration_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return MigrationReport(account: dco_decode_opt_box_autoadd_migrated_pickle(arr[0]),
+sessions: dco_decode_list_migrated_pickle(arr[1]),
+inboundGroupSessions: dco_decode_list_migrated_pickle(arr[2]),
+pkDecryption: dco_decode_opt_box_autoadd_migrated_pickle(arr[3]),
+failures: dco_decode_list_migration_failure(arr[4]),); }
+
+@protected OlmPayload dco_decode_olm_payload(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return OlmPayload(eventType: dco_decode_String(arr[0]),
+content: dco_decode_String(arr[1]),
+sender: dco_decode_String(arr[2]),
+senderDevice: dco_decode_opt_String(arr[3]),
+senderClaimedEd25519: dco_decode_vodozemac_ed_25519_public_key(arr[4]),); }
+
+@protected OlmPayloadError dco_decode_olm_payload_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OlmPayloadError.values[raw as int]; }
+
+@protected OlmSessionStore dco_decode_olm_session_store(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return OlmSessionStore.raw(state: dco_decode_RustOpaque_RwLockSessionStoreState(arr[0]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected MigratedPickle? dco_decode_opt_box_autoadd_migrated_pickle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_migrated_pickle(raw); }
+
+@protected PickleFormatError? dco_decode_opt_box_autoadd_pickle_format_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_pickle_format_error(raw); }
+
+@protected RetainedRoomKey? dco_decode_opt_box_autoadd_retained_room_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_retained_room_key(raw); }
+
+@protected RotationReason? dco_decode_opt_box_autoadd_rotation_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_rotation_reason(raw); }
+
+@protected TrustLink? dco_decode_opt_box_autoadd_trust_link(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_trust_link(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected UtdClassification? dco_decode_opt_box_autoadd_utd_classification(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_utd_classification(raw); }
+
+@protected VodozemacEd25519PublicKey? dco_decode_opt_box_autoadd_vodozemac_ed_25519_public_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_vodozemac_ed_25519_public_key(raw); }
+
+@protected VodozemacSession? dco_decode_opt_box_autoadd_vodozemac_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_vodozemac_session(raw); }
+
+@protected WithheldCode? dco_decode_opt_box_autoadd_withheld_code(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_withheld_code(raw); }
+
+@protected List<String>? dco_decode_opt_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_String(raw); }
+
+@protected List<RekeyResult>? dco_decode_opt_list_rekey_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_rekey_result(raw); }
+
+@protected OutboundGroupSession dco_decode_outbound_group_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return OutboundGroupSession.raw(session: dco_decode_RustOpaque_RwLockGroupSession(arr[0]),
+rotation: dco_decode_RustOpaque_RwLockRotationState(arr[1]),
+shares: dco_decode_RustOpaque_RwLockShareState(arr[2]),); }
+
+@protected PassphraseKdf dco_decode_passphrase_kdf(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PassphraseKdf.values[raw as int]; }
+
+@protected PassphraseKdfParams dco_decode_passphrase_kdf_params(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PassphraseKdfParams.raw(kdf: dco_decode_passphrase_kdf(arr[0]),
+iterations: dco_decode_u_32(arr[1]),); }
+
+@protected PassphrasePickleError dco_decode_passphrase_pickle_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PassphrasePickleError.values[raw as int]; }
+
+@protected PickleFormat dco_decode_pickle_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PickleFormat.values[raw as int]; }
+
+@protected PickleFormatError dco_decode_pickle_format_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PickleFormatError.values[raw as int]; }
+
+@protected PickleInfo dco_decode_pickle_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PickleInfo(format: dco_decode_pickle_format(arr[0]),
+object: dco_decode_pickle_object(arr[1]),
+version: dco_decode_opt_box_autoadd_u_32(arr[2]),); }
+
+@protected PickleObject dco_decode_pickle_object(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PickleObject.values[raw as int]; }
+
+@protected (String,String,String) dco_decode_record_string_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 3) {
+                throw Exception('Expected 3 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_String(arr[1]),dco_decode_String(arr[2]),); }
+
+@protected (String,int) dco_decode_record_string_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_u_32(arr[1]),); }
+
+@protected RekeyProgress dco_decode_rekey_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RekeyProgress(done: dco_decode_u_32(arr[0]),
+results: dco_decode_opt_list_rekey_result(arr[1]),); }
+
+@protected RekeyResult dco_decode_rekey_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RekeyResult(pickle: dco_decode_opt_String(arr[0]),
+error: dco_decode_opt_box_autoadd_pickle_format_error(arr[1]),); }
+
+@protected RetainedRoomKey dco_decode_retained_room_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RetainedRoomKey(sessionId: dco_decode_String(arr[0]),
+pickle: dco_decode_String(arr[1]),
+firstKnownIndex: dco_decode_u_32(arr[2]),
+discarded: dco_decode_bool(arr[3]),); }
+
+@protected RetentionResult dco_decode_retention_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RetentionResult(roomKey: dco_decode_opt_box_autoadd_retained_room_key(arr[0]),
+error: dco_decode_opt_box_autoadd_pickle_format_error(arr[1]),); }
+
+@protected RetriedEvent dco_decode_retried_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RetriedEvent(eventId: dco_decode_String(arr[0]),
+result: dco_decode_room_key_decryption_result(arr[1]),); }
+
+@protected RoomEventDecryptionResult dco_decode_room_event_decryption_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RoomEventDecryptionResult(eventType: dco_decode_String(arr[0]),
+content: dco_decode_String(arr[1]),
+messageIndex: dco_decode_u_32(arr[2]),); }
+
+@protected RoomEventError dco_decode_room_event_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RoomEventError.values[raw as int]; }
+
+@protected RoomKeyBundleDownload dco_decode_room_key_bundle_download(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RoomKeyBundleDownload(roomId: dco_decode_String(arr[0]),
+url: dco_decode_String(arr[1]),
+file: dco_decode_String(arr[2]),
+senderKey: dco_decode_String(arr[3]),); }
+
+@protected RoomKeyBundleError dco_decode_room_key_bundle_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RoomKeyBundleError.values[raw as int]; }
+
+@protected RoomKeyDecryptionResult dco_decode_room_key_decryption_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return RoomKeyDecryptionResult(eventType: dco_decode_String(arr[0]),
+content: dco_decode_String(arr[1]),
+messageIndex: dco_decode_u_32(arr[2]),
+authenticity: dco_decode_key_authenticity(arr[3]),
+senderKey: dco_decode_String(arr[4]),
+senderClaimedEd25519: dco_decode_String(arr[5]),); }
+
+@protected RoomKeyError dco_decode_room_key_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RoomKeyError.values[raw as int]; }
+
+@protected RoomKeyInfo dco_decode_room_key_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return RoomKeyInfo(algorithm: dco_decode_String(arr[0]),
+roomId: dco_decode_String(arr[1]),
+senderKey: dco_decode_String(arr[2]),
+senderClaimedEd25519: dco_decode_String(arr[3]),
+forwardingCurve25519KeyChain: dco_decode_list_String(arr[4]),
+origin: dco_decode_room_key_origin(arr[5]),
+sharedHistory: dco_decode_bool(arr[6]),
+backedUp: dco_decode_bool(arr[7]),); }
+
+@protected RoomKeyOrigin dco_decode_room_key_origin(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RoomKeyOrigin.values[raw as int]; }
+
+@protected RoomRetention dco_decode_room_retention(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RoomRetention(roomId: dco_decode_String(arr[0]),
+maxAgeMs: dco_decode_u_64(arr[1]),); }
+
+@protected RotationReason dco_decode_rotation_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RotationReason.values[raw as int]; }
+
+@protected SessionKeySource dco_decode_session_key_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SessionKeySource.values[raw as int]; }
+
+@protected ShareRecipient dco_decode_share_recipient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ShareRecipient(userId: dco_decode_String(arr[0]),
+deviceId: dco_decode_String(arr[1]),
+curve25519Key: dco_decode_String(arr[2]),); }
+
+@protected ToDeviceDecryptionError dco_decode_to_device_decryption_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ToDeviceDecryptionError.values[raw as int]; }
+
+@protected ToDeviceDecryptionResult dco_decode_to_device_decryption_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ToDeviceDecryptionResult(plaintext: dco_decode_String(arr[0]),
+senderKey: dco_decode_vodozemac_curve_25519_public_key(arr[1]),
+sessionId: dco_decode_String(arr[2]),
+newSession: dco_decode_opt_box_autoadd_vodozemac_session(arr[3]),); }
+
+@protected TrustLink dco_decode_trust_link(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TrustLink.values[raw as int]; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected U8Array32 dco_decode_u_8_array_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return U8Array32(dco_decode_list_prim_u_8_strict(raw)); }
+
+@protected U8Array64 dco_decode_u_8_array_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return U8Array64(dco_decode_list_prim_u_8_strict(raw)); }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected UnpickledAccount dco_decode_unpickled_account(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UnpickledAccount(account: dco_decode_vodozemac_account(arr[0]),
+needsRepickle: dco_decode_bool(arr[1]),); }
+
+@protected UnpickledGroupSession dco_decode_unpickled_group_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UnpickledGroupSession(session: dco_decode_vodozemac_group_session(arr[0]),
+needsRepickle: dco_decode_bool(arr[1]),); }
+
+@protected UnpickledInboundGroupSession dco_decode_unpickled_inbound_group_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UnpickledInboundGroupSession(session: dco_decode_vodozemac_inbound_group_session(arr[0]),
+needsRepickle: dco_decode_bool(arr[1]),); }
+
+@protected UnpickledPkDecryption dco_decode_unpickled_pk_decryption(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UnpickledPkDecryption(pkDecryption: dco_decode_vodozemac_pk_decryption(arr[0]),
+needsRepickle: dco_decode_bool(arr[1]),); }
+
+@protected UnpickledSession dco_decode_unpickled_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UnpickledSession(session: dco_decode_vodozemac_session(arr[0]),
+needsRepickle: dco_decode_bool(arr[1]),); }
+
+@protected UnwedgeError dco_decode_unwedge_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UnwedgeError.values[raw as int]; }
+
+@protected UnwedgeResult dco_decode_unwedge_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UnwedgeResult(session: dco_decode_vodozemac_session(arr[0]),
+encryptedContent: dco_decode_String(arr[1]),); }
+
+@protected UnwedgeTracker dco_decode_unwedge_tracker(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return UnwedgeTracker.raw(state: dco_decode_RustOpaque_RwLockUnwedgeState(arr[0]),); }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected UtdCause dco_decode_utd_cause(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UtdCause.values[raw as int]; }
+
+@protected UtdClassification dco_decode_utd_classification(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UtdClassification(cause: dco_decode_utd_cause(arr[0]),
+withheldCode: dco_decode_opt_box_autoadd_withheld_code(arr[1]),); }
+
+@protected UtdQueue dco_decode_utd_queue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return UtdQueue.raw(state: dco_decode_RustOpaque_RwLockUtdQueueState(arr[0]),); }
+
+@protected VodozemacAccount dco_decode_vodozemac_account(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return VodozemacAccount.raw(account: dco_decode_RustOpaque_stdsyncRwLockAccount(arr[0]),); }
+
+@protected VodozemacCurve25519PublicKey dco_decode_vodozemac_curve_25519_public_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return VodozemacCurve25519PublicKey(key: dco_decode_RustOpaque_Curve25519PublicKey(arr[0]),); }
+
+@protected VodozemacEd25519PublicKey dco_decode_vodozemac_ed_25519_public_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return VodozemacEd25519PublicKey(key: dco_decode_RustOpaque_Ed25519PublicKey(arr[0]),); }
+
+@protected VodozemacEd25519Signature dco_decode_vodozemac_ed_25519_signature(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return VodozemacEd25519Signature(signature: dco_decode_RustOpaque_Ed25519Signature(arr[0]),); }
+
+@protected VodozemacEstablishedSas dco_decode_vodozemac_established_sas(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return VodozemacEstablishedSas(establishedSas: dco_decode_RustOpaque_EstablishedSas(arr[0]),); }
+
+@protected VodozemacGroupSession dco_decode_vodozemac_group_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return VodozemacGroupSession.raw(session: dco_decode_RustOpaque_RwLockGroupSession(arr[0]),); }
+
+@protected VodozemacIdentityKeys dco_decode_vodozemac_identity_keys(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return VodozemacIdentityKeys(ed25519: dco_decode_vodozemac_ed_25519_public_key(arr[0]),
+curve25519: dco_decode_vodozemac_curve_25519_public_key(arr[1]),); }
+
+@protected VodozemacInboundGroupSession dco_decode_vodozemac_inbound_group_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return VodozemacInboundGroupSession.raw(session: dco_decode_RustOpaque_RwLockInboundGroupSession(arr[0]),
+source: dco_decode_RustOpaque_RwLockSessionKeySource(arr[1]),); }
+
+@protected VodozemacMegolmSessionConfig dco_decode_vodozemac_megolm_session_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return VodozemacMegolmSessionConfig(config: dco_decode_RustOpaque_MegolmSessionConfig(arr[0]),); }
+
+@protected VodozemacOlmMessage dco_decode_vodozemac_olm_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return VodozemacOlmMessage(msg: dco_decode_RustOpaque_OlmMessage(arr[0]),); }
+
+@protected VodozemacOlmSessionConfig dco_decode_vodozemac_olm_session_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return VodozemacOlmSessionConfig(config: dco_decode_RustOpaque_OlmSessionConfig(arr[0]),); }
+
+@protected VodozemacOlmSessionCreationResult dco_decode_vodozemac_olm_session_creation_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return VodozemacOlmSessionCreationResult(session: dco_decode_vodozemac_session(arr[0]),
+plaintext: dco_decode_String(arr[1]),); }
+
+@protected VodozemacOneTimeKey dco_decode_vodozemac_one_time_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return VodozemacOneTimeKey(keyid: dco_decode_String(arr[0]),
+key: dco_decode_vodozemac_curve_25519_public_key(arr[1]),); }
+
+@protected VodozemacPkDecryption dco_decode_vodozemac_pk_decryption(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return VodozemacPkDecryption.raw(pkDecryption: dco_decode_RustOpaque_PkDecryption(arr[0]),); }
+
+@protected VodozemacPkEncryption dco_decode_vodozemac_pk_encryption(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return VodozemacPkEncryption(pkEncryption: dco_decode_RustOpaque_PkEncryption(arr[0]),); }
+
+@protected VodozemacPkMessage dco_decode_vodozemac_pk_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return VodozemacPkMessage.raw(ciphertext: dco_decode_list_prim_u_8_strict(arr[0]),
+mac: dco_decode_list_prim_u_8_strict(arr[1]),
+ephemeralKey: dco_decode_vodozemac_curve_25519_public_key(arr[2]),); }
+
+@protected VodozemacSession dco_decode_vodozemac_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return VodozemacSession(session: dco_decode_RustOpaque_RwLockSession(arr[0]),); }
+
+@protected WithheldCode dco_decode_withheld_code(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return WithheldCode.values[raw as int]; }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected CrossSigningIdentity sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CrossSigningIdentityImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PkSigning sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PkSigningImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected VodozemacSas sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return VodozemacSasImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CrossSigningIdentity sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CrossSigningIdentityImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PkSigning sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PkSigningImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected VodozemacSas sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return VodozemacSasImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Map<String, int> sse_decode_Map_String_u_32_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_u_32(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Curve25519PublicKey sse_decode_RustOpaque_Curve25519PublicKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Curve25519PublicKeyImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Ed25519PublicKey sse_decode_RustOpaque_Ed25519PublicKey(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Ed25519PublicKeyImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Ed25519Signature sse_decode_RustOpaque_Ed25519Signature(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Ed25519SignatureImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected EstablishedSas sse_decode_RustOpaque_EstablishedSas(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return EstablishedSasImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MegolmSessionConfig sse_decode_RustOpaque_MegolmSessionConfig(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MegolmSessionConfigImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected OlmMessage sse_decode_RustOpaque_OlmMessage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return OlmMessageImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected OlmSessionConfig sse_decode_RustOpaque_OlmSessionConfig(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return OlmSessionConfigImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PkDecryption sse_decode_RustOpaque_PkDecryption(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PkDecryptionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PkEncryption sse_decode_RustOpaque_PkEncryption(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PkEncryptionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockFallbackKeyState sse_decode_RustOpaque_RwLockFallbackKeyState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockFallbackKeyStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockGroupSession sse_decode_RustOpaque_RwLockGroupSession(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockGroupSessionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockInboundGroupSession sse_decode_RustOpaque_RwLockInboundGroupSession(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockInboundGroupSessionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockReplayState sse_decode_RustOpaque_RwLockReplayState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockReplayStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockRoomKeyInfo sse_decode_RustOpaque_RwLockRoomKeyInfo(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockRoomKeyInfoImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockRotationState sse_decode_RustOpaque_RwLockRotationState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockRotationStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockSession sse_decode_RustOpaque_RwLockSession(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockSessionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockSessionKeySource sse_decode_RustOpaque_RwLockSessionKeySource(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockSessionKeySourceImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockSessionStoreState sse_decode_RustOpaque_RwLockSessionStoreState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockSessionStoreStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockShareState sse_decode_RustOpaque_RwLockShareState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockShareStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockUnwedgeState sse_decode_RustOpaque_RwLockUnwedgeState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockUnwedgeStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockUtdQueueState sse_decode_RustOpaque_RwLockUtdQueueState(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockUtdQueueStateImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CrossSigningIdentity sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CrossSigningIdentityImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PkSigning sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PkSigningImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected VodozemacSas sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return VodozemacSasImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RwLockAccount sse_decode_RustOpaque_stdsyncRwLockAccount(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return RwLockAccountImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<RekeyProgress> sse_decode_StreamSink_rekey_progress_Dco(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected DeviceKeys sse_decode_box_autoadd_device_keys(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_device_keys(deserializer)); }
+
+@protected EncryptedRoomKeyBundle sse_decode_box_autoadd_encrypted_room_key_bundle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_encrypted_room_key_bundle(deserializer)); }
+
+@protected FallbackKeyTracker sse_decode_box_autoadd_fallback_key_tracker(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_fallback_key_tracker(deserializer)); }
+
+@protected InboundRoomKey sse_decode_box_autoadd_inbound_room_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_inbound_room_key(deserializer)); }
+
+@protected LibolmStore sse_decode_box_autoadd_libolm_store(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_libolm_store(deserializer)); }
+
+@protected MegolmReplayGuard sse_decode_box_autoadd_megolm_replay_guard(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_megolm_replay_guard(deserializer)); }
+
+@protected MigratedPickle sse_decode_box_autoadd_migrated_pickle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_migrated_pickle(deserializer)); }
+
+@protected MigrationReport sse_decode_box_autoadd_migration_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_migration_report(deserializer)); }
+
+@protected OlmPayload sse_decode_box_autoadd_olm_payload(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_olm_payload(deserializer)); }
+
+@protected OlmSessionStore sse_decode_box_autoadd_olm_session_store(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_olm_session_store(deserializer)); }
+
+@protected OutboundGroupSession sse_decode_box_autoadd_outbound_group_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_outbound_group_session(deserializer)); }
+
+@protected PassphraseKdfParams sse_decode_box_autoadd_passphrase_kdf_params(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_passphrase_kdf_params(deserializer)); }
+
+@protected PickleFormatError sse_decode_box_autoadd_pickle_format_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_pickle_format_error(deserializer)); }
+
+@protected PickleInfo sse_decode_box_autoadd_pickle_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_pickle_info(deserializer)); }
+
+@protected RetainedRoomKey sse_decode_box_autoadd_retained_room_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_retained_room_key(deserializer)); }
+
+@protected RoomKeyBundleDownload sse_decode_box_autoadd_room_key_bundle_download(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_room_key_bundle_download(deserializer)); }
+
+@protected RoomKeyInfo sse_decode_box_autoadd_room_key_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_room_key_info(deserializer)); }
+
+@protected RotationReason sse_decode_box_autoadd_rotation_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_rotation_reason(deserializer)); }
+
+@protected ShareRecipient sse_decode_box_autoadd_share_recipient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_share_recipient(deserializer)); }
+
+@protected TrustLink sse_decode_box_autoadd_trust_link(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_trust_link(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected UnwedgeTracker sse_decode_box_autoadd_unwedge_tracker(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_unwedge_tracker(deserializer)); }
+
+@protected UtdClassification sse_decode_box_autoadd_utd_classification(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_utd_classification(deserializer)); }
+
+@protected UtdQueue sse_decode_box_autoadd_utd_queue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_utd_queue(deserializer)); }
+
+@protected VodozemacAccount sse_decode_box_autoadd_vodozemac_account(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_account(deserializer)); }
+
+@protected VodozemacCurve25519PublicKey sse_decode_box_autoadd_vodozemac_curve_25519_public_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_curve_25519_public_key(deserializer)); }
+
+@protected VodozemacEd25519PublicKey sse_decode_box_autoadd_vodozemac_ed_25519_public_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_ed_25519_public_key(deserializer)); }
+
+@protected VodozemacEd25519Signature sse_decode_box_autoadd_vodozemac_ed_25519_signature(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_ed_25519_signature(deserializer)); }
+
+@protected VodozemacEstablishedSas sse_decode_box_autoadd_vodozemac_established_sas(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_established_sas(deserializer)); }
+
+@protected VodozemacGroupSession sse_decode_box_autoadd_vodozemac_group_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_group_session(deserializer)); }
+
+@protected VodozemacInboundGroupSession sse_decode_box_autoadd_vodozemac_inbound_group_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_inbound_group_session(deserializer)); }
+
+@protected VodozemacMegolmSessionConfig sse_decode_box_autoadd_vodozemac_megolm_session_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_megolm_session_config(deserializer)); }
+
+@protected VodozemacOlmMessage sse_decode_box_autoadd_vodozemac_olm_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_olm_message(deserializer)); }
+
+@protected VodozemacOlmSessionConfig sse_decode_box_autoadd_vodozemac_olm_session_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_olm_session_config(deserializer)); }
+
+@protected VodozemacPkDecryption sse_decode_box_autoadd_vodozemac_pk_decryption(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_pk_decryption(deserializer)); }
+
+@protected VodozemacPkEncryption sse_decode_box_autoadd_vodozemac_pk_encryption(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_pk_encryption(deserializer)); }
+
+@protected VodozemacPkMessage sse_decode_box_autoadd_vodozemac_pk_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_pk_message(deserializer)); }
+
+@protected VodozemacSession sse_decode_box_autoadd_vodozemac_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vodozemac_session(deserializer)); }
+
+@protected WithheldCode sse_decode_box_autoadd_withheld_code(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_withheld_code(deserializer)); }
+
+@protected ClaimedSession sse_decode_claimed_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_session = sse_decode_vodozemac_session(deserializer);
+var var_fallback = sse_decode_bool(deserializer);
+return ClaimedSession(session: var_session, fallback: var_fallback); }
+
+@protected DecryptResult sse_decode_decrypt_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_u_32(deserializer);
+return DecryptResult(field0: var_field0, field1: var_field1); }
+
+@protected DeviceKeys sse_decode_device_keys(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userId = sse_decode_String(deserializer);
+var var_deviceId = sse_decode_String(deserializer);
+var var_algorithms = sse_decode_list_String(deserializer);
+var var_ed25519 = sse_decode_vodozemac_ed_25519_public_key(deserializer);
+var var_curve25519 = sse_decode_vodozemac_curve_25519_public_key(deserializer);
+var var_displayName = sse_decode_opt_String(deserializer);
+return DeviceKeys(userId: var_userId, deviceId: var_deviceId, algorithms: var_algorithms, ed25519: var_ed25519, curve25519: var_curve25519, displayName: var_displayName); }
+
+@protected DeviceKeysError sse_decode_device_keys_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DeviceKeysError.values[inner]; }
+
+@protected DeviceTrust sse_decode_device_trust(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userId = sse_decode_String(deserializer);
+var var_deviceId = sse_decode_String(deserializer);
+var var_level = sse_decode_device_trust_level(deserializer);
+var var_failedLink = sse_decode_opt_box_autoadd_trust_link(deserializer);
+return DeviceTrust(userId: var_userId, deviceId: var_deviceId, level: var_level, failedLink: var_failedLink); }
+
+@protected DeviceTrustLevel sse_decode_device_trust_level(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DeviceTrustLevel.values[inner]; }
+
+@protected EncryptedRoomKeyBundle sse_decode_encrypted_room_key_bundle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_roomId = sse_decode_String(deserializer);
+var var_ciphertext = sse_decode_list_prim_u_8_strict(deserializer);
+var var_file = sse_decode_String(deserializer);
+return EncryptedRoomKeyBundle(roomId: var_roomId, ciphertext: var_ciphertext, file: var_file); }
+
+@protected ExtendedDecryptResult sse_decode_extended_decrypt_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_plaintext = sse_decode_String(deserializer);
+var var_messageIndex = sse_decode_u_32(deserializer);
+var var_signingKey = sse_decode_vodozemac_ed_25519_public_key(deserializer);
+var var_keySource = sse_decode_session_key_source(deserializer);
+return ExtendedDecryptResult(plaintext: var_plaintext, messageIndex: var_messageIndex, signingKey: var_signingKey, keySource: var_keySource); }
+
+@protected FallbackKeyTracker sse_decode_fallback_key_tracker(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_state = sse_decode_RustOpaque_RwLockFallbackKeyState(deserializer);
+return FallbackKeyTracker.raw(state: var_state); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected ImportedRoomKeyBundle sse_decode_imported_room_key_bundle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_roomKeys = sse_decode_list_inbound_room_key(deserializer);
+var var_skipped = sse_decode_u_32(deserializer);
+return ImportedRoomKeyBundle(roomKeys: var_roomKeys, skipped: var_skipped); }
+
+@protected InboundRoomKey sse_decode_inbound_room_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_session = sse_decode_RustOpaque_RwLockInboundGroupSession(deserializer);
+var var_source = sse_decode_RustOpaque_RwLockSessionKeySource(deserializer);
+var var_keyInfo = sse_decode_RustOpaque_RwLockRoomKeyInfo(deserializer);
+return InboundRoomKey.raw(session: var_session, source: var_source, keyInfo: var_keyInfo); }
+
+@protected KeyAuthenticity sse_decode_key_authenticity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return KeyAuthenticity.values[inner]; }
+
+@protected KeyClaimError sse_decode_key_claim_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return KeyClaimError.values[inner]; }
+
+@protected KeyMaintenancePlan sse_decode_key_maintenance_plan(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_generateOneTimeKeys = sse_decode_usize(deserializer);
+var var_rotateFallbackKey = sse_decode_bool(deserializer);
+var var_forgetOldFallbackKey = sse_decode_bool(deserializer);
+return KeyMaintenancePlan(generateOneTimeKeys: var_generateOneTimeKeys, rotateFallbackKey: var_rotateFallbackKey, forgetOldFallbackKey: var_forgetOldFallbackKey); }
+
+@protected LibolmStore sse_decode_libolm_store(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_account = sse_decode_opt_String(deserializer);
+var var_sessions = sse_decode_list_String(deserializer);
+var var_inboundGroupSessions = sse_decode_list_String(deserializer);
+var var_pkDecryption = sse_decode_opt_String(deserializer);
+return LibolmStore(account: var_account, sessions: var_sessions, inboundGroupSessions: var_inboundGroupSessions, pkDecryption: var_pkDecryption); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DeviceTrust> sse_decode_list_device_trust(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DeviceTrust>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_device_trust(deserializer)); }
+        return ans_;
+         }
+
+@protected List<InboundRoomKey> sse_decode_list_inbound_room_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <InboundRoomKey>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_inbound_room_key(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MigratedPickle> sse_decode_list_migrated_pickle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MigratedPickle>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_migrated_pickle(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MigrationFailure> sse_decode_list_migration_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MigrationFailure>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_migration_failure(deserializer)); }
+        return ans_;
+         }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<(String,int)> sse_decode_list_record_string_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,int)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_u_32(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RekeyResult> sse_decode_list_rekey_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RekeyResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_rekey_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RetentionResult> sse_decode_list_retention_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RetentionResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_retention_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RetriedEvent> sse_decode_list_retried_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RetriedEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_retried_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RoomRetention> sse_decode_list_room_retention(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RoomRetention>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_room_retention(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ShareRecipient> sse_decode_list_share_recipient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ShareRecipient>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_share_recipient(deserializer)); }
+        return ans_;
+         }
+
+@protected List<VodozemacOneTimeKey> sse_decode_list_vodozemac_one_time_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <VodozemacOneTimeKey>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_vodozemac_one_time_key(deserializer)); }
+        return ans_;
+         }
+
+@protected List<VodozemacSession> sse_decode_list_vodozemac_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <VodozemacSession>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_vodozemac_session(deserializer)); }
+        return ans_;
+         }
+
+@protected MegolmReplayGuard sse_decode_megolm_replay_guard(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_state = sse_decode_RustOpaque_RwLockReplayState(deserializer);
+return MegolmReplayGuard.raw(state: var_state); }
+
+@protected MigratedPickle sse_decode_migrated_pickle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_idBefore = sse_decode_String(deserializer);
+var var_idAfter = sse_decode_String(deserializer);
+var var_pickle = sse_decode_String(deserializer);
+return MigratedPickle(index: var_index, idBefore: var_idBefore, idAfter: var_idAfter, pickle: var_pickle); }
+
+@protected MigrationError sse_decode_migration_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MigrationError.values[inner]; }
+
+@protected MigrationFailure sse_decode_migration_failure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_object = sse_decode_pickle_object(deserializer);
+var var_index = sse_decode_u_32(deserializer);
+var var_idBefore = sse_decode_opt_String(deserializer);
+var var_idAfter = sse_decode_opt_String(deserializer);
+var var_error = sse_decode_migration_error(deserializer);
+return MigrationFailure(object: var_object, index: var_index, idBefore: var_idBefore, idAfter: var_idAfter, error: var_error); }
+
+@protected MigrationReport sse_decode_migration_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_account = sse_decode_opt_box_autoadd_migrated_pickle(deserializer);
+var var_sessions = sse_decode_list_migrated_pickle(deserializer);
+var var_inboundGroupSessions = sse_decode_list_migrated_pickle(deserializer);
+var var_pkDecryption = sse_decode_opt_box_autoadd_migrated_pickle(deserializer);
+var var_failures = sse_decode_list_migration_failure(deserializer);
+return MigrationReport(account: var_account, sessions: var_sessions, inboundGroupSessions: var_inboundGroupSessions, pkDecryption: var_pkDecryption, failures: var_failures); }
+
+@protected OlmPayload sse_decode_olm_payload(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_eventType = sse_decode_String(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_sender = sse_decode_String(deserializer);
+var var_senderDevice = sse_decode_opt_String(deserializer);
+var var_senderClaimedEd25519 = sse_decode_vodozemac_ed_25519_public_key(deserializer);
+return OlmPayload(eventType: var_eventType, content: var_content, sender: var_sender, senderDevice: var_senderDevice, senderClaimedEd25519: var_senderClaimedEd25519); }
+
+@protected OlmPayloadError sse_decode_olm_payload_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return OlmPayloadError.values[inner]; }
+
+@protected OlmSessionStore sse_decode_olm_session_store(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_state = sse_decode_RustOpaque_RwLockSessionStoreState(deserializer);
+return OlmSessionStore.raw(state: var_state); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected MigratedPickle? sse_decode_opt_box_autoadd_migrated_pickle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_migrated_pickle(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PickleFormatError? sse_decode_opt_box_autoadd_pickle_format_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_pickle_format_error(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RetainedRoomKey? sse_decode_opt_box_autoadd_retained_room_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_retained_room_key(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RotationReason? sse_decode_opt_box_autoadd_rotation_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_rotation_reason(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TrustLink? sse_decode_opt_box_autoadd_trust_link(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_trust_link(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UtdClassification? sse_decode_opt_box_autoadd_utd_classification(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_utd_classification(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected VodozemacEd25519PublicKey? sse_decode_opt_box_autoadd_vodozemac_ed_25519_public_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_vodozemac_ed_25519_public_key(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected VodozemacSession? sse_decode_opt_box_autoadd_vodozemac_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_vodozemac_session(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected WithheldCode? sse_decode_opt_box_autoadd_withheld_code(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_withheld_code(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<RekeyResult>? sse_decode_opt_list_rekey_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_rekey_result(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OutboundGroupSession sse_decode_outbound_group_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_session = sse_decode_RustOpaque_RwLockGroupSession(deserializer);
+var var_rotation = sse_decode_RustOpaque_RwLockRotationState(deserializer);
+var var_shares = sse_decode_RustOpaque_RwLockShareState(deserializer);
+return OutboundGroupSession.raw(session: var_session, rotation: var_rotation, shares: var_shares); }
+
+@protected PassphraseKdf sse_decode_passphrase_kdf(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PassphraseKdf.values[inner]; }
+
+@protected PassphraseKdfParams sse_decode_passphrase_kdf_params(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kdf = sse_decode_passphrase_kdf(deserializer);
+var var_iterations = sse_decode_u_32(deserializer);
+return PassphraseKdfParams.raw(kdf: var_kdf, iterations: var_iterations); }
+
+@protected PassphrasePickleError sse_decode_passphrase_pickle_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PassphrasePickleError.values[inner]; }
+
+@protected PickleFormat sse_decode_pickle_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PickleFormat.values[inner]; }
+
+@protected PickleFormatError sse_decode_pickle_format_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PickleFormatError.values[inner]; }
+
+@protected PickleInfo sse_decode_pickle_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_format = sse_decode_pickle_format(deserializer);
+var var_object = sse_decode_pickle_object(deserializer);
+var var_version = sse_decode_opt_box_autoadd_u_32(deserializer);
+return PickleInfo(format: var_format, object: var_object, version: var_version); }
+
+@protected PickleObject sse_decode_pickle_object(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PickleObject.values[inner]; }
+
+@protected (String,String,String) sse_decode_record_string_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_String(deserializer);
+var var_field2 = sse_decode_String(deserializer);
+return (var_field0, var_field1, var_field2); }
+
+@protected (String,int) sse_decode_record_string_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_u_32(deserializer);
+return (var_field0, var_field1); }
+
+@protected RekeyProgress sse_decode_rekey_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_done = sse_decode_u_32(deserializer);
+var var_results = sse_decode_opt_list_rekey_result(deserializer);
+return RekeyProgress(done: var_done, results: var_results); }
+
+@protected RekeyResult sse_decode_rekey_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pickle = sse_decode_opt_String(deserializer);
+var var_error = sse_decode_opt_box_autoadd_pickle_format_error(deserializer);
+return RekeyResult(pickle: var_pickle, error: var_error); }
+
+@protected RetainedRoomKey sse_decode_retained_room_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sessionId = sse_decode_String(deserializer);
+var var_pickle = sse_decode_String(deserializer);
+var var_firstKnownIndex = sse_decode_u_32(deserializer);
+var var_discarded = sse_decode_bool(deserializer);
+return RetainedRoomKey(sessionId: var_sessionId, pickle: var_pickle, firstKnownIndex: var_firstKnownIndex, discarded: var_discarded); }
+
+@protected RetentionResult sse_decode_retention_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_roomKey = sse_decode_opt_box_autoadd_retained_room_key(deserializer);
+var var_error = sse_decode_opt_box_autoadd_pickle_format_error(deserializer);
+return RetentionResult(roomKey: var_roomKey, error: var_error); }
+
+@protected RetriedEvent sse_decode_retried_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_eventId = sse_decode_String(deserializer);
+var var_result = sse_decode_room_key_decryption_result(deserializer);
+return RetriedEvent(eventId: var_eventId, result: var_result); }
+
+@protected RoomEventDecryptionResult sse_decode_room_event_decryption_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_eventType = sse_decode_String(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_messageIndex = sse_decode_u_32(deserializer);
+return RoomEventDecryptionResult(eventType: var_eventType, content: var_content, messageIndex: var_messageIndex); }
+
+@protected RoomEventError sse_decode_room_event_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RoomEventError.values[inner]; }
+
+@protected RoomKeyBundleDownload sse_decode_room_key_bundle_download(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_roomId = sse_decode_String(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_file = sse_decode_String(deserializer);
+var var_senderKey = sse_decode_String(deserializer);
+return RoomKeyBundleDownload(roomId: var_roomId, url: var_url, file: var_file, senderKey: var_senderKey); }
+
+@protected RoomKeyBundleError sse_decode_room_key_bundle_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RoomKeyBundleError.values[inner]; }
+
+@protected RoomKeyDecryptionResult sse_decode_room_key_decryption_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_eventType = sse_decode_String(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_messageIndex = sse_decode_u_32(deserializer);
+var var_authenticity = sse_decode_key_authenticity(deserializer);
+var var_senderKey = sse_decode_String(deserializer);
+var var_senderClaimedEd25519 = sse_decode_String(deserializer);
+return RoomKeyDecryptionResult(eventType: var_eventType, content: var_content, messageIndex: var_messageIndex, authenticity: var_authenticity, senderKey: var_senderKey, senderClaimedEd25519: var_senderClaimedEd25519); }
+
+@protected RoomKeyError sse_decode_room_key_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RoomKeyError.values[inner]; }
+
+@protected RoomKeyInfo sse_decode_room_key_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_algorithm = sse_decode_String(deserializer);
+var var_roomId = sse_decode_String(deserializer);
+var var_senderKey = sse_decode_String(deserializer);
+var var_senderClaimedEd25519 = sse_decode_String(deserializer);
+var var_forwardingCurve25519KeyChain = sse_decode_list_String(deserializer);
+var var_origin = sse_decode_room_key_origin(deserializer);
+var var_sharedHistory = sse_decode_bool(deserializer);
+var var_backedUp = sse_decode_bool(deserializer);
+return RoomKeyInfo(algorithm: var_algorithm, roomId: var_roomId, senderKey: var_senderKey, senderClaimedEd25519: var_senderClaimedEd25519, forwardingCurve25519KeyChain: var_forwardingCurve25519KeyChain, origin: var_origin, sharedHistory: var_sharedHistory, backedUp: var_backedUp); }
+
+@protected RoomKeyOrigin sse_decode_room_key_origin(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RoomKeyOrigin.values[inner]; }
+
+@protected RoomRetention sse_decode_room_retention(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_roomId = sse_decode_String(deserializer);
+var var_maxAgeMs = sse_decode_u_64(deserializer);
+return RoomRetention(roomId: var_roomId, maxAgeMs: var_maxAgeMs); }
+
+@protected RotationReason sse_decode_rotation_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RotationReason.values[inner]; }
+
+@protected SessionKeySource sse_decode_session_key_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SessionKeySource.values[inner]; }
+
+@protected ShareRecipient sse_decode_share_recipient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userId = sse_decode_String(deserializer);
+var var_deviceId = sse_decode_String(deserializer);
+var var_curve25519Key = sse_decode_String(deserializer);
+return ShareRecipient(userId: var_userId, deviceId: var_deviceId, curve25519Key: var_curve25519Key); }
+
+@protected ToDeviceDecryptionError sse_decode_to_device_decryption_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ToDeviceDecryptionError.values[inner]; }
+
+@protected ToDeviceDecryptionResult sse_decode_to_device_decryption_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_plaintext = sse_decode_String(deserializer);
+var var_senderKey = sse_decode_vodozemac_curve_25519_public_key(deserializer);
+var var_sessionId = sse_decode_String(deserializer);
+var var_newSession = sse_decode_opt_box_autoadd_vodozemac_session(deserializer);
+return ToDeviceDecryptionResult(plaintext: var_plaintext, senderKey: var_senderKey, sessionId: var_sessionId, newSession: var_newSession); }
+
+@protected TrustLink sse_decode_trust_link(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TrustLink.values[inner]; }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected U8Array32 sse_decode_u_8_array_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return U8Array32(inner); }
+
+@protected U8Array64 sse_decode_u_8_array_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return U8Array64(inner); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected UnpickledAccount sse_decode_unpickled_account(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_account = sse_decode_vodozemac_account(deserializer);
+var var_needsRepickle = sse_decode_bool(deserializer);
+return UnpickledAccount(account: var_account, needsRepickle: var_needsRepickle); }
+
+@protected UnpickledGroupSession sse_decode_unpickled_group_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_session = sse_decode_vodozemac_group_session(deserializer);
+var var_needsRepickle = sse_decode_bool(deserializer);
+return UnpickledGroupSession(session: var_session, needsRepickle: var_needsRepickle); }
+
+@protected UnpickledInboundGroupSession sse_decode_unpickled_inbound_group_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_session = sse_decode_vodozemac_inbound_group_session(deserializer);
+var var_needsRepickle = sse_decode_bool(deserializer);
+return UnpickledInboundGroupSession(session: var_session, needsRepickle: var_needsRepickle); }
+
+@protected UnpickledPkDecryption sse_decode_unpickled_pk_decryption(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pkDecryption = sse_decode_vodozemac_pk_decryption(deserializer);
+var var_needsRepickle = sse_decode_bool(deserializer);
+return UnpickledPkDecryption(pkDecryption: var_pkDecryption, needsRepickle: var_needsRepickle); }
+
+@protected UnpickledSession sse_decode_unpickled_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_session = sse_decode_vodozemac_session(deserializer);
+var var_needsRepickle = sse_decode_bool(deserializer);
+return UnpickledSession(session: var_session, needsRepickle: var_needsRepickle); }
+
+@protected UnwedgeError sse_decode_unwedge_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UnwedgeError.values[inner]; }
+
+@protected UnwedgeResult sse_decode_unwedge_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_session = sse_decode_vodozemac_session(deserializer);
+var var_encryptedContent = sse_decode_String(deserializer);
+return UnwedgeResult(session: var_session, encryptedContent: var_encryptedContent); }
+
+@protected UnwedgeTracker sse_decode_unwedge_tracker(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_state = sse_decode_RustOpaque_RwLockUnwedgeState(deserializer);
+return UnwedgeTracker.raw(state: var_state); }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected UtdCause sse_decode_utd_cause(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UtdCause.values[inner]; }
+
+@protected UtdClassification sse_decode_utd_classification(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_cause = sse_decode_utd_cause(deserializer);
+var var_withheldCode = sse_decode_opt_box_autoadd_withheld_code(deserializer);
+return UtdClassification(cause: var_cause, withheldCode: var_withheldCode); }
+
+@protected UtdQueue sse_decode_utd_queue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_state = sse_decode_RustOpaque_RwLockUtdQueueState(deserializer);
+return UtdQueue.raw(state: var_state); }
+
+@protected VodozemacAccount sse_decode_vodozemac_account(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_account = sse_decode_RustOpaque_stdsyncRwLockAccount(deserializer);
+return VodozemacAccount.raw(account: var_account); }
+
+@protected VodozemacCurve25519PublicKey sse_decode_vodozemac_curve_25519_public_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_RustOpaque_Curve25519PublicKey(deserializer);
+return VodozemacCurve25519PublicKey(key: var_key); }
+
+@protected VodozemacEd25519PublicKey sse_decode_vodozemac_ed_25519_public_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_RustOpaque_Ed25519PublicKey(deserializer);
+return VodozemacEd25519PublicKey(key: var_key); }
+
+@protected VodozemacEd25519Signature sse_decode_vodozemac_ed_25519_signature(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_signature = sse_decode_RustOpaque_Ed25519Signature(deserializer);
+return VodozemacEd25519Signature(signature: var_signature); }
+
+@protected VodozemacEstablishedSas sse_decode_vodozemac_established_sas(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_establishedSas = sse_decode_RustOpaque_EstablishedSas(deserializer);
+return VodozemacEstablishedSas(establishedSas: var_establishedSas); }
+
+@protected VodozemacGroupSession sse_decode_vodozemac_group_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_session = sse_decode_RustOpaque_RwLockGroupSession(deserializer);
+return VodozemacGroupSession.raw(session: var_session); }
+
+@protected VodozemacIdentityKeys sse_decode_vodozemac_identity_keys(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_ed25519 = sse_decode_vodozemac_ed_25519_public_key(deserializer);
+var var_curve25519 = sse_decode_vodozemac_curve_25519_public_key(deserializer);
+return VodozemacIdentityKeys(ed25519: var_ed25519, curve25519: var_curve25519); }
+
+@protected VodozemacInboundGroupSession sse_decode_vodozemac_inbound_group_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_session = sse_decode_RustOpaque_RwLockInboundGroupSession(deserializer);
+var var_source = sse_decode_RustOpaque_RwLockSessionKeySource(deserializer);
+return VodozemacInboundGroupSession.raw(session: var_session, source: var_source); }
+
+@protected VodozemacMegolmSessionConfig sse_decode_vodozemac_megolm_session_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_config = sse_decode_RustOpaque_MegolmSessionConfig(deserializer);
+return VodozemacMegolmSessionConfig(config: var_config); }
+
+@protected VodozemacOlmMessage sse_decode_vodozemac_olm_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_msg = sse_decode_RustOpaque_OlmMessage(deserializer);
+return VodozemacOlmMessage(msg: var_msg); }
+
+@protected VodozemacOlmSessionConfig sse_decode_vodozemac_olm_session_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_config = sse_decode_RustOpaque_OlmSessionConfig(deserializer);
+return VodozemacOlmSessionConfig(config: var_config); }
+
+@protected VodozemacOlmSessionCreationResult sse_decode_vodozemac_olm_session_creation_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_session = sse_decode_vodozemac_session(deserializer);
+var var_plaintext = sse_decode_String(deserializer);
+return VodozemacOlmSessionCreationResult(session: var_session, plaintext: var_plaintext); }
+
+@protected VodozemacOneTimeKey sse_decode_vodozemac_one_time_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_keyid = sse_decode_String(deserializer);
+var var_key = sse_decode_vodozemac_curve_25519_public_key(deserializer);
+return VodozemacOneTimeKey(keyid: var_keyid, key: var_key); }
+
+@protected VodozemacPkDecryption sse_decode_vodozemac_pk_decryption(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pkDecryption = sse_decode_RustOpaque_PkDecryption(deserializer);
+return VodozemacPkDecryption.raw(pkDecryption: var_pkDecryption); }
+
+@protected VodozemacPkEncryption sse_decode_vodozemac_pk_encryption(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pkEncryption = sse_decode_RustOpaque_PkEncryption(deserializer);
+return VodozemacPkEncryption(pkEncryption: var_pkEncryption); }
+
+@protected VodozemacPkMessage sse_decode_vodozemac_pk_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_ciphertext = sse_decode_list_prim_u_8_strict(deserializer);
+var var_mac = sse_decode_list_prim_u_8_strict(deserializer);
+var var_ephemeralKey = sse_decode_vodozemac_curve_25519_public_key(deserializer);
+return VodozemacPkMessage.raw(ciphertext: var_ciphertext, mac: var_mac, ephemeralKey: var_ephemeralKey); }
+
+@protected VodozemacSession sse_decode_vodozemac_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_session = sse_decode_RustOpaque_RwLockSession(deserializer);
+return VodozemacSession(session: var_session); }
+
+@protected WithheldCode sse_decode_withheld_code(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return WithheldCode.values[inner]; }
+
+@protected int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+            return (raw as CrossSigningIdentityImpl).frbInternalCstEncode(move: true); }
+
+@protected int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+            return (raw as PkSigningImpl).frbInternalCstEncode(move: true); }
+
+@protected int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+            return (raw as VodozemacSasImpl).frbInternalCstEncode(move: true); }
+
+@protected int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+            return (raw as CrossSigningIdentityImpl).frbInternalCstEncode(move: false); }
+
+@protected int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+            return (raw as PkSigningImpl).frbInternalCstEncode(move: false); }
+
+@protected int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+            return (raw as VodozemacSasImpl).frbInternalCstEncode(move: false); }
+
+@protected int cst_encode_RustOpaque_Curve25519PublicKey(Curve25519PublicKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+                return (raw as Curve25519PublicKeyImpl).frbInternalCstEncode(); }
+
+@protected int cst_encode_RustOpaque_Ed25519PublicKey(Ed25519PublicKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+                return (raw as Ed25519PublicKeyImpl).frbInternalCstEncode(); }
+
+@protected int cst_encode_RustOpaque_Ed25519Signature(Ed25519Signature raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+                return (raw as Ed25519SignatureImpl).frbInternalCstEncode(); }
+
+@protected int cst_encode_RustOpaque_EstablishedSas(EstablishedSas raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+                return (raw as EstablishedSasImpl).frbInternalCstEncode(); }
+
+@protected int cst_encode_RustOpaque_MegolmSessionConfig(MegolmSessionConfig raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+                return (raw as MegolmSessionConfigImpl).frbInternalCstEncode(); }
+
+@protected int cst_encode_RustOpaque_OlmMessage(OlmMessage raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+                return (raw as OlmMessageImpl).frbInternalCstEncode(); }
+
+@protected int cst_encode_RustOpaque_OlmSessionConfig(OlmSessionConfig raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as PkSigningImpl).frbInternalCstEncode(move: true);
-  }
+                return (raw as OlmSessionConfigImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      VodozemacSas raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_PkDecryption(PkDecryption raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as VodozemacSasImpl).frbInternalCstEncode(move: true);
-  }
+                return (raw as PkDecryptionImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_PkEncryption(PkEncryption raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as PkSigningImpl).frbInternalCstEncode(move: false);
-  }
+                return (raw as PkEncryptionImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_RwLockFallbackKeyState(RwLockFallbackKeyState raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as VodozemacSasImpl).frbInternalCstEncode(move: false);
-  }
+                return (raw as RwLockFallbackKeyStateImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_Curve25519PublicKey(Curve25519PublicKey raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_RwLockGroupSession(RwLockGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as Curve25519PublicKeyImpl).frbInternalCstEncode();
-  }
+                return (raw as RwLockGroupSessionImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_Ed25519PublicKey(Ed25519PublicKey raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_RwLockInboundGroupSession(RwLockInboundGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as Ed25519PublicKeyImpl).frbInternalCstEncode();
-  }
+                return (raw as RwLockInboundGroupSessionImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_Ed25519Signature(Ed25519Signature raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_RwLockReplayState(RwLockReplayState raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as Ed25519SignatureImpl).frbInternalCstEncode();
-  }
+                return (raw as RwLockReplayStateImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_EstablishedSas(EstablishedSas raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_RwLockRoomKeyInfo(RwLockRoomKeyInfo raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as EstablishedSasImpl).frbInternalCstEncode();
-  }
+                return (raw as RwLockRoomKeyInfoImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_MegolmSessionConfig(MegolmSessionConfig raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_RwLockRotationState(RwLockRotationState raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as MegolmSessionConfigImpl).frbInternalCstEncode();
-  }
+                return (raw as RwLockRotationStateImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_OlmMessage(OlmMessage raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_RwLockSession(RwLockSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as OlmMessageImpl).frbInternalCstEncode();
-  }
+                return (raw as RwLockSessionImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_OlmSessionConfig(OlmSessionConfig raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_RwLockSessionKeySource(RwLockSessionKeySource raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as OlmSessionConfigImpl).frbInternalCstEncode();
-  }
+                return (raw as RwLockSessionKeySourceImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_PkDecryption(PkDecryption raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_RwLockSessionStoreState(RwLockSessionStoreState raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as PkDecryptionImpl).frbInternalCstEncode();
-  }
+                return (raw as RwLockSessionStoreStateImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_PkEncryption(PkEncryption raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_RwLockShareState(RwLockShareState raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as PkEncryptionImpl).frbInternalCstEncode();
-  }
+                return (raw as RwLockShareStateImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_RwLockGroupSession(RwLockGroupSession raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_RwLockUnwedgeState(RwLockUnwedgeState raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as RwLockGroupSessionImpl).frbInternalCstEncode();
-  }
+                return (raw as RwLockUnwedgeStateImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_RwLockInboundGroupSession(RwLockInboundGroupSession raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_RwLockUtdQueueState(RwLockUtdQueueState raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as RwLockInboundGroupSessionImpl).frbInternalCstEncode();
-  }
+                return (raw as RwLockUtdQueueStateImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_RwLockSession(RwLockSession raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as RwLockSessionImpl).frbInternalCstEncode();
-  }
+                return (raw as CrossSigningIdentityImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as PkSigningImpl).frbInternalCstEncode();
-  }
+                return (raw as PkSigningImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as VodozemacSasImpl).frbInternalCstEncode();
-  }
+                return (raw as VodozemacSasImpl).frbInternalCstEncode(); }
 
-  @protected
-  int cst_encode_RustOpaque_stdsyncRwLockAccount(RwLockAccount raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
+@protected int cst_encode_RustOpaque_stdsyncRwLockAccount(RwLockAccount raw){ // Codec=Cst (C-struct based), see doc to use other codecs
 // ignore: invalid_use_of_internal_member
-    return (raw as RwLockAccountImpl).frbInternalCstEncode();
-  }
-
-  @protected
-  bool cst_encode_bool(bool raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
-  }
-
-  @protected
-  int cst_encode_u_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
-  }
-
-  @protected
-  int cst_encode_u_8(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
-  }
-
-  @protected
-  void cst_encode_unit(void raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
-  }
-
-  @protected
-  void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      PkSigning self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as PkSigningImpl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      VodozemacSas self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as VodozemacSasImpl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      PkSigning self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as PkSigningImpl).frbInternalSseEncode(move: false), serializer);
-  }
-
-  @protected
-  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      VodozemacSas self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as VodozemacSasImpl).frbInternalSseEncode(move: false), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_Curve25519PublicKey(Curve25519PublicKey self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as Curve25519PublicKeyImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_Ed25519PublicKey(Ed25519PublicKey self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as Ed25519PublicKeyImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_Ed25519Signature(Ed25519Signature self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as Ed25519SignatureImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_EstablishedSas(EstablishedSas self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as EstablishedSasImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_MegolmSessionConfig(MegolmSessionConfig self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as MegolmSessionConfigImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_OlmMessage(OlmMessage self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as OlmMessageImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_OlmSessionConfig(OlmSessionConfig self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as OlmSessionConfigImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_PkDecryption(PkDecryption self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as PkDecryptionImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_PkEncryption(PkEncryption self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as PkEncryptionImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_RwLockGroupSession(RwLockGroupSession self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as RwLockGroupSessionImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_RwLockInboundGroupSession(RwLockInboundGroupSession self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as RwLockInboundGroupSessionImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_RwLockSession(RwLockSession self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as RwLockSessionImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      PkSigning self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as PkSigningImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      VodozemacSas self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as VodozemacSasImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_RustOpaque_stdsyncRwLockAccount(RwLockAccount self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as RwLockAccountImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_account(VodozemacAccount self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_account(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_curve_25519_public_key(
-      VodozemacCurve25519PublicKey self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_curve_25519_public_key(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_ed_25519_public_key(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_ed_25519_signature(VodozemacEd25519Signature self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_ed_25519_signature(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_established_sas(VodozemacEstablishedSas self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_established_sas(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_group_session(VodozemacGroupSession self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_group_session(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_inbound_group_session(
-      VodozemacInboundGroupSession self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_inbound_group_session(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_megolm_session_config(
-      VodozemacMegolmSessionConfig self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_megolm_session_config(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_olm_message(VodozemacOlmMessage self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_olm_message(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_olm_session_config(VodozemacOlmSessionConfig self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_olm_session_config(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_pk_decryption(VodozemacPkDecryption self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_pk_decryption(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_pk_encryption(VodozemacPkEncryption self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_pk_encryption(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_pk_message(VodozemacPkMessage self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_pk_message(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_vodozemac_session(VodozemacSession self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_session(self, serializer);
-  }
-
-  @protected
-  void sse_encode_decrypt_result(DecryptResult self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.field0, serializer);
-    sse_encode_u_32(self.field1, serializer);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self));
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_vodozemac_one_time_key(List<VodozemacOneTimeKey> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_vodozemac_one_time_key(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_record_string_string_string((String, String, String) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_String(self.$2, serializer);
-    sse_encode_String(self.$3, serializer);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_u_8_array_32(U8Array32 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.inner, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8_array_64(U8Array64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.inner, serializer);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_vodozemac_account(VodozemacAccount self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_stdsyncRwLockAccount(self.account, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_Curve25519PublicKey(self.key, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_Ed25519PublicKey(self.key, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_ed_25519_signature(VodozemacEd25519Signature self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_Ed25519Signature(self.signature, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_established_sas(VodozemacEstablishedSas self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_EstablishedSas(self.establishedSas, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_group_session(VodozemacGroupSession self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_RwLockGroupSession(self.session, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_identity_keys(VodozemacIdentityKeys self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_ed_25519_public_key(self.ed25519, serializer);
-    sse_encode_vodozemac_curve_25519_public_key(self.curve25519, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_inbound_group_session(VodozemacInboundGroupSession self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_RwLockInboundGroupSession(self.session, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_MegolmSessionConfig(self.config, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_olm_message(VodozemacOlmMessage self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_OlmMessage(self.msg, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_olm_session_config(VodozemacOlmSessionConfig self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_OlmSessionConfig(self.config, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_olm_session_creation_result(
-      VodozemacOlmSessionCreationResult self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_vodozemac_session(self.session, serializer);
-    sse_encode_String(self.plaintext, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_one_time_key(VodozemacOneTimeKey self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.keyid, serializer);
-    sse_encode_vodozemac_curve_25519_public_key(self.key, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_pk_decryption(VodozemacPkDecryption self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_PkDecryption(self.pkDecryption, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_pk_encryption(VodozemacPkEncryption self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_PkEncryption(self.pkEncryption, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_pk_message(VodozemacPkMessage self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.ciphertext, serializer);
-    sse_encode_list_prim_u_8_strict(self.mac, serializer);
-    sse_encode_vodozemac_curve_25519_public_key(self.ephemeralKey, serializer);
-  }
-
-  @protected
-  void sse_encode_vodozemac_session(VodozemacSession self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_RustOpaque_RwLockSession(self.session, serializer);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-}
-
-@sealed
-class Curve25519PublicKeyImpl extends RustOpaque implements Curve25519PublicKey {
-  // Not to be used by end users
-  Curve25519PublicKeyImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  Curve25519PublicKeyImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Curve25519PublicKey,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Curve25519PublicKey,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Curve25519PublicKeyPtr,
-  );
-}
-
-@sealed
-class Ed25519PublicKeyImpl extends RustOpaque implements Ed25519PublicKey {
-  // Not to be used by end users
-  Ed25519PublicKeyImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  Ed25519PublicKeyImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Ed25519PublicKey,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Ed25519PublicKey,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Ed25519PublicKeyPtr,
-  );
-}
-
-@sealed
-class Ed25519SignatureImpl extends RustOpaque implements Ed25519Signature {
-  // Not to be used by end users
-  Ed25519SignatureImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  Ed25519SignatureImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Ed25519Signature,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Ed25519Signature,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Ed25519SignaturePtr,
-  );
-}
-
-@sealed
-class EstablishedSasImpl extends RustOpaque implements EstablishedSas {
-  // Not to be used by end users
-  EstablishedSasImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  EstablishedSasImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_EstablishedSas,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_EstablishedSas,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_EstablishedSasPtr,
-  );
-}
-
-@sealed
-class MegolmSessionConfigImpl extends RustOpaque implements MegolmSessionConfig {
-  // Not to be used by end users
-  MegolmSessionConfigImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  MegolmSessionConfigImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_MegolmSessionConfig,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_MegolmSessionConfig,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_MegolmSessionConfigPtr,
-  );
-}
-
-@sealed
-class OlmMessageImpl extends RustOpaque implements OlmMessage {
-  // Not to be used by end users
-  OlmMessageImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  OlmMessageImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_OlmMessage,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_OlmMessage,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_OlmMessagePtr,
-  );
-}
-
-@sealed
-class OlmSessionConfigImpl extends RustOpaque implements OlmSessionConfig {
-  // Not to be used by end users
-  OlmSessionConfigImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  OlmSessionConfigImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_OlmSessionConfig,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_OlmSessionConfig,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_OlmSessionConfigPtr,
-  );
-}
-
-@sealed
-class PkDecryptionImpl extends RustOpaque implements PkDecryption {
-  // Not to be used by end users
-  PkDecryptionImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  PkDecryptionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_PkDecryption,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_PkDecryption,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_PkDecryptionPtr,
-  );
-}
-
-@sealed
-class PkEncryptionImpl extends RustOpaque implements PkEncryption {
-  // Not to be used by end users
-  PkEncryptionImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  PkEncryptionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_PkEncryption,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_PkEncryption,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_PkEncryptionPtr,
-  );
-}
-
-@sealed
-class PkSigningImpl extends RustOpaque implements PkSigning {
-  // Not to be used by end users
-  PkSigningImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  PkSigningImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_PkSigning,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_PkSigning,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_PkSigningPtr,
-  );
-
-  VodozemacEd25519PublicKey publicKey() => RustLib.instance.api.crateBindingsPkSigningPublicKey(
-        that: this,
-      );
-
-  String secretKey() => RustLib.instance.api.crateBindingsPkSigningSecretKey(
-        that: this,
-      );
-
-  VodozemacEd25519Signature sign({required String message}) =>
-      RustLib.instance.api.crateBindingsPkSigningSign(that: this, message: message);
-}
-
-@sealed
-class RwLockAccountImpl extends RustOpaque implements RwLockAccount {
-  // Not to be used by end users
-  RwLockAccountImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  RwLockAccountImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockAccount,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockAccount,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockAccountPtr,
-  );
-}
-
-@sealed
-class RwLockGroupSessionImpl extends RustOpaque implements RwLockGroupSession {
-  // Not to be used by end users
-  RwLockGroupSessionImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  RwLockGroupSessionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockGroupSession,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockGroupSession,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockGroupSessionPtr,
-  );
-}
-
-@sealed
-class RwLockInboundGroupSessionImpl extends RustOpaque implements RwLockInboundGroupSession {
-  // Not to be used by end users
-  RwLockInboundGroupSessionImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  RwLockInboundGroupSessionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockInboundGroupSession,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockInboundGroupSession,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockInboundGroupSessionPtr,
-  );
-}
-
-@sealed
-class RwLockSessionImpl extends RustOpaque implements RwLockSession {
-  // Not to be used by end users
-  RwLockSessionImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  RwLockSessionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockSession,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockSession,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockSessionPtr,
-  );
-}
-
-@sealed
-class VodozemacSasImpl extends RustOpaque implements VodozemacSas {
-  // Not to be used by end users
-  VodozemacSasImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  VodozemacSasImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_VodozemacSas,
-    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_VodozemacSas,
-    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_VodozemacSasPtr,
-  );
-
-  VodozemacEstablishedSas establishSasSecret({required String otherPublicKey}) =>
-      RustLib.instance.api.crateBindingsVodozemacSasEstablishSasSecret(that: this, otherPublicKey: otherPublicKey);
-
-  String publicKey() => RustLib.instance.api.crateBindingsVodozemacSasPublicKey(
-        that: this,
-      );
-}
+                return (raw as RwLockAccountImpl).frbInternalCstEncode(); }
+
+@protected bool cst_encode_bool(bool raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected int cst_encode_device_keys_error(DeviceKeysError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_device_trust_level(DeviceTrustLevel raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_i_32(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected int cst_encode_key_authenticity(KeyAuthenticity raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_key_claim_error(KeyClaimError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_migration_error(MigrationError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_olm_payload_error(OlmPayloadError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_passphrase_kdf(PassphraseKdf raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_passphrase_pickle_error(PassphrasePickleError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_pickle_format(PickleFormat raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_pickle_format_error(PickleFormatError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_pickle_object(PickleObject raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_room_event_error(RoomEventError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_room_key_bundle_error(RoomKeyBundleError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_room_key_error(RoomKeyError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_room_key_origin(RoomKeyOrigin raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_rotation_reason(RotationReason raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_session_key_source(SessionKeySource raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_to_device_decryption_error(ToDeviceDecryptionError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_trust_link(TrustLink raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_u_32(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected int cst_encode_u_8(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected void cst_encode_unit(void raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected int cst_encode_unwedge_error(UnwedgeError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_utd_cause(UtdCause raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_withheld_code(WithheldCode raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CrossSigningIdentityImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PkSigningImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as VodozemacSasImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CrossSigningIdentityImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PkSigningImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as VodozemacSasImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Map_String_u_32_None(Map<String, int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_u_32(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_RustOpaque_Curve25519PublicKey(Curve25519PublicKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Curve25519PublicKeyImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_Ed25519PublicKey(Ed25519PublicKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Ed25519PublicKeyImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_Ed25519Signature(Ed25519Signature self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Ed25519SignatureImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_EstablishedSas(EstablishedSas self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as EstablishedSasImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_MegolmSessionConfig(MegolmSessionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MegolmSessionConfigImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_OlmMessage(OlmMessage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as OlmMessageImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_OlmSessionConfig(OlmSessionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as OlmSessionConfigImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_PkDecryption(PkDecryption self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PkDecryptionImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_PkEncryption(PkEncryption self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PkEncryptionImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_RwLockFallbackKeyState(RwLockFallbackKeyState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockFallbackKeyStateImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_RwLockGroupSession(RwLockGroupSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockGroupSessionImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_RwLockInboundGroupSession(RwLockInboundGroupSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockInboundGroupSessionImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_RwLockReplayState(RwLockReplayState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockReplayStateImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_RwLockRoomKeyInfo(RwLockRoomKeyInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockRoomKeyInfoImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_RwLockRotationState(RwLockRotationState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockRotationStateImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_RwLockSession(RwLockSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockSessionImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_RwLockSessionKeySource(RwLockSessionKeySource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockSessionKeySourceImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_RwLockSessionStoreState(RwLockSessionStoreState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockSessionStoreStateImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_RwLockShareState(RwLockShareState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockShareStateImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_RwLockUnwedgeState(RwLockUnwedgeState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockUnwedgeStateImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_RwLockUtdQueueState(RwLockUtdQueueState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockUtdQueueStateImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CrossSigningIdentityImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PkSigningImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as VodozemacSasImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_stdsyncRwLockAccount(RwLockAccount self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as RwLockAccountImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_rekey_progress_Dco(RustStreamSink<RekeyProgress> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_rekey_progress,
+            decodeErrorData: dco_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_device_keys(DeviceKeys self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_device_keys(self, serializer); }
+
+@protected void sse_encode_box_autoadd_encrypted_room_key_bundle(EncryptedRoomKeyBundle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_encrypted_room_key_bundle(self, serializer); }
+
+@protected void sse_encode_box_autoadd_fallback_key_tracker(FallbackKeyTracker self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_fallback_key_tracker(self, serializer); }
+
+@protected void sse_encode_box_autoadd_inbound_room_key(InboundRoomKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_inbound_room_key(self, serializer); }
+
+@protected void sse_encode_box_autoadd_libolm_store(LibolmStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libolm_store(self, serializer); }
+
+@protected void sse_encode_box_autoadd_megolm_replay_guard(MegolmReplayGuard self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_megolm_replay_guard(self, serializer); }
+
+@protected void sse_encode_box_autoadd_migrated_pickle(MigratedPickle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_migrated_pickle(self, serializer); }
+
+@protected void sse_encode_box_autoadd_migration_report(MigrationReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_migration_report(self, serializer); }
+
+@protected void sse_encode_box_autoadd_olm_payload(OlmPayload self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_olm_payload(self, serializer); }
+
+@protected void sse_encode_box_autoadd_olm_session_store(OlmSessionStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_olm_session_store(self, serializer); }
+
+@protected void sse_encode_box_autoadd_outbound_group_session(OutboundGroupSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_outbound_group_session(self, serializer); }
+
+@protected void sse_encode_box_autoadd_passphrase_kdf_params(PassphraseKdfParams self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_passphrase_kdf_params(self, serializer); }
+
+@protected void sse_encode_box_autoadd_pickle_format_error(PickleFormatError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pickle_format_error(self, serializer); }
+
+@protected void sse_encode_box_autoadd_pickle_info(PickleInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pickle_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_retained_room_key(RetainedRoomKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_retained_room_key(self, serializer); }
+
+@protected void sse_encode_box_autoadd_room_key_bundle_download(RoomKeyBundleDownload self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_room_key_bundle_download(self, serializer); }
+
+@protected void sse_encode_box_autoadd_room_key_info(RoomKeyInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_room_key_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_rotation_reason(RotationReason self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_rotation_reason(self, serializer); }
+
+@protected void sse_encode_box_autoadd_share_recipient(ShareRecipient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_share_recipient(self, serializer); }
+
+@protected void sse_encode_box_autoadd_trust_link(TrustLink self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_trust_link(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_unwedge_tracker(UnwedgeTracker self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_unwedge_tracker(self, serializer); }
+
+@protected void sse_encode_box_autoadd_utd_classification(UtdClassification self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_utd_classification(self, serializer); }
+
+@protected void sse_encode_box_autoadd_utd_queue(UtdQueue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_utd_queue(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_account(VodozemacAccount self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_account(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_curve_25519_public_key(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_ed_25519_public_key(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_ed_25519_signature(VodozemacEd25519Signature self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_ed_25519_signature(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_established_sas(VodozemacEstablishedSas self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_established_sas(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_group_session(VodozemacGroupSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_group_session(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_inbound_group_session(VodozemacInboundGroupSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_inbound_group_session(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_megolm_session_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_olm_message(VodozemacOlmMessage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_olm_message(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_olm_session_config(VodozemacOlmSessionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_olm_session_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_pk_decryption(VodozemacPkDecryption self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_pk_decryption(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_pk_encryption(VodozemacPkEncryption self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_pk_encryption(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_pk_message(VodozemacPkMessage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_pk_message(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vodozemac_session(VodozemacSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_session(self, serializer); }
+
+@protected void sse_encode_box_autoadd_withheld_code(WithheldCode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_withheld_code(self, serializer); }
+
+@protected void sse_encode_claimed_session(ClaimedSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_session(self.session, serializer);
+sse_encode_bool(self.fallback, serializer);
+ }
+
+@protected void sse_encode_decrypt_result(DecryptResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.field0, serializer);
+sse_encode_u_32(self.field1, serializer);
+ }
+
+@protected void sse_encode_device_keys(DeviceKeys self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.userId, serializer);
+sse_encode_String(self.deviceId, serializer);
+sse_encode_list_String(self.algorithms, serializer);
+sse_encode_vodozemac_ed_25519_public_key(self.ed25519, serializer);
+sse_encode_vodozemac_curve_25519_public_key(self.curve25519, serializer);
+sse_encode_opt_String(self.displayName, serializer);
+ }
+
+@protected void sse_encode_device_keys_error(DeviceKeysError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_device_trust(DeviceTrust self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.userId, serializer);
+sse_encode_String(self.deviceId, serializer);
+sse_encode_device_trust_level(self.level, serializer);
+sse_encode_opt_box_autoadd_trust_link(self.failedLink, serializer);
+ }
+
+@protected void sse_encode_device_trust_level(DeviceTrustLevel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_encrypted_room_key_bundle(EncryptedRoomKeyBundle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.roomId, serializer);
+sse_encode_list_prim_u_8_strict(self.ciphertext, serializer);
+sse_encode_String(self.file, serializer);
+ }
+
+@protected void sse_encode_extended_decrypt_result(ExtendedDecryptResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.plaintext, serializer);
+sse_encode_u_32(self.messageIndex, serializer);
+sse_encode_vodozemac_ed_25519_public_key(self.signingKey, serializer);
+sse_encode_session_key_source(self.keySource, serializer);
+ }
+
+@protected void sse_encode_fallback_key_tracker(FallbackKeyTracker self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_RwLockFallbackKeyState(self.state, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_imported_room_key_bundle(ImportedRoomKeyBundle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_inbound_room_key(self.roomKeys, serializer);
+sse_encode_u_32(self.skipped, serializer);
+ }
+
+@protected void sse_encode_inbound_room_key(InboundRoomKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_RwLockInboundGroupSession(self.session, serializer);
+sse_encode_RustOpaque_RwLockSessionKeySource(self.source, serializer);
+sse_encode_RustOpaque_RwLockRoomKeyInfo(self.keyInfo, serializer);
+ }
+
+@protected void sse_encode_key_authenticity(KeyAuthenticity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_key_claim_error(KeyClaimError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_key_maintenance_plan(KeyMaintenancePlan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.generateOneTimeKeys, serializer);
+sse_encode_bool(self.rotateFallbackKey, serializer);
+sse_encode_bool(self.forgetOldFallbackKey, serializer);
+ }
+
+@protected void sse_encode_libolm_store(LibolmStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.account, serializer);
+sse_encode_list_String(self.sessions, serializer);
+sse_encode_list_String(self.inboundGroupSessions, serializer);
+sse_encode_opt_String(self.pkDecryption, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_device_trust(List<DeviceTrust> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_device_trust(item, serializer); } }
+
+@protected void sse_encode_list_inbound_room_key(List<InboundRoomKey> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_inbound_room_key(item, serializer); } }
+
+@protected void sse_encode_list_migrated_pickle(List<MigratedPickle> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_migrated_pickle(item, serializer); } }
+
+@protected void sse_encode_list_migration_failure(List<MigrationFailure> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_migration_failure(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_record_string_u_32(List<(String,int)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_u_32(item, serializer); } }
+
+@protected void sse_encode_list_rekey_result(List<RekeyResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_rekey_result(item, serializer); } }
+
+@protected void sse_encode_list_retention_result(List<RetentionResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_retention_result(item, serializer); } }
+
+@protected void sse_encode_list_retried_event(List<RetriedEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_retried_event(item, serializer); } }
+
+@protected void sse_encode_list_room_retention(List<RoomRetention> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_room_retention(item, serializer); } }
+
+@protected void sse_encode_list_share_recipient(List<ShareRecipient> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_share_recipient(item, serializer); } }
+
+@protected void sse_encode_list_vodozemac_one_time_key(List<VodozemacOneTimeKey> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_vodozemac_one_time_key(item, serializer); } }
+
+@protected void sse_encode_list_vodozemac_session(List<VodozemacSession> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_vodozemac_session(item, serializer); } }
+
+@protected void sse_encode_megolm_replay_guard(MegolmReplayGuard self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_RwLockReplayState(self.state, serializer);
+ }
+
+@protected void sse_encode_migrated_pickle(MigratedPickle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_String(self.idBefore, serializer);
+sse_encode_String(self.idAfter, serializer);
+sse_encode_String(self.pickle, serializer);
+ }
+
+@protected void sse_encode_migration_error(MigrationError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_migration_failure(MigrationFailure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pickle_object(self.object, serializer);
+sse_encode_u_32(self.index, serializer);
+sse_encode_opt_String(self.idBefore, serializer);
+sse_encode_opt_String(self.idAfter, serializer);
+sse_encode_migration_error(self.error, serializer);
+ }
+
+@protected void sse_encode_migration_report(MigrationReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_migrated_pickle(self.account, serializer);
+sse_encode_list_migrated_pickle(self.sessions, serializer);
+sse_encode_list_migrated_pickle(self.inboundGroupSessions, serializer);
+sse_encode_opt_box_autoadd_migrated_pickle(self.pkDecryption, serializer);
+sse_encode_list_migration_failure(self.failures, serializer);
+ }
+
+@protected void sse_encode_olm_payload(OlmPayload self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.eventType, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_String(self.sender, serializer);
+sse_encode_opt_String(self.senderDevice, serializer);
+sse_encode_vodozemac_ed_25519_public_key(self.senderClaimedEd25519, serializer);
+ }
+
+@protected void sse_encode_olm_payload_error(OlmPayloadError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_olm_session_store(OlmSessionStore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_RwLockSessionStoreState(self.state, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_migrated_pickle(MigratedPickle? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_migrated_pickle(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_pickle_format_error(PickleFormatError? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_pickle_format_error(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_retained_room_key(RetainedRoomKey? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_retained_room_key(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_rotation_reason(RotationReason? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_rotation_reason(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_trust_link(TrustLink? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_trust_link(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_utd_classification(UtdClassification? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_utd_classification(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_vodozemac_ed_25519_public_key(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_vodozemac_session(VodozemacSession? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_vodozemac_session(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_withheld_code(WithheldCode? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_withheld_code(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_rekey_result(List<RekeyResult>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_rekey_result(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_outbound_group_session(OutboundGroupSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_RwLockGroupSession(self.session, serializer);
+sse_encode_RustOpaque_RwLockRotationState(self.rotation, serializer);
+sse_encode_RustOpaque_RwLockShareState(self.shares, serializer);
+ }
+
+@protected void sse_encode_passphrase_kdf(PassphraseKdf self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_passphrase_kdf_params(PassphraseKdfParams self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_passphrase_kdf(self.kdf, serializer);
+sse_encode_u_32(self.iterations, serializer);
+ }
+
+@protected void sse_encode_passphrase_pickle_error(PassphrasePickleError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_pickle_format(PickleFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_pickle_format_error(PickleFormatError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_pickle_info(PickleInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pickle_format(self.format, serializer);
+sse_encode_pickle_object(self.object, serializer);
+sse_encode_opt_box_autoadd_u_32(self.version, serializer);
+ }
+
+@protected void sse_encode_pickle_object(PickleObject self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_record_string_string_string((String,String,String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_String(self.$2, serializer);
+sse_encode_String(self.$3, serializer);
+ }
+
+@protected void sse_encode_record_string_u_32((String,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_u_32(self.$2, serializer);
+ }
+
+@protected void sse_encode_rekey_progress(RekeyProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.done, serializer);
+sse_encode_opt_list_rekey_result(self.results, serializer);
+ }
+
+@protected void sse_encode_rekey_result(RekeyResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.pickle, serializer);
+sse_encode_opt_box_autoadd_pickle_format_error(self.error, serializer);
+ }
+
+@protected void sse_encode_retained_room_key(RetainedRoomKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.sessionId, serializer);
+sse_encode_String(self.pickle, serializer);
+sse_encode_u_32(self.firstKnownIndex, serializer);
+sse_encode_bool(self.discarded, serializer);
+ }
+
+@protected void sse_encode_retention_result(RetentionResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_retained_room_key(self.roomKey, serializer);
+sse_encode_opt_box_autoadd_pickle_format_error(self.error, serializer);
+ }
+
+@protected void sse_encode_retried_event(RetriedEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.eventId, serializer);
+sse_encode_room_key_decryption_result(self.result, serializer);
+ }
+
+@protected void sse_encode_room_event_decryption_result(RoomEventDecryptionResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.eventType, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_u_32(self.messageIndex, serializer);
+ }
+
+@protected void sse_encode_room_event_error(RoomEventError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_room_key_bundle_download(RoomKeyBundleDownload self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.roomId, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.file, serializer);
+sse_encode_String(self.senderKey, serializer);
+ }
+
+@protected void sse_encode_room_key_bundle_error(RoomKeyBundleError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_room_key_decryption_result(RoomKeyDecryptionResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.eventType, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_u_32(self.messageIndex, serializer);
+sse_encode_key_authenticity(self.authenticity, serializer);
+sse_encode_String(self.senderKey, serializer);
+sse_encode_String(self.senderClaimedEd25519, serializer);
+ }
+
+@protected void sse_encode_room_key_error(RoomKeyError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_room_key_info(RoomKeyInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.algorithm, serializer);
+sse_encode_String(self.roomId, serializer);
+sse_encode_String(self.senderKey, serializer);
+sse_encode_String(self.senderClaimedEd25519, serializer);
+sse_encode_list_String(self.forwardingCurve25519KeyChain, serializer);
+sse_encode_room_key_origin(self.origin, serializer);
+sse_encode_bool(self.sharedHistory, serializer);
+sse_encode_bool(self.backedUp, serializer);
+ }
+
+@protected void sse_encode_room_key_origin(RoomKeyOrigin self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_room_retention(RoomRetention self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.roomId, serializer);
+sse_encode_u_64(self.maxAgeMs, serializer);
+ }
+
+@protected void sse_encode_rotation_reason(RotationReason self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_session_key_source(SessionKeySource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_share_recipient(ShareRecipient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.userId, serializer);
+sse_encode_String(self.deviceId, serializer);
+sse_encode_String(self.curve25519Key, serializer);
+ }
+
+@protected void sse_encode_to_device_decryption_error(ToDeviceDecryptionError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_to_device_decryption_result(ToDeviceDecryptionResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.plaintext, serializer);
+sse_encode_vodozemac_curve_25519_public_key(self.senderKey, serializer);
+sse_encode_String(self.sessionId, serializer);
+sse_encode_opt_box_autoadd_vodozemac_session(self.newSession, serializer);
+ }
+
+@protected void sse_encode_trust_link(TrustLink self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_u_8_array_32(U8Array32 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.inner, serializer); }
+
+@protected void sse_encode_u_8_array_64(U8Array64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.inner, serializer); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_unpickled_account(UnpickledAccount self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_account(self.account, serializer);
+sse_encode_bool(self.needsRepickle, serializer);
+ }
+
+@protected void sse_encode_unpickled_group_session(UnpickledGroupSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_group_session(self.session, serializer);
+sse_encode_bool(self.needsRepickle, serializer);
+ }
+
+@protected void sse_encode_unpickled_inbound_group_session(UnpickledInboundGroupSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_inbound_group_session(self.session, serializer);
+sse_encode_bool(self.needsRepickle, serializer);
+ }
+
+@protected void sse_encode_unpickled_pk_decryption(UnpickledPkDecryption self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_pk_decryption(self.pkDecryption, serializer);
+sse_encode_bool(self.needsRepickle, serializer);
+ }
+
+@protected void sse_encode_unpickled_session(UnpickledSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_session(self.session, serializer);
+sse_encode_bool(self.needsRepickle, serializer);
+ }
+
+@protected void sse_encode_unwedge_error(UnwedgeError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_unwedge_result(UnwedgeResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_session(self.session, serializer);
+sse_encode_String(self.encryptedContent, serializer);
+ }
+
+@protected void sse_encode_unwedge_tracker(UnwedgeTracker self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_RwLockUnwedgeState(self.state, serializer);
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_utd_cause(UtdCause self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_utd_classification(UtdClassification self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_utd_cause(self.cause, serializer);
+sse_encode_opt_box_autoadd_withheld_code(self.withheldCode, serializer);
+ }
+
+@protected void sse_encode_utd_queue(UtdQueue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_RwLockUtdQueueState(self.state, serializer);
+ }
+
+@protected void sse_encode_vodozemac_account(VodozemacAccount self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_stdsyncRwLockAccount(self.account, serializer);
+ }
+
+@protected void sse_encode_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_Curve25519PublicKey(self.key, serializer);
+ }
+
+@protected void sse_encode_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_Ed25519PublicKey(self.key, serializer);
+ }
+
+@protected void sse_encode_vodozemac_ed_25519_signature(VodozemacEd25519Signature self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_Ed25519Signature(self.signature, serializer);
+ }
+
+@protected void sse_encode_vodozemac_established_sas(VodozemacEstablishedSas self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_EstablishedSas(self.establishedSas, serializer);
+ }
+
+@protected void sse_encode_vodozemac_group_session(VodozemacGroupSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_RwLockGroupSession(self.session, serializer);
+ }
+
+@protected void sse_encode_vodozemac_identity_keys(VodozemacIdentityKeys self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_ed_25519_public_key(self.ed25519, serializer);
+sse_encode_vodozemac_curve_25519_public_key(self.curve25519, serializer);
+ }
+
+@protected void sse_encode_vodozemac_inbound_group_session(VodozemacInboundGroupSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_RwLockInboundGroupSession(self.session, serializer);
+sse_encode_RustOpaque_RwLockSessionKeySource(self.source, serializer);
+ }
+
+@protected void sse_encode_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_MegolmSessionConfig(self.config, serializer);
+ }
+
+@protected void sse_encode_vodozemac_olm_message(VodozemacOlmMessage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_OlmMessage(self.msg, serializer);
+ }
+
+@protected void sse_encode_vodozemac_olm_session_config(VodozemacOlmSessionConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_OlmSessionConfig(self.config, serializer);
+ }
+
+@protected void sse_encode_vodozemac_olm_session_creation_result(VodozemacOlmSessionCreationResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vodozemac_session(self.session, serializer);
+sse_encode_String(self.plaintext, serializer);
+ }
+
+@protected void sse_encode_vodozemac_one_time_key(VodozemacOneTimeKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.keyid, serializer);
+sse_encode_vodozemac_curve_25519_public_key(self.key, serializer);
+ }
+
+@protected void sse_encode_vodozemac_pk_decryption(VodozemacPkDecryption self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_PkDecryption(self.pkDecryption, serializer);
+ }
+
+@protected void sse_encode_vodozemac_pk_encryption(VodozemacPkEncryption self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_PkEncryption(self.pkEncryption, serializer);
+ }
+
+@protected void sse_encode_vodozemac_pk_message(VodozemacPkMessage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.ciphertext, serializer);
+sse_encode_list_prim_u_8_strict(self.mac, serializer);
+sse_encode_vodozemac_curve_25519_public_key(self.ephemeralKey, serializer);
+ }
+
+@protected void sse_encode_vodozemac_session(VodozemacSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_RwLockSession(self.session, serializer);
+ }
+
+@protected void sse_encode_withheld_code(WithheldCode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+                }
+                
+
+            @sealed class CrossSigningIdentityImpl extends RustOpaque implements CrossSigningIdentity {
+                // Not to be used by end users
+                CrossSigningIdentityImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CrossSigningIdentityImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CrossSigningIdentity,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CrossSigningIdentity,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CrossSigningIdentityPtr,
+                );
+
+                /// The request body for `/keys/device_signing/upload`, with the self-signing and
+/// user-signing keys signed by the master key.
+///
+/// The body does not contain the `auth` field, which the caller has to add for
+/// user-interactive authentication.
+ String  deviceSigningUpload()=>RustLib.instance.api.crateBindingsCrossSigningCrossSigningIdentityDeviceSigningUpload(that: this, );
+
+
+ VodozemacEd25519PublicKey  masterKey()=>RustLib.instance.api.crateBindingsCrossSigningCrossSigningIdentityMasterKey(that: this, );
+
+
+ String  masterSecretKey()=>RustLib.instance.api.crateBindingsCrossSigningCrossSigningIdentityMasterSecretKey(that: this, );
+
+
+ VodozemacEd25519PublicKey  selfSigningKey()=>RustLib.instance.api.crateBindingsCrossSigningCrossSigningIdentitySelfSigningKey(that: this, );
+
+
+ String  selfSigningSecretKey()=>RustLib.instance.api.crateBindingsCrossSigningCrossSigningIdentitySelfSigningSecretKey(that: this, );
+
+
+/// Sign one of our own devices with the self-signing key.
+///
+/// Takes the device keys as returned by `/keys/query` and returns the request body for
+/// `/keys/signatures/upload`.
+ String  signDevice({required String deviceKeys })=>RustLib.instance.api.crateBindingsCrossSigningCrossSigningIdentitySignDevice(that: this, deviceKeys: deviceKeys);
+
+
+/// Sign the master key of another user with the user-signing key.
+///
+/// Takes the master key as returned by `/keys/query` and returns the request body for
+/// `/keys/signatures/upload`.
+ String  signUser({required String masterKey })=>RustLib.instance.api.crateBindingsCrossSigningCrossSigningIdentitySignUser(that: this, masterKey: masterKey);
+
+
+ String  userId()=>RustLib.instance.api.crateBindingsCrossSigningCrossSigningIdentityUserId(that: this, );
+
+
+ VodozemacEd25519PublicKey  userSigningKey()=>RustLib.instance.api.crateBindingsCrossSigningCrossSigningIdentityUserSigningKey(that: this, );
+
+
+ String  userSigningSecretKey()=>RustLib.instance.api.crateBindingsCrossSigningCrossSigningIdentityUserSigningSecretKey(that: this, );
+
+
+            }
+            @sealed class Curve25519PublicKeyImpl extends RustOpaque implements Curve25519PublicKey {
+                // Not to be used by end users
+                Curve25519PublicKeyImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Curve25519PublicKeyImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Curve25519PublicKey,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Curve25519PublicKey,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Curve25519PublicKeyPtr,
+                );
+
+                
+            }
+            @sealed class Ed25519PublicKeyImpl extends RustOpaque implements Ed25519PublicKey {
+                // Not to be used by end users
+                Ed25519PublicKeyImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Ed25519PublicKeyImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Ed25519PublicKey,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Ed25519PublicKey,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Ed25519PublicKeyPtr,
+                );
+
+                
+            }
+            @sealed class Ed25519SignatureImpl extends RustOpaque implements Ed25519Signature {
+                // Not to be used by end users
+                Ed25519SignatureImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Ed25519SignatureImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Ed25519Signature,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Ed25519Signature,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Ed25519SignaturePtr,
+                );
+
+                
+            }
+            @sealed class EstablishedSasImpl extends RustOpaque implements EstablishedSas {
+                // Not to be used by end users
+                EstablishedSasImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                EstablishedSasImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_EstablishedSas,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_EstablishedSas,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_EstablishedSasPtr,
+                );
+
+                
+            }
+            @sealed class MegolmSessionConfigImpl extends RustOpaque implements MegolmSessionConfig {
+                // Not to be used by end users
+                MegolmSessionConfigImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                MegolmSessionConfigImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_MegolmSessionConfig,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_MegolmSessionConfig,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_MegolmSessionConfigPtr,
+                );
+
+                
+            }
+            @sealed class OlmMessageImpl extends RustOpaque implements OlmMessage {
+                // Not to be used by end users
+                OlmMessageImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                OlmMessageImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_OlmMessage,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_OlmMessage,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_OlmMessagePtr,
+                );
+
+                
+            }
+            @sealed class OlmSessionConfigImpl extends RustOpaque implements OlmSessionConfig {
+                // Not to be used by end users
+                OlmSessionConfigImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                OlmSessionConfigImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_OlmSessionConfig,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_OlmSessionConfig,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_OlmSessionConfigPtr,
+                );
+
+                
+            }
+            @sealed class PkDecryptionImpl extends RustOpaque implements PkDecryption {
+                // Not to be used by end users
+                PkDecryptionImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                PkDecryptionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_PkDecryption,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_PkDecryption,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_PkDecryptionPtr,
+                );
+
+                
+            }
+            @sealed class PkEncryptionImpl extends RustOpaque implements PkEncryption {
+                // Not to be used by end users
+                PkEncryptionImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                PkEncryptionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_PkEncryption,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_PkEncryption,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_PkEncryptionPtr,
+                );
+
+                
+            }
+            @sealed class PkSigningImpl extends RustOpaque implements PkSigning {
+                // Not to be used by end users
+                PkSigningImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                PkSigningImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_PkSigning,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_PkSigning,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_PkSigningPtr,
+                );
+
+                 VodozemacEd25519PublicKey  publicKey()=>RustLib.instance.api.crateBindingsPkSigningPublicKey(that: this, );
+
+
+ String  secretKey()=>RustLib.instance.api.crateBindingsPkSigningSecretKey(that: this, );
+
+
+ VodozemacEd25519Signature  sign({required String message })=>RustLib.instance.api.crateBindingsPkSigningSign(that: this, message: message);
+
+
+            }
+            @sealed class RwLockAccountImpl extends RustOpaque implements RwLockAccount {
+                // Not to be used by end users
+                RwLockAccountImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockAccountImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockAccount,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockAccount,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockAccountPtr,
+                );
+
+                
+            }
+            @sealed class RwLockFallbackKeyStateImpl extends RustOpaque implements RwLockFallbackKeyState {
+                // Not to be used by end users
+                RwLockFallbackKeyStateImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockFallbackKeyStateImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockFallbackKeyState,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockFallbackKeyState,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockFallbackKeyStatePtr,
+                );
+
+                
+            }
+            @sealed class RwLockGroupSessionImpl extends RustOpaque implements RwLockGroupSession {
+                // Not to be used by end users
+                RwLockGroupSessionImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockGroupSessionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockGroupSession,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockGroupSession,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockGroupSessionPtr,
+                );
+
+                
+            }
+            @sealed class RwLockInboundGroupSessionImpl extends RustOpaque implements RwLockInboundGroupSession {
+                // Not to be used by end users
+                RwLockInboundGroupSessionImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockInboundGroupSessionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockInboundGroupSession,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockInboundGroupSession,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockInboundGroupSessionPtr,
+                );
+
+                
+            }
+            @sealed class RwLockReplayStateImpl extends RustOpaque implements RwLockReplayState {
+                // Not to be used by end users
+                RwLockReplayStateImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockReplayStateImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockReplayState,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockReplayState,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockReplayStatePtr,
+                );
+
+                
+            }
+            @sealed class RwLockRoomKeyInfoImpl extends RustOpaque implements RwLockRoomKeyInfo {
+                // Not to be used by end users
+                RwLockRoomKeyInfoImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockRoomKeyInfoImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockRoomKeyInfo,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockRoomKeyInfo,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockRoomKeyInfoPtr,
+                );
+
+                
+            }
+            @sealed class RwLockRotationStateImpl extends RustOpaque implements RwLockRotationState {
+                // Not to be used by end users
+                RwLockRotationStateImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockRotationStateImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockRotationState,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockRotationState,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockRotationStatePtr,
+                );
+
+                
+            }
+            @sealed class RwLockSessionImpl extends RustOpaque implements RwLockSession {
+                // Not to be used by end users
+                RwLockSessionImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockSessionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockSession,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockSession,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockSessionPtr,
+                );
+
+                
+            }
+            @sealed class RwLockSessionKeySourceImpl extends RustOpaque implements RwLockSessionKeySource {
+                // Not to be used by end users
+                RwLockSessionKeySourceImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockSessionKeySourceImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockSessionKeySource,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockSessionKeySource,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockSessionKeySourcePtr,
+                );
+
+                
+            }
+            @sealed class RwLockSessionStoreStateImpl extends RustOpaque implements RwLockSessionStoreState {
+                // Not to be used by end users
+                RwLockSessionStoreStateImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockSessionStoreStateImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockSessionStoreState,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockSessionStoreState,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockSessionStoreStatePtr,
+                );
+
+                
+            }
+            @sealed class RwLockShareStateImpl extends RustOpaque implements RwLockShareState {
+                // Not to be used by end users
+                RwLockShareStateImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockShareStateImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockShareState,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockShareState,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockShareStatePtr,
+                );
+
+                
+            }
+            @sealed class RwLockUnwedgeStateImpl extends RustOpaque implements RwLockUnwedgeState {
+                // Not to be used by end users
+                RwLockUnwedgeStateImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockUnwedgeStateImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockUnwedgeState,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockUnwedgeState,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockUnwedgeStatePtr,
+                );
+
+                
+            }
+            @sealed class RwLockUtdQueueStateImpl extends RustOpaque implements RwLockUtdQueueState {
+                // Not to be used by end users
+                RwLockUtdQueueStateImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                RwLockUtdQueueStateImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RwLockUtdQueueState,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockUtdQueueState,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RwLockUtdQueueStatePtr,
+                );
+
+                
+            }
+            @sealed class VodozemacSasImpl extends RustOpaque implements VodozemacSas {
+                // Not to be used by end users
+                VodozemacSasImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                VodozemacSasImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_VodozemacSas,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_VodozemacSas,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_VodozemacSasPtr,
+                );
+
+                 VodozemacEstablishedSas  establishSasSecret({required String otherPublicKey })=>RustLib.instance.api.crateBindingsVodozemacSasEstablishSasSecret(that: this, otherPublicKey: otherPublicKey);
+
+
+ String  publicKey()=>RustLib.instance.api.crateBindingsVodozemacSasPublicKey(that: this, );
+
+
+            }
\ No newline at end of file
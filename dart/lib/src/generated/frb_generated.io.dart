@@ -4,6 +4,30 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'bindings.dart';
+import 'bindings/cross_signing.dart';
+import 'bindings/device_keys.dart';
+import 'bindings/forwarded_room_key.dart';
+import 'bindings/key_claim.dart';
+import 'bindings/key_maintenance.dart';
+import 'bindings/megolm_signing.dart';
+import 'bindings/migration.dart';
+import 'bindings/olm_sessions.dart';
+import 'bindings/outbound_group_session.dart';
+import 'bindings/passphrase_pickle.dart';
+import 'bindings/pickle_format.dart';
+import 'bindings/rekey.dart';
+import 'bindings/replay.dart';
+import 'bindings/retention.dart';
+import 'bindings/room_event.dart';
+import 'bindings/room_key.dart';
+import 'bindings/room_key_bundle.dart';
+import 'bindings/room_key_info.dart';
+import 'bindings/share_tracking.dart';
+import 'bindings/to_device.dart';
+import 'bindings/trust.dart';
+import 'bindings/unwedge.dart';
+import 'bindings/utd_cause.dart';
+import 'bindings/utd_queue.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
@@ -11,3168 +35,7017 @@ import 'frb_generated.dart';
 import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Curve25519PublicKeyPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKeyPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Ed25519PublicKeyPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKeyPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Ed25519SignaturePtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_Ed25519SignaturePtr;
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EstablishedSasPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_EstablishedSasPtr;
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Curve25519PublicKeyPtr => wire._rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKeyPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MegolmSessionConfigPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfigPtr;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Ed25519PublicKeyPtr => wire._rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKeyPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OlmMessagePtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_OlmMessagePtr;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Ed25519SignaturePtr => wire._rust_arc_decrement_strong_count_RustOpaque_Ed25519SignaturePtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OlmSessionConfigPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfigPtr;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EstablishedSasPtr => wire._rust_arc_decrement_strong_count_RustOpaque_EstablishedSasPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkDecryptionPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_PkDecryptionPtr;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MegolmSessionConfigPtr => wire._rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfigPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkEncryptionPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_PkEncryptionPtr;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OlmMessagePtr => wire._rust_arc_decrement_strong_count_RustOpaque_OlmMessagePtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockGroupSessionPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSessionPtr;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OlmSessionConfigPtr => wire._rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfigPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockInboundGroupSessionPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSessionPtr;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkDecryptionPtr => wire._rust_arc_decrement_strong_count_RustOpaque_PkDecryptionPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockSessionPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_RwLockSessionPtr;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkEncryptionPtr => wire._rust_arc_decrement_strong_count_RustOpaque_PkEncryptionPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkSigningPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigningPtr;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockFallbackKeyStatePtr => wire._rust_arc_decrement_strong_count_RustOpaque_RwLockFallbackKeyStatePtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_VodozemacSasPtr => wire
-      ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSasPtr;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockGroupSessionPtr => wire._rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSessionPtr;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockAccountPtr =>
-      wire._rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccountPtr;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockInboundGroupSessionPtr => wire._rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSessionPtr;
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockReplayStatePtr => wire._rust_arc_decrement_strong_count_RustOpaque_RwLockReplayStatePtr;
 
-  @protected
-  PkSigning dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockRoomKeyInfoPtr => wire._rust_arc_decrement_strong_count_RustOpaque_RwLockRoomKeyInfoPtr;
 
-  @protected
-  VodozemacSas dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockRotationStatePtr => wire._rust_arc_decrement_strong_count_RustOpaque_RwLockRotationStatePtr;
 
-  @protected
-  PkSigning dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockSessionPtr => wire._rust_arc_decrement_strong_count_RustOpaque_RwLockSessionPtr;
 
-  @protected
-  VodozemacSas dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockSessionKeySourcePtr => wire._rust_arc_decrement_strong_count_RustOpaque_RwLockSessionKeySourcePtr;
 
-  @protected
-  Curve25519PublicKey dco_decode_RustOpaque_Curve25519PublicKey(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockSessionStoreStatePtr => wire._rust_arc_decrement_strong_count_RustOpaque_RwLockSessionStoreStatePtr;
 
-  @protected
-  Ed25519PublicKey dco_decode_RustOpaque_Ed25519PublicKey(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockShareStatePtr => wire._rust_arc_decrement_strong_count_RustOpaque_RwLockShareStatePtr;
 
-  @protected
-  Ed25519Signature dco_decode_RustOpaque_Ed25519Signature(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockUnwedgeStatePtr => wire._rust_arc_decrement_strong_count_RustOpaque_RwLockUnwedgeStatePtr;
 
-  @protected
-  EstablishedSas dco_decode_RustOpaque_EstablishedSas(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockUtdQueueStatePtr => wire._rust_arc_decrement_strong_count_RustOpaque_RwLockUtdQueueStatePtr;
 
-  @protected
-  MegolmSessionConfig dco_decode_RustOpaque_MegolmSessionConfig(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CrossSigningIdentityPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentityPtr;
 
-  @protected
-  OlmMessage dco_decode_RustOpaque_OlmMessage(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkSigningPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigningPtr;
 
-  @protected
-  OlmSessionConfig dco_decode_RustOpaque_OlmSessionConfig(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_VodozemacSasPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSasPtr;
 
-  @protected
-  PkDecryption dco_decode_RustOpaque_PkDecryption(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockAccountPtr => wire._rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccountPtr;
 
-  @protected
-  PkEncryption dco_decode_RustOpaque_PkEncryption(dynamic raw);
 
-  @protected
-  RwLockGroupSession dco_decode_RustOpaque_RwLockGroupSession(dynamic raw);
 
-  @protected
-  RwLockInboundGroupSession dco_decode_RustOpaque_RwLockInboundGroupSession(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  RwLockSession dco_decode_RustOpaque_RwLockSession(dynamic raw);
+@protected CrossSigningIdentity dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(dynamic raw);
 
-  @protected
-  PkSigning dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw);
+@protected PkSigning dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw);
 
-  @protected
-  VodozemacSas dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(dynamic raw);
+@protected VodozemacSas dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(dynamic raw);
 
-  @protected
-  RwLockAccount dco_decode_RustOpaque_stdsyncRwLockAccount(dynamic raw);
+@protected CrossSigningIdentity dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(dynamic raw);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+@protected PkSigning dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+@protected VodozemacSas dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(dynamic raw);
 
-  @protected
-  VodozemacAccount dco_decode_box_autoadd_vodozemac_account(dynamic raw);
+@protected Map<String, int> dco_decode_Map_String_u_32_None(dynamic raw);
 
-  @protected
-  VodozemacCurve25519PublicKey dco_decode_box_autoadd_vodozemac_curve_25519_public_key(dynamic raw);
+@protected Curve25519PublicKey dco_decode_RustOpaque_Curve25519PublicKey(dynamic raw);
 
-  @protected
-  VodozemacEd25519PublicKey dco_decode_box_autoadd_vodozemac_ed_25519_public_key(dynamic raw);
+@protected Ed25519PublicKey dco_decode_RustOpaque_Ed25519PublicKey(dynamic raw);
 
-  @protected
-  VodozemacEd25519Signature dco_decode_box_autoadd_vodozemac_ed_25519_signature(dynamic raw);
+@protected Ed25519Signature dco_decode_RustOpaque_Ed25519Signature(dynamic raw);
 
-  @protected
-  VodozemacEstablishedSas dco_decode_box_autoadd_vodozemac_established_sas(dynamic raw);
+@protected EstablishedSas dco_decode_RustOpaque_EstablishedSas(dynamic raw);
 
-  @protected
-  VodozemacGroupSession dco_decode_box_autoadd_vodozemac_group_session(dynamic raw);
+@protected MegolmSessionConfig dco_decode_RustOpaque_MegolmSessionConfig(dynamic raw);
 
-  @protected
-  VodozemacInboundGroupSession dco_decode_box_autoadd_vodozemac_inbound_group_session(dynamic raw);
+@protected OlmMessage dco_decode_RustOpaque_OlmMessage(dynamic raw);
 
-  @protected
-  VodozemacMegolmSessionConfig dco_decode_box_autoadd_vodozemac_megolm_session_config(dynamic raw);
+@protected OlmSessionConfig dco_decode_RustOpaque_OlmSessionConfig(dynamic raw);
 
-  @protected
-  VodozemacOlmMessage dco_decode_box_autoadd_vodozemac_olm_message(dynamic raw);
+@protected PkDecryption dco_decode_RustOpaque_PkDecryption(dynamic raw);
 
-  @protected
-  VodozemacOlmSessionConfig dco_decode_box_autoadd_vodozemac_olm_session_config(dynamic raw);
+@protected PkEncryption dco_decode_RustOpaque_PkEncryption(dynamic raw);
 
-  @protected
-  VodozemacPkDecryption dco_decode_box_autoadd_vodozemac_pk_decryption(dynamic raw);
+@protected RwLockFallbackKeyState dco_decode_RustOpaque_RwLockFallbackKeyState(dynamic raw);
 
-  @protected
-  VodozemacPkEncryption dco_decode_box_autoadd_vodozemac_pk_encryption(dynamic raw);
+@protected RwLockGroupSession dco_decode_RustOpaque_RwLockGroupSession(dynamic raw);
 
-  @protected
-  VodozemacPkMessage dco_decode_box_autoadd_vodozemac_pk_message(dynamic raw);
+@protected RwLockInboundGroupSession dco_decode_RustOpaque_RwLockInboundGroupSession(dynamic raw);
 
-  @protected
-  VodozemacSession dco_decode_box_autoadd_vodozemac_session(dynamic raw);
+@protected RwLockReplayState dco_decode_RustOpaque_RwLockReplayState(dynamic raw);
 
-  @protected
-  DecryptResult dco_decode_decrypt_result(dynamic raw);
+@protected RwLockRoomKeyInfo dco_decode_RustOpaque_RwLockRoomKeyInfo(dynamic raw);
 
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+@protected RwLockRotationState dco_decode_RustOpaque_RwLockRotationState(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected RwLockSession dco_decode_RustOpaque_RwLockSession(dynamic raw);
 
-  @protected
-  List<VodozemacOneTimeKey> dco_decode_list_vodozemac_one_time_key(dynamic raw);
+@protected RwLockSessionKeySource dco_decode_RustOpaque_RwLockSessionKeySource(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected RwLockSessionStoreState dco_decode_RustOpaque_RwLockSessionStoreState(dynamic raw);
 
-  @protected
-  (String, String, String) dco_decode_record_string_string_string(dynamic raw);
+@protected RwLockShareState dco_decode_RustOpaque_RwLockShareState(dynamic raw);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected RwLockUnwedgeState dco_decode_RustOpaque_RwLockUnwedgeState(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected RwLockUtdQueueState dco_decode_RustOpaque_RwLockUtdQueueState(dynamic raw);
 
-  @protected
-  U8Array32 dco_decode_u_8_array_32(dynamic raw);
+@protected CrossSigningIdentity dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(dynamic raw);
 
-  @protected
-  U8Array64 dco_decode_u_8_array_64(dynamic raw);
+@protected PkSigning dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected VodozemacSas dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(dynamic raw);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected RwLockAccount dco_decode_RustOpaque_stdsyncRwLockAccount(dynamic raw);
 
-  @protected
-  VodozemacAccount dco_decode_vodozemac_account(dynamic raw);
+@protected RustStreamSink<RekeyProgress> dco_decode_StreamSink_rekey_progress_Dco(dynamic raw);
 
-  @protected
-  VodozemacCurve25519PublicKey dco_decode_vodozemac_curve_25519_public_key(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  VodozemacEd25519PublicKey dco_decode_vodozemac_ed_25519_public_key(dynamic raw);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  VodozemacEd25519Signature dco_decode_vodozemac_ed_25519_signature(dynamic raw);
+@protected DeviceKeys dco_decode_box_autoadd_device_keys(dynamic raw);
 
-  @protected
-  VodozemacEstablishedSas dco_decode_vodozemac_established_sas(dynamic raw);
+@protected EncryptedRoomKeyBundle dco_decode_box_autoadd_encrypted_room_key_bundle(dynamic raw);
 
-  @protected
-  VodozemacGroupSession dco_decode_vodozemac_group_session(dynamic raw);
+@protected FallbackKeyTracker dco_decode_box_autoadd_fallback_key_tracker(dynamic raw);
 
-  @protected
-  VodozemacIdentityKeys dco_decode_vodozemac_identity_keys(dynamic raw);
+@protected InboundRoomKey dco_decode_box_autoadd_inbound_room_key(dynamic raw);
 
-  @protected
-  VodozemacInboundGroupSession dco_decode_vodozemac_inbound_group_session(dynamic raw);
+@protected LibolmStore dco_decode_box_autoadd_libolm_store(dynamic raw);
 
-  @protected
-  VodozemacMegolmSessionConfig dco_decode_vodozemac_megolm_session_config(dynamic raw);
+@protected MegolmReplayGuard dco_decode_box_autoadd_megolm_replay_guard(dynamic raw);
 
-  @protected
-  VodozemacOlmMessage dco_decode_vodozemac_olm_message(dynamic raw);
+@protected MigratedPickle dco_decode_box_autoadd_migrated_pickle(dynamic raw);
 
-  @protected
-  VodozemacOlmSessionConfig dco_decode_vodozemac_olm_session_config(dynamic raw);
+@protected MigrationReport dco_decode_box_autoadd_migration_report(dynamic raw);
 
-  @protected
-  VodozemacOlmSessionCreationResult dco_decode_vodozemac_olm_session_creation_result(dynamic raw);
+@protected OlmPayload dco_decode_box_autoadd_olm_payload(dynamic raw);
 
-  @protected
-  VodozemacOneTimeKey dco_decode_vodozemac_one_time_key(dynamic raw);
+@protected OlmSessionStore dco_decode_box_autoadd_olm_session_store(dynamic raw);
 
-  @protected
-  VodozemacPkDecryption dco_decode_vodozemac_pk_decryption(dynamic raw);
+@protected OutboundGroupSession dco_decode_box_autoadd_outbound_group_session(dynamic raw);
 
-  @protected
-  VodozemacPkEncryption dco_decode_vodozemac_pk_encryption(dynamic raw);
+@protected PassphraseKdfParams dco_decode_box_autoadd_passphrase_kdf_params(dynamic raw);
 
-  @protected
-  VodozemacPkMessage dco_decode_vodozemac_pk_message(dynamic raw);
+@protected PickleFormatError dco_decode_box_autoadd_pickle_format_error(dynamic raw);
 
-  @protected
-  VodozemacSession dco_decode_vodozemac_session(dynamic raw);
+@protected PickleInfo dco_decode_box_autoadd_pickle_info(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected RetainedRoomKey dco_decode_box_autoadd_retained_room_key(dynamic raw);
 
-  @protected
-  PkSigning sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      SseDeserializer deserializer);
+@protected RoomKeyBundleDownload dco_decode_box_autoadd_room_key_bundle_download(dynamic raw);
 
-  @protected
-  VodozemacSas sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      SseDeserializer deserializer);
+@protected RoomKeyInfo dco_decode_box_autoadd_room_key_info(dynamic raw);
 
-  @protected
-  PkSigning sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      SseDeserializer deserializer);
+@protected RotationReason dco_decode_box_autoadd_rotation_reason(dynamic raw);
 
-  @protected
-  VodozemacSas sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      SseDeserializer deserializer);
+@protected ShareRecipient dco_decode_box_autoadd_share_recipient(dynamic raw);
 
-  @protected
-  Curve25519PublicKey sse_decode_RustOpaque_Curve25519PublicKey(SseDeserializer deserializer);
+@protected TrustLink dco_decode_box_autoadd_trust_link(dynamic raw);
 
-  @protected
-  Ed25519PublicKey sse_decode_RustOpaque_Ed25519PublicKey(SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  Ed25519Signature sse_decode_RustOpaque_Ed25519Signature(SseDeserializer deserializer);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  EstablishedSas sse_decode_RustOpaque_EstablishedSas(SseDeserializer deserializer);
+@protected UnwedgeTracker dco_decode_box_autoadd_unwedge_tracker(dynamic raw);
 
-  @protected
-  MegolmSessionConfig sse_decode_RustOpaque_MegolmSessionConfig(SseDeserializer deserializer);
+@protected UtdClassification dco_decode_box_autoadd_utd_classification(dynamic raw);
 
-  @protected
-  OlmMessage sse_decode_RustOpaque_OlmMessage(SseDeserializer deserializer);
+@protected UtdQueue dco_decode_box_autoadd_utd_queue(dynamic raw);
 
-  @protected
-  OlmSessionConfig sse_decode_RustOpaque_OlmSessionConfig(SseDeserializer deserializer);
+@protected VodozemacAccount dco_decode_box_autoadd_vodozemac_account(dynamic raw);
 
-  @protected
-  PkDecryption sse_decode_RustOpaque_PkDecryption(SseDeserializer deserializer);
+@protected VodozemacCurve25519PublicKey dco_decode_box_autoadd_vodozemac_curve_25519_public_key(dynamic raw);
 
-  @protected
-  PkEncryption sse_decode_RustOpaque_PkEncryption(SseDeserializer deserializer);
+@protected VodozemacEd25519PublicKey dco_decode_box_autoadd_vodozemac_ed_25519_public_key(dynamic raw);
 
-  @protected
-  RwLockGroupSession sse_decode_RustOpaque_RwLockGroupSession(SseDeserializer deserializer);
+@protected VodozemacEd25519Signature dco_decode_box_autoadd_vodozemac_ed_25519_signature(dynamic raw);
 
-  @protected
-  RwLockInboundGroupSession sse_decode_RustOpaque_RwLockInboundGroupSession(SseDeserializer deserializer);
+@protected VodozemacEstablishedSas dco_decode_box_autoadd_vodozemac_established_sas(dynamic raw);
 
-  @protected
-  RwLockSession sse_decode_RustOpaque_RwLockSession(SseDeserializer deserializer);
+@protected VodozemacGroupSession dco_decode_box_autoadd_vodozemac_group_session(dynamic raw);
 
-  @protected
-  PkSigning sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      SseDeserializer deserializer);
+@protected VodozemacInboundGroupSession dco_decode_box_autoadd_vodozemac_inbound_group_session(dynamic raw);
 
-  @protected
-  VodozemacSas sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      SseDeserializer deserializer);
+@protected VodozemacMegolmSessionConfig dco_decode_box_autoadd_vodozemac_megolm_session_config(dynamic raw);
 
-  @protected
-  RwLockAccount sse_decode_RustOpaque_stdsyncRwLockAccount(SseDeserializer deserializer);
+@protected VodozemacOlmMessage dco_decode_box_autoadd_vodozemac_olm_message(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected VodozemacOlmSessionConfig dco_decode_box_autoadd_vodozemac_olm_session_config(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected VodozemacPkDecryption dco_decode_box_autoadd_vodozemac_pk_decryption(dynamic raw);
 
-  @protected
-  VodozemacAccount sse_decode_box_autoadd_vodozemac_account(SseDeserializer deserializer);
+@protected VodozemacPkEncryption dco_decode_box_autoadd_vodozemac_pk_encryption(dynamic raw);
 
-  @protected
-  VodozemacCurve25519PublicKey sse_decode_box_autoadd_vodozemac_curve_25519_public_key(SseDeserializer deserializer);
+@protected VodozemacPkMessage dco_decode_box_autoadd_vodozemac_pk_message(dynamic raw);
 
-  @protected
-  VodozemacEd25519PublicKey sse_decode_box_autoadd_vodozemac_ed_25519_public_key(SseDeserializer deserializer);
+@protected VodozemacSession dco_decode_box_autoadd_vodozemac_session(dynamic raw);
 
-  @protected
-  VodozemacEd25519Signature sse_decode_box_autoadd_vodozemac_ed_25519_signature(SseDeserializer deserializer);
+@protected WithheldCode dco_decode_box_autoadd_withheld_code(dynamic raw);
 
-  @protected
-  VodozemacEstablishedSas sse_decode_box_autoadd_vodozemac_established_sas(SseDeserializer deserializer);
+@protected ClaimedSession dco_decode_claimed_session(dynamic raw);
 
-  @protected
-  VodozemacGroupSession sse_decode_box_autoadd_vodozemac_group_session(SseDeserializer deserializer);
+@protected DecryptResult dco_decode_decrypt_result(dynamic raw);
 
-  @protected
-  VodozemacInboundGroupSession sse_decode_box_autoadd_vodozemac_inbound_group_session(SseDeserializer deserializer);
+@protected DeviceKeys dco_decode_device_keys(dynamic raw);
 
-  @protected
-  VodozemacMegolmSessionConfig sse_decode_box_autoadd_vodozemac_megolm_session_config(SseDeserializer deserializer);
+@protected DeviceKeysError dco_decode_device_keys_error(dynamic raw);
 
-  @protected
-  VodozemacOlmMessage sse_decode_box_autoadd_vodozemac_olm_message(SseDeserializer deserializer);
+@protected DeviceTrust dco_decode_device_trust(dynamic raw);
 
-  @protected
-  VodozemacOlmSessionConfig sse_decode_box_autoadd_vodozemac_olm_session_config(SseDeserializer deserializer);
+@protected DeviceTrustLevel dco_decode_device_trust_level(dynamic raw);
 
-  @protected
-  VodozemacPkDecryption sse_decode_box_autoadd_vodozemac_pk_decryption(SseDeserializer deserializer);
+@protected EncryptedRoomKeyBundle dco_decode_encrypted_room_key_bundle(dynamic raw);
 
-  @protected
-  VodozemacPkEncryption sse_decode_box_autoadd_vodozemac_pk_encryption(SseDeserializer deserializer);
+@protected ExtendedDecryptResult dco_decode_extended_decrypt_result(dynamic raw);
 
-  @protected
-  VodozemacPkMessage sse_decode_box_autoadd_vodozemac_pk_message(SseDeserializer deserializer);
+@protected FallbackKeyTracker dco_decode_fallback_key_tracker(dynamic raw);
 
-  @protected
-  VodozemacSession sse_decode_box_autoadd_vodozemac_session(SseDeserializer deserializer);
+@protected int dco_decode_i_32(dynamic raw);
 
-  @protected
-  DecryptResult sse_decode_decrypt_result(SseDeserializer deserializer);
+@protected ImportedRoomKeyBundle dco_decode_imported_room_key_bundle(dynamic raw);
 
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+@protected InboundRoomKey dco_decode_inbound_room_key(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected KeyAuthenticity dco_decode_key_authenticity(dynamic raw);
 
-  @protected
-  List<VodozemacOneTimeKey> sse_decode_list_vodozemac_one_time_key(SseDeserializer deserializer);
+@protected KeyClaimError dco_decode_key_claim_error(dynamic raw);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected KeyMaintenancePlan dco_decode_key_maintenance_plan(dynamic raw);
 
-  @protected
-  (String, String, String) sse_decode_record_string_string_string(SseDeserializer deserializer);
+@protected LibolmStore dco_decode_libolm_store(dynamic raw);
 
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+@protected List<String> dco_decode_list_String(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected List<DeviceTrust> dco_decode_list_device_trust(dynamic raw);
 
-  @protected
-  U8Array32 sse_decode_u_8_array_32(SseDeserializer deserializer);
+@protected List<InboundRoomKey> dco_decode_list_inbound_room_key(dynamic raw);
 
-  @protected
-  U8Array64 sse_decode_u_8_array_64(SseDeserializer deserializer);
+@protected List<MigratedPickle> dco_decode_list_migrated_pickle(dynamic raw);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected List<MigrationFailure> dco_decode_list_migration_failure(dynamic raw);
 
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
-  @protected
-  VodozemacAccount sse_decode_vodozemac_account(SseDeserializer deserializer);
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  VodozemacCurve25519PublicKey sse_decode_vodozemac_curve_25519_public_key(SseDeserializer deserializer);
+@protected List<(String,int)> dco_decode_list_record_string_u_32(dynamic raw);
 
-  @protected
-  VodozemacEd25519PublicKey sse_decode_vodozemac_ed_25519_public_key(SseDeserializer deserializer);
+@protected List<RekeyResult> dco_decode_list_rekey_result(dynamic raw);
 
-  @protected
-  VodozemacEd25519Signature sse_decode_vodozemac_ed_25519_signature(SseDeserializer deserializer);
+@protected List<RetentionResult> dco_decode_list_retention_result(dynamic raw);
 
-  @protected
-  VodozemacEstablishedSas sse_decode_vodozemac_established_sas(SseDeserializer deserializer);
+@protected List<RetriedEvent> dco_decode_list_retried_event(dynamic raw);
 
-  @protected
-  VodozemacGroupSession sse_decode_vodozemac_group_session(SseDeserializer deserializer);
+@protected List<RoomRetention> dco_decode_list_room_retention(dynamic raw);
 
-  @protected
-  VodozemacIdentityKeys sse_decode_vodozemac_identity_keys(SseDeserializer deserializer);
+@protected List<ShareRecipient> dco_decode_list_share_recipient(dynamic raw);
 
-  @protected
-  VodozemacInboundGroupSession sse_decode_vodozemac_inbound_group_session(SseDeserializer deserializer);
+@protected List<VodozemacOneTimeKey> dco_decode_list_vodozemac_one_time_key(dynamic raw);
 
-  @protected
-  VodozemacMegolmSessionConfig sse_decode_vodozemac_megolm_session_config(SseDeserializer deserializer);
+@protected List<VodozemacSession> dco_decode_list_vodozemac_session(dynamic raw);
 
-  @protected
-  VodozemacOlmMessage sse_decode_vodozemac_olm_message(SseDeserializer deserializer);
+@protected MegolmReplayGuard dco_decode_megolm_replay_guard(dynamic raw);
 
-  @protected
-  VodozemacOlmSessionConfig sse_decode_vodozemac_olm_session_config(SseDeserializer deserializer);
+@protected MigratedPickle dco_decode_migrated_pickle(dynamic raw);
 
-  @protected
-  VodozemacOlmSessionCreationResult sse_decode_vodozemac_olm_session_creation_result(SseDeserializer deserializer);
+@protected MigrationError dco_decode_migration_error(dynamic raw);
 
-  @protected
-  VodozemacOneTimeKey sse_decode_vodozemac_one_time_key(SseDeserializer deserializer);
+@protected MigrationFailure dco_decode_migration_failure(dynamic raw);
 
-  @protected
-  VodozemacPkDecryption sse_decode_vodozemac_pk_decryption(SseDeserializer deserializer);
+@protected MigrationReport dco_decode_migration_report(dynamic raw);
 
-  @protected
-  VodozemacPkEncryption sse_decode_vodozemac_pk_encryption(SseDeserializer deserializer);
+@protected OlmPayload dco_decode_olm_payload(dynamic raw);
 
-  @protected
-  VodozemacPkMessage sse_decode_vodozemac_pk_message(SseDeserializer deserializer);
+@protected OlmPayloadError dco_decode_olm_payload_error(dynamic raw);
 
-  @protected
-  VodozemacSession sse_decode_vodozemac_session(SseDeserializer deserializer);
+@protected OlmSessionStore dco_decode_olm_session_store(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected String? dco_decode_opt_String(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_AnyhowException(AnyhowException raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    throw UnimplementedError();
-  }
+@protected MigratedPickle? dco_decode_opt_box_autoadd_migrated_pickle(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_String(String raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_list_prim_u_8_strict(utf8.encoder.convert(raw));
-  }
+@protected PickleFormatError? dco_decode_opt_box_autoadd_pickle_format_error(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_account> cst_encode_box_autoadd_vodozemac_account(VodozemacAccount raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_account();
-    cst_api_fill_to_wire_vodozemac_account(raw, ptr.ref);
-    return ptr;
-  }
+@protected RetainedRoomKey? dco_decode_opt_box_autoadd_retained_room_key(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> cst_encode_box_autoadd_vodozemac_curve_25519_public_key(
-      VodozemacCurve25519PublicKey raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_curve_25519_public_key();
-    cst_api_fill_to_wire_vodozemac_curve_25519_public_key(raw, ptr.ref);
-    return ptr;
-  }
+@protected RotationReason? dco_decode_opt_box_autoadd_rotation_reason(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> cst_encode_box_autoadd_vodozemac_ed_25519_public_key(
-      VodozemacEd25519PublicKey raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_ed_25519_public_key();
-    cst_api_fill_to_wire_vodozemac_ed_25519_public_key(raw, ptr.ref);
-    return ptr;
-  }
+@protected TrustLink? dco_decode_opt_box_autoadd_trust_link(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_ed_25519_signature> cst_encode_box_autoadd_vodozemac_ed_25519_signature(
-      VodozemacEd25519Signature raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_ed_25519_signature();
-    cst_api_fill_to_wire_vodozemac_ed_25519_signature(raw, ptr.ref);
-    return ptr;
-  }
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_established_sas> cst_encode_box_autoadd_vodozemac_established_sas(
-      VodozemacEstablishedSas raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_established_sas();
-    cst_api_fill_to_wire_vodozemac_established_sas(raw, ptr.ref);
-    return ptr;
-  }
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_group_session> cst_encode_box_autoadd_vodozemac_group_session(
-      VodozemacGroupSession raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_group_session();
-    cst_api_fill_to_wire_vodozemac_group_session(raw, ptr.ref);
-    return ptr;
-  }
+@protected UtdClassification? dco_decode_opt_box_autoadd_utd_classification(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_inbound_group_session> cst_encode_box_autoadd_vodozemac_inbound_group_session(
-      VodozemacInboundGroupSession raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_inbound_group_session();
-    cst_api_fill_to_wire_vodozemac_inbound_group_session(raw, ptr.ref);
-    return ptr;
-  }
+@protected VodozemacEd25519PublicKey? dco_decode_opt_box_autoadd_vodozemac_ed_25519_public_key(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_megolm_session_config> cst_encode_box_autoadd_vodozemac_megolm_session_config(
-      VodozemacMegolmSessionConfig raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_megolm_session_config();
-    cst_api_fill_to_wire_vodozemac_megolm_session_config(raw, ptr.ref);
-    return ptr;
-  }
+@protected VodozemacSession? dco_decode_opt_box_autoadd_vodozemac_session(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_olm_message> cst_encode_box_autoadd_vodozemac_olm_message(VodozemacOlmMessage raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_olm_message();
-    cst_api_fill_to_wire_vodozemac_olm_message(raw, ptr.ref);
-    return ptr;
-  }
+@protected WithheldCode? dco_decode_opt_box_autoadd_withheld_code(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_olm_session_config> cst_encode_box_autoadd_vodozemac_olm_session_config(
-      VodozemacOlmSessionConfig raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_olm_session_config();
-    cst_api_fill_to_wire_vodozemac_olm_session_config(raw, ptr.ref);
-    return ptr;
-  }
+@protected List<String>? dco_decode_opt_list_String(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_pk_decryption> cst_encode_box_autoadd_vodozemac_pk_decryption(
-      VodozemacPkDecryption raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_pk_decryption();
-    cst_api_fill_to_wire_vodozemac_pk_decryption(raw, ptr.ref);
-    return ptr;
-  }
+@protected List<RekeyResult>? dco_decode_opt_list_rekey_result(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_pk_encryption> cst_encode_box_autoadd_vodozemac_pk_encryption(
-      VodozemacPkEncryption raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_pk_encryption();
-    cst_api_fill_to_wire_vodozemac_pk_encryption(raw, ptr.ref);
-    return ptr;
-  }
+@protected OutboundGroupSession dco_decode_outbound_group_session(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_pk_message> cst_encode_box_autoadd_vodozemac_pk_message(VodozemacPkMessage raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_pk_message();
-    cst_api_fill_to_wire_vodozemac_pk_message(raw, ptr.ref);
-    return ptr;
-  }
+@protected PassphraseKdf dco_decode_passphrase_kdf(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_vodozemac_session> cst_encode_box_autoadd_vodozemac_session(VodozemacSession raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_vodozemac_session();
-    cst_api_fill_to_wire_vodozemac_session(raw, ptr.ref);
-    return ptr;
-  }
+@protected PassphraseKdfParams dco_decode_passphrase_kdf_params(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_loose> cst_encode_list_prim_u_8_loose(List<int> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_prim_u_8_loose(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
-  }
+@protected PassphrasePickleError dco_decode_passphrase_pickle_error(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(Uint8List raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_prim_u_8_strict(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
-  }
+@protected PickleFormat dco_decode_pickle_format(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_vodozemac_one_time_key> cst_encode_list_vodozemac_one_time_key(
-      List<VodozemacOneTimeKey> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_vodozemac_one_time_key(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_vodozemac_one_time_key(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+@protected PickleFormatError dco_decode_pickle_format_error(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(String? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_String(raw);
-  }
+@protected PickleInfo dco_decode_pickle_info(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_u_8_array_32(U8Array32 raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_prim_u_8_strict(32);
-    ans.ref.ptr.asTypedList(32).setAll(0, raw);
-    return ans;
-  }
+@protected PickleObject dco_decode_pickle_object(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_u_8_array_64(U8Array64 raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_prim_u_8_strict(64);
-    ans.ref.ptr.asTypedList(64).setAll(0, raw);
-    return ans;
-  }
+@protected (String,String,String) dco_decode_record_string_string_string(dynamic raw);
 
-  @protected
-  int cst_encode_usize(BigInt raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.toSigned(64).toInt();
-  }
+@protected (String,int) dco_decode_record_string_u_32(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_account(
-      VodozemacAccount apiObj, ffi.Pointer<wire_cst_vodozemac_account> wireObj) {
-    cst_api_fill_to_wire_vodozemac_account(apiObj, wireObj.ref);
-  }
+@protected RekeyProgress dco_decode_rekey_progress(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_curve_25519_public_key(
-      VodozemacCurve25519PublicKey apiObj, ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> wireObj) {
-    cst_api_fill_to_wire_vodozemac_curve_25519_public_key(apiObj, wireObj.ref);
-  }
+@protected RekeyResult dco_decode_rekey_result(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_ed_25519_public_key(
-      VodozemacEd25519PublicKey apiObj, ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> wireObj) {
-    cst_api_fill_to_wire_vodozemac_ed_25519_public_key(apiObj, wireObj.ref);
-  }
+@protected RetainedRoomKey dco_decode_retained_room_key(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_ed_25519_signature(
-      VodozemacEd25519Signature apiObj, ffi.Pointer<wire_cst_vodozemac_ed_25519_signature> wireObj) {
-    cst_api_fill_to_wire_vodozemac_ed_25519_signature(apiObj, wireObj.ref);
-  }
+@protected RetentionResult dco_decode_retention_result(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_established_sas(
-      VodozemacEstablishedSas apiObj, ffi.Pointer<wire_cst_vodozemac_established_sas> wireObj) {
-    cst_api_fill_to_wire_vodozemac_established_sas(apiObj, wireObj.ref);
-  }
+@protected RetriedEvent dco_decode_retried_event(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_group_session(
-      VodozemacGroupSession apiObj, ffi.Pointer<wire_cst_vodozemac_group_session> wireObj) {
-    cst_api_fill_to_wire_vodozemac_group_session(apiObj, wireObj.ref);
-  }
+@protected RoomEventDecryptionResult dco_decode_room_event_decryption_result(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_inbound_group_session(
-      VodozemacInboundGroupSession apiObj, ffi.Pointer<wire_cst_vodozemac_inbound_group_session> wireObj) {
-    cst_api_fill_to_wire_vodozemac_inbound_group_session(apiObj, wireObj.ref);
-  }
+@protected RoomEventError dco_decode_room_event_error(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_megolm_session_config(
-      VodozemacMegolmSessionConfig apiObj, ffi.Pointer<wire_cst_vodozemac_megolm_session_config> wireObj) {
-    cst_api_fill_to_wire_vodozemac_megolm_session_config(apiObj, wireObj.ref);
-  }
+@protected RoomKeyBundleDownload dco_decode_room_key_bundle_download(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_olm_message(
-      VodozemacOlmMessage apiObj, ffi.Pointer<wire_cst_vodozemac_olm_message> wireObj) {
-    cst_api_fill_to_wire_vodozemac_olm_message(apiObj, wireObj.ref);
-  }
+@protected RoomKeyBundleError dco_decode_room_key_bundle_error(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_olm_session_config(
-      VodozemacOlmSessionConfig apiObj, ffi.Pointer<wire_cst_vodozemac_olm_session_config> wireObj) {
-    cst_api_fill_to_wire_vodozemac_olm_session_config(apiObj, wireObj.ref);
-  }
+@protected RoomKeyDecryptionResult dco_decode_room_key_decryption_result(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_pk_decryption(
-      VodozemacPkDecryption apiObj, ffi.Pointer<wire_cst_vodozemac_pk_decryption> wireObj) {
-    cst_api_fill_to_wire_vodozemac_pk_decryption(apiObj, wireObj.ref);
-  }
+@protected RoomKeyError dco_decode_room_key_error(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_pk_encryption(
-      VodozemacPkEncryption apiObj, ffi.Pointer<wire_cst_vodozemac_pk_encryption> wireObj) {
-    cst_api_fill_to_wire_vodozemac_pk_encryption(apiObj, wireObj.ref);
-  }
+@protected RoomKeyInfo dco_decode_room_key_info(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_pk_message(
-      VodozemacPkMessage apiObj, ffi.Pointer<wire_cst_vodozemac_pk_message> wireObj) {
-    cst_api_fill_to_wire_vodozemac_pk_message(apiObj, wireObj.ref);
-  }
+@protected RoomKeyOrigin dco_decode_room_key_origin(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_vodozemac_session(
-      VodozemacSession apiObj, ffi.Pointer<wire_cst_vodozemac_session> wireObj) {
-    cst_api_fill_to_wire_vodozemac_session(apiObj, wireObj.ref);
-  }
+@protected RoomRetention dco_decode_room_retention(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_decrypt_result(DecryptResult apiObj, wire_cst_decrypt_result wireObj) {
-    wireObj.field0 = cst_encode_String(apiObj.field0);
-    wireObj.field1 = cst_encode_u_32(apiObj.field1);
-  }
+@protected RotationReason dco_decode_rotation_reason(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_record_string_string_string(
-      (String, String, String) apiObj, wire_cst_record_string_string_string wireObj) {
-    wireObj.field0 = cst_encode_String(apiObj.$1);
-    wireObj.field1 = cst_encode_String(apiObj.$2);
-    wireObj.field2 = cst_encode_String(apiObj.$3);
-  }
+@protected SessionKeySource dco_decode_session_key_source(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_account(VodozemacAccount apiObj, wire_cst_vodozemac_account wireObj) {
-    wireObj.account = cst_encode_RustOpaque_stdsyncRwLockAccount(apiObj.account);
-  }
+@protected ShareRecipient dco_decode_share_recipient(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_curve_25519_public_key(
-      VodozemacCurve25519PublicKey apiObj, wire_cst_vodozemac_curve_25519_public_key wireObj) {
-    wireObj.key = cst_encode_RustOpaque_Curve25519PublicKey(apiObj.key);
-  }
+@protected ToDeviceDecryptionError dco_decode_to_device_decryption_error(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_ed_25519_public_key(
-      VodozemacEd25519PublicKey apiObj, wire_cst_vodozemac_ed_25519_public_key wireObj) {
-    wireObj.key = cst_encode_RustOpaque_Ed25519PublicKey(apiObj.key);
-  }
+@protected ToDeviceDecryptionResult dco_decode_to_device_decryption_result(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_ed_25519_signature(
-      VodozemacEd25519Signature apiObj, wire_cst_vodozemac_ed_25519_signature wireObj) {
-    wireObj.signature = cst_encode_RustOpaque_Ed25519Signature(apiObj.signature);
-  }
+@protected TrustLink dco_decode_trust_link(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_established_sas(
-      VodozemacEstablishedSas apiObj, wire_cst_vodozemac_established_sas wireObj) {
-    wireObj.established_sas = cst_encode_RustOpaque_EstablishedSas(apiObj.establishedSas);
-  }
+@protected int dco_decode_u_32(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_group_session(
-      VodozemacGroupSession apiObj, wire_cst_vodozemac_group_session wireObj) {
-    wireObj.session = cst_encode_RustOpaque_RwLockGroupSession(apiObj.session);
-  }
+@protected BigInt dco_decode_u_64(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_identity_keys(
-      VodozemacIdentityKeys apiObj, wire_cst_vodozemac_identity_keys wireObj) {
-    cst_api_fill_to_wire_vodozemac_ed_25519_public_key(apiObj.ed25519, wireObj.ed25519);
-    cst_api_fill_to_wire_vodozemac_curve_25519_public_key(apiObj.curve25519, wireObj.curve25519);
-  }
+@protected int dco_decode_u_8(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_inbound_group_session(
-      VodozemacInboundGroupSession apiObj, wire_cst_vodozemac_inbound_group_session wireObj) {
-    wireObj.session = cst_encode_RustOpaque_RwLockInboundGroupSession(apiObj.session);
-  }
+@protected U8Array32 dco_decode_u_8_array_32(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_megolm_session_config(
-      VodozemacMegolmSessionConfig apiObj, wire_cst_vodozemac_megolm_session_config wireObj) {
-    wireObj.config = cst_encode_RustOpaque_MegolmSessionConfig(apiObj.config);
-  }
+@protected U8Array64 dco_decode_u_8_array_64(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_olm_message(VodozemacOlmMessage apiObj, wire_cst_vodozemac_olm_message wireObj) {
-    wireObj.msg = cst_encode_RustOpaque_OlmMessage(apiObj.msg);
-  }
+@protected void dco_decode_unit(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_olm_session_config(
-      VodozemacOlmSessionConfig apiObj, wire_cst_vodozemac_olm_session_config wireObj) {
-    wireObj.config = cst_encode_RustOpaque_OlmSessionConfig(apiObj.config);
-  }
+@protected UnpickledAccount dco_decode_unpickled_account(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_olm_session_creation_result(
-      VodozemacOlmSessionCreationResult apiObj, wire_cst_vodozemac_olm_session_creation_result wireObj) {
-    cst_api_fill_to_wire_vodozemac_session(apiObj.session, wireObj.session);
-    wireObj.plaintext = cst_encode_String(apiObj.plaintext);
-  }
+@protected UnpickledGroupSession dco_decode_unpickled_group_session(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_one_time_key(
-      VodozemacOneTimeKey apiObj, wire_cst_vodozemac_one_time_key wireObj) {
-    wireObj.keyid = cst_encode_String(apiObj.keyid);
-    cst_api_fill_to_wire_vodozemac_curve_25519_public_key(apiObj.key, wireObj.key);
-  }
+@protected UnpickledInboundGroupSession dco_decode_unpickled_inbound_group_session(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_pk_decryption(
-      VodozemacPkDecryption apiObj, wire_cst_vodozemac_pk_decryption wireObj) {
-    wireObj.pk_decryption = cst_encode_RustOpaque_PkDecryption(apiObj.pkDecryption);
-  }
+@protected UnpickledPkDecryption dco_decode_unpickled_pk_decryption(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_pk_encryption(
-      VodozemacPkEncryption apiObj, wire_cst_vodozemac_pk_encryption wireObj) {
-    wireObj.pk_encryption = cst_encode_RustOpaque_PkEncryption(apiObj.pkEncryption);
-  }
+@protected UnpickledSession dco_decode_unpickled_session(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_pk_message(VodozemacPkMessage apiObj, wire_cst_vodozemac_pk_message wireObj) {
-    wireObj.ciphertext = cst_encode_list_prim_u_8_strict(apiObj.ciphertext);
-    wireObj.mac = cst_encode_list_prim_u_8_strict(apiObj.mac);
-    cst_api_fill_to_wire_vodozemac_curve_25519_public_key(apiObj.ephemeralKey, wireObj.ephemeral_key);
-  }
+@protected UnwedgeError dco_decode_unwedge_error(dynamic raw);
 
-  @protected
-  void cst_api_fill_to_wire_vodozemac_session(VodozemacSession apiObj, wire_cst_vodozemac_session wireObj) {
-    wireObj.session = cst_encode_RustOpaque_RwLockSession(apiObj.session);
-  }
+@protected UnwedgeResult dco_decode_unwedge_result(dynamic raw);
 
-  @protected
-  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw);
+@protected UnwedgeTracker dco_decode_unwedge_tracker(dynamic raw);
 
-  @protected
-  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      VodozemacSas raw);
+@protected BigInt dco_decode_usize(dynamic raw);
 
-  @protected
-  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw);
+@protected UtdCause dco_decode_utd_cause(dynamic raw);
 
-  @protected
-  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw);
+@protected UtdClassification dco_decode_utd_classification(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_Curve25519PublicKey(Curve25519PublicKey raw);
+@protected UtdQueue dco_decode_utd_queue(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_Ed25519PublicKey(Ed25519PublicKey raw);
+@protected VodozemacAccount dco_decode_vodozemac_account(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_Ed25519Signature(Ed25519Signature raw);
+@protected VodozemacCurve25519PublicKey dco_decode_vodozemac_curve_25519_public_key(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_EstablishedSas(EstablishedSas raw);
+@protected VodozemacEd25519PublicKey dco_decode_vodozemac_ed_25519_public_key(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_MegolmSessionConfig(MegolmSessionConfig raw);
+@protected VodozemacEd25519Signature dco_decode_vodozemac_ed_25519_signature(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_OlmMessage(OlmMessage raw);
+@protected VodozemacEstablishedSas dco_decode_vodozemac_established_sas(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_OlmSessionConfig(OlmSessionConfig raw);
+@protected VodozemacGroupSession dco_decode_vodozemac_group_session(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_PkDecryption(PkDecryption raw);
+@protected VodozemacIdentityKeys dco_decode_vodozemac_identity_keys(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_PkEncryption(PkEncryption raw);
+@protected VodozemacInboundGroupSession dco_decode_vodozemac_inbound_group_session(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_RwLockGroupSession(RwLockGroupSession raw);
+@protected VodozemacMegolmSessionConfig dco_decode_vodozemac_megolm_session_config(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_RwLockInboundGroupSession(RwLockInboundGroupSession raw);
+@protected VodozemacOlmMessage dco_decode_vodozemac_olm_message(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_RwLockSession(RwLockSession raw);
+@protected VodozemacOlmSessionConfig dco_decode_vodozemac_olm_session_config(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw);
+@protected VodozemacOlmSessionCreationResult dco_decode_vodozemac_olm_session_creation_result(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw);
+@protected VodozemacOneTimeKey dco_decode_vodozemac_one_time_key(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_stdsyncRwLockAccount(RwLockAccount raw);
+@protected VodozemacPkDecryption dco_decode_vodozemac_pk_decryption(dynamic raw);
 
-  @protected
-  bool cst_encode_bool(bool raw);
+@protected VodozemacPkEncryption dco_decode_vodozemac_pk_encryption(dynamic raw);
 
-  @protected
-  int cst_encode_u_32(int raw);
+@protected VodozemacPkMessage dco_decode_vodozemac_pk_message(dynamic raw);
 
-  @protected
-  int cst_encode_u_8(int raw);
+@protected VodozemacSession dco_decode_vodozemac_session(dynamic raw);
 
-  @protected
-  void cst_encode_unit(void raw);
+@protected WithheldCode dco_decode_withheld_code(dynamic raw);
 
-  @protected
-  void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      PkSigning self, SseSerializer serializer);
+@protected CrossSigningIdentity sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      VodozemacSas self, SseSerializer serializer);
+@protected PkSigning sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      PkSigning self, SseSerializer serializer);
+@protected VodozemacSas sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      VodozemacSas self, SseSerializer serializer);
+@protected CrossSigningIdentity sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_Curve25519PublicKey(Curve25519PublicKey self, SseSerializer serializer);
+@protected PkSigning sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_Ed25519PublicKey(Ed25519PublicKey self, SseSerializer serializer);
+@protected VodozemacSas sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_Ed25519Signature(Ed25519Signature self, SseSerializer serializer);
+@protected Map<String, int> sse_decode_Map_String_u_32_None(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_EstablishedSas(EstablishedSas self, SseSerializer serializer);
+@protected Curve25519PublicKey sse_decode_RustOpaque_Curve25519PublicKey(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_MegolmSessionConfig(MegolmSessionConfig self, SseSerializer serializer);
+@protected Ed25519PublicKey sse_decode_RustOpaque_Ed25519PublicKey(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_OlmMessage(OlmMessage self, SseSerializer serializer);
+@protected Ed25519Signature sse_decode_RustOpaque_Ed25519Signature(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_OlmSessionConfig(OlmSessionConfig self, SseSerializer serializer);
+@protected EstablishedSas sse_decode_RustOpaque_EstablishedSas(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_PkDecryption(PkDecryption self, SseSerializer serializer);
+@protected MegolmSessionConfig sse_decode_RustOpaque_MegolmSessionConfig(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_PkEncryption(PkEncryption self, SseSerializer serializer);
+@protected OlmMessage sse_decode_RustOpaque_OlmMessage(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_RwLockGroupSession(RwLockGroupSession self, SseSerializer serializer);
+@protected OlmSessionConfig sse_decode_RustOpaque_OlmSessionConfig(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_RwLockInboundGroupSession(RwLockInboundGroupSession self, SseSerializer serializer);
+@protected PkDecryption sse_decode_RustOpaque_PkDecryption(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_RwLockSession(RwLockSession self, SseSerializer serializer);
+@protected PkEncryption sse_decode_RustOpaque_PkEncryption(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      PkSigning self, SseSerializer serializer);
+@protected RwLockFallbackKeyState sse_decode_RustOpaque_RwLockFallbackKeyState(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      VodozemacSas self, SseSerializer serializer);
+@protected RwLockGroupSession sse_decode_RustOpaque_RwLockGroupSession(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_RustOpaque_stdsyncRwLockAccount(RwLockAccount self, SseSerializer serializer);
+@protected RwLockInboundGroupSession sse_decode_RustOpaque_RwLockInboundGroupSession(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected RwLockReplayState sse_decode_RustOpaque_RwLockReplayState(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected RwLockRoomKeyInfo sse_decode_RustOpaque_RwLockRoomKeyInfo(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_account(VodozemacAccount self, SseSerializer serializer);
+@protected RwLockRotationState sse_decode_RustOpaque_RwLockRotationState(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_curve_25519_public_key(
-      VodozemacCurve25519PublicKey self, SseSerializer serializer);
+@protected RwLockSession sse_decode_RustOpaque_RwLockSession(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey self, SseSerializer serializer);
+@protected RwLockSessionKeySource sse_decode_RustOpaque_RwLockSessionKeySource(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_ed_25519_signature(VodozemacEd25519Signature self, SseSerializer serializer);
+@protected RwLockSessionStoreState sse_decode_RustOpaque_RwLockSessionStoreState(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_established_sas(VodozemacEstablishedSas self, SseSerializer serializer);
+@protected RwLockShareState sse_decode_RustOpaque_RwLockShareState(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_group_session(VodozemacGroupSession self, SseSerializer serializer);
+@protected RwLockUnwedgeState sse_decode_RustOpaque_RwLockUnwedgeState(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_inbound_group_session(
-      VodozemacInboundGroupSession self, SseSerializer serializer);
+@protected RwLockUtdQueueState sse_decode_RustOpaque_RwLockUtdQueueState(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_megolm_session_config(
-      VodozemacMegolmSessionConfig self, SseSerializer serializer);
+@protected CrossSigningIdentity sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_olm_message(VodozemacOlmMessage self, SseSerializer serializer);
+@protected PkSigning sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_olm_session_config(VodozemacOlmSessionConfig self, SseSerializer serializer);
+@protected VodozemacSas sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_pk_decryption(VodozemacPkDecryption self, SseSerializer serializer);
+@protected RwLockAccount sse_decode_RustOpaque_stdsyncRwLockAccount(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_pk_encryption(VodozemacPkEncryption self, SseSerializer serializer);
+@protected RustStreamSink<RekeyProgress> sse_decode_StreamSink_rekey_progress_Dco(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_pk_message(VodozemacPkMessage self, SseSerializer serializer);
+@protected String sse_decode_String(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_session(VodozemacSession self, SseSerializer serializer);
+@protected bool sse_decode_bool(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_decrypt_result(DecryptResult self, SseSerializer serializer);
+@protected DeviceKeys sse_decode_box_autoadd_device_keys(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+@protected EncryptedRoomKeyBundle sse_decode_box_autoadd_encrypted_room_key_bundle(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+@protected FallbackKeyTracker sse_decode_box_autoadd_fallback_key_tracker(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_vodozemac_one_time_key(List<VodozemacOneTimeKey> self, SseSerializer serializer);
+@protected InboundRoomKey sse_decode_box_autoadd_inbound_room_key(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected LibolmStore sse_decode_box_autoadd_libolm_store(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_record_string_string_string((String, String, String) self, SseSerializer serializer);
+@protected MegolmReplayGuard sse_decode_box_autoadd_megolm_replay_guard(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+@protected MigratedPickle sse_decode_box_autoadd_migrated_pickle(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected MigrationReport sse_decode_box_autoadd_migration_report(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_8_array_32(U8Array32 self, SseSerializer serializer);
+@protected OlmPayload sse_decode_box_autoadd_olm_payload(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_8_array_64(U8Array64 self, SseSerializer serializer);
+@protected OlmSessionStore sse_decode_box_autoadd_olm_session_store(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected OutboundGroupSession sse_decode_box_autoadd_outbound_group_session(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
+@protected PassphraseKdfParams sse_decode_box_autoadd_passphrase_kdf_params(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_account(VodozemacAccount self, SseSerializer serializer);
+@protected PickleFormatError sse_decode_box_autoadd_pickle_format_error(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey self, SseSerializer serializer);
+@protected PickleInfo sse_decode_box_autoadd_pickle_info(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey self, SseSerializer serializer);
+@protected RetainedRoomKey sse_decode_box_autoadd_retained_room_key(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_ed_25519_signature(VodozemacEd25519Signature self, SseSerializer serializer);
+@protected RoomKeyBundleDownload sse_decode_box_autoadd_room_key_bundle_download(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_established_sas(VodozemacEstablishedSas self, SseSerializer serializer);
+@protected RoomKeyInfo sse_decode_box_autoadd_room_key_info(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_group_session(VodozemacGroupSession self, SseSerializer serializer);
+@protected RotationReason sse_decode_box_autoadd_rotation_reason(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_identity_keys(VodozemacIdentityKeys self, SseSerializer serializer);
+@protected ShareRecipient sse_decode_box_autoadd_share_recipient(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_inbound_group_session(VodozemacInboundGroupSession self, SseSerializer serializer);
+@protected TrustLink sse_decode_box_autoadd_trust_link(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig self, SseSerializer serializer);
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_olm_message(VodozemacOlmMessage self, SseSerializer serializer);
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_olm_session_config(VodozemacOlmSessionConfig self, SseSerializer serializer);
+@protected UnwedgeTracker sse_decode_box_autoadd_unwedge_tracker(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_olm_session_creation_result(
-      VodozemacOlmSessionCreationResult self, SseSerializer serializer);
+@protected UtdClassification sse_decode_box_autoadd_utd_classification(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_one_time_key(VodozemacOneTimeKey self, SseSerializer serializer);
+@protected UtdQueue sse_decode_box_autoadd_utd_queue(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_pk_decryption(VodozemacPkDecryption self, SseSerializer serializer);
+@protected VodozemacAccount sse_decode_box_autoadd_vodozemac_account(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_pk_encryption(VodozemacPkEncryption self, SseSerializer serializer);
+@protected VodozemacCurve25519PublicKey sse_decode_box_autoadd_vodozemac_curve_25519_public_key(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_pk_message(VodozemacPkMessage self, SseSerializer serializer);
+@protected VodozemacEd25519PublicKey sse_decode_box_autoadd_vodozemac_ed_25519_public_key(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_session(VodozemacSession self, SseSerializer serializer);
+@protected VodozemacEd25519Signature sse_decode_box_autoadd_vodozemac_ed_25519_signature(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-}
+@protected VodozemacEstablishedSas sse_decode_box_autoadd_vodozemac_established_sas(SseDeserializer deserializer);
 
-// Section: wire_class
+@protected VodozemacGroupSession sse_decode_box_autoadd_vodozemac_group_session(SseDeserializer deserializer);
 
-// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
-// AUTO GENERATED FILE, DO NOT EDIT.
-//
-// Generated by `package:ffigen`.
-// ignore_for_file: type=lint
+@protected VodozemacInboundGroupSession sse_decode_box_autoadd_vodozemac_inbound_group_session(SseDeserializer deserializer);
 
-/// generated by flutter_rust_bridge
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) => RustLibWire(lib.ffiDynamicLibrary);
+@protected VodozemacMegolmSessionConfig sse_decode_box_autoadd_vodozemac_megolm_session_config(SseDeserializer deserializer);
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) _lookup;
+@protected VodozemacOlmMessage sse_decode_box_autoadd_vodozemac_olm_message(SseDeserializer deserializer);
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary) : _lookup = dynamicLibrary.lookup;
+@protected VodozemacOlmSessionConfig sse_decode_box_autoadd_vodozemac_olm_session_config(SseDeserializer deserializer);
 
-  /// The symbols are looked up with [lookup].
-  RustLibWire.fromLookup(ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) lookup)
-      : _lookup = lookup;
+@protected VodozemacPkDecryption sse_decode_box_autoadd_vodozemac_pk_decryption(SseDeserializer deserializer);
 
-  void store_dart_post_cobject(
-    DartPostCObjectFnType ptr,
-  ) {
-    return _store_dart_post_cobject(
-      ptr,
-    );
-  }
+@protected VodozemacPkEncryption sse_decode_box_autoadd_vodozemac_pk_encryption(SseDeserializer deserializer);
 
-  late final _store_dart_post_cobjectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>('store_dart_post_cobject');
-  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr.asFunction<void Function(DartPostCObjectFnType)>();
+@protected VodozemacPkMessage sse_decode_box_autoadd_vodozemac_pk_message(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__PkSigning_from_secret_key(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> key,
-  ) {
-    return _wire__crate__bindings__PkSigning_from_secret_key(
-      key,
-    );
-  }
+@protected VodozemacSession sse_decode_box_autoadd_vodozemac_session(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__PkSigning_from_secret_keyPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__PkSigning_from_secret_key');
-  late final _wire__crate__bindings__PkSigning_from_secret_key = _wire__crate__bindings__PkSigning_from_secret_keyPtr
-      .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+@protected WithheldCode sse_decode_box_autoadd_withheld_code(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__PkSigning_new() {
-    return _wire__crate__bindings__PkSigning_new();
-  }
+@protected ClaimedSession sse_decode_claimed_session(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__PkSigning_newPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-      'frbgen_vodozemac_wire__crate__bindings__PkSigning_new');
-  late final _wire__crate__bindings__PkSigning_new =
-      _wire__crate__bindings__PkSigning_newPtr.asFunction<WireSyncRust2DartDco Function()>();
+@protected DecryptResult sse_decode_decrypt_result(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__PkSigning_public_key(
-    int that,
-  ) {
-    return _wire__crate__bindings__PkSigning_public_key(
-      that,
-    );
-  }
+@protected DeviceKeys sse_decode_device_keys(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__PkSigning_public_keyPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
-          'frbgen_vodozemac_wire__crate__bindings__PkSigning_public_key');
-  late final _wire__crate__bindings__PkSigning_public_key =
-      _wire__crate__bindings__PkSigning_public_keyPtr.asFunction<WireSyncRust2DartDco Function(int)>();
+@protected DeviceKeysError sse_decode_device_keys_error(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__PkSigning_secret_key(
-    int that,
-  ) {
-    return _wire__crate__bindings__PkSigning_secret_key(
-      that,
-    );
-  }
+@protected DeviceTrust sse_decode_device_trust(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__PkSigning_secret_keyPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
-          'frbgen_vodozemac_wire__crate__bindings__PkSigning_secret_key');
-  late final _wire__crate__bindings__PkSigning_secret_key =
-      _wire__crate__bindings__PkSigning_secret_keyPtr.asFunction<WireSyncRust2DartDco Function(int)>();
+@protected DeviceTrustLevel sse_decode_device_trust_level(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__PkSigning_sign(
-    int that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
-  ) {
-    return _wire__crate__bindings__PkSigning_sign(
-      that,
-      message,
-    );
-  }
+@protected EncryptedRoomKeyBundle sse_decode_encrypted_room_key_bundle(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__PkSigning_signPtr = _lookup<
-          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__PkSigning_sign');
-  late final _wire__crate__bindings__PkSigning_sign = _wire__crate__bindings__PkSigning_signPtr
-      .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+@protected ExtendedDecryptResult sse_decode_extended_decrypt_result(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__VodozemacSas_establish_sas_secret(
-    int that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> other_public_key,
-  ) {
-    return _wire__crate__bindings__VodozemacSas_establish_sas_secret(
-      that,
-      other_public_key,
-    );
-  }
+@protected FallbackKeyTracker sse_decode_fallback_key_tracker(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__VodozemacSas_establish_sas_secretPtr = _lookup<
-          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__VodozemacSas_establish_sas_secret');
-  late final _wire__crate__bindings__VodozemacSas_establish_sas_secret =
-      _wire__crate__bindings__VodozemacSas_establish_sas_secretPtr
-          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+@protected int sse_decode_i_32(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__VodozemacSas_new() {
-    return _wire__crate__bindings__VodozemacSas_new();
-  }
+@protected ImportedRoomKeyBundle sse_decode_imported_room_key_bundle(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__VodozemacSas_newPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-      'frbgen_vodozemac_wire__crate__bindings__VodozemacSas_new');
-  late final _wire__crate__bindings__VodozemacSas_new =
-      _wire__crate__bindings__VodozemacSas_newPtr.asFunction<WireSyncRust2DartDco Function()>();
+@protected InboundRoomKey sse_decode_inbound_room_key(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__VodozemacSas_public_key(
-    int that,
-  ) {
-    return _wire__crate__bindings__VodozemacSas_public_key(
-      that,
-    );
-  }
+@protected KeyAuthenticity sse_decode_key_authenticity(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__VodozemacSas_public_keyPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
-          'frbgen_vodozemac_wire__crate__bindings__VodozemacSas_public_key');
-  late final _wire__crate__bindings__VodozemacSas_public_key =
-      _wire__crate__bindings__VodozemacSas_public_keyPtr.asFunction<WireSyncRust2DartDco Function(int)>();
+@protected KeyClaimError sse_decode_key_claim_error(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__aes_ctr(
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> input,
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> key,
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> iv,
-  ) {
-    return _wire__crate__bindings__aes_ctr(
-      input,
-      key,
-      iv,
-    );
-  }
+@protected KeyMaintenancePlan sse_decode_key_maintenance_plan(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__aes_ctrPtr = _lookup<
-      ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_loose>,
-              ffi.Pointer<wire_cst_list_prim_u_8_loose>,
-              ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_vodozemac_wire__crate__bindings__aes_ctr');
-  late final _wire__crate__bindings__aes_ctr = _wire__crate__bindings__aes_ctrPtr.asFunction<
-      WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>,
-          ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+@protected LibolmStore sse_decode_libolm_store(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__hmac(
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> key,
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> input,
-  ) {
-    return _wire__crate__bindings__hmac(
-      key,
-      input,
-    );
-  }
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__hmacPtr = _lookup<
-      ffi.NativeFunction<
-          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>,
-              ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_vodozemac_wire__crate__bindings__hmac');
-  late final _wire__crate__bindings__hmac = _wire__crate__bindings__hmacPtr.asFunction<
-      WireSyncRust2DartDco Function(
-          ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+@protected List<DeviceTrust> sse_decode_list_device_trust(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__pbkdf2(
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> passphrase,
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> salt,
-    int iterations,
-  ) {
-    return _wire__crate__bindings__pbkdf2(
-      passphrase,
-      salt,
-      iterations,
-    );
-  }
+@protected List<InboundRoomKey> sse_decode_list_inbound_room_key(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__pbkdf2Ptr = _lookup<
-      ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_loose>,
-              ffi.Pointer<wire_cst_list_prim_u_8_loose>,
-              ffi.Uint32)>>('frbgen_vodozemac_wire__crate__bindings__pbkdf2');
-  late final _wire__crate__bindings__pbkdf2 = _wire__crate__bindings__pbkdf2Ptr.asFunction<
-      WireSyncRust2DartDco Function(
-          ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, int)>();
+@protected List<MigratedPickle> sse_decode_list_migrated_pickle(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__sha256(
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> input,
-  ) {
-    return _wire__crate__bindings__sha256(
-      input,
-    );
-  }
+@protected List<MigrationFailure> sse_decode_list_migration_failure(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__sha256Ptr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__sha256');
-  late final _wire__crate__bindings__sha256 = _wire__crate__bindings__sha256Ptr
-      .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__sha512(
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> input,
-  ) {
-    return _wire__crate__bindings__sha512(
-      input,
-    );
-  }
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
-  late final _wire__crate__bindings__sha512Ptr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__sha512');
-  late final _wire__crate__bindings__sha512 = _wire__crate__bindings__sha512Ptr
-      .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+@protected List<(String,int)> sse_decode_list_record_string_u_32(SseDeserializer deserializer);
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_create_inbound_session(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
-    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> their_identity_key,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pre_key_message_base64,
+@protected List<RekeyResult> sse_decode_list_rekey_result(SseDeserializer deserializer);
+
+@protected List<RetentionResult> sse_decode_list_retention_result(SseDeserializer deserializer);
+
+@protected List<RetriedEvent> sse_decode_list_retried_event(SseDeserializer deserializer);
+
+@protected List<RoomRetention> sse_decode_list_room_retention(SseDeserializer deserializer);
+
+@protected List<ShareRecipient> sse_decode_list_share_recipient(SseDeserializer deserializer);
+
+@protected List<VodozemacOneTimeKey> sse_decode_list_vodozemac_one_time_key(SseDeserializer deserializer);
+
+@protected List<VodozemacSession> sse_decode_list_vodozemac_session(SseDeserializer deserializer);
+
+@protected MegolmReplayGuard sse_decode_megolm_replay_guard(SseDeserializer deserializer);
+
+@protected MigratedPickle sse_decode_migrated_pickle(SseDeserializer deserializer);
+
+@protected MigrationError sse_decode_migration_error(SseDeserializer deserializer);
+
+@protected MigrationFailure sse_decode_migration_failure(SseDeserializer deserializer);
+
+@protected MigrationReport sse_decode_migration_report(SseDeserializer deserializer);
+
+@protected OlmPayload sse_decode_olm_payload(SseDeserializer deserializer);
+
+@protected OlmPayloadError sse_decode_olm_payload_error(SseDeserializer deserializer);
+
+@protected OlmSessionStore sse_decode_olm_session_store(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected MigratedPickle? sse_decode_opt_box_autoadd_migrated_pickle(SseDeserializer deserializer);
+
+@protected PickleFormatError? sse_decode_opt_box_autoadd_pickle_format_error(SseDeserializer deserializer);
+
+@protected RetainedRoomKey? sse_decode_opt_box_autoadd_retained_room_key(SseDeserializer deserializer);
+
+@protected RotationReason? sse_decode_opt_box_autoadd_rotation_reason(SseDeserializer deserializer);
+
+@protected TrustLink? sse_decode_opt_box_autoadd_trust_link(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected UtdClassification? sse_decode_opt_box_autoadd_utd_classification(SseDeserializer deserializer);
+
+@protected VodozemacEd25519PublicKey? sse_decode_opt_box_autoadd_vodozemac_ed_25519_public_key(SseDeserializer deserializer);
+
+@protected VodozemacSession? sse_decode_opt_box_autoadd_vodozemac_session(SseDeserializer deserializer);
+
+@protected WithheldCode? sse_decode_opt_box_autoadd_withheld_code(SseDeserializer deserializer);
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+@protected List<RekeyResult>? sse_decode_opt_list_rekey_result(SseDeserializer deserializer);
+
+@protected OutboundGroupSession sse_decode_outbound_group_session(SseDeserializer deserializer);
+
+@protected PassphraseKdf sse_decode_passphrase_kdf(SseDeserializer deserializer);
+
+@protected PassphraseKdfParams sse_decode_passphrase_kdf_params(SseDeserializer deserializer);
+
+@protected PassphrasePickleError sse_decode_passphrase_pickle_error(SseDeserializer deserializer);
+
+@protected PickleFormat sse_decode_pickle_format(SseDeserializer deserializer);
+
+@protected PickleFormatError sse_decode_pickle_format_error(SseDeserializer deserializer);
+
+@protected PickleInfo sse_decode_pickle_info(SseDeserializer deserializer);
+
+@protected PickleObject sse_decode_pickle_object(SseDeserializer deserializer);
+
+@protected (String,String,String) sse_decode_record_string_string_string(SseDeserializer deserializer);
+
+@protected (String,int) sse_decode_record_string_u_32(SseDeserializer deserializer);
+
+@protected RekeyProgress sse_decode_rekey_progress(SseDeserializer deserializer);
+
+@protected RekeyResult sse_decode_rekey_result(SseDeserializer deserializer);
+
+@protected RetainedRoomKey sse_decode_retained_room_key(SseDeserializer deserializer);
+
+@protected RetentionResult sse_decode_retention_result(SseDeserializer deserializer);
+
+@protected RetriedEvent sse_decode_retried_event(SseDeserializer deserializer);
+
+@protected RoomEventDecryptionResult sse_decode_room_event_decryption_result(SseDeserializer deserializer);
+
+@protected RoomEventError sse_decode_room_event_error(SseDeserializer deserializer);
+
+@protected RoomKeyBundleDownload sse_decode_room_key_bundle_download(SseDeserializer deserializer);
+
+@protected RoomKeyBundleError sse_decode_room_key_bundle_error(SseDeserializer deserializer);
+
+@protected RoomKeyDecryptionResult sse_decode_room_key_decryption_result(SseDeserializer deserializer);
+
+@protected RoomKeyError sse_decode_room_key_error(SseDeserializer deserializer);
+
+@protected RoomKeyInfo sse_decode_room_key_info(SseDeserializer deserializer);
+
+@protected RoomKeyOrigin sse_decode_room_key_origin(SseDeserializer deserializer);
+
+@protected RoomRetention sse_decode_room_retention(SseDeserializer deserializer);
+
+@protected RotationReason sse_decode_rotation_reason(SseDeserializer deserializer);
+
+@protected SessionKeySource sse_decode_session_key_source(SseDeserializer deserializer);
+
+@protected ShareRecipient sse_decode_share_recipient(SseDeserializer deserializer);
+
+@protected ToDeviceDecryptionError sse_decode_to_device_decryption_error(SseDeserializer deserializer);
+
+@protected ToDeviceDecryptionResult sse_decode_to_device_decryption_result(SseDeserializer deserializer);
+
+@protected TrustLink sse_decode_trust_link(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected U8Array32 sse_decode_u_8_array_32(SseDeserializer deserializer);
+
+@protected U8Array64 sse_decode_u_8_array_64(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected UnpickledAccount sse_decode_unpickled_account(SseDeserializer deserializer);
+
+@protected UnpickledGroupSession sse_decode_unpickled_group_session(SseDeserializer deserializer);
+
+@protected UnpickledInboundGroupSession sse_decode_unpickled_inbound_group_session(SseDeserializer deserializer);
+
+@protected UnpickledPkDecryption sse_decode_unpickled_pk_decryption(SseDeserializer deserializer);
+
+@protected UnpickledSession sse_decode_unpickled_session(SseDeserializer deserializer);
+
+@protected UnwedgeError sse_decode_unwedge_error(SseDeserializer deserializer);
+
+@protected UnwedgeResult sse_decode_unwedge_result(SseDeserializer deserializer);
+
+@protected UnwedgeTracker sse_decode_unwedge_tracker(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected UtdCause sse_decode_utd_cause(SseDeserializer deserializer);
+
+@protected UtdClassification sse_decode_utd_classification(SseDeserializer deserializer);
+
+@protected UtdQueue sse_decode_utd_queue(SseDeserializer deserializer);
+
+@protected VodozemacAccount sse_decode_vodozemac_account(SseDeserializer deserializer);
+
+@protected VodozemacCurve25519PublicKey sse_decode_vodozemac_curve_25519_public_key(SseDeserializer deserializer);
+
+@protected VodozemacEd25519PublicKey sse_decode_vodozemac_ed_25519_public_key(SseDeserializer deserializer);
+
+@protected VodozemacEd25519Signature sse_decode_vodozemac_ed_25519_signature(SseDeserializer deserializer);
+
+@protected VodozemacEstablishedSas sse_decode_vodozemac_established_sas(SseDeserializer deserializer);
+
+@protected VodozemacGroupSession sse_decode_vodozemac_group_session(SseDeserializer deserializer);
+
+@protected VodozemacIdentityKeys sse_decode_vodozemac_identity_keys(SseDeserializer deserializer);
+
+@protected VodozemacInboundGroupSession sse_decode_vodozemac_inbound_group_session(SseDeserializer deserializer);
+
+@protected VodozemacMegolmSessionConfig sse_decode_vodozemac_megolm_session_config(SseDeserializer deserializer);
+
+@protected VodozemacOlmMessage sse_decode_vodozemac_olm_message(SseDeserializer deserializer);
+
+@protected VodozemacOlmSessionConfig sse_decode_vodozemac_olm_session_config(SseDeserializer deserializer);
+
+@protected VodozemacOlmSessionCreationResult sse_decode_vodozemac_olm_session_creation_result(SseDeserializer deserializer);
+
+@protected VodozemacOneTimeKey sse_decode_vodozemac_one_time_key(SseDeserializer deserializer);
+
+@protected VodozemacPkDecryption sse_decode_vodozemac_pk_decryption(SseDeserializer deserializer);
+
+@protected VodozemacPkEncryption sse_decode_vodozemac_pk_encryption(SseDeserializer deserializer);
+
+@protected VodozemacPkMessage sse_decode_vodozemac_pk_message(SseDeserializer deserializer);
+
+@protected VodozemacSession sse_decode_vodozemac_session(SseDeserializer deserializer);
+
+@protected WithheldCode sse_decode_withheld_code(SseDeserializer deserializer);
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_AnyhowException(AnyhowException raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected ffi.Pointer<wire_cst_list_record_string_u_32> cst_encode_Map_String_u_32_None(Map<String, int> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_list_record_string_u_32(raw.entries.map((e) => (e.key, e.value)).toList()); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_rekey_progress_Dco(RustStreamSink<RekeyProgress> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_rekey_progress,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_String(String raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_list_prim_u_8_strict(utf8.encoder.convert(raw)); }
+
+@protected ffi.Pointer<wire_cst_device_keys> cst_encode_box_autoadd_device_keys(DeviceKeys raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_device_keys();
+                    cst_api_fill_to_wire_device_keys(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_encrypted_room_key_bundle> cst_encode_box_autoadd_encrypted_room_key_bundle(EncryptedRoomKeyBundle raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_encrypted_room_key_bundle();
+                    cst_api_fill_to_wire_encrypted_room_key_bundle(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_fallback_key_tracker> cst_encode_box_autoadd_fallback_key_tracker(FallbackKeyTracker raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_fallback_key_tracker();
+                    cst_api_fill_to_wire_fallback_key_tracker(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_inbound_room_key> cst_encode_box_autoadd_inbound_room_key(InboundRoomKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_inbound_room_key();
+                    cst_api_fill_to_wire_inbound_room_key(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_libolm_store> cst_encode_box_autoadd_libolm_store(LibolmStore raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_libolm_store();
+                    cst_api_fill_to_wire_libolm_store(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_megolm_replay_guard> cst_encode_box_autoadd_megolm_replay_guard(MegolmReplayGuard raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_megolm_replay_guard();
+                    cst_api_fill_to_wire_megolm_replay_guard(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_migrated_pickle> cst_encode_box_autoadd_migrated_pickle(MigratedPickle raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_migrated_pickle();
+                    cst_api_fill_to_wire_migrated_pickle(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_migration_report> cst_encode_box_autoadd_migration_report(MigrationReport raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_migration_report();
+                    cst_api_fill_to_wire_migration_report(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_olm_payload> cst_encode_box_autoadd_olm_payload(OlmPayload raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_olm_payload();
+                    cst_api_fill_to_wire_olm_payload(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_olm_session_store> cst_encode_box_autoadd_olm_session_store(OlmSessionStore raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_olm_session_store();
+                    cst_api_fill_to_wire_olm_session_store(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_outbound_group_session> cst_encode_box_autoadd_outbound_group_session(OutboundGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_outbound_group_session();
+                    cst_api_fill_to_wire_outbound_group_session(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_passphrase_kdf_params> cst_encode_box_autoadd_passphrase_kdf_params(PassphraseKdfParams raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_passphrase_kdf_params();
+                    cst_api_fill_to_wire_passphrase_kdf_params(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_pickle_format_error(PickleFormatError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return wire.cst_new_box_autoadd_pickle_format_error(cst_encode_pickle_format_error(raw)); }
+
+@protected ffi.Pointer<wire_cst_pickle_info> cst_encode_box_autoadd_pickle_info(PickleInfo raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_pickle_info();
+                    cst_api_fill_to_wire_pickle_info(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_retained_room_key> cst_encode_box_autoadd_retained_room_key(RetainedRoomKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_retained_room_key();
+                    cst_api_fill_to_wire_retained_room_key(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_room_key_bundle_download> cst_encode_box_autoadd_room_key_bundle_download(RoomKeyBundleDownload raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_room_key_bundle_download();
+                    cst_api_fill_to_wire_room_key_bundle_download(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_room_key_info> cst_encode_box_autoadd_room_key_info(RoomKeyInfo raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_room_key_info();
+                    cst_api_fill_to_wire_room_key_info(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_rotation_reason(RotationReason raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return wire.cst_new_box_autoadd_rotation_reason(cst_encode_rotation_reason(raw)); }
+
+@protected ffi.Pointer<wire_cst_share_recipient> cst_encode_box_autoadd_share_recipient(ShareRecipient raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_share_recipient();
+                    cst_api_fill_to_wire_share_recipient(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_trust_link(TrustLink raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return wire.cst_new_box_autoadd_trust_link(cst_encode_trust_link(raw)); }
+
+@protected ffi.Pointer<ffi.Uint32> cst_encode_box_autoadd_u_32(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return wire.cst_new_box_autoadd_u_32(cst_encode_u_32(raw)); }
+
+@protected ffi.Pointer<ffi.Uint64> cst_encode_box_autoadd_u_64(BigInt raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return wire.cst_new_box_autoadd_u_64(cst_encode_u_64(raw)); }
+
+@protected ffi.Pointer<wire_cst_unwedge_tracker> cst_encode_box_autoadd_unwedge_tracker(UnwedgeTracker raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_unwedge_tracker();
+                    cst_api_fill_to_wire_unwedge_tracker(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_utd_classification> cst_encode_box_autoadd_utd_classification(UtdClassification raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_utd_classification();
+                    cst_api_fill_to_wire_utd_classification(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_utd_queue> cst_encode_box_autoadd_utd_queue(UtdQueue raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_utd_queue();
+                    cst_api_fill_to_wire_utd_queue(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_account> cst_encode_box_autoadd_vodozemac_account(VodozemacAccount raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_account();
+                    cst_api_fill_to_wire_vodozemac_account(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> cst_encode_box_autoadd_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_curve_25519_public_key();
+                    cst_api_fill_to_wire_vodozemac_curve_25519_public_key(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> cst_encode_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_ed_25519_public_key();
+                    cst_api_fill_to_wire_vodozemac_ed_25519_public_key(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_ed_25519_signature> cst_encode_box_autoadd_vodozemac_ed_25519_signature(VodozemacEd25519Signature raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_ed_25519_signature();
+                    cst_api_fill_to_wire_vodozemac_ed_25519_signature(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_established_sas> cst_encode_box_autoadd_vodozemac_established_sas(VodozemacEstablishedSas raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_established_sas();
+                    cst_api_fill_to_wire_vodozemac_established_sas(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_group_session> cst_encode_box_autoadd_vodozemac_group_session(VodozemacGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_group_session();
+                    cst_api_fill_to_wire_vodozemac_group_session(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_inbound_group_session> cst_encode_box_autoadd_vodozemac_inbound_group_session(VodozemacInboundGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_inbound_group_session();
+                    cst_api_fill_to_wire_vodozemac_inbound_group_session(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_megolm_session_config> cst_encode_box_autoadd_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_megolm_session_config();
+                    cst_api_fill_to_wire_vodozemac_megolm_session_config(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_olm_message> cst_encode_box_autoadd_vodozemac_olm_message(VodozemacOlmMessage raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_olm_message();
+                    cst_api_fill_to_wire_vodozemac_olm_message(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_olm_session_config> cst_encode_box_autoadd_vodozemac_olm_session_config(VodozemacOlmSessionConfig raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_olm_session_config();
+                    cst_api_fill_to_wire_vodozemac_olm_session_config(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_pk_decryption> cst_encode_box_autoadd_vodozemac_pk_decryption(VodozemacPkDecryption raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_pk_decryption();
+                    cst_api_fill_to_wire_vodozemac_pk_decryption(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_pk_encryption> cst_encode_box_autoadd_vodozemac_pk_encryption(VodozemacPkEncryption raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_pk_encryption();
+                    cst_api_fill_to_wire_vodozemac_pk_encryption(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_pk_message> cst_encode_box_autoadd_vodozemac_pk_message(VodozemacPkMessage raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_pk_message();
+                    cst_api_fill_to_wire_vodozemac_pk_message(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_vodozemac_session> cst_encode_box_autoadd_vodozemac_session(VodozemacSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_vodozemac_session();
+                    cst_api_fill_to_wire_vodozemac_session(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_withheld_code(WithheldCode raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return wire.cst_new_box_autoadd_withheld_code(cst_encode_withheld_code(raw)); }
+
+@protected ffi.Pointer<wire_cst_list_String> cst_encode_list_String(List<String> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_String(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    ans.ref.ptr[i] = cst_encode_String(raw[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_device_trust> cst_encode_list_device_trust(List<DeviceTrust> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_device_trust(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_device_trust(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_inbound_room_key> cst_encode_list_inbound_room_key(List<InboundRoomKey> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_inbound_room_key(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_inbound_room_key(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_migrated_pickle> cst_encode_list_migrated_pickle(List<MigratedPickle> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_migrated_pickle(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_migrated_pickle(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_migration_failure> cst_encode_list_migration_failure(List<MigrationFailure> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_migration_failure(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_migration_failure(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_loose> cst_encode_list_prim_u_8_loose(List<int> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_prim_u_8_loose(raw.length);
+                ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+                return ans; }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(Uint8List raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_prim_u_8_strict(raw.length);
+                ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+                return ans; }
+
+@protected ffi.Pointer<wire_cst_list_record_string_u_32> cst_encode_list_record_string_u_32(List<(String,int)> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_record_string_u_32(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_record_string_u_32(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_rekey_result> cst_encode_list_rekey_result(List<RekeyResult> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_rekey_result(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_rekey_result(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_retention_result> cst_encode_list_retention_result(List<RetentionResult> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_retention_result(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_retention_result(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_retried_event> cst_encode_list_retried_event(List<RetriedEvent> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_retried_event(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_retried_event(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_room_retention> cst_encode_list_room_retention(List<RoomRetention> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_room_retention(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_room_retention(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_share_recipient> cst_encode_list_share_recipient(List<ShareRecipient> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_share_recipient(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_share_recipient(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_vodozemac_one_time_key> cst_encode_list_vodozemac_one_time_key(List<VodozemacOneTimeKey> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_vodozemac_one_time_key(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_vodozemac_one_time_key(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_vodozemac_session> cst_encode_list_vodozemac_session(List<VodozemacSession> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_vodozemac_session(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_vodozemac_session(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(String? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_String(raw); }
+
+@protected ffi.Pointer<wire_cst_migrated_pickle> cst_encode_opt_box_autoadd_migrated_pickle(MigratedPickle? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_migrated_pickle(raw); }
+
+@protected ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_pickle_format_error(PickleFormatError? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_pickle_format_error(raw); }
+
+@protected ffi.Pointer<wire_cst_retained_room_key> cst_encode_opt_box_autoadd_retained_room_key(RetainedRoomKey? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_retained_room_key(raw); }
+
+@protected ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_rotation_reason(RotationReason? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_rotation_reason(raw); }
+
+@protected ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_trust_link(TrustLink? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_trust_link(raw); }
+
+@protected ffi.Pointer<ffi.Uint32> cst_encode_opt_box_autoadd_u_32(int? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_32(raw); }
+
+@protected ffi.Pointer<ffi.Uint64> cst_encode_opt_box_autoadd_u_64(BigInt? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_64(raw); }
+
+@protected ffi.Pointer<wire_cst_utd_classification> cst_encode_opt_box_autoadd_utd_classification(UtdClassification? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_utd_classification(raw); }
+
+@protected ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> cst_encode_opt_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_vodozemac_ed_25519_public_key(raw); }
+
+@protected ffi.Pointer<wire_cst_vodozemac_session> cst_encode_opt_box_autoadd_vodozemac_session(VodozemacSession? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_vodozemac_session(raw); }
+
+@protected ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_withheld_code(WithheldCode? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_withheld_code(raw); }
+
+@protected ffi.Pointer<wire_cst_list_String> cst_encode_opt_list_String(List<String>? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_list_String(raw); }
+
+@protected ffi.Pointer<wire_cst_list_rekey_result> cst_encode_opt_list_rekey_result(List<RekeyResult>? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_list_rekey_result(raw); }
+
+@protected int cst_encode_u_64(BigInt raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.toSigned(64).toInt(); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_u_8_array_32(U8Array32 raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_prim_u_8_strict(32);
+                        ans.ref.ptr.asTypedList(32).setAll(0, raw);
+                        return ans; }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_u_8_array_64(U8Array64 raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_prim_u_8_strict(64);
+                        ans.ref.ptr.asTypedList(64).setAll(0, raw);
+                        return ans; }
+
+@protected int cst_encode_usize(BigInt raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.toSigned(64).toInt(); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_device_keys(DeviceKeys apiObj, ffi.Pointer<wire_cst_device_keys> wireObj){ cst_api_fill_to_wire_device_keys(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_encrypted_room_key_bundle(EncryptedRoomKeyBundle apiObj, ffi.Pointer<wire_cst_encrypted_room_key_bundle> wireObj){ cst_api_fill_to_wire_encrypted_room_key_bundle(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_fallback_key_tracker(FallbackKeyTracker apiObj, ffi.Pointer<wire_cst_fallback_key_tracker> wireObj){ cst_api_fill_to_wire_fallback_key_tracker(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_inbound_room_key(InboundRoomKey apiObj, ffi.Pointer<wire_cst_inbound_room_key> wireObj){ cst_api_fill_to_wire_inbound_room_key(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_libolm_store(LibolmStore apiObj, ffi.Pointer<wire_cst_libolm_store> wireObj){ cst_api_fill_to_wire_libolm_store(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_megolm_replay_guard(MegolmReplayGuard apiObj, ffi.Pointer<wire_cst_megolm_replay_guard> wireObj){ cst_api_fill_to_wire_megolm_replay_guard(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_migrated_pickle(MigratedPickle apiObj, ffi.Pointer<wire_cst_migrated_pickle> wireObj){ cst_api_fill_to_wire_migrated_pickle(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_migration_report(MigrationReport apiObj, ffi.Pointer<wire_cst_migration_report> wireObj){ cst_api_fill_to_wire_migration_report(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_olm_payload(OlmPayload apiObj, ffi.Pointer<wire_cst_olm_payload> wireObj){ cst_api_fill_to_wire_olm_payload(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_olm_session_store(OlmSessionStore apiObj, ffi.Pointer<wire_cst_olm_session_store> wireObj){ cst_api_fill_to_wire_olm_session_store(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_outbound_group_session(OutboundGroupSession apiObj, ffi.Pointer<wire_cst_outbound_group_session> wireObj){ cst_api_fill_to_wire_outbound_group_session(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_passphrase_kdf_params(PassphraseKdfParams apiObj, ffi.Pointer<wire_cst_passphrase_kdf_params> wireObj){ cst_api_fill_to_wire_passphrase_kdf_params(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_pickle_info(PickleInfo apiObj, ffi.Pointer<wire_cst_pickle_info> wireObj){ cst_api_fill_to_wire_pickle_info(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_retained_room_key(RetainedRoomKey apiObj, ffi.Pointer<wire_cst_retained_room_key> wireObj){ cst_api_fill_to_wire_retained_room_key(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_room_key_bundle_download(RoomKeyBundleDownload apiObj, ffi.Pointer<wire_cst_room_key_bundle_download> wireObj){ cst_api_fill_to_wire_room_key_bundle_download(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_room_key_info(RoomKeyInfo apiObj, ffi.Pointer<wire_cst_room_key_info> wireObj){ cst_api_fill_to_wire_room_key_info(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_share_recipient(ShareRecipient apiObj, ffi.Pointer<wire_cst_share_recipient> wireObj){ cst_api_fill_to_wire_share_recipient(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_unwedge_tracker(UnwedgeTracker apiObj, ffi.Pointer<wire_cst_unwedge_tracker> wireObj){ cst_api_fill_to_wire_unwedge_tracker(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_utd_classification(UtdClassification apiObj, ffi.Pointer<wire_cst_utd_classification> wireObj){ cst_api_fill_to_wire_utd_classification(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_utd_queue(UtdQueue apiObj, ffi.Pointer<wire_cst_utd_queue> wireObj){ cst_api_fill_to_wire_utd_queue(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_account(VodozemacAccount apiObj, ffi.Pointer<wire_cst_vodozemac_account> wireObj){ cst_api_fill_to_wire_vodozemac_account(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey apiObj, ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> wireObj){ cst_api_fill_to_wire_vodozemac_curve_25519_public_key(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey apiObj, ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> wireObj){ cst_api_fill_to_wire_vodozemac_ed_25519_public_key(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_ed_25519_signature(VodozemacEd25519Signature apiObj, ffi.Pointer<wire_cst_vodozemac_ed_25519_signature> wireObj){ cst_api_fill_to_wire_vodozemac_ed_25519_signature(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_established_sas(VodozemacEstablishedSas apiObj, ffi.Pointer<wire_cst_vodozemac_established_sas> wireObj){ cst_api_fill_to_wire_vodozemac_established_sas(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_group_session(VodozemacGroupSession apiObj, ffi.Pointer<wire_cst_vodozemac_group_session> wireObj){ cst_api_fill_to_wire_vodozemac_group_session(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_inbound_group_session(VodozemacInboundGroupSession apiObj, ffi.Pointer<wire_cst_vodozemac_inbound_group_session> wireObj){ cst_api_fill_to_wire_vodozemac_inbound_group_session(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig apiObj, ffi.Pointer<wire_cst_vodozemac_megolm_session_config> wireObj){ cst_api_fill_to_wire_vodozemac_megolm_session_config(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_olm_message(VodozemacOlmMessage apiObj, ffi.Pointer<wire_cst_vodozemac_olm_message> wireObj){ cst_api_fill_to_wire_vodozemac_olm_message(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_olm_session_config(VodozemacOlmSessionConfig apiObj, ffi.Pointer<wire_cst_vodozemac_olm_session_config> wireObj){ cst_api_fill_to_wire_vodozemac_olm_session_config(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_pk_decryption(VodozemacPkDecryption apiObj, ffi.Pointer<wire_cst_vodozemac_pk_decryption> wireObj){ cst_api_fill_to_wire_vodozemac_pk_decryption(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_pk_encryption(VodozemacPkEncryption apiObj, ffi.Pointer<wire_cst_vodozemac_pk_encryption> wireObj){ cst_api_fill_to_wire_vodozemac_pk_encryption(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_pk_message(VodozemacPkMessage apiObj, ffi.Pointer<wire_cst_vodozemac_pk_message> wireObj){ cst_api_fill_to_wire_vodozemac_pk_message(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_vodozemac_session(VodozemacSession apiObj, ffi.Pointer<wire_cst_vodozemac_session> wireObj){ cst_api_fill_to_wire_vodozemac_session(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_claimed_session(ClaimedSession apiObj, wire_cst_claimed_session wireObj){ cst_api_fill_to_wire_vodozemac_session(apiObj.session, wireObj.session);
+wireObj.fallback = cst_encode_bool(apiObj.fallback); }
+
+@protected void cst_api_fill_to_wire_decrypt_result(DecryptResult apiObj, wire_cst_decrypt_result wireObj){ wireObj.field0 = cst_encode_String(apiObj.field0);
+wireObj.field1 = cst_encode_u_32(apiObj.field1); }
+
+@protected void cst_api_fill_to_wire_device_keys(DeviceKeys apiObj, wire_cst_device_keys wireObj){ wireObj.user_id = cst_encode_String(apiObj.userId);
+wireObj.device_id = cst_encode_String(apiObj.deviceId);
+wireObj.algorithms = cst_encode_list_String(apiObj.algorithms);
+cst_api_fill_to_wire_vodozemac_ed_25519_public_key(apiObj.ed25519, wireObj.ed25519);
+cst_api_fill_to_wire_vodozemac_curve_25519_public_key(apiObj.curve25519, wireObj.curve25519);
+wireObj.display_name = cst_encode_opt_String(apiObj.displayName); }
+
+@protected void cst_api_fill_to_wire_device_trust(DeviceTrust apiObj, wire_cst_device_trust wireObj){ wireObj.user_id = cst_encode_String(apiObj.userId);
+wireObj.device_id = cst_encode_String(apiObj.deviceId);
+wireObj.level = cst_encode_device_trust_level(apiObj.level);
+wireObj.failed_link = cst_encode_opt_box_autoadd_trust_link(apiObj.failedLink); }
+
+@protected void cst_api_fill_to_wire_encrypted_room_key_bundle(EncryptedRoomKeyBundle apiObj, wire_cst_encrypted_room_key_bundle wireObj){ wireObj.room_id = cst_encode_String(apiObj.roomId);
+wireObj.ciphertext = cst_encode_list_prim_u_8_strict(apiObj.ciphertext);
+wireObj.file = cst_encode_String(apiObj.file); }
+
+@protected void cst_api_fill_to_wire_extended_decrypt_result(ExtendedDecryptResult apiObj, wire_cst_extended_decrypt_result wireObj){ wireObj.plaintext = cst_encode_String(apiObj.plaintext);
+wireObj.message_index = cst_encode_u_32(apiObj.messageIndex);
+cst_api_fill_to_wire_vodozemac_ed_25519_public_key(apiObj.signingKey, wireObj.signing_key);
+wireObj.key_source = cst_encode_session_key_source(apiObj.keySource); }
+
+@protected void cst_api_fill_to_wire_fallback_key_tracker(FallbackKeyTracker apiObj, wire_cst_fallback_key_tracker wireObj){ wireObj.state = cst_encode_RustOpaque_RwLockFallbackKeyState(apiObj.state); }
+
+@protected void cst_api_fill_to_wire_imported_room_key_bundle(ImportedRoomKeyBundle apiObj, wire_cst_imported_room_key_bundle wireObj){ wireObj.room_keys = cst_encode_list_inbound_room_key(apiObj.roomKeys);
+wireObj.skipped = cst_encode_u_32(apiObj.skipped); }
+
+@protected void cst_api_fill_to_wire_inbound_room_key(InboundRoomKey apiObj, wire_cst_inbound_room_key wireObj){ wireObj.session = cst_encode_RustOpaque_RwLockInboundGroupSession(apiObj.session);
+wireObj.source = cst_encode_RustOpaque_RwLockSessionKeySource(apiObj.source);
+wireObj.key_info = cst_encode_RustOpaque_RwLockRoomKeyInfo(apiObj.keyInfo); }
+
+@protected void cst_api_fill_to_wire_key_maintenance_plan(KeyMaintenancePlan apiObj, wire_cst_key_maintenance_plan wireObj){ wireObj.generate_one_time_keys = cst_encode_usize(apiObj.generateOneTimeKeys);
+wireObj.rotate_fallback_key = cst_encode_bool(apiObj.rotateFallbackKey);
+wireObj.forget_old_fallback_key = cst_encode_bool(apiObj.forgetOldFallbackKey); }
+
+@protected void cst_api_fill_to_wire_libolm_store(LibolmStore apiObj, wire_cst_libolm_store wireObj){ wireObj.account = cst_encode_opt_String(apiObj.account);
+wireObj.sessions = cst_encode_list_String(apiObj.sessions);
+wireObj.inbound_group_sessions = cst_encode_list_String(apiObj.inboundGroupSessions);
+wireObj.pk_decryption = cst_encode_opt_String(apiObj.pkDecryption); }
+
+@protected void cst_api_fill_to_wire_megolm_replay_guard(MegolmReplayGuard apiObj, wire_cst_megolm_replay_guard wireObj){ wireObj.state = cst_encode_RustOpaque_RwLockReplayState(apiObj.state); }
+
+@protected void cst_api_fill_to_wire_migrated_pickle(MigratedPickle apiObj, wire_cst_migrated_pickle wireObj){ wireObj.index = cst_encode_u_32(apiObj.index);
+wireObj.id_before = cst_encode_String(apiObj.idBefore);
+wireObj.id_after = cst_encode_String(apiObj.idAfter);
+wireObj.pickle = cst_encode_String(apiObj.pickle); }
+
+@protected void cst_api_fill_to_wire_migration_failure(MigrationFailure apiObj, wire_cst_migration_failure wireObj){ wireObj.object = cst_encode_pickle_object(apiObj.object);
+wireObj.index = cst_encode_u_32(apiObj.index);
+wireObj.id_before = cst_encode_opt_String(apiObj.idBefore);
+wireObj.id_after = cst_encode_opt_String(apiObj.idAfter);
+wireObj.error = cst_encode_migration_error(apiObj.error); }
+
+@protected void cst_api_fill_to_wire_migration_report(MigrationReport apiObj, wire_cst_migration_report wireObj){ wireObj.account = cst_encode_opt_box_autoadd_migrated_pickle(apiObj.account);
+wireObj.sessions = cst_encode_list_migrated_pickle(apiObj.sessions);
+wireObj.inbound_group_sessions = cst_encode_list_migrated_pickle(apiObj.inboundGroupSessions);
+wireObj.pk_decryption = cst_encode_opt_box_autoadd_migrated_pickle(apiObj.pkDecryption);
+wireObj.failures = cst_encode_list_migration_failure(apiObj.failures); }
+
+@protected void cst_api_fill_to_wire_olm_payload(OlmPayload apiObj, wire_cst_olm_payload wireObj){ wireObj.event_type = cst_encode_String(apiObj.eventType);
+wireObj.content = cst_encode_String(apiObj.content);
+wireObj.sender = cst_encode_String(apiObj.sender);
+wireObj.sender_device = cst_encode_opt_String(apiObj.senderDevice);
+cst_api_fill_to_wire_vodozemac_ed_25519_public_key(apiObj.senderClaimedEd25519, wireObj.sender_claimed_ed25519); }
+
+@protected void cst_api_fill_to_wire_olm_session_store(OlmSessionStore apiObj, wire_cst_olm_session_store wireObj){ wireObj.state = cst_encode_RustOpaque_RwLockSessionStoreState(apiObj.state); }
+
+@protected void cst_api_fill_to_wire_outbound_group_session(OutboundGroupSession apiObj, wire_cst_outbound_group_session wireObj){ wireObj.session = cst_encode_RustOpaque_RwLockGroupSession(apiObj.session);
+wireObj.rotation = cst_encode_RustOpaque_RwLockRotationState(apiObj.rotation);
+wireObj.shares = cst_encode_RustOpaque_RwLockShareState(apiObj.shares); }
+
+@protected void cst_api_fill_to_wire_passphrase_kdf_params(PassphraseKdfParams apiObj, wire_cst_passphrase_kdf_params wireObj){ wireObj.kdf = cst_encode_passphrase_kdf(apiObj.kdf);
+wireObj.iterations = cst_encode_u_32(apiObj.iterations); }
+
+@protected void cst_api_fill_to_wire_pickle_info(PickleInfo apiObj, wire_cst_pickle_info wireObj){ wireObj.format = cst_encode_pickle_format(apiObj.format);
+wireObj.object = cst_encode_pickle_object(apiObj.object);
+wireObj.version = cst_encode_opt_box_autoadd_u_32(apiObj.version); }
+
+@protected void cst_api_fill_to_wire_record_string_string_string((String,String,String) apiObj, wire_cst_record_string_string_string wireObj){ wireObj.field0 = cst_encode_String(apiObj.$1);
+wireObj.field1 = cst_encode_String(apiObj.$2);
+wireObj.field2 = cst_encode_String(apiObj.$3); }
+
+@protected void cst_api_fill_to_wire_record_string_u_32((String,int) apiObj, wire_cst_record_string_u_32 wireObj){ wireObj.field0 = cst_encode_String(apiObj.$1);
+wireObj.field1 = cst_encode_u_32(apiObj.$2); }
+
+@protected void cst_api_fill_to_wire_rekey_progress(RekeyProgress apiObj, wire_cst_rekey_progress wireObj){ wireObj.done = cst_encode_u_32(apiObj.done);
+wireObj.results = cst_encode_opt_list_rekey_result(apiObj.results); }
+
+@protected void cst_api_fill_to_wire_rekey_result(RekeyResult apiObj, wire_cst_rekey_result wireObj){ wireObj.pickle = cst_encode_opt_String(apiObj.pickle);
+wireObj.error = cst_encode_opt_box_autoadd_pickle_format_error(apiObj.error); }
+
+@protected void cst_api_fill_to_wire_retained_room_key(RetainedRoomKey apiObj, wire_cst_retained_room_key wireObj){ wireObj.session_id = cst_encode_String(apiObj.sessionId);
+wireObj.pickle = cst_encode_String(apiObj.pickle);
+wireObj.first_known_index = cst_encode_u_32(apiObj.firstKnownIndex);
+wireObj.discarded = cst_encode_bool(apiObj.discarded); }
+
+@protected void cst_api_fill_to_wire_retention_result(RetentionResult apiObj, wire_cst_retention_result wireObj){ wireObj.room_key = cst_encode_opt_box_autoadd_retained_room_key(apiObj.roomKey);
+wireObj.error = cst_encode_opt_box_autoadd_pickle_format_error(apiObj.error); }
+
+@protected void cst_api_fill_to_wire_retried_event(RetriedEvent apiObj, wire_cst_retried_event wireObj){ wireObj.event_id = cst_encode_String(apiObj.eventId);
+cst_api_fill_to_wire_room_key_decryption_result(apiObj.result, wireObj.result); }
+
+@protected void cst_api_fill_to_wire_room_event_decryption_result(RoomEventDecryptionResult apiObj, wire_cst_room_event_decryption_result wireObj){ wireObj.event_type = cst_encode_String(apiObj.eventType);
+wireObj.content = cst_encode_String(apiObj.content);
+wireObj.message_index = cst_encode_u_32(apiObj.messageIndex); }
+
+@protected void cst_api_fill_to_wire_room_key_bundle_download(RoomKeyBundleDownload apiObj, wire_cst_room_key_bundle_download wireObj){ wireObj.room_id = cst_encode_String(apiObj.roomId);
+wireObj.url = cst_encode_String(apiObj.url);
+wireObj.file = cst_encode_String(apiObj.file);
+wireObj.sender_key = cst_encode_String(apiObj.senderKey); }
+
+@protected void cst_api_fill_to_wire_room_key_decryption_result(RoomKeyDecryptionResult apiObj, wire_cst_room_key_decryption_result wireObj){ wireObj.event_type = cst_encode_String(apiObj.eventType);
+wireObj.content = cst_encode_String(apiObj.content);
+wireObj.message_index = cst_encode_u_32(apiObj.messageIndex);
+wireObj.authenticity = cst_encode_key_authenticity(apiObj.authenticity);
+wireObj.sender_key = cst_encode_String(apiObj.senderKey);
+wireObj.sender_claimed_ed25519 = cst_encode_String(apiObj.senderClaimedEd25519); }
+
+@protected void cst_api_fill_to_wire_room_key_info(RoomKeyInfo apiObj, wire_cst_room_key_info wireObj){ wireObj.algorithm = cst_encode_String(apiObj.algorithm);
+wireObj.room_id = cst_encode_String(apiObj.roomId);
+wireObj.sender_key = cst_encode_String(apiObj.senderKey);
+wireObj.sender_claimed_ed25519 = cst_encode_String(apiObj.senderClaimedEd25519);
+wireObj.forwarding_curve25519_key_chain = cst_encode_list_String(apiObj.forwardingCurve25519KeyChain);
+wireObj.origin = cst_encode_room_key_origin(apiObj.origin);
+wireObj.shared_history = cst_encode_bool(apiObj.sharedHistory);
+wireObj.backed_up = cst_encode_bool(apiObj.backedUp); }
+
+@protected void cst_api_fill_to_wire_room_retention(RoomRetention apiObj, wire_cst_room_retention wireObj){ wireObj.room_id = cst_encode_String(apiObj.roomId);
+wireObj.max_age_ms = cst_encode_u_64(apiObj.maxAgeMs); }
+
+@protected void cst_api_fill_to_wire_share_recipient(ShareRecipient apiObj, wire_cst_share_recipient wireObj){ wireObj.user_id = cst_encode_String(apiObj.userId);
+wireObj.device_id = cst_encode_String(apiObj.deviceId);
+wireObj.curve25519_key = cst_encode_String(apiObj.curve25519Key); }
+
+@protected void cst_api_fill_to_wire_to_device_decryption_result(ToDeviceDecryptionResult apiObj, wire_cst_to_device_decryption_result wireObj){ wireObj.plaintext = cst_encode_String(apiObj.plaintext);
+cst_api_fill_to_wire_vodozemac_curve_25519_public_key(apiObj.senderKey, wireObj.sender_key);
+wireObj.session_id = cst_encode_String(apiObj.sessionId);
+wireObj.new_session = cst_encode_opt_box_autoadd_vodozemac_session(apiObj.newSession); }
+
+@protected void cst_api_fill_to_wire_unpickled_account(UnpickledAccount apiObj, wire_cst_unpickled_account wireObj){ cst_api_fill_to_wire_vodozemac_account(apiObj.account, wireObj.account);
+wireObj.needs_repickle = cst_encode_bool(apiObj.needsRepickle); }
+
+@protected void cst_api_fill_to_wire_unpickled_group_session(UnpickledGroupSession apiObj, wire_cst_unpickled_group_session wireObj){ cst_api_fill_to_wire_vodozemac_group_session(apiObj.session, wireObj.session);
+wireObj.needs_repickle = cst_encode_bool(apiObj.needsRepickle); }
+
+@protected void cst_api_fill_to_wire_unpickled_inbound_group_session(UnpickledInboundGroupSession apiObj, wire_cst_unpickled_inbound_group_session wireObj){ cst_api_fill_to_wire_vodozemac_inbound_group_session(apiObj.session, wireObj.session);
+wireObj.needs_repickle = cst_encode_bool(apiObj.needsRepickle); }
+
+@protected void cst_api_fill_to_wire_unpickled_pk_decryption(UnpickledPkDecryption apiObj, wire_cst_unpickled_pk_decryption wireObj){ cst_api_fill_to_wire_vodozemac_pk_decryption(apiObj.pkDecryption, wireObj.pk_decryption);
+wireObj.needs_repickle = cst_encode_bool(apiObj.needsRepickle); }
+
+@protected void cst_api_fill_to_wire_unpickled_session(UnpickledSession apiObj, wire_cst_unpickled_session wireObj){ cst_api_fill_to_wire_vodozemac_session(apiObj.session, wireObj.session);
+wireObj.needs_repickle = cst_encode_bool(apiObj.needsRepickle); }
+
+@protected void cst_api_fill_to_wire_unwedge_result(UnwedgeResult apiObj, wire_cst_unwedge_result wireObj){ cst_api_fill_to_wire_vodozemac_session(apiObj.session, wireObj.session);
+wireObj.encrypted_content = cst_encode_String(apiObj.encryptedContent); }
+
+@protected void cst_api_fill_to_wire_unwedge_tracker(UnwedgeTracker apiObj, wire_cst_unwedge_tracker wireObj){ wireObj.state = cst_encode_RustOpaque_RwLockUnwedgeState(apiObj.state); }
+
+@protected void cst_api_fill_to_wire_utd_classification(UtdClassification apiObj, wire_cst_utd_classification wireObj){ wireObj.cause = cst_encode_utd_cause(apiObj.cause);
+wireObj.withheld_code = cst_encode_opt_box_autoadd_withheld_code(apiObj.withheldCode); }
+
+@protected void cst_api_fill_to_wire_utd_queue(UtdQueue apiObj, wire_cst_utd_queue wireObj){ wireObj.state = cst_encode_RustOpaque_RwLockUtdQueueState(apiObj.state); }
+
+@protected void cst_api_fill_to_wire_vodozemac_account(VodozemacAccount apiObj, wire_cst_vodozemac_account wireObj){ wireObj.account = cst_encode_RustOpaque_stdsyncRwLockAccount(apiObj.account); }
+
+@protected void cst_api_fill_to_wire_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey apiObj, wire_cst_vodozemac_curve_25519_public_key wireObj){ wireObj.key = cst_encode_RustOpaque_Curve25519PublicKey(apiObj.key); }
+
+@protected void cst_api_fill_to_wire_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey apiObj, wire_cst_vodozemac_ed_25519_public_key wireObj){ wireObj.key = cst_encode_RustOpaque_Ed25519PublicKey(apiObj.key); }
+
+@protected void cst_api_fill_to_wire_vodozemac_ed_25519_signature(VodozemacEd25519Signature apiObj, wire_cst_vodozemac_ed_25519_signature wireObj){ wireObj.signature = cst_encode_RustOpaque_Ed25519Signature(apiObj.signature); }
+
+@protected void cst_api_fill_to_wire_vodozemac_established_sas(VodozemacEstablishedSas apiObj, wire_cst_vodozemac_established_sas wireObj){ wireObj.established_sas = cst_encode_RustOpaque_EstablishedSas(apiObj.establishedSas); }
+
+@protected void cst_api_fill_to_wire_vodozemac_group_session(VodozemacGroupSession apiObj, wire_cst_vodozemac_group_session wireObj){ wireObj.session = cst_encode_RustOpaque_RwLockGroupSession(apiObj.session); }
+
+@protected void cst_api_fill_to_wire_vodozemac_identity_keys(VodozemacIdentityKeys apiObj, wire_cst_vodozemac_identity_keys wireObj){ cst_api_fill_to_wire_vodozemac_ed_25519_public_key(apiObj.ed25519, wireObj.ed25519);
+cst_api_fill_to_wire_vodozemac_curve_25519_public_key(apiObj.curve25519, wireObj.curve25519); }
+
+@protected void cst_api_fill_to_wire_vodozemac_inbound_group_session(VodozemacInboundGroupSession apiObj, wire_cst_vodozemac_inbound_group_session wireObj){ wireObj.session = cst_encode_RustOpaque_RwLockInboundGroupSession(apiObj.session);
+wireObj.source = cst_encode_RustOpaque_RwLockSessionKeySource(apiObj.source); }
+
+@protected void cst_api_fill_to_wire_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig apiObj, wire_cst_vodozemac_megolm_session_config wireObj){ wireObj.config = cst_encode_RustOpaque_MegolmSessionConfig(apiObj.config); }
+
+@protected void cst_api_fill_to_wire_vodozemac_olm_message(VodozemacOlmMessage apiObj, wire_cst_vodozemac_olm_message wireObj){ wireObj.msg = cst_encode_RustOpaque_OlmMessage(apiObj.msg); }
+
+@protected void cst_api_fill_to_wire_vodozemac_olm_session_config(VodozemacOlmSessionConfig apiObj, wire_cst_vodozemac_olm_session_config wireObj){ wireObj.config = cst_encode_RustOpaque_OlmSessionConfig(apiObj.config); }
+
+@protected void cst_api_fill_to_wire_vodozemac_olm_session_creation_result(VodozemacOlmSessionCreationResult apiObj, wire_cst_vodozemac_olm_session_creation_result wireObj){ cst_api_fill_to_wire_vodozemac_session(apiObj.session, wireObj.session);
+wireObj.plaintext = cst_encode_String(apiObj.plaintext); }
+
+@protected void cst_api_fill_to_wire_vodozemac_one_time_key(VodozemacOneTimeKey apiObj, wire_cst_vodozemac_one_time_key wireObj){ wireObj.keyid = cst_encode_String(apiObj.keyid);
+cst_api_fill_to_wire_vodozemac_curve_25519_public_key(apiObj.key, wireObj.key); }
+
+@protected void cst_api_fill_to_wire_vodozemac_pk_decryption(VodozemacPkDecryption apiObj, wire_cst_vodozemac_pk_decryption wireObj){ wireObj.pk_decryption = cst_encode_RustOpaque_PkDecryption(apiObj.pkDecryption); }
+
+@protected void cst_api_fill_to_wire_vodozemac_pk_encryption(VodozemacPkEncryption apiObj, wire_cst_vodozemac_pk_encryption wireObj){ wireObj.pk_encryption = cst_encode_RustOpaque_PkEncryption(apiObj.pkEncryption); }
+
+@protected void cst_api_fill_to_wire_vodozemac_pk_message(VodozemacPkMessage apiObj, wire_cst_vodozemac_pk_message wireObj){ wireObj.ciphertext = cst_encode_list_prim_u_8_strict(apiObj.ciphertext);
+wireObj.mac = cst_encode_list_prim_u_8_strict(apiObj.mac);
+cst_api_fill_to_wire_vodozemac_curve_25519_public_key(apiObj.ephemeralKey, wireObj.ephemeral_key); }
+
+@protected void cst_api_fill_to_wire_vodozemac_session(VodozemacSession apiObj, wire_cst_vodozemac_session wireObj){ wireObj.session = cst_encode_RustOpaque_RwLockSession(apiObj.session); }
+
+@protected int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity raw);
+
+@protected int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw);
+
+@protected int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw);
+
+@protected int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity raw);
+
+@protected int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw);
+
+@protected int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw);
+
+@protected int cst_encode_RustOpaque_Curve25519PublicKey(Curve25519PublicKey raw);
+
+@protected int cst_encode_RustOpaque_Ed25519PublicKey(Ed25519PublicKey raw);
+
+@protected int cst_encode_RustOpaque_Ed25519Signature(Ed25519Signature raw);
+
+@protected int cst_encode_RustOpaque_EstablishedSas(EstablishedSas raw);
+
+@protected int cst_encode_RustOpaque_MegolmSessionConfig(MegolmSessionConfig raw);
+
+@protected int cst_encode_RustOpaque_OlmMessage(OlmMessage raw);
+
+@protected int cst_encode_RustOpaque_OlmSessionConfig(OlmSessionConfig raw);
+
+@protected int cst_encode_RustOpaque_PkDecryption(PkDecryption raw);
+
+@protected int cst_encode_RustOpaque_PkEncryption(PkEncryption raw);
+
+@protected int cst_encode_RustOpaque_RwLockFallbackKeyState(RwLockFallbackKeyState raw);
+
+@protected int cst_encode_RustOpaque_RwLockGroupSession(RwLockGroupSession raw);
+
+@protected int cst_encode_RustOpaque_RwLockInboundGroupSession(RwLockInboundGroupSession raw);
+
+@protected int cst_encode_RustOpaque_RwLockReplayState(RwLockReplayState raw);
+
+@protected int cst_encode_RustOpaque_RwLockRoomKeyInfo(RwLockRoomKeyInfo raw);
+
+@protected int cst_encode_RustOpaque_RwLockRotationState(RwLockRotationState raw);
+
+@protected int cst_encode_RustOpaque_RwLockSession(RwLockSession raw);
+
+@protected int cst_encode_RustOpaque_RwLockSessionKeySource(RwLockSessionKeySource raw);
+
+@protected int cst_encode_RustOpaque_RwLockSessionStoreState(RwLockSessionStoreState raw);
+
+@protected int cst_encode_RustOpaque_RwLockShareState(RwLockShareState raw);
+
+@protected int cst_encode_RustOpaque_RwLockUnwedgeState(RwLockUnwedgeState raw);
+
+@protected int cst_encode_RustOpaque_RwLockUtdQueueState(RwLockUtdQueueState raw);
+
+@protected int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity raw);
+
+@protected int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw);
+
+@protected int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw);
+
+@protected int cst_encode_RustOpaque_stdsyncRwLockAccount(RwLockAccount raw);
+
+@protected bool cst_encode_bool(bool raw);
+
+@protected int cst_encode_device_keys_error(DeviceKeysError raw);
+
+@protected int cst_encode_device_trust_level(DeviceTrustLevel raw);
+
+@protected int cst_encode_i_32(int raw);
+
+@protected int cst_encode_key_authenticity(KeyAuthenticity raw);
+
+@protected int cst_encode_key_claim_error(KeyClaimError raw);
+
+@protected int cst_encode_migration_error(MigrationError raw);
+
+@protected int cst_encode_olm_payload_error(OlmPayloadError raw);
+
+@protected int cst_encode_passphrase_kdf(PassphraseKdf raw);
+
+@protected int cst_encode_passphrase_pickle_error(PassphrasePickleError raw);
+
+@protected int cst_encode_pickle_format(PickleFormat raw);
+
+@protected int cst_encode_pickle_format_error(PickleFormatError raw);
+
+@protected int cst_encode_pickle_object(PickleObject raw);
+
+@protected int cst_encode_room_event_error(RoomEventError raw);
+
+@protected int cst_encode_room_key_bundle_error(RoomKeyBundleError raw);
+
+@protected int cst_encode_room_key_error(RoomKeyError raw);
+
+@protected int cst_encode_room_key_origin(RoomKeyOrigin raw);
+
+@protected int cst_encode_rotation_reason(RotationReason raw);
+
+@protected int cst_encode_session_key_source(SessionKeySource raw);
+
+@protected int cst_encode_to_device_decryption_error(ToDeviceDecryptionError raw);
+
+@protected int cst_encode_trust_link(TrustLink raw);
+
+@protected int cst_encode_u_32(int raw);
+
+@protected int cst_encode_u_8(int raw);
+
+@protected void cst_encode_unit(void raw);
+
+@protected int cst_encode_unwedge_error(UnwedgeError raw);
+
+@protected int cst_encode_utd_cause(UtdCause raw);
+
+@protected int cst_encode_withheld_code(WithheldCode raw);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_u_32_None(Map<String, int> self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_Curve25519PublicKey(Curve25519PublicKey self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_Ed25519PublicKey(Ed25519PublicKey self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_Ed25519Signature(Ed25519Signature self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_EstablishedSas(EstablishedSas self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_MegolmSessionConfig(MegolmSessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_OlmMessage(OlmMessage self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_OlmSessionConfig(OlmSessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_PkDecryption(PkDecryption self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_PkEncryption(PkEncryption self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockFallbackKeyState(RwLockFallbackKeyState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockGroupSession(RwLockGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockInboundGroupSession(RwLockInboundGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockReplayState(RwLockReplayState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockRoomKeyInfo(RwLockRoomKeyInfo self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockRotationState(RwLockRotationState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockSession(RwLockSession self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockSessionKeySource(RwLockSessionKeySource self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockSessionStoreState(RwLockSessionStoreState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockShareState(RwLockShareState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockUnwedgeState(RwLockUnwedgeState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockUtdQueueState(RwLockUtdQueueState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_stdsyncRwLockAccount(RwLockAccount self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_rekey_progress_Dco(RustStreamSink<RekeyProgress> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_device_keys(DeviceKeys self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_encrypted_room_key_bundle(EncryptedRoomKeyBundle self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_fallback_key_tracker(FallbackKeyTracker self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_inbound_room_key(InboundRoomKey self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_libolm_store(LibolmStore self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_megolm_replay_guard(MegolmReplayGuard self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_migrated_pickle(MigratedPickle self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_migration_report(MigrationReport self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_olm_payload(OlmPayload self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_olm_session_store(OlmSessionStore self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_outbound_group_session(OutboundGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_passphrase_kdf_params(PassphraseKdfParams self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_pickle_format_error(PickleFormatError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_pickle_info(PickleInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_retained_room_key(RetainedRoomKey self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_room_key_bundle_download(RoomKeyBundleDownload self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_room_key_info(RoomKeyInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_rotation_reason(RotationReason self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_share_recipient(ShareRecipient self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_trust_link(TrustLink self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_unwedge_tracker(UnwedgeTracker self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_utd_classification(UtdClassification self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_utd_queue(UtdQueue self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_account(VodozemacAccount self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_ed_25519_signature(VodozemacEd25519Signature self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_established_sas(VodozemacEstablishedSas self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_group_session(VodozemacGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_inbound_group_session(VodozemacInboundGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_olm_message(VodozemacOlmMessage self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_olm_session_config(VodozemacOlmSessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_pk_decryption(VodozemacPkDecryption self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_pk_encryption(VodozemacPkEncryption self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_pk_message(VodozemacPkMessage self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_session(VodozemacSession self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_withheld_code(WithheldCode self, SseSerializer serializer);
+
+@protected void sse_encode_claimed_session(ClaimedSession self, SseSerializer serializer);
+
+@protected void sse_encode_decrypt_result(DecryptResult self, SseSerializer serializer);
+
+@protected void sse_encode_device_keys(DeviceKeys self, SseSerializer serializer);
+
+@protected void sse_encode_device_keys_error(DeviceKeysError self, SseSerializer serializer);
+
+@protected void sse_encode_device_trust(DeviceTrust self, SseSerializer serializer);
+
+@protected void sse_encode_device_trust_level(DeviceTrustLevel self, SseSerializer serializer);
+
+@protected void sse_encode_encrypted_room_key_bundle(EncryptedRoomKeyBundle self, SseSerializer serializer);
+
+@protected void sse_encode_extended_decrypt_result(ExtendedDecryptResult self, SseSerializer serializer);
+
+@protected void sse_encode_fallback_key_tracker(FallbackKeyTracker self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_imported_room_key_bundle(ImportedRoomKeyBundle self, SseSerializer serializer);
+
+@protected void sse_encode_inbound_room_key(InboundRoomKey self, SseSerializer serializer);
+
+@protected void sse_encode_key_authenticity(KeyAuthenticity self, SseSerializer serializer);
+
+@protected void sse_encode_key_claim_error(KeyClaimError self, SseSerializer serializer);
+
+@protected void sse_encode_key_maintenance_plan(KeyMaintenancePlan self, SseSerializer serializer);
+
+@protected void sse_encode_libolm_store(LibolmStore self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_device_trust(List<DeviceTrust> self, SseSerializer serializer);
+
+@protected void sse_encode_list_inbound_room_key(List<InboundRoomKey> self, SseSerializer serializer);
+
+@protected void sse_encode_list_migrated_pickle(List<MigratedPickle> self, SseSerializer serializer);
+
+@protected void sse_encode_list_migration_failure(List<MigrationFailure> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_u_32(List<(String,int)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_rekey_result(List<RekeyResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_retention_result(List<RetentionResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_retried_event(List<RetriedEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_list_room_retention(List<RoomRetention> self, SseSerializer serializer);
+
+@protected void sse_encode_list_share_recipient(List<ShareRecipient> self, SseSerializer serializer);
+
+@protected void sse_encode_list_vodozemac_one_time_key(List<VodozemacOneTimeKey> self, SseSerializer serializer);
+
+@protected void sse_encode_list_vodozemac_session(List<VodozemacSession> self, SseSerializer serializer);
+
+@protected void sse_encode_megolm_replay_guard(MegolmReplayGuard self, SseSerializer serializer);
+
+@protected void sse_encode_migrated_pickle(MigratedPickle self, SseSerializer serializer);
+
+@protected void sse_encode_migration_error(MigrationError self, SseSerializer serializer);
+
+@protected void sse_encode_migration_failure(MigrationFailure self, SseSerializer serializer);
+
+@protected void sse_encode_migration_report(MigrationReport self, SseSerializer serializer);
+
+@protected void sse_encode_olm_payload(OlmPayload self, SseSerializer serializer);
+
+@protected void sse_encode_olm_payload_error(OlmPayloadError self, SseSerializer serializer);
+
+@protected void sse_encode_olm_session_store(OlmSessionStore self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_migrated_pickle(MigratedPickle? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_pickle_format_error(PickleFormatError? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_retained_room_key(RetainedRoomKey? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_rotation_reason(RotationReason? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_trust_link(TrustLink? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_utd_classification(UtdClassification? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_vodozemac_session(VodozemacSession? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_withheld_code(WithheldCode? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_rekey_result(List<RekeyResult>? self, SseSerializer serializer);
+
+@protected void sse_encode_outbound_group_session(OutboundGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_passphrase_kdf(PassphraseKdf self, SseSerializer serializer);
+
+@protected void sse_encode_passphrase_kdf_params(PassphraseKdfParams self, SseSerializer serializer);
+
+@protected void sse_encode_passphrase_pickle_error(PassphrasePickleError self, SseSerializer serializer);
+
+@protected void sse_encode_pickle_format(PickleFormat self, SseSerializer serializer);
+
+@protected void sse_encode_pickle_format_error(PickleFormatError self, SseSerializer serializer);
+
+@protected void sse_encode_pickle_info(PickleInfo self, SseSerializer serializer);
+
+@protected void sse_encode_pickle_object(PickleObject self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_string_string((String,String,String) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_u_32((String,int) self, SseSerializer serializer);
+
+@protected void sse_encode_rekey_progress(RekeyProgress self, SseSerializer serializer);
+
+@protected void sse_encode_rekey_result(RekeyResult self, SseSerializer serializer);
+
+@protected void sse_encode_retained_room_key(RetainedRoomKey self, SseSerializer serializer);
+
+@protected void sse_encode_retention_result(RetentionResult self, SseSerializer serializer);
+
+@protected void sse_encode_retried_event(RetriedEvent self, SseSerializer serializer);
+
+@protected void sse_encode_room_event_decryption_result(RoomEventDecryptionResult self, SseSerializer serializer);
+
+@protected void sse_encode_room_event_error(RoomEventError self, SseSerializer serializer);
+
+@protected void sse_encode_room_key_bundle_download(RoomKeyBundleDownload self, SseSerializer serializer);
+
+@protected void sse_encode_room_key_bundle_error(RoomKeyBundleError self, SseSerializer serializer);
+
+@protected void sse_encode_room_key_decryption_result(RoomKeyDecryptionResult self, SseSerializer serializer);
+
+@protected void sse_encode_room_key_error(RoomKeyError self, SseSerializer serializer);
+
+@protected void sse_encode_room_key_info(RoomKeyInfo self, SseSerializer serializer);
+
+@protected void sse_encode_room_key_origin(RoomKeyOrigin self, SseSerializer serializer);
+
+@protected void sse_encode_room_retention(RoomRetention self, SseSerializer serializer);
+
+@protected void sse_encode_rotation_reason(RotationReason self, SseSerializer serializer);
+
+@protected void sse_encode_session_key_source(SessionKeySource self, SseSerializer serializer);
+
+@protected void sse_encode_share_recipient(ShareRecipient self, SseSerializer serializer);
+
+@protected void sse_encode_to_device_decryption_error(ToDeviceDecryptionError self, SseSerializer serializer);
+
+@protected void sse_encode_to_device_decryption_result(ToDeviceDecryptionResult self, SseSerializer serializer);
+
+@protected void sse_encode_trust_link(TrustLink self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_8_array_32(U8Array32 self, SseSerializer serializer);
+
+@protected void sse_encode_u_8_array_64(U8Array64 self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_unpickled_account(UnpickledAccount self, SseSerializer serializer);
+
+@protected void sse_encode_unpickled_group_session(UnpickledGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_unpickled_inbound_group_session(UnpickledInboundGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_unpickled_pk_decryption(UnpickledPkDecryption self, SseSerializer serializer);
+
+@protected void sse_encode_unpickled_session(UnpickledSession self, SseSerializer serializer);
+
+@protected void sse_encode_unwedge_error(UnwedgeError self, SseSerializer serializer);
+
+@protected void sse_encode_unwedge_result(UnwedgeResult self, SseSerializer serializer);
+
+@protected void sse_encode_unwedge_tracker(UnwedgeTracker self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_utd_cause(UtdCause self, SseSerializer serializer);
+
+@protected void sse_encode_utd_classification(UtdClassification self, SseSerializer serializer);
+
+@protected void sse_encode_utd_queue(UtdQueue self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_account(VodozemacAccount self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_ed_25519_signature(VodozemacEd25519Signature self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_established_sas(VodozemacEstablishedSas self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_group_session(VodozemacGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_identity_keys(VodozemacIdentityKeys self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_inbound_group_session(VodozemacInboundGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_olm_message(VodozemacOlmMessage self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_olm_session_config(VodozemacOlmSessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_olm_session_creation_result(VodozemacOlmSessionCreationResult self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_one_time_key(VodozemacOneTimeKey self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_pk_decryption(VodozemacPkDecryption self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_pk_encryption(VodozemacPkEncryption self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_pk_message(VodozemacPkMessage self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_session(VodozemacSession self, SseSerializer serializer);
+
+@protected void sse_encode_withheld_code(WithheldCode self, SseSerializer serializer);
+                }
+                
+
+
+// Section: wire_class
+
+// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+// AUTO GENERATED FILE, DO NOT EDIT.
+//
+// Generated by `package:ffigen`.
+// ignore_for_file: type=lint
+
+/// generated by flutter_rust_bridge
+class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+  /// Holds the symbol lookup function.
+  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) _lookup;
+
+  /// The symbols are looked up in [dynamicLibrary].
+  RustLibWire(ffi.DynamicLibrary dynamicLibrary) : _lookup = dynamicLibrary.lookup;
+
+  /// The symbols are looked up with [lookup].
+  RustLibWire.fromLookup(ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) lookup)
+      : _lookup = lookup;
+
+  void store_dart_post_cobject(
+    DartPostCObjectFnType ptr,
+  ) {
+    return _store_dart_post_cobject(
+      ptr,
+    );
+  }
+
+  late final _store_dart_post_cobjectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>('store_dart_post_cobject');
+  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr.asFunction<void Function(DartPostCObjectFnType)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__cross_signing__CrossSigningIdentity_device_signing_upload(
+    int that,
+  ) {
+    return _wire__crate__bindings__cross_signing__CrossSigningIdentity_device_signing_upload(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_device_signing_uploadPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_vodozemac_wire__crate__bindings__cross_signing__CrossSigningIdentity_device_signing_upload');
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_device_signing_upload =
+      _wire__crate__bindings__cross_signing__CrossSigningIdentity_device_signing_uploadPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__cross_signing__CrossSigningIdentity_from_secret_keys(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> user_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> master_key,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> self_signing_key,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> user_signing_key,
+  ) {
+    return _wire__crate__bindings__cross_signing__CrossSigningIdentity_from_secret_keys(
+      user_id,
+      master_key,
+      self_signing_key,
+      user_signing_key,
+    );
+  }
+
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_from_secret_keysPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__cross_signing__CrossSigningIdentity_from_secret_keys');
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_from_secret_keys =
+      _wire__crate__bindings__cross_signing__CrossSigningIdentity_from_secret_keysPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__cross_signing__CrossSigningIdentity_master_key(
+    int that,
+  ) {
+    return _wire__crate__bindings__cross_signing__CrossSigningIdentity_master_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_master_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_vodozemac_wire__crate__bindings__cross_signing__CrossSigningIdentity_master_key');
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_master_key =
+      _wire__crate__bindings__cross_signing__CrossSigningIdentity_master_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__cross_signing__CrossSigningIdentity_master_secret_key(
+    int that,
+  ) {
+    return _wire__crate__bindings__cross_signing__CrossSigningIdentity_master_secret_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_master_secret_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_vodozemac_wire__crate__bindings__cross_signing__CrossSigningIdentity_master_secret_key');
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_master_secret_key =
+      _wire__crate__bindings__cross_signing__CrossSigningIdentity_master_secret_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__cross_signing__CrossSigningIdentity_new(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> user_id,
+  ) {
+    return _wire__crate__bindings__cross_signing__CrossSigningIdentity_new(
+      user_id,
+    );
+  }
+
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_newPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__cross_signing__CrossSigningIdentity_new');
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_new =
+      _wire__crate__bindings__cross_signing__CrossSigningIdentity_newPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_key(
+    int that,
+  ) {
+    return _wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_vodozemac_wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_key');
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_key =
+      _wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_secret_key(
+    int that,
+  ) {
+    return _wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_secret_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_secret_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_vodozemac_wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_secret_key');
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_secret_key =
+      _wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_secret_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_device(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> device_keys,
+  ) {
+    return _wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_device(
+      that,
+      device_keys,
+    );
+  }
+
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_devicePtr = _lookup<
+          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_device');
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_device =
+      _wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_devicePtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_user(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> master_key,
+  ) {
+    return _wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_user(
+      that,
+      master_key,
+    );
+  }
+
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_userPtr = _lookup<
+          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_user');
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_user =
+      _wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_userPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__cross_signing__CrossSigningIdentity_user_id(
+    int that,
+  ) {
+    return _wire__crate__bindings__cross_signing__CrossSigningIdentity_user_id(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_user_idPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_vodozemac_wire__crate__bindings__cross_signing__CrossSigningIdentity_user_id');
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_user_id =
+      _wire__crate__bindings__cross_signing__CrossSigningIdentity_user_idPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_key(
+    int that,
+  ) {
+    return _wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_vodozemac_wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_key');
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_key =
+      _wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_secret_key(
+    int that,
+  ) {
+    return _wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_secret_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_secret_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_vodozemac_wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_secret_key');
+  late final _wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_secret_key =
+      _wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_secret_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__PkSigning_from_secret_key(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> key,
+  ) {
+    return _wire__crate__bindings__PkSigning_from_secret_key(
+      key,
+    );
+  }
+
+  late final _wire__crate__bindings__PkSigning_from_secret_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__PkSigning_from_secret_key');
+  late final _wire__crate__bindings__PkSigning_from_secret_key = _wire__crate__bindings__PkSigning_from_secret_keyPtr
+      .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__PkSigning_new() {
+    return _wire__crate__bindings__PkSigning_new();
+  }
+
+  late final _wire__crate__bindings__PkSigning_newPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+      'frbgen_vodozemac_wire__crate__bindings__PkSigning_new');
+  late final _wire__crate__bindings__PkSigning_new =
+      _wire__crate__bindings__PkSigning_newPtr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__PkSigning_public_key(
+    int that,
+  ) {
+    return _wire__crate__bindings__PkSigning_public_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__PkSigning_public_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_vodozemac_wire__crate__bindings__PkSigning_public_key');
+  late final _wire__crate__bindings__PkSigning_public_key =
+      _wire__crate__bindings__PkSigning_public_keyPtr.asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__PkSigning_secret_key(
+    int that,
+  ) {
+    return _wire__crate__bindings__PkSigning_secret_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__PkSigning_secret_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_vodozemac_wire__crate__bindings__PkSigning_secret_key');
+  late final _wire__crate__bindings__PkSigning_secret_key =
+      _wire__crate__bindings__PkSigning_secret_keyPtr.asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__PkSigning_sign(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
+  ) {
+    return _wire__crate__bindings__PkSigning_sign(
+      that,
+      message,
+    );
+  }
+
+  late final _wire__crate__bindings__PkSigning_signPtr = _lookup<
+          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__PkSigning_sign');
+  late final _wire__crate__bindings__PkSigning_sign = _wire__crate__bindings__PkSigning_signPtr
+      .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__VodozemacSas_establish_sas_secret(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> other_public_key,
+  ) {
+    return _wire__crate__bindings__VodozemacSas_establish_sas_secret(
+      that,
+      other_public_key,
+    );
+  }
+
+  late final _wire__crate__bindings__VodozemacSas_establish_sas_secretPtr = _lookup<
+          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__VodozemacSas_establish_sas_secret');
+  late final _wire__crate__bindings__VodozemacSas_establish_sas_secret =
+      _wire__crate__bindings__VodozemacSas_establish_sas_secretPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__VodozemacSas_new() {
+    return _wire__crate__bindings__VodozemacSas_new();
+  }
+
+  late final _wire__crate__bindings__VodozemacSas_newPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+      'frbgen_vodozemac_wire__crate__bindings__VodozemacSas_new');
+  late final _wire__crate__bindings__VodozemacSas_new =
+      _wire__crate__bindings__VodozemacSas_newPtr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__VodozemacSas_public_key(
+    int that,
+  ) {
+    return _wire__crate__bindings__VodozemacSas_public_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__VodozemacSas_public_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_vodozemac_wire__crate__bindings__VodozemacSas_public_key');
+  late final _wire__crate__bindings__VodozemacSas_public_key =
+      _wire__crate__bindings__VodozemacSas_public_keyPtr.asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__aes_ctr(
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> input,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> key,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> iv,
+  ) {
+    return _wire__crate__bindings__aes_ctr(
+      input,
+      key,
+      iv,
+    );
+  }
+
+  late final _wire__crate__bindings__aes_ctrPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_loose>,
+              ffi.Pointer<wire_cst_list_prim_u_8_loose>,
+              ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_vodozemac_wire__crate__bindings__aes_ctr');
+  late final _wire__crate__bindings__aes_ctr = _wire__crate__bindings__aes_ctrPtr.asFunction<
+      WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>,
+          ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__retention__apply_room_retention(
+    ffi.Pointer<wire_cst_list_String> pickles,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+    ffi.Pointer<wire_cst_list_room_retention> policies,
+    ffi.Pointer<wire_cst_megolm_replay_guard> guard,
+    int now_ms,
+  ) {
+    return _wire__crate__bindings__retention__apply_room_retention(
+      pickles,
+      pickle_key,
+      policies,
+      guard,
+      now_ms,
+    );
+  }
+
+  late final _wire__crate__bindings__retention__apply_room_retentionPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_String>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_room_retention>,
+              ffi.Pointer<wire_cst_megolm_replay_guard>,
+              ffi.Uint64)>>('frbgen_vodozemac_wire__crate__bindings__retention__apply_room_retention');
+  late final _wire__crate__bindings__retention__apply_room_retention =
+      _wire__crate__bindings__retention__apply_room_retentionPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_room_retention>, ffi.Pointer<wire_cst_megolm_replay_guard>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_cause__classify_utd(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> encrypted_content,
+    ffi.Pointer<wire_cst_list_inbound_room_key> candidates,
+    ffi.Pointer<ffi.Int32> withheld_code,
+    int origin_server_ts,
+    int device_created_at_ms,
+  ) {
+    return _wire__crate__bindings__utd_cause__classify_utd(
+      room_id,
+      encrypted_content,
+      candidates,
+      withheld_code,
+      origin_server_ts,
+      device_created_at_ms,
+    );
+  }
+
+  late final _wire__crate__bindings__utd_cause__classify_utdPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_inbound_room_key>,
+              ffi.Pointer<ffi.Int32>,
+              ffi.Uint64,
+              ffi.Uint64)>>('frbgen_vodozemac_wire__crate__bindings__utd_cause__classify_utd');
+  late final _wire__crate__bindings__utd_cause__classify_utd =
+      _wire__crate__bindings__utd_cause__classify_utdPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_inbound_room_key>,
+              ffi.Pointer<ffi.Int32>,
+              int,
+              int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_bundle__create_room_key_bundle(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id,
+    ffi.Pointer<wire_cst_list_inbound_room_key> room_keys,
+  ) {
+    return _wire__crate__bindings__room_key_bundle__create_room_key_bundle(
+      room_id,
+      room_keys,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_bundle__create_room_key_bundlePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_inbound_room_key>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__room_key_bundle__create_room_key_bundle');
+  late final _wire__crate__bindings__room_key_bundle__create_room_key_bundle =
+      _wire__crate__bindings__room_key_bundle__create_room_key_bundlePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_inbound_room_key>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_event__decrypt_room_event(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> session,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> encrypted_content,
+  ) {
+    return _wire__crate__bindings__room_event__decrypt_room_event(
+      session,
+      room_id,
+      encrypted_content,
+    );
+  }
+
+  late final _wire__crate__bindings__room_event__decrypt_room_eventPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__room_event__decrypt_room_event');
+  late final _wire__crate__bindings__room_event__decrypt_room_event =
+      _wire__crate__bindings__room_event__decrypt_room_eventPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__replay__decrypt_room_event_checked(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> session,
+    ffi.Pointer<wire_cst_megolm_replay_guard> guard,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> event_id,
+    int origin_server_ts,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> encrypted_content,
+  ) {
+    return _wire__crate__bindings__replay__decrypt_room_event_checked(
+      session,
+      guard,
+      room_id,
+      event_id,
+      origin_server_ts,
+      encrypted_content,
+    );
+  }
+
+  late final _wire__crate__bindings__replay__decrypt_room_event_checkedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_inbound_group_session>,
+                  ffi.Pointer<wire_cst_megolm_replay_guard>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Uint64,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__replay__decrypt_room_event_checked');
+  late final _wire__crate__bindings__replay__decrypt_room_event_checked =
+      _wire__crate__bindings__replay__decrypt_room_event_checkedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_inbound_group_session>,
+              ffi.Pointer<wire_cst_megolm_replay_guard>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__to_device__decrypt_to_device(
+    ffi.Pointer<wire_cst_vodozemac_account> account,
+    ffi.Pointer<wire_cst_list_vodozemac_session> sessions_for_sender,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> event_json,
+  ) {
+    return _wire__crate__bindings__to_device__decrypt_to_device(
+      account,
+      sessions_for_sender,
+      event_json,
+    );
+  }
+
+  late final _wire__crate__bindings__to_device__decrypt_to_devicePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>,
+                  ffi.Pointer<wire_cst_list_vodozemac_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__to_device__decrypt_to_device');
+  late final _wire__crate__bindings__to_device__decrypt_to_device =
+      _wire__crate__bindings__to_device__decrypt_to_devicePtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>,
+              ffi.Pointer<wire_cst_list_vodozemac_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__pickle_format__detect_pickle(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
+  ) {
+    return _wire__crate__bindings__pickle_format__detect_pickle(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__pickle_format__detect_picklePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__pickle_format__detect_pickle');
+  late final _wire__crate__bindings__pickle_format__detect_pickle =
+      _wire__crate__bindings__pickle_format__detect_picklePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__device_keys__device_keys_parse_and_verify(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> json,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> expected_user,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> expected_device,
+  ) {
+    return _wire__crate__bindings__device_keys__device_keys_parse_and_verify(
+      json,
+      expected_user,
+      expected_device,
+    );
+  }
+
+  late final _wire__crate__bindings__device_keys__device_keys_parse_and_verifyPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__device_keys__device_keys_parse_and_verify');
+  late final _wire__crate__bindings__device_keys__device_keys_parse_and_verify =
+      _wire__crate__bindings__device_keys__device_keys_parse_and_verifyPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_event__encrypt_room_event(
+    ffi.Pointer<wire_cst_vodozemac_group_session> session,
+    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> sender_key,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> device_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> event_type,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> content,
+  ) {
+    return _wire__crate__bindings__room_event__encrypt_room_event(
+      session,
+      sender_key,
+      device_id,
+      room_id,
+      event_type,
+      content,
+    );
+  }
+
+  late final _wire__crate__bindings__room_event__encrypt_room_eventPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_group_session>,
+                  ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__room_event__encrypt_room_event');
+  late final _wire__crate__bindings__room_event__encrypt_room_event =
+      _wire__crate__bindings__room_event__encrypt_room_eventPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_group_session>,
+              ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__to_device__encrypt_to_device(
+    ffi.Pointer<wire_cst_vodozemac_account> account,
+    ffi.Pointer<wire_cst_vodozemac_session> session,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sender,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_device,
+    ffi.Pointer<wire_cst_device_keys> recipient,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> event_type,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> content,
+  ) {
+    return _wire__crate__bindings__to_device__encrypt_to_device(
+      account,
+      session,
+      sender,
+      sender_device,
+      recipient,
+      event_type,
+      content,
+    );
+  }
+
+  late final _wire__crate__bindings__to_device__encrypt_to_devicePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_account>,
+                  ffi.Pointer<wire_cst_vodozemac_session>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_device_keys>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__to_device__encrypt_to_device');
+  late final _wire__crate__bindings__to_device__encrypt_to_device =
+      _wire__crate__bindings__to_device__encrypt_to_devicePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_account>,
+              ffi.Pointer<wire_cst_vodozemac_session>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_device_keys>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_bundle__encrypted_room_key_bundle_encrypt_for_device(
+    ffi.Pointer<wire_cst_encrypted_room_key_bundle> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> url,
+    ffi.Pointer<wire_cst_vodozemac_account> account,
+    ffi.Pointer<wire_cst_vodozemac_session> session,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sender,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_device,
+    ffi.Pointer<wire_cst_device_keys> recipient,
+  ) {
+    return _wire__crate__bindings__room_key_bundle__encrypted_room_key_bundle_encrypt_for_device(
+      that,
+      url,
+      account,
+      session,
+      sender,
+      sender_device,
+      recipient,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_bundle__encrypted_room_key_bundle_encrypt_for_devicePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_encrypted_room_key_bundle>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_vodozemac_account>,
+                  ffi.Pointer<wire_cst_vodozemac_session>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_device_keys>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__room_key_bundle__encrypted_room_key_bundle_encrypt_for_device');
+  late final _wire__crate__bindings__room_key_bundle__encrypted_room_key_bundle_encrypt_for_device =
+      _wire__crate__bindings__room_key_bundle__encrypted_room_key_bundle_encrypt_for_devicePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_encrypted_room_key_bundle>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_vodozemac_account>,
+              ffi.Pointer<wire_cst_vodozemac_session>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_device_keys>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__trust__evaluate_device_trust(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> own_user_id,
+    ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> own_master_key,
+    ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> own_user_signing_key,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> keys_query_response,
+  ) {
+    return _wire__crate__bindings__trust__evaluate_device_trust(
+      own_user_id,
+      own_master_key,
+      own_user_signing_key,
+      keys_query_response,
+    );
+  }
+
+  late final _wire__crate__bindings__trust__evaluate_device_trustPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>,
+                  ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__trust__evaluate_device_trust');
+  late final _wire__crate__bindings__trust__evaluate_device_trust =
+      _wire__crate__bindings__trust__evaluate_device_trustPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>,
+              ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__key_maintenance__fallback_key_tracker_default() {
+    return _wire__crate__bindings__key_maintenance__fallback_key_tracker_default();
+  }
+
+  late final _wire__crate__bindings__key_maintenance__fallback_key_tracker_defaultPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__key_maintenance__fallback_key_tracker_default');
+  late final _wire__crate__bindings__key_maintenance__fallback_key_tracker_default =
+      _wire__crate__bindings__key_maintenance__fallback_key_tracker_defaultPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__key_maintenance__fallback_key_tracker_from_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__key_maintenance__fallback_key_tracker_from_pickle_encrypted(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__key_maintenance__fallback_key_tracker_from_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__key_maintenance__fallback_key_tracker_from_pickle_encrypted');
+  late final _wire__crate__bindings__key_maintenance__fallback_key_tracker_from_pickle_encrypted =
+      _wire__crate__bindings__key_maintenance__fallback_key_tracker_from_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__key_maintenance__fallback_key_tracker_grace_period_ms(
+    ffi.Pointer<wire_cst_fallback_key_tracker> that,
+  ) {
+    return _wire__crate__bindings__key_maintenance__fallback_key_tracker_grace_period_ms(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__key_maintenance__fallback_key_tracker_grace_period_msPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_fallback_key_tracker>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__key_maintenance__fallback_key_tracker_grace_period_ms');
+  late final _wire__crate__bindings__key_maintenance__fallback_key_tracker_grace_period_ms =
+      _wire__crate__bindings__key_maintenance__fallback_key_tracker_grace_period_msPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_fallback_key_tracker>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__key_maintenance__fallback_key_tracker_new() {
+    return _wire__crate__bindings__key_maintenance__fallback_key_tracker_new();
+  }
+
+  late final _wire__crate__bindings__key_maintenance__fallback_key_tracker_newPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__key_maintenance__fallback_key_tracker_new');
+  late final _wire__crate__bindings__key_maintenance__fallback_key_tracker_new =
+      _wire__crate__bindings__key_maintenance__fallback_key_tracker_newPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__key_maintenance__fallback_key_tracker_pickle_encrypted(
+    ffi.Pointer<wire_cst_fallback_key_tracker> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__key_maintenance__fallback_key_tracker_pickle_encrypted(
+      that,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__key_maintenance__fallback_key_tracker_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_fallback_key_tracker>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__key_maintenance__fallback_key_tracker_pickle_encrypted');
+  late final _wire__crate__bindings__key_maintenance__fallback_key_tracker_pickle_encrypted =
+      _wire__crate__bindings__key_maintenance__fallback_key_tracker_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_fallback_key_tracker>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__key_maintenance__fallback_key_tracker_set_grace_period_ms(
+    ffi.Pointer<wire_cst_fallback_key_tracker> that,
+    int grace_period_ms,
+  ) {
+    return _wire__crate__bindings__key_maintenance__fallback_key_tracker_set_grace_period_ms(
+      that,
+      grace_period_ms,
+    );
+  }
+
+  late final _wire__crate__bindings__key_maintenance__fallback_key_tracker_set_grace_period_msPtr = _lookup<
+          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_fallback_key_tracker>, ffi.Uint64)>>(
+      'frbgen_vodozemac_wire__crate__bindings__key_maintenance__fallback_key_tracker_set_grace_period_ms');
+  late final _wire__crate__bindings__key_maintenance__fallback_key_tracker_set_grace_period_ms =
+      _wire__crate__bindings__key_maintenance__fallback_key_tracker_set_grace_period_msPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_fallback_key_tracker>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__forwarded_room_key__forwarded_room_key_content(
+    ffi.Pointer<wire_cst_inbound_room_key> room_key,
+    ffi.Pointer<ffi.Uint32> message_index,
+  ) {
+    return _wire__crate__bindings__forwarded_room_key__forwarded_room_key_content(
+      room_key,
+      message_index,
+    );
+  }
+
+  late final _wire__crate__bindings__forwarded_room_key__forwarded_room_key_contentPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>, ffi.Pointer<ffi.Uint32>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__forwarded_room_key__forwarded_room_key_content');
+  late final _wire__crate__bindings__forwarded_room_key__forwarded_room_key_content =
+      _wire__crate__bindings__forwarded_room_key__forwarded_room_key_contentPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>, ffi.Pointer<ffi.Uint32>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__hmac(
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> key,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> input,
+  ) {
+    return _wire__crate__bindings__hmac(
+      key,
+      input,
+    );
+  }
+
+  late final _wire__crate__bindings__hmacPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>,
+              ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_vodozemac_wire__crate__bindings__hmac');
+  late final _wire__crate__bindings__hmac = _wire__crate__bindings__hmacPtr.asFunction<
+      WireSyncRust2DartDco Function(
+          ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_info__inbound_room_key_decrypt_room_event(
+    ffi.Pointer<wire_cst_inbound_room_key> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> encrypted_content,
+  ) {
+    return _wire__crate__bindings__room_key_info__inbound_room_key_decrypt_room_event(
+      that,
+      room_id,
+      encrypted_content,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_decrypt_room_eventPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__room_key_info__inbound_room_key_decrypt_room_event');
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_decrypt_room_event =
+      _wire__crate__bindings__room_key_info__inbound_room_key_decrypt_room_eventPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_info__inbound_room_key_first_known_index(
+    ffi.Pointer<wire_cst_inbound_room_key> that,
+  ) {
+    return _wire__crate__bindings__room_key_info__inbound_room_key_first_known_index(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_first_known_indexPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__room_key_info__inbound_room_key_first_known_index');
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_first_known_index =
+      _wire__crate__bindings__room_key_info__inbound_room_key_first_known_indexPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_info__inbound_room_key_from_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__room_key_info__inbound_room_key_from_pickle_encrypted(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_from_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__room_key_info__inbound_room_key_from_pickle_encrypted');
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_from_pickle_encrypted =
+      _wire__crate__bindings__room_key_info__inbound_room_key_from_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_info__inbound_room_key_inbound_group_session(
+    ffi.Pointer<wire_cst_inbound_room_key> that,
+  ) {
+    return _wire__crate__bindings__room_key_info__inbound_room_key_inbound_group_session(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_inbound_group_sessionPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__room_key_info__inbound_room_key_inbound_group_session');
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_inbound_group_session =
+      _wire__crate__bindings__room_key_info__inbound_room_key_inbound_group_sessionPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_info__inbound_room_key_info(
+    ffi.Pointer<wire_cst_inbound_room_key> that,
+  ) {
+    return _wire__crate__bindings__room_key_info__inbound_room_key_info(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_infoPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__room_key_info__inbound_room_key_info');
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_info =
+      _wire__crate__bindings__room_key_info__inbound_room_key_infoPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_info__inbound_room_key_mark_backed_up(
+    ffi.Pointer<wire_cst_inbound_room_key> that,
+    bool backed_up,
+  ) {
+    return _wire__crate__bindings__room_key_info__inbound_room_key_mark_backed_up(
+      that,
+      backed_up,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_mark_backed_upPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>, ffi.Bool)>>(
+          'frbgen_vodozemac_wire__crate__bindings__room_key_info__inbound_room_key_mark_backed_up');
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_mark_backed_up =
+      _wire__crate__bindings__room_key_info__inbound_room_key_mark_backed_upPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>, bool)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_info__inbound_room_key_new(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> session,
+    ffi.Pointer<wire_cst_room_key_info> info,
+  ) {
+    return _wire__crate__bindings__room_key_info__inbound_room_key_new(
+      session,
+      info,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_newPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, ffi.Pointer<wire_cst_room_key_info>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__room_key_info__inbound_room_key_new');
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_new =
+      _wire__crate__bindings__room_key_info__inbound_room_key_newPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, ffi.Pointer<wire_cst_room_key_info>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_info__inbound_room_key_pickle_encrypted(
+    ffi.Pointer<wire_cst_inbound_room_key> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__room_key_info__inbound_room_key_pickle_encrypted(
+      that,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_inbound_room_key>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__room_key_info__inbound_room_key_pickle_encrypted');
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_pickle_encrypted =
+      _wire__crate__bindings__room_key_info__inbound_room_key_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_inbound_room_key>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_info__inbound_room_key_session_id(
+    ffi.Pointer<wire_cst_inbound_room_key> that,
+  ) {
+    return _wire__crate__bindings__room_key_info__inbound_room_key_session_id(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_session_idPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__room_key_info__inbound_room_key_session_id');
+  late final _wire__crate__bindings__room_key_info__inbound_room_key_session_id =
+      _wire__crate__bindings__room_key_info__inbound_room_key_session_idPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_inbound_room_key>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__replay__megolm_replay_guard_default() {
+    return _wire__crate__bindings__replay__megolm_replay_guard_default();
+  }
+
+  late final _wire__crate__bindings__replay__megolm_replay_guard_defaultPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__replay__megolm_replay_guard_default');
+  late final _wire__crate__bindings__replay__megolm_replay_guard_default =
+      _wire__crate__bindings__replay__megolm_replay_guard_defaultPtr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__replay__megolm_replay_guard_forget_before(
+    ffi.Pointer<wire_cst_megolm_replay_guard> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> session_id,
+    int index,
+  ) {
+    return _wire__crate__bindings__replay__megolm_replay_guard_forget_before(
+      that,
+      session_id,
+      index,
+    );
+  }
+
+  late final _wire__crate__bindings__replay__megolm_replay_guard_forget_beforePtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_megolm_replay_guard>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Uint32)>>('frbgen_vodozemac_wire__crate__bindings__replay__megolm_replay_guard_forget_before');
+  late final _wire__crate__bindings__replay__megolm_replay_guard_forget_before =
+      _wire__crate__bindings__replay__megolm_replay_guard_forget_beforePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_megolm_replay_guard>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__replay__megolm_replay_guard_forget_session(
+    ffi.Pointer<wire_cst_megolm_replay_guard> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> session_id,
+  ) {
+    return _wire__crate__bindings__replay__megolm_replay_guard_forget_session(
+      that,
+      session_id,
+    );
+  }
+
+  late final _wire__crate__bindings__replay__megolm_replay_guard_forget_sessionPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_megolm_replay_guard>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__replay__megolm_replay_guard_forget_session');
+  late final _wire__crate__bindings__replay__megolm_replay_guard_forget_session =
+      _wire__crate__bindings__replay__megolm_replay_guard_forget_sessionPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_megolm_replay_guard>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__replay__megolm_replay_guard_from_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__replay__megolm_replay_guard_from_pickle_encrypted(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__replay__megolm_replay_guard_from_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__replay__megolm_replay_guard_from_pickle_encrypted');
+  late final _wire__crate__bindings__replay__megolm_replay_guard_from_pickle_encrypted =
+      _wire__crate__bindings__replay__megolm_replay_guard_from_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__replay__megolm_replay_guard_new() {
+    return _wire__crate__bindings__replay__megolm_replay_guard_new();
+  }
+
+  late final _wire__crate__bindings__replay__megolm_replay_guard_newPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__replay__megolm_replay_guard_new');
+  late final _wire__crate__bindings__replay__megolm_replay_guard_new =
+      _wire__crate__bindings__replay__megolm_replay_guard_newPtr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__replay__megolm_replay_guard_pickle_encrypted(
+    ffi.Pointer<wire_cst_megolm_replay_guard> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__replay__megolm_replay_guard_pickle_encrypted(
+      that,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__replay__megolm_replay_guard_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_megolm_replay_guard>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__replay__megolm_replay_guard_pickle_encrypted');
+  late final _wire__crate__bindings__replay__megolm_replay_guard_pickle_encrypted =
+      _wire__crate__bindings__replay__megolm_replay_guard_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_megolm_replay_guard>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__replay__megolm_replay_guard_record(
+    ffi.Pointer<wire_cst_megolm_replay_guard> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> session_id,
+    int message_index,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> event_id,
+    int origin_server_ts,
+  ) {
+    return _wire__crate__bindings__replay__megolm_replay_guard_record(
+      that,
+      session_id,
+      message_index,
+      event_id,
+      origin_server_ts,
+    );
+  }
+
+  late final _wire__crate__bindings__replay__megolm_replay_guard_recordPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_megolm_replay_guard>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Uint32,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Uint64)>>('frbgen_vodozemac_wire__crate__bindings__replay__megolm_replay_guard_record');
+  late final _wire__crate__bindings__replay__megolm_replay_guard_record =
+      _wire__crate__bindings__replay__megolm_replay_guard_recordPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_megolm_replay_guard>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__migration__migrate_libolm_store(
+    ffi.Pointer<wire_cst_libolm_store> store,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> old_pickle_key,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> new_pickle_key,
+  ) {
+    return _wire__crate__bindings__migration__migrate_libolm_store(
+      store,
+      old_pickle_key,
+      new_pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__migration__migrate_libolm_storePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_libolm_store>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__migration__migrate_libolm_store');
+  late final _wire__crate__bindings__migration__migrate_libolm_store =
+      _wire__crate__bindings__migration__migrate_libolm_storePtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_libolm_store>, ffi.Pointer<wire_cst_list_prim_u_8_loose>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__migration__migration_report_is_complete(
+    ffi.Pointer<wire_cst_migration_report> that,
+  ) {
+    return _wire__crate__bindings__migration__migration_report_is_complete(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__migration__migration_report_is_completePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_migration_report>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__migration__migration_report_is_complete');
+  late final _wire__crate__bindings__migration__migration_report_is_complete =
+      _wire__crate__bindings__migration__migration_report_is_completePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_migration_report>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__olm_sessions__olm_session_store_add_session(
+    ffi.Pointer<wire_cst_olm_session_store> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_key,
+    ffi.Pointer<wire_cst_vodozemac_session> session,
+    int now_ms,
+  ) {
+    return _wire__crate__bindings__olm_sessions__olm_session_store_add_session(
+      that,
+      sender_key,
+      session,
+      now_ms,
+    );
+  }
+
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_add_sessionPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_olm_session_store>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_vodozemac_session>,
+              ffi.Uint64)>>('frbgen_vodozemac_wire__crate__bindings__olm_sessions__olm_session_store_add_session');
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_add_session =
+      _wire__crate__bindings__olm_sessions__olm_session_store_add_sessionPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_session_store>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_session>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__olm_sessions__olm_session_store_default() {
+    return _wire__crate__bindings__olm_sessions__olm_session_store_default();
+  }
+
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_defaultPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__olm_sessions__olm_session_store_default');
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_default =
+      _wire__crate__bindings__olm_sessions__olm_session_store_defaultPtr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__olm_sessions__olm_session_store_from_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__olm_sessions__olm_session_store_from_pickle_encrypted(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_from_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__olm_sessions__olm_session_store_from_pickle_encrypted');
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_from_pickle_encrypted =
+      _wire__crate__bindings__olm_sessions__olm_session_store_from_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__olm_sessions__olm_session_store_mark_used(
+    ffi.Pointer<wire_cst_olm_session_store> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_key,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> session_id,
+    int now_ms,
+  ) {
+    return _wire__crate__bindings__olm_sessions__olm_session_store_mark_used(
+      that,
+      sender_key,
+      session_id,
+      now_ms,
+    );
+  }
+
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_mark_usedPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_olm_session_store>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Uint64)>>('frbgen_vodozemac_wire__crate__bindings__olm_sessions__olm_session_store_mark_used');
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_mark_used =
+      _wire__crate__bindings__olm_sessions__olm_session_store_mark_usedPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_session_store>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__olm_sessions__olm_session_store_max_sessions_per_device(
+    ffi.Pointer<wire_cst_olm_session_store> that,
+  ) {
+    return _wire__crate__bindings__olm_sessions__olm_session_store_max_sessions_per_device(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_max_sessions_per_devicePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_session_store>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__olm_sessions__olm_session_store_max_sessions_per_device');
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_max_sessions_per_device =
+      _wire__crate__bindings__olm_sessions__olm_session_store_max_sessions_per_devicePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_session_store>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__olm_sessions__olm_session_store_new() {
+    return _wire__crate__bindings__olm_sessions__olm_session_store_new();
+  }
+
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_newPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__olm_sessions__olm_session_store_new');
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_new =
+      _wire__crate__bindings__olm_sessions__olm_session_store_newPtr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__olm_sessions__olm_session_store_pickle_encrypted(
+    ffi.Pointer<wire_cst_olm_session_store> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__olm_sessions__olm_session_store_pickle_encrypted(
+      that,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_olm_session_store>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__olm_sessions__olm_session_store_pickle_encrypted');
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_pickle_encrypted =
+      _wire__crate__bindings__olm_sessions__olm_session_store_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_olm_session_store>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__olm_sessions__olm_session_store_remove_session(
+    ffi.Pointer<wire_cst_olm_session_store> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_key,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> session_id,
+  ) {
+    return _wire__crate__bindings__olm_sessions__olm_session_store_remove_session(
+      that,
+      sender_key,
+      session_id,
+    );
+  }
+
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_remove_sessionPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_session_store>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__olm_sessions__olm_session_store_remove_session');
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_remove_session =
+      _wire__crate__bindings__olm_sessions__olm_session_store_remove_sessionPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_session_store>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__olm_sessions__olm_session_store_sender_keys(
+    ffi.Pointer<wire_cst_olm_session_store> that,
+  ) {
+    return _wire__crate__bindings__olm_sessions__olm_session_store_sender_keys(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_sender_keysPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_session_store>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__olm_sessions__olm_session_store_sender_keys');
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_sender_keys =
+      _wire__crate__bindings__olm_sessions__olm_session_store_sender_keysPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_session_store>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__olm_sessions__olm_session_store_session_for_encryption(
+    ffi.Pointer<wire_cst_olm_session_store> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_key,
+  ) {
+    return _wire__crate__bindings__olm_sessions__olm_session_store_session_for_encryption(
+      that,
+      sender_key,
+    );
+  }
+
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_session_for_encryptionPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_olm_session_store>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__olm_sessions__olm_session_store_session_for_encryption');
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_session_for_encryption =
+      _wire__crate__bindings__olm_sessions__olm_session_store_session_for_encryptionPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_olm_session_store>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__olm_sessions__olm_session_store_sessions_for_device(
+    ffi.Pointer<wire_cst_olm_session_store> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_key,
+  ) {
+    return _wire__crate__bindings__olm_sessions__olm_session_store_sessions_for_device(
+      that,
+      sender_key,
+    );
+  }
+
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_sessions_for_devicePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_olm_session_store>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__olm_sessions__olm_session_store_sessions_for_device');
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_sessions_for_device =
+      _wire__crate__bindings__olm_sessions__olm_session_store_sessions_for_devicePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_olm_session_store>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__olm_sessions__olm_session_store_set_max_sessions_per_device(
+    ffi.Pointer<wire_cst_olm_session_store> that,
+    int max_sessions_per_device,
+  ) {
+    return _wire__crate__bindings__olm_sessions__olm_session_store_set_max_sessions_per_device(
+      that,
+      max_sessions_per_device,
+    );
+  }
+
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_set_max_sessions_per_devicePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_session_store>, ffi.Uint32)>>(
+          'frbgen_vodozemac_wire__crate__bindings__olm_sessions__olm_session_store_set_max_sessions_per_device');
+  late final _wire__crate__bindings__olm_sessions__olm_session_store_set_max_sessions_per_device =
+      _wire__crate__bindings__olm_sessions__olm_session_store_set_max_sessions_per_devicePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_session_store>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_created_at_ms(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_created_at_ms(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_created_at_msPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_created_at_ms');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_created_at_ms =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_created_at_msPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_devices_missing_key(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+    ffi.Pointer<wire_cst_list_share_recipient> devices,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_devices_missing_key(
+      that,
+      devices,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_devices_missing_keyPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_list_share_recipient>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_devices_missing_key');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_devices_missing_key =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_devices_missing_keyPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_list_share_recipient>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_expired_reason(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+    int now_ms,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_expired_reason(
+      that,
+      now_ms,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_expired_reasonPtr = _lookup<
+          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>, ffi.Uint64)>>(
+      'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_expired_reason');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_expired_reason =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_expired_reasonPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_from_group_session(
+    ffi.Pointer<wire_cst_vodozemac_group_session> session,
+    int created_at_ms,
+    ffi.Pointer<ffi.Uint64> rotation_period_ms,
+    ffi.Pointer<ffi.Uint64> rotation_period_msgs,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_from_group_session(
+      session,
+      created_at_ms,
+      rotation_period_ms,
+      rotation_period_msgs,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_from_group_sessionPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>, ffi.Uint64,
+                  ffi.Pointer<ffi.Uint64>, ffi.Pointer<ffi.Uint64>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_from_group_session');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_from_group_session =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_from_group_sessionPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_group_session>, int, ffi.Pointer<ffi.Uint64>, ffi.Pointer<ffi.Uint64>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_from_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_from_pickle_encrypted(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_from_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_from_pickle_encrypted');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_from_pickle_encrypted =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_from_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_group_session(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_group_session(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_group_sessionPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_group_session');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_group_session =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_group_sessionPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_invalidate(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_invalidate(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_invalidatePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_invalidate');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_invalidate =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_invalidatePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_needs_rotation(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+    int now_ms,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_needs_rotation(
+      that,
+      now_ms,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_needs_rotationPtr = _lookup<
+          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>, ffi.Uint64)>>(
+      'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_needs_rotation');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_needs_rotation =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_needs_rotationPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_new(
+    ffi.Pointer<wire_cst_vodozemac_megolm_session_config> config,
+    int created_at_ms,
+    ffi.Pointer<ffi.Uint64> rotation_period_ms,
+    ffi.Pointer<ffi.Uint64> rotation_period_msgs,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_new(
+      config,
+      created_at_ms,
+      rotation_period_ms,
+      rotation_period_msgs,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_newPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_megolm_session_config>, ffi.Uint64,
+                  ffi.Pointer<ffi.Uint64>, ffi.Pointer<ffi.Uint64>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_new');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_new =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_newPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_megolm_session_config>, int,
+              ffi.Pointer<ffi.Uint64>, ffi.Pointer<ffi.Uint64>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_pickle_encrypted(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_pickle_encrypted(
+      that,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_pickle_encrypted');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_pickle_encrypted =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_record_share(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+    ffi.Pointer<wire_cst_share_recipient> recipient,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_record_share(
+      that,
+      recipient,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_record_sharePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_share_recipient>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_record_share');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_record_share =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_record_sharePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_share_recipient>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_record_withheld(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+    ffi.Pointer<wire_cst_share_recipient> recipient,
+    int code,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_record_withheld(
+      that,
+      recipient,
+      code,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_record_withheldPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_share_recipient>, ffi.Int32)>>(
+      'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_record_withheld');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_record_withheld =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_record_withheldPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_share_recipient>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_removed_devices(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+    ffi.Pointer<wire_cst_list_share_recipient> devices,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_removed_devices(
+      that,
+      devices,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_removed_devicesPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_list_share_recipient>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_removed_devices');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_removed_devices =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_removed_devicesPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_list_share_recipient>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_ms(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_ms(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_msPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_ms');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_ms =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_msPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_msgs(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_msgs(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_msgsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_msgs');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_msgs =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_msgsPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_session_id(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_session_id(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_session_idPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_session_id');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_session_id =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_session_idPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_outbound_group_session>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_shared_message_index(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+    ffi.Pointer<wire_cst_share_recipient> recipient,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_shared_message_index(
+      that,
+      recipient,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_shared_message_indexPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_share_recipient>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_shared_message_index');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_shared_message_index =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_shared_message_indexPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_share_recipient>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__outbound_group_session__outbound_group_session_withheld_code(
+    ffi.Pointer<wire_cst_outbound_group_session> that,
+    ffi.Pointer<wire_cst_share_recipient> recipient,
+  ) {
+    return _wire__crate__bindings__outbound_group_session__outbound_group_session_withheld_code(
+      that,
+      recipient,
+    );
+  }
+
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_withheld_codePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_share_recipient>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__outbound_group_session__outbound_group_session_withheld_code');
+  late final _wire__crate__bindings__outbound_group_session__outbound_group_session_withheld_code =
+      _wire__crate__bindings__outbound_group_session__outbound_group_session_withheld_codePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_outbound_group_session>, ffi.Pointer<wire_cst_share_recipient>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_default() {
+    return _wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_default();
+  }
+
+  late final _wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_defaultPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_default');
+  late final _wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_default =
+      _wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_defaultPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_new() {
+    return _wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_new();
+  }
+
+  late final _wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_newPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_new');
+  late final _wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_new =
+      _wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_newPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__passphrase_pickle__passphrase_pickle_params(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+  ) {
+    return _wire__crate__bindings__passphrase_pickle__passphrase_pickle_params(
+      pickle,
+    );
+  }
+
+  late final _wire__crate__bindings__passphrase_pickle__passphrase_pickle_paramsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__passphrase_pickle__passphrase_pickle_params');
+  late final _wire__crate__bindings__passphrase_pickle__passphrase_pickle_params =
+      _wire__crate__bindings__passphrase_pickle__passphrase_pickle_paramsPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__pbkdf2(
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> passphrase,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> salt,
+    int iterations,
+  ) {
+    return _wire__crate__bindings__pbkdf2(
+      passphrase,
+      salt,
+      iterations,
+    );
+  }
+
+  late final _wire__crate__bindings__pbkdf2Ptr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_loose>,
+              ffi.Pointer<wire_cst_list_prim_u_8_loose>,
+              ffi.Uint32)>>('frbgen_vodozemac_wire__crate__bindings__pbkdf2');
+  late final _wire__crate__bindings__pbkdf2 = _wire__crate__bindings__pbkdf2Ptr.asFunction<
+      WireSyncRust2DartDco Function(
+          ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__pickle_format__pickle_info_needs_repickle(
+    ffi.Pointer<wire_cst_pickle_info> that,
+  ) {
+    return _wire__crate__bindings__pickle_format__pickle_info_needs_repickle(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__pickle_format__pickle_info_needs_repicklePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_pickle_info>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__pickle_format__pickle_info_needs_repickle');
+  late final _wire__crate__bindings__pickle_format__pickle_info_needs_repickle =
+      _wire__crate__bindings__pickle_format__pickle_info_needs_repicklePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_pickle_info>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__forwarded_room_key__receive_forwarded_room_key(
+    ffi.Pointer<wire_cst_olm_payload> payload,
+    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> forwarder_key,
+    ffi.Pointer<wire_cst_device_keys> forwarder_device,
+  ) {
+    return _wire__crate__bindings__forwarded_room_key__receive_forwarded_room_key(
+      payload,
+      forwarder_key,
+      forwarder_device,
+    );
+  }
+
+  late final _wire__crate__bindings__forwarded_room_key__receive_forwarded_room_keyPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_payload>,
+                  ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>, ffi.Pointer<wire_cst_device_keys>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__forwarded_room_key__receive_forwarded_room_key');
+  late final _wire__crate__bindings__forwarded_room_key__receive_forwarded_room_key =
+      _wire__crate__bindings__forwarded_room_key__receive_forwarded_room_keyPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_payload>,
+              ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>, ffi.Pointer<wire_cst_device_keys>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key__receive_room_key(
+    ffi.Pointer<wire_cst_olm_payload> payload,
+    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> sender_key,
+    ffi.Pointer<wire_cst_device_keys> sender_device,
+  ) {
+    return _wire__crate__bindings__room_key__receive_room_key(
+      payload,
+      sender_key,
+      sender_device,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key__receive_room_keyPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_payload>,
+                  ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>, ffi.Pointer<wire_cst_device_keys>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__room_key__receive_room_key');
+  late final _wire__crate__bindings__room_key__receive_room_key =
+      _wire__crate__bindings__room_key__receive_room_keyPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_payload>,
+              ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>, ffi.Pointer<wire_cst_device_keys>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_bundle__receive_room_key_bundle(
+    ffi.Pointer<wire_cst_olm_payload> payload,
+    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> sender_key,
+    ffi.Pointer<wire_cst_device_keys> sender_device,
+  ) {
+    return _wire__crate__bindings__room_key_bundle__receive_room_key_bundle(
+      payload,
+      sender_key,
+      sender_device,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_bundle__receive_room_key_bundlePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_payload>,
+                  ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>, ffi.Pointer<wire_cst_device_keys>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__room_key_bundle__receive_room_key_bundle');
+  late final _wire__crate__bindings__room_key_bundle__receive_room_key_bundle =
+      _wire__crate__bindings__room_key_bundle__receive_room_key_bundlePtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_olm_payload>,
+              ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>, ffi.Pointer<wire_cst_device_keys>)>();
+
+  void wire__crate__bindings__rekey__rekey_pickles(
+    int port_,
+    ffi.Pointer<wire_cst_list_String> pickles,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> old_pickle_key,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> new_pickle_key,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> progress,
+  ) {
+    return _wire__crate__bindings__rekey__rekey_pickles(
+      port_,
+      pickles,
+      old_pickle_key,
+      new_pickle_key,
+      progress,
+    );
+  }
+
+  late final _wire__crate__bindings__rekey__rekey_picklesPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_cst_list_String>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__rekey__rekey_pickles');
+  late final _wire__crate__bindings__rekey__rekey_pickles = _wire__crate__bindings__rekey__rekey_picklesPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_bundle__room_key_bundle_download_decrypt(
+    ffi.Pointer<wire_cst_room_key_bundle_download> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> ciphertext,
+  ) {
+    return _wire__crate__bindings__room_key_bundle__room_key_bundle_download_decrypt(
+      that,
+      ciphertext,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_bundle__room_key_bundle_download_decryptPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_room_key_bundle_download>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__room_key_bundle__room_key_bundle_download_decrypt');
+  late final _wire__crate__bindings__room_key_bundle__room_key_bundle_download_decrypt =
+      _wire__crate__bindings__room_key_bundle__room_key_bundle_download_decryptPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_room_key_bundle_download>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key__room_key_content(
+    ffi.Pointer<wire_cst_vodozemac_group_session> session,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id,
+    bool shared_history,
+  ) {
+    return _wire__crate__bindings__room_key__room_key_content(
+      session,
+      room_id,
+      shared_history,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key__room_key_contentPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_group_session>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Bool)>>('frbgen_vodozemac_wire__crate__bindings__room_key__room_key_content');
+  late final _wire__crate__bindings__room_key__room_key_content =
+      _wire__crate__bindings__room_key__room_key_contentPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__room_key_info__room_key_info_authenticity(
+    ffi.Pointer<wire_cst_room_key_info> that,
+  ) {
+    return _wire__crate__bindings__room_key_info__room_key_info_authenticity(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__room_key_info__room_key_info_authenticityPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_room_key_info>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__room_key_info__room_key_info_authenticity');
+  late final _wire__crate__bindings__room_key_info__room_key_info_authenticity =
+      _wire__crate__bindings__room_key_info__room_key_info_authenticityPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_room_key_info>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__sha256(
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> input,
+  ) {
+    return _wire__crate__bindings__sha256(
+      input,
+    );
+  }
+
+  late final _wire__crate__bindings__sha256Ptr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__sha256');
+  late final _wire__crate__bindings__sha256 = _wire__crate__bindings__sha256Ptr
+      .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__sha512(
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> input,
+  ) {
+    return _wire__crate__bindings__sha512(
+      input,
+    );
+  }
+
+  late final _wire__crate__bindings__sha512Ptr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__sha512');
+  late final _wire__crate__bindings__sha512 = _wire__crate__bindings__sha512Ptr
+      .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__unwedge__unwedge_tracker_can_unwedge(
+    ffi.Pointer<wire_cst_unwedge_tracker> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_key,
+    int now_ms,
+  ) {
+    return _wire__crate__bindings__unwedge__unwedge_tracker_can_unwedge(
+      that,
+      sender_key,
+      now_ms,
+    );
+  }
+
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_can_unwedgePtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_unwedge_tracker>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Uint64)>>('frbgen_vodozemac_wire__crate__bindings__unwedge__unwedge_tracker_can_unwedge');
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_can_unwedge =
+      _wire__crate__bindings__unwedge__unwedge_tracker_can_unwedgePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_unwedge_tracker>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__unwedge__unwedge_tracker_cooldown_ms(
+    ffi.Pointer<wire_cst_unwedge_tracker> that,
+  ) {
+    return _wire__crate__bindings__unwedge__unwedge_tracker_cooldown_ms(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_cooldown_msPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_unwedge_tracker>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__unwedge__unwedge_tracker_cooldown_ms');
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_cooldown_ms =
+      _wire__crate__bindings__unwedge__unwedge_tracker_cooldown_msPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_unwedge_tracker>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__unwedge__unwedge_tracker_default() {
+    return _wire__crate__bindings__unwedge__unwedge_tracker_default();
+  }
+
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_defaultPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__unwedge__unwedge_tracker_default');
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_default =
+      _wire__crate__bindings__unwedge__unwedge_tracker_defaultPtr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__unwedge__unwedge_tracker_from_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__unwedge__unwedge_tracker_from_pickle_encrypted(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_from_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__unwedge__unwedge_tracker_from_pickle_encrypted');
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_from_pickle_encrypted =
+      _wire__crate__bindings__unwedge__unwedge_tracker_from_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__unwedge__unwedge_tracker_new() {
+    return _wire__crate__bindings__unwedge__unwedge_tracker_new();
+  }
+
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_newPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__unwedge__unwedge_tracker_new');
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_new =
+      _wire__crate__bindings__unwedge__unwedge_tracker_newPtr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__unwedge__unwedge_tracker_pickle_encrypted(
+    ffi.Pointer<wire_cst_unwedge_tracker> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__unwedge__unwedge_tracker_pickle_encrypted(
+      that,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_unwedge_tracker>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__unwedge__unwedge_tracker_pickle_encrypted');
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_pickle_encrypted =
+      _wire__crate__bindings__unwedge__unwedge_tracker_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_unwedge_tracker>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__unwedge__unwedge_tracker_set_cooldown_ms(
+    ffi.Pointer<wire_cst_unwedge_tracker> that,
+    int cooldown_ms,
+  ) {
+    return _wire__crate__bindings__unwedge__unwedge_tracker_set_cooldown_ms(
+      that,
+      cooldown_ms,
+    );
+  }
+
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_set_cooldown_msPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_unwedge_tracker>, ffi.Uint64)>>(
+          'frbgen_vodozemac_wire__crate__bindings__unwedge__unwedge_tracker_set_cooldown_ms');
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_set_cooldown_ms =
+      _wire__crate__bindings__unwedge__unwedge_tracker_set_cooldown_msPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_unwedge_tracker>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__unwedge__unwedge_tracker_start_attempt(
+    ffi.Pointer<wire_cst_unwedge_tracker> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_key,
+    int now_ms,
+  ) {
+    return _wire__crate__bindings__unwedge__unwedge_tracker_start_attempt(
+      that,
+      sender_key,
+      now_ms,
+    );
+  }
+
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_start_attemptPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_unwedge_tracker>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Uint64)>>('frbgen_vodozemac_wire__crate__bindings__unwedge__unwedge_tracker_start_attempt');
+  late final _wire__crate__bindings__unwedge__unwedge_tracker_start_attempt =
+      _wire__crate__bindings__unwedge__unwedge_tracker_start_attemptPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_unwedge_tracker>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_add_event(
+    ffi.Pointer<wire_cst_utd_queue> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> event_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> encrypted_content,
+  ) {
+    return _wire__crate__bindings__utd_queue__utd_queue_add_event(
+      that,
+      room_id,
+      event_id,
+      encrypted_content,
+    );
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_add_eventPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>, ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_add_event');
+  late final _wire__crate__bindings__utd_queue__utd_queue_add_event =
+      _wire__crate__bindings__utd_queue__utd_queue_add_eventPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>, ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_add_room_key(
+    ffi.Pointer<wire_cst_utd_queue> that,
+    ffi.Pointer<wire_cst_inbound_room_key> room_key,
+  ) {
+    return _wire__crate__bindings__utd_queue__utd_queue_add_room_key(
+      that,
+      room_key,
+    );
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_add_room_keyPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>, ffi.Pointer<wire_cst_inbound_room_key>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_add_room_key');
+  late final _wire__crate__bindings__utd_queue__utd_queue_add_room_key =
+      _wire__crate__bindings__utd_queue__utd_queue_add_room_keyPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>, ffi.Pointer<wire_cst_inbound_room_key>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_default() {
+    return _wire__crate__bindings__utd_queue__utd_queue_default();
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_defaultPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_default');
+  late final _wire__crate__bindings__utd_queue__utd_queue_default =
+      _wire__crate__bindings__utd_queue__utd_queue_defaultPtr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_from_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__utd_queue__utd_queue_from_pickle_encrypted(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_from_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_from_pickle_encrypted');
+  late final _wire__crate__bindings__utd_queue__utd_queue_from_pickle_encrypted =
+      _wire__crate__bindings__utd_queue__utd_queue_from_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_is_empty(
+    ffi.Pointer<wire_cst_utd_queue> that,
+  ) {
+    return _wire__crate__bindings__utd_queue__utd_queue_is_empty(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_is_emptyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_is_empty');
+  late final _wire__crate__bindings__utd_queue__utd_queue_is_empty =
+      _wire__crate__bindings__utd_queue__utd_queue_is_emptyPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_len(
+    ffi.Pointer<wire_cst_utd_queue> that,
+  ) {
+    return _wire__crate__bindings__utd_queue__utd_queue_len(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_lenPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_len');
+  late final _wire__crate__bindings__utd_queue__utd_queue_len = _wire__crate__bindings__utd_queue__utd_queue_lenPtr
+      .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_max_events(
+    ffi.Pointer<wire_cst_utd_queue> that,
+  ) {
+    return _wire__crate__bindings__utd_queue__utd_queue_max_events(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_max_eventsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_max_events');
+  late final _wire__crate__bindings__utd_queue__utd_queue_max_events =
+      _wire__crate__bindings__utd_queue__utd_queue_max_eventsPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_merge_room_key(
+    ffi.Pointer<wire_cst_utd_queue> that,
+    ffi.Pointer<wire_cst_inbound_room_key> existing,
+    ffi.Pointer<wire_cst_inbound_room_key> other,
+  ) {
+    return _wire__crate__bindings__utd_queue__utd_queue_merge_room_key(
+      that,
+      existing,
+      other,
+    );
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_merge_room_keyPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>, ffi.Pointer<wire_cst_inbound_room_key>,
+                  ffi.Pointer<wire_cst_inbound_room_key>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_merge_room_key');
+  late final _wire__crate__bindings__utd_queue__utd_queue_merge_room_key =
+      _wire__crate__bindings__utd_queue__utd_queue_merge_room_keyPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>, ffi.Pointer<wire_cst_inbound_room_key>,
+              ffi.Pointer<wire_cst_inbound_room_key>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_new() {
+    return _wire__crate__bindings__utd_queue__utd_queue_new();
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_newPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_new');
+  late final _wire__crate__bindings__utd_queue__utd_queue_new =
+      _wire__crate__bindings__utd_queue__utd_queue_newPtr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_pending_events(
+    ffi.Pointer<wire_cst_utd_queue> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> session_id,
+  ) {
+    return _wire__crate__bindings__utd_queue__utd_queue_pending_events(
+      that,
+      room_id,
+      session_id,
+    );
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_pending_eventsPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>, ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_pending_events');
+  late final _wire__crate__bindings__utd_queue__utd_queue_pending_events =
+      _wire__crate__bindings__utd_queue__utd_queue_pending_eventsPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>, ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_pickle_encrypted(
+    ffi.Pointer<wire_cst_utd_queue> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__utd_queue__utd_queue_pickle_encrypted(
+      that,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_utd_queue>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_pickle_encrypted');
+  late final _wire__crate__bindings__utd_queue__utd_queue_pickle_encrypted =
+      _wire__crate__bindings__utd_queue__utd_queue_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_remove_event(
+    ffi.Pointer<wire_cst_utd_queue> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> event_id,
+  ) {
+    return _wire__crate__bindings__utd_queue__utd_queue_remove_event(
+      that,
+      event_id,
+    );
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_remove_eventPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_utd_queue>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_remove_event');
+  late final _wire__crate__bindings__utd_queue__utd_queue_remove_event =
+      _wire__crate__bindings__utd_queue__utd_queue_remove_eventPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__utd_queue__utd_queue_set_max_events(
+    ffi.Pointer<wire_cst_utd_queue> that,
+    int max_events,
+  ) {
+    return _wire__crate__bindings__utd_queue__utd_queue_set_max_events(
+      that,
+      max_events,
+    );
+  }
+
+  late final _wire__crate__bindings__utd_queue__utd_queue_set_max_eventsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>, ffi.Uint32)>>(
+          'frbgen_vodozemac_wire__crate__bindings__utd_queue__utd_queue_set_max_events');
+  late final _wire__crate__bindings__utd_queue__utd_queue_set_max_events =
+      _wire__crate__bindings__utd_queue__utd_queue_set_max_eventsPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_utd_queue>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__to_device__validate_to_device_payload(
+    ffi.Pointer<wire_cst_vodozemac_account> account,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> own_user_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> event_sender,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext,
+  ) {
+    return _wire__crate__bindings__to_device__validate_to_device_payload(
+      account,
+      own_user_id,
+      event_sender,
+      plaintext,
+    );
+  }
+
+  late final _wire__crate__bindings__to_device__validate_to_device_payloadPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_account>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__to_device__validate_to_device_payload');
+  late final _wire__crate__bindings__to_device__validate_to_device_payload =
+      _wire__crate__bindings__to_device__validate_to_device_payloadPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_account>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_create_inbound_session(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> their_identity_key,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pre_key_message_base64,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_create_inbound_session(
+      that,
+      their_identity_key,
+      pre_key_message_base64,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_create_inbound_sessionPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>,
+                  ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_create_inbound_session');
+  late final _wire__crate__bindings__vodozemac_account_create_inbound_session =
+      _wire__crate__bindings__vodozemac_account_create_inbound_sessionPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>,
+              ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_create_outbound_session(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+    ffi.Pointer<wire_cst_vodozemac_olm_session_config> config,
+    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> identity_key,
+    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> one_time_key,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_create_outbound_session(
+      that,
+      config,
+      identity_key,
+      one_time_key,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_create_outbound_sessionPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_account>,
+                  ffi.Pointer<wire_cst_vodozemac_olm_session_config>,
+                  ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>,
+                  ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_create_outbound_session');
+  late final _wire__crate__bindings__vodozemac_account_create_outbound_session =
+      _wire__crate__bindings__vodozemac_account_create_outbound_sessionPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_account>,
+              ffi.Pointer<wire_cst_vodozemac_olm_session_config>,
+              ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>,
+              ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_create_outbound_session_from_claim(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+    ffi.Pointer<wire_cst_vodozemac_olm_session_config> config,
+    ffi.Pointer<wire_cst_device_keys> device_keys,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> claimed_key_json,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_create_outbound_session_from_claim(
+      that,
+      config,
+      device_keys,
+      claimed_key_json,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_create_outbound_session_from_claimPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_account>,
+                  ffi.Pointer<wire_cst_vodozemac_olm_session_config>,
+                  ffi.Pointer<wire_cst_device_keys>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_create_outbound_session_from_claim');
+  late final _wire__crate__bindings__vodozemac_account_create_outbound_session_from_claim =
+      _wire__crate__bindings__vodozemac_account_create_outbound_session_from_claimPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_account>,
+              ffi.Pointer<wire_cst_vodozemac_olm_session_config>,
+              ffi.Pointer<wire_cst_device_keys>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_curve25519_key(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_curve25519_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_curve25519_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_curve25519_key');
+  late final _wire__crate__bindings__vodozemac_account_curve25519_key =
+      _wire__crate__bindings__vodozemac_account_curve25519_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_ed25519_key(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_ed25519_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_ed25519_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_ed25519_key');
+  late final _wire__crate__bindings__vodozemac_account_ed25519_key =
+      _wire__crate__bindings__vodozemac_account_ed25519_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_fallback_key(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_fallback_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_fallback_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_fallback_key');
+  late final _wire__crate__bindings__vodozemac_account_fallback_key =
+      _wire__crate__bindings__vodozemac_account_fallback_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_forget_fallback_key(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_forget_fallback_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_forget_fallback_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_forget_fallback_key');
+  late final _wire__crate__bindings__vodozemac_account_forget_fallback_key =
+      _wire__crate__bindings__vodozemac_account_forget_fallback_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_from_any_pickle(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_from_any_pickle(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_from_any_picklePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_from_any_pickle');
+  late final _wire__crate__bindings__vodozemac_account_from_any_pickle =
+      _wire__crate__bindings__vodozemac_account_from_any_picklePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_from_olm_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted');
+  late final _wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted =
+      _wire__crate__bindings__vodozemac_account_from_olm_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_from_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_from_pickle_encrypted(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_from_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_from_pickle_encrypted');
+  late final _wire__crate__bindings__vodozemac_account_from_pickle_encrypted =
+      _wire__crate__bindings__vodozemac_account_from_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_from_pickle_with_passphrase(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_from_pickle_with_passphrase(
+      pickle,
+      passphrase,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_from_pickle_with_passphrasePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_from_pickle_with_passphrase');
+  late final _wire__crate__bindings__vodozemac_account_from_pickle_with_passphrase =
+      _wire__crate__bindings__vodozemac_account_from_pickle_with_passphrasePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_generate_fallback_key(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_generate_fallback_key(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_generate_fallback_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_generate_fallback_key');
+  late final _wire__crate__bindings__vodozemac_account_generate_fallback_key =
+      _wire__crate__bindings__vodozemac_account_generate_fallback_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_generate_one_time_keys(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+    int count,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_generate_one_time_keys(
+      that,
+      count,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_generate_one_time_keysPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>, ffi.UintPtr)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_generate_one_time_keys');
+  late final _wire__crate__bindings__vodozemac_account_generate_one_time_keys =
+      _wire__crate__bindings__vodozemac_account_generate_one_time_keysPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_identity_keys(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_identity_keys(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_identity_keysPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_identity_keys');
+  late final _wire__crate__bindings__vodozemac_account_identity_keys =
+      _wire__crate__bindings__vodozemac_account_identity_keysPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_mark_keys_as_published(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_mark_keys_as_published(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_mark_keys_as_publishedPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_mark_keys_as_published');
+  late final _wire__crate__bindings__vodozemac_account_mark_keys_as_published =
+      _wire__crate__bindings__vodozemac_account_mark_keys_as_publishedPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_max_number_of_one_time_keysPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys');
+  late final _wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys =
+      _wire__crate__bindings__vodozemac_account_max_number_of_one_time_keysPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_new() {
+    return _wire__crate__bindings__vodozemac_account_new();
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_newPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_new');
+  late final _wire__crate__bindings__vodozemac_account_new =
+      _wire__crate__bindings__vodozemac_account_newPtr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_one_time_keys(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_one_time_keys(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_one_time_keysPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_one_time_keys');
+  late final _wire__crate__bindings__vodozemac_account_one_time_keys =
+      _wire__crate__bindings__vodozemac_account_one_time_keysPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_pickle_encrypted(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_pickle_encrypted(
+      that,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_account>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_pickle_encrypted');
+  late final _wire__crate__bindings__vodozemac_account_pickle_encrypted =
+      _wire__crate__bindings__vodozemac_account_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_account>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_pickle_with_passphrase(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase,
+    ffi.Pointer<wire_cst_passphrase_kdf_params> params,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_pickle_with_passphrase(
+      that,
+      passphrase,
+      params,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_pickle_with_passphrasePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_passphrase_kdf_params>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_pickle_with_passphrase');
+  late final _wire__crate__bindings__vodozemac_account_pickle_with_passphrase =
+      _wire__crate__bindings__vodozemac_account_pickle_with_passphrasePtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_passphrase_kdf_params>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_plan_key_maintenance(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+    ffi.Pointer<wire_cst_fallback_key_tracker> tracker,
+    ffi.Pointer<wire_cst_list_record_string_u_32> server_counts,
+    ffi.Pointer<wire_cst_list_String> unused_fallback_types,
+    int now_ms,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_plan_key_maintenance(
+      that,
+      tracker,
+      server_counts,
+      unused_fallback_types,
+      now_ms,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_plan_key_maintenancePtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_account>,
+              ffi.Pointer<wire_cst_fallback_key_tracker>,
+              ffi.Pointer<wire_cst_list_record_string_u_32>,
+              ffi.Pointer<wire_cst_list_String>,
+              ffi.Uint64)>>('frbgen_vodozemac_wire__crate__bindings__vodozemac_account_plan_key_maintenance');
+  late final _wire__crate__bindings__vodozemac_account_plan_key_maintenance =
+      _wire__crate__bindings__vodozemac_account_plan_key_maintenancePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_account>,
+              ffi.Pointer<wire_cst_fallback_key_tracker>,
+              ffi.Pointer<wire_cst_list_record_string_u_32>,
+              ffi.Pointer<wire_cst_list_String>,
+              int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_remove_one_time_key(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_remove_one_time_key(
+      that,
+      public_key,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_remove_one_time_keyPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_account>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_remove_one_time_key');
+  late final _wire__crate__bindings__vodozemac_account_remove_one_time_key =
+      _wire__crate__bindings__vodozemac_account_remove_one_time_keyPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_account>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_sign(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_sign(
+      that,
+      message,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_signPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_account>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_sign');
+  late final _wire__crate__bindings__vodozemac_account_sign =
+      _wire__crate__bindings__vodozemac_account_signPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_account>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_unwedge_session(
+    ffi.Pointer<wire_cst_vodozemac_account> that,
+    ffi.Pointer<wire_cst_unwedge_tracker> tracker,
+    ffi.Pointer<wire_cst_vodozemac_olm_session_config> config,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> own_user_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> own_device_id,
+    ffi.Pointer<wire_cst_device_keys> device_keys,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> claimed_key_json,
+  ) {
+    return _wire__crate__bindings__vodozemac_account_unwedge_session(
+      that,
+      tracker,
+      config,
+      own_user_id,
+      own_device_id,
+      device_keys,
+      claimed_key_json,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_account_unwedge_sessionPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_account>,
+                  ffi.Pointer<wire_cst_unwedge_tracker>,
+                  ffi.Pointer<wire_cst_vodozemac_olm_session_config>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_device_keys>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_unwedge_session');
+  late final _wire__crate__bindings__vodozemac_account_unwedge_session =
+      _wire__crate__bindings__vodozemac_account_unwedge_sessionPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_account>,
+              ffi.Pointer<wire_cst_unwedge_tracker>,
+              ffi.Pointer<wire_cst_vodozemac_olm_session_config>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_device_keys>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes(
+    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytesPtr = _lookup<
+          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes');
+  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes =
+      _wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytesPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> base64_key,
+  ) {
+    return _wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64(
+      base64_key,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64Ptr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64');
+  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64 =
+      _wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64Ptr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> bytes,
+  ) {
+    return _wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice(
+      bytes,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_from_slicePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice');
+  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice =
+      _wire__crate__bindings__vodozemac_curve_25519_public_key_from_slicePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64(
+    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64Ptr = _lookup<
+          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64');
+  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64 =
+      _wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64Ptr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes(
+    ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytesPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes');
+  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes =
+      _wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytesPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> base64_key,
+  ) {
+    return _wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64(
+      base64_key,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64Ptr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64');
+  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64 =
+      _wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64Ptr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> bytes,
+  ) {
+    return _wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice(
+      bytes,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_from_slicePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice');
+  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice =
+      _wire__crate__bindings__vodozemac_ed_25519_public_key_from_slicePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64(
+    ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64Ptr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64');
+  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64 =
+      _wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64Ptr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_public_key_verify(
+    ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
+    ffi.Pointer<wire_cst_vodozemac_ed_25519_signature> signature,
   ) {
-    return _wire__crate__bindings__vodozemac_account_create_inbound_session(
+    return _wire__crate__bindings__vodozemac_ed_25519_public_key_verify(
       that,
-      their_identity_key,
-      pre_key_message_base64,
+      message,
+      signature,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_verifyPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_ed_25519_signature>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_public_key_verify');
+  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_verify =
+      _wire__crate__bindings__vodozemac_ed_25519_public_key_verifyPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_ed_25519_signature>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_signature_from_base64(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> signature,
+  ) {
+    return _wire__crate__bindings__vodozemac_ed_25519_signature_from_base64(
+      signature,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_ed_25519_signature_from_base64Ptr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_signature_from_base64');
+  late final _wire__crate__bindings__vodozemac_ed_25519_signature_from_base64 =
+      _wire__crate__bindings__vodozemac_ed_25519_signature_from_base64Ptr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_signature_from_slice(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> bytes,
+  ) {
+    return _wire__crate__bindings__vodozemac_ed_25519_signature_from_slice(
+      bytes,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_ed_25519_signature_from_slicePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_signature_from_slice');
+  late final _wire__crate__bindings__vodozemac_ed_25519_signature_from_slice =
+      _wire__crate__bindings__vodozemac_ed_25519_signature_from_slicePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_signature_to_base64(
+    ffi.Pointer<wire_cst_vodozemac_ed_25519_signature> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_ed_25519_signature_to_base64(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_ed_25519_signature_to_base64Ptr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_signature>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_signature_to_base64');
+  late final _wire__crate__bindings__vodozemac_ed_25519_signature_to_base64 =
+      _wire__crate__bindings__vodozemac_ed_25519_signature_to_base64Ptr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_signature>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes(
+    ffi.Pointer<wire_cst_vodozemac_ed_25519_signature> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_ed_25519_signature_to_bytesPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_signature>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes');
+  late final _wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes =
+      _wire__crate__bindings__vodozemac_ed_25519_signature_to_bytesPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_signature>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_established_sas_calculate_mac(
+    ffi.Pointer<wire_cst_vodozemac_established_sas> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> input,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> info,
+  ) {
+    return _wire__crate__bindings__vodozemac_established_sas_calculate_mac(
+      that,
+      input,
+      info,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_established_sas_calculate_macPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_established_sas>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_established_sas_calculate_mac');
+  late final _wire__crate__bindings__vodozemac_established_sas_calculate_mac =
+      _wire__crate__bindings__vodozemac_established_sas_calculate_macPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_established_sas>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated(
+    ffi.Pointer<wire_cst_vodozemac_established_sas> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> input,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> info,
+  ) {
+    return _wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated(
+      that,
+      input,
+      info,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecatedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_established_sas>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated');
+  late final _wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated =
+      _wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecatedPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_established_sas>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_established_sas_generate_bytes(
+    ffi.Pointer<wire_cst_vodozemac_established_sas> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> info,
+    int length,
+  ) {
+    return _wire__crate__bindings__vodozemac_established_sas_generate_bytes(
+      that,
+      info,
+      length,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_established_sas_generate_bytesPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_established_sas>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Uint32)>>('frbgen_vodozemac_wire__crate__bindings__vodozemac_established_sas_generate_bytes');
+  late final _wire__crate__bindings__vodozemac_established_sas_generate_bytes =
+      _wire__crate__bindings__vodozemac_established_sas_generate_bytesPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_established_sas>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_established_sas_verify_mac(
+    ffi.Pointer<wire_cst_vodozemac_established_sas> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> input,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> info,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> mac,
+  ) {
+    return _wire__crate__bindings__vodozemac_established_sas_verify_mac(
+      that,
+      input,
+      info,
+      mac,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_established_sas_verify_macPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_established_sas>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_established_sas_verify_mac');
+  late final _wire__crate__bindings__vodozemac_established_sas_verify_mac =
+      _wire__crate__bindings__vodozemac_established_sas_verify_macPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_established_sas>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_encrypt(
+    ffi.Pointer<wire_cst_vodozemac_group_session> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext,
+  ) {
+    return _wire__crate__bindings__vodozemac_group_session_encrypt(
+      that,
+      plaintext,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_group_session_encryptPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_encrypt');
+  late final _wire__crate__bindings__vodozemac_group_session_encrypt =
+      _wire__crate__bindings__vodozemac_group_session_encryptPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_from_any_pickle(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
+  ) {
+    return _wire__crate__bindings__vodozemac_group_session_from_any_pickle(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_group_session_from_any_picklePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_from_any_pickle');
+  late final _wire__crate__bindings__vodozemac_group_session_from_any_pickle =
+      _wire__crate__bindings__vodozemac_group_session_from_any_picklePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
+  ) {
+    return _wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted');
+  late final _wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted =
+      _wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted(
+      pickle,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_group_session_from_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted');
+  late final _wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted =
+      _wire__crate__bindings__vodozemac_group_session_from_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_from_pickle_with_passphrase(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase,
+  ) {
+    return _wire__crate__bindings__vodozemac_group_session_from_pickle_with_passphrase(
+      pickle,
+      passphrase,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_create_inbound_sessionPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_group_session_from_pickle_with_passphrasePtr = _lookup<
           ffi.NativeFunction<
-              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>,
-                  ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_create_inbound_session');
-  late final _wire__crate__bindings__vodozemac_account_create_inbound_session =
-      _wire__crate__bindings__vodozemac_account_create_inbound_sessionPtr.asFunction<
-          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>,
-              ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_from_pickle_with_passphrase');
+  late final _wire__crate__bindings__vodozemac_group_session_from_pickle_with_passphrase =
+      _wire__crate__bindings__vodozemac_group_session_from_pickle_with_passphrasePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_create_outbound_session(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
-    ffi.Pointer<wire_cst_vodozemac_olm_session_config> config,
-    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> identity_key,
-    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> one_time_key,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_message_index(
+    ffi.Pointer<wire_cst_vodozemac_group_session> that,
   ) {
-    return _wire__crate__bindings__vodozemac_account_create_outbound_session(
+    return _wire__crate__bindings__vodozemac_group_session_message_index(
       that,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_group_session_message_indexPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_message_index');
+  late final _wire__crate__bindings__vodozemac_group_session_message_index =
+      _wire__crate__bindings__vodozemac_group_session_message_indexPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_new(
+    ffi.Pointer<wire_cst_vodozemac_megolm_session_config> config,
+  ) {
+    return _wire__crate__bindings__vodozemac_group_session_new(
       config,
-      identity_key,
-      one_time_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_create_outbound_sessionPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_group_session_newPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_new');
+  late final _wire__crate__bindings__vodozemac_group_session_new =
+      _wire__crate__bindings__vodozemac_group_session_newPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_pickle_encrypted(
+    ffi.Pointer<wire_cst_vodozemac_group_session> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  ) {
+    return _wire__crate__bindings__vodozemac_group_session_pickle_encrypted(
+      that,
+      pickle_key,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_group_session_pickle_encryptedPtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_account>,
-                  ffi.Pointer<wire_cst_vodozemac_olm_session_config>,
-                  ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>,
-                  ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_create_outbound_session');
-  late final _wire__crate__bindings__vodozemac_account_create_outbound_session =
-      _wire__crate__bindings__vodozemac_account_create_outbound_sessionPtr.asFunction<
+                  ffi.Pointer<wire_cst_vodozemac_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_pickle_encrypted');
+  late final _wire__crate__bindings__vodozemac_group_session_pickle_encrypted =
+      _wire__crate__bindings__vodozemac_group_session_pickle_encryptedPtr.asFunction<
           WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_account>,
-              ffi.Pointer<wire_cst_vodozemac_olm_session_config>,
-              ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>,
-              ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>();
+              ffi.Pointer<wire_cst_vodozemac_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_curve25519_key(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_pickle_with_passphrase(
+    ffi.Pointer<wire_cst_vodozemac_group_session> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase,
+    ffi.Pointer<wire_cst_passphrase_kdf_params> params,
   ) {
-    return _wire__crate__bindings__vodozemac_account_curve25519_key(
+    return _wire__crate__bindings__vodozemac_group_session_pickle_with_passphrase(
       that,
+      passphrase,
+      params,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_curve25519_keyPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_curve25519_key');
-  late final _wire__crate__bindings__vodozemac_account_curve25519_key =
-      _wire__crate__bindings__vodozemac_account_curve25519_keyPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+  late final _wire__crate__bindings__vodozemac_group_session_pickle_with_passphrasePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_passphrase_kdf_params>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_pickle_with_passphrase');
+  late final _wire__crate__bindings__vodozemac_group_session_pickle_with_passphrase =
+      _wire__crate__bindings__vodozemac_group_session_pickle_with_passphrasePtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_passphrase_kdf_params>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_ed25519_key(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_session_config(
+    ffi.Pointer<wire_cst_vodozemac_group_session> that,
   ) {
-    return _wire__crate__bindings__vodozemac_account_ed25519_key(
+    return _wire__crate__bindings__vodozemac_group_session_session_config(
       that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_ed25519_keyPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_ed25519_key');
-  late final _wire__crate__bindings__vodozemac_account_ed25519_key =
-      _wire__crate__bindings__vodozemac_account_ed25519_keyPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+  late final _wire__crate__bindings__vodozemac_group_session_session_configPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_session_config');
+  late final _wire__crate__bindings__vodozemac_group_session_session_config =
+      _wire__crate__bindings__vodozemac_group_session_session_configPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_fallback_key(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_session_id(
+    ffi.Pointer<wire_cst_vodozemac_group_session> that,
   ) {
-    return _wire__crate__bindings__vodozemac_account_fallback_key(
+    return _wire__crate__bindings__vodozemac_group_session_session_id(
       that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_fallback_keyPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_fallback_key');
-  late final _wire__crate__bindings__vodozemac_account_fallback_key =
-      _wire__crate__bindings__vodozemac_account_fallback_keyPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+  late final _wire__crate__bindings__vodozemac_group_session_session_idPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_session_id');
+  late final _wire__crate__bindings__vodozemac_group_session_session_id =
+      _wire__crate__bindings__vodozemac_group_session_session_idPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_forget_fallback_key(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_session_key(
+    ffi.Pointer<wire_cst_vodozemac_group_session> that,
   ) {
-    return _wire__crate__bindings__vodozemac_account_forget_fallback_key(
+    return _wire__crate__bindings__vodozemac_group_session_session_key(
       that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_forget_fallback_keyPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_forget_fallback_key');
-  late final _wire__crate__bindings__vodozemac_account_forget_fallback_key =
-      _wire__crate__bindings__vodozemac_account_forget_fallback_keyPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+  late final _wire__crate__bindings__vodozemac_group_session_session_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_session_key');
+  late final _wire__crate__bindings__vodozemac_group_session_session_key =
+      _wire__crate__bindings__vodozemac_group_session_session_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_to_inbound(
+    ffi.Pointer<wire_cst_vodozemac_group_session> that,
   ) {
-    return _wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted(
-      pickle,
-      pickle_key,
+    return _wire__crate__bindings__vodozemac_group_session_to_inbound(
+      that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_from_olm_pickle_encryptedPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_group_session_to_inboundPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_to_inbound');
+  late final _wire__crate__bindings__vodozemac_group_session_to_inbound =
+      _wire__crate__bindings__vodozemac_group_session_to_inboundPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_decrypt(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> encrypted,
+  ) {
+    return _wire__crate__bindings__vodozemac_inbound_group_session_decrypt(
+      that,
+      encrypted,
+    );
+  }
+
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_decryptPtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted');
-  late final _wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted =
-      _wire__crate__bindings__vodozemac_account_from_olm_pickle_encryptedPtr.asFunction<
+                  ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_decrypt');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_decrypt =
+      _wire__crate__bindings__vodozemac_inbound_group_session_decryptPtr.asFunction<
           WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+              ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_from_pickle_encrypted(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_decrypt_extended(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> encrypted,
   ) {
-    return _wire__crate__bindings__vodozemac_account_from_pickle_encrypted(
-      pickle,
-      pickle_key,
+    return _wire__crate__bindings__vodozemac_inbound_group_session_decrypt_extended(
+      that,
+      encrypted,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_from_pickle_encryptedPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_decrypt_extendedPtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_from_pickle_encrypted');
-  late final _wire__crate__bindings__vodozemac_account_from_pickle_encrypted =
-      _wire__crate__bindings__vodozemac_account_from_pickle_encryptedPtr.asFunction<
+                  ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_decrypt_extended');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_decrypt_extended =
+      _wire__crate__bindings__vodozemac_inbound_group_session_decrypt_extendedPtr.asFunction<
           WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+              ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_generate_fallback_key(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_discard_before(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
+    int index,
   ) {
-    return _wire__crate__bindings__vodozemac_account_generate_fallback_key(
+    return _wire__crate__bindings__vodozemac_inbound_group_session_discard_before(
       that,
+      index,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_generate_fallback_keyPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_generate_fallback_key');
-  late final _wire__crate__bindings__vodozemac_account_generate_fallback_key =
-      _wire__crate__bindings__vodozemac_account_generate_fallback_keyPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_discard_beforePtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>,
+              ffi.Uint32)>>('frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_discard_before');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_discard_before =
+      _wire__crate__bindings__vodozemac_inbound_group_session_discard_beforePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, int)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_generate_one_time_keys(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
-    int count,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_export_at(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
+    int index,
   ) {
-    return _wire__crate__bindings__vodozemac_account_generate_one_time_keys(
+    return _wire__crate__bindings__vodozemac_inbound_group_session_export_at(
       that,
-      count,
+      index,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_generate_one_time_keysPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>, ffi.UintPtr)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_generate_one_time_keys');
-  late final _wire__crate__bindings__vodozemac_account_generate_one_time_keys =
-      _wire__crate__bindings__vodozemac_account_generate_one_time_keysPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>, int)>();
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_export_atPtr = _lookup<
+      ffi.NativeFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>,
+              ffi.Uint32)>>('frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_export_at');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_export_at =
+      _wire__crate__bindings__vodozemac_inbound_group_session_export_atPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, int)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_identity_keys(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
   ) {
-    return _wire__crate__bindings__vodozemac_account_identity_keys(
+    return _wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index(
       that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_identity_keysPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_identity_keys');
-  late final _wire__crate__bindings__vodozemac_account_identity_keys =
-      _wire__crate__bindings__vodozemac_account_identity_keysPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_indexPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index =
+      _wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_indexPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_mark_keys_as_published(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_first_known_index(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
   ) {
-    return _wire__crate__bindings__vodozemac_account_mark_keys_as_published(
+    return _wire__crate__bindings__vodozemac_inbound_group_session_first_known_index(
       that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_mark_keys_as_publishedPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_mark_keys_as_published');
-  late final _wire__crate__bindings__vodozemac_account_mark_keys_as_published =
-      _wire__crate__bindings__vodozemac_account_mark_keys_as_publishedPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_first_known_indexPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_first_known_index');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_first_known_index =
+      _wire__crate__bindings__vodozemac_inbound_group_session_first_known_indexPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_from_any_pickle(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
   ) {
-    return _wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys(
-      that,
+    return _wire__crate__bindings__vodozemac_inbound_group_session_from_any_pickle(
+      pickle,
+      pickle_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_max_number_of_one_time_keysPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys');
-  late final _wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys =
-      _wire__crate__bindings__vodozemac_account_max_number_of_one_time_keysPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
-
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_new() {
-    return _wire__crate__bindings__vodozemac_account_new();
-  }
-
-  late final _wire__crate__bindings__vodozemac_account_newPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_new');
-  late final _wire__crate__bindings__vodozemac_account_new =
-      _wire__crate__bindings__vodozemac_account_newPtr.asFunction<WireSyncRust2DartDco Function()>();
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_from_any_picklePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_from_any_pickle');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_from_any_pickle =
+      _wire__crate__bindings__vodozemac_inbound_group_session_from_any_picklePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_one_time_keys(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
   ) {
-    return _wire__crate__bindings__vodozemac_account_one_time_keys(
-      that,
+    return _wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted(
+      pickle,
+      pickle_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_one_time_keysPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_one_time_keys');
-  late final _wire__crate__bindings__vodozemac_account_one_time_keys =
-      _wire__crate__bindings__vodozemac_account_one_time_keysPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_account>)>();
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted =
+      _wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_pickle_encrypted(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
     ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
   ) {
-    return _wire__crate__bindings__vodozemac_account_pickle_encrypted(
-      that,
+    return _wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted(
+      pickle,
       pickle_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_pickle_encryptedPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encryptedPtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_account>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_pickle_encrypted');
-  late final _wire__crate__bindings__vodozemac_account_pickle_encrypted =
-      _wire__crate__bindings__vodozemac_account_pickle_encryptedPtr.asFunction<
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted =
+      _wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encryptedPtr.asFunction<
           WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_account>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_remove_one_time_key(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_with_passphrase(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase,
   ) {
-    return _wire__crate__bindings__vodozemac_account_remove_one_time_key(
-      that,
-      public_key,
+    return _wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_with_passphrase(
+      pickle,
+      passphrase,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_remove_one_time_keyPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_with_passphrasePtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_account>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_remove_one_time_key');
-  late final _wire__crate__bindings__vodozemac_account_remove_one_time_key =
-      _wire__crate__bindings__vodozemac_account_remove_one_time_keyPtr.asFunction<
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_with_passphrase');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_with_passphrase =
+      _wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_with_passphrasePtr.asFunction<
           WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_account>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_account_sign(
-    ffi.Pointer<wire_cst_vodozemac_account> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_import(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> exported_session_key,
+    ffi.Pointer<wire_cst_vodozemac_megolm_session_config> config,
   ) {
-    return _wire__crate__bindings__vodozemac_account_sign(
-      that,
-      message,
+    return _wire__crate__bindings__vodozemac_inbound_group_session_import(
+      exported_session_key,
+      config,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_account_signPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_importPtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_account>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_account_sign');
-  late final _wire__crate__bindings__vodozemac_account_sign =
-      _wire__crate__bindings__vodozemac_account_signPtr.asFunction<
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_import');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_import =
+      _wire__crate__bindings__vodozemac_inbound_group_session_importPtr.asFunction<
           WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_account>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes(
-    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_key_source(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
   ) {
-    return _wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes(
+    return _wire__crate__bindings__vodozemac_inbound_group_session_key_source(
       that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytesPtr = _lookup<
-          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes');
-  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes =
-      _wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytesPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>();
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_key_sourcePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_key_source');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_key_source =
+      _wire__crate__bindings__vodozemac_inbound_group_session_key_sourcePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> base64_key,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_new(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> session_key,
+    ffi.Pointer<wire_cst_vodozemac_megolm_session_config> config,
   ) {
-    return _wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64(
-      base64_key,
+    return _wire__crate__bindings__vodozemac_inbound_group_session_new(
+      session_key,
+      config,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64Ptr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64');
-  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64 =
-      _wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64Ptr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_newPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_new');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_new =
+      _wire__crate__bindings__vodozemac_inbound_group_session_newPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> bytes,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
   ) {
-    return _wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice(
-      bytes,
+    return _wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted(
+      that,
+      pickle_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_from_slicePtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice');
-  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice =
-      _wire__crate__bindings__vodozemac_curve_25519_public_key_from_slicePtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted =
+      _wire__crate__bindings__vodozemac_inbound_group_session_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64(
-    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_pickle_with_passphrase(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase,
+    ffi.Pointer<wire_cst_passphrase_kdf_params> params,
   ) {
-    return _wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64(
+    return _wire__crate__bindings__vodozemac_inbound_group_session_pickle_with_passphrase(
       that,
+      passphrase,
+      params,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64Ptr = _lookup<
-          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64');
-  late final _wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64 =
-      _wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64Ptr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>();
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_pickle_with_passphrasePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_passphrase_kdf_params>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_pickle_with_passphrase');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_pickle_with_passphrase =
+      _wire__crate__bindings__vodozemac_inbound_group_session_pickle_with_passphrasePtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_passphrase_kdf_params>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes(
-    ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_session_id(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
   ) {
-    return _wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes(
+    return _wire__crate__bindings__vodozemac_inbound_group_session_session_id(
       that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytesPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes');
-  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes =
-      _wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytesPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>)>();
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_session_idPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_session_id');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_session_id =
+      _wire__crate__bindings__vodozemac_inbound_group_session_session_idPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> base64_key,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_signing_key(
+    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
   ) {
-    return _wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64(
-      base64_key,
+    return _wire__crate__bindings__vodozemac_inbound_group_session_signing_key(
+      that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64Ptr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64');
-  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64 =
-      _wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64Ptr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_signing_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_signing_key');
+  late final _wire__crate__bindings__vodozemac_inbound_group_session_signing_key =
+      _wire__crate__bindings__vodozemac_inbound_group_session_signing_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> bytes,
-  ) {
-    return _wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice(
-      bytes,
-    );
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_megolm_session_config_def() {
+    return _wire__crate__bindings__vodozemac_megolm_session_config_def();
   }
 
-  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_from_slicePtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice');
-  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice =
-      _wire__crate__bindings__vodozemac_ed_25519_public_key_from_slicePtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _wire__crate__bindings__vodozemac_megolm_session_config_defPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_megolm_session_config_def');
+  late final _wire__crate__bindings__vodozemac_megolm_session_config_def =
+      _wire__crate__bindings__vodozemac_megolm_session_config_defPtr.asFunction<WireSyncRust2DartDco Function()>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64(
-    ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_megolm_session_config_version(
+    ffi.Pointer<wire_cst_vodozemac_megolm_session_config> that,
   ) {
-    return _wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64(
+    return _wire__crate__bindings__vodozemac_megolm_session_config_version(
       that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64Ptr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64');
-  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64 =
-      _wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64Ptr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>)>();
+  late final _wire__crate__bindings__vodozemac_megolm_session_config_versionPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_megolm_session_config_version');
+  late final _wire__crate__bindings__vodozemac_megolm_session_config_version =
+      _wire__crate__bindings__vodozemac_megolm_session_config_versionPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_public_key_verify(
-    ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
-    ffi.Pointer<wire_cst_vodozemac_ed_25519_signature> signature,
-  ) {
-    return _wire__crate__bindings__vodozemac_ed_25519_public_key_verify(
-      that,
-      message,
-      signature,
-    );
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_megolm_session_config_version_1() {
+    return _wire__crate__bindings__vodozemac_megolm_session_config_version_1();
   }
 
-  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_verifyPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>,
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_ed_25519_signature>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_public_key_verify');
-  late final _wire__crate__bindings__vodozemac_ed_25519_public_key_verify =
-      _wire__crate__bindings__vodozemac_ed_25519_public_key_verifyPtr.asFunction<
-          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_public_key>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_ed_25519_signature>)>();
+  late final _wire__crate__bindings__vodozemac_megolm_session_config_version_1Ptr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_megolm_session_config_version_1');
+  late final _wire__crate__bindings__vodozemac_megolm_session_config_version_1 =
+      _wire__crate__bindings__vodozemac_megolm_session_config_version_1Ptr
+          .asFunction<WireSyncRust2DartDco Function()>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_signature_from_base64(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> signature,
-  ) {
-    return _wire__crate__bindings__vodozemac_ed_25519_signature_from_base64(
-      signature,
-    );
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_megolm_session_config_version_2() {
+    return _wire__crate__bindings__vodozemac_megolm_session_config_version_2();
   }
 
-  late final _wire__crate__bindings__vodozemac_ed_25519_signature_from_base64Ptr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_signature_from_base64');
-  late final _wire__crate__bindings__vodozemac_ed_25519_signature_from_base64 =
-      _wire__crate__bindings__vodozemac_ed_25519_signature_from_base64Ptr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _wire__crate__bindings__vodozemac_megolm_session_config_version_2Ptr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_megolm_session_config_version_2');
+  late final _wire__crate__bindings__vodozemac_megolm_session_config_version_2 =
+      _wire__crate__bindings__vodozemac_megolm_session_config_version_2Ptr
+          .asFunction<WireSyncRust2DartDco Function()>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_signature_from_slice(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> bytes,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_message_from_parts(
+    int message_type,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> ciphertext,
   ) {
-    return _wire__crate__bindings__vodozemac_ed_25519_signature_from_slice(
-      bytes,
+    return _wire__crate__bindings__vodozemac_olm_message_from_parts(
+      message_type,
+      ciphertext,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_ed_25519_signature_from_slicePtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_signature_from_slice');
-  late final _wire__crate__bindings__vodozemac_ed_25519_signature_from_slice =
-      _wire__crate__bindings__vodozemac_ed_25519_signature_from_slicePtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _wire__crate__bindings__vodozemac_olm_message_from_partsPtr = _lookup<
+          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_message_from_parts');
+  late final _wire__crate__bindings__vodozemac_olm_message_from_parts =
+      _wire__crate__bindings__vodozemac_olm_message_from_partsPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_signature_to_base64(
-    ffi.Pointer<wire_cst_vodozemac_ed_25519_signature> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_message_message(
+    ffi.Pointer<wire_cst_vodozemac_olm_message> that,
   ) {
-    return _wire__crate__bindings__vodozemac_ed_25519_signature_to_base64(
+    return _wire__crate__bindings__vodozemac_olm_message_message(
       that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_ed_25519_signature_to_base64Ptr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_signature>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_signature_to_base64');
-  late final _wire__crate__bindings__vodozemac_ed_25519_signature_to_base64 =
-      _wire__crate__bindings__vodozemac_ed_25519_signature_to_base64Ptr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_signature>)>();
+  late final _wire__crate__bindings__vodozemac_olm_message_messagePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_olm_message>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_message_message');
+  late final _wire__crate__bindings__vodozemac_olm_message_message =
+      _wire__crate__bindings__vodozemac_olm_message_messagePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_olm_message>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes(
-    ffi.Pointer<wire_cst_vodozemac_ed_25519_signature> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_message_message_type(
+    ffi.Pointer<wire_cst_vodozemac_olm_message> that,
   ) {
-    return _wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes(
+    return _wire__crate__bindings__vodozemac_olm_message_message_type(
       that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_ed_25519_signature_to_bytesPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_signature>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes');
-  late final _wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes =
-      _wire__crate__bindings__vodozemac_ed_25519_signature_to_bytesPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_ed_25519_signature>)>();
+  late final _wire__crate__bindings__vodozemac_olm_message_message_typePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_olm_message>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_message_message_type');
+  late final _wire__crate__bindings__vodozemac_olm_message_message_type =
+      _wire__crate__bindings__vodozemac_olm_message_message_typePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_olm_message>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_established_sas_calculate_mac(
-    ffi.Pointer<wire_cst_vodozemac_established_sas> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> input,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> info,
-  ) {
-    return _wire__crate__bindings__vodozemac_established_sas_calculate_mac(
-      that,
-      input,
-      info,
-    );
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_session_config_def() {
+    return _wire__crate__bindings__vodozemac_olm_session_config_def();
   }
 
-  late final _wire__crate__bindings__vodozemac_established_sas_calculate_macPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_established_sas>,
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_established_sas_calculate_mac');
-  late final _wire__crate__bindings__vodozemac_established_sas_calculate_mac =
-      _wire__crate__bindings__vodozemac_established_sas_calculate_macPtr.asFunction<
-          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_established_sas>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _wire__crate__bindings__vodozemac_olm_session_config_defPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_session_config_def');
+  late final _wire__crate__bindings__vodozemac_olm_session_config_def =
+      _wire__crate__bindings__vodozemac_olm_session_config_defPtr.asFunction<WireSyncRust2DartDco Function()>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated(
-    ffi.Pointer<wire_cst_vodozemac_established_sas> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> input,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> info,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_session_config_version(
+    ffi.Pointer<wire_cst_vodozemac_olm_session_config> that,
   ) {
-    return _wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated(
+    return _wire__crate__bindings__vodozemac_olm_session_config_version(
       that,
-      input,
-      info,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecatedPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_established_sas>,
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated');
-  late final _wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated =
-      _wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecatedPtr.asFunction<
-          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_established_sas>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _wire__crate__bindings__vodozemac_olm_session_config_versionPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_olm_session_config>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_session_config_version');
+  late final _wire__crate__bindings__vodozemac_olm_session_config_version =
+      _wire__crate__bindings__vodozemac_olm_session_config_versionPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_olm_session_config>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_established_sas_generate_bytes(
-    ffi.Pointer<wire_cst_vodozemac_established_sas> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> info,
-    int length,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_session_config_version_1() {
+    return _wire__crate__bindings__vodozemac_olm_session_config_version_1();
+  }
+
+  late final _wire__crate__bindings__vodozemac_olm_session_config_version_1Ptr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_session_config_version_1');
+  late final _wire__crate__bindings__vodozemac_olm_session_config_version_1 =
+      _wire__crate__bindings__vodozemac_olm_session_config_version_1Ptr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_session_config_version_2() {
+    return _wire__crate__bindings__vodozemac_olm_session_config_version_2();
+  }
+
+  late final _wire__crate__bindings__vodozemac_olm_session_config_version_2Ptr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_session_config_version_2');
+  late final _wire__crate__bindings__vodozemac_olm_session_config_version_2 =
+      _wire__crate__bindings__vodozemac_olm_session_config_version_2Ptr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_decrypt(
+    ffi.Pointer<wire_cst_vodozemac_pk_decryption> that,
+    ffi.Pointer<wire_cst_vodozemac_pk_message> message,
   ) {
-    return _wire__crate__bindings__vodozemac_established_sas_generate_bytes(
+    return _wire__crate__bindings__vodozemac_pk_decryption_decrypt(
       that,
-      info,
-      length,
+      message,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_established_sas_generate_bytesPtr = _lookup<
-      ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_established_sas>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Uint32)>>('frbgen_vodozemac_wire__crate__bindings__vodozemac_established_sas_generate_bytes');
-  late final _wire__crate__bindings__vodozemac_established_sas_generate_bytes =
-      _wire__crate__bindings__vodozemac_established_sas_generate_bytesPtr.asFunction<
+  late final _wire__crate__bindings__vodozemac_pk_decryption_decryptPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_pk_decryption>, ffi.Pointer<wire_cst_vodozemac_pk_message>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_decrypt');
+  late final _wire__crate__bindings__vodozemac_pk_decryption_decrypt =
+      _wire__crate__bindings__vodozemac_pk_decryption_decryptPtr.asFunction<
           WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_established_sas>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+              ffi.Pointer<wire_cst_vodozemac_pk_decryption>, ffi.Pointer<wire_cst_vodozemac_pk_message>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_established_sas_verify_mac(
-    ffi.Pointer<wire_cst_vodozemac_established_sas> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> input,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> info,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> mac,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_from_any_pickle(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
   ) {
-    return _wire__crate__bindings__vodozemac_established_sas_verify_mac(
-      that,
-      input,
-      info,
-      mac,
+    return _wire__crate__bindings__vodozemac_pk_decryption_from_any_pickle(
+      pickle,
+      pickle_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_established_sas_verify_macPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_pk_decryption_from_any_picklePtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_established_sas>,
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_established_sas_verify_mac');
-  late final _wire__crate__bindings__vodozemac_established_sas_verify_mac =
-      _wire__crate__bindings__vodozemac_established_sas_verify_macPtr.asFunction<
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_from_any_pickle');
+  late final _wire__crate__bindings__vodozemac_pk_decryption_from_any_pickle =
+      _wire__crate__bindings__vodozemac_pk_decryption_from_any_picklePtr.asFunction<
           WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_established_sas>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_encrypt(
-    ffi.Pointer<wire_cst_vodozemac_group_session> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_from_key(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key,
   ) {
-    return _wire__crate__bindings__vodozemac_group_session_encrypt(
-      that,
-      plaintext,
+    return _wire__crate__bindings__vodozemac_pk_decryption_from_key(
+      secret_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_group_session_encryptPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_encrypt');
-  late final _wire__crate__bindings__vodozemac_group_session_encrypt =
-      _wire__crate__bindings__vodozemac_group_session_encryptPtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _wire__crate__bindings__vodozemac_pk_decryption_from_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_from_key');
+  late final _wire__crate__bindings__vodozemac_pk_decryption_from_key =
+      _wire__crate__bindings__vodozemac_pk_decryption_from_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted(
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle(
     ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
     ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
   ) {
-    return _wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted(
+    return _wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle(
       pickle,
       pickle_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encryptedPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_pk_decryption_from_libolm_picklePtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
                   ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted');
-  late final _wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted =
-      _wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encryptedPtr.asFunction<
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle');
+  late final _wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle =
+      _wire__crate__bindings__vodozemac_pk_decryption_from_libolm_picklePtr.asFunction<
           WireSyncRust2DartDco Function(
               ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted(
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_from_pickle_with_passphrase(
     ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase,
   ) {
-    return _wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted(
+    return _wire__crate__bindings__vodozemac_pk_decryption_from_pickle_with_passphrase(
       pickle,
-      pickle_key,
+      passphrase,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_group_session_from_pickle_encryptedPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_pk_decryption_from_pickle_with_passphrasePtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
                   ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted');
-  late final _wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted =
-      _wire__crate__bindings__vodozemac_group_session_from_pickle_encryptedPtr.asFunction<
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_from_pickle_with_passphrase');
+  late final _wire__crate__bindings__vodozemac_pk_decryption_from_pickle_with_passphrase =
+      _wire__crate__bindings__vodozemac_pk_decryption_from_pickle_with_passphrasePtr.asFunction<
           WireSyncRust2DartDco Function(
               ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_message_index(
-    ffi.Pointer<wire_cst_vodozemac_group_session> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_new() {
+    return _wire__crate__bindings__vodozemac_pk_decryption_new();
+  }
+
+  late final _wire__crate__bindings__vodozemac_pk_decryption_newPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_new');
+  late final _wire__crate__bindings__vodozemac_pk_decryption_new =
+      _wire__crate__bindings__vodozemac_pk_decryption_newPtr.asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_pickle_with_passphrase(
+    ffi.Pointer<wire_cst_vodozemac_pk_decryption> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase,
+    ffi.Pointer<wire_cst_passphrase_kdf_params> params,
   ) {
-    return _wire__crate__bindings__vodozemac_group_session_message_index(
+    return _wire__crate__bindings__vodozemac_pk_decryption_pickle_with_passphrase(
       that,
+      passphrase,
+      params,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_group_session_message_indexPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_message_index');
-  late final _wire__crate__bindings__vodozemac_group_session_message_index =
-      _wire__crate__bindings__vodozemac_group_session_message_indexPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>();
+  late final _wire__crate__bindings__vodozemac_pk_decryption_pickle_with_passphrasePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_decryption>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_passphrase_kdf_params>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_pickle_with_passphrase');
+  late final _wire__crate__bindings__vodozemac_pk_decryption_pickle_with_passphrase =
+      _wire__crate__bindings__vodozemac_pk_decryption_pickle_with_passphrasePtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_decryption>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_passphrase_kdf_params>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_new(
-    ffi.Pointer<wire_cst_vodozemac_megolm_session_config> config,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_private_key(
+    ffi.Pointer<wire_cst_vodozemac_pk_decryption> that,
   ) {
-    return _wire__crate__bindings__vodozemac_group_session_new(
-      config,
+    return _wire__crate__bindings__vodozemac_pk_decryption_private_key(
+      that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_group_session_newPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_new');
-  late final _wire__crate__bindings__vodozemac_group_session_new =
-      _wire__crate__bindings__vodozemac_group_session_newPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>();
+  late final _wire__crate__bindings__vodozemac_pk_decryption_private_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_decryption>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_private_key');
+  late final _wire__crate__bindings__vodozemac_pk_decryption_private_key =
+      _wire__crate__bindings__vodozemac_pk_decryption_private_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_decryption>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_pickle_encrypted(
-    ffi.Pointer<wire_cst_vodozemac_group_session> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_public_key(
+    ffi.Pointer<wire_cst_vodozemac_pk_decryption> that,
   ) {
-    return _wire__crate__bindings__vodozemac_group_session_pickle_encrypted(
+    return _wire__crate__bindings__vodozemac_pk_decryption_public_key(
       that,
-      pickle_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_group_session_pickle_encryptedPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_pickle_encrypted');
-  late final _wire__crate__bindings__vodozemac_group_session_pickle_encrypted =
-      _wire__crate__bindings__vodozemac_group_session_pickle_encryptedPtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _wire__crate__bindings__vodozemac_pk_decryption_public_keyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_decryption>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_public_key');
+  late final _wire__crate__bindings__vodozemac_pk_decryption_public_key =
+      _wire__crate__bindings__vodozemac_pk_decryption_public_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_decryption>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_session_config(
-    ffi.Pointer<wire_cst_vodozemac_group_session> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle(
+    ffi.Pointer<wire_cst_vodozemac_pk_decryption> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
   ) {
-    return _wire__crate__bindings__vodozemac_group_session_session_config(
+    return _wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle(
       that,
+      pickle_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_group_session_session_configPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_session_config');
-  late final _wire__crate__bindings__vodozemac_group_session_session_config =
-      _wire__crate__bindings__vodozemac_group_session_session_configPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>();
+  late final _wire__crate__bindings__vodozemac_pk_decryption_to_libolm_picklePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_pk_decryption>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle');
+  late final _wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle =
+      _wire__crate__bindings__vodozemac_pk_decryption_to_libolm_picklePtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_pk_decryption>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_session_id(
-    ffi.Pointer<wire_cst_vodozemac_group_session> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_encryption_encrypt(
+    ffi.Pointer<wire_cst_vodozemac_pk_encryption> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
   ) {
-    return _wire__crate__bindings__vodozemac_group_session_session_id(
+    return _wire__crate__bindings__vodozemac_pk_encryption_encrypt(
       that,
+      message,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_group_session_session_idPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_session_id');
-  late final _wire__crate__bindings__vodozemac_group_session_session_id =
-      _wire__crate__bindings__vodozemac_group_session_session_idPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>();
+  late final _wire__crate__bindings__vodozemac_pk_encryption_encryptPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_pk_encryption>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_encryption_encrypt');
+  late final _wire__crate__bindings__vodozemac_pk_encryption_encrypt =
+      _wire__crate__bindings__vodozemac_pk_encryption_encryptPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_pk_encryption>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_session_key(
-    ffi.Pointer<wire_cst_vodozemac_group_session> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_encryption_from_key(
+    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> public_key,
   ) {
-    return _wire__crate__bindings__vodozemac_group_session_session_key(
-      that,
+    return _wire__crate__bindings__vodozemac_pk_encryption_from_key(
+      public_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_group_session_session_keyPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_session_key');
-  late final _wire__crate__bindings__vodozemac_group_session_session_key =
-      _wire__crate__bindings__vodozemac_group_session_session_keyPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>();
+  late final _wire__crate__bindings__vodozemac_pk_encryption_from_keyPtr = _lookup<
+          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_encryption_from_key');
+  late final _wire__crate__bindings__vodozemac_pk_encryption_from_key =
+      _wire__crate__bindings__vodozemac_pk_encryption_from_keyPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_group_session_to_inbound(
-    ffi.Pointer<wire_cst_vodozemac_group_session> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_message_from_base64(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> ciphertext,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> mac,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> ephemeral_key,
   ) {
-    return _wire__crate__bindings__vodozemac_group_session_to_inbound(
-      that,
+    return _wire__crate__bindings__vodozemac_pk_message_from_base64(
+      ciphertext,
+      mac,
+      ephemeral_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_group_session_to_inboundPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_group_session_to_inbound');
-  late final _wire__crate__bindings__vodozemac_group_session_to_inbound =
-      _wire__crate__bindings__vodozemac_group_session_to_inboundPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_group_session>)>();
+  late final _wire__crate__bindings__vodozemac_pk_message_from_base64Ptr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_message_from_base64');
+  late final _wire__crate__bindings__vodozemac_pk_message_from_base64 =
+      _wire__crate__bindings__vodozemac_pk_message_from_base64Ptr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_decrypt(
-    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> encrypted,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_message_new(
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> ciphertext,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> mac,
+    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> ephemeral_key,
   ) {
-    return _wire__crate__bindings__vodozemac_inbound_group_session_decrypt(
-      that,
-      encrypted,
+    return _wire__crate__bindings__vodozemac_pk_message_new(
+      ciphertext,
+      mac,
+      ephemeral_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_decryptPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_pk_message_newPtr = _lookup<
           ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_decrypt');
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_decrypt =
-      _wire__crate__bindings__vodozemac_inbound_group_session_decryptPtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_message_new');
+  late final _wire__crate__bindings__vodozemac_pk_message_new =
+      _wire__crate__bindings__vodozemac_pk_message_newPtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>,
+              ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_export_at(
-    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
-    int index,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_message_to_base64(
+    ffi.Pointer<wire_cst_vodozemac_pk_message> that,
   ) {
-    return _wire__crate__bindings__vodozemac_inbound_group_session_export_at(
+    return _wire__crate__bindings__vodozemac_pk_message_to_base64(
       that,
-      index,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_export_atPtr = _lookup<
-      ffi.NativeFunction<
-          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>,
-              ffi.Uint32)>>('frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_export_at');
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_export_at =
-      _wire__crate__bindings__vodozemac_inbound_group_session_export_atPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, int)>();
+  late final _wire__crate__bindings__vodozemac_pk_message_to_base64Ptr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_message>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_message_to_base64');
+  late final _wire__crate__bindings__vodozemac_pk_message_to_base64 =
+      _wire__crate__bindings__vodozemac_pk_message_to_base64Ptr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_message>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index(
-    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_decrypt(
+    ffi.Pointer<wire_cst_vodozemac_session> that,
+    ffi.Pointer<wire_cst_vodozemac_olm_message> message,
   ) {
-    return _wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index(
+    return _wire__crate__bindings__vodozemac_session_decrypt(
       that,
+      message,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_indexPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index');
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index =
-      _wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_indexPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>();
+  late final _wire__crate__bindings__vodozemac_session_decryptPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_session>, ffi.Pointer<wire_cst_vodozemac_olm_message>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_decrypt');
+  late final _wire__crate__bindings__vodozemac_session_decrypt =
+      _wire__crate__bindings__vodozemac_session_decryptPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_session>, ffi.Pointer<wire_cst_vodozemac_olm_message>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_first_known_index(
-    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_encrypt(
+    ffi.Pointer<wire_cst_vodozemac_session> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext,
   ) {
-    return _wire__crate__bindings__vodozemac_inbound_group_session_first_known_index(
+    return _wire__crate__bindings__vodozemac_session_encrypt(
       that,
+      plaintext,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_first_known_indexPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_first_known_index');
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_first_known_index =
-      _wire__crate__bindings__vodozemac_inbound_group_session_first_known_indexPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>();
+  late final _wire__crate__bindings__vodozemac_session_encryptPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_encrypt');
+  late final _wire__crate__bindings__vodozemac_session_encrypt =
+      _wire__crate__bindings__vodozemac_session_encryptPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted(
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_from_any_pickle(
     ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
     ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
   ) {
-    return _wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted(
+    return _wire__crate__bindings__vodozemac_session_from_any_pickle(
       pickle,
       pickle_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encryptedPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_session_from_any_picklePtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
                   ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted');
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted =
-      _wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encryptedPtr.asFunction<
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_from_any_pickle');
+  late final _wire__crate__bindings__vodozemac_session_from_any_pickle =
+      _wire__crate__bindings__vodozemac_session_from_any_picklePtr.asFunction<
           WireSyncRust2DartDco Function(
               ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted(
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted(
     ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+    ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
   ) {
-    return _wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted(
+    return _wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted(
       pickle,
       pickle_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encryptedPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted');
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted =
-      _wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encryptedPtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
-
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_import(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> exported_session_key,
-    ffi.Pointer<wire_cst_vodozemac_megolm_session_config> config,
-  ) {
-    return _wire__crate__bindings__vodozemac_inbound_group_session_import(
-      exported_session_key,
-      config,
-    );
-  }
-
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_importPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_session_from_olm_pickle_encryptedPtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_import');
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_import =
-      _wire__crate__bindings__vodozemac_inbound_group_session_importPtr.asFunction<
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted');
+  late final _wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted =
+      _wire__crate__bindings__vodozemac_session_from_olm_pickle_encryptedPtr.asFunction<
           WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>();
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_new(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> session_key,
-    ffi.Pointer<wire_cst_vodozemac_megolm_session_config> config,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_from_pickle_encrypted(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
   ) {
-    return _wire__crate__bindings__vodozemac_inbound_group_session_new(
-      session_key,
-      config,
+    return _wire__crate__bindings__vodozemac_session_from_pickle_encrypted(
+      pickle,
+      pickle_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_newPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_session_from_pickle_encryptedPtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_new');
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_new =
-      _wire__crate__bindings__vodozemac_inbound_group_session_newPtr.asFunction<
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_from_pickle_encrypted');
+  late final _wire__crate__bindings__vodozemac_session_from_pickle_encrypted =
+      _wire__crate__bindings__vodozemac_session_from_pickle_encryptedPtr.asFunction<
           WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>();
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted(
-    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_from_pickle_with_passphrase(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase,
   ) {
-    return _wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted(
-      that,
-      pickle_key,
+    return _wire__crate__bindings__vodozemac_session_from_pickle_with_passphrase(
+      pickle,
+      passphrase,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_pickle_encryptedPtr = _lookup<
+  late final _wire__crate__bindings__vodozemac_session_from_pickle_with_passphrasePtr = _lookup<
           ffi.NativeFunction<
               WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted');
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted =
-      _wire__crate__bindings__vodozemac_inbound_group_session_pickle_encryptedPtr.asFunction<
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_from_pickle_with_passphrase');
+  late final _wire__crate__bindings__vodozemac_session_from_pickle_with_passphrase =
+      _wire__crate__bindings__vodozemac_session_from_pickle_with_passphrasePtr.asFunction<
           WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_inbound_group_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
-
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_inbound_group_session_session_id(
-    ffi.Pointer<wire_cst_vodozemac_inbound_group_session> that,
-  ) {
-    return _wire__crate__bindings__vodozemac_inbound_group_session_session_id(
-      that,
-    );
-  }
-
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_session_idPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_inbound_group_session_session_id');
-  late final _wire__crate__bindings__vodozemac_inbound_group_session_session_id =
-      _wire__crate__bindings__vodozemac_inbound_group_session_session_idPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_inbound_group_session>)>();
-
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_megolm_session_config_def() {
-    return _wire__crate__bindings__vodozemac_megolm_session_config_def();
-  }
-
-  late final _wire__crate__bindings__vodozemac_megolm_session_config_defPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_megolm_session_config_def');
-  late final _wire__crate__bindings__vodozemac_megolm_session_config_def =
-      _wire__crate__bindings__vodozemac_megolm_session_config_defPtr.asFunction<WireSyncRust2DartDco Function()>();
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_megolm_session_config_version(
-    ffi.Pointer<wire_cst_vodozemac_megolm_session_config> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_has_received_message(
+    ffi.Pointer<wire_cst_vodozemac_session> that,
   ) {
-    return _wire__crate__bindings__vodozemac_megolm_session_config_version(
+    return _wire__crate__bindings__vodozemac_session_has_received_message(
       that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_megolm_session_config_versionPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_megolm_session_config_version');
-  late final _wire__crate__bindings__vodozemac_megolm_session_config_version =
-      _wire__crate__bindings__vodozemac_megolm_session_config_versionPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_megolm_session_config>)>();
-
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_megolm_session_config_version_1() {
-    return _wire__crate__bindings__vodozemac_megolm_session_config_version_1();
-  }
-
-  late final _wire__crate__bindings__vodozemac_megolm_session_config_version_1Ptr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_megolm_session_config_version_1');
-  late final _wire__crate__bindings__vodozemac_megolm_session_config_version_1 =
-      _wire__crate__bindings__vodozemac_megolm_session_config_version_1Ptr
-          .asFunction<WireSyncRust2DartDco Function()>();
-
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_megolm_session_config_version_2() {
-    return _wire__crate__bindings__vodozemac_megolm_session_config_version_2();
-  }
-
-  late final _wire__crate__bindings__vodozemac_megolm_session_config_version_2Ptr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_megolm_session_config_version_2');
-  late final _wire__crate__bindings__vodozemac_megolm_session_config_version_2 =
-      _wire__crate__bindings__vodozemac_megolm_session_config_version_2Ptr
-          .asFunction<WireSyncRust2DartDco Function()>();
-
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_message_from_parts(
-    int message_type,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> ciphertext,
-  ) {
-    return _wire__crate__bindings__vodozemac_olm_message_from_parts(
-      message_type,
-      ciphertext,
-    );
-  }
-
-  late final _wire__crate__bindings__vodozemac_olm_message_from_partsPtr = _lookup<
-          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_message_from_parts');
-  late final _wire__crate__bindings__vodozemac_olm_message_from_parts =
-      _wire__crate__bindings__vodozemac_olm_message_from_partsPtr
-          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _wire__crate__bindings__vodozemac_session_has_received_messagePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_has_received_message');
+  late final _wire__crate__bindings__vodozemac_session_has_received_message =
+      _wire__crate__bindings__vodozemac_session_has_received_messagePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_message_message(
-    ffi.Pointer<wire_cst_vodozemac_olm_message> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_pickle_encrypted(
+    ffi.Pointer<wire_cst_vodozemac_session> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
   ) {
-    return _wire__crate__bindings__vodozemac_olm_message_message(
+    return _wire__crate__bindings__vodozemac_session_pickle_encrypted(
       that,
+      pickle_key,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_olm_message_messagePtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_olm_message>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_message_message');
-  late final _wire__crate__bindings__vodozemac_olm_message_message =
-      _wire__crate__bindings__vodozemac_olm_message_messagePtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_olm_message>)>();
+  late final _wire__crate__bindings__vodozemac_session_pickle_encryptedPtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(
+                  ffi.Pointer<wire_cst_vodozemac_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_pickle_encrypted');
+  late final _wire__crate__bindings__vodozemac_session_pickle_encrypted =
+      _wire__crate__bindings__vodozemac_session_pickle_encryptedPtr.asFunction<
+          WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_vodozemac_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_message_message_type(
-    ffi.Pointer<wire_cst_vodozemac_olm_message> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_pickle_with_passphrase(
+    ffi.Pointer<wire_cst_vodozemac_session> that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase,
+    ffi.Pointer<wire_cst_passphrase_kdf_params> params,
   ) {
-    return _wire__crate__bindings__vodozemac_olm_message_message_type(
+    return _wire__crate__bindings__vodozemac_session_pickle_with_passphrase(
       that,
+      passphrase,
+      params,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_olm_message_message_typePtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_olm_message>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_message_message_type');
-  late final _wire__crate__bindings__vodozemac_olm_message_message_type =
-      _wire__crate__bindings__vodozemac_olm_message_message_typePtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_olm_message>)>();
-
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_session_config_def() {
-    return _wire__crate__bindings__vodozemac_olm_session_config_def();
-  }
-
-  late final _wire__crate__bindings__vodozemac_olm_session_config_defPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_session_config_def');
-  late final _wire__crate__bindings__vodozemac_olm_session_config_def =
-      _wire__crate__bindings__vodozemac_olm_session_config_defPtr.asFunction<WireSyncRust2DartDco Function()>();
+  late final _wire__crate__bindings__vodozemac_session_pickle_with_passphrasePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>,
+                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_passphrase_kdf_params>)>>(
+      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_pickle_with_passphrase');
+  late final _wire__crate__bindings__vodozemac_session_pickle_with_passphrase =
+      _wire__crate__bindings__vodozemac_session_pickle_with_passphrasePtr.asFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_passphrase_kdf_params>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_session_config_version(
-    ffi.Pointer<wire_cst_vodozemac_olm_session_config> that,
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_session_config(
+    ffi.Pointer<wire_cst_vodozemac_session> that,
   ) {
-    return _wire__crate__bindings__vodozemac_olm_session_config_version(
+    return _wire__crate__bindings__vodozemac_session_session_config(
       that,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_olm_session_config_versionPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_olm_session_config>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_session_config_version');
-  late final _wire__crate__bindings__vodozemac_olm_session_config_version =
-      _wire__crate__bindings__vodozemac_olm_session_config_versionPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_olm_session_config>)>();
-
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_session_config_version_1() {
-    return _wire__crate__bindings__vodozemac_olm_session_config_version_1();
-  }
-
-  late final _wire__crate__bindings__vodozemac_olm_session_config_version_1Ptr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_session_config_version_1');
-  late final _wire__crate__bindings__vodozemac_olm_session_config_version_1 =
-      _wire__crate__bindings__vodozemac_olm_session_config_version_1Ptr.asFunction<WireSyncRust2DartDco Function()>();
+  late final _wire__crate__bindings__vodozemac_session_session_configPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_session_config');
+  late final _wire__crate__bindings__vodozemac_session_session_config =
+      _wire__crate__bindings__vodozemac_session_session_configPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_olm_session_config_version_2() {
-    return _wire__crate__bindings__vodozemac_olm_session_config_version_2();
+  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_session_id(
+    ffi.Pointer<wire_cst_vodozemac_session> that,
+  ) {
+    return _wire__crate__bindings__vodozemac_session_session_id(
+      that,
+    );
   }
 
-  late final _wire__crate__bindings__vodozemac_olm_session_config_version_2Ptr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_olm_session_config_version_2');
-  late final _wire__crate__bindings__vodozemac_olm_session_config_version_2 =
-      _wire__crate__bindings__vodozemac_olm_session_config_version_2Ptr.asFunction<WireSyncRust2DartDco Function()>();
+  late final _wire__crate__bindings__vodozemac_session_session_idPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>)>>(
+          'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_session_id');
+  late final _wire__crate__bindings__vodozemac_session_session_id =
+      _wire__crate__bindings__vodozemac_session_session_idPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_decrypt(
-    ffi.Pointer<wire_cst_vodozemac_pk_decryption> that,
-    ffi.Pointer<wire_cst_vodozemac_pk_message> message,
+  WireSyncRust2DartDco wire__crate__bindings__share_tracking__withheld_code_code(
+    int that,
   ) {
-    return _wire__crate__bindings__vodozemac_pk_decryption_decrypt(
+    return _wire__crate__bindings__share_tracking__withheld_code_code(
       that,
-      message,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_pk_decryption_decryptPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_pk_decryption>, ffi.Pointer<wire_cst_vodozemac_pk_message>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_decrypt');
-  late final _wire__crate__bindings__vodozemac_pk_decryption_decrypt =
-      _wire__crate__bindings__vodozemac_pk_decryption_decryptPtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_pk_decryption>, ffi.Pointer<wire_cst_vodozemac_pk_message>)>();
+  late final _wire__crate__bindings__share_tracking__withheld_code_codePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Int32)>>(
+          'frbgen_vodozemac_wire__crate__bindings__share_tracking__withheld_code_code');
+  late final _wire__crate__bindings__share_tracking__withheld_code_code =
+      _wire__crate__bindings__share_tracking__withheld_code_codePtr.asFunction<WireSyncRust2DartDco Function(int)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_from_key(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key,
+  WireSyncRust2DartDco wire__crate__bindings__share_tracking__withheld_code_from_code(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> code,
   ) {
-    return _wire__crate__bindings__vodozemac_pk_decryption_from_key(
-      secret_key,
+    return _wire__crate__bindings__share_tracking__withheld_code_from_code(
+      code,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_pk_decryption_from_keyPtr =
+  late final _wire__crate__bindings__share_tracking__withheld_code_from_codePtr =
       _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_from_key');
-  late final _wire__crate__bindings__vodozemac_pk_decryption_from_key =
-      _wire__crate__bindings__vodozemac_pk_decryption_from_keyPtr
+          'frbgen_vodozemac_wire__crate__bindings__share_tracking__withheld_code_from_code');
+  late final _wire__crate__bindings__share_tracking__withheld_code_from_code =
+      _wire__crate__bindings__share_tracking__withheld_code_from_codePtr
           .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
+  void rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle(
-      pickle,
-      pickle_key,
+    return _rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_pk_decryption_from_libolm_picklePtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle');
-  late final _wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle =
-      _wire__crate__bindings__vodozemac_pk_decryption_from_libolm_picklePtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+  late final _rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKeyPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey');
+  late final _rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey =
+      _rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKeyPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_new() {
-    return _wire__crate__bindings__vodozemac_pk_decryption_new();
+  void rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey(
+      ptr,
+    );
   }
 
-  late final _wire__crate__bindings__vodozemac_pk_decryption_newPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_new');
-  late final _wire__crate__bindings__vodozemac_pk_decryption_new =
-      _wire__crate__bindings__vodozemac_pk_decryption_newPtr.asFunction<WireSyncRust2DartDco Function()>();
+  late final _rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKeyPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey');
+  late final _rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey =
+      _rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKeyPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_private_key(
-    ffi.Pointer<wire_cst_vodozemac_pk_decryption> that,
+  void rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_pk_decryption_private_key(
-      that,
+    return _rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_pk_decryption_private_keyPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_decryption>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_private_key');
-  late final _wire__crate__bindings__vodozemac_pk_decryption_private_key =
-      _wire__crate__bindings__vodozemac_pk_decryption_private_keyPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_decryption>)>();
+  late final _rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKeyPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey');
+  late final _rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey =
+      _rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKeyPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_public_key(
-    ffi.Pointer<wire_cst_vodozemac_pk_decryption> that,
+  void rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_pk_decryption_public_key(
-      that,
+    return _rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_pk_decryption_public_keyPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_decryption>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_public_key');
-  late final _wire__crate__bindings__vodozemac_pk_decryption_public_key =
-      _wire__crate__bindings__vodozemac_pk_decryption_public_keyPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_decryption>)>();
+  late final _rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKeyPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey');
+  late final _rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey =
+      _rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKeyPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle(
-    ffi.Pointer<wire_cst_vodozemac_pk_decryption> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  void rust_arc_increment_strong_count_RustOpaque_Ed25519Signature(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle(
-      that,
-      pickle_key,
+    return _rust_arc_increment_strong_count_RustOpaque_Ed25519Signature(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_pk_decryption_to_libolm_picklePtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_pk_decryption>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle');
-  late final _wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle =
-      _wire__crate__bindings__vodozemac_pk_decryption_to_libolm_picklePtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_pk_decryption>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _rust_arc_increment_strong_count_RustOpaque_Ed25519SignaturePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_Ed25519Signature');
+  late final _rust_arc_increment_strong_count_RustOpaque_Ed25519Signature =
+      _rust_arc_increment_strong_count_RustOpaque_Ed25519SignaturePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_encryption_encrypt(
-    ffi.Pointer<wire_cst_vodozemac_pk_encryption> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
+  void rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_pk_encryption_encrypt(
-      that,
-      message,
+    return _rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_pk_encryption_encryptPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_pk_encryption>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_encryption_encrypt');
-  late final _wire__crate__bindings__vodozemac_pk_encryption_encrypt =
-      _wire__crate__bindings__vodozemac_pk_encryption_encryptPtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_pk_encryption>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _rust_arc_decrement_strong_count_RustOpaque_Ed25519SignaturePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature');
+  late final _rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature =
+      _rust_arc_decrement_strong_count_RustOpaque_Ed25519SignaturePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_encryption_from_key(
-    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> public_key,
+  void rust_arc_increment_strong_count_RustOpaque_EstablishedSas(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_pk_encryption_from_key(
-      public_key,
+    return _rust_arc_increment_strong_count_RustOpaque_EstablishedSas(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_pk_encryption_from_keyPtr = _lookup<
-          ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_encryption_from_key');
-  late final _wire__crate__bindings__vodozemac_pk_encryption_from_key =
-      _wire__crate__bindings__vodozemac_pk_encryption_from_keyPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>();
+  late final _rust_arc_increment_strong_count_RustOpaque_EstablishedSasPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_EstablishedSas');
+  late final _rust_arc_increment_strong_count_RustOpaque_EstablishedSas =
+      _rust_arc_increment_strong_count_RustOpaque_EstablishedSasPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_message_from_base64(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> ciphertext,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> mac,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> ephemeral_key,
+  void rust_arc_decrement_strong_count_RustOpaque_EstablishedSas(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_pk_message_from_base64(
-      ciphertext,
-      mac,
-      ephemeral_key,
+    return _rust_arc_decrement_strong_count_RustOpaque_EstablishedSas(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_decrement_strong_count_RustOpaque_EstablishedSasPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_EstablishedSas');
+  late final _rust_arc_decrement_strong_count_RustOpaque_EstablishedSas =
+      _rust_arc_decrement_strong_count_RustOpaque_EstablishedSasPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig(
+      ptr,
+    );
+  }
+
+  late final _rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfigPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig');
+  late final _rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig =
+      _rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfigPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  void rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_pk_message_from_base64Ptr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_message_from_base64');
-  late final _wire__crate__bindings__vodozemac_pk_message_from_base64 =
-      _wire__crate__bindings__vodozemac_pk_message_from_base64Ptr.asFunction<
-          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfigPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig');
+  late final _rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig =
+      _rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfigPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_message_new(
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> ciphertext,
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> mac,
-    ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key> ephemeral_key,
+  void rust_arc_increment_strong_count_RustOpaque_OlmMessage(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_pk_message_new(
-      ciphertext,
-      mac,
-      ephemeral_key,
+    return _rust_arc_increment_strong_count_RustOpaque_OlmMessage(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_pk_message_newPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>,
-                  ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_message_new');
-  late final _wire__crate__bindings__vodozemac_pk_message_new =
-      _wire__crate__bindings__vodozemac_pk_message_newPtr.asFunction<
-          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>,
-              ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_vodozemac_curve_25519_public_key>)>();
+  late final _rust_arc_increment_strong_count_RustOpaque_OlmMessagePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_OlmMessage');
+  late final _rust_arc_increment_strong_count_RustOpaque_OlmMessage =
+      _rust_arc_increment_strong_count_RustOpaque_OlmMessagePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_pk_message_to_base64(
-    ffi.Pointer<wire_cst_vodozemac_pk_message> that,
+  void rust_arc_decrement_strong_count_RustOpaque_OlmMessage(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_pk_message_to_base64(
-      that,
+    return _rust_arc_decrement_strong_count_RustOpaque_OlmMessage(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_pk_message_to_base64Ptr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_message>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_pk_message_to_base64');
-  late final _wire__crate__bindings__vodozemac_pk_message_to_base64 =
-      _wire__crate__bindings__vodozemac_pk_message_to_base64Ptr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_pk_message>)>();
+  late final _rust_arc_decrement_strong_count_RustOpaque_OlmMessagePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_OlmMessage');
+  late final _rust_arc_decrement_strong_count_RustOpaque_OlmMessage =
+      _rust_arc_decrement_strong_count_RustOpaque_OlmMessagePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_decrypt(
-    ffi.Pointer<wire_cst_vodozemac_session> that,
-    ffi.Pointer<wire_cst_vodozemac_olm_message> message,
+  void rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_session_decrypt(
-      that,
-      message,
+    return _rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_session_decryptPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_session>, ffi.Pointer<wire_cst_vodozemac_olm_message>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_decrypt');
-  late final _wire__crate__bindings__vodozemac_session_decrypt =
-      _wire__crate__bindings__vodozemac_session_decryptPtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_session>, ffi.Pointer<wire_cst_vodozemac_olm_message>)>();
+  late final _rust_arc_increment_strong_count_RustOpaque_OlmSessionConfigPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig');
+  late final _rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig =
+      _rust_arc_increment_strong_count_RustOpaque_OlmSessionConfigPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_encrypt(
-    ffi.Pointer<wire_cst_vodozemac_session> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext,
+  void rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_session_encrypt(
-      that,
-      plaintext,
+    return _rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_session_encryptPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_encrypt');
-  late final _wire__crate__bindings__vodozemac_session_encrypt =
-      _wire__crate__bindings__vodozemac_session_encryptPtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfigPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig');
+  late final _rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig =
+      _rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfigPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> pickle_key,
+  void rust_arc_increment_strong_count_RustOpaque_PkDecryption(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted(
-      pickle,
-      pickle_key,
+    return _rust_arc_increment_strong_count_RustOpaque_PkDecryption(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_session_from_olm_pickle_encryptedPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted');
-  late final _wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted =
-      _wire__crate__bindings__vodozemac_session_from_olm_pickle_encryptedPtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+  late final _rust_arc_increment_strong_count_RustOpaque_PkDecryptionPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_PkDecryption');
+  late final _rust_arc_increment_strong_count_RustOpaque_PkDecryption =
+      _rust_arc_increment_strong_count_RustOpaque_PkDecryptionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_from_pickle_encrypted(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  void rust_arc_decrement_strong_count_RustOpaque_PkDecryption(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_session_from_pickle_encrypted(
-      pickle,
-      pickle_key,
+    return _rust_arc_decrement_strong_count_RustOpaque_PkDecryption(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_session_from_pickle_encryptedPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_from_pickle_encrypted');
-  late final _wire__crate__bindings__vodozemac_session_from_pickle_encrypted =
-      _wire__crate__bindings__vodozemac_session_from_pickle_encryptedPtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _rust_arc_decrement_strong_count_RustOpaque_PkDecryptionPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_PkDecryption');
+  late final _rust_arc_decrement_strong_count_RustOpaque_PkDecryption =
+      _rust_arc_decrement_strong_count_RustOpaque_PkDecryptionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_has_received_message(
-    ffi.Pointer<wire_cst_vodozemac_session> that,
+  void rust_arc_increment_strong_count_RustOpaque_PkEncryption(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_session_has_received_message(
-      that,
+    return _rust_arc_increment_strong_count_RustOpaque_PkEncryption(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_session_has_received_messagePtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_has_received_message');
-  late final _wire__crate__bindings__vodozemac_session_has_received_message =
-      _wire__crate__bindings__vodozemac_session_has_received_messagePtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>)>();
+  late final _rust_arc_increment_strong_count_RustOpaque_PkEncryptionPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_PkEncryption');
+  late final _rust_arc_increment_strong_count_RustOpaque_PkEncryption =
+      _rust_arc_increment_strong_count_RustOpaque_PkEncryptionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_pickle_encrypted(
-    ffi.Pointer<wire_cst_vodozemac_session> that,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle_key,
+  void rust_arc_decrement_strong_count_RustOpaque_PkEncryption(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_session_pickle_encrypted(
-      that,
-      pickle_key,
+    return _rust_arc_decrement_strong_count_RustOpaque_PkEncryption(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_session_pickle_encryptedPtr = _lookup<
-          ffi.NativeFunction<
-              WireSyncRust2DartDco Function(
-                  ffi.Pointer<wire_cst_vodozemac_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
-      'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_pickle_encrypted');
-  late final _wire__crate__bindings__vodozemac_session_pickle_encrypted =
-      _wire__crate__bindings__vodozemac_session_pickle_encryptedPtr.asFunction<
-          WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_vodozemac_session>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+  late final _rust_arc_decrement_strong_count_RustOpaque_PkEncryptionPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_PkEncryption');
+  late final _rust_arc_decrement_strong_count_RustOpaque_PkEncryption =
+      _rust_arc_decrement_strong_count_RustOpaque_PkEncryptionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_session_config(
-    ffi.Pointer<wire_cst_vodozemac_session> that,
+  void rust_arc_increment_strong_count_RustOpaque_RwLockFallbackKeyState(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_session_session_config(
-      that,
+    return _rust_arc_increment_strong_count_RustOpaque_RwLockFallbackKeyState(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_session_session_configPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_session_config');
-  late final _wire__crate__bindings__vodozemac_session_session_config =
-      _wire__crate__bindings__vodozemac_session_session_configPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>)>();
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockFallbackKeyStatePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockFallbackKeyState');
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockFallbackKeyState =
+      _rust_arc_increment_strong_count_RustOpaque_RwLockFallbackKeyStatePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__vodozemac_session_session_id(
-    ffi.Pointer<wire_cst_vodozemac_session> that,
+  void rust_arc_decrement_strong_count_RustOpaque_RwLockFallbackKeyState(
+    ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _wire__crate__bindings__vodozemac_session_session_id(
-      that,
+    return _rust_arc_decrement_strong_count_RustOpaque_RwLockFallbackKeyState(
+      ptr,
     );
   }
 
-  late final _wire__crate__bindings__vodozemac_session_session_idPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>)>>(
-          'frbgen_vodozemac_wire__crate__bindings__vodozemac_session_session_id');
-  late final _wire__crate__bindings__vodozemac_session_session_id =
-      _wire__crate__bindings__vodozemac_session_session_idPtr
-          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_vodozemac_session>)>();
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockFallbackKeyStatePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockFallbackKeyState');
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockFallbackKeyState =
+      _rust_arc_decrement_strong_count_RustOpaque_RwLockFallbackKeyStatePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey(
+  void rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey(
+    return _rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKeyPtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockGroupSessionPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey');
-  late final _rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey =
-      _rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKeyPtr
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession');
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession =
+      _rust_arc_increment_strong_count_RustOpaque_RwLockGroupSessionPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey(
+  void rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey(
+    return _rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKeyPtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSessionPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey');
-  late final _rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey =
-      _rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKeyPtr
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession');
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession =
+      _rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSessionPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey(
+  void rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey(
+    return _rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKeyPtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSessionPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey');
-  late final _rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey =
-      _rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKeyPtr
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession');
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession =
+      _rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSessionPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey(
+  void rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey(
+    return _rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKeyPtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSessionPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey');
-  late final _rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey =
-      _rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKeyPtr
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession');
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession =
+      _rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSessionPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_increment_strong_count_RustOpaque_Ed25519Signature(
+  void rust_arc_increment_strong_count_RustOpaque_RwLockReplayState(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_Ed25519Signature(
+    return _rust_arc_increment_strong_count_RustOpaque_RwLockReplayState(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_Ed25519SignaturePtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockReplayStatePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_Ed25519Signature');
-  late final _rust_arc_increment_strong_count_RustOpaque_Ed25519Signature =
-      _rust_arc_increment_strong_count_RustOpaque_Ed25519SignaturePtr
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockReplayState');
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockReplayState =
+      _rust_arc_increment_strong_count_RustOpaque_RwLockReplayStatePtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature(
+  void rust_arc_decrement_strong_count_RustOpaque_RwLockReplayState(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature(
+    return _rust_arc_decrement_strong_count_RustOpaque_RwLockReplayState(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_Ed25519SignaturePtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockReplayStatePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature');
-  late final _rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature =
-      _rust_arc_decrement_strong_count_RustOpaque_Ed25519SignaturePtr
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockReplayState');
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockReplayState =
+      _rust_arc_decrement_strong_count_RustOpaque_RwLockReplayStatePtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_increment_strong_count_RustOpaque_EstablishedSas(
+  void rust_arc_increment_strong_count_RustOpaque_RwLockRoomKeyInfo(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_EstablishedSas(
+    return _rust_arc_increment_strong_count_RustOpaque_RwLockRoomKeyInfo(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_EstablishedSasPtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockRoomKeyInfoPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_EstablishedSas');
-  late final _rust_arc_increment_strong_count_RustOpaque_EstablishedSas =
-      _rust_arc_increment_strong_count_RustOpaque_EstablishedSasPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockRoomKeyInfo');
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockRoomKeyInfo =
+      _rust_arc_increment_strong_count_RustOpaque_RwLockRoomKeyInfoPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_decrement_strong_count_RustOpaque_EstablishedSas(
+  void rust_arc_decrement_strong_count_RustOpaque_RwLockRoomKeyInfo(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_EstablishedSas(
+    return _rust_arc_decrement_strong_count_RustOpaque_RwLockRoomKeyInfo(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_EstablishedSasPtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockRoomKeyInfoPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_EstablishedSas');
-  late final _rust_arc_decrement_strong_count_RustOpaque_EstablishedSas =
-      _rust_arc_decrement_strong_count_RustOpaque_EstablishedSasPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockRoomKeyInfo');
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockRoomKeyInfo =
+      _rust_arc_decrement_strong_count_RustOpaque_RwLockRoomKeyInfoPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig(
+  void rust_arc_increment_strong_count_RustOpaque_RwLockRotationState(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig(
+    return _rust_arc_increment_strong_count_RustOpaque_RwLockRotationState(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfigPtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockRotationStatePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig');
-  late final _rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig =
-      _rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfigPtr
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockRotationState');
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockRotationState =
+      _rust_arc_increment_strong_count_RustOpaque_RwLockRotationStatePtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig(
+  void rust_arc_decrement_strong_count_RustOpaque_RwLockRotationState(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig(
+    return _rust_arc_decrement_strong_count_RustOpaque_RwLockRotationState(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfigPtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockRotationStatePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig');
-  late final _rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig =
-      _rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfigPtr
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockRotationState');
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockRotationState =
+      _rust_arc_decrement_strong_count_RustOpaque_RwLockRotationStatePtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_increment_strong_count_RustOpaque_OlmMessage(
+  void rust_arc_increment_strong_count_RustOpaque_RwLockSession(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_OlmMessage(
+    return _rust_arc_increment_strong_count_RustOpaque_RwLockSession(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_OlmMessagePtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockSessionPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_OlmMessage');
-  late final _rust_arc_increment_strong_count_RustOpaque_OlmMessage =
-      _rust_arc_increment_strong_count_RustOpaque_OlmMessagePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockSession');
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockSession =
+      _rust_arc_increment_strong_count_RustOpaque_RwLockSessionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_decrement_strong_count_RustOpaque_OlmMessage(
+  void rust_arc_decrement_strong_count_RustOpaque_RwLockSession(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_OlmMessage(
+    return _rust_arc_decrement_strong_count_RustOpaque_RwLockSession(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_OlmMessagePtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockSessionPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_OlmMessage');
-  late final _rust_arc_decrement_strong_count_RustOpaque_OlmMessage =
-      _rust_arc_decrement_strong_count_RustOpaque_OlmMessagePtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockSession');
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockSession =
+      _rust_arc_decrement_strong_count_RustOpaque_RwLockSessionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig(
+  void rust_arc_increment_strong_count_RustOpaque_RwLockSessionKeySource(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig(
+    return _rust_arc_increment_strong_count_RustOpaque_RwLockSessionKeySource(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_OlmSessionConfigPtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockSessionKeySourcePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig');
-  late final _rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig =
-      _rust_arc_increment_strong_count_RustOpaque_OlmSessionConfigPtr
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockSessionKeySource');
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockSessionKeySource =
+      _rust_arc_increment_strong_count_RustOpaque_RwLockSessionKeySourcePtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig(
+  void rust_arc_decrement_strong_count_RustOpaque_RwLockSessionKeySource(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig(
+    return _rust_arc_decrement_strong_count_RustOpaque_RwLockSessionKeySource(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfigPtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockSessionKeySourcePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig');
-  late final _rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig =
-      _rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfigPtr
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockSessionKeySource');
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockSessionKeySource =
+      _rust_arc_decrement_strong_count_RustOpaque_RwLockSessionKeySourcePtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_increment_strong_count_RustOpaque_PkDecryption(
+  void rust_arc_increment_strong_count_RustOpaque_RwLockSessionStoreState(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_PkDecryption(
+    return _rust_arc_increment_strong_count_RustOpaque_RwLockSessionStoreState(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_PkDecryptionPtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockSessionStoreStatePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_PkDecryption');
-  late final _rust_arc_increment_strong_count_RustOpaque_PkDecryption =
-      _rust_arc_increment_strong_count_RustOpaque_PkDecryptionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockSessionStoreState');
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockSessionStoreState =
+      _rust_arc_increment_strong_count_RustOpaque_RwLockSessionStoreStatePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_decrement_strong_count_RustOpaque_PkDecryption(
+  void rust_arc_decrement_strong_count_RustOpaque_RwLockSessionStoreState(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_PkDecryption(
+    return _rust_arc_decrement_strong_count_RustOpaque_RwLockSessionStoreState(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_PkDecryptionPtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockSessionStoreStatePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_PkDecryption');
-  late final _rust_arc_decrement_strong_count_RustOpaque_PkDecryption =
-      _rust_arc_decrement_strong_count_RustOpaque_PkDecryptionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockSessionStoreState');
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockSessionStoreState =
+      _rust_arc_decrement_strong_count_RustOpaque_RwLockSessionStoreStatePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_increment_strong_count_RustOpaque_PkEncryption(
+  void rust_arc_increment_strong_count_RustOpaque_RwLockShareState(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_PkEncryption(
+    return _rust_arc_increment_strong_count_RustOpaque_RwLockShareState(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_PkEncryptionPtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockShareStatePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_PkEncryption');
-  late final _rust_arc_increment_strong_count_RustOpaque_PkEncryption =
-      _rust_arc_increment_strong_count_RustOpaque_PkEncryptionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockShareState');
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockShareState =
+      _rust_arc_increment_strong_count_RustOpaque_RwLockShareStatePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_decrement_strong_count_RustOpaque_PkEncryption(
+  void rust_arc_decrement_strong_count_RustOpaque_RwLockShareState(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_PkEncryption(
+    return _rust_arc_decrement_strong_count_RustOpaque_RwLockShareState(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_PkEncryptionPtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockShareStatePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_PkEncryption');
-  late final _rust_arc_decrement_strong_count_RustOpaque_PkEncryption =
-      _rust_arc_decrement_strong_count_RustOpaque_PkEncryptionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockShareState');
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockShareState =
+      _rust_arc_decrement_strong_count_RustOpaque_RwLockShareStatePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession(
+  void rust_arc_increment_strong_count_RustOpaque_RwLockUnwedgeState(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession(
+    return _rust_arc_increment_strong_count_RustOpaque_RwLockUnwedgeState(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_RwLockGroupSessionPtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockUnwedgeStatePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession');
-  late final _rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession =
-      _rust_arc_increment_strong_count_RustOpaque_RwLockGroupSessionPtr
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockUnwedgeState');
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockUnwedgeState =
+      _rust_arc_increment_strong_count_RustOpaque_RwLockUnwedgeStatePtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession(
+  void rust_arc_decrement_strong_count_RustOpaque_RwLockUnwedgeState(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession(
+    return _rust_arc_decrement_strong_count_RustOpaque_RwLockUnwedgeState(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSessionPtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockUnwedgeStatePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession');
-  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession =
-      _rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSessionPtr
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockUnwedgeState');
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockUnwedgeState =
+      _rust_arc_decrement_strong_count_RustOpaque_RwLockUnwedgeStatePtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession(
+  void rust_arc_increment_strong_count_RustOpaque_RwLockUtdQueueState(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession(
+    return _rust_arc_increment_strong_count_RustOpaque_RwLockUtdQueueState(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSessionPtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockUtdQueueStatePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession');
-  late final _rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession =
-      _rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSessionPtr
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockUtdQueueState');
+  late final _rust_arc_increment_strong_count_RustOpaque_RwLockUtdQueueState =
+      _rust_arc_increment_strong_count_RustOpaque_RwLockUtdQueueStatePtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession(
+  void rust_arc_decrement_strong_count_RustOpaque_RwLockUtdQueueState(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession(
+    return _rust_arc_decrement_strong_count_RustOpaque_RwLockUtdQueueState(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSessionPtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockUtdQueueStatePtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession');
-  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession =
-      _rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSessionPtr
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockUtdQueueState');
+  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockUtdQueueState =
+      _rust_arc_decrement_strong_count_RustOpaque_RwLockUtdQueueStatePtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_increment_strong_count_RustOpaque_RwLockSession(
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_increment_strong_count_RustOpaque_RwLockSession(
+    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(
       ptr,
     );
   }
 
-  late final _rust_arc_increment_strong_count_RustOpaque_RwLockSessionPtr =
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentityPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_RwLockSession');
-  late final _rust_arc_increment_strong_count_RustOpaque_RwLockSession =
-      _rust_arc_increment_strong_count_RustOpaque_RwLockSessionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+          'frbgen_vodozemac_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity');
+  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity =
+      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentityPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  void rust_arc_decrement_strong_count_RustOpaque_RwLockSession(
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_RwLockSession(
+    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(
       ptr,
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockSessionPtr =
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentityPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_RwLockSession');
-  late final _rust_arc_decrement_strong_count_RustOpaque_RwLockSession =
-      _rust_arc_decrement_strong_count_RustOpaque_RwLockSessionPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity');
+  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity =
+      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentityPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
   void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
     ffi.Pointer<ffi.Void> ptr,
@@ -3257,12 +7130,280 @@ class RustLibWire implements BaseWire {
     );
   }
 
-  late final _rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccountPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount');
-  late final _rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount =
-      _rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccountPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+  late final _rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccountPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frbgen_vodozemac_rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount');
+  late final _rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount =
+      _rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccountPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<wire_cst_device_keys> cst_new_box_autoadd_device_keys() {
+    return _cst_new_box_autoadd_device_keys();
+  }
+
+  late final _cst_new_box_autoadd_device_keysPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_device_keys> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_device_keys');
+  late final _cst_new_box_autoadd_device_keys =
+      _cst_new_box_autoadd_device_keysPtr.asFunction<ffi.Pointer<wire_cst_device_keys> Function()>();
+
+  ffi.Pointer<wire_cst_encrypted_room_key_bundle> cst_new_box_autoadd_encrypted_room_key_bundle() {
+    return _cst_new_box_autoadd_encrypted_room_key_bundle();
+  }
+
+  late final _cst_new_box_autoadd_encrypted_room_key_bundlePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_encrypted_room_key_bundle> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_encrypted_room_key_bundle');
+  late final _cst_new_box_autoadd_encrypted_room_key_bundle = _cst_new_box_autoadd_encrypted_room_key_bundlePtr
+      .asFunction<ffi.Pointer<wire_cst_encrypted_room_key_bundle> Function()>();
+
+  ffi.Pointer<wire_cst_fallback_key_tracker> cst_new_box_autoadd_fallback_key_tracker() {
+    return _cst_new_box_autoadd_fallback_key_tracker();
+  }
+
+  late final _cst_new_box_autoadd_fallback_key_trackerPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_fallback_key_tracker> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_fallback_key_tracker');
+  late final _cst_new_box_autoadd_fallback_key_tracker =
+      _cst_new_box_autoadd_fallback_key_trackerPtr.asFunction<ffi.Pointer<wire_cst_fallback_key_tracker> Function()>();
+
+  ffi.Pointer<wire_cst_inbound_room_key> cst_new_box_autoadd_inbound_room_key() {
+    return _cst_new_box_autoadd_inbound_room_key();
+  }
+
+  late final _cst_new_box_autoadd_inbound_room_keyPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_inbound_room_key> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_inbound_room_key');
+  late final _cst_new_box_autoadd_inbound_room_key =
+      _cst_new_box_autoadd_inbound_room_keyPtr.asFunction<ffi.Pointer<wire_cst_inbound_room_key> Function()>();
+
+  ffi.Pointer<wire_cst_libolm_store> cst_new_box_autoadd_libolm_store() {
+    return _cst_new_box_autoadd_libolm_store();
+  }
+
+  late final _cst_new_box_autoadd_libolm_storePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_libolm_store> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_libolm_store');
+  late final _cst_new_box_autoadd_libolm_store =
+      _cst_new_box_autoadd_libolm_storePtr.asFunction<ffi.Pointer<wire_cst_libolm_store> Function()>();
+
+  ffi.Pointer<wire_cst_megolm_replay_guard> cst_new_box_autoadd_megolm_replay_guard() {
+    return _cst_new_box_autoadd_megolm_replay_guard();
+  }
+
+  late final _cst_new_box_autoadd_megolm_replay_guardPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_megolm_replay_guard> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_megolm_replay_guard');
+  late final _cst_new_box_autoadd_megolm_replay_guard =
+      _cst_new_box_autoadd_megolm_replay_guardPtr.asFunction<ffi.Pointer<wire_cst_megolm_replay_guard> Function()>();
+
+  ffi.Pointer<wire_cst_migrated_pickle> cst_new_box_autoadd_migrated_pickle() {
+    return _cst_new_box_autoadd_migrated_pickle();
+  }
+
+  late final _cst_new_box_autoadd_migrated_picklePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_migrated_pickle> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_migrated_pickle');
+  late final _cst_new_box_autoadd_migrated_pickle =
+      _cst_new_box_autoadd_migrated_picklePtr.asFunction<ffi.Pointer<wire_cst_migrated_pickle> Function()>();
+
+  ffi.Pointer<wire_cst_migration_report> cst_new_box_autoadd_migration_report() {
+    return _cst_new_box_autoadd_migration_report();
+  }
+
+  late final _cst_new_box_autoadd_migration_reportPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_migration_report> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_migration_report');
+  late final _cst_new_box_autoadd_migration_report =
+      _cst_new_box_autoadd_migration_reportPtr.asFunction<ffi.Pointer<wire_cst_migration_report> Function()>();
+
+  ffi.Pointer<wire_cst_olm_payload> cst_new_box_autoadd_olm_payload() {
+    return _cst_new_box_autoadd_olm_payload();
+  }
+
+  late final _cst_new_box_autoadd_olm_payloadPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_olm_payload> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_olm_payload');
+  late final _cst_new_box_autoadd_olm_payload =
+      _cst_new_box_autoadd_olm_payloadPtr.asFunction<ffi.Pointer<wire_cst_olm_payload> Function()>();
+
+  ffi.Pointer<wire_cst_olm_session_store> cst_new_box_autoadd_olm_session_store() {
+    return _cst_new_box_autoadd_olm_session_store();
+  }
+
+  late final _cst_new_box_autoadd_olm_session_storePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_olm_session_store> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_olm_session_store');
+  late final _cst_new_box_autoadd_olm_session_store =
+      _cst_new_box_autoadd_olm_session_storePtr.asFunction<ffi.Pointer<wire_cst_olm_session_store> Function()>();
+
+  ffi.Pointer<wire_cst_outbound_group_session> cst_new_box_autoadd_outbound_group_session() {
+    return _cst_new_box_autoadd_outbound_group_session();
+  }
+
+  late final _cst_new_box_autoadd_outbound_group_sessionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_outbound_group_session> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_outbound_group_session');
+  late final _cst_new_box_autoadd_outbound_group_session = _cst_new_box_autoadd_outbound_group_sessionPtr
+      .asFunction<ffi.Pointer<wire_cst_outbound_group_session> Function()>();
+
+  ffi.Pointer<wire_cst_passphrase_kdf_params> cst_new_box_autoadd_passphrase_kdf_params() {
+    return _cst_new_box_autoadd_passphrase_kdf_params();
+  }
+
+  late final _cst_new_box_autoadd_passphrase_kdf_paramsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_passphrase_kdf_params> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_passphrase_kdf_params');
+  late final _cst_new_box_autoadd_passphrase_kdf_params = _cst_new_box_autoadd_passphrase_kdf_paramsPtr
+      .asFunction<ffi.Pointer<wire_cst_passphrase_kdf_params> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_pickle_format_error(
+    int value,
+  ) {
+    return _cst_new_box_autoadd_pickle_format_error(
+      value,
+    );
+  }
+
+  late final _cst_new_box_autoadd_pickle_format_errorPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_pickle_format_error');
+  late final _cst_new_box_autoadd_pickle_format_error =
+      _cst_new_box_autoadd_pickle_format_errorPtr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_cst_pickle_info> cst_new_box_autoadd_pickle_info() {
+    return _cst_new_box_autoadd_pickle_info();
+  }
+
+  late final _cst_new_box_autoadd_pickle_infoPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_pickle_info> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_pickle_info');
+  late final _cst_new_box_autoadd_pickle_info =
+      _cst_new_box_autoadd_pickle_infoPtr.asFunction<ffi.Pointer<wire_cst_pickle_info> Function()>();
+
+  ffi.Pointer<wire_cst_retained_room_key> cst_new_box_autoadd_retained_room_key() {
+    return _cst_new_box_autoadd_retained_room_key();
+  }
+
+  late final _cst_new_box_autoadd_retained_room_keyPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_retained_room_key> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_retained_room_key');
+  late final _cst_new_box_autoadd_retained_room_key =
+      _cst_new_box_autoadd_retained_room_keyPtr.asFunction<ffi.Pointer<wire_cst_retained_room_key> Function()>();
+
+  ffi.Pointer<wire_cst_room_key_bundle_download> cst_new_box_autoadd_room_key_bundle_download() {
+    return _cst_new_box_autoadd_room_key_bundle_download();
+  }
+
+  late final _cst_new_box_autoadd_room_key_bundle_downloadPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_room_key_bundle_download> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_room_key_bundle_download');
+  late final _cst_new_box_autoadd_room_key_bundle_download = _cst_new_box_autoadd_room_key_bundle_downloadPtr
+      .asFunction<ffi.Pointer<wire_cst_room_key_bundle_download> Function()>();
+
+  ffi.Pointer<wire_cst_room_key_info> cst_new_box_autoadd_room_key_info() {
+    return _cst_new_box_autoadd_room_key_info();
+  }
+
+  late final _cst_new_box_autoadd_room_key_infoPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_room_key_info> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_room_key_info');
+  late final _cst_new_box_autoadd_room_key_info =
+      _cst_new_box_autoadd_room_key_infoPtr.asFunction<ffi.Pointer<wire_cst_room_key_info> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_rotation_reason(
+    int value,
+  ) {
+    return _cst_new_box_autoadd_rotation_reason(
+      value,
+    );
+  }
+
+  late final _cst_new_box_autoadd_rotation_reasonPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_rotation_reason');
+  late final _cst_new_box_autoadd_rotation_reason =
+      _cst_new_box_autoadd_rotation_reasonPtr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_cst_share_recipient> cst_new_box_autoadd_share_recipient() {
+    return _cst_new_box_autoadd_share_recipient();
+  }
+
+  late final _cst_new_box_autoadd_share_recipientPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_share_recipient> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_share_recipient');
+  late final _cst_new_box_autoadd_share_recipient =
+      _cst_new_box_autoadd_share_recipientPtr.asFunction<ffi.Pointer<wire_cst_share_recipient> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_trust_link(
+    int value,
+  ) {
+    return _cst_new_box_autoadd_trust_link(
+      value,
+    );
+  }
+
+  late final _cst_new_box_autoadd_trust_linkPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_trust_link');
+  late final _cst_new_box_autoadd_trust_link =
+      _cst_new_box_autoadd_trust_linkPtr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<ffi.Uint32> cst_new_box_autoadd_u_32(
+    int value,
+  ) {
+    return _cst_new_box_autoadd_u_32(
+      value,
+    );
+  }
+
+  late final _cst_new_box_autoadd_u_32Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>(
+      'frbgen_vodozemac_cst_new_box_autoadd_u_32');
+  late final _cst_new_box_autoadd_u_32 =
+      _cst_new_box_autoadd_u_32Ptr.asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+
+  ffi.Pointer<ffi.Uint64> cst_new_box_autoadd_u_64(
+    int value,
+  ) {
+    return _cst_new_box_autoadd_u_64(
+      value,
+    );
+  }
+
+  late final _cst_new_box_autoadd_u_64Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint64> Function(ffi.Uint64)>>(
+      'frbgen_vodozemac_cst_new_box_autoadd_u_64');
+  late final _cst_new_box_autoadd_u_64 =
+      _cst_new_box_autoadd_u_64Ptr.asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
+
+  ffi.Pointer<wire_cst_unwedge_tracker> cst_new_box_autoadd_unwedge_tracker() {
+    return _cst_new_box_autoadd_unwedge_tracker();
+  }
+
+  late final _cst_new_box_autoadd_unwedge_trackerPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_unwedge_tracker> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_unwedge_tracker');
+  late final _cst_new_box_autoadd_unwedge_tracker =
+      _cst_new_box_autoadd_unwedge_trackerPtr.asFunction<ffi.Pointer<wire_cst_unwedge_tracker> Function()>();
+
+  ffi.Pointer<wire_cst_utd_classification> cst_new_box_autoadd_utd_classification() {
+    return _cst_new_box_autoadd_utd_classification();
+  }
+
+  late final _cst_new_box_autoadd_utd_classificationPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_utd_classification> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_utd_classification');
+  late final _cst_new_box_autoadd_utd_classification =
+      _cst_new_box_autoadd_utd_classificationPtr.asFunction<ffi.Pointer<wire_cst_utd_classification> Function()>();
+
+  ffi.Pointer<wire_cst_utd_queue> cst_new_box_autoadd_utd_queue() {
+    return _cst_new_box_autoadd_utd_queue();
+  }
+
+  late final _cst_new_box_autoadd_utd_queuePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_utd_queue> Function()>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_utd_queue');
+  late final _cst_new_box_autoadd_utd_queue =
+      _cst_new_box_autoadd_utd_queuePtr.asFunction<ffi.Pointer<wire_cst_utd_queue> Function()>();
 
   ffi.Pointer<wire_cst_vodozemac_account> cst_new_box_autoadd_vodozemac_account() {
     return _cst_new_box_autoadd_vodozemac_account();
@@ -3407,6 +7548,90 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_vodozemac_session =
       _cst_new_box_autoadd_vodozemac_sessionPtr.asFunction<ffi.Pointer<wire_cst_vodozemac_session> Function()>();
 
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_withheld_code(
+    int value,
+  ) {
+    return _cst_new_box_autoadd_withheld_code(
+      value,
+    );
+  }
+
+  late final _cst_new_box_autoadd_withheld_codePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_box_autoadd_withheld_code');
+  late final _cst_new_box_autoadd_withheld_code =
+      _cst_new_box_autoadd_withheld_codePtr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_String> cst_new_list_String(
+    int len,
+  ) {
+    return _cst_new_list_String(
+      len,
+    );
+  }
+
+  late final _cst_new_list_StringPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_String> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_String');
+  late final _cst_new_list_String =
+      _cst_new_list_StringPtr.asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_device_trust> cst_new_list_device_trust(
+    int len,
+  ) {
+    return _cst_new_list_device_trust(
+      len,
+    );
+  }
+
+  late final _cst_new_list_device_trustPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_device_trust> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_device_trust');
+  late final _cst_new_list_device_trust =
+      _cst_new_list_device_trustPtr.asFunction<ffi.Pointer<wire_cst_list_device_trust> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_inbound_room_key> cst_new_list_inbound_room_key(
+    int len,
+  ) {
+    return _cst_new_list_inbound_room_key(
+      len,
+    );
+  }
+
+  late final _cst_new_list_inbound_room_keyPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_inbound_room_key> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_inbound_room_key');
+  late final _cst_new_list_inbound_room_key =
+      _cst_new_list_inbound_room_keyPtr.asFunction<ffi.Pointer<wire_cst_list_inbound_room_key> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_migrated_pickle> cst_new_list_migrated_pickle(
+    int len,
+  ) {
+    return _cst_new_list_migrated_pickle(
+      len,
+    );
+  }
+
+  late final _cst_new_list_migrated_picklePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_migrated_pickle> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_migrated_pickle');
+  late final _cst_new_list_migrated_pickle =
+      _cst_new_list_migrated_picklePtr.asFunction<ffi.Pointer<wire_cst_list_migrated_pickle> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_migration_failure> cst_new_list_migration_failure(
+    int len,
+  ) {
+    return _cst_new_list_migration_failure(
+      len,
+    );
+  }
+
+  late final _cst_new_list_migration_failurePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_migration_failure> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_migration_failure');
+  late final _cst_new_list_migration_failure =
+      _cst_new_list_migration_failurePtr.asFunction<ffi.Pointer<wire_cst_list_migration_failure> Function(int)>();
+
   ffi.Pointer<wire_cst_list_prim_u_8_loose> cst_new_list_prim_u_8_loose(
     int len,
   ) {
@@ -3435,6 +7660,90 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_prim_u_8_strict =
       _cst_new_list_prim_u_8_strictPtr.asFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_record_string_u_32> cst_new_list_record_string_u_32(
+    int len,
+  ) {
+    return _cst_new_list_record_string_u_32(
+      len,
+    );
+  }
+
+  late final _cst_new_list_record_string_u_32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_record_string_u_32> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_record_string_u_32');
+  late final _cst_new_list_record_string_u_32 =
+      _cst_new_list_record_string_u_32Ptr.asFunction<ffi.Pointer<wire_cst_list_record_string_u_32> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_rekey_result> cst_new_list_rekey_result(
+    int len,
+  ) {
+    return _cst_new_list_rekey_result(
+      len,
+    );
+  }
+
+  late final _cst_new_list_rekey_resultPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_rekey_result> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_rekey_result');
+  late final _cst_new_list_rekey_result =
+      _cst_new_list_rekey_resultPtr.asFunction<ffi.Pointer<wire_cst_list_rekey_result> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_retention_result> cst_new_list_retention_result(
+    int len,
+  ) {
+    return _cst_new_list_retention_result(
+      len,
+    );
+  }
+
+  late final _cst_new_list_retention_resultPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_retention_result> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_retention_result');
+  late final _cst_new_list_retention_result =
+      _cst_new_list_retention_resultPtr.asFunction<ffi.Pointer<wire_cst_list_retention_result> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_retried_event> cst_new_list_retried_event(
+    int len,
+  ) {
+    return _cst_new_list_retried_event(
+      len,
+    );
+  }
+
+  late final _cst_new_list_retried_eventPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_retried_event> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_retried_event');
+  late final _cst_new_list_retried_event =
+      _cst_new_list_retried_eventPtr.asFunction<ffi.Pointer<wire_cst_list_retried_event> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_room_retention> cst_new_list_room_retention(
+    int len,
+  ) {
+    return _cst_new_list_room_retention(
+      len,
+    );
+  }
+
+  late final _cst_new_list_room_retentionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_room_retention> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_room_retention');
+  late final _cst_new_list_room_retention =
+      _cst_new_list_room_retentionPtr.asFunction<ffi.Pointer<wire_cst_list_room_retention> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_share_recipient> cst_new_list_share_recipient(
+    int len,
+  ) {
+    return _cst_new_list_share_recipient(
+      len,
+    );
+  }
+
+  late final _cst_new_list_share_recipientPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_share_recipient> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_share_recipient');
+  late final _cst_new_list_share_recipient =
+      _cst_new_list_share_recipientPtr.asFunction<ffi.Pointer<wire_cst_list_share_recipient> Function(int)>();
+
   ffi.Pointer<wire_cst_list_vodozemac_one_time_key> cst_new_list_vodozemac_one_time_key(
     int len,
   ) {
@@ -3443,128 +7752,404 @@ class RustLibWire implements BaseWire {
     );
   }
 
-  late final _cst_new_list_vodozemac_one_time_keyPtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_vodozemac_one_time_key> Function(ffi.Int32)>>(
-          'frbgen_vodozemac_cst_new_list_vodozemac_one_time_key');
-  late final _cst_new_list_vodozemac_one_time_key = _cst_new_list_vodozemac_one_time_keyPtr
-      .asFunction<ffi.Pointer<wire_cst_list_vodozemac_one_time_key> Function(int)>();
+  late final _cst_new_list_vodozemac_one_time_keyPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_vodozemac_one_time_key> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_vodozemac_one_time_key');
+  late final _cst_new_list_vodozemac_one_time_key = _cst_new_list_vodozemac_one_time_keyPtr
+      .asFunction<ffi.Pointer<wire_cst_list_vodozemac_one_time_key> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_vodozemac_session> cst_new_list_vodozemac_session(
+    int len,
+  ) {
+    return _cst_new_list_vodozemac_session(
+      len,
+    );
+  }
+
+  late final _cst_new_list_vodozemac_sessionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_vodozemac_session> Function(ffi.Int32)>>(
+          'frbgen_vodozemac_cst_new_list_vodozemac_session');
+  late final _cst_new_list_vodozemac_session =
+      _cst_new_list_vodozemac_sessionPtr.asFunction<ffi.Pointer<wire_cst_list_vodozemac_session> Function(int)>();
+
+  IOSDecryptResult ios_decrypt_event(
+    ffi.Pointer<ffi.Char> pickled_session,
+    ffi.Pointer<ffi.Array<ffi.Uint8>> pickle_key,
+    ffi.Pointer<ffi.Char> ciphertext,
+  ) {
+    return _ios_decrypt_event(
+      pickled_session,
+      pickle_key,
+      ciphertext,
+    );
+  }
+
+  late final _ios_decrypt_eventPtr = _lookup<
+      ffi.NativeFunction<
+          IOSDecryptResult Function(
+              ffi.Pointer<ffi.Char>, ffi.Pointer<ffi.Array<ffi.Uint8>>, ffi.Pointer<ffi.Char>)>>('ios_decrypt_event');
+  late final _ios_decrypt_event = _ios_decrypt_eventPtr.asFunction<
+      IOSDecryptResult Function(ffi.Pointer<ffi.Char>, ffi.Pointer<ffi.Array<ffi.Uint8>>, ffi.Pointer<ffi.Char>)>();
+
+  void ios_free_string(
+    ffi.Pointer<ffi.Char> s,
+  ) {
+    return _ios_free_string(
+      s,
+    );
+  }
+
+  late final _ios_free_stringPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Char>)>>('ios_free_string');
+  late final _ios_free_string = _ios_free_stringPtr.asFunction<void Function(ffi.Pointer<ffi.Char>)>();
+
+  void ios_free_result(
+    IOSDecryptResult result,
+  ) {
+    return _ios_free_result(
+      result,
+    );
+  }
+
+  late final _ios_free_resultPtr = _lookup<ffi.NativeFunction<ffi.Void Function(IOSDecryptResult)>>('ios_free_result');
+  late final _ios_free_result = _ios_free_resultPtr.asFunction<void Function(IOSDecryptResult)>();
+
+  int dummy_method_to_enforce_bundling() {
+    return _dummy_method_to_enforce_bundling();
+  }
+
+  late final _dummy_method_to_enforce_bundlingPtr =
+      _lookup<ffi.NativeFunction<ffi.Int64 Function()>>('dummy_method_to_enforce_bundling');
+  late final _dummy_method_to_enforce_bundling = _dummy_method_to_enforce_bundlingPtr.asFunction<int Function()>();
+}
+
+typedef DartPort = ffi.Int64;
+typedef DartDartPort = int;
+typedef DartPostCObjectFnTypeFunction = ffi.Bool Function(DartPort port_id, ffi.Pointer<ffi.Void> message);
+typedef DartDartPostCObjectFnTypeFunction = bool Function(DartDartPort port_id, ffi.Pointer<ffi.Void> message);
+typedef DartPostCObjectFnType = ffi.Pointer<ffi.NativeFunction<DartPostCObjectFnTypeFunction>>;
+
+final class wire_cst_list_prim_u_8_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint8> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_prim_u_8_loose extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint8> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_String extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_room_retention extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id;
+
+  @ffi.Uint64()
+  external int max_age_ms;
+}
+
+final class wire_cst_list_room_retention extends ffi.Struct {
+  external ffi.Pointer<wire_cst_room_retention> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_megolm_replay_guard extends ffi.Struct {
+  @ffi.UintPtr()
+  external int state;
+}
+
+final class wire_cst_inbound_room_key extends ffi.Struct {
+  @ffi.UintPtr()
+  external int session;
+
+  @ffi.UintPtr()
+  external int source;
+
+  @ffi.UintPtr()
+  external int key_info;
+}
+
+final class wire_cst_list_inbound_room_key extends ffi.Struct {
+  external ffi.Pointer<wire_cst_inbound_room_key> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_vodozemac_inbound_group_session extends ffi.Struct {
+  @ffi.UintPtr()
+  external int session;
+
+  @ffi.UintPtr()
+  external int source;
+}
+
+final class wire_cst_vodozemac_account extends ffi.Struct {
+  @ffi.UintPtr()
+  external int account;
+}
+
+final class wire_cst_vodozemac_session extends ffi.Struct {
+  @ffi.UintPtr()
+  external int session;
+}
+
+final class wire_cst_list_vodozemac_session extends ffi.Struct {
+  external ffi.Pointer<wire_cst_vodozemac_session> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_vodozemac_group_session extends ffi.Struct {
+  @ffi.UintPtr()
+  external int session;
+}
+
+final class wire_cst_vodozemac_curve_25519_public_key extends ffi.Struct {
+  @ffi.UintPtr()
+  external int key;
+}
+
+final class wire_cst_vodozemac_ed_25519_public_key extends ffi.Struct {
+  @ffi.UintPtr()
+  external int key;
+}
+
+final class wire_cst_device_keys extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> user_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> device_id;
+
+  external ffi.Pointer<wire_cst_list_String> algorithms;
+
+  external wire_cst_vodozemac_ed_25519_public_key ed25519;
+
+  external wire_cst_vodozemac_curve_25519_public_key curve25519;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> display_name;
+}
+
+final class wire_cst_encrypted_room_key_bundle extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ciphertext;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> file;
+}
+
+final class wire_cst_fallback_key_tracker extends ffi.Struct {
+  @ffi.UintPtr()
+  external int state;
+}
+
+final class wire_cst_room_key_info extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> algorithm;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_claimed_ed25519;
 
-  IOSDecryptResult ios_decrypt_event(
-    ffi.Pointer<ffi.Char> pickled_session,
-    ffi.Pointer<ffi.Uint8> pickle_key,
-    int pickle_key_len,
-    ffi.Pointer<ffi.Char> ciphertext,
-  ) {
-    return _ios_decrypt_event(
-      pickled_session,
-      pickle_key,
-      pickle_key_len,
-      ciphertext,
-    );
-  }
+  external ffi.Pointer<wire_cst_list_String> forwarding_curve25519_key_chain;
 
-  late final _ios_decrypt_eventPtr = _lookup<
-      ffi.NativeFunction<
-          IOSDecryptResult Function(
-              ffi.Pointer<ffi.Char>, ffi.Pointer<ffi.Uint8>, ffi.UintPtr, ffi.Pointer<ffi.Char>)>>('ios_decrypt_event');
-  late final _ios_decrypt_event = _ios_decrypt_eventPtr.asFunction<
-      IOSDecryptResult Function(ffi.Pointer<ffi.Char>, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Char>)>();
+  @ffi.Int32()
+  external int origin;
 
-  void ios_free_string(
-    ffi.Pointer<ffi.Char> s,
-  ) {
-    return _ios_free_string(
-      s,
-    );
-  }
+  @ffi.Bool()
+  external bool shared_history;
 
-  late final _ios_free_stringPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Char>)>>('ios_free_string');
-  late final _ios_free_string = _ios_free_stringPtr.asFunction<void Function(ffi.Pointer<ffi.Char>)>();
+  @ffi.Bool()
+  external bool backed_up;
+}
 
-  void ios_free_result(
-    IOSDecryptResult result,
-  ) {
-    return _ios_free_result(
-      result,
-    );
-  }
+final class wire_cst_libolm_store extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> account;
 
-  late final _ios_free_resultPtr = _lookup<ffi.NativeFunction<ffi.Void Function(IOSDecryptResult)>>('ios_free_result');
-  late final _ios_free_result = _ios_free_resultPtr.asFunction<void Function(IOSDecryptResult)>();
+  external ffi.Pointer<wire_cst_list_String> sessions;
 
-  int dummy_method_to_enforce_bundling() {
-    return _dummy_method_to_enforce_bundling();
-  }
+  external ffi.Pointer<wire_cst_list_String> inbound_group_sessions;
 
-  late final _dummy_method_to_enforce_bundlingPtr =
-      _lookup<ffi.NativeFunction<ffi.Int64 Function()>>('dummy_method_to_enforce_bundling');
-  late final _dummy_method_to_enforce_bundling = _dummy_method_to_enforce_bundlingPtr.asFunction<int Function()>();
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pk_decryption;
 }
 
-typedef DartPort = ffi.Int64;
-typedef DartDartPort = int;
-typedef DartPostCObjectFnTypeFunction = ffi.Bool Function(DartPort port_id, ffi.Pointer<ffi.Void> message);
-typedef DartDartPostCObjectFnTypeFunction = bool Function(DartDartPort port_id, ffi.Pointer<ffi.Void> message);
-typedef DartPostCObjectFnType = ffi.Pointer<ffi.NativeFunction<DartPostCObjectFnTypeFunction>>;
+final class wire_cst_migrated_pickle extends ffi.Struct {
+  @ffi.Uint32()
+  external int index;
 
-final class wire_cst_list_prim_u_8_strict extends ffi.Struct {
-  external ffi.Pointer<ffi.Uint8> ptr;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id_before;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id_after;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle;
+}
+
+final class wire_cst_list_migrated_pickle extends ffi.Struct {
+  external ffi.Pointer<wire_cst_migrated_pickle> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_list_prim_u_8_loose extends ffi.Struct {
-  external ffi.Pointer<ffi.Uint8> ptr;
+final class wire_cst_migration_failure extends ffi.Struct {
+  @ffi.Int32()
+  external int object;
+
+  @ffi.Uint32()
+  external int index;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id_before;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id_after;
+
+  @ffi.Int32()
+  external int error;
+}
+
+final class wire_cst_list_migration_failure extends ffi.Struct {
+  external ffi.Pointer<wire_cst_migration_failure> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_vodozemac_account extends ffi.Struct {
+final class wire_cst_migration_report extends ffi.Struct {
+  external ffi.Pointer<wire_cst_migrated_pickle> account;
+
+  external ffi.Pointer<wire_cst_list_migrated_pickle> sessions;
+
+  external ffi.Pointer<wire_cst_list_migrated_pickle> inbound_group_sessions;
+
+  external ffi.Pointer<wire_cst_migrated_pickle> pk_decryption;
+
+  external ffi.Pointer<wire_cst_list_migration_failure> failures;
+}
+
+final class wire_cst_olm_session_store extends ffi.Struct {
   @ffi.UintPtr()
-  external int account;
+  external int state;
 }
 
-final class wire_cst_vodozemac_curve_25519_public_key extends ffi.Struct {
+final class wire_cst_outbound_group_session extends ffi.Struct {
   @ffi.UintPtr()
-  external int key;
+  external int session;
+
+  @ffi.UintPtr()
+  external int rotation;
+
+  @ffi.UintPtr()
+  external int shares;
 }
 
-final class wire_cst_vodozemac_olm_session_config extends ffi.Struct {
+final class wire_cst_share_recipient extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> user_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> device_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> curve25519_key;
+}
+
+final class wire_cst_list_share_recipient extends ffi.Struct {
+  external ffi.Pointer<wire_cst_share_recipient> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_vodozemac_megolm_session_config extends ffi.Struct {
   @ffi.UintPtr()
   external int config;
 }
 
-final class wire_cst_vodozemac_ed_25519_public_key extends ffi.Struct {
-  @ffi.UintPtr()
-  external int key;
+final class wire_cst_pickle_info extends ffi.Struct {
+  @ffi.Int32()
+  external int format;
+
+  @ffi.Int32()
+  external int object;
+
+  external ffi.Pointer<ffi.Uint32> version;
 }
 
-final class wire_cst_vodozemac_ed_25519_signature extends ffi.Struct {
-  @ffi.UintPtr()
-  external int signature;
+final class wire_cst_olm_payload extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> event_type;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> content;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sender;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_device;
+
+  external wire_cst_vodozemac_ed_25519_public_key sender_claimed_ed25519;
 }
 
-final class wire_cst_vodozemac_established_sas extends ffi.Struct {
+final class wire_cst_room_key_bundle_download extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> room_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> file;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_key;
+}
+
+final class wire_cst_unwedge_tracker extends ffi.Struct {
   @ffi.UintPtr()
-  external int established_sas;
+  external int state;
 }
 
-final class wire_cst_vodozemac_group_session extends ffi.Struct {
+final class wire_cst_utd_queue extends ffi.Struct {
   @ffi.UintPtr()
-  external int session;
+  external int state;
 }
 
-final class wire_cst_vodozemac_megolm_session_config extends ffi.Struct {
+final class wire_cst_vodozemac_olm_session_config extends ffi.Struct {
   @ffi.UintPtr()
   external int config;
 }
 
-final class wire_cst_vodozemac_inbound_group_session extends ffi.Struct {
+final class wire_cst_passphrase_kdf_params extends ffi.Struct {
+  @ffi.Int32()
+  external int kdf;
+
+  @ffi.Uint32()
+  external int iterations;
+}
+
+final class wire_cst_record_string_u_32 extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> field0;
+
+  @ffi.Uint32()
+  external int field1;
+}
+
+final class wire_cst_list_record_string_u_32 extends ffi.Struct {
+  external ffi.Pointer<wire_cst_record_string_u_32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_vodozemac_ed_25519_signature extends ffi.Struct {
   @ffi.UintPtr()
-  external int session;
+  external int signature;
+}
+
+final class wire_cst_vodozemac_established_sas extends ffi.Struct {
+  @ffi.UintPtr()
+  external int established_sas;
 }
 
 final class wire_cst_vodozemac_olm_message extends ffi.Struct {
@@ -3590,9 +8175,96 @@ final class wire_cst_vodozemac_pk_encryption extends ffi.Struct {
   external int pk_encryption;
 }
 
-final class wire_cst_vodozemac_session extends ffi.Struct {
-  @ffi.UintPtr()
-  external int session;
+final class wire_cst_retained_room_key extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> session_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle;
+
+  @ffi.Uint32()
+  external int first_known_index;
+
+  @ffi.Bool()
+  external bool discarded;
+}
+
+final class wire_cst_utd_classification extends ffi.Struct {
+  @ffi.Int32()
+  external int cause;
+
+  external ffi.Pointer<ffi.Int32> withheld_code;
+}
+
+final class wire_cst_device_trust extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> user_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> device_id;
+
+  @ffi.Int32()
+  external int level;
+
+  external ffi.Pointer<ffi.Int32> failed_link;
+}
+
+final class wire_cst_list_device_trust extends ffi.Struct {
+  external ffi.Pointer<wire_cst_device_trust> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_rekey_result extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pickle;
+
+  external ffi.Pointer<ffi.Int32> error;
+}
+
+final class wire_cst_list_rekey_result extends ffi.Struct {
+  external ffi.Pointer<wire_cst_rekey_result> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_retention_result extends ffi.Struct {
+  external ffi.Pointer<wire_cst_retained_room_key> room_key;
+
+  external ffi.Pointer<ffi.Int32> error;
+}
+
+final class wire_cst_list_retention_result extends ffi.Struct {
+  external ffi.Pointer<wire_cst_retention_result> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_room_key_decryption_result extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> event_type;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> content;
+
+  @ffi.Uint32()
+  external int message_index;
+
+  @ffi.Int32()
+  external int authenticity;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sender_claimed_ed25519;
+}
+
+final class wire_cst_retried_event extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> event_id;
+
+  external wire_cst_room_key_decryption_result result;
+}
+
+final class wire_cst_list_retried_event extends ffi.Struct {
+  external ffi.Pointer<wire_cst_retried_event> ptr;
+
+  @ffi.Int32()
+  external int len;
 }
 
 final class wire_cst_vodozemac_one_time_key extends ffi.Struct {
@@ -3614,6 +8286,13 @@ final class IOSDecryptResult extends ffi.Struct {
   external ffi.Pointer<ffi.Char> error;
 }
 
+final class wire_cst_claimed_session extends ffi.Struct {
+  external wire_cst_vodozemac_session session;
+
+  @ffi.Bool()
+  external bool fallback;
+}
+
 final class wire_cst_decrypt_result extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> field0;
 
@@ -3621,6 +8300,36 @@ final class wire_cst_decrypt_result extends ffi.Struct {
   external int field1;
 }
 
+final class wire_cst_extended_decrypt_result extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext;
+
+  @ffi.Uint32()
+  external int message_index;
+
+  external wire_cst_vodozemac_ed_25519_public_key signing_key;
+
+  @ffi.Int32()
+  external int key_source;
+}
+
+final class wire_cst_imported_room_key_bundle extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_inbound_room_key> room_keys;
+
+  @ffi.Uint32()
+  external int skipped;
+}
+
+final class wire_cst_key_maintenance_plan extends ffi.Struct {
+  @ffi.UintPtr()
+  external int generate_one_time_keys;
+
+  @ffi.Bool()
+  external bool rotate_fallback_key;
+
+  @ffi.Bool()
+  external bool forget_old_fallback_key;
+}
+
 final class wire_cst_record_string_string_string extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> field0;
 
@@ -3629,6 +8338,73 @@ final class wire_cst_record_string_string_string extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> field2;
 }
 
+final class wire_cst_rekey_progress extends ffi.Struct {
+  @ffi.Uint32()
+  external int done;
+
+  external ffi.Pointer<wire_cst_list_rekey_result> results;
+}
+
+final class wire_cst_room_event_decryption_result extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> event_type;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> content;
+
+  @ffi.Uint32()
+  external int message_index;
+}
+
+final class wire_cst_to_device_decryption_result extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext;
+
+  external wire_cst_vodozemac_curve_25519_public_key sender_key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> session_id;
+
+  external ffi.Pointer<wire_cst_vodozemac_session> new_session;
+}
+
+final class wire_cst_unpickled_account extends ffi.Struct {
+  external wire_cst_vodozemac_account account;
+
+  @ffi.Bool()
+  external bool needs_repickle;
+}
+
+final class wire_cst_unpickled_group_session extends ffi.Struct {
+  external wire_cst_vodozemac_group_session session;
+
+  @ffi.Bool()
+  external bool needs_repickle;
+}
+
+final class wire_cst_unpickled_inbound_group_session extends ffi.Struct {
+  external wire_cst_vodozemac_inbound_group_session session;
+
+  @ffi.Bool()
+  external bool needs_repickle;
+}
+
+final class wire_cst_unpickled_pk_decryption extends ffi.Struct {
+  external wire_cst_vodozemac_pk_decryption pk_decryption;
+
+  @ffi.Bool()
+  external bool needs_repickle;
+}
+
+final class wire_cst_unpickled_session extends ffi.Struct {
+  external wire_cst_vodozemac_session session;
+
+  @ffi.Bool()
+  external bool needs_repickle;
+}
+
+final class wire_cst_unwedge_result extends ffi.Struct {
+  external wire_cst_vodozemac_session session;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> encrypted_content;
+}
+
 final class wire_cst_vodozemac_identity_keys extends ffi.Struct {
   external wire_cst_vodozemac_ed_25519_public_key ed25519;
 
@@ -3,1879 +3,2953 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
 import 'bindings.dart';
+import 'bindings/cross_signing.dart';
+import 'bindings/device_keys.dart';
+import 'bindings/forwarded_room_key.dart';
+import 'bindings/key_claim.dart';
+import 'bindings/key_maintenance.dart';
+import 'bindings/megolm_signing.dart';
+import 'bindings/migration.dart';
+import 'bindings/olm_sessions.dart';
+import 'bindings/outbound_group_session.dart';
+import 'bindings/passphrase_pickle.dart';
+import 'bindings/pickle_format.dart';
+import 'bindings/rekey.dart';
+import 'bindings/replay.dart';
+import 'bindings/retention.dart';
+import 'bindings/room_event.dart';
+import 'bindings/room_key.dart';
+import 'bindings/room_key_bundle.dart';
+import 'bindings/room_key_info.dart';
+import 'bindings/share_tracking.dart';
+import 'bindings/to_device.dart';
+import 'bindings/trust.dart';
+import 'bindings/unwedge.dart';
+import 'bindings/utd_cause.dart';
+import 'bindings/utd_queue.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Curve25519PublicKeyPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Ed25519PublicKeyPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Ed25519SignaturePtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature;
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EstablishedSasPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_EstablishedSas;
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Curve25519PublicKeyPtr => wire.rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MegolmSessionConfigPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Ed25519PublicKeyPtr => wire.rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OlmMessagePtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_OlmMessage;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Ed25519SignaturePtr => wire.rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OlmSessionConfigPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_EstablishedSasPtr => wire.rust_arc_decrement_strong_count_RustOpaque_EstablishedSas;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkDecryptionPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_PkDecryption;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MegolmSessionConfigPtr => wire.rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkEncryptionPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_PkEncryption;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OlmMessagePtr => wire.rust_arc_decrement_strong_count_RustOpaque_OlmMessage;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockGroupSessionPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OlmSessionConfigPtr => wire.rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockInboundGroupSessionPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkDecryptionPtr => wire.rust_arc_decrement_strong_count_RustOpaque_PkDecryption;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockSessionPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_RwLockSession;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkEncryptionPtr => wire.rust_arc_decrement_strong_count_RustOpaque_PkEncryption;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkSigningPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockFallbackKeyStatePtr => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockFallbackKeyState;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_VodozemacSasPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockGroupSessionPtr => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession;
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockAccountPtr =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount;
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockInboundGroupSessionPtr => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession;
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockReplayStatePtr => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockReplayState;
 
-  @protected
-  PkSigning dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockRoomKeyInfoPtr => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockRoomKeyInfo;
 
-  @protected
-  VodozemacSas dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockRotationStatePtr => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockRotationState;
 
-  @protected
-  PkSigning dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockSessionPtr => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockSession;
 
-  @protected
-  VodozemacSas dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockSessionKeySourcePtr => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockSessionKeySource;
 
-  @protected
-  Curve25519PublicKey dco_decode_RustOpaque_Curve25519PublicKey(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockSessionStoreStatePtr => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockSessionStoreState;
 
-  @protected
-  Ed25519PublicKey dco_decode_RustOpaque_Ed25519PublicKey(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockShareStatePtr => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockShareState;
 
-  @protected
-  Ed25519Signature dco_decode_RustOpaque_Ed25519Signature(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockUnwedgeStatePtr => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockUnwedgeState;
 
-  @protected
-  EstablishedSas dco_decode_RustOpaque_EstablishedSas(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockUtdQueueStatePtr => wire.rust_arc_decrement_strong_count_RustOpaque_RwLockUtdQueueState;
 
-  @protected
-  MegolmSessionConfig dco_decode_RustOpaque_MegolmSessionConfig(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CrossSigningIdentityPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity;
 
-  @protected
-  OlmMessage dco_decode_RustOpaque_OlmMessage(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PkSigningPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning;
 
-  @protected
-  OlmSessionConfig dco_decode_RustOpaque_OlmSessionConfig(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_VodozemacSasPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas;
 
-  @protected
-  PkDecryption dco_decode_RustOpaque_PkDecryption(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RwLockAccountPtr => wire.rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount;
 
-  @protected
-  PkEncryption dco_decode_RustOpaque_PkEncryption(dynamic raw);
 
-  @protected
-  RwLockGroupSession dco_decode_RustOpaque_RwLockGroupSession(dynamic raw);
 
-  @protected
-  RwLockInboundGroupSession dco_decode_RustOpaque_RwLockInboundGroupSession(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  RwLockSession dco_decode_RustOpaque_RwLockSession(dynamic raw);
+@protected CrossSigningIdentity dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(dynamic raw);
 
-  @protected
-  PkSigning dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw);
+@protected PkSigning dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw);
 
-  @protected
-  VodozemacSas dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(dynamic raw);
+@protected VodozemacSas dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(dynamic raw);
 
-  @protected
-  RwLockAccount dco_decode_RustOpaque_stdsyncRwLockAccount(dynamic raw);
+@protected CrossSigningIdentity dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(dynamic raw);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+@protected PkSigning dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+@protected VodozemacSas dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(dynamic raw);
 
-  @protected
-  VodozemacAccount dco_decode_box_autoadd_vodozemac_account(dynamic raw);
+@protected Map<String, int> dco_decode_Map_String_u_32_None(dynamic raw);
 
-  @protected
-  VodozemacCurve25519PublicKey dco_decode_box_autoadd_vodozemac_curve_25519_public_key(dynamic raw);
+@protected Curve25519PublicKey dco_decode_RustOpaque_Curve25519PublicKey(dynamic raw);
 
-  @protected
-  VodozemacEd25519PublicKey dco_decode_box_autoadd_vodozemac_ed_25519_public_key(dynamic raw);
+@protected Ed25519PublicKey dco_decode_RustOpaque_Ed25519PublicKey(dynamic raw);
 
-  @protected
-  VodozemacEd25519Signature dco_decode_box_autoadd_vodozemac_ed_25519_signature(dynamic raw);
+@protected Ed25519Signature dco_decode_RustOpaque_Ed25519Signature(dynamic raw);
 
-  @protected
-  VodozemacEstablishedSas dco_decode_box_autoadd_vodozemac_established_sas(dynamic raw);
+@protected EstablishedSas dco_decode_RustOpaque_EstablishedSas(dynamic raw);
 
-  @protected
-  VodozemacGroupSession dco_decode_box_autoadd_vodozemac_group_session(dynamic raw);
+@protected MegolmSessionConfig dco_decode_RustOpaque_MegolmSessionConfig(dynamic raw);
 
-  @protected
-  VodozemacInboundGroupSession dco_decode_box_autoadd_vodozemac_inbound_group_session(dynamic raw);
+@protected OlmMessage dco_decode_RustOpaque_OlmMessage(dynamic raw);
 
-  @protected
-  VodozemacMegolmSessionConfig dco_decode_box_autoadd_vodozemac_megolm_session_config(dynamic raw);
+@protected OlmSessionConfig dco_decode_RustOpaque_OlmSessionConfig(dynamic raw);
 
-  @protected
-  VodozemacOlmMessage dco_decode_box_autoadd_vodozemac_olm_message(dynamic raw);
+@protected PkDecryption dco_decode_RustOpaque_PkDecryption(dynamic raw);
 
-  @protected
-  VodozemacOlmSessionConfig dco_decode_box_autoadd_vodozemac_olm_session_config(dynamic raw);
+@protected PkEncryption dco_decode_RustOpaque_PkEncryption(dynamic raw);
 
-  @protected
-  VodozemacPkDecryption dco_decode_box_autoadd_vodozemac_pk_decryption(dynamic raw);
+@protected RwLockFallbackKeyState dco_decode_RustOpaque_RwLockFallbackKeyState(dynamic raw);
 
-  @protected
-  VodozemacPkEncryption dco_decode_box_autoadd_vodozemac_pk_encryption(dynamic raw);
+@protected RwLockGroupSession dco_decode_RustOpaque_RwLockGroupSession(dynamic raw);
 
-  @protected
-  VodozemacPkMessage dco_decode_box_autoadd_vodozemac_pk_message(dynamic raw);
+@protected RwLockInboundGroupSession dco_decode_RustOpaque_RwLockInboundGroupSession(dynamic raw);
 
-  @protected
-  VodozemacSession dco_decode_box_autoadd_vodozemac_session(dynamic raw);
+@protected RwLockReplayState dco_decode_RustOpaque_RwLockReplayState(dynamic raw);
 
-  @protected
-  DecryptResult dco_decode_decrypt_result(dynamic raw);
+@protected RwLockRoomKeyInfo dco_decode_RustOpaque_RwLockRoomKeyInfo(dynamic raw);
 
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+@protected RwLockRotationState dco_decode_RustOpaque_RwLockRotationState(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected RwLockSession dco_decode_RustOpaque_RwLockSession(dynamic raw);
 
-  @protected
-  List<VodozemacOneTimeKey> dco_decode_list_vodozemac_one_time_key(dynamic raw);
+@protected RwLockSessionKeySource dco_decode_RustOpaque_RwLockSessionKeySource(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected RwLockSessionStoreState dco_decode_RustOpaque_RwLockSessionStoreState(dynamic raw);
 
-  @protected
-  (String, String, String) dco_decode_record_string_string_string(dynamic raw);
+@protected RwLockShareState dco_decode_RustOpaque_RwLockShareState(dynamic raw);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected RwLockUnwedgeState dco_decode_RustOpaque_RwLockUnwedgeState(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected RwLockUtdQueueState dco_decode_RustOpaque_RwLockUtdQueueState(dynamic raw);
 
-  @protected
-  U8Array32 dco_decode_u_8_array_32(dynamic raw);
+@protected CrossSigningIdentity dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(dynamic raw);
 
-  @protected
-  U8Array64 dco_decode_u_8_array_64(dynamic raw);
+@protected PkSigning dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected VodozemacSas dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(dynamic raw);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected RwLockAccount dco_decode_RustOpaque_stdsyncRwLockAccount(dynamic raw);
 
-  @protected
-  VodozemacAccount dco_decode_vodozemac_account(dynamic raw);
+@protected RustStreamSink<RekeyProgress> dco_decode_StreamSink_rekey_progress_Dco(dynamic raw);
 
-  @protected
-  VodozemacCurve25519PublicKey dco_decode_vodozemac_curve_25519_public_key(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  VodozemacEd25519PublicKey dco_decode_vodozemac_ed_25519_public_key(dynamic raw);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  VodozemacEd25519Signature dco_decode_vodozemac_ed_25519_signature(dynamic raw);
+@protected DeviceKeys dco_decode_box_autoadd_device_keys(dynamic raw);
 
-  @protected
-  VodozemacEstablishedSas dco_decode_vodozemac_established_sas(dynamic raw);
+@protected EncryptedRoomKeyBundle dco_decode_box_autoadd_encrypted_room_key_bundle(dynamic raw);
 
-  @protected
-  VodozemacGroupSession dco_decode_vodozemac_group_session(dynamic raw);
+@protected FallbackKeyTracker dco_decode_box_autoadd_fallback_key_tracker(dynamic raw);
 
-  @protected
-  VodozemacIdentityKeys dco_decode_vodozemac_identity_keys(dynamic raw);
+@protected InboundRoomKey dco_decode_box_autoadd_inbound_room_key(dynamic raw);
 
-  @protected
-  VodozemacInboundGroupSession dco_decode_vodozemac_inbound_group_session(dynamic raw);
+@protected LibolmStore dco_decode_box_autoadd_libolm_store(dynamic raw);
 
-  @protected
-  VodozemacMegolmSessionConfig dco_decode_vodozemac_megolm_session_config(dynamic raw);
+@protected MegolmReplayGuard dco_decode_box_autoadd_megolm_replay_guard(dynamic raw);
 
-  @protected
-  VodozemacOlmMessage dco_decode_vodozemac_olm_message(dynamic raw);
+@protected MigratedPickle dco_decode_box_autoadd_migrated_pickle(dynamic raw);
 
-  @protected
-  VodozemacOlmSessionConfig dco_decode_vodozemac_olm_session_config(dynamic raw);
+@protected MigrationReport dco_decode_box_autoadd_migration_report(dynamic raw);
 
-  @protected
-  VodozemacOlmSessionCreationResult dco_decode_vodozemac_olm_session_creation_result(dynamic raw);
+@protected OlmPayload dco_decode_box_autoadd_olm_payload(dynamic raw);
 
-  @protected
-  VodozemacOneTimeKey dco_decode_vodozemac_one_time_key(dynamic raw);
+@protected OlmSessionStore dco_decode_box_autoadd_olm_session_store(dynamic raw);
 
-  @protected
-  VodozemacPkDecryption dco_decode_vodozemac_pk_decryption(dynamic raw);
+@protected OutboundGroupSession dco_decode_box_autoadd_outbound_group_session(dynamic raw);
 
-  @protected
-  VodozemacPkEncryption dco_decode_vodozemac_pk_encryption(dynamic raw);
+@protected PassphraseKdfParams dco_decode_box_autoadd_passphrase_kdf_params(dynamic raw);
 
-  @protected
-  VodozemacPkMessage dco_decode_vodozemac_pk_message(dynamic raw);
+@protected PickleFormatError dco_decode_box_autoadd_pickle_format_error(dynamic raw);
 
-  @protected
-  VodozemacSession dco_decode_vodozemac_session(dynamic raw);
+@protected PickleInfo dco_decode_box_autoadd_pickle_info(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected RetainedRoomKey dco_decode_box_autoadd_retained_room_key(dynamic raw);
 
-  @protected
-  PkSigning sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      SseDeserializer deserializer);
+@protected RoomKeyBundleDownload dco_decode_box_autoadd_room_key_bundle_download(dynamic raw);
 
-  @protected
-  VodozemacSas sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      SseDeserializer deserializer);
+@protected RoomKeyInfo dco_decode_box_autoadd_room_key_info(dynamic raw);
 
-  @protected
-  PkSigning sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      SseDeserializer deserializer);
+@protected RotationReason dco_decode_box_autoadd_rotation_reason(dynamic raw);
 
-  @protected
-  VodozemacSas sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      SseDeserializer deserializer);
+@protected ShareRecipient dco_decode_box_autoadd_share_recipient(dynamic raw);
 
-  @protected
-  Curve25519PublicKey sse_decode_RustOpaque_Curve25519PublicKey(SseDeserializer deserializer);
+@protected TrustLink dco_decode_box_autoadd_trust_link(dynamic raw);
 
-  @protected
-  Ed25519PublicKey sse_decode_RustOpaque_Ed25519PublicKey(SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  Ed25519Signature sse_decode_RustOpaque_Ed25519Signature(SseDeserializer deserializer);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  EstablishedSas sse_decode_RustOpaque_EstablishedSas(SseDeserializer deserializer);
+@protected UnwedgeTracker dco_decode_box_autoadd_unwedge_tracker(dynamic raw);
 
-  @protected
-  MegolmSessionConfig sse_decode_RustOpaque_MegolmSessionConfig(SseDeserializer deserializer);
+@protected UtdClassification dco_decode_box_autoadd_utd_classification(dynamic raw);
 
-  @protected
-  OlmMessage sse_decode_RustOpaque_OlmMessage(SseDeserializer deserializer);
+@protected UtdQueue dco_decode_box_autoadd_utd_queue(dynamic raw);
 
-  @protected
-  OlmSessionConfig sse_decode_RustOpaque_OlmSessionConfig(SseDeserializer deserializer);
+@protected VodozemacAccount dco_decode_box_autoadd_vodozemac_account(dynamic raw);
 
-  @protected
-  PkDecryption sse_decode_RustOpaque_PkDecryption(SseDeserializer deserializer);
+@protected VodozemacCurve25519PublicKey dco_decode_box_autoadd_vodozemac_curve_25519_public_key(dynamic raw);
 
-  @protected
-  PkEncryption sse_decode_RustOpaque_PkEncryption(SseDeserializer deserializer);
+@protected VodozemacEd25519PublicKey dco_decode_box_autoadd_vodozemac_ed_25519_public_key(dynamic raw);
 
-  @protected
-  RwLockGroupSession sse_decode_RustOpaque_RwLockGroupSession(SseDeserializer deserializer);
+@protected VodozemacEd25519Signature dco_decode_box_autoadd_vodozemac_ed_25519_signature(dynamic raw);
 
-  @protected
-  RwLockInboundGroupSession sse_decode_RustOpaque_RwLockInboundGroupSession(SseDeserializer deserializer);
+@protected VodozemacEstablishedSas dco_decode_box_autoadd_vodozemac_established_sas(dynamic raw);
 
-  @protected
-  RwLockSession sse_decode_RustOpaque_RwLockSession(SseDeserializer deserializer);
+@protected VodozemacGroupSession dco_decode_box_autoadd_vodozemac_group_session(dynamic raw);
 
-  @protected
-  PkSigning sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      SseDeserializer deserializer);
+@protected VodozemacInboundGroupSession dco_decode_box_autoadd_vodozemac_inbound_group_session(dynamic raw);
 
-  @protected
-  VodozemacSas sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      SseDeserializer deserializer);
+@protected VodozemacMegolmSessionConfig dco_decode_box_autoadd_vodozemac_megolm_session_config(dynamic raw);
 
-  @protected
-  RwLockAccount sse_decode_RustOpaque_stdsyncRwLockAccount(SseDeserializer deserializer);
+@protected VodozemacOlmMessage dco_decode_box_autoadd_vodozemac_olm_message(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected VodozemacOlmSessionConfig dco_decode_box_autoadd_vodozemac_olm_session_config(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected VodozemacPkDecryption dco_decode_box_autoadd_vodozemac_pk_decryption(dynamic raw);
 
-  @protected
-  VodozemacAccount sse_decode_box_autoadd_vodozemac_account(SseDeserializer deserializer);
+@protected VodozemacPkEncryption dco_decode_box_autoadd_vodozemac_pk_encryption(dynamic raw);
 
-  @protected
-  VodozemacCurve25519PublicKey sse_decode_box_autoadd_vodozemac_curve_25519_public_key(SseDeserializer deserializer);
+@protected VodozemacPkMessage dco_decode_box_autoadd_vodozemac_pk_message(dynamic raw);
 
-  @protected
-  VodozemacEd25519PublicKey sse_decode_box_autoadd_vodozemac_ed_25519_public_key(SseDeserializer deserializer);
+@protected VodozemacSession dco_decode_box_autoadd_vodozemac_session(dynamic raw);
 
-  @protected
-  VodozemacEd25519Signature sse_decode_box_autoadd_vodozemac_ed_25519_signature(SseDeserializer deserializer);
+@protected WithheldCode dco_decode_box_autoadd_withheld_code(dynamic raw);
 
-  @protected
-  VodozemacEstablishedSas sse_decode_box_autoadd_vodozemac_established_sas(SseDeserializer deserializer);
+@protected ClaimedSession dco_decode_claimed_session(dynamic raw);
 
-  @protected
-  VodozemacGroupSession sse_decode_box_autoadd_vodozemac_group_session(SseDeserializer deserializer);
+@protected DecryptResult dco_decode_decrypt_result(dynamic raw);
 
-  @protected
-  VodozemacInboundGroupSession sse_decode_box_autoadd_vodozemac_inbound_group_session(SseDeserializer deserializer);
+@protected DeviceKeys dco_decode_device_keys(dynamic raw);
 
-  @protected
-  VodozemacMegolmSessionConfig sse_decode_box_autoadd_vodozemac_megolm_session_config(SseDeserializer deserializer);
+@protected DeviceKeysError dco_decode_device_keys_error(dynamic raw);
 
-  @protected
-  VodozemacOlmMessage sse_decode_box_autoadd_vodozemac_olm_message(SseDeserializer deserializer);
+@protected DeviceTrust dco_decode_device_trust(dynamic raw);
 
-  @protected
-  VodozemacOlmSessionConfig sse_decode_box_autoadd_vodozemac_olm_session_config(SseDeserializer deserializer);
+@protected DeviceTrustLevel dco_decode_device_trust_level(dynamic raw);
 
-  @protected
-  VodozemacPkDecryption sse_decode_box_autoadd_vodozemac_pk_decryption(SseDeserializer deserializer);
+@protected EncryptedRoomKeyBundle dco_decode_encrypted_room_key_bundle(dynamic raw);
 
-  @protected
-  VodozemacPkEncryption sse_decode_box_autoadd_vodozemac_pk_encryption(SseDeserializer deserializer);
+@protected ExtendedDecryptResult dco_decode_extended_decrypt_result(dynamic raw);
 
-  @protected
-  VodozemacPkMessage sse_decode_box_autoadd_vodozemac_pk_message(SseDeserializer deserializer);
+@protected FallbackKeyTracker dco_decode_fallback_key_tracker(dynamic raw);
 
-  @protected
-  VodozemacSession sse_decode_box_autoadd_vodozemac_session(SseDeserializer deserializer);
+@protected int dco_decode_i_32(dynamic raw);
 
-  @protected
-  DecryptResult sse_decode_decrypt_result(SseDeserializer deserializer);
+@protected ImportedRoomKeyBundle dco_decode_imported_room_key_bundle(dynamic raw);
 
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+@protected InboundRoomKey dco_decode_inbound_room_key(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected KeyAuthenticity dco_decode_key_authenticity(dynamic raw);
 
-  @protected
-  List<VodozemacOneTimeKey> sse_decode_list_vodozemac_one_time_key(SseDeserializer deserializer);
+@protected KeyClaimError dco_decode_key_claim_error(dynamic raw);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected KeyMaintenancePlan dco_decode_key_maintenance_plan(dynamic raw);
 
-  @protected
-  (String, String, String) sse_decode_record_string_string_string(SseDeserializer deserializer);
+@protected LibolmStore dco_decode_libolm_store(dynamic raw);
 
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+@protected List<String> dco_decode_list_String(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected List<DeviceTrust> dco_decode_list_device_trust(dynamic raw);
 
-  @protected
-  U8Array32 sse_decode_u_8_array_32(SseDeserializer deserializer);
+@protected List<InboundRoomKey> dco_decode_list_inbound_room_key(dynamic raw);
 
-  @protected
-  U8Array64 sse_decode_u_8_array_64(SseDeserializer deserializer);
+@protected List<MigratedPickle> dco_decode_list_migrated_pickle(dynamic raw);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected List<MigrationFailure> dco_decode_list_migration_failure(dynamic raw);
 
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
-  @protected
-  VodozemacAccount sse_decode_vodozemac_account(SseDeserializer deserializer);
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  VodozemacCurve25519PublicKey sse_decode_vodozemac_curve_25519_public_key(SseDeserializer deserializer);
+@protected List<(String,int)> dco_decode_list_record_string_u_32(dynamic raw);
 
-  @protected
-  VodozemacEd25519PublicKey sse_decode_vodozemac_ed_25519_public_key(SseDeserializer deserializer);
+@protected List<RekeyResult> dco_decode_list_rekey_result(dynamic raw);
 
-  @protected
-  VodozemacEd25519Signature sse_decode_vodozemac_ed_25519_signature(SseDeserializer deserializer);
+@protected List<RetentionResult> dco_decode_list_retention_result(dynamic raw);
 
-  @protected
-  VodozemacEstablishedSas sse_decode_vodozemac_established_sas(SseDeserializer deserializer);
+@protected List<RetriedEvent> dco_decode_list_retried_event(dynamic raw);
 
-  @protected
-  VodozemacGroupSession sse_decode_vodozemac_group_session(SseDeserializer deserializer);
+@protected List<RoomRetention> dco_decode_list_room_retention(dynamic raw);
 
-  @protected
-  VodozemacIdentityKeys sse_decode_vodozemac_identity_keys(SseDeserializer deserializer);
+@protected List<ShareRecipient> dco_decode_list_share_recipient(dynamic raw);
 
-  @protected
-  VodozemacInboundGroupSession sse_decode_vodozemac_inbound_group_session(SseDeserializer deserializer);
+@protected List<VodozemacOneTimeKey> dco_decode_list_vodozemac_one_time_key(dynamic raw);
 
-  @protected
-  VodozemacMegolmSessionConfig sse_decode_vodozemac_megolm_session_config(SseDeserializer deserializer);
+@protected List<VodozemacSession> dco_decode_list_vodozemac_session(dynamic raw);
 
-  @protected
-  VodozemacOlmMessage sse_decode_vodozemac_olm_message(SseDeserializer deserializer);
+@protected MegolmReplayGuard dco_decode_megolm_replay_guard(dynamic raw);
 
-  @protected
-  VodozemacOlmSessionConfig sse_decode_vodozemac_olm_session_config(SseDeserializer deserializer);
+@protected MigratedPickle dco_decode_migrated_pickle(dynamic raw);
 
-  @protected
-  VodozemacOlmSessionCreationResult sse_decode_vodozemac_olm_session_creation_result(SseDeserializer deserializer);
+@protected MigrationError dco_decode_migration_error(dynamic raw);
 
-  @protected
-  VodozemacOneTimeKey sse_decode_vodozemac_one_time_key(SseDeserializer deserializer);
+@protected MigrationFailure dco_decode_migration_failure(dynamic raw);
 
-  @protected
-  VodozemacPkDecryption sse_decode_vodozemac_pk_decryption(SseDeserializer deserializer);
+@protected MigrationReport dco_decode_migration_report(dynamic raw);
 
-  @protected
-  VodozemacPkEncryption sse_decode_vodozemac_pk_encryption(SseDeserializer deserializer);
+@protected OlmPayload dco_decode_olm_payload(dynamic raw);
 
-  @protected
-  VodozemacPkMessage sse_decode_vodozemac_pk_message(SseDeserializer deserializer);
+@protected OlmPayloadError dco_decode_olm_payload_error(dynamic raw);
 
-  @protected
-  VodozemacSession sse_decode_vodozemac_session(SseDeserializer deserializer);
+@protected OlmSessionStore dco_decode_olm_session_store(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected String? dco_decode_opt_String(dynamic raw);
 
-  @protected
-  String cst_encode_AnyhowException(AnyhowException raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  String cst_encode_String(String raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_account(VodozemacAccount raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_account(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_curve_25519_public_key(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_ed_25519_public_key(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_ed_25519_signature(VodozemacEd25519Signature raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_ed_25519_signature(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_established_sas(VodozemacEstablishedSas raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_established_sas(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_group_session(VodozemacGroupSession raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_group_session(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_inbound_group_session(VodozemacInboundGroupSession raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_inbound_group_session(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_megolm_session_config(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_olm_message(VodozemacOlmMessage raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_olm_message(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_olm_session_config(VodozemacOlmSessionConfig raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_olm_session_config(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_pk_decryption(VodozemacPkDecryption raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_pk_decryption(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_pk_encryption(VodozemacPkEncryption raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_pk_encryption(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_pk_message(VodozemacPkMessage raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_pk_message(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_vodozemac_session(VodozemacSession raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_vodozemac_session(raw);
-  }
-
-  @protected
-  JSAny cst_encode_decrypt_result(DecryptResult raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_String(raw.field0), cst_encode_u_32(raw.field1)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_list_prim_u_8_loose(List<int> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_list_prim_u_8_strict(Uint8List raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_list_vodozemac_one_time_key(List<VodozemacOneTimeKey> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_vodozemac_one_time_key).toList().jsify()!;
-  }
-
-  @protected
-  String? cst_encode_opt_String(String? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_String(raw);
-  }
-
-  @protected
-  JSAny cst_encode_record_string_string_string((String, String, String) raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_String(raw.$1), cst_encode_String(raw.$2), cst_encode_String(raw.$3)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_u_8_array_32(U8Array32 raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return Uint8List.fromList(raw).jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_u_8_array_64(U8Array64 raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return Uint8List.fromList(raw).jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_usize(BigInt raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return castNativeBigInt(raw);
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_account(VodozemacAccount raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_stdsyncRwLockAccount(raw.account)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_Curve25519PublicKey(raw.key)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_Ed25519PublicKey(raw.key)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_ed_25519_signature(VodozemacEd25519Signature raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_Ed25519Signature(raw.signature)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_established_sas(VodozemacEstablishedSas raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_EstablishedSas(raw.establishedSas)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_group_session(VodozemacGroupSession raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_RwLockGroupSession(raw.session)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_identity_keys(VodozemacIdentityKeys raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_vodozemac_ed_25519_public_key(raw.ed25519),
-      cst_encode_vodozemac_curve_25519_public_key(raw.curve25519)
-    ].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_inbound_group_session(VodozemacInboundGroupSession raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_RwLockInboundGroupSession(raw.session)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_MegolmSessionConfig(raw.config)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_olm_message(VodozemacOlmMessage raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_OlmMessage(raw.msg)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_olm_session_config(VodozemacOlmSessionConfig raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_OlmSessionConfig(raw.config)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_olm_session_creation_result(VodozemacOlmSessionCreationResult raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_vodozemac_session(raw.session), cst_encode_String(raw.plaintext)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_one_time_key(VodozemacOneTimeKey raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_String(raw.keyid), cst_encode_vodozemac_curve_25519_public_key(raw.key)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_pk_decryption(VodozemacPkDecryption raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_PkDecryption(raw.pkDecryption)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_pk_encryption(VodozemacPkEncryption raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_PkEncryption(raw.pkEncryption)].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_pk_message(VodozemacPkMessage raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_list_prim_u_8_strict(raw.ciphertext),
-      cst_encode_list_prim_u_8_strict(raw.mac),
-      cst_encode_vodozemac_curve_25519_public_key(raw.ephemeralKey)
-    ].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_vodozemac_session(VodozemacSession raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_RustOpaque_RwLockSession(raw.session)].jsify()!;
-  }
-
-  @protected
-  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw);
-
-  @protected
-  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      VodozemacSas raw);
-
-  @protected
-  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw);
-
-  @protected
-  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw);
+@protected MigratedPickle? dco_decode_opt_box_autoadd_migrated_pickle(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_Curve25519PublicKey(Curve25519PublicKey raw);
+@protected PickleFormatError? dco_decode_opt_box_autoadd_pickle_format_error(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_Ed25519PublicKey(Ed25519PublicKey raw);
+@protected RetainedRoomKey? dco_decode_opt_box_autoadd_retained_room_key(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_Ed25519Signature(Ed25519Signature raw);
+@protected RotationReason? dco_decode_opt_box_autoadd_rotation_reason(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_EstablishedSas(EstablishedSas raw);
+@protected TrustLink? dco_decode_opt_box_autoadd_trust_link(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_MegolmSessionConfig(MegolmSessionConfig raw);
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_OlmMessage(OlmMessage raw);
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_OlmSessionConfig(OlmSessionConfig raw);
+@protected UtdClassification? dco_decode_opt_box_autoadd_utd_classification(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_PkDecryption(PkDecryption raw);
+@protected VodozemacEd25519PublicKey? dco_decode_opt_box_autoadd_vodozemac_ed_25519_public_key(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_PkEncryption(PkEncryption raw);
+@protected VodozemacSession? dco_decode_opt_box_autoadd_vodozemac_session(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_RwLockGroupSession(RwLockGroupSession raw);
+@protected WithheldCode? dco_decode_opt_box_autoadd_withheld_code(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_RwLockInboundGroupSession(RwLockInboundGroupSession raw);
+@protected List<String>? dco_decode_opt_list_String(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_RwLockSession(RwLockSession raw);
+@protected List<RekeyResult>? dco_decode_opt_list_rekey_result(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw);
+@protected OutboundGroupSession dco_decode_outbound_group_session(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw);
+@protected PassphraseKdf dco_decode_passphrase_kdf(dynamic raw);
 
-  @protected
-  int cst_encode_RustOpaque_stdsyncRwLockAccount(RwLockAccount raw);
+@protected PassphraseKdfParams dco_decode_passphrase_kdf_params(dynamic raw);
 
-  @protected
-  bool cst_encode_bool(bool raw);
+@protected PassphrasePickleError dco_decode_passphrase_pickle_error(dynamic raw);
 
-  @protected
-  int cst_encode_u_32(int raw);
+@protected PickleFormat dco_decode_pickle_format(dynamic raw);
 
-  @protected
-  int cst_encode_u_8(int raw);
+@protected PickleFormatError dco_decode_pickle_format_error(dynamic raw);
 
-  @protected
-  void cst_encode_unit(void raw);
+@protected PickleInfo dco_decode_pickle_info(dynamic raw);
 
-  @protected
-  void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+@protected PickleObject dco_decode_pickle_object(dynamic raw);
 
-  @protected
-  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      PkSigning self, SseSerializer serializer);
+@protected (String,String,String) dco_decode_record_string_string_string(dynamic raw);
 
-  @protected
-  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      VodozemacSas self, SseSerializer serializer);
+@protected (String,int) dco_decode_record_string_u_32(dynamic raw);
 
-  @protected
-  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      PkSigning self, SseSerializer serializer);
+@protected RekeyProgress dco_decode_rekey_progress(dynamic raw);
 
-  @protected
-  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      VodozemacSas self, SseSerializer serializer);
+@protected RekeyResult dco_decode_rekey_result(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_Curve25519PublicKey(Curve25519PublicKey self, SseSerializer serializer);
+@protected RetainedRoomKey dco_decode_retained_room_key(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_Ed25519PublicKey(Ed25519PublicKey self, SseSerializer serializer);
+@protected RetentionResult dco_decode_retention_result(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_Ed25519Signature(Ed25519Signature self, SseSerializer serializer);
+@protected RetriedEvent dco_decode_retried_event(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_EstablishedSas(EstablishedSas self, SseSerializer serializer);
+@protected RoomEventDecryptionResult dco_decode_room_event_decryption_result(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_MegolmSessionConfig(MegolmSessionConfig self, SseSerializer serializer);
+@protected RoomEventError dco_decode_room_event_error(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_OlmMessage(OlmMessage self, SseSerializer serializer);
+@protected RoomKeyBundleDownload dco_decode_room_key_bundle_download(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_OlmSessionConfig(OlmSessionConfig self, SseSerializer serializer);
+@protected RoomKeyBundleError dco_decode_room_key_bundle_error(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_PkDecryption(PkDecryption self, SseSerializer serializer);
+@protected RoomKeyDecryptionResult dco_decode_room_key_decryption_result(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_PkEncryption(PkEncryption self, SseSerializer serializer);
+@protected RoomKeyError dco_decode_room_key_error(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_RwLockGroupSession(RwLockGroupSession self, SseSerializer serializer);
+@protected RoomKeyInfo dco_decode_room_key_info(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_RwLockInboundGroupSession(RwLockInboundGroupSession self, SseSerializer serializer);
+@protected RoomKeyOrigin dco_decode_room_key_origin(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_RwLockSession(RwLockSession self, SseSerializer serializer);
+@protected RoomRetention dco_decode_room_retention(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      PkSigning self, SseSerializer serializer);
+@protected RotationReason dco_decode_rotation_reason(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-      VodozemacSas self, SseSerializer serializer);
+@protected SessionKeySource dco_decode_session_key_source(dynamic raw);
 
-  @protected
-  void sse_encode_RustOpaque_stdsyncRwLockAccount(RwLockAccount self, SseSerializer serializer);
+@protected ShareRecipient dco_decode_share_recipient(dynamic raw);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected ToDeviceDecryptionError dco_decode_to_device_decryption_error(dynamic raw);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected ToDeviceDecryptionResult dco_decode_to_device_decryption_result(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_account(VodozemacAccount self, SseSerializer serializer);
+@protected TrustLink dco_decode_trust_link(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_curve_25519_public_key(
-      VodozemacCurve25519PublicKey self, SseSerializer serializer);
+@protected int dco_decode_u_32(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey self, SseSerializer serializer);
+@protected BigInt dco_decode_u_64(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_ed_25519_signature(VodozemacEd25519Signature self, SseSerializer serializer);
+@protected int dco_decode_u_8(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_established_sas(VodozemacEstablishedSas self, SseSerializer serializer);
+@protected U8Array32 dco_decode_u_8_array_32(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_group_session(VodozemacGroupSession self, SseSerializer serializer);
+@protected U8Array64 dco_decode_u_8_array_64(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_inbound_group_session(
-      VodozemacInboundGroupSession self, SseSerializer serializer);
+@protected void dco_decode_unit(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_megolm_session_config(
-      VodozemacMegolmSessionConfig self, SseSerializer serializer);
+@protected UnpickledAccount dco_decode_unpickled_account(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_olm_message(VodozemacOlmMessage self, SseSerializer serializer);
+@protected UnpickledGroupSession dco_decode_unpickled_group_session(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_olm_session_config(VodozemacOlmSessionConfig self, SseSerializer serializer);
+@protected UnpickledInboundGroupSession dco_decode_unpickled_inbound_group_session(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_pk_decryption(VodozemacPkDecryption self, SseSerializer serializer);
+@protected UnpickledPkDecryption dco_decode_unpickled_pk_decryption(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_pk_encryption(VodozemacPkEncryption self, SseSerializer serializer);
+@protected UnpickledSession dco_decode_unpickled_session(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_pk_message(VodozemacPkMessage self, SseSerializer serializer);
+@protected UnwedgeError dco_decode_unwedge_error(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_vodozemac_session(VodozemacSession self, SseSerializer serializer);
+@protected UnwedgeResult dco_decode_unwedge_result(dynamic raw);
 
-  @protected
-  void sse_encode_decrypt_result(DecryptResult self, SseSerializer serializer);
+@protected UnwedgeTracker dco_decode_unwedge_tracker(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+@protected BigInt dco_decode_usize(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+@protected UtdCause dco_decode_utd_cause(dynamic raw);
 
-  @protected
-  void sse_encode_list_vodozemac_one_time_key(List<VodozemacOneTimeKey> self, SseSerializer serializer);
+@protected UtdClassification dco_decode_utd_classification(dynamic raw);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected UtdQueue dco_decode_utd_queue(dynamic raw);
 
-  @protected
-  void sse_encode_record_string_string_string((String, String, String) self, SseSerializer serializer);
+@protected VodozemacAccount dco_decode_vodozemac_account(dynamic raw);
 
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+@protected VodozemacCurve25519PublicKey dco_decode_vodozemac_curve_25519_public_key(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected VodozemacEd25519PublicKey dco_decode_vodozemac_ed_25519_public_key(dynamic raw);
 
-  @protected
-  void sse_encode_u_8_array_32(U8Array32 self, SseSerializer serializer);
+@protected VodozemacEd25519Signature dco_decode_vodozemac_ed_25519_signature(dynamic raw);
 
-  @protected
-  void sse_encode_u_8_array_64(U8Array64 self, SseSerializer serializer);
+@protected VodozemacEstablishedSas dco_decode_vodozemac_established_sas(dynamic raw);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected VodozemacGroupSession dco_decode_vodozemac_group_session(dynamic raw);
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
+@protected VodozemacIdentityKeys dco_decode_vodozemac_identity_keys(dynamic raw);
 
-  @protected
-  void sse_encode_vodozemac_account(VodozemacAccount self, SseSerializer serializer);
+@protected VodozemacInboundGroupSession dco_decode_vodozemac_inbound_group_session(dynamic raw);
 
-  @protected
-  void sse_encode_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey self, SseSerializer serializer);
+@protected VodozemacMegolmSessionConfig dco_decode_vodozemac_megolm_session_config(dynamic raw);
 
-  @protected
-  void sse_encode_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey self, SseSerializer serializer);
+@protected VodozemacOlmMessage dco_decode_vodozemac_olm_message(dynamic raw);
 
-  @protected
-  void sse_encode_vodozemac_ed_25519_signature(VodozemacEd25519Signature self, SseSerializer serializer);
+@protected VodozemacOlmSessionConfig dco_decode_vodozemac_olm_session_config(dynamic raw);
 
-  @protected
-  void sse_encode_vodozemac_established_sas(VodozemacEstablishedSas self, SseSerializer serializer);
+@protected VodozemacOlmSessionCreationResult dco_decode_vodozemac_olm_session_creation_result(dynamic raw);
 
-  @protected
-  void sse_encode_vodozemac_group_session(VodozemacGroupSession self, SseSerializer serializer);
+@protected VodozemacOneTimeKey dco_decode_vodozemac_one_time_key(dynamic raw);
 
-  @protected
-  void sse_encode_vodozemac_identity_keys(VodozemacIdentityKeys self, SseSerializer serializer);
+@protected VodozemacPkDecryption dco_decode_vodozemac_pk_decryption(dynamic raw);
 
-  @protected
-  void sse_encode_vodozemac_inbound_group_session(VodozemacInboundGroupSession self, SseSerializer serializer);
+@protected VodozemacPkEncryption dco_decode_vodozemac_pk_encryption(dynamic raw);
 
-  @protected
-  void sse_encode_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig self, SseSerializer serializer);
+@protected VodozemacPkMessage dco_decode_vodozemac_pk_message(dynamic raw);
 
-  @protected
-  void sse_encode_vodozemac_olm_message(VodozemacOlmMessage self, SseSerializer serializer);
+@protected VodozemacSession dco_decode_vodozemac_session(dynamic raw);
 
-  @protected
-  void sse_encode_vodozemac_olm_session_config(VodozemacOlmSessionConfig self, SseSerializer serializer);
+@protected WithheldCode dco_decode_withheld_code(dynamic raw);
 
-  @protected
-  void sse_encode_vodozemac_olm_session_creation_result(
-      VodozemacOlmSessionCreationResult self, SseSerializer serializer);
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_one_time_key(VodozemacOneTimeKey self, SseSerializer serializer);
+@protected CrossSigningIdentity sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_pk_decryption(VodozemacPkDecryption self, SseSerializer serializer);
+@protected PkSigning sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_pk_encryption(VodozemacPkEncryption self, SseSerializer serializer);
+@protected VodozemacSas sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_pk_message(VodozemacPkMessage self, SseSerializer serializer);
+@protected CrossSigningIdentity sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_vodozemac_session(VodozemacSession self, SseSerializer serializer);
+@protected PkSigning sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-}
+@protected VodozemacSas sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(SseDeserializer deserializer);
 
-// Section: wire_class
+@protected Map<String, int> sse_decode_Map_String_u_32_None(SseDeserializer deserializer);
 
-class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+@protected Curve25519PublicKey sse_decode_RustOpaque_Curve25519PublicKey(SseDeserializer deserializer);
+
+@protected Ed25519PublicKey sse_decode_RustOpaque_Ed25519PublicKey(SseDeserializer deserializer);
+
+@protected Ed25519Signature sse_decode_RustOpaque_Ed25519Signature(SseDeserializer deserializer);
+
+@protected EstablishedSas sse_decode_RustOpaque_EstablishedSas(SseDeserializer deserializer);
+
+@protected MegolmSessionConfig sse_decode_RustOpaque_MegolmSessionConfig(SseDeserializer deserializer);
+
+@protected OlmMessage sse_decode_RustOpaque_OlmMessage(SseDeserializer deserializer);
+
+@protected OlmSessionConfig sse_decode_RustOpaque_OlmSessionConfig(SseDeserializer deserializer);
+
+@protected PkDecryption sse_decode_RustOpaque_PkDecryption(SseDeserializer deserializer);
+
+@protected PkEncryption sse_decode_RustOpaque_PkEncryption(SseDeserializer deserializer);
+
+@protected RwLockFallbackKeyState sse_decode_RustOpaque_RwLockFallbackKeyState(SseDeserializer deserializer);
+
+@protected RwLockGroupSession sse_decode_RustOpaque_RwLockGroupSession(SseDeserializer deserializer);
+
+@protected RwLockInboundGroupSession sse_decode_RustOpaque_RwLockInboundGroupSession(SseDeserializer deserializer);
+
+@protected RwLockReplayState sse_decode_RustOpaque_RwLockReplayState(SseDeserializer deserializer);
+
+@protected RwLockRoomKeyInfo sse_decode_RustOpaque_RwLockRoomKeyInfo(SseDeserializer deserializer);
+
+@protected RwLockRotationState sse_decode_RustOpaque_RwLockRotationState(SseDeserializer deserializer);
+
+@protected RwLockSession sse_decode_RustOpaque_RwLockSession(SseDeserializer deserializer);
+
+@protected RwLockSessionKeySource sse_decode_RustOpaque_RwLockSessionKeySource(SseDeserializer deserializer);
+
+@protected RwLockSessionStoreState sse_decode_RustOpaque_RwLockSessionStoreState(SseDeserializer deserializer);
+
+@protected RwLockShareState sse_decode_RustOpaque_RwLockShareState(SseDeserializer deserializer);
+
+@protected RwLockUnwedgeState sse_decode_RustOpaque_RwLockUnwedgeState(SseDeserializer deserializer);
+
+@protected RwLockUtdQueueState sse_decode_RustOpaque_RwLockUtdQueueState(SseDeserializer deserializer);
+
+@protected CrossSigningIdentity sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(SseDeserializer deserializer);
+
+@protected PkSigning sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(SseDeserializer deserializer);
+
+@protected VodozemacSas sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(SseDeserializer deserializer);
+
+@protected RwLockAccount sse_decode_RustOpaque_stdsyncRwLockAccount(SseDeserializer deserializer);
+
+@protected RustStreamSink<RekeyProgress> sse_decode_StreamSink_rekey_progress_Dco(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected DeviceKeys sse_decode_box_autoadd_device_keys(SseDeserializer deserializer);
+
+@protected EncryptedRoomKeyBundle sse_decode_box_autoadd_encrypted_room_key_bundle(SseDeserializer deserializer);
+
+@protected FallbackKeyTracker sse_decode_box_autoadd_fallback_key_tracker(SseDeserializer deserializer);
+
+@protected InboundRoomKey sse_decode_box_autoadd_inbound_room_key(SseDeserializer deserializer);
+
+@protected LibolmStore sse_decode_box_autoadd_libolm_store(SseDeserializer deserializer);
+
+@protected MegolmReplayGuard sse_decode_box_autoadd_megolm_replay_guard(SseDeserializer deserializer);
+
+@protected MigratedPickle sse_decode_box_autoadd_migrated_pickle(SseDeserializer deserializer);
+
+@protected MigrationReport sse_decode_box_autoadd_migration_report(SseDeserializer deserializer);
+
+@protected OlmPayload sse_decode_box_autoadd_olm_payload(SseDeserializer deserializer);
+
+@protected OlmSessionStore sse_decode_box_autoadd_olm_session_store(SseDeserializer deserializer);
+
+@protected OutboundGroupSession sse_decode_box_autoadd_outbound_group_session(SseDeserializer deserializer);
+
+@protected PassphraseKdfParams sse_decode_box_autoadd_passphrase_kdf_params(SseDeserializer deserializer);
+
+@protected PickleFormatError sse_decode_box_autoadd_pickle_format_error(SseDeserializer deserializer);
+
+@protected PickleInfo sse_decode_box_autoadd_pickle_info(SseDeserializer deserializer);
+
+@protected RetainedRoomKey sse_decode_box_autoadd_retained_room_key(SseDeserializer deserializer);
+
+@protected RoomKeyBundleDownload sse_decode_box_autoadd_room_key_bundle_download(SseDeserializer deserializer);
+
+@protected RoomKeyInfo sse_decode_box_autoadd_room_key_info(SseDeserializer deserializer);
+
+@protected RotationReason sse_decode_box_autoadd_rotation_reason(SseDeserializer deserializer);
+
+@protected ShareRecipient sse_decode_box_autoadd_share_recipient(SseDeserializer deserializer);
+
+@protected TrustLink sse_decode_box_autoadd_trust_link(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected UnwedgeTracker sse_decode_box_autoadd_unwedge_tracker(SseDeserializer deserializer);
+
+@protected UtdClassification sse_decode_box_autoadd_utd_classification(SseDeserializer deserializer);
+
+@protected UtdQueue sse_decode_box_autoadd_utd_queue(SseDeserializer deserializer);
+
+@protected VodozemacAccount sse_decode_box_autoadd_vodozemac_account(SseDeserializer deserializer);
+
+@protected VodozemacCurve25519PublicKey sse_decode_box_autoadd_vodozemac_curve_25519_public_key(SseDeserializer deserializer);
+
+@protected VodozemacEd25519PublicKey sse_decode_box_autoadd_vodozemac_ed_25519_public_key(SseDeserializer deserializer);
+
+@protected VodozemacEd25519Signature sse_decode_box_autoadd_vodozemac_ed_25519_signature(SseDeserializer deserializer);
+
+@protected VodozemacEstablishedSas sse_decode_box_autoadd_vodozemac_established_sas(SseDeserializer deserializer);
+
+@protected VodozemacGroupSession sse_decode_box_autoadd_vodozemac_group_session(SseDeserializer deserializer);
+
+@protected VodozemacInboundGroupSession sse_decode_box_autoadd_vodozemac_inbound_group_session(SseDeserializer deserializer);
+
+@protected VodozemacMegolmSessionConfig sse_decode_box_autoadd_vodozemac_megolm_session_config(SseDeserializer deserializer);
+
+@protected VodozemacOlmMessage sse_decode_box_autoadd_vodozemac_olm_message(SseDeserializer deserializer);
+
+@protected VodozemacOlmSessionConfig sse_decode_box_autoadd_vodozemac_olm_session_config(SseDeserializer deserializer);
+
+@protected VodozemacPkDecryption sse_decode_box_autoadd_vodozemac_pk_decryption(SseDeserializer deserializer);
+
+@protected VodozemacPkEncryption sse_decode_box_autoadd_vodozemac_pk_encryption(SseDeserializer deserializer);
+
+@protected VodozemacPkMessage sse_decode_box_autoadd_vodozemac_pk_message(SseDeserializer deserializer);
+
+@protected VodozemacSession sse_decode_box_autoadd_vodozemac_session(SseDeserializer deserializer);
+
+@protected WithheldCode sse_decode_box_autoadd_withheld_code(SseDeserializer deserializer);
+
+@protected ClaimedSession sse_decode_claimed_session(SseDeserializer deserializer);
+
+@protected DecryptResult sse_decode_decrypt_result(SseDeserializer deserializer);
+
+@protected DeviceKeys sse_decode_device_keys(SseDeserializer deserializer);
+
+@protected DeviceKeysError sse_decode_device_keys_error(SseDeserializer deserializer);
+
+@protected DeviceTrust sse_decode_device_trust(SseDeserializer deserializer);
+
+@protected DeviceTrustLevel sse_decode_device_trust_level(SseDeserializer deserializer);
+
+@protected EncryptedRoomKeyBundle sse_decode_encrypted_room_key_bundle(SseDeserializer deserializer);
+
+@protected ExtendedDecryptResult sse_decode_extended_decrypt_result(SseDeserializer deserializer);
+
+@protected FallbackKeyTracker sse_decode_fallback_key_tracker(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected ImportedRoomKeyBundle sse_decode_imported_room_key_bundle(SseDeserializer deserializer);
+
+@protected InboundRoomKey sse_decode_inbound_room_key(SseDeserializer deserializer);
+
+@protected KeyAuthenticity sse_decode_key_authenticity(SseDeserializer deserializer);
+
+@protected KeyClaimError sse_decode_key_claim_error(SseDeserializer deserializer);
+
+@protected KeyMaintenancePlan sse_decode_key_maintenance_plan(SseDeserializer deserializer);
+
+@protected LibolmStore sse_decode_libolm_store(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<DeviceTrust> sse_decode_list_device_trust(SseDeserializer deserializer);
+
+@protected List<InboundRoomKey> sse_decode_list_inbound_room_key(SseDeserializer deserializer);
+
+@protected List<MigratedPickle> sse_decode_list_migrated_pickle(SseDeserializer deserializer);
+
+@protected List<MigrationFailure> sse_decode_list_migration_failure(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<(String,int)> sse_decode_list_record_string_u_32(SseDeserializer deserializer);
+
+@protected List<RekeyResult> sse_decode_list_rekey_result(SseDeserializer deserializer);
+
+@protected List<RetentionResult> sse_decode_list_retention_result(SseDeserializer deserializer);
+
+@protected List<RetriedEvent> sse_decode_list_retried_event(SseDeserializer deserializer);
+
+@protected List<RoomRetention> sse_decode_list_room_retention(SseDeserializer deserializer);
+
+@protected List<ShareRecipient> sse_decode_list_share_recipient(SseDeserializer deserializer);
+
+@protected List<VodozemacOneTimeKey> sse_decode_list_vodozemac_one_time_key(SseDeserializer deserializer);
+
+@protected List<VodozemacSession> sse_decode_list_vodozemac_session(SseDeserializer deserializer);
+
+@protected MegolmReplayGuard sse_decode_megolm_replay_guard(SseDeserializer deserializer);
+
+@protected MigratedPickle sse_decode_migrated_pickle(SseDeserializer deserializer);
+
+@protected MigrationError sse_decode_migration_error(SseDeserializer deserializer);
+
+@protected MigrationFailure sse_decode_migration_failure(SseDeserializer deserializer);
+
+@protected MigrationReport sse_decode_migration_report(SseDeserializer deserializer);
+
+@protected OlmPayload sse_decode_olm_payload(SseDeserializer deserializer);
+
+@protected OlmPayloadError sse_decode_olm_payload_error(SseDeserializer deserializer);
+
+@protected OlmSessionStore sse_decode_olm_session_store(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected MigratedPickle? sse_decode_opt_box_autoadd_migrated_pickle(SseDeserializer deserializer);
+
+@protected PickleFormatError? sse_decode_opt_box_autoadd_pickle_format_error(SseDeserializer deserializer);
+
+@protected RetainedRoomKey? sse_decode_opt_box_autoadd_retained_room_key(SseDeserializer deserializer);
+
+@protected RotationReason? sse_decode_opt_box_autoadd_rotation_reason(SseDeserializer deserializer);
+
+@protected TrustLink? sse_decode_opt_box_autoadd_trust_link(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected UtdClassification? sse_decode_opt_box_autoadd_utd_classification(SseDeserializer deserializer);
+
+@protected VodozemacEd25519PublicKey? sse_decode_opt_box_autoadd_vodozemac_ed_25519_public_key(SseDeserializer deserializer);
+
+@protected VodozemacSession? sse_decode_opt_box_autoadd_vodozemac_session(SseDeserializer deserializer);
+
+@protected WithheldCode? sse_decode_opt_box_autoadd_withheld_code(SseDeserializer deserializer);
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+@protected List<RekeyResult>? sse_decode_opt_list_rekey_result(SseDeserializer deserializer);
+
+@protected OutboundGroupSession sse_decode_outbound_group_session(SseDeserializer deserializer);
+
+@protected PassphraseKdf sse_decode_passphrase_kdf(SseDeserializer deserializer);
+
+@protected PassphraseKdfParams sse_decode_passphrase_kdf_params(SseDeserializer deserializer);
+
+@protected PassphrasePickleError sse_decode_passphrase_pickle_error(SseDeserializer deserializer);
+
+@protected PickleFormat sse_decode_pickle_format(SseDeserializer deserializer);
+
+@protected PickleFormatError sse_decode_pickle_format_error(SseDeserializer deserializer);
+
+@protected PickleInfo sse_decode_pickle_info(SseDeserializer deserializer);
+
+@protected PickleObject sse_decode_pickle_object(SseDeserializer deserializer);
+
+@protected (String,String,String) sse_decode_record_string_string_string(SseDeserializer deserializer);
+
+@protected (String,int) sse_decode_record_string_u_32(SseDeserializer deserializer);
+
+@protected RekeyProgress sse_decode_rekey_progress(SseDeserializer deserializer);
+
+@protected RekeyResult sse_decode_rekey_result(SseDeserializer deserializer);
+
+@protected RetainedRoomKey sse_decode_retained_room_key(SseDeserializer deserializer);
+
+@protected RetentionResult sse_decode_retention_result(SseDeserializer deserializer);
+
+@protected RetriedEvent sse_decode_retried_event(SseDeserializer deserializer);
+
+@protected RoomEventDecryptionResult sse_decode_room_event_decryption_result(SseDeserializer deserializer);
+
+@protected RoomEventError sse_decode_room_event_error(SseDeserializer deserializer);
+
+@protected RoomKeyBundleDownload sse_decode_room_key_bundle_download(SseDeserializer deserializer);
+
+@protected RoomKeyBundleError sse_decode_room_key_bundle_error(SseDeserializer deserializer);
+
+@protected RoomKeyDecryptionResult sse_decode_room_key_decryption_result(SseDeserializer deserializer);
+
+@protected RoomKeyError sse_decode_room_key_error(SseDeserializer deserializer);
+
+@protected RoomKeyInfo sse_decode_room_key_info(SseDeserializer deserializer);
+
+@protected RoomKeyOrigin sse_decode_room_key_origin(SseDeserializer deserializer);
+
+@protected RoomRetention sse_decode_room_retention(SseDeserializer deserializer);
+
+@protected RotationReason sse_decode_rotation_reason(SseDeserializer deserializer);
+
+@protected SessionKeySource sse_decode_session_key_source(SseDeserializer deserializer);
+
+@protected ShareRecipient sse_decode_share_recipient(SseDeserializer deserializer);
+
+@protected ToDeviceDecryptionError sse_decode_to_device_decryption_error(SseDeserializer deserializer);
+
+@protected ToDeviceDecryptionResult sse_decode_to_device_decryption_result(SseDeserializer deserializer);
+
+@protected TrustLink sse_decode_trust_link(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected U8Array32 sse_decode_u_8_array_32(SseDeserializer deserializer);
+
+@protected U8Array64 sse_decode_u_8_array_64(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected UnpickledAccount sse_decode_unpickled_account(SseDeserializer deserializer);
+
+@protected UnpickledGroupSession sse_decode_unpickled_group_session(SseDeserializer deserializer);
+
+@protected UnpickledInboundGroupSession sse_decode_unpickled_inbound_group_session(SseDeserializer deserializer);
+
+@protected UnpickledPkDecryption sse_decode_unpickled_pk_decryption(SseDeserializer deserializer);
+
+@protected UnpickledSession sse_decode_unpickled_session(SseDeserializer deserializer);
+
+@protected UnwedgeError sse_decode_unwedge_error(SseDeserializer deserializer);
+
+@protected UnwedgeResult sse_decode_unwedge_result(SseDeserializer deserializer);
+
+@protected UnwedgeTracker sse_decode_unwedge_tracker(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected UtdCause sse_decode_utd_cause(SseDeserializer deserializer);
+
+@protected UtdClassification sse_decode_utd_classification(SseDeserializer deserializer);
+
+@protected UtdQueue sse_decode_utd_queue(SseDeserializer deserializer);
+
+@protected VodozemacAccount sse_decode_vodozemac_account(SseDeserializer deserializer);
+
+@protected VodozemacCurve25519PublicKey sse_decode_vodozemac_curve_25519_public_key(SseDeserializer deserializer);
+
+@protected VodozemacEd25519PublicKey sse_decode_vodozemac_ed_25519_public_key(SseDeserializer deserializer);
+
+@protected VodozemacEd25519Signature sse_decode_vodozemac_ed_25519_signature(SseDeserializer deserializer);
+
+@protected VodozemacEstablishedSas sse_decode_vodozemac_established_sas(SseDeserializer deserializer);
+
+@protected VodozemacGroupSession sse_decode_vodozemac_group_session(SseDeserializer deserializer);
+
+@protected VodozemacIdentityKeys sse_decode_vodozemac_identity_keys(SseDeserializer deserializer);
+
+@protected VodozemacInboundGroupSession sse_decode_vodozemac_inbound_group_session(SseDeserializer deserializer);
+
+@protected VodozemacMegolmSessionConfig sse_decode_vodozemac_megolm_session_config(SseDeserializer deserializer);
+
+@protected VodozemacOlmMessage sse_decode_vodozemac_olm_message(SseDeserializer deserializer);
+
+@protected VodozemacOlmSessionConfig sse_decode_vodozemac_olm_session_config(SseDeserializer deserializer);
+
+@protected VodozemacOlmSessionCreationResult sse_decode_vodozemac_olm_session_creation_result(SseDeserializer deserializer);
+
+@protected VodozemacOneTimeKey sse_decode_vodozemac_one_time_key(SseDeserializer deserializer);
+
+@protected VodozemacPkDecryption sse_decode_vodozemac_pk_decryption(SseDeserializer deserializer);
+
+@protected VodozemacPkEncryption sse_decode_vodozemac_pk_encryption(SseDeserializer deserializer);
+
+@protected VodozemacPkMessage sse_decode_vodozemac_pk_message(SseDeserializer deserializer);
+
+@protected VodozemacSession sse_decode_vodozemac_session(SseDeserializer deserializer);
+
+@protected WithheldCode sse_decode_withheld_code(SseDeserializer deserializer);
+
+@protected String cst_encode_AnyhowException(AnyhowException raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected JSAny cst_encode_Map_String_u_32_None(Map<String, int> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_list_record_string_u_32(raw.entries.map((e) => (e.key, e.value)).toList()); }
+
+@protected String cst_encode_StreamSink_rekey_progress_Dco(RustStreamSink<RekeyProgress> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_rekey_progress,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected String cst_encode_String(String raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected JSAny cst_encode_box_autoadd_device_keys(DeviceKeys raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_device_keys(raw); }
+
+@protected JSAny cst_encode_box_autoadd_encrypted_room_key_bundle(EncryptedRoomKeyBundle raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_encrypted_room_key_bundle(raw); }
+
+@protected JSAny cst_encode_box_autoadd_fallback_key_tracker(FallbackKeyTracker raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_fallback_key_tracker(raw); }
+
+@protected JSAny cst_encode_box_autoadd_inbound_room_key(InboundRoomKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_inbound_room_key(raw); }
+
+@protected JSAny cst_encode_box_autoadd_libolm_store(LibolmStore raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_libolm_store(raw); }
+
+@protected JSAny cst_encode_box_autoadd_megolm_replay_guard(MegolmReplayGuard raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_megolm_replay_guard(raw); }
+
+@protected JSAny cst_encode_box_autoadd_migrated_pickle(MigratedPickle raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_migrated_pickle(raw); }
+
+@protected JSAny cst_encode_box_autoadd_migration_report(MigrationReport raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_migration_report(raw); }
+
+@protected JSAny cst_encode_box_autoadd_olm_payload(OlmPayload raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_olm_payload(raw); }
+
+@protected JSAny cst_encode_box_autoadd_olm_session_store(OlmSessionStore raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_olm_session_store(raw); }
+
+@protected JSAny cst_encode_box_autoadd_outbound_group_session(OutboundGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_outbound_group_session(raw); }
+
+@protected JSAny cst_encode_box_autoadd_passphrase_kdf_params(PassphraseKdfParams raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_passphrase_kdf_params(raw); }
+
+@protected int cst_encode_box_autoadd_pickle_format_error(PickleFormatError raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_pickle_format_error(raw); }
+
+@protected JSAny cst_encode_box_autoadd_pickle_info(PickleInfo raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_pickle_info(raw); }
+
+@protected JSAny cst_encode_box_autoadd_retained_room_key(RetainedRoomKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_retained_room_key(raw); }
+
+@protected JSAny cst_encode_box_autoadd_room_key_bundle_download(RoomKeyBundleDownload raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_room_key_bundle_download(raw); }
+
+@protected JSAny cst_encode_box_autoadd_room_key_info(RoomKeyInfo raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_room_key_info(raw); }
+
+@protected int cst_encode_box_autoadd_rotation_reason(RotationReason raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_rotation_reason(raw); }
+
+@protected JSAny cst_encode_box_autoadd_share_recipient(ShareRecipient raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_share_recipient(raw); }
+
+@protected int cst_encode_box_autoadd_trust_link(TrustLink raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_trust_link(raw); }
+
+@protected int cst_encode_box_autoadd_u_32(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_u_32(raw); }
+
+@protected JSAny cst_encode_box_autoadd_u_64(BigInt raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_u_64(raw); }
+
+@protected JSAny cst_encode_box_autoadd_unwedge_tracker(UnwedgeTracker raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_unwedge_tracker(raw); }
+
+@protected JSAny cst_encode_box_autoadd_utd_classification(UtdClassification raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_utd_classification(raw); }
+
+@protected JSAny cst_encode_box_autoadd_utd_queue(UtdQueue raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_utd_queue(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_account(VodozemacAccount raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_account(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_curve_25519_public_key(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_ed_25519_public_key(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_ed_25519_signature(VodozemacEd25519Signature raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_ed_25519_signature(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_established_sas(VodozemacEstablishedSas raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_established_sas(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_group_session(VodozemacGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_group_session(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_inbound_group_session(VodozemacInboundGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_inbound_group_session(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_megolm_session_config(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_olm_message(VodozemacOlmMessage raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_olm_message(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_olm_session_config(VodozemacOlmSessionConfig raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_olm_session_config(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_pk_decryption(VodozemacPkDecryption raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_pk_decryption(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_pk_encryption(VodozemacPkEncryption raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_pk_encryption(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_pk_message(VodozemacPkMessage raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_pk_message(raw); }
+
+@protected JSAny cst_encode_box_autoadd_vodozemac_session(VodozemacSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_vodozemac_session(raw); }
+
+@protected int cst_encode_box_autoadd_withheld_code(WithheldCode raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_withheld_code(raw); }
+
+@protected JSAny cst_encode_claimed_session(ClaimedSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_vodozemac_session(raw.session),cst_encode_bool(raw.fallback)].jsify()!; }
+
+@protected JSAny cst_encode_decrypt_result(DecryptResult raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.field0),cst_encode_u_32(raw.field1)].jsify()!; }
+
+@protected JSAny cst_encode_device_keys(DeviceKeys raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.userId),cst_encode_String(raw.deviceId),cst_encode_list_String(raw.algorithms),cst_encode_vodozemac_ed_25519_public_key(raw.ed25519),cst_encode_vodozemac_curve_25519_public_key(raw.curve25519),cst_encode_opt_String(raw.displayName)].jsify()!; }
+
+@protected JSAny cst_encode_device_trust(DeviceTrust raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.userId),cst_encode_String(raw.deviceId),cst_encode_device_trust_level(raw.level),cst_encode_opt_box_autoadd_trust_link(raw.failedLink)].jsify()!; }
+
+@protected JSAny cst_encode_encrypted_room_key_bundle(EncryptedRoomKeyBundle raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.roomId),cst_encode_list_prim_u_8_strict(raw.ciphertext),cst_encode_String(raw.file)].jsify()!; }
+
+@protected JSAny cst_encode_extended_decrypt_result(ExtendedDecryptResult raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.plaintext),cst_encode_u_32(raw.messageIndex),cst_encode_vodozemac_ed_25519_public_key(raw.signingKey),cst_encode_session_key_source(raw.keySource)].jsify()!; }
+
+@protected JSAny cst_encode_fallback_key_tracker(FallbackKeyTracker raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_RwLockFallbackKeyState(raw.state)].jsify()!; }
+
+@protected JSAny cst_encode_imported_room_key_bundle(ImportedRoomKeyBundle raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_list_inbound_room_key(raw.roomKeys),cst_encode_u_32(raw.skipped)].jsify()!; }
+
+@protected JSAny cst_encode_inbound_room_key(InboundRoomKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_RwLockInboundGroupSession(raw.session),cst_encode_RustOpaque_RwLockSessionKeySource(raw.source),cst_encode_RustOpaque_RwLockRoomKeyInfo(raw.keyInfo)].jsify()!; }
+
+@protected JSAny cst_encode_key_maintenance_plan(KeyMaintenancePlan raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_usize(raw.generateOneTimeKeys),cst_encode_bool(raw.rotateFallbackKey),cst_encode_bool(raw.forgetOldFallbackKey)].jsify()!; }
+
+@protected JSAny cst_encode_libolm_store(LibolmStore raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_opt_String(raw.account),cst_encode_list_String(raw.sessions),cst_encode_list_String(raw.inboundGroupSessions),cst_encode_opt_String(raw.pkDecryption)].jsify()!; }
+
+@protected JSAny cst_encode_list_String(List<String> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_String).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_device_trust(List<DeviceTrust> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_device_trust).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_inbound_room_key(List<InboundRoomKey> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_inbound_room_key).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_migrated_pickle(List<MigratedPickle> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_migrated_pickle).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_migration_failure(List<MigrationFailure> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_migration_failure).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_prim_u_8_loose(List<int> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.jsify()!; }
+
+@protected JSAny cst_encode_list_prim_u_8_strict(Uint8List raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.jsify()!; }
+
+@protected JSAny cst_encode_list_record_string_u_32(List<(String,int)> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_record_string_u_32).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_rekey_result(List<RekeyResult> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_rekey_result).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_retention_result(List<RetentionResult> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_retention_result).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_retried_event(List<RetriedEvent> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_retried_event).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_room_retention(List<RoomRetention> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_room_retention).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_share_recipient(List<ShareRecipient> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_share_recipient).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_vodozemac_one_time_key(List<VodozemacOneTimeKey> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_vodozemac_one_time_key).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_vodozemac_session(List<VodozemacSession> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_vodozemac_session).toList().jsify()!; }
+
+@protected JSAny cst_encode_megolm_replay_guard(MegolmReplayGuard raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_RwLockReplayState(raw.state)].jsify()!; }
+
+@protected JSAny cst_encode_migrated_pickle(MigratedPickle raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_u_32(raw.index),cst_encode_String(raw.idBefore),cst_encode_String(raw.idAfter),cst_encode_String(raw.pickle)].jsify()!; }
+
+@protected JSAny cst_encode_migration_failure(MigrationFailure raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_pickle_object(raw.object),cst_encode_u_32(raw.index),cst_encode_opt_String(raw.idBefore),cst_encode_opt_String(raw.idAfter),cst_encode_migration_error(raw.error)].jsify()!; }
+
+@protected JSAny cst_encode_migration_report(MigrationReport raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_opt_box_autoadd_migrated_pickle(raw.account),cst_encode_list_migrated_pickle(raw.sessions),cst_encode_list_migrated_pickle(raw.inboundGroupSessions),cst_encode_opt_box_autoadd_migrated_pickle(raw.pkDecryption),cst_encode_list_migration_failure(raw.failures)].jsify()!; }
+
+@protected JSAny cst_encode_olm_payload(OlmPayload raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.eventType),cst_encode_String(raw.content),cst_encode_String(raw.sender),cst_encode_opt_String(raw.senderDevice),cst_encode_vodozemac_ed_25519_public_key(raw.senderClaimedEd25519)].jsify()!; }
+
+@protected JSAny cst_encode_olm_session_store(OlmSessionStore raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_RwLockSessionStoreState(raw.state)].jsify()!; }
+
+@protected String? cst_encode_opt_String(String? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_String(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_migrated_pickle(MigratedPickle? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_migrated_pickle(raw); }
+
+@protected int? cst_encode_opt_box_autoadd_pickle_format_error(PickleFormatError? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_pickle_format_error(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_retained_room_key(RetainedRoomKey? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_retained_room_key(raw); }
+
+@protected int? cst_encode_opt_box_autoadd_rotation_reason(RotationReason? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_rotation_reason(raw); }
+
+@protected int? cst_encode_opt_box_autoadd_trust_link(TrustLink? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_trust_link(raw); }
+
+@protected int? cst_encode_opt_box_autoadd_u_32(int? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_u_32(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_u_64(BigInt? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_u_64(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_utd_classification(UtdClassification? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_utd_classification(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_vodozemac_ed_25519_public_key(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_vodozemac_session(VodozemacSession? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_vodozemac_session(raw); }
+
+@protected int? cst_encode_opt_box_autoadd_withheld_code(WithheldCode? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_withheld_code(raw); }
+
+@protected JSAny? cst_encode_opt_list_String(List<String>? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_list_String(raw); }
+
+@protected JSAny? cst_encode_opt_list_rekey_result(List<RekeyResult>? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_list_rekey_result(raw); }
+
+@protected JSAny cst_encode_outbound_group_session(OutboundGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_RwLockGroupSession(raw.session),cst_encode_RustOpaque_RwLockRotationState(raw.rotation),cst_encode_RustOpaque_RwLockShareState(raw.shares)].jsify()!; }
+
+@protected JSAny cst_encode_passphrase_kdf_params(PassphraseKdfParams raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_passphrase_kdf(raw.kdf),cst_encode_u_32(raw.iterations)].jsify()!; }
+
+@protected JSAny cst_encode_pickle_info(PickleInfo raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_pickle_format(raw.format),cst_encode_pickle_object(raw.object),cst_encode_opt_box_autoadd_u_32(raw.version)].jsify()!; }
+
+@protected JSAny cst_encode_record_string_string_string((String,String,String) raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.$1),cst_encode_String(raw.$2),cst_encode_String(raw.$3)].jsify()!; }
+
+@protected JSAny cst_encode_record_string_u_32((String,int) raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.$1),cst_encode_u_32(raw.$2)].jsify()!; }
+
+@protected JSAny cst_encode_rekey_progress(RekeyProgress raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_u_32(raw.done),cst_encode_opt_list_rekey_result(raw.results)].jsify()!; }
+
+@protected JSAny cst_encode_rekey_result(RekeyResult raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_opt_String(raw.pickle),cst_encode_opt_box_autoadd_pickle_format_error(raw.error)].jsify()!; }
+
+@protected JSAny cst_encode_retained_room_key(RetainedRoomKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.sessionId),cst_encode_String(raw.pickle),cst_encode_u_32(raw.firstKnownIndex),cst_encode_bool(raw.discarded)].jsify()!; }
+
+@protected JSAny cst_encode_retention_result(RetentionResult raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_opt_box_autoadd_retained_room_key(raw.roomKey),cst_encode_opt_box_autoadd_pickle_format_error(raw.error)].jsify()!; }
+
+@protected JSAny cst_encode_retried_event(RetriedEvent raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.eventId),cst_encode_room_key_decryption_result(raw.result)].jsify()!; }
+
+@protected JSAny cst_encode_room_event_decryption_result(RoomEventDecryptionResult raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.eventType),cst_encode_String(raw.content),cst_encode_u_32(raw.messageIndex)].jsify()!; }
+
+@protected JSAny cst_encode_room_key_bundle_download(RoomKeyBundleDownload raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.roomId),cst_encode_String(raw.url),cst_encode_String(raw.file),cst_encode_String(raw.senderKey)].jsify()!; }
+
+@protected JSAny cst_encode_room_key_decryption_result(RoomKeyDecryptionResult raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.eventType),cst_encode_String(raw.content),cst_encode_u_32(raw.messageIndex),cst_encode_key_authenticity(raw.authenticity),cst_encode_String(raw.senderKey),cst_encode_String(raw.senderClaimedEd25519)].jsify()!; }
+
+@protected JSAny cst_encode_room_key_info(RoomKeyInfo raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.algorithm),cst_encode_String(raw.roomId),cst_encode_String(raw.senderKey),cst_encode_String(raw.senderClaimedEd25519),cst_encode_list_String(raw.forwardingCurve25519KeyChain),cst_encode_room_key_origin(raw.origin),cst_encode_bool(raw.sharedHistory),cst_encode_bool(raw.backedUp)].jsify()!; }
+
+@protected JSAny cst_encode_room_retention(RoomRetention raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.roomId),cst_encode_u_64(raw.maxAgeMs)].jsify()!; }
+
+@protected JSAny cst_encode_share_recipient(ShareRecipient raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.userId),cst_encode_String(raw.deviceId),cst_encode_String(raw.curve25519Key)].jsify()!; }
+
+@protected JSAny cst_encode_to_device_decryption_result(ToDeviceDecryptionResult raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.plaintext),cst_encode_vodozemac_curve_25519_public_key(raw.senderKey),cst_encode_String(raw.sessionId),cst_encode_opt_box_autoadd_vodozemac_session(raw.newSession)].jsify()!; }
+
+@protected JSAny cst_encode_u_64(BigInt raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return castNativeBigInt(raw); }
+
+@protected JSAny cst_encode_u_8_array_32(U8Array32 raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return Uint8List.fromList(raw).jsify()!; }
+
+@protected JSAny cst_encode_u_8_array_64(U8Array64 raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return Uint8List.fromList(raw).jsify()!; }
+
+@protected JSAny cst_encode_unpickled_account(UnpickledAccount raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_vodozemac_account(raw.account),cst_encode_bool(raw.needsRepickle)].jsify()!; }
+
+@protected JSAny cst_encode_unpickled_group_session(UnpickledGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_vodozemac_group_session(raw.session),cst_encode_bool(raw.needsRepickle)].jsify()!; }
+
+@protected JSAny cst_encode_unpickled_inbound_group_session(UnpickledInboundGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_vodozemac_inbound_group_session(raw.session),cst_encode_bool(raw.needsRepickle)].jsify()!; }
+
+@protected JSAny cst_encode_unpickled_pk_decryption(UnpickledPkDecryption raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_vodozemac_pk_decryption(raw.pkDecryption),cst_encode_bool(raw.needsRepickle)].jsify()!; }
+
+@protected JSAny cst_encode_unpickled_session(UnpickledSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_vodozemac_session(raw.session),cst_encode_bool(raw.needsRepickle)].jsify()!; }
+
+@protected JSAny cst_encode_unwedge_result(UnwedgeResult raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_vodozemac_session(raw.session),cst_encode_String(raw.encryptedContent)].jsify()!; }
+
+@protected JSAny cst_encode_unwedge_tracker(UnwedgeTracker raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_RwLockUnwedgeState(raw.state)].jsify()!; }
+
+@protected JSAny cst_encode_usize(BigInt raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return castNativeBigInt(raw); }
+
+@protected JSAny cst_encode_utd_classification(UtdClassification raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_utd_cause(raw.cause),cst_encode_opt_box_autoadd_withheld_code(raw.withheldCode)].jsify()!; }
+
+@protected JSAny cst_encode_utd_queue(UtdQueue raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_RwLockUtdQueueState(raw.state)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_account(VodozemacAccount raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_stdsyncRwLockAccount(raw.account)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_Curve25519PublicKey(raw.key)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_Ed25519PublicKey(raw.key)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_ed_25519_signature(VodozemacEd25519Signature raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_Ed25519Signature(raw.signature)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_established_sas(VodozemacEstablishedSas raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_EstablishedSas(raw.establishedSas)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_group_session(VodozemacGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_RwLockGroupSession(raw.session)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_identity_keys(VodozemacIdentityKeys raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_vodozemac_ed_25519_public_key(raw.ed25519),cst_encode_vodozemac_curve_25519_public_key(raw.curve25519)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_inbound_group_session(VodozemacInboundGroupSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_RwLockInboundGroupSession(raw.session),cst_encode_RustOpaque_RwLockSessionKeySource(raw.source)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_MegolmSessionConfig(raw.config)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_olm_message(VodozemacOlmMessage raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_OlmMessage(raw.msg)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_olm_session_config(VodozemacOlmSessionConfig raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_OlmSessionConfig(raw.config)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_olm_session_creation_result(VodozemacOlmSessionCreationResult raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_vodozemac_session(raw.session),cst_encode_String(raw.plaintext)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_one_time_key(VodozemacOneTimeKey raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.keyid),cst_encode_vodozemac_curve_25519_public_key(raw.key)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_pk_decryption(VodozemacPkDecryption raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_PkDecryption(raw.pkDecryption)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_pk_encryption(VodozemacPkEncryption raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_PkEncryption(raw.pkEncryption)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_pk_message(VodozemacPkMessage raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_list_prim_u_8_strict(raw.ciphertext),cst_encode_list_prim_u_8_strict(raw.mac),cst_encode_vodozemac_curve_25519_public_key(raw.ephemeralKey)].jsify()!; }
+
+@protected JSAny cst_encode_vodozemac_session(VodozemacSession raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_RustOpaque_RwLockSession(raw.session)].jsify()!; }
+
+@protected int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity raw);
+
+@protected int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw);
+
+@protected int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw);
+
+@protected int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity raw);
+
+@protected int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw);
+
+@protected int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw);
+
+@protected int cst_encode_RustOpaque_Curve25519PublicKey(Curve25519PublicKey raw);
+
+@protected int cst_encode_RustOpaque_Ed25519PublicKey(Ed25519PublicKey raw);
+
+@protected int cst_encode_RustOpaque_Ed25519Signature(Ed25519Signature raw);
+
+@protected int cst_encode_RustOpaque_EstablishedSas(EstablishedSas raw);
+
+@protected int cst_encode_RustOpaque_MegolmSessionConfig(MegolmSessionConfig raw);
+
+@protected int cst_encode_RustOpaque_OlmMessage(OlmMessage raw);
+
+@protected int cst_encode_RustOpaque_OlmSessionConfig(OlmSessionConfig raw);
+
+@protected int cst_encode_RustOpaque_PkDecryption(PkDecryption raw);
+
+@protected int cst_encode_RustOpaque_PkEncryption(PkEncryption raw);
+
+@protected int cst_encode_RustOpaque_RwLockFallbackKeyState(RwLockFallbackKeyState raw);
+
+@protected int cst_encode_RustOpaque_RwLockGroupSession(RwLockGroupSession raw);
+
+@protected int cst_encode_RustOpaque_RwLockInboundGroupSession(RwLockInboundGroupSession raw);
+
+@protected int cst_encode_RustOpaque_RwLockReplayState(RwLockReplayState raw);
+
+@protected int cst_encode_RustOpaque_RwLockRoomKeyInfo(RwLockRoomKeyInfo raw);
+
+@protected int cst_encode_RustOpaque_RwLockRotationState(RwLockRotationState raw);
+
+@protected int cst_encode_RustOpaque_RwLockSession(RwLockSession raw);
+
+@protected int cst_encode_RustOpaque_RwLockSessionKeySource(RwLockSessionKeySource raw);
+
+@protected int cst_encode_RustOpaque_RwLockSessionStoreState(RwLockSessionStoreState raw);
+
+@protected int cst_encode_RustOpaque_RwLockShareState(RwLockShareState raw);
+
+@protected int cst_encode_RustOpaque_RwLockUnwedgeState(RwLockUnwedgeState raw);
+
+@protected int cst_encode_RustOpaque_RwLockUtdQueueState(RwLockUtdQueueState raw);
+
+@protected int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity raw);
+
+@protected int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning raw);
+
+@protected int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas raw);
+
+@protected int cst_encode_RustOpaque_stdsyncRwLockAccount(RwLockAccount raw);
+
+@protected bool cst_encode_bool(bool raw);
+
+@protected int cst_encode_device_keys_error(DeviceKeysError raw);
+
+@protected int cst_encode_device_trust_level(DeviceTrustLevel raw);
+
+@protected int cst_encode_i_32(int raw);
+
+@protected int cst_encode_key_authenticity(KeyAuthenticity raw);
+
+@protected int cst_encode_key_claim_error(KeyClaimError raw);
+
+@protected int cst_encode_migration_error(MigrationError raw);
+
+@protected int cst_encode_olm_payload_error(OlmPayloadError raw);
+
+@protected int cst_encode_passphrase_kdf(PassphraseKdf raw);
+
+@protected int cst_encode_passphrase_pickle_error(PassphrasePickleError raw);
+
+@protected int cst_encode_pickle_format(PickleFormat raw);
+
+@protected int cst_encode_pickle_format_error(PickleFormatError raw);
+
+@protected int cst_encode_pickle_object(PickleObject raw);
+
+@protected int cst_encode_room_event_error(RoomEventError raw);
+
+@protected int cst_encode_room_key_bundle_error(RoomKeyBundleError raw);
+
+@protected int cst_encode_room_key_error(RoomKeyError raw);
+
+@protected int cst_encode_room_key_origin(RoomKeyOrigin raw);
+
+@protected int cst_encode_rotation_reason(RotationReason raw);
+
+@protected int cst_encode_session_key_source(SessionKeySource raw);
+
+@protected int cst_encode_to_device_decryption_error(ToDeviceDecryptionError raw);
+
+@protected int cst_encode_trust_link(TrustLink raw);
+
+@protected int cst_encode_u_32(int raw);
+
+@protected int cst_encode_u_8(int raw);
+
+@protected void cst_encode_unit(void raw);
+
+@protected int cst_encode_unwedge_error(UnwedgeError raw);
+
+@protected int cst_encode_utd_cause(UtdCause raw);
+
+@protected int cst_encode_withheld_code(WithheldCode raw);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_u_32_None(Map<String, int> self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_Curve25519PublicKey(Curve25519PublicKey self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_Ed25519PublicKey(Ed25519PublicKey self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_Ed25519Signature(Ed25519Signature self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_EstablishedSas(EstablishedSas self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_MegolmSessionConfig(MegolmSessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_OlmMessage(OlmMessage self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_OlmSessionConfig(OlmSessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_PkDecryption(PkDecryption self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_PkEncryption(PkEncryption self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockFallbackKeyState(RwLockFallbackKeyState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockGroupSession(RwLockGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockInboundGroupSession(RwLockInboundGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockReplayState(RwLockReplayState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockRoomKeyInfo(RwLockRoomKeyInfo self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockRotationState(RwLockRotationState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockSession(RwLockSession self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockSessionKeySource(RwLockSessionKeySource self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockSessionStoreState(RwLockSessionStoreState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockShareState(RwLockShareState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockUnwedgeState(RwLockUnwedgeState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_RwLockUtdQueueState(RwLockUtdQueueState self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(CrossSigningIdentity self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(PkSigning self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(VodozemacSas self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_stdsyncRwLockAccount(RwLockAccount self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_rekey_progress_Dco(RustStreamSink<RekeyProgress> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_device_keys(DeviceKeys self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_encrypted_room_key_bundle(EncryptedRoomKeyBundle self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_fallback_key_tracker(FallbackKeyTracker self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_inbound_room_key(InboundRoomKey self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_libolm_store(LibolmStore self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_megolm_replay_guard(MegolmReplayGuard self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_migrated_pickle(MigratedPickle self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_migration_report(MigrationReport self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_olm_payload(OlmPayload self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_olm_session_store(OlmSessionStore self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_outbound_group_session(OutboundGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_passphrase_kdf_params(PassphraseKdfParams self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_pickle_format_error(PickleFormatError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_pickle_info(PickleInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_retained_room_key(RetainedRoomKey self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_room_key_bundle_download(RoomKeyBundleDownload self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_room_key_info(RoomKeyInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_rotation_reason(RotationReason self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_share_recipient(ShareRecipient self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_trust_link(TrustLink self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_unwedge_tracker(UnwedgeTracker self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_utd_classification(UtdClassification self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_utd_queue(UtdQueue self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_account(VodozemacAccount self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_ed_25519_signature(VodozemacEd25519Signature self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_established_sas(VodozemacEstablishedSas self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_group_session(VodozemacGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_inbound_group_session(VodozemacInboundGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_olm_message(VodozemacOlmMessage self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_olm_session_config(VodozemacOlmSessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_pk_decryption(VodozemacPkDecryption self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_pk_encryption(VodozemacPkEncryption self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_pk_message(VodozemacPkMessage self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vodozemac_session(VodozemacSession self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_withheld_code(WithheldCode self, SseSerializer serializer);
+
+@protected void sse_encode_claimed_session(ClaimedSession self, SseSerializer serializer);
+
+@protected void sse_encode_decrypt_result(DecryptResult self, SseSerializer serializer);
+
+@protected void sse_encode_device_keys(DeviceKeys self, SseSerializer serializer);
+
+@protected void sse_encode_device_keys_error(DeviceKeysError self, SseSerializer serializer);
+
+@protected void sse_encode_device_trust(DeviceTrust self, SseSerializer serializer);
+
+@protected void sse_encode_device_trust_level(DeviceTrustLevel self, SseSerializer serializer);
+
+@protected void sse_encode_encrypted_room_key_bundle(EncryptedRoomKeyBundle self, SseSerializer serializer);
+
+@protected void sse_encode_extended_decrypt_result(ExtendedDecryptResult self, SseSerializer serializer);
+
+@protected void sse_encode_fallback_key_tracker(FallbackKeyTracker self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_imported_room_key_bundle(ImportedRoomKeyBundle self, SseSerializer serializer);
+
+@protected void sse_encode_inbound_room_key(InboundRoomKey self, SseSerializer serializer);
+
+@protected void sse_encode_key_authenticity(KeyAuthenticity self, SseSerializer serializer);
+
+@protected void sse_encode_key_claim_error(KeyClaimError self, SseSerializer serializer);
+
+@protected void sse_encode_key_maintenance_plan(KeyMaintenancePlan self, SseSerializer serializer);
+
+@protected void sse_encode_libolm_store(LibolmStore self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_device_trust(List<DeviceTrust> self, SseSerializer serializer);
+
+@protected void sse_encode_list_inbound_room_key(List<InboundRoomKey> self, SseSerializer serializer);
+
+@protected void sse_encode_list_migrated_pickle(List<MigratedPickle> self, SseSerializer serializer);
+
+@protected void sse_encode_list_migration_failure(List<MigrationFailure> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_u_32(List<(String,int)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_rekey_result(List<RekeyResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_retention_result(List<RetentionResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_retried_event(List<RetriedEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_list_room_retention(List<RoomRetention> self, SseSerializer serializer);
+
+@protected void sse_encode_list_share_recipient(List<ShareRecipient> self, SseSerializer serializer);
+
+@protected void sse_encode_list_vodozemac_one_time_key(List<VodozemacOneTimeKey> self, SseSerializer serializer);
+
+@protected void sse_encode_list_vodozemac_session(List<VodozemacSession> self, SseSerializer serializer);
+
+@protected void sse_encode_megolm_replay_guard(MegolmReplayGuard self, SseSerializer serializer);
+
+@protected void sse_encode_migrated_pickle(MigratedPickle self, SseSerializer serializer);
+
+@protected void sse_encode_migration_error(MigrationError self, SseSerializer serializer);
+
+@protected void sse_encode_migration_failure(MigrationFailure self, SseSerializer serializer);
+
+@protected void sse_encode_migration_report(MigrationReport self, SseSerializer serializer);
+
+@protected void sse_encode_olm_payload(OlmPayload self, SseSerializer serializer);
+
+@protected void sse_encode_olm_payload_error(OlmPayloadError self, SseSerializer serializer);
+
+@protected void sse_encode_olm_session_store(OlmSessionStore self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_migrated_pickle(MigratedPickle? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_pickle_format_error(PickleFormatError? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_retained_room_key(RetainedRoomKey? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_rotation_reason(RotationReason? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_trust_link(TrustLink? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_utd_classification(UtdClassification? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_vodozemac_session(VodozemacSession? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_withheld_code(WithheldCode? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_rekey_result(List<RekeyResult>? self, SseSerializer serializer);
+
+@protected void sse_encode_outbound_group_session(OutboundGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_passphrase_kdf(PassphraseKdf self, SseSerializer serializer);
+
+@protected void sse_encode_passphrase_kdf_params(PassphraseKdfParams self, SseSerializer serializer);
+
+@protected void sse_encode_passphrase_pickle_error(PassphrasePickleError self, SseSerializer serializer);
+
+@protected void sse_encode_pickle_format(PickleFormat self, SseSerializer serializer);
+
+@protected void sse_encode_pickle_format_error(PickleFormatError self, SseSerializer serializer);
+
+@protected void sse_encode_pickle_info(PickleInfo self, SseSerializer serializer);
+
+@protected void sse_encode_pickle_object(PickleObject self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_string_string((String,String,String) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_u_32((String,int) self, SseSerializer serializer);
+
+@protected void sse_encode_rekey_progress(RekeyProgress self, SseSerializer serializer);
+
+@protected void sse_encode_rekey_result(RekeyResult self, SseSerializer serializer);
+
+@protected void sse_encode_retained_room_key(RetainedRoomKey self, SseSerializer serializer);
+
+@protected void sse_encode_retention_result(RetentionResult self, SseSerializer serializer);
+
+@protected void sse_encode_retried_event(RetriedEvent self, SseSerializer serializer);
+
+@protected void sse_encode_room_event_decryption_result(RoomEventDecryptionResult self, SseSerializer serializer);
+
+@protected void sse_encode_room_event_error(RoomEventError self, SseSerializer serializer);
+
+@protected void sse_encode_room_key_bundle_download(RoomKeyBundleDownload self, SseSerializer serializer);
+
+@protected void sse_encode_room_key_bundle_error(RoomKeyBundleError self, SseSerializer serializer);
+
+@protected void sse_encode_room_key_decryption_result(RoomKeyDecryptionResult self, SseSerializer serializer);
+
+@protected void sse_encode_room_key_error(RoomKeyError self, SseSerializer serializer);
+
+@protected void sse_encode_room_key_info(RoomKeyInfo self, SseSerializer serializer);
+
+@protected void sse_encode_room_key_origin(RoomKeyOrigin self, SseSerializer serializer);
+
+@protected void sse_encode_room_retention(RoomRetention self, SseSerializer serializer);
+
+@protected void sse_encode_rotation_reason(RotationReason self, SseSerializer serializer);
+
+@protected void sse_encode_session_key_source(SessionKeySource self, SseSerializer serializer);
+
+@protected void sse_encode_share_recipient(ShareRecipient self, SseSerializer serializer);
+
+@protected void sse_encode_to_device_decryption_error(ToDeviceDecryptionError self, SseSerializer serializer);
+
+@protected void sse_encode_to_device_decryption_result(ToDeviceDecryptionResult self, SseSerializer serializer);
+
+@protected void sse_encode_trust_link(TrustLink self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_8_array_32(U8Array32 self, SseSerializer serializer);
+
+@protected void sse_encode_u_8_array_64(U8Array64 self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_unpickled_account(UnpickledAccount self, SseSerializer serializer);
+
+@protected void sse_encode_unpickled_group_session(UnpickledGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_unpickled_inbound_group_session(UnpickledInboundGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_unpickled_pk_decryption(UnpickledPkDecryption self, SseSerializer serializer);
+
+@protected void sse_encode_unpickled_session(UnpickledSession self, SseSerializer serializer);
+
+@protected void sse_encode_unwedge_error(UnwedgeError self, SseSerializer serializer);
+
+@protected void sse_encode_unwedge_result(UnwedgeResult self, SseSerializer serializer);
+
+@protected void sse_encode_unwedge_tracker(UnwedgeTracker self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_utd_cause(UtdCause self, SseSerializer serializer);
+
+@protected void sse_encode_utd_classification(UtdClassification self, SseSerializer serializer);
+
+@protected void sse_encode_utd_queue(UtdQueue self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_account(VodozemacAccount self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_curve_25519_public_key(VodozemacCurve25519PublicKey self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_ed_25519_public_key(VodozemacEd25519PublicKey self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_ed_25519_signature(VodozemacEd25519Signature self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_established_sas(VodozemacEstablishedSas self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_group_session(VodozemacGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_identity_keys(VodozemacIdentityKeys self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_inbound_group_session(VodozemacInboundGroupSession self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_megolm_session_config(VodozemacMegolmSessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_olm_message(VodozemacOlmMessage self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_olm_session_config(VodozemacOlmSessionConfig self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_olm_session_creation_result(VodozemacOlmSessionCreationResult self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_one_time_key(VodozemacOneTimeKey self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_pk_decryption(VodozemacPkDecryption self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_pk_encryption(VodozemacPkEncryption self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_pk_message(VodozemacPkMessage self, SseSerializer serializer);
+
+@protected void sse_encode_vodozemac_session(VodozemacSession self, SseSerializer serializer);
+
+@protected void sse_encode_withheld_code(WithheldCode self, SseSerializer serializer);
+                }
+                
+
+
+// Section: wire_class
+
+class RustLibWire implements BaseWire {
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+            JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_device_signing_upload(int that) => wasmModule.wire__crate__bindings__cross_signing__CrossSigningIdentity_device_signing_upload(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_from_secret_keys(String user_id,String master_key,String self_signing_key,String user_signing_key) => wasmModule.wire__crate__bindings__cross_signing__CrossSigningIdentity_from_secret_keys(user_id,master_key,self_signing_key,user_signing_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_master_key(int that) => wasmModule.wire__crate__bindings__cross_signing__CrossSigningIdentity_master_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_master_secret_key(int that) => wasmModule.wire__crate__bindings__cross_signing__CrossSigningIdentity_master_secret_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_new(String user_id) => wasmModule.wire__crate__bindings__cross_signing__CrossSigningIdentity_new(user_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_key(int that) => wasmModule.wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_secret_key(int that) => wasmModule.wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_secret_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_device(int that,String device_keys) => wasmModule.wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_device(that,device_keys);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_user(int that,String master_key) => wasmModule.wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_user(that,master_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_user_id(int that) => wasmModule.wire__crate__bindings__cross_signing__CrossSigningIdentity_user_id(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_key(int that) => wasmModule.wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_secret_key(int that) => wasmModule.wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_secret_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_from_secret_key(String key) => wasmModule.wire__crate__bindings__PkSigning_from_secret_key(key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_new() => wasmModule.wire__crate__bindings__PkSigning_new();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_public_key(int that) => wasmModule.wire__crate__bindings__PkSigning_public_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_secret_key(int that) => wasmModule.wire__crate__bindings__PkSigning_secret_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_sign(int that,String message) => wasmModule.wire__crate__bindings__PkSigning_sign(that,message);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__VodozemacSas_establish_sas_secret(int that,String other_public_key) => wasmModule.wire__crate__bindings__VodozemacSas_establish_sas_secret(that,other_public_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__VodozemacSas_new() => wasmModule.wire__crate__bindings__VodozemacSas_new();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__VodozemacSas_public_key(int that) => wasmModule.wire__crate__bindings__VodozemacSas_public_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__aes_ctr(JSAny input,JSAny key,JSAny iv) => wasmModule.wire__crate__bindings__aes_ctr(input,key,iv);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__retention__apply_room_retention(JSAny pickles,JSAny pickle_key,JSAny policies,JSAny guard,JSAny now_ms) => wasmModule.wire__crate__bindings__retention__apply_room_retention(pickles,pickle_key,policies,guard,now_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_cause__classify_utd(String room_id,String encrypted_content,JSAny candidates,int? withheld_code,JSAny origin_server_ts,JSAny device_created_at_ms) => wasmModule.wire__crate__bindings__utd_cause__classify_utd(room_id,encrypted_content,candidates,withheld_code,origin_server_ts,device_created_at_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_bundle__create_room_key_bundle(String room_id,JSAny room_keys) => wasmModule.wire__crate__bindings__room_key_bundle__create_room_key_bundle(room_id,room_keys);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_event__decrypt_room_event(JSAny session,String room_id,String encrypted_content) => wasmModule.wire__crate__bindings__room_event__decrypt_room_event(session,room_id,encrypted_content);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__decrypt_room_event_checked(JSAny session,JSAny guard,String room_id,String event_id,JSAny origin_server_ts,String encrypted_content) => wasmModule.wire__crate__bindings__replay__decrypt_room_event_checked(session,guard,room_id,event_id,origin_server_ts,encrypted_content);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__to_device__decrypt_to_device(JSAny account,JSAny sessions_for_sender,String event_json) => wasmModule.wire__crate__bindings__to_device__decrypt_to_device(account,sessions_for_sender,event_json);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__pickle_format__detect_pickle(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__pickle_format__detect_pickle(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__device_keys__device_keys_parse_and_verify(String json,String expected_user,String expected_device) => wasmModule.wire__crate__bindings__device_keys__device_keys_parse_and_verify(json,expected_user,expected_device);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_event__encrypt_room_event(JSAny session,JSAny sender_key,String device_id,String room_id,String event_type,String content) => wasmModule.wire__crate__bindings__room_event__encrypt_room_event(session,sender_key,device_id,room_id,event_type,content);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__to_device__encrypt_to_device(JSAny account,JSAny session,String sender,String sender_device,JSAny recipient,String event_type,String content) => wasmModule.wire__crate__bindings__to_device__encrypt_to_device(account,session,sender,sender_device,recipient,event_type,content);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_bundle__encrypted_room_key_bundle_encrypt_for_device(JSAny that,String url,JSAny account,JSAny session,String sender,String sender_device,JSAny recipient) => wasmModule.wire__crate__bindings__room_key_bundle__encrypted_room_key_bundle_encrypt_for_device(that,url,account,session,sender,sender_device,recipient);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__trust__evaluate_device_trust(String own_user_id,JSAny own_master_key,JSAny? own_user_signing_key,String keys_query_response) => wasmModule.wire__crate__bindings__trust__evaluate_device_trust(own_user_id,own_master_key,own_user_signing_key,keys_query_response);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__key_maintenance__fallback_key_tracker_default() => wasmModule.wire__crate__bindings__key_maintenance__fallback_key_tracker_default();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__key_maintenance__fallback_key_tracker_from_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__key_maintenance__fallback_key_tracker_from_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__key_maintenance__fallback_key_tracker_grace_period_ms(JSAny that) => wasmModule.wire__crate__bindings__key_maintenance__fallback_key_tracker_grace_period_ms(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__key_maintenance__fallback_key_tracker_new() => wasmModule.wire__crate__bindings__key_maintenance__fallback_key_tracker_new();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__key_maintenance__fallback_key_tracker_pickle_encrypted(JSAny that,JSAny pickle_key) => wasmModule.wire__crate__bindings__key_maintenance__fallback_key_tracker_pickle_encrypted(that,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__key_maintenance__fallback_key_tracker_set_grace_period_ms(JSAny that,JSAny grace_period_ms) => wasmModule.wire__crate__bindings__key_maintenance__fallback_key_tracker_set_grace_period_ms(that,grace_period_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__forwarded_room_key__forwarded_room_key_content(JSAny room_key,int? message_index) => wasmModule.wire__crate__bindings__forwarded_room_key__forwarded_room_key_content(room_key,message_index);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__hmac(JSAny key,JSAny input) => wasmModule.wire__crate__bindings__hmac(key,input);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_decrypt_room_event(JSAny that,String room_id,String encrypted_content) => wasmModule.wire__crate__bindings__room_key_info__inbound_room_key_decrypt_room_event(that,room_id,encrypted_content);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_first_known_index(JSAny that) => wasmModule.wire__crate__bindings__room_key_info__inbound_room_key_first_known_index(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_from_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__room_key_info__inbound_room_key_from_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_inbound_group_session(JSAny that) => wasmModule.wire__crate__bindings__room_key_info__inbound_room_key_inbound_group_session(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_info(JSAny that) => wasmModule.wire__crate__bindings__room_key_info__inbound_room_key_info(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_mark_backed_up(JSAny that,bool backed_up) => wasmModule.wire__crate__bindings__room_key_info__inbound_room_key_mark_backed_up(that,backed_up);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_new(JSAny session,JSAny info) => wasmModule.wire__crate__bindings__room_key_info__inbound_room_key_new(session,info);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_pickle_encrypted(JSAny that,JSAny pickle_key) => wasmModule.wire__crate__bindings__room_key_info__inbound_room_key_pickle_encrypted(that,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_session_id(JSAny that) => wasmModule.wire__crate__bindings__room_key_info__inbound_room_key_session_id(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_default() => wasmModule.wire__crate__bindings__replay__megolm_replay_guard_default();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_forget_before(JSAny that,String session_id,int index) => wasmModule.wire__crate__bindings__replay__megolm_replay_guard_forget_before(that,session_id,index);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_forget_session(JSAny that,String session_id) => wasmModule.wire__crate__bindings__replay__megolm_replay_guard_forget_session(that,session_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_from_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__replay__megolm_replay_guard_from_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_new() => wasmModule.wire__crate__bindings__replay__megolm_replay_guard_new();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_pickle_encrypted(JSAny that,JSAny pickle_key) => wasmModule.wire__crate__bindings__replay__megolm_replay_guard_pickle_encrypted(that,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_record(JSAny that,String session_id,int message_index,String event_id,JSAny origin_server_ts) => wasmModule.wire__crate__bindings__replay__megolm_replay_guard_record(that,session_id,message_index,event_id,origin_server_ts);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__migration__migrate_libolm_store(JSAny store,JSAny old_pickle_key,JSAny new_pickle_key) => wasmModule.wire__crate__bindings__migration__migrate_libolm_store(store,old_pickle_key,new_pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__migration__migration_report_is_complete(JSAny that) => wasmModule.wire__crate__bindings__migration__migration_report_is_complete(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_add_session(JSAny that,String sender_key,JSAny session,JSAny now_ms) => wasmModule.wire__crate__bindings__olm_sessions__olm_session_store_add_session(that,sender_key,session,now_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_default() => wasmModule.wire__crate__bindings__olm_sessions__olm_session_store_default();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_from_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__olm_sessions__olm_session_store_from_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_mark_used(JSAny that,String sender_key,String session_id,JSAny now_ms) => wasmModule.wire__crate__bindings__olm_sessions__olm_session_store_mark_used(that,sender_key,session_id,now_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_max_sessions_per_device(JSAny that) => wasmModule.wire__crate__bindings__olm_sessions__olm_session_store_max_sessions_per_device(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_new() => wasmModule.wire__crate__bindings__olm_sessions__olm_session_store_new();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_pickle_encrypted(JSAny that,JSAny pickle_key) => wasmModule.wire__crate__bindings__olm_sessions__olm_session_store_pickle_encrypted(that,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_remove_session(JSAny that,String sender_key,String session_id) => wasmModule.wire__crate__bindings__olm_sessions__olm_session_store_remove_session(that,sender_key,session_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_sender_keys(JSAny that) => wasmModule.wire__crate__bindings__olm_sessions__olm_session_store_sender_keys(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_session_for_encryption(JSAny that,String sender_key) => wasmModule.wire__crate__bindings__olm_sessions__olm_session_store_session_for_encryption(that,sender_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_sessions_for_device(JSAny that,String sender_key) => wasmModule.wire__crate__bindings__olm_sessions__olm_session_store_sessions_for_device(that,sender_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_set_max_sessions_per_device(JSAny that,int max_sessions_per_device) => wasmModule.wire__crate__bindings__olm_sessions__olm_session_store_set_max_sessions_per_device(that,max_sessions_per_device);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_created_at_ms(JSAny that) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_created_at_ms(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_devices_missing_key(JSAny that,JSAny devices) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_devices_missing_key(that,devices);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_expired_reason(JSAny that,JSAny now_ms) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_expired_reason(that,now_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_from_group_session(JSAny session,JSAny created_at_ms,JSAny? rotation_period_ms,JSAny? rotation_period_msgs) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_from_group_session(session,created_at_ms,rotation_period_ms,rotation_period_msgs);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_from_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_from_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_group_session(JSAny that) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_group_session(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_invalidate(JSAny that) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_invalidate(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_needs_rotation(JSAny that,JSAny now_ms) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_needs_rotation(that,now_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_new(JSAny config,JSAny created_at_ms,JSAny? rotation_period_ms,JSAny? rotation_period_msgs) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_new(config,created_at_ms,rotation_period_ms,rotation_period_msgs);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_pickle_encrypted(JSAny that,JSAny pickle_key) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_pickle_encrypted(that,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_record_share(JSAny that,JSAny recipient) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_record_share(that,recipient);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_record_withheld(JSAny that,JSAny recipient,int code) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_record_withheld(that,recipient,code);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_removed_devices(JSAny that,JSAny devices) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_removed_devices(that,devices);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_ms(JSAny that) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_ms(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_msgs(JSAny that) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_msgs(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_session_id(JSAny that) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_session_id(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_shared_message_index(JSAny that,JSAny recipient) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_shared_message_index(that,recipient);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_withheld_code(JSAny that,JSAny recipient) => wasmModule.wire__crate__bindings__outbound_group_session__outbound_group_session_withheld_code(that,recipient);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_default() => wasmModule.wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_default();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_new() => wasmModule.wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_new();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__passphrase_pickle__passphrase_pickle_params(String pickle) => wasmModule.wire__crate__bindings__passphrase_pickle__passphrase_pickle_params(pickle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__pbkdf2(JSAny passphrase,JSAny salt,int iterations) => wasmModule.wire__crate__bindings__pbkdf2(passphrase,salt,iterations);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__pickle_format__pickle_info_needs_repickle(JSAny that) => wasmModule.wire__crate__bindings__pickle_format__pickle_info_needs_repickle(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__forwarded_room_key__receive_forwarded_room_key(JSAny payload,JSAny forwarder_key,JSAny forwarder_device) => wasmModule.wire__crate__bindings__forwarded_room_key__receive_forwarded_room_key(payload,forwarder_key,forwarder_device);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key__receive_room_key(JSAny payload,JSAny sender_key,JSAny sender_device) => wasmModule.wire__crate__bindings__room_key__receive_room_key(payload,sender_key,sender_device);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_bundle__receive_room_key_bundle(JSAny payload,JSAny sender_key,JSAny sender_device) => wasmModule.wire__crate__bindings__room_key_bundle__receive_room_key_bundle(payload,sender_key,sender_device);
+
+void wire__crate__bindings__rekey__rekey_pickles(NativePortType port_,JSAny pickles,JSAny old_pickle_key,JSAny new_pickle_key,String progress) => wasmModule.wire__crate__bindings__rekey__rekey_pickles(port_,pickles,old_pickle_key,new_pickle_key,progress);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_bundle__room_key_bundle_download_decrypt(JSAny that,JSAny ciphertext) => wasmModule.wire__crate__bindings__room_key_bundle__room_key_bundle_download_decrypt(that,ciphertext);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key__room_key_content(JSAny session,String room_id,bool shared_history) => wasmModule.wire__crate__bindings__room_key__room_key_content(session,room_id,shared_history);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__room_key_info_authenticity(JSAny that) => wasmModule.wire__crate__bindings__room_key_info__room_key_info_authenticity(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__sha256(JSAny input) => wasmModule.wire__crate__bindings__sha256(input);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__sha512(JSAny input) => wasmModule.wire__crate__bindings__sha512(input);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_can_unwedge(JSAny that,String sender_key,JSAny now_ms) => wasmModule.wire__crate__bindings__unwedge__unwedge_tracker_can_unwedge(that,sender_key,now_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_cooldown_ms(JSAny that) => wasmModule.wire__crate__bindings__unwedge__unwedge_tracker_cooldown_ms(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_default() => wasmModule.wire__crate__bindings__unwedge__unwedge_tracker_default();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_from_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__unwedge__unwedge_tracker_from_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_new() => wasmModule.wire__crate__bindings__unwedge__unwedge_tracker_new();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_pickle_encrypted(JSAny that,JSAny pickle_key) => wasmModule.wire__crate__bindings__unwedge__unwedge_tracker_pickle_encrypted(that,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_set_cooldown_ms(JSAny that,JSAny cooldown_ms) => wasmModule.wire__crate__bindings__unwedge__unwedge_tracker_set_cooldown_ms(that,cooldown_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_start_attempt(JSAny that,String sender_key,JSAny now_ms) => wasmModule.wire__crate__bindings__unwedge__unwedge_tracker_start_attempt(that,sender_key,now_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_add_event(JSAny that,String room_id,String event_id,String encrypted_content) => wasmModule.wire__crate__bindings__utd_queue__utd_queue_add_event(that,room_id,event_id,encrypted_content);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_add_room_key(JSAny that,JSAny room_key) => wasmModule.wire__crate__bindings__utd_queue__utd_queue_add_room_key(that,room_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_default() => wasmModule.wire__crate__bindings__utd_queue__utd_queue_default();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_from_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__utd_queue__utd_queue_from_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_is_empty(JSAny that) => wasmModule.wire__crate__bindings__utd_queue__utd_queue_is_empty(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_len(JSAny that) => wasmModule.wire__crate__bindings__utd_queue__utd_queue_len(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_max_events(JSAny that) => wasmModule.wire__crate__bindings__utd_queue__utd_queue_max_events(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_merge_room_key(JSAny that,JSAny existing,JSAny other) => wasmModule.wire__crate__bindings__utd_queue__utd_queue_merge_room_key(that,existing,other);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_new() => wasmModule.wire__crate__bindings__utd_queue__utd_queue_new();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_pending_events(JSAny that,String room_id,String session_id) => wasmModule.wire__crate__bindings__utd_queue__utd_queue_pending_events(that,room_id,session_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_pickle_encrypted(JSAny that,JSAny pickle_key) => wasmModule.wire__crate__bindings__utd_queue__utd_queue_pickle_encrypted(that,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_remove_event(JSAny that,String event_id) => wasmModule.wire__crate__bindings__utd_queue__utd_queue_remove_event(that,event_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_set_max_events(JSAny that,int max_events) => wasmModule.wire__crate__bindings__utd_queue__utd_queue_set_max_events(that,max_events);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__to_device__validate_to_device_payload(JSAny account,String own_user_id,String event_sender,String plaintext) => wasmModule.wire__crate__bindings__to_device__validate_to_device_payload(account,own_user_id,event_sender,plaintext);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_create_inbound_session(JSAny that,JSAny their_identity_key,String pre_key_message_base64) => wasmModule.wire__crate__bindings__vodozemac_account_create_inbound_session(that,their_identity_key,pre_key_message_base64);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_create_outbound_session(JSAny that,JSAny config,JSAny identity_key,JSAny one_time_key) => wasmModule.wire__crate__bindings__vodozemac_account_create_outbound_session(that,config,identity_key,one_time_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_create_outbound_session_from_claim(JSAny that,JSAny config,JSAny device_keys,String claimed_key_json) => wasmModule.wire__crate__bindings__vodozemac_account_create_outbound_session_from_claim(that,config,device_keys,claimed_key_json);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_curve25519_key(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_account_curve25519_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_ed25519_key(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_account_ed25519_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_fallback_key(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_account_fallback_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_forget_fallback_key(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_account_forget_fallback_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_from_any_pickle(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_account_from_any_pickle(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_from_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_account_from_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_from_pickle_with_passphrase(String pickle,String passphrase) => wasmModule.wire__crate__bindings__vodozemac_account_from_pickle_with_passphrase(pickle,passphrase);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_generate_fallback_key(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_account_generate_fallback_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_generate_one_time_keys(JSAny that,JSAny count) => wasmModule.wire__crate__bindings__vodozemac_account_generate_one_time_keys(that,count);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_identity_keys(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_account_identity_keys(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_mark_keys_as_published(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_account_mark_keys_as_published(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_new() => wasmModule.wire__crate__bindings__vodozemac_account_new();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_one_time_keys(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_account_one_time_keys(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_pickle_encrypted(JSAny that,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_account_pickle_encrypted(that,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_pickle_with_passphrase(JSAny that,String passphrase,JSAny params) => wasmModule.wire__crate__bindings__vodozemac_account_pickle_with_passphrase(that,passphrase,params);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_plan_key_maintenance(JSAny that,JSAny tracker,JSAny server_counts,JSAny? unused_fallback_types,JSAny now_ms) => wasmModule.wire__crate__bindings__vodozemac_account_plan_key_maintenance(that,tracker,server_counts,unused_fallback_types,now_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_remove_one_time_key(JSAny that,String public_key) => wasmModule.wire__crate__bindings__vodozemac_account_remove_one_time_key(that,public_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_sign(JSAny that,String message) => wasmModule.wire__crate__bindings__vodozemac_account_sign(that,message);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_unwedge_session(JSAny that,JSAny tracker,JSAny config,String own_user_id,String own_device_id,JSAny device_keys,String claimed_key_json) => wasmModule.wire__crate__bindings__vodozemac_account_unwedge_session(that,tracker,config,own_user_id,own_device_id,device_keys,claimed_key_json);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64(String base64_key) => wasmModule.wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64(base64_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice(JSAny bytes) => wasmModule.wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice(bytes);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64(String base64_key) => wasmModule.wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64(base64_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice(JSAny bytes) => wasmModule.wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice(bytes);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_public_key_verify(JSAny that,String message,JSAny signature) => wasmModule.wire__crate__bindings__vodozemac_ed_25519_public_key_verify(that,message,signature);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_signature_from_base64(String signature) => wasmModule.wire__crate__bindings__vodozemac_ed_25519_signature_from_base64(signature);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_signature_from_slice(JSAny bytes) => wasmModule.wire__crate__bindings__vodozemac_ed_25519_signature_from_slice(bytes);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_signature_to_base64(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_ed_25519_signature_to_base64(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_established_sas_calculate_mac(JSAny that,String input,String info) => wasmModule.wire__crate__bindings__vodozemac_established_sas_calculate_mac(that,input,info);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated(JSAny that,String input,String info) => wasmModule.wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated(that,input,info);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_established_sas_generate_bytes(JSAny that,String info,int length) => wasmModule.wire__crate__bindings__vodozemac_established_sas_generate_bytes(that,info,length);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_established_sas_verify_mac(JSAny that,String input,String info,String mac) => wasmModule.wire__crate__bindings__vodozemac_established_sas_verify_mac(that,input,info,mac);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_encrypt(JSAny that,String plaintext) => wasmModule.wire__crate__bindings__vodozemac_group_session_encrypt(that,plaintext);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_from_any_pickle(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_group_session_from_any_pickle(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_from_pickle_with_passphrase(String pickle,String passphrase) => wasmModule.wire__crate__bindings__vodozemac_group_session_from_pickle_with_passphrase(pickle,passphrase);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_message_index(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_group_session_message_index(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_new(JSAny config) => wasmModule.wire__crate__bindings__vodozemac_group_session_new(config);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_pickle_encrypted(JSAny that,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_group_session_pickle_encrypted(that,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_pickle_with_passphrase(JSAny that,String passphrase,JSAny params) => wasmModule.wire__crate__bindings__vodozemac_group_session_pickle_with_passphrase(that,passphrase,params);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_session_config(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_group_session_session_config(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_session_id(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_group_session_session_id(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_session_key(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_group_session_session_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_to_inbound(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_group_session_to_inbound(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_decrypt(JSAny that,String encrypted) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_decrypt(that,encrypted);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_decrypt_extended(JSAny that,String encrypted) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_decrypt_extended(that,encrypted);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_discard_before(JSAny that,int index) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_discard_before(that,index);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_export_at(JSAny that,int index) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_export_at(that,index);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_first_known_index(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_first_known_index(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_from_any_pickle(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_from_any_pickle(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_with_passphrase(String pickle,String passphrase) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_with_passphrase(pickle,passphrase);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_import(String exported_session_key,JSAny config) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_import(exported_session_key,config);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_key_source(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_key_source(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_new(String session_key,JSAny config) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_new(session_key,config);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted(JSAny that,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted(that,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_pickle_with_passphrase(JSAny that,String passphrase,JSAny params) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_pickle_with_passphrase(that,passphrase,params);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_session_id(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_session_id(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_signing_key(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_signing_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_megolm_session_config_def() => wasmModule.wire__crate__bindings__vodozemac_megolm_session_config_def();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_megolm_session_config_version(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_megolm_session_config_version(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_megolm_session_config_version_1() => wasmModule.wire__crate__bindings__vodozemac_megolm_session_config_version_1();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_megolm_session_config_version_2() => wasmModule.wire__crate__bindings__vodozemac_megolm_session_config_version_2();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_message_from_parts(JSAny message_type,String ciphertext) => wasmModule.wire__crate__bindings__vodozemac_olm_message_from_parts(message_type,ciphertext);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_message_message(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_olm_message_message(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_message_message_type(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_olm_message_message_type(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_session_config_def() => wasmModule.wire__crate__bindings__vodozemac_olm_session_config_def();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_session_config_version(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_olm_session_config_version(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_session_config_version_1() => wasmModule.wire__crate__bindings__vodozemac_olm_session_config_version_1();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_session_config_version_2() => wasmModule.wire__crate__bindings__vodozemac_olm_session_config_version_2();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_decrypt(JSAny that,JSAny message) => wasmModule.wire__crate__bindings__vodozemac_pk_decryption_decrypt(that,message);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_from_any_pickle(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_pk_decryption_from_any_pickle(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_from_key(JSAny secret_key) => wasmModule.wire__crate__bindings__vodozemac_pk_decryption_from_key(secret_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_from_pickle_with_passphrase(String pickle,String passphrase) => wasmModule.wire__crate__bindings__vodozemac_pk_decryption_from_pickle_with_passphrase(pickle,passphrase);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_new() => wasmModule.wire__crate__bindings__vodozemac_pk_decryption_new();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_pickle_with_passphrase(JSAny that,String passphrase,JSAny params) => wasmModule.wire__crate__bindings__vodozemac_pk_decryption_pickle_with_passphrase(that,passphrase,params);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_private_key(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_pk_decryption_private_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_public_key(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_pk_decryption_public_key(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle(JSAny that,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle(that,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_encryption_encrypt(JSAny that,String message) => wasmModule.wire__crate__bindings__vodozemac_pk_encryption_encrypt(that,message);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_encryption_from_key(JSAny public_key) => wasmModule.wire__crate__bindings__vodozemac_pk_encryption_from_key(public_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_message_from_base64(String ciphertext,String mac,String ephemeral_key) => wasmModule.wire__crate__bindings__vodozemac_pk_message_from_base64(ciphertext,mac,ephemeral_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_message_new(JSAny ciphertext,JSAny mac,JSAny ephemeral_key) => wasmModule.wire__crate__bindings__vodozemac_pk_message_new(ciphertext,mac,ephemeral_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_message_to_base64(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_pk_message_to_base64(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_decrypt(JSAny that,JSAny message) => wasmModule.wire__crate__bindings__vodozemac_session_decrypt(that,message);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_encrypt(JSAny that,String plaintext) => wasmModule.wire__crate__bindings__vodozemac_session_encrypt(that,plaintext);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_from_any_pickle(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_session_from_any_pickle(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_from_pickle_encrypted(String pickle,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_session_from_pickle_encrypted(pickle,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_from_pickle_with_passphrase(String pickle,String passphrase) => wasmModule.wire__crate__bindings__vodozemac_session_from_pickle_with_passphrase(pickle,passphrase);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_has_received_message(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_session_has_received_message(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_pickle_encrypted(JSAny that,JSAny pickle_key) => wasmModule.wire__crate__bindings__vodozemac_session_pickle_encrypted(that,pickle_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_pickle_with_passphrase(JSAny that,String passphrase,JSAny params) => wasmModule.wire__crate__bindings__vodozemac_session_pickle_with_passphrase(that,passphrase,params);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_session_config(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_session_session_config(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_session_id(JSAny that) => wasmModule.wire__crate__bindings__vodozemac_session_session_id(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__share_tracking__withheld_code_code(int that) => wasmModule.wire__crate__bindings__share_tracking__withheld_code_code(that);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__share_tracking__withheld_code_from_code(String code) => wasmModule.wire__crate__bindings__share_tracking__withheld_code_from_code(code);
+
+void rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_Ed25519Signature(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_Ed25519Signature(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_EstablishedSas(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_EstablishedSas(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_EstablishedSas(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_EstablishedSas(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_OlmMessage(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_OlmMessage(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_OlmMessage(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_OlmMessage(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_PkDecryption(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_PkDecryption(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_PkDecryption(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_PkDecryption(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_PkEncryption(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_PkEncryption(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_PkEncryption(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_PkEncryption(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_RwLockFallbackKeyState(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockFallbackKeyState(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_RwLockFallbackKeyState(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockFallbackKeyState(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_RwLockReplayState(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockReplayState(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_RwLockReplayState(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockReplayState(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_RwLockRoomKeyInfo(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockRoomKeyInfo(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_RwLockRoomKeyInfo(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockRoomKeyInfo(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_RwLockRotationState(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockRotationState(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_RwLockRotationState(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockRotationState(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_RwLockSession(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockSession(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_RwLockSession(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockSession(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_RwLockSessionKeySource(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockSessionKeySource(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_RwLockSessionKeySource(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockSessionKeySource(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_RwLockSessionStoreState(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockSessionStoreState(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_RwLockSessionStoreState(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockSessionStoreState(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_RwLockShareState(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockShareState(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_RwLockShareState(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockShareState(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_RwLockUnwedgeState(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockUnwedgeState(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_RwLockUnwedgeState(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockUnwedgeState(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_RwLockUtdQueueState(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockUtdQueueState(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_RwLockUtdQueueState(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockUtdQueueState(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_stdsyncRwLockAccount(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_stdsyncRwLockAccount(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount(ptr);
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
+
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_device_signing_upload(int that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_from_secret_keys(String user_id,String master_key,String self_signing_key,String user_signing_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_master_key(int that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_master_secret_key(int that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_new(String user_id);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_key(int that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_self_signing_secret_key(int that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_device(int that,String device_keys);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_sign_user(int that,String master_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_user_id(int that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_key(int that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__cross_signing__CrossSigningIdentity_user_signing_secret_key(int that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_from_secret_key(String key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_new();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_public_key(int that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_secret_key(int that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_sign(int that,String message);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__VodozemacSas_establish_sas_secret(int that,String other_public_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__VodozemacSas_new();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__VodozemacSas_public_key(int that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__aes_ctr(JSAny input,JSAny key,JSAny iv);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__retention__apply_room_retention(JSAny pickles,JSAny pickle_key,JSAny policies,JSAny guard,JSAny now_ms);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_cause__classify_utd(String room_id,String encrypted_content,JSAny candidates,int? withheld_code,JSAny origin_server_ts,JSAny device_created_at_ms);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_bundle__create_room_key_bundle(String room_id,JSAny room_keys);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_event__decrypt_room_event(JSAny session,String room_id,String encrypted_content);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__decrypt_room_event_checked(JSAny session,JSAny guard,String room_id,String event_id,JSAny origin_server_ts,String encrypted_content);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__to_device__decrypt_to_device(JSAny account,JSAny sessions_for_sender,String event_json);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__pickle_format__detect_pickle(String pickle,JSAny pickle_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__device_keys__device_keys_parse_and_verify(String json,String expected_user,String expected_device);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_event__encrypt_room_event(JSAny session,JSAny sender_key,String device_id,String room_id,String event_type,String content);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__to_device__encrypt_to_device(JSAny account,JSAny session,String sender,String sender_device,JSAny recipient,String event_type,String content);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_bundle__encrypted_room_key_bundle_encrypt_for_device(JSAny that,String url,JSAny account,JSAny session,String sender,String sender_device,JSAny recipient);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__trust__evaluate_device_trust(String own_user_id,JSAny own_master_key,JSAny? own_user_signing_key,String keys_query_response);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__key_maintenance__fallback_key_tracker_default();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__key_maintenance__fallback_key_tracker_from_pickle_encrypted(String pickle,JSAny pickle_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__key_maintenance__fallback_key_tracker_grace_period_ms(JSAny that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__key_maintenance__fallback_key_tracker_new();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__key_maintenance__fallback_key_tracker_pickle_encrypted(JSAny that,JSAny pickle_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__key_maintenance__fallback_key_tracker_set_grace_period_ms(JSAny that,JSAny grace_period_ms);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__forwarded_room_key__forwarded_room_key_content(JSAny room_key,int? message_index);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__hmac(JSAny key,JSAny input);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_decrypt_room_event(JSAny that,String room_id,String encrypted_content);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_first_known_index(JSAny that);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_from_pickle_encrypted(String pickle,JSAny pickle_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_inbound_group_session(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__PkSigning_from_secret_key(String key) =>
-          wasmModule.wire__crate__bindings__PkSigning_from_secret_key(key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_info(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_new() =>
-      wasmModule.wire__crate__bindings__PkSigning_new();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_mark_backed_up(JSAny that,bool backed_up);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_public_key(
-          int that) =>
-      wasmModule.wire__crate__bindings__PkSigning_public_key(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_new(JSAny session,JSAny info);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_secret_key(
-          int that) =>
-      wasmModule.wire__crate__bindings__PkSigning_secret_key(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_pickle_encrypted(JSAny that,JSAny pickle_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_sign(
-          int that, String message) =>
-      wasmModule.wire__crate__bindings__PkSigning_sign(that, message);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__inbound_room_key_session_id(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__VodozemacSas_establish_sas_secret(int that, String other_public_key) =>
-          wasmModule.wire__crate__bindings__VodozemacSas_establish_sas_secret(that, other_public_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_default();
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__VodozemacSas_new() =>
-      wasmModule.wire__crate__bindings__VodozemacSas_new();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_forget_before(JSAny that,String session_id,int index);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__VodozemacSas_public_key(
-          int that) =>
-      wasmModule.wire__crate__bindings__VodozemacSas_public_key(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_forget_session(JSAny that,String session_id);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__aes_ctr(
-          JSAny input, JSAny key, JSAny iv) =>
-      wasmModule.wire__crate__bindings__aes_ctr(input, key, iv);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_from_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__hmac(
-          JSAny key, JSAny input) =>
-      wasmModule.wire__crate__bindings__hmac(key, input);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_new();
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__pbkdf2(
-          JSAny passphrase, JSAny salt, int iterations) =>
-      wasmModule.wire__crate__bindings__pbkdf2(passphrase, salt, iterations);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_pickle_encrypted(JSAny that,JSAny pickle_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__sha256(JSAny input) =>
-      wasmModule.wire__crate__bindings__sha256(input);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__replay__megolm_replay_guard_record(JSAny that,String session_id,int message_index,String event_id,JSAny origin_server_ts);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__sha512(JSAny input) =>
-      wasmModule.wire__crate__bindings__sha512(input);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__migration__migrate_libolm_store(JSAny store,JSAny old_pickle_key,JSAny new_pickle_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_create_inbound_session(
-              JSAny that, JSAny their_identity_key, String pre_key_message_base64) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_create_inbound_session(
-              that, their_identity_key, pre_key_message_base64);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__migration__migration_report_is_complete(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_create_outbound_session(
-              JSAny that, JSAny config, JSAny identity_key, JSAny one_time_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_create_outbound_session(
-              that, config, identity_key, one_time_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_add_session(JSAny that,String sender_key,JSAny session,JSAny now_ms);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_curve25519_key(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_curve25519_key(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_default();
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_ed25519_key(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_ed25519_key(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_from_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_fallback_key(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_fallback_key(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_mark_used(JSAny that,String sender_key,String session_id,JSAny now_ms);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_forget_fallback_key(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_forget_fallback_key(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_max_sessions_per_device(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted(String pickle, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted(pickle, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_new();
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_from_pickle_encrypted(String pickle, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_from_pickle_encrypted(pickle, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_pickle_encrypted(JSAny that,JSAny pickle_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_generate_fallback_key(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_generate_fallback_key(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_remove_session(JSAny that,String sender_key,String session_id);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_generate_one_time_keys(JSAny that, JSAny count) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_generate_one_time_keys(that, count);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_sender_keys(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_identity_keys(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_identity_keys(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_session_for_encryption(JSAny that,String sender_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_mark_keys_as_published(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_mark_keys_as_published(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_sessions_for_device(JSAny that,String sender_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__olm_sessions__olm_session_store_set_max_sessions_per_device(JSAny that,int max_sessions_per_device);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_new() => wasmModule.wire__crate__bindings__vodozemac_account_new();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_created_at_ms(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_one_time_keys(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_one_time_keys(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_devices_missing_key(JSAny that,JSAny devices);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_pickle_encrypted(JSAny that, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_pickle_encrypted(that, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_expired_reason(JSAny that,JSAny now_ms);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_remove_one_time_key(JSAny that, String public_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_account_remove_one_time_key(that, public_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_from_group_session(JSAny session,JSAny created_at_ms,JSAny? rotation_period_ms,JSAny? rotation_period_msgs);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_sign(
-          JSAny that, String message) =>
-      wasmModule.wire__crate__bindings__vodozemac_account_sign(that, message);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_from_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes(that);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64(String base64_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64(base64_key);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice(JSAny bytes) =>
-          wasmModule.wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice(bytes);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64(that);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes(that);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64(String base64_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64(base64_key);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice(JSAny bytes) =>
-          wasmModule.wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice(bytes);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64(that);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_public_key_verify(JSAny that, String message, JSAny signature) =>
-          wasmModule.wire__crate__bindings__vodozemac_ed_25519_public_key_verify(that, message, signature);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_group_session(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_signature_from_base64(String signature) =>
-          wasmModule.wire__crate__bindings__vodozemac_ed_25519_signature_from_base64(signature);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_invalidate(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_signature_from_slice(JSAny bytes) =>
-          wasmModule.wire__crate__bindings__vodozemac_ed_25519_signature_from_slice(bytes);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_needs_rotation(JSAny that,JSAny now_ms);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_signature_to_base64(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_ed_25519_signature_to_base64(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_new(JSAny config,JSAny created_at_ms,JSAny? rotation_period_ms,JSAny? rotation_period_msgs);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_pickle_encrypted(JSAny that,JSAny pickle_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_established_sas_calculate_mac(JSAny that, String input, String info) =>
-          wasmModule.wire__crate__bindings__vodozemac_established_sas_calculate_mac(that, input, info);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_record_share(JSAny that,JSAny recipient);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated(
-              JSAny that, String input, String info) =>
-          wasmModule.wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated(that, input, info);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_record_withheld(JSAny that,JSAny recipient,int code);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_established_sas_generate_bytes(JSAny that, String info, int length) =>
-          wasmModule.wire__crate__bindings__vodozemac_established_sas_generate_bytes(that, info, length);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_removed_devices(JSAny that,JSAny devices);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_established_sas_verify_mac(JSAny that, String input, String info, String mac) =>
-          wasmModule.wire__crate__bindings__vodozemac_established_sas_verify_mac(that, input, info, mac);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_ms(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_encrypt(JSAny that, String plaintext) =>
-          wasmModule.wire__crate__bindings__vodozemac_group_session_encrypt(that, plaintext);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_rotation_period_msgs(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted(String pickle, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted(pickle, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_session_id(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted(String pickle, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted(pickle, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_shared_message_index(JSAny that,JSAny recipient);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_message_index(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_group_session_message_index(that);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_new(JSAny config) =>
-          wasmModule.wire__crate__bindings__vodozemac_group_session_new(config);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_pickle_encrypted(JSAny that, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_group_session_pickle_encrypted(that, pickle_key);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_session_config(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_group_session_session_config(that);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_session_id(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_group_session_session_id(that);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_session_key(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_group_session_session_key(that);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_to_inbound(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_group_session_to_inbound(that);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_decrypt(JSAny that, String encrypted) =>
-          wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_decrypt(that, encrypted);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_export_at(JSAny that, int index) =>
-          wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_export_at(that, index);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index(that);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_first_known_index(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_first_known_index(that);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted(
-              String pickle, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted(
-              pickle, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__outbound_group_session__outbound_group_session_withheld_code(JSAny that,JSAny recipient);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted(String pickle, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted(pickle, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_default();
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_import(String exported_session_key, JSAny config) =>
-          wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_import(exported_session_key, config);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__passphrase_pickle__passphrase_kdf_params_new();
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_new(String session_key, JSAny config) =>
-          wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_new(session_key, config);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__passphrase_pickle__passphrase_pickle_params(String pickle);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted(JSAny that, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted(that, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__pbkdf2(JSAny passphrase,JSAny salt,int iterations);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_session_id(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_inbound_group_session_session_id(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__pickle_format__pickle_info_needs_repickle(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_megolm_session_config_def() =>
-          wasmModule.wire__crate__bindings__vodozemac_megolm_session_config_def();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__forwarded_room_key__receive_forwarded_room_key(JSAny payload,JSAny forwarder_key,JSAny forwarder_device);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_megolm_session_config_version(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_megolm_session_config_version(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key__receive_room_key(JSAny payload,JSAny sender_key,JSAny sender_device);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_megolm_session_config_version_1() =>
-          wasmModule.wire__crate__bindings__vodozemac_megolm_session_config_version_1();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_bundle__receive_room_key_bundle(JSAny payload,JSAny sender_key,JSAny sender_device);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_megolm_session_config_version_2() =>
-          wasmModule.wire__crate__bindings__vodozemac_megolm_session_config_version_2();
+external void wire__crate__bindings__rekey__rekey_pickles(NativePortType port_,JSAny pickles,JSAny old_pickle_key,JSAny new_pickle_key,String progress);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_message_from_parts(JSAny message_type, String ciphertext) =>
-          wasmModule.wire__crate__bindings__vodozemac_olm_message_from_parts(message_type, ciphertext);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_bundle__room_key_bundle_download_decrypt(JSAny that,JSAny ciphertext);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_message_message(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_olm_message_message(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key__room_key_content(JSAny session,String room_id,bool shared_history);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_message_message_type(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_olm_message_message_type(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__room_key_info__room_key_info_authenticity(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_session_config_def() =>
-          wasmModule.wire__crate__bindings__vodozemac_olm_session_config_def();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__sha256(JSAny input);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_session_config_version(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_olm_session_config_version(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__sha512(JSAny input);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_session_config_version_1() =>
-          wasmModule.wire__crate__bindings__vodozemac_olm_session_config_version_1();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_can_unwedge(JSAny that,String sender_key,JSAny now_ms);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_session_config_version_2() =>
-          wasmModule.wire__crate__bindings__vodozemac_olm_session_config_version_2();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_cooldown_ms(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_decrypt(JSAny that, JSAny message) =>
-          wasmModule.wire__crate__bindings__vodozemac_pk_decryption_decrypt(that, message);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_default();
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_from_key(JSAny secret_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_pk_decryption_from_key(secret_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_from_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle(String pickle, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle(pickle, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_new();
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_new() =>
-          wasmModule.wire__crate__bindings__vodozemac_pk_decryption_new();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_pickle_encrypted(JSAny that,JSAny pickle_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_private_key(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_pk_decryption_private_key(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_set_cooldown_ms(JSAny that,JSAny cooldown_ms);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_public_key(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_pk_decryption_public_key(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__unwedge__unwedge_tracker_start_attempt(JSAny that,String sender_key,JSAny now_ms);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle(JSAny that, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle(that, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_add_event(JSAny that,String room_id,String event_id,String encrypted_content);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_encryption_encrypt(JSAny that, String message) =>
-          wasmModule.wire__crate__bindings__vodozemac_pk_encryption_encrypt(that, message);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_add_room_key(JSAny that,JSAny room_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_encryption_from_key(JSAny public_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_pk_encryption_from_key(public_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_default();
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_message_from_base64(String ciphertext, String mac, String ephemeral_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_pk_message_from_base64(ciphertext, mac, ephemeral_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_from_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_message_new(
-          JSAny ciphertext, JSAny mac, JSAny ephemeral_key) =>
-      wasmModule.wire__crate__bindings__vodozemac_pk_message_new(ciphertext, mac, ephemeral_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_is_empty(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_message_to_base64(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_pk_message_to_base64(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_len(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_decrypt(JSAny that, JSAny message) =>
-          wasmModule.wire__crate__bindings__vodozemac_session_decrypt(that, message);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_max_events(JSAny that);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_encrypt(JSAny that, String plaintext) =>
-          wasmModule.wire__crate__bindings__vodozemac_session_encrypt(that, plaintext);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_merge_room_key(JSAny that,JSAny existing,JSAny other);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted(String pickle, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted(pickle, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_new();
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_from_pickle_encrypted(String pickle, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_session_from_pickle_encrypted(pickle, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_pending_events(JSAny that,String room_id,String session_id);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_has_received_message(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_session_has_received_message(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_pickle_encrypted(JSAny that,JSAny pickle_key);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_pickle_encrypted(JSAny that, JSAny pickle_key) =>
-          wasmModule.wire__crate__bindings__vodozemac_session_pickle_encrypted(that, pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_remove_event(JSAny that,String event_id);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_session_config(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_session_session_config(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__utd_queue__utd_queue_set_max_events(JSAny that,int max_events);
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_session_id(JSAny that) =>
-          wasmModule.wire__crate__bindings__vodozemac_session_session_id(that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__to_device__validate_to_device_payload(JSAny account,String own_user_id,String event_sender,String plaintext);
 
-  void rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_create_inbound_session(JSAny that,JSAny their_identity_key,String pre_key_message_base64);
 
-  void rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_create_outbound_session(JSAny that,JSAny config,JSAny identity_key,JSAny one_time_key);
 
-  void rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_create_outbound_session_from_claim(JSAny that,JSAny config,JSAny device_keys,String claimed_key_json);
 
-  void rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_curve25519_key(JSAny that);
 
-  void rust_arc_increment_strong_count_RustOpaque_Ed25519Signature(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_Ed25519Signature(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_ed25519_key(JSAny that);
 
-  void rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_fallback_key(JSAny that);
 
-  void rust_arc_increment_strong_count_RustOpaque_EstablishedSas(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_EstablishedSas(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_forget_fallback_key(JSAny that);
 
-  void rust_arc_decrement_strong_count_RustOpaque_EstablishedSas(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_EstablishedSas(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_from_any_pickle(String pickle,JSAny pickle_key);
 
-  void rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  void rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_from_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  void rust_arc_increment_strong_count_RustOpaque_OlmMessage(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_OlmMessage(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_from_pickle_with_passphrase(String pickle,String passphrase);
 
-  void rust_arc_decrement_strong_count_RustOpaque_OlmMessage(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_OlmMessage(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_generate_fallback_key(JSAny that);
 
-  void rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_generate_one_time_keys(JSAny that,JSAny count);
 
-  void rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_identity_keys(JSAny that);
 
-  void rust_arc_increment_strong_count_RustOpaque_PkDecryption(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_PkDecryption(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_mark_keys_as_published(JSAny that);
 
-  void rust_arc_decrement_strong_count_RustOpaque_PkDecryption(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_PkDecryption(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys(JSAny that);
 
-  void rust_arc_increment_strong_count_RustOpaque_PkEncryption(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_PkEncryption(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_new();
 
-  void rust_arc_decrement_strong_count_RustOpaque_PkEncryption(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_PkEncryption(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_one_time_keys(JSAny that);
 
-  void rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_pickle_encrypted(JSAny that,JSAny pickle_key);
 
-  void rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_pickle_with_passphrase(JSAny that,String passphrase,JSAny params);
 
-  void rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_plan_key_maintenance(JSAny that,JSAny tracker,JSAny server_counts,JSAny? unused_fallback_types,JSAny now_ms);
 
-  void rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_remove_one_time_key(JSAny that,String public_key);
 
-  void rust_arc_increment_strong_count_RustOpaque_RwLockSession(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_RwLockSession(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_sign(JSAny that,String message);
 
-  void rust_arc_decrement_strong_count_RustOpaque_RwLockSession(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_RwLockSession(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_account_unwedge_session(JSAny that,JSAny tracker,JSAny config,String own_user_id,String own_device_id,JSAny device_keys,String claimed_key_json);
 
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes(JSAny that);
 
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64(String base64_key);
 
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-              ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice(JSAny bytes);
 
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-              ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64(JSAny that);
 
-  void rust_arc_increment_strong_count_RustOpaque_stdsyncRwLockAccount(int ptr) =>
-      wasmModule.rust_arc_increment_strong_count_RustOpaque_stdsyncRwLockAccount(ptr);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes(JSAny that);
 
-  void rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount(int ptr) =>
-      wasmModule.rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount(ptr);
-}
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64(String base64_key);
 
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice(JSAny bytes);
 
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__PkSigning_from_secret_key(String key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_new();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_public_key_verify(JSAny that,String message,JSAny signature);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__PkSigning_public_key(int that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_signature_from_base64(String signature);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__PkSigning_secret_key(int that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_signature_from_slice(JSAny bytes);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__PkSigning_sign(
-      int that, String message);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_signature_to_base64(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__VodozemacSas_establish_sas_secret(int that, String other_public_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__VodozemacSas_new();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_established_sas_calculate_mac(JSAny that,String input,String info);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__VodozemacSas_public_key(int that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated(JSAny that,String input,String info);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__aes_ctr(
-      JSAny input, JSAny key, JSAny iv);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_established_sas_generate_bytes(JSAny that,String info,int length);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__hmac(
-      JSAny key, JSAny input);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_established_sas_verify_mac(JSAny that,String input,String info,String mac);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__pbkdf2(
-      JSAny passphrase, JSAny salt, int iterations);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_encrypt(JSAny that,String plaintext);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__sha256(
-      JSAny input);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_from_any_pickle(String pickle,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__sha512(
-      JSAny input);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_create_inbound_session(
-          JSAny that, JSAny their_identity_key, String pre_key_message_base64);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_create_outbound_session(
-          JSAny that, JSAny config, JSAny identity_key, JSAny one_time_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_from_pickle_with_passphrase(String pickle,String passphrase);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_curve25519_key(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_message_index(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_ed25519_key(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_new(JSAny config);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_fallback_key(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_pickle_encrypted(JSAny that,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_forget_fallback_key(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_pickle_with_passphrase(JSAny that,String passphrase,JSAny params);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_from_olm_pickle_encrypted(String pickle, JSAny pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_session_config(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_from_pickle_encrypted(String pickle, JSAny pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_session_id(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_generate_fallback_key(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_session_key(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_generate_one_time_keys(JSAny that, JSAny count);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_group_session_to_inbound(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_identity_keys(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_decrypt(JSAny that,String encrypted);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_mark_keys_as_published(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_decrypt_extended(JSAny that,String encrypted);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_max_number_of_one_time_keys(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_discard_before(JSAny that,int index);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_new();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_export_at(JSAny that,int index);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_one_time_keys(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_pickle_encrypted(JSAny that, JSAny pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_first_known_index(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_remove_one_time_key(JSAny that, String public_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_from_any_pickle(String pickle,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_account_sign(JSAny that, String message);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_curve_25519_public_key_as_bytes(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_curve_25519_public_key_from_base64(String base64_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_with_passphrase(String pickle,String passphrase);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_curve_25519_public_key_from_slice(JSAny bytes);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_import(String exported_session_key,JSAny config);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_curve_25519_public_key_to_base64(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_key_source(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_public_key_as_bytes(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_new(String session_key,JSAny config);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_public_key_from_base64(String base64_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted(JSAny that,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_public_key_from_slice(JSAny bytes);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_pickle_with_passphrase(JSAny that,String passphrase,JSAny params);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_public_key_to_base64(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_session_id(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_public_key_verify(JSAny that, String message, JSAny signature);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_inbound_group_session_signing_key(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_signature_from_base64(String signature);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_megolm_session_config_def();
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_signature_from_slice(JSAny bytes);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_megolm_session_config_version(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_signature_to_base64(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_megolm_session_config_version_1();
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_ed_25519_signature_to_bytes(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_megolm_session_config_version_2();
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_established_sas_calculate_mac(JSAny that, String input, String info);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_message_from_parts(JSAny message_type,String ciphertext);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_established_sas_calculate_mac_deprecated(JSAny that, String input, String info);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_message_message(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_established_sas_generate_bytes(JSAny that, String info, int length);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_message_message_type(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_established_sas_verify_mac(JSAny that, String input, String info, String mac);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_session_config_def();
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_encrypt(JSAny that, String plaintext);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_session_config_version(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_from_olm_pickle_encrypted(String pickle, JSAny pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_session_config_version_1();
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_from_pickle_encrypted(String pickle, JSAny pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_olm_session_config_version_2();
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_message_index(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_decrypt(JSAny that,JSAny message);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_new(JSAny config);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_from_any_pickle(String pickle,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_pickle_encrypted(JSAny that, JSAny pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_from_key(JSAny secret_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_session_config(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle(String pickle,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_session_id(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_from_pickle_with_passphrase(String pickle,String passphrase);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_session_key(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_new();
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_group_session_to_inbound(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_pickle_with_passphrase(JSAny that,String passphrase,JSAny params);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_decrypt(JSAny that, String encrypted);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_private_key(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_export_at(JSAny that, int index);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_public_key(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_export_at_first_known_index(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle(JSAny that,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_first_known_index(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_encryption_encrypt(JSAny that,String message);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_from_olm_pickle_encrypted(String pickle, JSAny pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_encryption_from_key(JSAny public_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_from_pickle_encrypted(String pickle, JSAny pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_message_from_base64(String ciphertext,String mac,String ephemeral_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_import(String exported_session_key, JSAny config);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_message_new(JSAny ciphertext,JSAny mac,JSAny ephemeral_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_new(String session_key, JSAny config);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_pk_message_to_base64(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_pickle_encrypted(JSAny that, JSAny pickle_key);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_decrypt(JSAny that,JSAny message);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_inbound_group_session_session_id(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_encrypt(JSAny that,String plaintext);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_megolm_session_config_def();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_from_any_pickle(String pickle,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_megolm_session_config_version(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_megolm_session_config_version_1();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_from_pickle_encrypted(String pickle,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_megolm_session_config_version_2();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_from_pickle_with_passphrase(String pickle,String passphrase);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_message_from_parts(JSAny message_type, String ciphertext);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_has_received_message(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_message_message(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_pickle_encrypted(JSAny that,JSAny pickle_key);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_message_message_type(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_pickle_with_passphrase(JSAny that,String passphrase,JSAny params);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_session_config_def();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_session_config(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_session_config_version(JSAny that);
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__vodozemac_session_session_id(JSAny that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_session_config_version_1();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__share_tracking__withheld_code_code(int that);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_olm_session_config_version_2();
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__bindings__share_tracking__withheld_code_from_code(String code);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_decrypt(JSAny that, JSAny message);
+external void rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_from_key(JSAny secret_key);
+external void rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_from_libolm_pickle(String pickle, JSAny pickle_key);
+external void rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_new();
+external void rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_private_key(JSAny that);
+external void rust_arc_increment_strong_count_RustOpaque_Ed25519Signature(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_public_key(JSAny that);
+external void rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_decryption_to_libolm_pickle(JSAny that, JSAny pickle_key);
+external void rust_arc_increment_strong_count_RustOpaque_EstablishedSas(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_encryption_encrypt(JSAny that, String message);
+external void rust_arc_decrement_strong_count_RustOpaque_EstablishedSas(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_encryption_from_key(JSAny public_key);
+external void rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_message_from_base64(String ciphertext, String mac, String ephemeral_key);
+external void rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_message_new(JSAny ciphertext, JSAny mac, JSAny ephemeral_key);
+external void rust_arc_increment_strong_count_RustOpaque_OlmMessage(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_pk_message_to_base64(JSAny that);
+external void rust_arc_decrement_strong_count_RustOpaque_OlmMessage(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_decrypt(JSAny that, JSAny message);
+external void rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_encrypt(JSAny that, String plaintext);
+external void rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_from_olm_pickle_encrypted(String pickle, JSAny pickle_key);
+external void rust_arc_increment_strong_count_RustOpaque_PkDecryption(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_from_pickle_encrypted(String pickle, JSAny pickle_key);
+external void rust_arc_decrement_strong_count_RustOpaque_PkDecryption(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_has_received_message(JSAny that);
+external void rust_arc_increment_strong_count_RustOpaque_PkEncryption(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_pickle_encrypted(JSAny that, JSAny pickle_key);
+external void rust_arc_decrement_strong_count_RustOpaque_PkEncryption(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_session_config(JSAny that);
+external void rust_arc_increment_strong_count_RustOpaque_RwLockFallbackKeyState(int ptr);
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-      wire__crate__bindings__vodozemac_session_session_id(JSAny that);
+external void rust_arc_decrement_strong_count_RustOpaque_RwLockFallbackKeyState(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_Curve25519PublicKey(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_Curve25519PublicKey(int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_Ed25519PublicKey(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_Ed25519PublicKey(int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_Ed25519Signature(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_RwLockReplayState(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_Ed25519Signature(int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_RwLockReplayState(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_EstablishedSas(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_RwLockRoomKeyInfo(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_EstablishedSas(int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_RwLockRoomKeyInfo(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_MegolmSessionConfig(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_RwLockRotationState(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_MegolmSessionConfig(int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_RwLockRotationState(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_OlmMessage(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_RwLockSession(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_OlmMessage(int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_RwLockSession(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_OlmSessionConfig(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_RwLockSessionKeySource(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_OlmSessionConfig(int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_RwLockSessionKeySource(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_PkDecryption(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_RwLockSessionStoreState(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_PkDecryption(int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_RwLockSessionStoreState(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_PkEncryption(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_RwLockShareState(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_PkEncryption(int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_RwLockShareState(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_RwLockGroupSession(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_RwLockUnwedgeState(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_RwLockGroupSession(int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_RwLockUnwedgeState(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_RwLockInboundGroupSession(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_RwLockUtdQueueState(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_RwLockInboundGroupSession(int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_RwLockUtdQueueState(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_RwLockSession(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_RwLockSession(int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCrossSigningIdentity(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(
-      int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPkSigning(int ptr);
 
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-          int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(int ptr);
 
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(
-          int ptr);
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVodozemacSas(int ptr);
 
-  external void rust_arc_increment_strong_count_RustOpaque_stdsyncRwLockAccount(int ptr);
+external void rust_arc_increment_strong_count_RustOpaque_stdsyncRwLockAccount(int ptr);
 
-  external void rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount(int ptr);
-}
+external void rust_arc_decrement_strong_count_RustOpaque_stdsyncRwLockAccount(int ptr);
+        }
+        
\ No newline at end of file
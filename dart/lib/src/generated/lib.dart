@@ -6,29 +6,161 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// Rust type: RustOpaqueNom<Curve25519PublicKey>
-abstract class Curve25519PublicKey implements RustOpaqueInterface {}
 
-// Rust type: RustOpaqueNom<Ed25519PublicKey>
-abstract class Ed25519PublicKey implements RustOpaqueInterface {}
+            
 
-// Rust type: RustOpaqueNom<Ed25519Signature>
-abstract class Ed25519Signature implements RustOpaqueInterface {}
+            
 
-// Rust type: RustOpaqueNom<MegolmSessionConfig>
-abstract class MegolmSessionConfig implements RustOpaqueInterface {}
+            
+                // Rust type: RustOpaqueNom<Curve25519PublicKey>
+                abstract class Curve25519PublicKey implements RustOpaqueInterface {
+                    
 
-// Rust type: RustOpaqueNom<OlmMessage>
-abstract class OlmMessage implements RustOpaqueInterface {}
+                    
+                }
+                
 
-// Rust type: RustOpaqueNom<OlmSessionConfig>
-abstract class OlmSessionConfig implements RustOpaqueInterface {}
 
-// Rust type: RustOpaqueNom<RwLock < GroupSession >>
-abstract class RwLockGroupSession implements RustOpaqueInterface {}
+                // Rust type: RustOpaqueNom<Ed25519PublicKey>
+                abstract class Ed25519PublicKey implements RustOpaqueInterface {
+                    
 
-// Rust type: RustOpaqueNom<RwLock < InboundGroupSession >>
-abstract class RwLockInboundGroupSession implements RustOpaqueInterface {}
+                    
+                }
+                
 
-// Rust type: RustOpaqueNom<RwLock < Session >>
-abstract class RwLockSession implements RustOpaqueInterface {}
+
+                // Rust type: RustOpaqueNom<Ed25519Signature>
+                abstract class Ed25519Signature implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<MegolmSessionConfig>
+                abstract class MegolmSessionConfig implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<OlmMessage>
+                abstract class OlmMessage implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<OlmSessionConfig>
+                abstract class OlmSessionConfig implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<PkDecryption>
+                abstract class PkDecryption implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<RwLock < FallbackKeyState >>
+                abstract class RwLockFallbackKeyState implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<RwLock < GroupSession >>
+                abstract class RwLockGroupSession implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<RwLock < InboundGroupSession >>
+                abstract class RwLockInboundGroupSession implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<RwLock < ReplayState >>
+                abstract class RwLockReplayState implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<RwLock < Session >>
+                abstract class RwLockSession implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<RwLock < SessionKeySource >>
+                abstract class RwLockSessionKeySource implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<RwLock < SessionStoreState >>
+                abstract class RwLockSessionStoreState implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<RwLock < UnwedgeState >>
+                abstract class RwLockUnwedgeState implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<RwLock < UtdQueueState >>
+                abstract class RwLockUtdQueueState implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueNom<std :: sync :: RwLock < Account >>
+                abstract class RwLockAccount implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -13,6 +13,54 @@ export 'src/api.dart'
         PkEncryption,
         PkMessage,
         PkSigning,
+        DeviceKeys,
+        OlmPayload,
+        ToDeviceDecryptionResult,
+        CrossSigningIdentity,
+        FallbackKeyTracker,
+        OlmSessionStore,
+        OutboundGroupSession,
+        MegolmReplayGuard,
+        InboundRoomKey,
+        EncryptedRoomKeyBundle,
+        RoomKeyBundleDownload,
+        UnwedgeTracker,
+        UtdQueue,
         init,
         isInitialized,
-        CryptoUtils;
+        CryptoUtils,
+        PickleUtils;
+export 'src/generated/bindings/device_keys.dart' show DeviceKeysError;
+export 'src/generated/bindings/key_claim.dart' show KeyClaimError;
+export 'src/generated/bindings/megolm_signing.dart' show SessionKeySource;
+export 'src/generated/bindings/migration.dart'
+    show
+        LibolmStore,
+        MigratedPickle,
+        MigrationError,
+        MigrationFailure,
+        MigrationReport;
+export 'src/generated/bindings/outbound_group_session.dart' show RotationReason;
+export 'src/generated/bindings/passphrase_pickle.dart'
+    show PassphraseKdf, PassphraseKdfParams, PassphrasePickleError;
+export 'src/generated/bindings/pickle_format.dart'
+    show PickleFormat, PickleFormatError, PickleInfo, PickleObject;
+export 'src/generated/bindings/rekey.dart' show RekeyResult;
+export 'src/generated/bindings/retention.dart'
+    show RetainedRoomKey, RetentionResult, RoomRetention;
+export 'src/generated/bindings/room_event.dart'
+    show RoomEventDecryptionResult, RoomEventError;
+export 'src/generated/bindings/room_key.dart' show RoomKeyError;
+export 'src/generated/bindings/room_key_bundle.dart' show RoomKeyBundleError;
+export 'src/generated/bindings/room_key_info.dart'
+    show KeyAuthenticity, RoomKeyDecryptionResult, RoomKeyInfo, RoomKeyOrigin;
+export 'src/generated/bindings/share_tracking.dart'
+    show ShareRecipient, WithheldCode;
+export 'src/generated/bindings/to_device.dart'
+    show OlmPayloadError, ToDeviceDecryptionError;
+export 'src/generated/bindings/trust.dart'
+    show DeviceTrust, DeviceTrustLevel, TrustLink;
+export 'src/generated/bindings/unwedge.dart' show UnwedgeError;
+export 'src/generated/bindings/utd_cause.dart'
+    show UtdCause, UtdClassification;
+export 'src/generated/bindings/utd_queue.dart' show RetriedEvent;
@@ -26,6 +26,107 @@ class Utils {
   static String encodeBase64Unpadded(List<int> s) {
     return base64Encode(s).replaceAll(RegExp(r'=+$', multiLine: true), '');
   }
+
+  /// Encode [value] with sorted object keys, as Matrix signs JSON.
+  static String canonicalJson(Object? value) => jsonEncode(_sortKeys(value));
+
+  static Object? _sortKeys(Object? value) {
+    if (value is Map<String, dynamic>) {
+      final keys = value.keys.toList()..sort();
+      return {for (final key in keys) key: _sortKeys(value[key])};
+    }
+    if (value is List) return value.map(_sortKeys).toList();
+    return value;
+  }
+
+  /// Add the signature of [account] to a JSON object.
+  static Map<String, dynamic> sign(
+    Map<String, dynamic> object,
+    Account account,
+    String userId,
+    String deviceId,
+  ) {
+    final signature = account.sign(canonicalJson(object)).toBase64();
+    return {
+      ...object,
+      'signatures': {
+        userId: {'ed25519:$deviceId': signature},
+      },
+    };
+  }
+
+  /// The device keys of [account], as returned by `/keys/query`.
+  static String deviceKeysJson(
+    Account account,
+    String userId,
+    String deviceId,
+  ) =>
+      jsonEncode(sign(
+        {
+          'user_id': userId,
+          'device_id': deviceId,
+          'algorithms': [
+            'm.olm.v1.curve25519-aes-sha2',
+            'm.megolm.v1.aes-sha2',
+          ],
+          'keys': {
+            'curve25519:$deviceId': account.curve25519Key.toBase64(),
+            'ed25519:$deviceId': account.ed25519Key.toBase64(),
+          },
+        },
+        account,
+        userId,
+        deviceId,
+      ));
+
+  static DeviceKeys deviceKeys(
+    Account account,
+    String userId,
+    String deviceId,
+  ) =>
+      DeviceKeys.parseAndVerify(
+        json: deviceKeysJson(account, userId, deviceId),
+        userId: userId,
+        deviceId: deviceId,
+      );
+
+  /// Claim a new one-time key of [account], as returned by `/keys/claim`.
+  static String claimOneTimeKey(
+    Account account,
+    String userId,
+    String deviceId,
+  ) {
+    account.generateOneTimeKeys(1);
+    final oneTimeKey = account.oneTimeKeys.entries.first;
+    account.markKeysAsPublished();
+    return jsonEncode({
+      'signed_curve25519:${oneTimeKey.key}': sign(
+          {'key': oneTimeKey.value.toBase64()}, account, userId, deviceId),
+    });
+  }
+
+  /// Wrap an `m.room.encrypted` content in a to-device event.
+  static String toDeviceEvent(String sender, String content) => jsonEncode({
+        'type': 'm.room.encrypted',
+        'sender': sender,
+        'content': jsonDecode(content),
+      });
+
+  static RoomKeyInfo roomKeyInfo(
+    String roomId, {
+    RoomKeyOrigin origin = RoomKeyOrigin.direct,
+    bool sharedHistory = false,
+  }) =>
+      RoomKeyInfo(
+        algorithm: 'm.megolm.v1.aes-sha2',
+        roomId: roomId,
+        senderKey: Account().curve25519Key.toBase64(),
+        senderClaimedEd25519: Account().ed25519Key.toBase64(),
+        forwardingCurve25519KeyChain: const [],
+        origin: origin,
+        sharedHistory: sharedHistory,
+        backedUp: false,
+      );
 }
 
 void main() async {
@@ -834,4 +935,835 @@ void main() async {
       );
     });
   });
+
+  group('CrossSigningIdentity', () {
+    test('signs its subkeys with the master key', () async {
+      final identity = CrossSigningIdentity('@alice:example.org');
+      final upload = jsonDecode(identity.deviceSigningUpload());
+
+      final selfSigningKey =
+          Map<String, dynamic>.from(upload['self_signing_key'])
+            ..remove('signatures');
+      final signature = upload['self_signing_key']['signatures']
+          ['@alice:example.org']['ed25519:${identity.masterKey.toBase64()}'];
+      expect(
+          () => identity.masterKey.verify(
+              message: Utils.canonicalJson(selfSigningKey),
+              signature: Ed25519Signature.fromBase64(signature)),
+          returnsNormally);
+      check(upload['user_signing_key']['usage'] as List)
+          .deepEquals(['user_signing']);
+    });
+
+    test('can be restored from its secret keys', () async {
+      final identity = CrossSigningIdentity('@alice:example.org');
+      final restored = CrossSigningIdentity.fromSecretKeys(
+        userId: identity.userId,
+        masterKey: identity.masterSecretKey,
+        selfSigningKey: identity.selfSigningSecretKey,
+        userSigningKey: identity.userSigningSecretKey,
+      );
+
+      check(restored.masterKey.toBase64())
+          .equals(identity.masterKey.toBase64());
+      check(restored.selfSigningKey.toBase64())
+          .equals(identity.selfSigningKey.toBase64());
+      check(restored.userSigningKey.toBase64())
+          .equals(identity.userSigningKey.toBase64());
+    });
+
+    test('only signs devices of its own user', () async {
+      final identity = CrossSigningIdentity('@alice:example.org');
+      final device = Account();
+
+      final upload = jsonDecode(identity.signDevice(
+          Utils.deviceKeysJson(device, '@alice:example.org', 'ALICE')));
+      check((upload['@alice:example.org']['ALICE']['signatures']
+              ['@alice:example.org'] as Map)
+          .keys)
+        ..contains('ed25519:ALICE')
+        ..contains('ed25519:${identity.selfSigningKey.toBase64()}');
+
+      expect(
+          () => identity.signDevice(
+              Utils.deviceKeysJson(device, '@bob:example.org', 'BOB')),
+          throwsA(anything));
+    });
+
+    test('evaluates the trust of devices', () async {
+      final alice = CrossSigningIdentity('@alice:example.org');
+      final upload = jsonDecode(alice.deviceSigningUpload());
+      final signed = jsonDecode(alice.signDevice(
+          Utils.deviceKeysJson(Account(), '@alice:example.org', 'ALICE')));
+      final response = jsonEncode({
+        'device_keys': {
+          '@alice:example.org': {
+            'ALICE': signed['@alice:example.org']['ALICE'],
+            'OTHER': jsonDecode(Utils.deviceKeysJson(
+                Account(), '@alice:example.org', 'OTHER')),
+          },
+        },
+        'master_keys': {'@alice:example.org': upload['master_key']},
+        'self_signing_keys': {'@alice:example.org': upload['self_signing_key']},
+      });
+
+      final trust = CrossSigningIdentity.evaluateDeviceTrust(
+        ownUserId: '@alice:example.org',
+        ownMasterKey: alice.masterKey,
+        ownUserSigningKey: alice.userSigningKey,
+        keysQueryResponse: response,
+      );
+      final levels = {for (final t in trust) t.deviceId: t.level};
+      check(levels['ALICE']).equals(DeviceTrustLevel.crossSignedVerified);
+      check(levels['OTHER']).equals(DeviceTrustLevel.selfSigned);
+    });
+  });
+
+  group('DeviceKeys', () {
+    test('can be parsed and verified', () async {
+      final account = Account();
+      final keys = Utils.deviceKeys(account, '@alice:example.org', 'ALICE');
+
+      check(keys.userId).equals('@alice:example.org');
+      check(keys.deviceId).equals('ALICE');
+      check(keys.algorithms).contains('m.olm.v1.curve25519-aes-sha2');
+      check(keys.ed25519Key.toBase64()).equals(account.ed25519Key.toBase64());
+      check(keys.curve25519Key.toBase64())
+          .equals(account.curve25519Key.toBase64());
+    });
+
+    test('reject other devices and forged signatures', () async {
+      final json = Utils.deviceKeysJson(Account(), '@alice:example.org', 'A');
+
+      expect(
+          () => DeviceKeys.parseAndVerify(
+              json: json, userId: '@bob:example.org', deviceId: 'A'),
+          throwsA(DeviceKeysError.userIdMismatch));
+      expect(
+          () => DeviceKeys.parseAndVerify(
+              json: json, userId: '@alice:example.org', deviceId: 'B'),
+          throwsA(DeviceKeysError.deviceIdMismatch));
+
+      final forged = jsonDecode(json)
+        ..['algorithms'] = ['m.megolm.v1.aes-sha2'];
+      expect(
+          () => DeviceKeys.parseAndVerify(
+              json: jsonEncode(forged),
+              userId: '@alice:example.org',
+              deviceId: 'A'),
+          throwsA(DeviceKeysError.invalidSignature));
+    });
+  });
+
+  group('Olm', () {
+    test('can create sessions from claimed keys', () async {
+      final alice = Account();
+      final bob = Account();
+      final bobKeys = Utils.deviceKeys(bob, '@bob:example.org', 'BOB');
+
+      final claimed = alice.createOutboundSessionFromClaim(
+        deviceKeys: bobKeys,
+        claimedKeyJson:
+            Utils.claimOneTimeKey(bob, '@bob:example.org', 'BOB'),
+      );
+      check(claimed.fallback).isFalse();
+
+      final encrypted = claimed.session.encrypt('Hi');
+      check(bob
+              .createInboundSession(
+                  theirIdentityKey: alice.curve25519Key,
+                  preKeyMessageBase64: encrypted.ciphertext)
+              .plaintext)
+          .equals('Hi');
+
+      final forged =
+          Utils.claimOneTimeKey(Account(), '@bob:example.org', 'BOB');
+      expect(
+          () => alice.createOutboundSessionFromClaim(
+              deviceKeys: bobKeys, claimedKeyJson: forged),
+          throwsA(KeyClaimError.invalidSignature));
+    });
+
+    test('plans the key maintenance', () async {
+      final account = Account();
+      final tracker = FallbackKeyTracker();
+
+      final plan = account.planKeyMaintenance(
+        tracker: tracker,
+        serverCounts: {},
+        unusedFallbackTypes: [],
+        nowMs: 0,
+      );
+      check(plan.generateOneTimeKeys)
+          .equals(account.maxNumberOfOneTimeKeys ~/ 2);
+      check(plan.rotateFallbackKey).isTrue();
+      check(plan.forgetOldFallbackKey).isFalse();
+      check(account.oneTimeKeys.length).equals(plan.generateOneTimeKeys);
+      check(account.fallbackKey.length).equals(1);
+
+      // Nothing changes until the new keys were uploaded.
+      final again = account.planKeyMaintenance(
+        tracker: tracker,
+        serverCounts: {},
+        unusedFallbackTypes: [],
+        nowMs: 0,
+      );
+      check(again.generateOneTimeKeys).equals(0);
+      check(again.rotateFallbackKey).isFalse();
+
+      final pickleKey = Uint8List(32);
+      tracker.gracePeriodMs = 1000;
+      check(FallbackKeyTracker.fromPickleEncrypted(
+              pickle: tracker.toPickleEncrypted(pickleKey),
+              pickleKey: pickleKey)
+          .gracePeriodMs).equals(1000);
+    });
+
+    test('encrypts and decrypts to-device events', () async {
+      final alice = Account();
+      final bob = Account();
+      final bobKeys = Utils.deviceKeys(bob, '@bob:example.org', 'BOB');
+      final session = alice.createOutboundSessionFromClaim(
+        deviceKeys: bobKeys,
+        claimedKeyJson:
+            Utils.claimOneTimeKey(bob, '@bob:example.org', 'BOB'),
+      ).session;
+
+      String send(String content) => Utils.toDeviceEvent(
+            '@alice:example.org',
+            alice.encryptToDevice(
+              session: session,
+              sender: '@alice:example.org',
+              senderDevice: 'ALICE',
+              recipient: bobKeys,
+              eventType: 'm.dummy',
+              content: content,
+            ),
+          );
+
+      final first =
+          bob.decryptToDevice(sessionsForSender: [], eventJson: send('{}'));
+      check(first.senderKey.toBase64()).equals(alice.curve25519Key.toBase64());
+      final newSession = first.newSession;
+      check(newSession).isNotNull();
+
+      final payload = bob.validateToDevicePayload(
+        ownUserId: '@bob:example.org',
+        eventSender: '@alice:example.org',
+        plaintext: first.plaintext,
+      );
+      check(payload.eventType).equals('m.dummy');
+      check(payload.content).equals('{}');
+      check(payload.senderDevice).equals('ALICE');
+      check(payload.senderClaimedEd25519.toBase64())
+          .equals(alice.ed25519Key.toBase64());
+      expect(
+          () => bob.validateToDevicePayload(
+              ownUserId: '@bob:example.org',
+              eventSender: '@mallory:example.org',
+              plaintext: first.plaintext),
+          throwsA(OlmPayloadError.senderMismatch));
+
+      final second = bob.decryptToDevice(
+          sessionsForSender: [newSession!], eventJson: send('{"a":1}'));
+      check(second.sessionId).equals(newSession.sessionId);
+      check(second.newSession).isNull();
+    });
+
+    test('unwedges sessions at most once per cooldown', () async {
+      final alice = Account();
+      final bob = Account();
+      final bobKeys = Utils.deviceKeys(bob, '@bob:example.org', 'BOB');
+      final tracker = UnwedgeTracker();
+      final bobKey = bob.curve25519Key.toBase64();
+
+      expect(
+          () => alice.unwedgeSession(
+                tracker: tracker,
+                ownUserId: '@alice:example.org',
+                ownDeviceId: 'ALICE',
+                deviceKeys: bobKeys,
+                claimedKeyJson:
+                    Utils.claimOneTimeKey(bob, '@bob:example.org', 'BOB'),
+              ),
+          throwsA(UnwedgeError.notStarted));
+
+      tracker.startAttempt(senderKey: bobKey, nowMs: 0);
+      final result = alice.unwedgeSession(
+        tracker: tracker,
+        ownUserId: '@alice:example.org',
+        ownDeviceId: 'ALICE',
+        deviceKeys: bobKeys,
+        claimedKeyJson: Utils.claimOneTimeKey(bob, '@bob:example.org', 'BOB'),
+      );
+      final decrypted = bob.decryptToDevice(
+          sessionsForSender: [],
+          eventJson: Utils.toDeviceEvent(
+              '@alice:example.org', result.encryptedContent));
+      check(decrypted.newSession?.sessionId)
+          .equals(result.session.sessionId);
+
+      // The attempt is used up by the new session.
+      expect(
+          () => alice.unwedgeSession(
+                tracker: tracker,
+                ownUserId: '@alice:example.org',
+                ownDeviceId: 'ALICE',
+                deviceKeys: bobKeys,
+                claimedKeyJson:
+                    Utils.claimOneTimeKey(bob, '@bob:example.org', 'BOB'),
+              ),
+          throwsA(UnwedgeError.notStarted));
+      expect(() => tracker.startAttempt(senderKey: bobKey, nowMs: 1000),
+          throwsA(UnwedgeError.rateLimited));
+      check(tracker.canUnwedge(senderKey: bobKey, nowMs: tracker.cooldownMs))
+          .isTrue();
+    });
+
+    test('stores sessions per device', () async {
+      final alice = Account();
+      final bob = Account();
+      final bobKey = bob.curve25519Key.toBase64();
+      final bobKeys = Utils.deviceKeys(bob, '@bob:example.org', 'BOB');
+      Session newSession() => alice
+          .createOutboundSessionFromClaim(
+            deviceKeys: bobKeys,
+            claimedKeyJson:
+                Utils.claimOneTimeKey(bob, '@bob:example.org', 'BOB'),
+          )
+          .session;
+
+      final store = OlmSessionStore();
+      final first = newSession();
+      final second = newSession();
+      check(store.addSession(senderKey: bobKey, session: first, nowMs: 1))
+          .isEmpty();
+      check(store.addSession(senderKey: bobKey, session: second, nowMs: 2))
+          .isEmpty();
+      check(store.sessionForEncryption(bobKey)?.sessionId)
+          .equals(second.sessionId);
+      check(store.senderKeys).deepEquals([bobKey]);
+
+      check(store.setMaxSessionsPerDevice(1)).deepEquals([first.sessionId]);
+      check(store.sessionsForDevice(bobKey).map((s) => s.sessionId))
+          .deepEquals([second.sessionId]);
+
+      final pickleKey = Uint8List(32);
+      final restored = OlmSessionStore.fromPickleEncrypted(
+          pickle: store.toPickleEncrypted(pickleKey), pickleKey: pickleKey);
+      check(restored.maxSessionsPerDevice).equals(1);
+      check(restored.removeSession(
+              senderKey: bobKey, sessionId: second.sessionId))
+          .isTrue();
+      check(restored.sessionForEncryption(bobKey)).isNull();
+    });
+  });
+
+  group('Megolm room events', () {
+    const room = '!room:example.org';
+
+    String encrypt(GroupSession session, String content) =>
+        session.encryptRoomEvent(
+          senderKey: Account().curve25519Key,
+          deviceId: 'DEVICE',
+          roomId: room,
+          eventType: 'm.room.message',
+          content: content,
+        );
+
+    test('can be encrypted and decrypted', () async {
+      final outbound = GroupSession();
+      final inbound = outbound.toInbound();
+
+      final decrypted = inbound.decryptRoomEvent(
+          roomId: room, encryptedContent: encrypt(outbound, '{"body":"Hi"}'));
+      check(decrypted.eventType).equals('m.room.message');
+      check(decrypted.content).equals('{"body":"Hi"}');
+      check(decrypted.messageIndex).equals(0);
+
+      expect(
+          () => inbound.decryptRoomEvent(
+              roomId: '!other:example.org',
+              encryptedContent: encrypt(outbound, '{}')),
+          throwsA(RoomEventError.roomMismatch));
+
+      final upgraded = jsonDecode(encrypt(outbound, '{}'))
+        ..['algorithm'] = 'm.megolm.v2.aes-sha2';
+      expect(
+          () => inbound.decryptRoomEvent(
+              roomId: room, encryptedContent: jsonEncode(upgraded)),
+          throwsA(RoomEventError.algorithmMismatch));
+    });
+
+    test('are rejected when replayed', () async {
+      final outbound = GroupSession();
+      final inbound = outbound.toInbound();
+      final guard = MegolmReplayGuard();
+      final encrypted = encrypt(outbound, '{}');
+
+      RoomEventDecryptionResult decrypt(String eventId) =>
+          inbound.decryptRoomEventChecked(
+            guard: guard,
+            roomId: room,
+            eventId: eventId,
+            originServerTs: 1000,
+            encryptedContent: encrypted,
+          );
+
+      check(decrypt(r'$first').messageIndex).equals(0);
+      check(decrypt(r'$first').messageIndex).equals(0);
+      expect(() => decrypt(r'$second'), throwsA(RoomEventError.replayed));
+
+      final pickleKey = Uint8List(32);
+      final restored = MegolmReplayGuard.fromPickleEncrypted(
+          pickle: guard.toPickleEncrypted(pickleKey), pickleKey: pickleKey);
+      check(restored.record(
+              sessionId: inbound.sessionId,
+              messageIndex: 0,
+              eventId: r'$second',
+              originServerTs: 1000))
+          .isFalse();
+      restored.forgetBefore(inbound.sessionId, 0);
+      check(restored.record(
+              sessionId: inbound.sessionId,
+              messageIndex: 0,
+              eventId: r'$second',
+              originServerTs: 1000))
+          .isFalse();
+      restored.forgetBefore(inbound.sessionId, 1);
+      check(restored.record(
+              sessionId: inbound.sessionId,
+              messageIndex: 0,
+              eventId: r'$second',
+              originServerTs: 1000))
+          .isTrue();
+      restored.forgetSession(inbound.sessionId);
+      check(restored.record(
+              sessionId: inbound.sessionId,
+              messageIndex: 0,
+              eventId: r'$third',
+              originServerTs: 1000))
+          .isTrue();
+    });
+
+    test('report the signing key and source of the session', () async {
+      final outbound = GroupSession();
+      final inbound = InboundGroupSession(outbound.sessionKey);
+      final imported =
+          InboundGroupSession.import(inbound.exportAtFirstKnownIndex());
+      final encrypted = outbound.encrypt('Hi');
+
+      final result = inbound.decryptExtended(encrypted);
+      check(result.plaintext).equals('Hi');
+      check(result.signingKey.toBase64()).equals(inbound.signingKey.toBase64());
+      check(result.keySource).equals(SessionKeySource.sessionKey);
+
+      check(imported.keySource).equals(SessionKeySource.exportedSessionKey);
+      check(imported.decryptExtended(encrypted).keySource)
+          .equals(SessionKeySource.exportedSessionKey);
+    });
+
+    test('can discard old message keys', () async {
+      final outbound = GroupSession();
+      final inbound = outbound.toInbound();
+      final first = outbound.encrypt('first');
+      final second = outbound.encrypt('second');
+
+      check(inbound.discardBefore(1)).isTrue();
+      check(inbound.discardBefore(1)).isFalse();
+      check(inbound.firstKnownIndex).equals(1);
+      expect(() => inbound.decrypt(first), throwsA(anything));
+      check(inbound.decrypt(second).plaintext).equals('second');
+    });
+
+    test('apply the retention of rooms to stored keys', () async {
+      final guard = MegolmReplayGuard();
+      final pickleKey = Uint8List(32);
+      final outbound = GroupSession();
+      final roomKey = InboundRoomKey(
+          session: outbound.toInbound(), info: Utils.roomKeyInfo(room));
+      for (final (i, ts) in [1000, 9000].indexed) {
+        roomKey.inboundGroupSession.decryptRoomEventChecked(
+          guard: guard,
+          roomId: room,
+          eventId: '\$$i',
+          originServerTs: ts,
+          encryptedContent: encrypt(outbound, '{}'),
+        );
+      }
+
+      final results = InboundRoomKey.applyRoomRetention(
+        pickles: [roomKey.toPickleEncrypted(pickleKey), 'not a pickle'],
+        pickleKey: pickleKey,
+        policies: [RoomRetention(roomId: room, maxAgeMs: BigInt.from(5000))],
+        guard: guard,
+        nowMs: 10000,
+      );
+      check(results.length).equals(2);
+      check(results[0].roomKey?.discarded).equals(true);
+      check(results[0].roomKey?.firstKnownIndex).equals(1);
+      check(results[1].roomKey).isNull();
+      check(results[1].error).isNotNull();
+    });
+  });
+
+  group('OutboundGroupSession', () {
+    test('rotates after the configured number of messages', () async {
+      final session = OutboundGroupSession(
+          createdAtMs: 0, rotationPeriodMs: 1000, rotationPeriodMsgs: 1);
+      check(session.needsRotation(0)).isFalse();
+
+      session.groupSession.encrypt('Hi');
+      check(session.expiredReason(0)).equals(RotationReason.messageCount);
+      check(session.expiredReason(999)).equals(RotationReason.messageCount);
+
+      final fresh = OutboundGroupSession.fromGroupSession(
+          session: GroupSession(), createdAtMs: 0, rotationPeriodMs: 1000);
+      check(fresh.expiredReason(1000)).equals(RotationReason.age);
+      fresh.invalidate();
+      check(fresh.expiredReason(0)).equals(RotationReason.invalidated);
+    });
+
+    test('tracks who received the key', () async {
+      final session = OutboundGroupSession(createdAtMs: 0);
+      const alice = ShareRecipient(
+          userId: '@alice:example.org', deviceId: 'A', curve25519Key: 'a');
+      const bob = ShareRecipient(
+          userId: '@bob:example.org', deviceId: 'B', curve25519Key: 'b');
+
+      check(session.devicesMissingKey([alice, bob])).deepEquals([alice, bob]);
+      session.recordShare(alice);
+      session.recordWithheld(bob, WithheldCode.unverified);
+      check(session.devicesMissingKey([alice, bob])).isEmpty();
+      check(session.sharedMessageIndex(alice)).equals(0);
+      check(session.withheldCode(bob)).equals(WithheldCode.unverified);
+      check(session.removedDevices([bob])).deepEquals([alice]);
+
+      final pickleKey = Uint8List(32);
+      final restored = OutboundGroupSession.fromPickleEncrypted(
+          pickle: session.toPickleEncrypted(pickleKey), pickleKey: pickleKey);
+      check(restored.sessionId).equals(session.sessionId);
+      check(restored.sharedMessageIndex(alice)).equals(0);
+    });
+  });
+
+  group('Room keys', () {
+    const room = '!room:example.org';
+
+    test('can be shared, forwarded and bundled over Olm', () async {
+      final alice = Account();
+      final bob = Account();
+      final aliceKeys = Utils.deviceKeys(alice, '@alice:example.org', 'ALICE');
+      final bobKeys = Utils.deviceKeys(bob, '@bob:example.org', 'BOB');
+      final session = alice.createOutboundSessionFromClaim(
+        deviceKeys: bobKeys,
+        claimedKeyJson:
+            Utils.claimOneTimeKey(bob, '@bob:example.org', 'BOB'),
+      ).session;
+      final bobSessions = <Session>[];
+
+      OlmPayload receive(String encryptedContent) {
+        final decrypted = bob.decryptToDevice(
+            sessionsForSender: bobSessions,
+            eventJson:
+                Utils.toDeviceEvent('@alice:example.org', encryptedContent));
+        final newSession = decrypted.newSession;
+        if (newSession != null) bobSessions.add(newSession);
+        return bob.validateToDevicePayload(
+            ownUserId: '@bob:example.org',
+            eventSender: '@alice:example.org',
+            plaintext: decrypted.plaintext);
+      }
+
+      String send(String eventType, String content) => alice.encryptToDevice(
+            session: session,
+            sender: '@alice:example.org',
+            senderDevice: 'ALICE',
+            recipient: bobKeys,
+            eventType: eventType,
+            content: content,
+          );
+
+      final outbound = GroupSession();
+      final roomKey = InboundRoomKey.fromRoomKey(
+        payload: receive(send('m.room_key',
+            outbound.roomKeyContent(roomId: room, sharedHistory: true))),
+        senderKey: alice.curve25519Key,
+        senderDevice: aliceKeys,
+      );
+      check(roomKey.sessionId).equals(outbound.sessionId);
+      check(roomKey.info.origin).equals(RoomKeyOrigin.direct);
+      check(roomKey.info.sharedHistory).isTrue();
+
+      final encrypted = outbound.encryptRoomEvent(
+        senderKey: alice.curve25519Key,
+        deviceId: 'ALICE',
+        roomId: room,
+        eventType: 'm.room.message',
+        content: '{"body":"Hi"}',
+      );
+      final decrypted =
+          roomKey.decryptRoomEvent(roomId: room, encryptedContent: encrypted);
+      check(decrypted.content).equals('{"body":"Hi"}');
+      check(decrypted.authenticity).equals(KeyAuthenticity.authenticated);
+
+      // Alice forwards the key, e.g. after Bob requested it.
+      final forwarded = InboundRoomKey.fromForwardedRoomKey(
+        payload: receive(send('m.forwarded_room_key',
+            roomKey.forwardedRoomKeyContent()!)),
+        forwarderKey: alice.curve25519Key,
+        forwarderDevice: aliceKeys,
+      );
+      check(forwarded.info.origin).equals(RoomKeyOrigin.forwarded);
+      check(forwarded.info.forwardingCurve25519KeyChain)
+          .deepEquals([alice.curve25519Key.toBase64()]);
+      check(forwarded
+              .decryptRoomEvent(roomId: room, encryptedContent: encrypted)
+              .authenticity)
+          .equals(KeyAuthenticity.forwarded);
+
+      // Keys of other rooms are left out of the bundle.
+      final otherRoomKey = InboundRoomKey(
+          session: GroupSession().toInbound(),
+          info: Utils.roomKeyInfo('!other:example.org', sharedHistory: true));
+      final bundle = EncryptedRoomKeyBundle(
+          roomId: room, roomKeys: [roomKey, otherRoomKey]);
+      final download = RoomKeyBundleDownload.fromPayload(
+        payload: receive(bundle.encryptForDevice(
+          url: 'mxc://example.org/bundle',
+          account: alice,
+          session: session,
+          sender: '@alice:example.org',
+          senderDevice: 'ALICE',
+          recipient: bobKeys,
+        )),
+        senderKey: alice.curve25519Key,
+        senderDevice: aliceKeys,
+      );
+      check(download.roomId).equals(room);
+      check(download.url).equals('mxc://example.org/bundle');
+      final imported = download.decrypt(bundle.ciphertext);
+      check(imported.skipped).equals(0);
+      check(imported.roomKeys.single.sessionId).equals(outbound.sessionId);
+      check(imported.roomKeys.single.info.origin)
+          .equals(RoomKeyOrigin.forwarded);
+    });
+
+    test('can be pickled with their info', () async {
+      final outbound = GroupSession();
+      final roomKey = InboundRoomKey(
+          session: outbound.toInbound(), info: Utils.roomKeyInfo(room));
+      roomKey.markBackedUp(true);
+
+      final pickleKey = Uint8List(32);
+      final restored = InboundRoomKey.fromPickleEncrypted(
+          pickle: roomKey.toPickleEncrypted(pickleKey), pickleKey: pickleKey);
+      check(restored.sessionId).equals(outbound.sessionId);
+      check(restored.firstKnownIndex).equals(0);
+      check(restored.info.backedUp).isTrue();
+      check(restored.info.roomId).equals(room);
+    });
+  });
+
+  group('Undecryptable events', () {
+    const room = '!room:example.org';
+
+    String encrypt(GroupSession session, String content) =>
+        session.encryptRoomEvent(
+          senderKey: Account().curve25519Key,
+          deviceId: 'DEVICE',
+          roomId: room,
+          eventType: 'm.room.message',
+          content: content,
+        );
+
+    test('are retried when the key arrives', () async {
+      final outbound = GroupSession();
+      final full = outbound.toInbound();
+      final queue = UtdQueue();
+      queue.addEvent(
+          roomId: room,
+          eventId: r'$0',
+          encryptedContent: encrypt(outbound, '{"body":"zero"}'));
+      final later = outbound.toInbound();
+      queue.addEvent(
+          roomId: room,
+          eventId: r'$1',
+          encryptedContent: encrypt(outbound, '{"body":"one"}'));
+      check(queue.length).equals(2);
+      check(queue.pendingEvents(roomId: room, sessionId: outbound.sessionId))
+          .deepEquals([r'$0', r'$1']);
+
+      final forwarded = InboundRoomKey(
+          session: later,
+          info: Utils.roomKeyInfo(room, origin: RoomKeyOrigin.forwarded));
+      final retried = queue.addRoomKey(forwarded);
+      check(retried.map((e) => e.eventId)).deepEquals([r'$1']);
+      check(retried.single.result.content).equals('{"body":"one"}');
+
+      final pickleKey = Uint8List(32);
+      final restored = UtdQueue.fromPickleEncrypted(
+          pickle: queue.toPickleEncrypted(pickleKey), pickleKey: pickleKey);
+      check(restored.length).equals(1);
+
+      final direct =
+          InboundRoomKey(session: full, info: Utils.roomKeyInfo(room));
+      final merged = restored.mergeRoomKey(existing: forwarded, other: direct);
+      check(merged.map((e) => e.eventId)).deepEquals([r'$0']);
+      check(restored.isEmpty).isTrue();
+    });
+
+    test('drop the oldest events beyond the limit', () async {
+      final outbound = GroupSession();
+      final queue = UtdQueue();
+      check(queue.setMaxEvents(1)).isEmpty();
+
+      for (final eventId in [r'$0', r'$1']) {
+        queue.addEvent(
+            roomId: room,
+            eventId: eventId,
+            encryptedContent: encrypt(outbound, '{}'));
+      }
+      check(queue.maxEvents).equals(1);
+      check(queue.removeEvent(r'$0')).isFalse();
+      check(queue.removeEvent(r'$1')).isTrue();
+    });
+
+    test('are classified', () async {
+      final outbound = GroupSession();
+      final inbound = outbound.toInbound();
+      final encrypted = encrypt(outbound, '{}');
+
+      UtdCause? classify(
+        List<InboundGroupSession> candidates, {
+        WithheldCode? withheldCode,
+        int originServerTs = 2000,
+      }) =>
+          UtdQueue.classifyUtd(
+            roomId: room,
+            encryptedContent: encrypted,
+            candidates: [
+              for (final session in candidates)
+                InboundRoomKey(session: session, info: Utils.roomKeyInfo(room)),
+            ],
+            withheldCode: withheldCode,
+            originServerTs: originServerTs,
+            deviceCreatedAtMs: 1000,
+          )?.cause;
+
+      check(classify([inbound])).isNull();
+      check(classify([outbound.toInbound()])).equals(UtdCause.indexTooEarly);
+      check(classify([])).equals(UtdCause.sessionUnknown);
+      check(classify([], originServerTs: 500))
+          .equals(UtdCause.sentBeforeDeviceCreated);
+      check(classify([], withheldCode: WithheldCode.unverified))
+          .equals(UtdCause.withheld);
+    });
+  });
+
+  group('Pickles', () {
+    final pickleKey = Uint8List.fromList(List.filled(32, 1));
+
+    test('can be detected and unpickled from any format', () async {
+      final account = Account();
+      final pickle = account.toPickleEncrypted(pickleKey);
+
+      final info =
+          PickleUtils.detectPickle(pickle: pickle, pickleKey: pickleKey);
+      check(info.format).equals(PickleFormat.vodozemac);
+      check(info.object).equals(PickleObject.account);
+      check(info.needsRepickle()).isFalse();
+
+      final unpickled =
+          Account.fromAnyPickle(pickle: pickle, pickleKey: pickleKey);
+      check(unpickled.needsRepickle).isFalse();
+      check(unpickled.account.curve25519Key.toBase64())
+          .equals(account.curve25519Key.toBase64());
+
+      final decryption = PkDecryption();
+      final libolmPickle = decryption.toLibolmPickle(pickleKey);
+      check(PickleUtils.detectPickle(pickle: libolmPickle, pickleKey: pickleKey)
+              .format)
+          .equals(PickleFormat.libolm);
+      check(PkDecryption.fromAnyPickle(
+              pickle: libolmPickle, pickleKey: pickleKey)
+          .pkDecryption
+          .publicKey).equals(decryption.publicKey);
+
+      expect(
+          () => PickleUtils.detectPickle(
+              pickle: pickle, pickleKey: Uint8List(32)),
+          throwsA(PickleFormatError.wrongKey));
+    });
+
+    test('can be protected with a passphrase', () async {
+      final session = GroupSession();
+      final params = PassphraseKdfParams.raw(
+          kdf: PassphraseKdf.pbkdf2Sha512, iterations: 100000);
+      final pickle =
+          session.toPickleWithPassphrase('passphrase', params: params);
+
+      check(PickleUtils.passphrasePickleParams(pickle)).equals(params);
+      check(GroupSession.fromPickleWithPassphrase(
+              pickle: pickle, passphrase: 'passphrase')
+          .sessionId).equals(session.sessionId);
+      expect(
+          () => GroupSession.fromPickleWithPassphrase(
+              pickle: pickle, passphrase: 'wrong'),
+          throwsA(anything));
+
+      final account = Account();
+      final accountPickle = account.toPickleWithPassphrase('passphrase');
+      check(Account.fromPickleWithPassphrase(
+              pickle: accountPickle, passphrase: 'passphrase')
+          .ed25519Key
+          .toBase64()).equals(account.ed25519Key.toBase64());
+    });
+
+    test('of a libolm store can be migrated', () async {
+      final decryption = PkDecryption();
+      final newPickleKey = Uint8List(32);
+      final report = PickleUtils.migrateLibolmStore(
+        store: LibolmStore(
+          sessions: [],
+          inboundGroupSessions: ['not a pickle'],
+          pkDecryption: decryption.toLibolmPickle(pickleKey),
+        ),
+        oldPickleKey: pickleKey,
+        newPickleKey: newPickleKey,
+      );
+
+      check(report.isComplete()).isFalse();
+      check(report.failures.single.object)
+          .equals(PickleObject.inboundGroupSession);
+      check(report.failures.single.error)
+          .equals(MigrationError.invalidLibolmPickle);
+      final migrated = report.pkDecryption!;
+      check(migrated.idBefore).equals(decryption.publicKey);
+      check(migrated.idAfter).equals(decryption.publicKey);
+      check(PkDecryption.fromLibolmPickle(
+              pickle: migrated.pickle, pickleKey: newPickleKey)
+          .publicKey).equals(decryption.publicKey);
+    });
+
+    test('can be rekeyed in the background', () async {
+      final account = Account();
+      final newPickleKey = Uint8List(32);
+      final done = <int>[];
+      final results = await PickleUtils.rekeyPickles(
+        pickles: [account.toPickleEncrypted(pickleKey), 'not base64!'],
+        oldPickleKey: pickleKey,
+        newPickleKey: newPickleKey,
+        onProgress: done.add,
+      );
+
+      check(done..sort()).deepEquals([1, 2]);
+      check(results).length.equals(2);
+      check(Account.fromPickleEncrypted(
+              pickle: results[0].pickle!, pickleKey: newPickleKey)
+          .curve25519Key
+          .toBase64()).equals(account.curve25519Key.toBase64());
+      check(results[1].error).equals(PickleFormatError.notBase64);
+    });
+  });
 }
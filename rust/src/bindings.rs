@@ -7,6 +7,36 @@ use std::ops::Deref;
 pub use std::sync::RwLock;
 pub use std::vec::Vec;
 use vodozemac::base64_encode;
+
+mod canonical_json;
+pub mod cross_signing;
+pub mod device_keys;
+pub mod forwarded_room_key;
+pub mod key_claim;
+pub mod key_maintenance;
+pub mod megolm_signing;
+pub mod migration;
+pub mod olm_sessions;
+pub mod outbound_group_session;
+pub mod passphrase_pickle;
+mod pickle;
+pub mod pickle_format;
+pub mod rekey;
+pub mod replay;
+pub mod retention;
+pub mod room_event;
+pub mod room_key;
+pub mod room_key_bundle;
+pub mod room_key_info;
+pub mod share_tracking;
+#[cfg(test)]
+mod test_utils;
+pub mod to_device;
+pub mod trust;
+pub mod unwedge;
+pub mod utd_cause;
+pub mod utd_queue;
+
 pub use vodozemac::{
     base64_decode,
     megolm::{
@@ -21,6 +51,11 @@ pub use vodozemac::{
     sas::{EstablishedSas, Mac, Sas},
     Curve25519PublicKey, Curve25519SecretKey, Ed25519PublicKey, Ed25519SecretKey, Ed25519Signature,
 };
+pub use {
+    key_maintenance::FallbackKeyState, megolm_signing::SessionKeySource,
+    olm_sessions::SessionStoreState, replay::ReplayState, share_tracking::ShareState,
+    unwedge::UnwedgeState, utd_queue::UtdQueueState,
+};
 //#[frb(mirror(IdentityKeys))]
 //pub struct _IdentityKeys {
 //    /// The ed25519 key, used for signing.
@@ -147,31 +182,36 @@ impl VodozemacGroupSession {
 
     pub fn to_inbound(&self) -> VodozemacInboundGroupSession {
         let session = self.session.read().expect("Failed to read session");
-        InboundGroupSession::from(session.deref()).into()
+        VodozemacInboundGroupSession::from_session(
+            InboundGroupSession::from(session.deref()),
+            SessionKeySource::SessionKey,
+        )
     }
 }
 
 pub struct VodozemacInboundGroupSession {
     pub session: RustOpaqueNom<RwLock<InboundGroupSession>>,
+    pub source: RustOpaqueNom<RwLock<SessionKeySource>>,
 }
 
-impl From<InboundGroupSession> for VodozemacInboundGroupSession {
-    fn from(session: InboundGroupSession) -> Self {
+pub struct DecryptResult(pub String, pub u32);
+
+impl VodozemacInboundGroupSession {
+    pub(crate) fn from_session(session: InboundGroupSession, key_source: SessionKeySource) -> Self {
         Self {
             session: RustOpaqueNom::new(RwLock::new(session)),
+            source: RustOpaqueNom::new(RwLock::new(key_source)),
         }
     }
-}
 
-pub struct DecryptResult(pub String, pub u32);
-
-impl VodozemacInboundGroupSession {
     pub fn new(session_key: String, config: VodozemacMegolmSessionConfig) -> anyhow::Result<Self> {
-        Ok(InboundGroupSession::new(
-            &vodozemac::megolm::SessionKey::from_base64(&session_key)?,
-            *config.config,
-        )
-        .into())
+        Ok(Self::from_session(
+            InboundGroupSession::new(
+                &vodozemac::megolm::SessionKey::from_base64(&session_key)?,
+                *config.config,
+            ),
+            SessionKeySource::SessionKey,
+        ))
     }
 
     pub fn session_id(&self) -> String {
@@ -214,32 +254,33 @@ impl VodozemacInboundGroupSession {
         pickle: String,
         pickle_key: [u8; 32usize],
     ) -> anyhow::Result<Self> {
-        Ok(Self {
-            session: RustOpaqueNom::new(RwLock::new(InboundGroupSession::from(
-                InboundGroupSessionPickle::from_encrypted(&pickle, &pickle_key)?,
-            ))),
-        })
+        Ok(Self::from_session(
+            InboundGroupSession::from(InboundGroupSessionPickle::from_encrypted(
+                &pickle,
+                &pickle_key,
+            )?),
+            SessionKeySource::ExportedSessionKey,
+        ))
     }
 
     pub fn from_olm_pickle_encrypted(pickle: String, pickle_key: Vec<u8>) -> anyhow::Result<Self> {
-        Ok(Self {
-            session: RustOpaqueNom::new(RwLock::new(InboundGroupSession::from_libolm_pickle(
-                &pickle,
-                &pickle_key,
-            )?)),
-        })
+        Ok(Self::from_session(
+            InboundGroupSession::from_libolm_pickle(&pickle, &pickle_key)?,
+            SessionKeySource::ExportedSessionKey,
+        ))
     }
 
     pub fn import(
         exported_session_key: String,
         config: VodozemacMegolmSessionConfig,
     ) -> anyhow::Result<Self> {
-        Ok(Self {
-            session: RustOpaqueNom::new(RwLock::new(InboundGroupSession::import(
+        Ok(Self::from_session(
+            InboundGroupSession::import(
                 &vodozemac::megolm::ExportedSessionKey::from_base64(&exported_session_key)?,
                 *config.config,
-            ))),
-        })
+            ),
+            SessionKeySource::ExportedSessionKey,
+        ))
     }
 
     pub fn export_at_first_known_index(&self) -> String {
@@ -304,6 +345,7 @@ impl From<Ed25519Signature> for VodozemacEd25519Signature {
 }
 
 impl VodozemacEd25519Signature {
+    #[allow(dead_code)]
     pub const LENGTH: usize = 64usize;
 
     pub fn from_slice(bytes: [u8; 64usize]) -> anyhow::Result<Self> {
@@ -330,6 +372,7 @@ pub struct VodozemacEd25519PublicKey {
 }
 
 impl VodozemacEd25519PublicKey {
+    #[allow(dead_code)]
     pub const LENGTH: usize = 32usize;
 
     pub fn from_slice(bytes: [u8; 32usize]) -> anyhow::Result<Self> {
@@ -338,7 +381,7 @@ impl VodozemacEd25519PublicKey {
     }
 
     pub fn as_bytes(&self) -> [u8; 32usize] {
-        self.key.as_bytes().clone()
+        *self.key.as_bytes()
     }
 
     pub fn from_base64(base64_key: String) -> anyhow::Result<Self> {
@@ -356,7 +399,7 @@ impl VodozemacEd25519PublicKey {
         message: String,
         signature: VodozemacEd25519Signature,
     ) -> anyhow::Result<()> {
-        self.key.verify(&message.as_bytes(), &signature.signature)?;
+        self.key.verify(message.as_bytes(), &signature.signature)?;
         Ok(())
     }
 }
@@ -382,6 +425,7 @@ impl From<Curve25519PublicKey> for VodozemacCurve25519PublicKey {
 }
 
 impl VodozemacCurve25519PublicKey {
+    #[allow(dead_code)]
     pub const LENGTH: usize = 32usize;
 
     pub fn from_slice(bytes: [u8; 32usize]) -> anyhow::Result<Self> {
@@ -782,12 +826,12 @@ impl From<PkMessage> for VodozemacPkMessage {
     }
 }
 
-impl Into<PkMessage> for VodozemacPkMessage {
-    fn into(self) -> PkMessage {
+impl From<VodozemacPkMessage> for PkMessage {
+    fn from(message: VodozemacPkMessage) -> Self {
         PkMessage {
-            ciphertext: self.ciphertext,
-            mac: self.mac,
-            ephemeral_key: *self.ephemeral_key.key,
+            ciphertext: message.ciphertext,
+            mac: message.mac,
+            ephemeral_key: *message.ephemeral_key.key,
         }
     }
 }
@@ -956,7 +1000,7 @@ pub fn aes_ctr(input: &[u8], key: &[u8], iv: &[u8]) -> Vec<u8> {
     let mut cipher = Aes256Ctr64BE::new(key.into(), iv.into());
     let mut buf = input.to_vec();
     cipher.apply_keystream(&mut buf);
-    return buf;
+    buf
 }
 
 /// Calculate pbkdf2 with fixes length of 256:
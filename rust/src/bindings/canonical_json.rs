@@ -0,0 +1,114 @@
+//! Helpers for Matrix canonical JSON and JSON object signing.
+//! https://spec.matrix.org/v1.16/appendices/#signing-json
+
+use anyhow::{anyhow, bail};
+use serde_json::{Map, Value};
+use vodozemac::{Ed25519PublicKey, Ed25519Signature};
+
+/// Encode a JSON value as canonical JSON: object keys sorted by codepoint, no insignificant
+/// whitespace.
+pub(crate) fn to_canonical_json(value: &Value) -> String {
+    let mut out = String::new();
+    write_canonical(value, &mut out);
+    out
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            out.push('{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(&map[key], out);
+            }
+            out.push('}');
+        }
+        other => out.push_str(&other.to_string()),
+    }
+}
+
+/// The canonical JSON of an object with its `signatures` and `unsigned` fields removed, which
+/// is what gets signed.
+pub(crate) fn signable_json(value: &Value) -> anyhow::Result<String> {
+    let mut object = value
+        .as_object()
+        .ok_or_else(|| anyhow!("Only JSON objects can be signed"))?
+        .clone();
+    object.remove("signatures");
+    object.remove("unsigned");
+    Ok(to_canonical_json(&Value::Object(object)))
+}
+
+/// Insert `signature` under `signatures.<user_id>.<key_id>`, keeping any existing signatures.
+pub(crate) fn add_signature(
+    value: &mut Value,
+    user_id: &str,
+    key_id: &str,
+    signature: String,
+) -> anyhow::Result<()> {
+    let object = value
+        .as_object_mut()
+        .ok_or_else(|| anyhow!("Only JSON objects can be signed"))?;
+    let signatures = object
+        .entry("signatures")
+        .or_insert_with(|| Value::Object(Map::new()));
+    let Some(signatures) = signatures.as_object_mut() else {
+        bail!("The signatures field is not an object");
+    };
+    let user_signatures = signatures
+        .entry(user_id)
+        .or_insert_with(|| Value::Object(Map::new()));
+    let Some(user_signatures) = user_signatures.as_object_mut() else {
+        bail!("The signatures of {user_id} are not an object");
+    };
+    user_signatures.insert(key_id.to_owned(), Value::String(signature));
+    Ok(())
+}
+
+/// The outcome of checking a single signature on a JSON object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SignatureCheck {
+    Valid,
+    /// There is no signature by the given key.
+    Missing,
+    /// There is a signature by the given key, but it is malformed or doesn't match.
+    Invalid,
+}
+
+/// Check the signature under `signatures.<user_id>.<key_id>` against `key`.
+pub(crate) fn check_signature(
+    value: &Value,
+    user_id: &str,
+    key_id: &str,
+    key: &Ed25519PublicKey,
+) -> SignatureCheck {
+    let Some(signature) = value["signatures"][user_id][key_id].as_str() else {
+        return SignatureCheck::Missing;
+    };
+    let (Ok(signature), Ok(message)) = (
+        Ed25519Signature::from_base64(signature),
+        signable_json(value),
+    ) else {
+        return SignatureCheck::Invalid;
+    };
+    match key.verify(message.as_bytes(), &signature) {
+        Ok(()) => SignatureCheck::Valid,
+        Err(_) => SignatureCheck::Invalid,
+    }
+}
@@ -0,0 +1,265 @@
+//! Cross-signing key generation and the payloads for uploading keys and signatures.
+//! https://spec.matrix.org/v1.16/client-server-api/#cross-signing
+
+use anyhow::{anyhow, bail};
+use serde_json::{json, Value};
+
+use super::canonical_json::{add_signature, signable_json};
+use super::{PkSigning, VodozemacEd25519PublicKey};
+
+/// Our own cross-signing identity: the master, self-signing and user-signing keys.
+pub struct CrossSigningIdentity {
+    user_id: String,
+    master_key: PkSigning,
+    self_signing_key: PkSigning,
+    user_signing_key: PkSigning,
+}
+
+impl CrossSigningIdentity {
+    /// Generate a fresh set of cross-signing keys for `user_id`.
+    pub fn new(user_id: String) -> Self {
+        Self {
+            user_id,
+            master_key: PkSigning::new(),
+            self_signing_key: PkSigning::new(),
+            user_signing_key: PkSigning::new(),
+        }
+    }
+
+    /// Import existing keys from their base64 encoded secrets, as used by
+    /// `PkSigning::from_secret_key`.
+    pub fn from_secret_keys(
+        user_id: String,
+        master_key: &str,
+        self_signing_key: &str,
+        user_signing_key: &str,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            user_id,
+            master_key: PkSigning::from_secret_key(master_key)?,
+            self_signing_key: PkSigning::from_secret_key(self_signing_key)?,
+            user_signing_key: PkSigning::from_secret_key(user_signing_key)?,
+        })
+    }
+
+    pub fn user_id(&self) -> String {
+        self.user_id.clone()
+    }
+
+    pub fn master_key(&self) -> VodozemacEd25519PublicKey {
+        self.master_key.public_key()
+    }
+
+    pub fn self_signing_key(&self) -> VodozemacEd25519PublicKey {
+        self.self_signing_key.public_key()
+    }
+
+    pub fn user_signing_key(&self) -> VodozemacEd25519PublicKey {
+        self.user_signing_key.public_key()
+    }
+
+    pub fn master_secret_key(&self) -> String {
+        self.master_key.secret_key()
+    }
+
+    pub fn self_signing_secret_key(&self) -> String {
+        self.self_signing_key.secret_key()
+    }
+
+    pub fn user_signing_secret_key(&self) -> String {
+        self.user_signing_key.secret_key()
+    }
+
+    /// The request body for `/keys/device_signing/upload`, with the self-signing and
+    /// user-signing keys signed by the master key.
+    ///
+    /// The body does not contain the `auth` field, which the caller has to add for
+    /// user-interactive authentication.
+    pub fn device_signing_upload(&self) -> anyhow::Result<String> {
+        let master_key = self.key_object("master", &self.master_key);
+        let mut self_signing_key = self.key_object("self_signing", &self.self_signing_key);
+        let mut user_signing_key = self.key_object("user_signing", &self.user_signing_key);
+        sign_value(&self.master_key, &self.user_id, &mut self_signing_key)?;
+        sign_value(&self.master_key, &self.user_id, &mut user_signing_key)?;
+
+        Ok(json!({
+            "master_key": master_key,
+            "self_signing_key": self_signing_key,
+            "user_signing_key": user_signing_key,
+        })
+        .to_string())
+    }
+
+    /// Sign one of our own devices with the self-signing key.
+    ///
+    /// Takes the device keys as returned by `/keys/query` and returns the request body for
+    /// `/keys/signatures/upload`.
+    pub fn sign_device(&self, device_keys: String) -> anyhow::Result<String> {
+        let mut device_keys: Value = serde_json::from_str(&device_keys)?;
+        let user_id = string_field(&device_keys, "user_id")?;
+        if user_id != self.user_id {
+            bail!(
+                "Can't sign a device of {user_id} with the self-signing key of {}",
+                self.user_id
+            );
+        }
+        let device_id = string_field(&device_keys, "device_id")?;
+
+        sign_value(&self.self_signing_key, &self.user_id, &mut device_keys)?;
+        Ok(json!({ user_id: { device_id: device_keys } }).to_string())
+    }
+
+    /// Sign the master key of another user with the user-signing key.
+    ///
+    /// Takes the master key as returned by `/keys/query` and returns the request body for
+    /// `/keys/signatures/upload`.
+    pub fn sign_user(&self, master_key: String) -> anyhow::Result<String> {
+        let mut master_key: Value = serde_json::from_str(&master_key)?;
+        let user_id = string_field(&master_key, "user_id")?;
+        if user_id == self.user_id {
+            bail!("Our own master key is not signed with the user-signing key");
+        }
+        let has_master_usage = master_key["usage"]
+            .as_array()
+            .is_some_and(|usage| usage.iter().any(|u| u == "master"));
+        if !has_master_usage {
+            bail!("The key of {user_id} is not a master key");
+        }
+        let public_key = match master_key["keys"].as_object() {
+            Some(keys) if keys.len() == 1 => keys
+                .values()
+                .next()
+                .and_then(Value::as_str)
+                .ok_or_else(|| anyhow!("The master key of {user_id} is not a string"))?
+                .to_owned(),
+            _ => bail!("The master key of {user_id} must contain exactly one key"),
+        };
+
+        sign_value(&self.user_signing_key, &self.user_id, &mut master_key)?;
+        Ok(json!({ user_id: { public_key: master_key } }).to_string())
+    }
+
+    fn key_object(&self, usage: &str, key: &PkSigning) -> Value {
+        let public_key = key.public_key.to_base64();
+        json!({
+            "user_id": self.user_id,
+            "usage": [usage],
+            "keys": { format!("ed25519:{public_key}"): public_key },
+        })
+    }
+}
+
+/// Sign a JSON object with `key` and add the signature as `signatures.<user_id>.ed25519:<key>`.
+fn sign_value(key: &PkSigning, user_id: &str, value: &mut Value) -> anyhow::Result<()> {
+    let signature = key.sign(&signable_json(value)?).to_base64();
+    let key_id = format!("ed25519:{}", key.public_key.to_base64());
+    add_signature(value, user_id, &key_id, signature)
+}
+
+fn string_field(value: &Value, field: &str) -> anyhow::Result<String> {
+    value[field]
+        .as_str()
+        .map(str::to_owned)
+        .ok_or_else(|| anyhow!("Missing or invalid {field} field"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verify(value: &Value, user_id: &str, key: &VodozemacEd25519PublicKey) {
+        let key_id = format!("ed25519:{}", key.to_base64());
+        let signature = value["signatures"][user_id][&key_id].as_str().unwrap();
+        key.key
+            .verify(
+                signable_json(value).unwrap().as_bytes(),
+                &vodozemac::Ed25519Signature::from_base64(signature).unwrap(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_device_signing_upload_is_signed_by_master_key() {
+        let identity = CrossSigningIdentity::new("@alice:example.org".to_owned());
+        let upload: Value =
+            serde_json::from_str(&identity.device_signing_upload().unwrap()).unwrap();
+
+        let master = identity.master_key().to_base64();
+        assert_eq!(upload["master_key"]["usage"], json!(["master"]));
+        assert_eq!(
+            upload["master_key"]["keys"][format!("ed25519:{master}")],
+            json!(master)
+        );
+        verify(
+            &upload["self_signing_key"],
+            "@alice:example.org",
+            &identity.master_key(),
+        );
+        verify(
+            &upload["user_signing_key"],
+            "@alice:example.org",
+            &identity.master_key(),
+        );
+    }
+
+    #[test]
+    fn test_import_from_secret_keys() {
+        let identity = CrossSigningIdentity::new("@alice:example.org".to_owned());
+        let imported = CrossSigningIdentity::from_secret_keys(
+            "@alice:example.org".to_owned(),
+            &identity.master_secret_key(),
+            &identity.self_signing_secret_key(),
+            &identity.user_signing_secret_key(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            imported.master_key().to_base64(),
+            identity.master_key().to_base64()
+        );
+        assert_eq!(
+            imported.self_signing_key().to_base64(),
+            identity.self_signing_key().to_base64()
+        );
+        assert_eq!(
+            imported.user_signing_key().to_base64(),
+            identity.user_signing_key().to_base64()
+        );
+    }
+
+    #[test]
+    fn test_sign_device_and_user() {
+        let alice = CrossSigningIdentity::new("@alice:example.org".to_owned());
+        let bob = CrossSigningIdentity::new("@bob:example.org".to_owned());
+
+        let device_keys = json!({
+            "user_id": "@alice:example.org",
+            "device_id": "ALICEDEVICE",
+            "algorithms": ["m.olm.v1.curve25519-aes-sha2"],
+            "keys": {},
+            "signatures": { "@alice:example.org": { "ed25519:ALICEDEVICE": "self" } },
+            "unsigned": { "device_display_name": "Phone" },
+        });
+        let upload: Value =
+            serde_json::from_str(&alice.sign_device(device_keys.to_string()).unwrap()).unwrap();
+        let signed = &upload["@alice:example.org"]["ALICEDEVICE"];
+        verify(signed, "@alice:example.org", &alice.self_signing_key());
+        assert_eq!(
+            signed["signatures"]["@alice:example.org"]["ed25519:ALICEDEVICE"],
+            json!("self")
+        );
+
+        let bob_master: Value = serde_json::from_str(&bob.device_signing_upload().unwrap())
+            .map(|v: Value| v["master_key"].clone())
+            .unwrap();
+        let upload: Value =
+            serde_json::from_str(&alice.sign_user(bob_master.to_string()).unwrap()).unwrap();
+        let signed = &upload["@bob:example.org"][bob.master_key().to_base64()];
+        verify(signed, "@alice:example.org", &alice.user_signing_key());
+
+        assert!(alice
+            .sign_user(bob_master.to_string().replace("bob", "alice"))
+            .is_err());
+        assert!(bob.sign_device(device_keys.to_string()).is_err());
+    }
+}
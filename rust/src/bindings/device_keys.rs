@@ -0,0 +1,201 @@
+//! Validation of the device keys returned by `/keys/query`.
+//! https://spec.matrix.org/v1.16/client-server-api/#post_matrixclientv3keysquery
+
+use serde_json::Value;
+use vodozemac::{Curve25519PublicKey, Ed25519PublicKey};
+
+use super::canonical_json::{check_signature, SignatureCheck};
+use super::{VodozemacCurve25519PublicKey, VodozemacEd25519PublicKey};
+
+pub(crate) const OLM_V1_ALGORITHM: &str = "m.olm.v1.curve25519-aes-sha2";
+
+/// Why a device keys object was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum DeviceKeysError {
+    #[error("The device keys are not a valid JSON object")]
+    MalformedJson,
+    #[error("The user_id of the device keys doesn't match the expected user")]
+    UserIdMismatch,
+    #[error("The device_id of the device keys doesn't match the expected device")]
+    DeviceIdMismatch,
+    #[error("The device doesn't support the Olm algorithm")]
+    UnsupportedAlgorithms,
+    #[error("The device keys don't contain a valid ed25519 key")]
+    InvalidEd25519Key,
+    #[error("The device keys don't contain a valid curve25519 key")]
+    InvalidCurve25519Key,
+    #[error("The device keys are not signed by their own ed25519 key")]
+    MissingSignature,
+    #[error("The signature of the device keys is invalid")]
+    InvalidSignature,
+}
+
+/// Device keys whose self-signature was checked.
+pub struct DeviceKeys {
+    pub user_id: String,
+    pub device_id: String,
+    pub algorithms: Vec<String>,
+    pub ed25519: VodozemacEd25519PublicKey,
+    pub curve25519: VodozemacCurve25519PublicKey,
+    pub display_name: Option<String>,
+}
+
+impl DeviceKeys {
+    /// Parse a single device keys object from a `/keys/query` response and check it against the
+    /// user and device id it was listed under.
+    pub fn parse_and_verify(
+        json: String,
+        expected_user: String,
+        expected_device: String,
+    ) -> Result<Self, DeviceKeysError> {
+        let value: Value =
+            serde_json::from_str(&json).map_err(|_| DeviceKeysError::MalformedJson)?;
+        Self::verify_value(&value, &expected_user, &expected_device)
+    }
+
+    pub(crate) fn verify_value(
+        value: &Value,
+        expected_user: &str,
+        expected_device: &str,
+    ) -> Result<Self, DeviceKeysError> {
+        let ed25519 = verify_self_signature(value, expected_user, expected_device)?;
+
+        let algorithms: Vec<String> = value["algorithms"]
+            .as_array()
+            .map(|a| {
+                a.iter()
+                    .filter_map(|a| a.as_str().map(str::to_owned))
+                    .collect()
+            })
+            .unwrap_or_default();
+        if !algorithms.iter().any(|a| a == OLM_V1_ALGORITHM) {
+            return Err(DeviceKeysError::UnsupportedAlgorithms);
+        }
+        let curve25519 = value["keys"][format!("curve25519:{expected_device}")]
+            .as_str()
+            .and_then(|k| Curve25519PublicKey::from_base64(k).ok())
+            .ok_or(DeviceKeysError::InvalidCurve25519Key)?;
+
+        Ok(Self {
+            user_id: expected_user.to_owned(),
+            device_id: expected_device.to_owned(),
+            algorithms,
+            ed25519: ed25519.into(),
+            curve25519: curve25519.into(),
+            display_name: value["unsigned"]["device_display_name"]
+                .as_str()
+                .map(str::to_owned),
+        })
+    }
+}
+
+/// Check that a device keys object belongs to the expected device and is signed by its own
+/// Ed25519 key, which is returned.
+pub(crate) fn verify_self_signature(
+    value: &Value,
+    expected_user: &str,
+    expected_device: &str,
+) -> Result<Ed25519PublicKey, DeviceKeysError> {
+    if !value.is_object() {
+        return Err(DeviceKeysError::MalformedJson);
+    }
+    if value["user_id"] != expected_user {
+        return Err(DeviceKeysError::UserIdMismatch);
+    }
+    if value["device_id"] != expected_device {
+        return Err(DeviceKeysError::DeviceIdMismatch);
+    }
+
+    let key_id = format!("ed25519:{expected_device}");
+    let ed25519 = value["keys"][&key_id]
+        .as_str()
+        .and_then(|k| Ed25519PublicKey::from_base64(k).ok())
+        .ok_or(DeviceKeysError::InvalidEd25519Key)?;
+    match check_signature(value, expected_user, &key_id, &ed25519) {
+        SignatureCheck::Valid => Ok(ed25519),
+        SignatureCheck::Missing => Err(DeviceKeysError::MissingSignature),
+        SignatureCheck::Invalid => Err(DeviceKeysError::InvalidSignature),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::canonical_json::{add_signature, signable_json};
+    use super::super::test_utils::signed_device_keys;
+    use super::*;
+    use serde_json::json;
+    use vodozemac::olm::Account;
+
+    fn alice_device_keys(account: &Account) -> Value {
+        let mut keys = signed_device_keys(account, "@alice:example.org", "ALICE");
+        // Not covered by the signature.
+        keys["unsigned"] = json!({ "device_display_name": "Alice's phone" });
+        keys
+    }
+
+    fn parse(keys: &Value, user: &str, device: &str) -> Result<DeviceKeys, DeviceKeysError> {
+        DeviceKeys::parse_and_verify(keys.to_string(), user.to_owned(), device.to_owned())
+    }
+
+    #[test]
+    fn test_valid_device_keys() {
+        let account = Account::new();
+        let device = parse(&alice_device_keys(&account), "@alice:example.org", "ALICE").unwrap();
+
+        assert_eq!(
+            device.ed25519.to_base64(),
+            account.ed25519_key().to_base64()
+        );
+        assert_eq!(
+            device.curve25519.to_base64(),
+            account.curve25519_key().to_base64()
+        );
+        assert_eq!(device.display_name.as_deref(), Some("Alice's phone"));
+    }
+
+    #[test]
+    fn test_rejection_reasons() {
+        let account = Account::new();
+        let keys = alice_device_keys(&account);
+
+        assert_eq!(
+            parse(&keys, "@mallory:example.org", "ALICE").err(),
+            Some(DeviceKeysError::UserIdMismatch)
+        );
+        assert_eq!(
+            parse(&keys, "@alice:example.org", "OTHER").err(),
+            Some(DeviceKeysError::DeviceIdMismatch)
+        );
+
+        let mut tampered = keys.clone();
+        tampered["keys"]["curve25519:ALICE"] = json!(Account::new().curve25519_key().to_base64());
+        assert_eq!(
+            parse(&tampered, "@alice:example.org", "ALICE").err(),
+            Some(DeviceKeysError::InvalidSignature)
+        );
+
+        let mut unsigned = keys.clone();
+        unsigned["signatures"] = json!({});
+        assert_eq!(
+            parse(&unsigned, "@alice:example.org", "ALICE").err(),
+            Some(DeviceKeysError::MissingSignature)
+        );
+
+        // The signature covers the algorithms, so the device has to sign them itself.
+        let mut no_olm = keys;
+        no_olm["algorithms"] = json!(["m.megolm.v1.aes-sha2"]);
+        no_olm.as_object_mut().unwrap().remove("signatures");
+        let signature = account.sign(signable_json(&no_olm).unwrap()).to_base64();
+        add_signature(
+            &mut no_olm,
+            "@alice:example.org",
+            "ed25519:ALICE",
+            signature,
+        )
+        .unwrap();
+        assert_eq!(
+            parse(&no_olm, "@alice:example.org", "ALICE").err(),
+            Some(DeviceKeysError::UnsupportedAlgorithms)
+        );
+    }
+}
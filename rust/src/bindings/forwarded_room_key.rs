@@ -0,0 +1,192 @@
+//! Forwarding Megolm sessions with `m.forwarded_room_key` to-device events.
+//! https://spec.matrix.org/v1.16/client-server-api/#mforwarded_room_key
+
+use serde_json::{json, Value};
+use vodozemac::megolm::{ExportedSessionKey, InboundGroupSession};
+
+use super::device_keys::DeviceKeys;
+use super::megolm_signing::SessionKeySource;
+use super::room_key::{checked_content, megolm_config, RoomKeyError};
+use super::room_key_info::{InboundRoomKey, RoomKeyInfo, RoomKeyOrigin};
+use super::to_device::OlmPayload;
+use super::VodozemacCurve25519PublicKey;
+
+pub(crate) const FORWARDED_ROOM_KEY_EVENT_TYPE: &str = "m.forwarded_room_key";
+
+/// Build the `m.forwarded_room_key` content to forward `room_key`.
+///
+/// The session is exported at `message_index`, or at its first known index if that is
+/// `None`. Returns `None` if the session doesn't know the requested index anymore.
+pub fn forwarded_room_key_content(
+    room_key: &InboundRoomKey,
+    message_index: Option<u32>,
+) -> Option<String> {
+    let info = room_key.info();
+    let mut session = room_key.session.write().expect("Failed to write session");
+    let session_key = match message_index {
+        Some(index) => session.export_at(index)?,
+        None => session.export_at_first_known_index(),
+    };
+
+    Some(
+        json!({
+            "algorithm": info.algorithm,
+            "room_id": info.room_id,
+            "sender_key": info.sender_key,
+            "session_id": session.session_id(),
+            "session_key": session_key.to_base64(),
+            "sender_claimed_ed25519_key": info.sender_claimed_ed25519,
+            "forwarding_curve25519_key_chain": info.forwarding_curve25519_key_chain,
+            "shared_history": info.shared_history,
+        })
+        .to_string(),
+    )
+}
+
+/// Import the session of a decrypted `m.forwarded_room_key` to-device event.
+///
+/// `forwarder_key` is the Curve25519 key of the Olm session that decrypted `payload` and
+/// `forwarder_device` the device that sent it. The forwarder is appended to the forwarding
+/// chain of the returned info. The session is never authenticated, the forwarder could have
+/// created it itself.
+pub fn receive_forwarded_room_key(
+    payload: &OlmPayload,
+    forwarder_key: &VodozemacCurve25519PublicKey,
+    forwarder_device: &DeviceKeys,
+) -> Result<InboundRoomKey, RoomKeyError> {
+    let content = checked_content(
+        payload,
+        FORWARDED_ROOM_KEY_EVENT_TYPE,
+        forwarder_key,
+        forwarder_device,
+    )?;
+    let (
+        Some(algorithm),
+        Some(room_id),
+        Some(sender_key),
+        Some(session_id),
+        Some(session_key),
+        Some(sender_claimed_ed25519),
+    ) = (
+        content["algorithm"].as_str(),
+        content["room_id"].as_str(),
+        content["sender_key"].as_str(),
+        content["session_id"].as_str(),
+        content["session_key"].as_str(),
+        content["sender_claimed_ed25519_key"].as_str(),
+    )
+    else {
+        return Err(RoomKeyError::MalformedContent);
+    };
+    let mut forwarding_curve25519_key_chain = match &content["forwarding_curve25519_key_chain"] {
+        Value::Null => vec![],
+        Value::Array(chain) => chain
+            .iter()
+            .map(|k| k.as_str().map(str::to_owned))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(RoomKeyError::MalformedContent)?,
+        _ => return Err(RoomKeyError::MalformedContent),
+    };
+    forwarding_curve25519_key_chain.push(forwarder_key.to_base64());
+
+    let config = megolm_config(algorithm).ok_or(RoomKeyError::UnknownAlgorithm)?;
+    let session_key = ExportedSessionKey::from_base64(session_key)
+        .map_err(|_| RoomKeyError::InvalidSessionKey)?;
+    let session = InboundGroupSession::import(&session_key, config);
+    if session.session_id() != session_id {
+        return Err(RoomKeyError::SessionIdMismatch);
+    }
+
+    Ok(InboundRoomKey::from_session(
+        session,
+        SessionKeySource::ExportedSessionKey,
+        RoomKeyInfo {
+            algorithm: algorithm.to_owned(),
+            room_id: room_id.to_owned(),
+            sender_key: sender_key.to_owned(),
+            sender_claimed_ed25519: sender_claimed_ed25519.to_owned(),
+            forwarding_curve25519_key_chain,
+            origin: RoomKeyOrigin::Forwarded,
+            shared_history: content["shared_history"].as_bool().unwrap_or_default(),
+            backed_up: false,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{device_keys, room_key_info};
+    use super::super::VodozemacGroupSession;
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+    use vodozemac::olm::Account;
+
+    #[test]
+    fn test_forwarding_extends_the_chain() {
+        let alice = Account::new();
+        let bob = Account::new();
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        outbound.encrypt("zero".to_owned());
+        let info = RoomKeyInfo {
+            sender_key: alice.curve25519_key().to_base64(),
+            sender_claimed_ed25519: alice.ed25519_key().to_base64(),
+            forwarding_curve25519_key_chain: vec!["earlier".to_owned()],
+            shared_history: true,
+            backed_up: true,
+            ..room_key_info("!room:example.org", RoomKeyOrigin::Direct)
+        };
+        let room_key = InboundRoomKey::new(&outbound.to_inbound(), info.clone());
+
+        assert!(forwarded_room_key_content(&room_key, Some(0)).is_none());
+        let content = forwarded_room_key_content(&room_key, None).unwrap();
+        let payload = OlmPayload {
+            event_type: FORWARDED_ROOM_KEY_EVENT_TYPE.to_owned(),
+            content,
+            sender: "@bob:example.org".to_owned(),
+            sender_device: Some("BOB".to_owned()),
+            sender_claimed_ed25519: bob.ed25519_key().into(),
+        };
+
+        let received = receive_forwarded_room_key(
+            &payload,
+            &bob.curve25519_key().into(),
+            &device_keys(&bob, "@bob:example.org", "BOB"),
+        )
+        .unwrap();
+        assert_eq!(received.session_id(), outbound.session_id());
+        assert_eq!(received.first_known_index(), 1);
+        assert_eq!(
+            received.info(),
+            RoomKeyInfo {
+                forwarding_curve25519_key_chain: vec![
+                    "earlier".to_owned(),
+                    bob.curve25519_key().to_base64()
+                ],
+                origin: RoomKeyOrigin::Forwarded,
+                backed_up: false,
+                ..info
+            }
+        );
+
+        let ciphertext = outbound.encrypt("one".to_owned());
+        assert_eq!(
+            received
+                .inbound_group_session()
+                .decrypt(ciphertext)
+                .unwrap()
+                .0,
+            "one"
+        );
+
+        // The forwarder's keys have to match the Olm session like for m.room_key.
+        assert_eq!(
+            receive_forwarded_room_key(
+                &payload,
+                &alice.curve25519_key().into(),
+                &device_keys(&bob, "@bob:example.org", "BOB")
+            )
+            .err(),
+            Some(RoomKeyError::SenderKeyMismatch)
+        );
+    }
+}
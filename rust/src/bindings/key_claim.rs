@@ -0,0 +1,200 @@
+//! Verification of one-time keys returned by `/keys/claim`.
+//! https://spec.matrix.org/v1.16/client-server-api/#post_matrixclientv3keysclaim
+
+use serde_json::Value;
+use vodozemac::Curve25519PublicKey;
+
+use super::canonical_json::{check_signature, SignatureCheck};
+use super::device_keys::DeviceKeys;
+use super::{VodozemacAccount, VodozemacOlmSessionConfig, VodozemacSession};
+
+const SIGNED_CURVE25519: &str = "signed_curve25519";
+
+/// Why a claimed one-time key was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum KeyClaimError {
+    #[error("The claimed key is not a JSON object with a single key")]
+    MalformedJson,
+    #[error("The claimed key is not a signed_curve25519 key")]
+    UnsupportedAlgorithm,
+    #[error("The claimed key is not a valid curve25519 key")]
+    InvalidKey,
+    #[error("The claimed key is not signed by the device")]
+    MissingSignature,
+    #[error("The signature of the claimed key is invalid")]
+    InvalidSignature,
+}
+
+/// A session created from a claimed key.
+pub struct ClaimedSession {
+    pub session: VodozemacSession,
+    /// Whether the claimed key was the device's fallback key rather than a one-time key. The
+    /// device has run out of one-time keys and will reuse the key for other senders.
+    pub fallback: bool,
+}
+
+/// A verified key returned by `/keys/claim`.
+pub(crate) struct ClaimedKey {
+    pub(crate) key: Curve25519PublicKey,
+    pub(crate) fallback: bool,
+}
+
+impl VodozemacAccount {
+    /// Create an outbound session from a one-time or fallback key returned by `/keys/claim`.
+    ///
+    /// `claimed_key_json` is the object returned for the device, mapping the key id to the
+    /// signed key, e.g. `{"signed_curve25519:AAAAHg": {"key": "...", "signatures": {...}}}`.
+    /// The signature is checked against the device's Ed25519 key before the key is used.
+    pub fn create_outbound_session_from_claim(
+        &self,
+        config: VodozemacOlmSessionConfig,
+        device_keys: &DeviceKeys,
+        claimed_key_json: String,
+    ) -> Result<ClaimedSession, KeyClaimError> {
+        let claimed = verify_claimed_key(device_keys, &claimed_key_json)?;
+        let session = self
+            .account
+            .read()
+            .expect("Failed to read account")
+            .create_outbound_session(*config.config, *device_keys.curve25519.key, claimed.key)
+            .into();
+        Ok(ClaimedSession {
+            session,
+            fallback: claimed.fallback,
+        })
+    }
+}
+
+/// Check the signature of a claimed key and return the Curve25519 key it contains.
+pub(crate) fn verify_claimed_key(
+    device_keys: &DeviceKeys,
+    claimed_key_json: &str,
+) -> Result<ClaimedKey, KeyClaimError> {
+    let value: Value =
+        serde_json::from_str(claimed_key_json).map_err(|_| KeyClaimError::MalformedJson)?;
+    let (key_id, signed_key) = match value.as_object() {
+        Some(map) if map.len() == 1 => map.iter().next().ok_or(KeyClaimError::MalformedJson)?,
+        _ => return Err(KeyClaimError::MalformedJson),
+    };
+    if key_id.split_once(':').map(|(algorithm, _)| algorithm) != Some(SIGNED_CURVE25519) {
+        return Err(KeyClaimError::UnsupportedAlgorithm);
+    }
+
+    // The `fallback` flag of fallback keys is covered by the signature like the key itself.
+    let one_time_key = signed_key["key"]
+        .as_str()
+        .and_then(|k| Curve25519PublicKey::from_base64(k).ok())
+        .ok_or(KeyClaimError::InvalidKey)?;
+    let signing_key_id = format!("ed25519:{}", device_keys.device_id);
+    match check_signature(
+        signed_key,
+        &device_keys.user_id,
+        &signing_key_id,
+        &device_keys.ed25519.key,
+    ) {
+        SignatureCheck::Valid => Ok(ClaimedKey {
+            key: one_time_key,
+            fallback: signed_key["fallback"] == true,
+        }),
+        SignatureCheck::Missing => Err(KeyClaimError::MissingSignature),
+        SignatureCheck::Invalid => Err(KeyClaimError::InvalidSignature),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::canonical_json::{add_signature, signable_json};
+    use super::super::test_utils::device_keys;
+    use super::*;
+    use serde_json::json;
+    use vodozemac::olm::{Account, OlmMessage, SessionConfig};
+
+    fn claimed_key(account: &Account, key_id: &str, mut key: Value) -> String {
+        let signature = account.sign(signable_json(&key).unwrap()).to_base64();
+        add_signature(&mut key, "@bob:example.org", "ed25519:BOB", signature).unwrap();
+        json!({ key_id: key }).to_string()
+    }
+
+    #[test]
+    fn test_session_from_one_time_and_fallback_key() {
+        let alice = VodozemacAccount::new();
+        let mut bob = Account::new();
+        bob.generate_one_time_keys(1);
+        bob.generate_fallback_key();
+        let (otk_id, otk) = bob.one_time_keys().into_iter().next().unwrap();
+        let (fallback_id, fallback) = bob.fallback_key().into_iter().next().unwrap();
+
+        for (claimed, is_fallback) in [
+            (
+                claimed_key(
+                    &bob,
+                    &format!("signed_curve25519:{}", otk_id.to_base64()),
+                    json!({ "key": otk.to_base64() }),
+                ),
+                false,
+            ),
+            (
+                claimed_key(
+                    &bob,
+                    &format!("signed_curve25519:{}", fallback_id.to_base64()),
+                    json!({ "key": fallback.to_base64(), "fallback": true }),
+                ),
+                true,
+            ),
+        ] {
+            let claimed = alice
+                .create_outbound_session_from_claim(
+                    SessionConfig::version_1().into(),
+                    &device_keys(&bob, "@bob:example.org", "BOB"),
+                    claimed,
+                )
+                .unwrap();
+            assert_eq!(claimed.fallback, is_fallback);
+            let message = claimed
+                .session
+                .encrypt("It's a secret to everybody".to_owned());
+            let OlmMessage::PreKey(message) = &*message.msg else {
+                panic!("Expected a pre-key message");
+            };
+            let result = bob
+                .create_inbound_session(*alice.curve25519_key().key, message)
+                .unwrap();
+            assert_eq!(result.plaintext, b"It's a secret to everybody");
+        }
+    }
+
+    #[test]
+    fn test_rejects_unsigned_and_forged_keys() {
+        let alice = VodozemacAccount::new();
+        let bob = Account::new();
+        let mallory = Account::new();
+        let key = json!({ "key": Account::new().curve25519_key().to_base64() });
+
+        let claim = |claimed: String| {
+            alice
+                .create_outbound_session_from_claim(
+                    SessionConfig::version_1().into(),
+                    &device_keys(&bob, "@bob:example.org", "BOB"),
+                    claimed,
+                )
+                .err()
+        };
+
+        assert_eq!(
+            claim(claimed_key(
+                &mallory,
+                "signed_curve25519:AAAAAQ",
+                key.clone()
+            )),
+            Some(KeyClaimError::InvalidSignature)
+        );
+        assert_eq!(
+            claim(json!({ "signed_curve25519:AAAAAQ": key }).to_string()),
+            Some(KeyClaimError::MissingSignature)
+        );
+        assert_eq!(
+            claim(json!({ "curve25519:AAAAAQ": "key" }).to_string()),
+            Some(KeyClaimError::UnsupportedAlgorithm)
+        );
+    }
+}
@@ -0,0 +1,234 @@
+//! Keeping the one-time and fallback keys on the server topped up.
+//! https://spec.matrix.org/v1.16/client-server-api/#one-time-and-fallback-keys
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::pickle::{decrypt_pickle, encrypt_pickle};
+use super::{RustOpaqueNom, RwLock, VodozemacAccount};
+
+const SIGNED_CURVE25519: &str = "signed_curve25519";
+/// How long the previous fallback key is kept after the rotated one reached the server.
+const DEFAULT_GRACE_PERIOD_MS: u64 = 60 * 60 * 1000;
+
+/// The work done by `VodozemacAccount::plan_key_maintenance`.
+///
+/// If any keys were generated, they have to be uploaded and then marked as published.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyMaintenancePlan {
+    pub generate_one_time_keys: usize,
+    pub rotate_fallback_key: bool,
+    pub forget_old_fallback_key: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FallbackKeyState {
+    grace_period_ms: u64,
+    /// When the server first reported the rotated fallback key as unused, while the previous
+    /// one is still kept.
+    published_at_ms: Option<u64>,
+}
+
+/// When the current fallback key was published, to be pickled next to the account.
+///
+/// Senders may have claimed the previous fallback key shortly before it was rotated, so it
+/// is kept for a grace period to decrypt their pre-key messages.
+pub struct FallbackKeyTracker {
+    pub state: RustOpaqueNom<RwLock<FallbackKeyState>>,
+}
+
+impl Default for FallbackKeyTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallbackKeyTracker {
+    pub fn new() -> Self {
+        FallbackKeyState {
+            grace_period_ms: DEFAULT_GRACE_PERIOD_MS,
+            published_at_ms: None,
+        }
+        .into()
+    }
+
+    pub fn grace_period_ms(&self) -> u64 {
+        self.state
+            .read()
+            .expect("Failed to read state")
+            .grace_period_ms
+    }
+
+    pub fn set_grace_period_ms(&self, grace_period_ms: u64) {
+        self.state
+            .write()
+            .expect("Failed to write state")
+            .grace_period_ms = grace_period_ms;
+    }
+
+    pub fn pickle_encrypted(&self, pickle_key: [u8; 32usize]) -> String {
+        encrypt_pickle(
+            &*self.state.read().expect("Failed to read state"),
+            &pickle_key,
+        )
+    }
+
+    pub fn from_pickle_encrypted(
+        pickle: String,
+        pickle_key: [u8; 32usize],
+    ) -> anyhow::Result<Self> {
+        Ok(decrypt_pickle::<FallbackKeyState>(&pickle, &pickle_key)?.into())
+    }
+}
+
+impl From<FallbackKeyState> for FallbackKeyTracker {
+    fn from(state: FallbackKeyState) -> Self {
+        Self {
+            state: RustOpaqueNom::new(RwLock::new(state)),
+        }
+    }
+}
+
+impl VodozemacAccount {
+    /// Decide which keys need to be generated after a sync and apply that to the account.
+    ///
+    /// `server_counts` is the `device_one_time_keys_count` of the sync response and
+    /// `unused_fallback_types` its `device_unused_fallback_key_types`, which is `None` if the
+    /// server doesn't support fallback keys.
+    ///
+    /// Following the spec, we keep half of the one-time keys the account can hold on the
+    /// server, counting the keys that were generated but not published yet. The fallback key is
+    /// rotated once the server reports it as used. The previous one is forgotten once the
+    /// rotated key has been on the server for the grace period of `tracker`, counted from the
+    /// first sync reporting it as unused.
+    pub fn plan_key_maintenance(
+        &self,
+        tracker: &FallbackKeyTracker,
+        server_counts: HashMap<String, u32>,
+        unused_fallback_types: Option<Vec<String>>,
+        now_ms: u64,
+    ) -> KeyMaintenancePlan {
+        let mut account = self.account.write().expect("Failed to write account");
+        let mut state = tracker.state.write().expect("Failed to write state");
+
+        let target = account.max_number_of_one_time_keys() / 2;
+        let uploaded = server_counts
+            .get(SIGNED_CURVE25519)
+            .copied()
+            .unwrap_or_default() as usize;
+        let unpublished = account.one_time_keys().len();
+        let generate_one_time_keys = target.saturating_sub(uploaded + unpublished);
+
+        let mut rotate_fallback_key = false;
+        let mut forget_old_fallback_key = false;
+        if let Some(unused_fallback_types) = unused_fallback_types {
+            // A fallback key that wasn't uploaded yet is not on the server, so it can't be
+            // reported as unused. Rotating again would throw away the key in use.
+            let has_unpublished_fallback_key = !account.fallback_key().is_empty();
+            let fallback_key_unused = unused_fallback_types.iter().any(|t| t == SIGNED_CURVE25519);
+            if !has_unpublished_fallback_key {
+                if fallback_key_unused {
+                    let published_at_ms = *state.published_at_ms.get_or_insert(now_ms);
+                    if now_ms.saturating_sub(published_at_ms) >= state.grace_period_ms {
+                        forget_old_fallback_key = account.forget_fallback_key();
+                        state.published_at_ms = None;
+                    }
+                } else {
+                    account.generate_fallback_key();
+                    rotate_fallback_key = true;
+                    state.published_at_ms = None;
+                }
+            }
+        }
+
+        if generate_one_time_keys > 0 {
+            account.generate_one_time_keys(generate_one_time_keys);
+        }
+
+        KeyMaintenancePlan {
+            generate_one_time_keys,
+            rotate_fallback_key,
+            forget_old_fallback_key,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(count: u32) -> HashMap<String, u32> {
+        HashMap::from([(SIGNED_CURVE25519.to_owned(), count)])
+    }
+
+    #[test]
+    fn test_one_time_keys_are_topped_up_to_half_of_max() {
+        let account = VodozemacAccount::new();
+        let tracker = FallbackKeyTracker::new();
+        let half = account.max_number_of_one_time_keys() / 2;
+        let plan = |counts| account.plan_key_maintenance(&tracker, counts, None, 0);
+
+        let plan_result = plan(HashMap::new());
+        assert_eq!(plan_result.generate_one_time_keys, half);
+        assert!(!plan_result.rotate_fallback_key);
+        assert_eq!(account.one_time_keys().len(), half);
+
+        // Nothing more to do until the generated keys are published.
+        assert_eq!(plan(HashMap::new()).generate_one_time_keys, 0);
+
+        account.mark_keys_as_published();
+        assert_eq!(plan(counts(half as u32 - 3)).generate_one_time_keys, 3);
+
+        account.mark_keys_as_published();
+        assert_eq!(plan(counts(half as u32 + 5)).generate_one_time_keys, 0);
+    }
+
+    #[test]
+    fn test_fallback_key_rotation() {
+        let account = VodozemacAccount::new();
+        let tracker = FallbackKeyTracker::new();
+        tracker.set_grace_period_ms(1000);
+        let half = account.max_number_of_one_time_keys() as u32 / 2;
+        let unused = || Some(vec![SIGNED_CURVE25519.to_owned()]);
+        let plan = |unused_fallback_types, now_ms| {
+            account.plan_key_maintenance(&tracker, counts(half), unused_fallback_types, now_ms)
+        };
+
+        // No fallback key on the server yet.
+        assert!(plan(Some(vec![]), 0).rotate_fallback_key);
+        let first = account.fallback_key();
+        assert_eq!(first.len(), 1);
+
+        // The key hasn't been uploaded yet, so the server can't know about it.
+        assert!(!plan(Some(vec![]), 0).rotate_fallback_key);
+        assert_eq!(account.fallback_key()[0].keyid, first[0].keyid);
+
+        account.mark_keys_as_published();
+        assert_eq!(
+            plan(unused(), 0),
+            KeyMaintenancePlan {
+                generate_one_time_keys: 0,
+                rotate_fallback_key: false,
+                forget_old_fallback_key: false,
+            }
+        );
+
+        // The fallback key got used, rotate it.
+        assert!(plan(Some(vec![]), 5000).rotate_fallback_key);
+        account.mark_keys_as_published();
+
+        // The old key is kept for the grace period after the new one reached the server.
+        assert!(!plan(unused(), 6000).forget_old_fallback_key);
+        let pickle_key = [6u8; 32];
+        let tracker = FallbackKeyTracker::from_pickle_encrypted(
+            tracker.pickle_encrypted(pickle_key),
+            pickle_key,
+        )
+        .unwrap();
+        let plan = |now_ms| account.plan_key_maintenance(&tracker, counts(half), unused(), now_ms);
+        assert!(!plan(6999).forget_old_fallback_key);
+        assert!(plan(7000).forget_old_fallback_key);
+        assert!(!plan(9000).forget_old_fallback_key);
+    }
+}
@@ -0,0 +1,119 @@
+//! The Ed25519 key every Megolm message is signed with.
+//! https://gitlab.matrix.org/matrix-org/olm/-/blob/master/docs/megolm.md#message-format
+
+use serde::{Deserialize, Serialize};
+use vodozemac::megolm::MegolmMessage;
+use vodozemac::Ed25519PublicKey;
+
+use super::{VodozemacEd25519PublicKey, VodozemacInboundGroupSession};
+
+/// What an inbound group session was created from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionKeySource {
+    /// A `SessionKey`, which is signed by the creator of the session, e.g. from `m.room_key`.
+    SessionKey,
+    /// An `ExportedSessionKey`, which anyone knowing the session could have produced, e.g.
+    /// from a forwarded key, backup or key export.
+    ///
+    /// Sessions restored from a vodozemac or libolm pickle report this too, as those pickles
+    /// don't record the source in a form we can read. The pickle of an `InboundRoomKey` does.
+    ExportedSessionKey,
+}
+
+impl SessionKeySource {
+    /// The source of a session merged from two copies: one copy from a `SessionKey` is enough.
+    pub(crate) fn merged(self, other: Self) -> Self {
+        if self == Self::SessionKey || other == Self::SessionKey {
+            Self::SessionKey
+        } else {
+            Self::ExportedSessionKey
+        }
+    }
+}
+
+/// A decrypted Megolm message with the information needed to judge where it came from.
+pub struct ExtendedDecryptResult {
+    pub plaintext: String,
+    pub message_index: u32,
+    /// The key the message was signed with, which is also the session id.
+    pub signing_key: VodozemacEd25519PublicKey,
+    pub key_source: SessionKeySource,
+}
+
+impl VodozemacInboundGroupSession {
+    /// The public key messages of this session are signed with.
+    pub fn signing_key(&self) -> VodozemacEd25519PublicKey {
+        let session_id = self.session_id();
+        Ed25519PublicKey::from_base64(&session_id)
+            .expect("The session id is the base64 encoded signing key")
+            .into()
+    }
+
+    /// Whether the session was created from a `SessionKey` or merged with one, as opposed to
+    /// only ever being imported from an `ExportedSessionKey`.
+    pub fn key_source(&self) -> SessionKeySource {
+        *self.source.read().expect("Failed to read key source")
+    }
+
+    /// Like `decrypt`, but also return the signing key and where the session came from.
+    pub fn decrypt_extended(&self, encrypted: String) -> anyhow::Result<ExtendedDecryptResult> {
+        let message = MegolmMessage::from_base64(&encrypted)?;
+        let mut session = self.session.write().expect("Failed to write session");
+        let decrypted = session.decrypt(&message)?;
+        Ok(ExtendedDecryptResult {
+            plaintext: String::from_utf8(decrypted.plaintext)?,
+            message_index: decrypted.message_index,
+            signing_key: Ed25519PublicKey::from_base64(&session.session_id())?.into(),
+            key_source: self.key_source(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::VodozemacGroupSession;
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+
+    #[test]
+    fn test_signing_key_and_key_source() {
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let inbound = VodozemacInboundGroupSession::new(
+            outbound.session_key(),
+            SessionConfig::version_1().into(),
+        )
+        .unwrap();
+        let imported = VodozemacInboundGroupSession::import(
+            inbound.export_at_first_known_index(),
+            SessionConfig::version_1().into(),
+        )
+        .unwrap();
+
+        assert_eq!(inbound.signing_key().to_base64(), outbound.session_id());
+        assert_eq!(inbound.key_source(), SessionKeySource::SessionKey);
+        assert_eq!(imported.key_source(), SessionKeySource::ExportedSessionKey);
+
+        let ciphertext = outbound.encrypt("Hello".to_owned());
+        let result = inbound.decrypt_extended(ciphertext.clone()).unwrap();
+        assert_eq!(result.plaintext, "Hello");
+        assert_eq!(result.message_index, 0);
+        assert_eq!(result.signing_key.to_base64(), outbound.session_id());
+        assert_eq!(result.key_source, SessionKeySource::SessionKey);
+
+        let result = imported.decrypt_extended(ciphertext).unwrap();
+        assert_eq!(result.signing_key.to_base64(), outbound.session_id());
+        assert_eq!(result.key_source, SessionKeySource::ExportedSessionKey);
+    }
+
+    #[test]
+    fn test_unpickled_sessions_are_not_authenticated() {
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let pickle_key = [7u8; 32];
+        let unpickled = VodozemacInboundGroupSession::from_pickle_encrypted(
+            outbound.to_inbound().pickle_encrypted(pickle_key),
+            pickle_key,
+        )
+        .unwrap();
+        assert_eq!(unpickled.key_source(), SessionKeySource::ExportedSessionKey);
+    }
+}
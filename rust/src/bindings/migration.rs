@@ -0,0 +1,394 @@
+//! Migration of a store written with libolm to vodozemac pickles.
+//!
+//! Every object is unpickled from libolm, pickled with vodozemac under the new key and
+//! unpickled again, to check that the round trip didn't change it. Nothing is written, the
+//! caller replaces the old pickles only if the report has no failures.
+
+use flutter_rust_bridge::frb;
+
+use super::pickle_format::PickleObject;
+use super::{
+    VodozemacAccount, VodozemacInboundGroupSession, VodozemacPkDecryption, VodozemacSession,
+};
+
+/// The pickles of a libolm store.
+pub struct LibolmStore {
+    pub account: Option<String>,
+    pub sessions: Vec<String>,
+    pub inbound_group_sessions: Vec<String>,
+    /// The key of the server-side key backup.
+    pub pk_decryption: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum MigrationError {
+    #[error("The libolm pickle couldn't be unpickled")]
+    InvalidLibolmPickle,
+    #[error("The migrated pickle couldn't be unpickled")]
+    InvalidMigratedPickle,
+    #[error("The id changed during the migration")]
+    IdMismatch,
+    #[error("The identity keys changed during the migration")]
+    IdentityKeyMismatch,
+    #[error("The first known index changed during the migration")]
+    FirstKnownIndexMismatch,
+}
+
+/// An object that was migrated successfully.
+pub struct MigratedPickle {
+    /// The position of the object in the `LibolmStore`.
+    pub index: u32,
+    /// The session id, the Curve25519 key for the account or the public key for the
+    /// `PkDecryption`, read from the libolm pickle.
+    pub id_before: String,
+    /// The same id read from the migrated pickle. The migration fails if they differ.
+    pub id_after: String,
+    pub pickle: String,
+}
+
+pub struct MigrationFailure {
+    pub object: PickleObject,
+    pub index: u32,
+    /// The id before the migration, if the libolm pickle could be unpickled.
+    pub id_before: Option<String>,
+    /// The id after the migration, if the migrated pickle could be unpickled.
+    pub id_after: Option<String>,
+    pub error: MigrationError,
+}
+
+pub struct MigrationReport {
+    pub account: Option<MigratedPickle>,
+    pub sessions: Vec<MigratedPickle>,
+    pub inbound_group_sessions: Vec<MigratedPickle>,
+    /// vodozemac can only pickle a `PkDecryption` in the libolm format, so this is a libolm
+    /// pickle under the new key.
+    pub pk_decryption: Option<MigratedPickle>,
+    pub failures: Vec<MigrationFailure>,
+}
+
+impl MigrationReport {
+    /// Whether every object was migrated, so the old pickles can be replaced.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// What has to stay the same during the migration.
+struct Fingerprint {
+    id: String,
+    ed25519: Option<String>,
+    first_known_index: Option<u32>,
+}
+
+impl Fingerprint {
+    fn id(id: String) -> Self {
+        Self {
+            id,
+            ed25519: None,
+            first_known_index: None,
+        }
+    }
+
+    fn mismatch(&self, other: &Self) -> Option<MigrationError> {
+        if self.id != other.id {
+            Some(MigrationError::IdMismatch)
+        } else if self.ed25519 != other.ed25519 {
+            Some(MigrationError::IdentityKeyMismatch)
+        } else if self.first_known_index != other.first_known_index {
+            Some(MigrationError::FirstKnownIndexMismatch)
+        } else {
+            None
+        }
+    }
+}
+
+/// The objects of a libolm store.
+trait LibolmObject: Sized {
+    const OBJECT: PickleObject;
+
+    #[frb(ignore)]
+    fn from_libolm(pickle: String, pickle_key: Vec<u8>) -> anyhow::Result<Self>;
+    #[frb(ignore)]
+    fn pickle(&self, pickle_key: [u8; 32]) -> String;
+    #[frb(ignore)]
+    fn unpickle(pickle: String, pickle_key: [u8; 32]) -> anyhow::Result<Self>;
+    #[frb(ignore)]
+    fn fingerprint(&self) -> Fingerprint;
+}
+
+#[frb(ignore)]
+impl LibolmObject for VodozemacAccount {
+    const OBJECT: PickleObject = PickleObject::Account;
+
+    fn from_libolm(pickle: String, pickle_key: Vec<u8>) -> anyhow::Result<Self> {
+        Self::from_olm_pickle_encrypted(pickle, pickle_key)
+    }
+
+    fn pickle(&self, pickle_key: [u8; 32]) -> String {
+        self.pickle_encrypted(pickle_key)
+    }
+
+    fn unpickle(pickle: String, pickle_key: [u8; 32]) -> anyhow::Result<Self> {
+        Self::from_pickle_encrypted(pickle, pickle_key)
+    }
+
+    fn fingerprint(&self) -> Fingerprint {
+        Fingerprint {
+            ed25519: Some(self.ed25519_key().to_base64()),
+            ..Fingerprint::id(self.curve25519_key().to_base64())
+        }
+    }
+}
+
+#[frb(ignore)]
+impl LibolmObject for VodozemacSession {
+    const OBJECT: PickleObject = PickleObject::Session;
+
+    fn from_libolm(pickle: String, pickle_key: Vec<u8>) -> anyhow::Result<Self> {
+        Self::from_olm_pickle_encrypted(pickle, pickle_key)
+    }
+
+    fn pickle(&self, pickle_key: [u8; 32]) -> String {
+        self.pickle_encrypted(pickle_key)
+    }
+
+    fn unpickle(pickle: String, pickle_key: [u8; 32]) -> anyhow::Result<Self> {
+        Self::from_pickle_encrypted(pickle, pickle_key)
+    }
+
+    fn fingerprint(&self) -> Fingerprint {
+        Fingerprint::id(self.session_id())
+    }
+}
+
+#[frb(ignore)]
+impl LibolmObject for VodozemacInboundGroupSession {
+    const OBJECT: PickleObject = PickleObject::InboundGroupSession;
+
+    fn from_libolm(pickle: String, pickle_key: Vec<u8>) -> anyhow::Result<Self> {
+        Self::from_olm_pickle_encrypted(pickle, pickle_key)
+    }
+
+    fn pickle(&self, pickle_key: [u8; 32]) -> String {
+        self.pickle_encrypted(pickle_key)
+    }
+
+    fn unpickle(pickle: String, pickle_key: [u8; 32]) -> anyhow::Result<Self> {
+        Self::from_pickle_encrypted(pickle, pickle_key)
+    }
+
+    fn fingerprint(&self) -> Fingerprint {
+        Fingerprint {
+            first_known_index: Some(self.first_known_index()),
+            ..Fingerprint::id(self.session_id())
+        }
+    }
+}
+
+#[frb(ignore)]
+impl LibolmObject for VodozemacPkDecryption {
+    const OBJECT: PickleObject = PickleObject::PkDecryption;
+
+    fn from_libolm(pickle: String, pickle_key: Vec<u8>) -> anyhow::Result<Self> {
+        Self::from_libolm_pickle(pickle, pickle_key)
+    }
+
+    fn pickle(&self, pickle_key: [u8; 32]) -> String {
+        self.to_libolm_pickle(pickle_key)
+    }
+
+    fn unpickle(pickle: String, pickle_key: [u8; 32]) -> anyhow::Result<Self> {
+        Self::from_libolm_pickle(pickle, pickle_key.to_vec())
+    }
+
+    fn fingerprint(&self) -> Fingerprint {
+        Fingerprint::id(self.public_key())
+    }
+}
+
+struct Migration<'a> {
+    old_pickle_key: &'a [u8],
+    new_pickle_key: [u8; 32],
+    failures: Vec<MigrationFailure>,
+}
+
+impl Migration<'_> {
+    fn migrate<T: LibolmObject>(&mut self, index: usize, pickle: String) -> Option<MigratedPickle> {
+        let index = index as u32;
+        let mut failure = |id_before, id_after, error| {
+            self.failures.push(MigrationFailure {
+                object: T::OBJECT,
+                index,
+                id_before,
+                id_after,
+                error,
+            });
+            None
+        };
+
+        let Ok(old) = T::from_libolm(pickle, self.old_pickle_key.to_vec()) else {
+            return failure(None, None, MigrationError::InvalidLibolmPickle);
+        };
+        let before = old.fingerprint();
+        let pickle = old.pickle(self.new_pickle_key);
+        let Ok(new) = T::unpickle(pickle.clone(), self.new_pickle_key) else {
+            return failure(Some(before.id), None, MigrationError::InvalidMigratedPickle);
+        };
+        let after = new.fingerprint();
+
+        match before.mismatch(&after) {
+            Some(error) => failure(Some(before.id), Some(after.id), error),
+            None => Some(MigratedPickle {
+                index,
+                id_before: before.id,
+                id_after: after.id,
+                pickle,
+            }),
+        }
+    }
+
+    fn migrate_all<T: LibolmObject>(&mut self, pickles: Vec<String>) -> Vec<MigratedPickle> {
+        pickles
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, pickle)| self.migrate::<T>(index, pickle))
+            .collect()
+    }
+}
+
+/// Migrate all pickles of a libolm store to vodozemac pickles under `new_pickle_key`.
+///
+/// libolm accepted pickle keys of any length, so `old_pickle_key` can have any length too.
+/// Objects that fail are listed in the report and left out, the others are still migrated.
+pub fn migrate_libolm_store(
+    store: LibolmStore,
+    old_pickle_key: Vec<u8>,
+    new_pickle_key: [u8; 32usize],
+) -> MigrationReport {
+    let mut migration = Migration {
+        old_pickle_key: &old_pickle_key,
+        new_pickle_key,
+        failures: vec![],
+    };
+
+    MigrationReport {
+        account: store
+            .account
+            .and_then(|pickle| migration.migrate::<VodozemacAccount>(0, pickle)),
+        sessions: migration.migrate_all::<VodozemacSession>(store.sessions),
+        inbound_group_sessions: migration
+            .migrate_all::<VodozemacInboundGroupSession>(store.inbound_group_sessions),
+        pk_decryption: store
+            .pk_decryption
+            .and_then(|pickle| migration.migrate::<VodozemacPkDecryption>(0, pickle)),
+        failures: migration.failures,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{libolm_inbound_group_session_pickle, libolm_session_pickle};
+    use super::super::VodozemacGroupSession;
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+    use vodozemac::olm::SessionConfig as OlmSessionConfig;
+
+    #[test]
+    fn test_migrate_libolm_store() {
+        // libolm pickle keys don't have to be 32 bytes long.
+        let old_key = b"a libolm pickle key".to_vec();
+        let new_key = [5u8; 32];
+        let account = VodozemacAccount::new();
+        let pk_decryption = VodozemacPkDecryption::new();
+        let bob = VodozemacAccount::new();
+        bob.generate_one_time_keys(1);
+        let session = account.create_outbound_session(
+            OlmSessionConfig::version_1().into(),
+            bob.curve25519_key(),
+            bob.one_time_keys().pop().unwrap().key,
+        );
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        outbound.encrypt("skipped".to_owned());
+        let inbound = VodozemacInboundGroupSession::import(
+            outbound.to_inbound().export_at(1).unwrap(),
+            SessionConfig::version_1().into(),
+        )
+        .unwrap();
+
+        let report = migrate_libolm_store(
+            LibolmStore {
+                account: Some(
+                    account
+                        .account
+                        .read()
+                        .unwrap()
+                        .to_libolm_pickle(&old_key)
+                        .unwrap(),
+                ),
+                sessions: vec![
+                    "not a pickle".to_owned(),
+                    libolm_session_pickle(&session, &old_key),
+                ],
+                inbound_group_sessions: vec![libolm_inbound_group_session_pickle(
+                    &inbound, &old_key,
+                )],
+                pk_decryption: Some(
+                    pk_decryption
+                        .pk_decryption
+                        .to_libolm_pickle(&old_key)
+                        .unwrap(),
+                ),
+            },
+            old_key,
+            new_key,
+        );
+
+        let migrated = report.account.as_ref().unwrap();
+        assert_eq!(migrated.id_before, account.curve25519_key().to_base64());
+        assert_eq!(migrated.id_after, migrated.id_before);
+        let unpickled =
+            VodozemacAccount::from_pickle_encrypted(migrated.pickle.clone(), new_key).unwrap();
+        assert_eq!(
+            unpickled.ed25519_key().to_base64(),
+            account.ed25519_key().to_base64()
+        );
+
+        let migrated = report.pk_decryption.as_ref().unwrap();
+        assert_eq!(migrated.id_before, pk_decryption.public_key());
+        assert_eq!(migrated.id_after, migrated.id_before);
+        assert!(VodozemacPkDecryption::from_libolm_pickle(
+            migrated.pickle.clone(),
+            new_key.to_vec()
+        )
+        .is_ok());
+
+        // The invalid session doesn't stop the valid one after it.
+        assert_eq!(report.sessions.len(), 1);
+        let migrated = &report.sessions[0];
+        assert_eq!(migrated.index, 1);
+        assert_eq!(migrated.id_before, session.session_id());
+        assert_eq!(migrated.id_after, migrated.id_before);
+        let unpickled =
+            VodozemacSession::from_pickle_encrypted(migrated.pickle.clone(), new_key).unwrap();
+        assert_eq!(unpickled.session_id(), session.session_id());
+
+        // The session was imported at index 1, which has to survive the migration.
+        let migrated = &report.inbound_group_sessions[0];
+        assert_eq!(migrated.id_before, inbound.session_id());
+        assert_eq!(migrated.id_after, migrated.id_before);
+        let unpickled =
+            VodozemacInboundGroupSession::from_pickle_encrypted(migrated.pickle.clone(), new_key)
+                .unwrap();
+        assert_eq!(unpickled.first_known_index(), 1);
+
+        assert!(!report.is_complete());
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].object, PickleObject::Session);
+        assert_eq!(report.failures[0].index, 0);
+        assert_eq!(report.failures[0].id_before, None);
+        assert_eq!(
+            report.failures[0].error,
+            MigrationError::InvalidLibolmPickle
+        );
+    }
+}
@@ -0,0 +1,385 @@
+//! Bookkeeping for the Olm sessions established with other devices.
+//! https://spec.matrix.org/v1.16/client-server-api/#molmv1curve25519-aes-sha2
+//!
+//! A device may end up with several sessions with the same peer, e.g. when both sides create
+//! an outbound session at the same time. All of them are tried on decryption, while new
+//! messages are encrypted with the session that most recently received a message.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use vodozemac::olm::{Session, SessionPickle};
+
+use super::pickle::{decrypt_pickle, encrypt_pickle};
+use super::{RustOpaqueNom, RwLock, VodozemacSession};
+
+/// How many sessions are kept per device unless configured otherwise.
+const DEFAULT_MAX_SESSIONS_PER_DEVICE: u32 = 10;
+
+struct StoredSession {
+    session: RustOpaqueNom<RwLock<Session>>,
+    created_at_ms: u64,
+    last_used_ms: u64,
+}
+
+impl StoredSession {
+    fn session_id(&self) -> String {
+        self.session
+            .read()
+            .expect("Failed to read session")
+            .session_id()
+    }
+
+    fn has_received_message(&self) -> bool {
+        self.session
+            .read()
+            .expect("Failed to read session")
+            .has_received_message()
+    }
+
+    fn handle(&self) -> VodozemacSession {
+        VodozemacSession {
+            session: self.session.clone(),
+        }
+    }
+}
+
+pub struct SessionStoreState {
+    max_sessions_per_device: u32,
+    /// The sessions per Curve25519 key of the peer, best candidate for encryption first.
+    devices: HashMap<String, Vec<StoredSession>>,
+}
+
+impl SessionStoreState {
+    fn sort(sessions: &mut [StoredSession]) {
+        sessions.sort_by_cached_key(|s| {
+            std::cmp::Reverse((s.has_received_message(), s.last_used_ms, s.created_at_ms))
+        });
+    }
+
+    /// Drop the least recently used sessions of a device beyond the limit, returning their ids.
+    fn prune(&mut self, sender_key: &str) -> Vec<String> {
+        let max = self.max_sessions_per_device.max(1) as usize;
+        let Some(sessions) = self.devices.get_mut(sender_key) else {
+            return vec![];
+        };
+        if sessions.len() <= max {
+            return vec![];
+        }
+
+        sessions.sort_by_key(|s| std::cmp::Reverse((s.last_used_ms, s.created_at_ms)));
+        let pruned = sessions.split_off(max);
+        Self::sort(sessions);
+        pruned.iter().map(StoredSession::session_id).collect()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredSessionPickle {
+    session: SessionPickle,
+    created_at_ms: u64,
+    last_used_ms: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SessionStorePickle {
+    max_sessions_per_device: u32,
+    devices: HashMap<String, Vec<StoredSessionPickle>>,
+}
+
+/// The Olm sessions with other devices, keyed by the Curve25519 key of the device.
+///
+/// The sessions handed out share their state with the store, so using them to encrypt or
+/// decrypt updates the sessions that get pickled with the store.
+pub struct OlmSessionStore {
+    pub state: RustOpaqueNom<RwLock<SessionStoreState>>,
+}
+
+impl Default for OlmSessionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<SessionStoreState> for OlmSessionStore {
+    fn from(state: SessionStoreState) -> Self {
+        Self {
+            state: RustOpaqueNom::new(RwLock::new(state)),
+        }
+    }
+}
+
+impl OlmSessionStore {
+    pub fn new() -> Self {
+        SessionStoreState {
+            max_sessions_per_device: DEFAULT_MAX_SESSIONS_PER_DEVICE,
+            devices: HashMap::new(),
+        }
+        .into()
+    }
+
+    pub fn max_sessions_per_device(&self) -> u32 {
+        self.state
+            .read()
+            .expect("Failed to read store")
+            .max_sessions_per_device
+    }
+
+    /// Change the session limit, returning the ids of the sessions pruned because of it.
+    pub fn set_max_sessions_per_device(&self, max_sessions_per_device: u32) -> Vec<String> {
+        let mut state = self.state.write().expect("Failed to write store");
+        state.max_sessions_per_device = max_sessions_per_device;
+        let sender_keys: Vec<String> = state.devices.keys().cloned().collect();
+        sender_keys
+            .iter()
+            .flat_map(|sender_key| state.prune(sender_key))
+            .collect()
+    }
+
+    /// Add a newly created session with the device owning `sender_key`.
+    ///
+    /// Returns the ids of the sessions that were pruned to stay within the limit. Adding a
+    /// session that is already stored does nothing.
+    pub fn add_session(
+        &self,
+        sender_key: String,
+        session: &VodozemacSession,
+        now_ms: u64,
+    ) -> Vec<String> {
+        let mut state = self.state.write().expect("Failed to write store");
+        let session_id = session.session_id();
+        let sessions = state.devices.entry(sender_key.clone()).or_default();
+        if sessions.iter().any(|s| s.session_id() == session_id) {
+            return vec![];
+        }
+
+        sessions.push(StoredSession {
+            session: session.session.clone(),
+            created_at_ms: now_ms,
+            last_used_ms: now_ms,
+        });
+        SessionStoreState::sort(sessions);
+        state.prune(&sender_key)
+    }
+
+    /// Record that a session was used to encrypt or decrypt a message.
+    ///
+    /// Returns false if the session is not in the store.
+    pub fn mark_used(&self, sender_key: String, session_id: String, now_ms: u64) -> bool {
+        let mut state = self.state.write().expect("Failed to write store");
+        let Some(sessions) = state.devices.get_mut(&sender_key) else {
+            return false;
+        };
+        let Some(session) = sessions.iter_mut().find(|s| s.session_id() == session_id) else {
+            return false;
+        };
+        session.last_used_ms = session.last_used_ms.max(now_ms);
+        SessionStoreState::sort(sessions);
+        true
+    }
+
+    /// The session to encrypt the next message to the device with.
+    ///
+    /// This is the most recently used session that received a message, or the most recently
+    /// used one if we never heard back from the device.
+    pub fn session_for_encryption(&self, sender_key: String) -> Option<VodozemacSession> {
+        let mut state = self.state.write().expect("Failed to write store");
+        let sessions = state.devices.get_mut(&sender_key)?;
+        // Decrypting with a handed out session may have changed `has_received_message`.
+        SessionStoreState::sort(sessions);
+        sessions.first().map(StoredSession::handle)
+    }
+
+    /// All sessions with the device, in the order they should be tried for decryption.
+    pub fn sessions_for_device(&self, sender_key: String) -> Vec<VodozemacSession> {
+        let mut state = self.state.write().expect("Failed to write store");
+        let Some(sessions) = state.devices.get_mut(&sender_key) else {
+            return vec![];
+        };
+        SessionStoreState::sort(sessions);
+        sessions.iter().map(StoredSession::handle).collect()
+    }
+
+    pub fn remove_session(&self, sender_key: String, session_id: String) -> bool {
+        let mut state = self.state.write().expect("Failed to write store");
+        let Some(sessions) = state.devices.get_mut(&sender_key) else {
+            return false;
+        };
+        let before = sessions.len();
+        sessions.retain(|s| s.session_id() != session_id);
+        let removed = sessions.len() != before;
+        if sessions.is_empty() {
+            state.devices.remove(&sender_key);
+        }
+        removed
+    }
+
+    pub fn sender_keys(&self) -> Vec<String> {
+        self.state
+            .read()
+            .expect("Failed to read store")
+            .devices
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    pub fn pickle_encrypted(&self, pickle_key: [u8; 32usize]) -> String {
+        let state = self.state.read().expect("Failed to read store");
+        let pickle = SessionStorePickle {
+            max_sessions_per_device: state.max_sessions_per_device,
+            devices: state
+                .devices
+                .iter()
+                .map(|(sender_key, sessions)| {
+                    let sessions = sessions
+                        .iter()
+                        .map(|s| StoredSessionPickle {
+                            session: s.session.read().expect("Failed to read session").pickle(),
+                            created_at_ms: s.created_at_ms,
+                            last_used_ms: s.last_used_ms,
+                        })
+                        .collect();
+                    (sender_key.clone(), sessions)
+                })
+                .collect(),
+        };
+        encrypt_pickle(&pickle, &pickle_key)
+    }
+
+    pub fn from_pickle_encrypted(
+        pickle: String,
+        pickle_key: [u8; 32usize],
+    ) -> anyhow::Result<Self> {
+        let pickle: SessionStorePickle = decrypt_pickle(&pickle, &pickle_key)?;
+        let devices = pickle
+            .devices
+            .into_iter()
+            .map(|(sender_key, sessions)| {
+                let mut sessions: Vec<StoredSession> = sessions
+                    .into_iter()
+                    .map(|s| StoredSession {
+                        session: RustOpaqueNom::new(RwLock::new(Session::from(s.session))),
+                        created_at_ms: s.created_at_ms,
+                        last_used_ms: s.last_used_ms,
+                    })
+                    .collect();
+                SessionStoreState::sort(&mut sessions);
+                (sender_key, sessions)
+            })
+            .collect();
+
+        Ok(SessionStoreState {
+            max_sessions_per_device: pickle.max_sessions_per_device,
+            devices,
+        }
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vodozemac::olm::{Account, OlmMessage, SessionConfig};
+
+    /// Create an outbound session from `alice` to `bob`, and `bob`'s matching inbound session.
+    fn session_pair(alice: &Account, bob: &mut Account) -> (VodozemacSession, VodozemacSession) {
+        bob.generate_one_time_keys(1);
+        let one_time_key = *bob.one_time_keys().values().next().unwrap();
+        bob.mark_keys_as_published();
+        let mut outbound = alice.create_outbound_session(
+            SessionConfig::version_1(),
+            bob.curve25519_key(),
+            one_time_key,
+        );
+        let OlmMessage::PreKey(message) = outbound.encrypt("hello") else {
+            panic!("Expected a pre-key message");
+        };
+        let inbound = bob
+            .create_inbound_session(alice.curve25519_key(), &message)
+            .unwrap();
+        (outbound.into(), inbound.session.into())
+    }
+
+    #[test]
+    fn test_session_selection_prefers_received_messages() {
+        let alice = Account::new();
+        let mut bob = Account::new();
+        let bob_key = bob.curve25519_key().to_base64();
+        let store = OlmSessionStore::new();
+
+        let (replied, bob_session) = session_pair(&alice, &mut bob);
+        let (unreplied, _) = session_pair(&alice, &mut bob);
+        store.add_session(bob_key.clone(), &replied, 1000);
+        store.add_session(bob_key.clone(), &unreplied, 2000);
+        assert_eq!(
+            store
+                .session_for_encryption(bob_key.clone())
+                .unwrap()
+                .session_id(),
+            unreplied.session_id()
+        );
+
+        // Decrypting through a handle updates the session in the store.
+        let reply = bob_session.encrypt("hi".to_owned());
+        let handle = store
+            .sessions_for_device(bob_key.clone())
+            .into_iter()
+            .find(|s| s.session_id() == replied.session_id())
+            .unwrap();
+        handle.decrypt(reply).unwrap();
+        assert!(store.mark_used(bob_key.clone(), replied.session_id(), 1500));
+        assert_eq!(
+            store
+                .session_for_encryption(bob_key.clone())
+                .unwrap()
+                .session_id(),
+            replied.session_id()
+        );
+        assert!(!store.mark_used(bob_key, "unknown".to_owned(), 1500));
+    }
+
+    #[test]
+    fn test_pruning_and_pickling() {
+        let alice = Account::new();
+        let mut bob = Account::new();
+        let bob_key = bob.curve25519_key().to_base64();
+        let store = OlmSessionStore::new();
+        store.set_max_sessions_per_device(2);
+
+        let sessions: Vec<_> = (0..3).map(|_| session_pair(&alice, &mut bob).0).collect();
+        assert!(store
+            .add_session(bob_key.clone(), &sessions[0], 1000)
+            .is_empty());
+        assert!(store
+            .add_session(bob_key.clone(), &sessions[1], 2000)
+            .is_empty());
+        assert!(store.mark_used(bob_key.clone(), sessions[0].session_id(), 3000));
+        assert_eq!(
+            store.add_session(bob_key.clone(), &sessions[2], 4000),
+            vec![sessions[1].session_id()]
+        );
+
+        let pickle_key = [5u8; 32];
+        let unpickled =
+            OlmSessionStore::from_pickle_encrypted(store.pickle_encrypted(pickle_key), pickle_key)
+                .unwrap();
+        assert_eq!(unpickled.max_sessions_per_device(), 2);
+        let ids: Vec<_> = unpickled
+            .sessions_for_device(bob_key.clone())
+            .iter()
+            .map(VodozemacSession::session_id)
+            .collect();
+        assert_eq!(
+            ids,
+            vec![sessions[2].session_id(), sessions[0].session_id()]
+        );
+
+        assert_eq!(
+            unpickled.set_max_sessions_per_device(1),
+            vec![sessions[0].session_id()]
+        );
+        assert!(unpickled.remove_session(bob_key.clone(), sessions[2].session_id()));
+        assert!(unpickled.sender_keys().is_empty());
+    }
+}
@@ -0,0 +1,237 @@
+//! Outbound Megolm sessions together with the room settings that decide when to rotate them.
+//! https://spec.matrix.org/v1.16/client-server-api/#mroomencryption
+
+use serde::{Deserialize, Serialize};
+use vodozemac::megolm::{GroupSession, GroupSessionPickle};
+
+use super::pickle::{decrypt_pickle, encrypt_pickle};
+use super::share_tracking::ShareState;
+use super::{RustOpaqueNom, RwLock, VodozemacGroupSession, VodozemacMegolmSessionConfig};
+
+/// The `rotation_period_ms` the spec recommends if the room doesn't set one.
+const DEFAULT_ROTATION_PERIOD_MS: u64 = 7 * 24 * 60 * 60 * 1000;
+/// The `rotation_period_msgs` the spec recommends if the room doesn't set one.
+const DEFAULT_ROTATION_PERIOD_MSGS: u64 = 100;
+
+/// Why an outbound session has to be replaced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum RotationReason {
+    #[error("The session is older than the rotation period of the room")]
+    Age,
+    #[error("The session encrypted as many messages as the room allows")]
+    MessageCount,
+    #[error("The session was invalidated, e.g. because a member left")]
+    Invalidated,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct RotationState {
+    created_at_ms: u64,
+    rotation_period_ms: u64,
+    rotation_period_msgs: u64,
+    invalidated: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct OutboundGroupSessionPickle {
+    session: GroupSessionPickle,
+    rotation: RotationState,
+    #[serde(default)]
+    shares: ShareState,
+}
+
+/// An outbound group session for a room.
+///
+/// `session` is shared with the `VodozemacGroupSession` returned by `group_session`, so
+/// messages encrypted with it count towards `rotation_period_msgs`.
+pub struct OutboundGroupSession {
+    pub session: RustOpaqueNom<RwLock<GroupSession>>,
+    pub rotation: RustOpaqueNom<RwLock<RotationState>>,
+    pub shares: RustOpaqueNom<RwLock<ShareState>>,
+}
+
+impl OutboundGroupSession {
+    /// Create a new session for a room whose `m.room.encryption` event contains the given
+    /// rotation periods. Missing periods fall back to the defaults of the spec.
+    pub fn new(
+        config: VodozemacMegolmSessionConfig,
+        created_at_ms: u64,
+        rotation_period_ms: Option<u64>,
+        rotation_period_msgs: Option<u64>,
+    ) -> Self {
+        Self::from_group_session(
+            &VodozemacGroupSession::new(config),
+            created_at_ms,
+            rotation_period_ms,
+            rotation_period_msgs,
+        )
+    }
+
+    /// Track the rotation of an existing session, e.g. one unpickled from the plain
+    /// `VodozemacGroupSession` pickle.
+    pub fn from_group_session(
+        session: &VodozemacGroupSession,
+        created_at_ms: u64,
+        rotation_period_ms: Option<u64>,
+        rotation_period_msgs: Option<u64>,
+    ) -> Self {
+        Self {
+            session: session.session.clone(),
+            rotation: RustOpaqueNom::new(RwLock::new(RotationState {
+                created_at_ms,
+                rotation_period_ms: rotation_period_ms.unwrap_or(DEFAULT_ROTATION_PERIOD_MS),
+                rotation_period_msgs: rotation_period_msgs.unwrap_or(DEFAULT_ROTATION_PERIOD_MSGS),
+                invalidated: false,
+            })),
+            shares: RustOpaqueNom::new(RwLock::new(ShareState::default())),
+        }
+    }
+
+    pub fn group_session(&self) -> VodozemacGroupSession {
+        VodozemacGroupSession {
+            session: self.session.clone(),
+        }
+    }
+
+    pub fn session_id(&self) -> String {
+        self.session
+            .read()
+            .expect("Failed to read session")
+            .session_id()
+    }
+
+    pub fn created_at_ms(&self) -> u64 {
+        self.rotation
+            .read()
+            .expect("Failed to read rotation")
+            .created_at_ms
+    }
+
+    pub fn rotation_period_ms(&self) -> u64 {
+        self.rotation
+            .read()
+            .expect("Failed to read rotation")
+            .rotation_period_ms
+    }
+
+    pub fn rotation_period_msgs(&self) -> u64 {
+        self.rotation
+            .read()
+            .expect("Failed to read rotation")
+            .rotation_period_msgs
+    }
+
+    /// Force a rotation before the next message, e.g. because a member left the room or
+    /// removed a device.
+    pub fn invalidate(&self) {
+        self.rotation
+            .write()
+            .expect("Failed to write rotation")
+            .invalidated = true;
+    }
+
+    /// Why the session has to be replaced before encrypting the next message, if it has to.
+    pub fn expired_reason(&self, now_ms: u64) -> Option<RotationReason> {
+        let message_index = self
+            .session
+            .read()
+            .expect("Failed to read session")
+            .message_index();
+        let rotation = self.rotation.read().expect("Failed to read rotation");
+
+        if rotation.invalidated {
+            Some(RotationReason::Invalidated)
+        } else if u64::from(message_index) >= rotation.rotation_period_msgs {
+            Some(RotationReason::MessageCount)
+        } else if now_ms.saturating_sub(rotation.created_at_ms) >= rotation.rotation_period_ms {
+            Some(RotationReason::Age)
+        } else {
+            None
+        }
+    }
+
+    pub fn needs_rotation(&self, now_ms: u64) -> bool {
+        self.expired_reason(now_ms).is_some()
+    }
+
+    pub fn pickle_encrypted(&self, pickle_key: [u8; 32usize]) -> String {
+        let session = self.session.read().expect("Failed to read session");
+        let rotation = self.rotation.read().expect("Failed to read rotation");
+        let shares = self.shares.read().expect("Failed to read shares");
+        let pickle = OutboundGroupSessionPickle {
+            session: session.pickle(),
+            rotation: *rotation,
+            shares: shares.clone(),
+        };
+        encrypt_pickle(&pickle, &pickle_key)
+    }
+
+    pub fn from_pickle_encrypted(
+        pickle: String,
+        pickle_key: [u8; 32usize],
+    ) -> anyhow::Result<Self> {
+        let pickle: OutboundGroupSessionPickle = decrypt_pickle(&pickle, &pickle_key)?;
+        Ok(Self {
+            session: RustOpaqueNom::new(RwLock::new(GroupSession::from(pickle.session))),
+            rotation: RustOpaqueNom::new(RwLock::new(pickle.rotation)),
+            shares: RustOpaqueNom::new(RwLock::new(pickle.shares)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+
+    #[test]
+    fn test_rotation_reasons() {
+        let session =
+            OutboundGroupSession::new(SessionConfig::version_1().into(), 1000, None, None);
+        assert_eq!(session.rotation_period_ms(), DEFAULT_ROTATION_PERIOD_MS);
+        assert_eq!(session.expired_reason(1000), None);
+        assert_eq!(
+            session.expired_reason(1000 + DEFAULT_ROTATION_PERIOD_MS),
+            Some(RotationReason::Age)
+        );
+
+        let session =
+            OutboundGroupSession::new(SessionConfig::version_1().into(), 1000, Some(5000), Some(2));
+        let group_session = session.group_session();
+        group_session.encrypt("first".to_owned());
+        assert!(!session.needs_rotation(1000));
+        group_session.encrypt("second".to_owned());
+        assert_eq!(
+            session.expired_reason(1000),
+            Some(RotationReason::MessageCount)
+        );
+
+        let session =
+            OutboundGroupSession::new(SessionConfig::version_1().into(), 1000, Some(5000), None);
+        session.invalidate();
+        assert_eq!(
+            session.expired_reason(1000),
+            Some(RotationReason::Invalidated)
+        );
+    }
+
+    #[test]
+    fn test_rotation_metadata_is_pickled() {
+        let session =
+            OutboundGroupSession::new(SessionConfig::version_1().into(), 1000, Some(5000), Some(2));
+        session.group_session().encrypt("first".to_owned());
+
+        let pickle_key = [1u8; 32];
+        let unpickled = OutboundGroupSession::from_pickle_encrypted(
+            session.pickle_encrypted(pickle_key),
+            pickle_key,
+        )
+        .unwrap();
+        assert_eq!(unpickled.session_id(), session.session_id());
+        assert_eq!(unpickled.created_at_ms(), 1000);
+        assert_eq!(unpickled.rotation_period_ms(), 5000);
+        assert_eq!(unpickled.rotation_period_msgs(), 2);
+        assert_eq!(unpickled.group_session().message_index(), 1);
+        assert_eq!(unpickled.expired_reason(6000), Some(RotationReason::Age));
+    }
+}
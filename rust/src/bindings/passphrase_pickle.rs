@@ -0,0 +1,377 @@
+//! Pickles protected by a passphrase instead of a raw pickle key.
+//!
+//! The pickle key is derived from the passphrase and a random salt. The result is a JSON
+//! envelope recording the KDF and its parameters next to the pickle, so the parameters can be
+//! raised later without breaking existing pickles.
+
+use hkdf::Hkdf;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use vodozemac::{base64_decode, base64_encode};
+use zeroize::Zeroize;
+
+use super::{
+    pbkdf2, VodozemacAccount, VodozemacGroupSession, VodozemacInboundGroupSession,
+    VodozemacPkDecryption, VodozemacSession,
+};
+
+const ENVELOPE_VERSION: u32 = 1;
+const SALT_LENGTH: usize = 16;
+/// The OWASP recommendation for PBKDF2-HMAC-SHA-512.
+const DEFAULT_PBKDF2_ITERATIONS: u32 = 210_000;
+/// Fewer iterations don't protect the pickle. Envelopes claiming fewer are rejected, so a
+/// modified envelope can't make us accept a weakly protected pickle.
+const MIN_PBKDF2_ITERATIONS: u32 = 100_000;
+/// More iterations would block the caller for minutes, e.g. with a modified envelope.
+const MAX_PBKDF2_ITERATIONS: u32 = 10_000_000;
+const HKDF_INFO: &[u8] = b"VODOZEMAC_DART_PASSPHRASE_PICKLE";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PassphraseKdf {
+    Pbkdf2Sha512,
+    /// Only suitable for passphrases with high entropy, e.g. generated ones.
+    HkdfSha256,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum PassphrasePickleError {
+    #[error("The pickle is not a passphrase pickle envelope")]
+    MalformedEnvelope,
+    #[error("The passphrase pickle envelope has an unsupported version")]
+    UnsupportedVersion,
+    #[error("The number of PBKDF2 iterations is below the minimum")]
+    TooFewIterations,
+    #[error("The number of PBKDF2 iterations is above the maximum")]
+    TooManyIterations,
+    #[error("The pickle couldn't be decrypted with the passphrase")]
+    InvalidPickle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PassphraseKdfParams {
+    pub kdf: PassphraseKdf,
+    /// Ignored for HKDF.
+    pub iterations: u32,
+}
+
+impl Default for PassphraseKdfParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PassphraseKdfParams {
+    /// PBKDF2-HMAC-SHA-512 with the currently recommended number of iterations.
+    pub fn new() -> Self {
+        Self {
+            kdf: PassphraseKdf::Pbkdf2Sha512,
+            iterations: DEFAULT_PBKDF2_ITERATIONS,
+        }
+    }
+
+    fn validate(self) -> Result<Self, PassphrasePickleError> {
+        match self.kdf {
+            PassphraseKdf::Pbkdf2Sha512 if self.iterations < MIN_PBKDF2_ITERATIONS => {
+                Err(PassphrasePickleError::TooFewIterations)
+            }
+            PassphraseKdf::Pbkdf2Sha512 if self.iterations > MAX_PBKDF2_ITERATIONS => {
+                Err(PassphrasePickleError::TooManyIterations)
+            }
+            _ => Ok(self),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    kdf: PassphraseKdf,
+    iterations: u32,
+    salt: String,
+    pickle: String,
+}
+
+impl Envelope {
+    fn parse(pickle: &str) -> Result<Self, PassphrasePickleError> {
+        let envelope: Self =
+            serde_json::from_str(pickle).map_err(|_| PassphrasePickleError::MalformedEnvelope)?;
+        if envelope.version != ENVELOPE_VERSION {
+            return Err(PassphrasePickleError::UnsupportedVersion);
+        }
+        Ok(envelope)
+    }
+
+    fn params(&self) -> PassphraseKdfParams {
+        PassphraseKdfParams {
+            kdf: self.kdf,
+            iterations: self.iterations,
+        }
+    }
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], params: PassphraseKdfParams) -> [u8; 32] {
+    match params.kdf {
+        PassphraseKdf::Pbkdf2Sha512 => {
+            let mut derived = pbkdf2(passphrase.as_bytes(), salt, params.iterations)
+                .expect("HMAC accepts keys of any length");
+            let key = derived
+                .as_slice()
+                .try_into()
+                .expect("pbkdf2 derives 32 bytes");
+            derived.zeroize();
+            key
+        }
+        PassphraseKdf::HkdfSha256 => {
+            let mut key = [0u8; 32];
+            Hkdf::<Sha256>::new(Some(salt), passphrase.as_bytes())
+                .expand(HKDF_INFO, &mut key)
+                .expect("32 bytes is a valid HKDF-SHA-256 output length");
+            key
+        }
+    }
+}
+
+fn pickle_with_passphrase(
+    passphrase: &str,
+    params: PassphraseKdfParams,
+    pickle: impl FnOnce([u8; 32]) -> String,
+) -> Result<String, PassphrasePickleError> {
+    let params = params.validate()?;
+    let mut salt = [0u8; SALT_LENGTH];
+    rand::thread_rng().fill_bytes(&mut salt);
+    let mut key = derive_key(passphrase, &salt, params);
+    let pickle = pickle(key);
+    key.zeroize();
+
+    Ok(serde_json::to_string(&Envelope {
+        version: ENVELOPE_VERSION,
+        kdf: params.kdf,
+        iterations: params.iterations,
+        salt: base64_encode(salt),
+        pickle,
+    })
+    .expect("The envelope serializes to JSON"))
+}
+
+fn from_pickle_with_passphrase<T>(
+    pickle: &str,
+    passphrase: &str,
+    unpickle: impl FnOnce(String, [u8; 32]) -> anyhow::Result<T>,
+) -> Result<T, PassphrasePickleError> {
+    let envelope = Envelope::parse(pickle)?;
+    let params = envelope.params().validate()?;
+    let salt =
+        base64_decode(&envelope.salt).map_err(|_| PassphrasePickleError::MalformedEnvelope)?;
+    let mut key = derive_key(passphrase, &salt, params);
+    let value = unpickle(envelope.pickle, key);
+    key.zeroize();
+    value.map_err(|_| PassphrasePickleError::InvalidPickle)
+}
+
+/// The KDF parameters a passphrase pickle was created with, e.g. to pickle it again if they
+/// are weaker than `PassphraseKdfParams::new()`.
+pub fn passphrase_pickle_params(
+    pickle: String,
+) -> Result<PassphraseKdfParams, PassphrasePickleError> {
+    Ok(Envelope::parse(&pickle)?.params())
+}
+
+impl VodozemacAccount {
+    pub fn pickle_with_passphrase(
+        &self,
+        passphrase: String,
+        params: PassphraseKdfParams,
+    ) -> Result<String, PassphrasePickleError> {
+        pickle_with_passphrase(&passphrase, params, |key| self.pickle_encrypted(key))
+    }
+
+    pub fn from_pickle_with_passphrase(
+        pickle: String,
+        passphrase: String,
+    ) -> Result<Self, PassphrasePickleError> {
+        from_pickle_with_passphrase(&pickle, &passphrase, Self::from_pickle_encrypted)
+    }
+}
+
+impl VodozemacSession {
+    pub fn pickle_with_passphrase(
+        &self,
+        passphrase: String,
+        params: PassphraseKdfParams,
+    ) -> Result<String, PassphrasePickleError> {
+        pickle_with_passphrase(&passphrase, params, |key| self.pickle_encrypted(key))
+    }
+
+    pub fn from_pickle_with_passphrase(
+        pickle: String,
+        passphrase: String,
+    ) -> Result<Self, PassphrasePickleError> {
+        from_pickle_with_passphrase(&pickle, &passphrase, Self::from_pickle_encrypted)
+    }
+}
+
+impl VodozemacGroupSession {
+    pub fn pickle_with_passphrase(
+        &self,
+        passphrase: String,
+        params: PassphraseKdfParams,
+    ) -> Result<String, PassphrasePickleError> {
+        pickle_with_passphrase(&passphrase, params, |key| self.pickle_encrypted(key))
+    }
+
+    pub fn from_pickle_with_passphrase(
+        pickle: String,
+        passphrase: String,
+    ) -> Result<Self, PassphrasePickleError> {
+        from_pickle_with_passphrase(&pickle, &passphrase, Self::from_pickle_encrypted)
+    }
+}
+
+impl VodozemacInboundGroupSession {
+    pub fn pickle_with_passphrase(
+        &self,
+        passphrase: String,
+        params: PassphraseKdfParams,
+    ) -> Result<String, PassphrasePickleError> {
+        pickle_with_passphrase(&passphrase, params, |key| self.pickle_encrypted(key))
+    }
+
+    pub fn from_pickle_with_passphrase(
+        pickle: String,
+        passphrase: String,
+    ) -> Result<Self, PassphrasePickleError> {
+        from_pickle_with_passphrase(&pickle, &passphrase, Self::from_pickle_encrypted)
+    }
+}
+
+impl VodozemacPkDecryption {
+    /// The pickle inside the envelope is a libolm pickle, the only format of a `PkDecryption`.
+    pub fn pickle_with_passphrase(
+        &self,
+        passphrase: String,
+        params: PassphraseKdfParams,
+    ) -> Result<String, PassphrasePickleError> {
+        pickle_with_passphrase(&passphrase, params, |key| self.to_libolm_pickle(key))
+    }
+
+    pub fn from_pickle_with_passphrase(
+        pickle: String,
+        passphrase: String,
+    ) -> Result<Self, PassphrasePickleError> {
+        from_pickle_with_passphrase(&pickle, &passphrase, |pickle, key| {
+            Self::from_libolm_pickle(pickle, key.to_vec())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+
+    const PBKDF2: PassphraseKdfParams = PassphraseKdfParams {
+        kdf: PassphraseKdf::Pbkdf2Sha512,
+        iterations: MIN_PBKDF2_ITERATIONS,
+    };
+    const HKDF: PassphraseKdfParams = PassphraseKdfParams {
+        kdf: PassphraseKdf::HkdfSha256,
+        iterations: 0,
+    };
+
+    #[test]
+    fn test_passphrase_pickle_roundtrip() {
+        // PBKDF2 is slow in debug builds, so it only derives the key twice.
+        let account = VodozemacAccount::new();
+        let pickle = account
+            .pickle_with_passphrase("correct horse".to_owned(), PBKDF2)
+            .unwrap();
+        let unpickled = VodozemacAccount::from_pickle_with_passphrase(
+            pickle.clone(),
+            "correct horse".to_owned(),
+        )
+        .unwrap();
+        assert_eq!(
+            unpickled.curve25519_key().to_base64(),
+            account.curve25519_key().to_base64()
+        );
+        assert_eq!(passphrase_pickle_params(pickle).unwrap(), PBKDF2);
+
+        let pickle = account
+            .pickle_with_passphrase("correct horse".to_owned(), HKDF)
+            .unwrap();
+        assert_eq!(
+            VodozemacAccount::from_pickle_with_passphrase(pickle.clone(), "wrong".to_owned()).err(),
+            Some(PassphrasePickleError::InvalidPickle)
+        );
+        // A new salt for every pickle.
+        assert_ne!(
+            account.pickle_with_passphrase("correct horse".to_owned(), HKDF),
+            Ok(pickle)
+        );
+    }
+
+    #[test]
+    fn test_iterations_are_bounded() {
+        let account = VodozemacAccount::new();
+        let with_iterations = |iterations| PassphraseKdfParams {
+            iterations,
+            ..PassphraseKdfParams::new()
+        };
+        assert_eq!(
+            account.pickle_with_passphrase("pass".to_owned(), with_iterations(10)),
+            Err(PassphrasePickleError::TooFewIterations)
+        );
+        assert_eq!(
+            account.pickle_with_passphrase("pass".to_owned(), with_iterations(u32::MAX)),
+            Err(PassphrasePickleError::TooManyIterations)
+        );
+
+        let pickle = account
+            .pickle_with_passphrase("pass".to_owned(), HKDF)
+            .unwrap();
+        let mut envelope: serde_json::Value = serde_json::from_str(&pickle).unwrap();
+        envelope["kdf"] = "Pbkdf2Sha512".into();
+        for (iterations, error) in [
+            (1, PassphrasePickleError::TooFewIterations),
+            (u32::MAX, PassphrasePickleError::TooManyIterations),
+        ] {
+            envelope["iterations"] = iterations.into();
+            assert_eq!(
+                VodozemacAccount::from_pickle_with_passphrase(
+                    envelope.to_string(),
+                    "pass".to_owned()
+                )
+                .err(),
+                Some(error)
+            );
+        }
+        assert_eq!(
+            VodozemacAccount::from_pickle_with_passphrase("{}".to_owned(), "pass".to_owned()).err(),
+            Some(PassphrasePickleError::MalformedEnvelope)
+        );
+    }
+
+    #[test]
+    fn test_hkdf_and_pk_decryption() {
+        let pk_decryption = VodozemacPkDecryption::new();
+        let pickle = pk_decryption
+            .pickle_with_passphrase("generated".to_owned(), HKDF)
+            .unwrap();
+        let unpickled =
+            VodozemacPkDecryption::from_pickle_with_passphrase(pickle, "generated".to_owned())
+                .unwrap();
+        assert_eq!(unpickled.public_key(), pk_decryption.public_key());
+
+        let session = VodozemacGroupSession::new(SessionConfig::version_1().into()).to_inbound();
+        let pickle = session
+            .pickle_with_passphrase("generated".to_owned(), HKDF)
+            .unwrap();
+        let unpickled = VodozemacInboundGroupSession::from_pickle_with_passphrase(
+            pickle,
+            "generated".to_owned(),
+        )
+        .unwrap();
+        assert_eq!(unpickled.session_id(), session.session_id());
+    }
+}
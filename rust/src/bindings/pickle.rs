@@ -0,0 +1,55 @@
+//! Encrypted pickles for the state these bindings keep next to the vodozemac objects.
+//!
+//! The state is serialized to JSON and encrypted exactly like a vodozemac pickle, so every
+//! pickle in a store can be detected and rekeyed the same way.
+
+use serde::{de::DeserializeOwned, Serialize};
+use vodozemac::hazmat::Cipher;
+use vodozemac::{base64_decode, base64_encode};
+use zeroize::Zeroize;
+
+use super::pickle_format::PickleFormatError;
+
+pub(crate) fn encrypt_pickle<T: Serialize>(value: &T, pickle_key: &[u8; 32]) -> String {
+    let mut json = serde_json::to_vec(value).expect("Pickles are always serializable to JSON");
+    let ciphertext = Cipher::new_pickle(pickle_key).encrypt_pickle(&json);
+    json.zeroize();
+    base64_encode(ciphertext)
+}
+
+pub(crate) fn decrypt_pickle<T: DeserializeOwned>(
+    pickle: &str,
+    pickle_key: &[u8; 32],
+) -> Result<T, PickleFormatError> {
+    let decoded = base64_decode(pickle).map_err(|_| PickleFormatError::NotBase64)?;
+    let mut json = Cipher::new_pickle(pickle_key)
+        .decrypt_pickle(&decoded)
+        .map_err(|_| PickleFormatError::WrongKey)?;
+    let value = serde_json::from_slice(&json).map_err(|_| PickleFormatError::InvalidPickle);
+    json.zeroize();
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_pickle_roundtrip() {
+        let pickle_key = [7u8; 32];
+        let value = HashMap::from([("device".to_owned(), 42u64)]);
+        let pickle = encrypt_pickle(&value, &pickle_key);
+
+        let unpickled: HashMap<String, u64> = decrypt_pickle(&pickle, &pickle_key).unwrap();
+        assert_eq!(unpickled, value);
+        assert_eq!(
+            decrypt_pickle::<HashMap<String, u64>>(&pickle, &[8u8; 32]).err(),
+            Some(PickleFormatError::WrongKey)
+        );
+        assert_eq!(
+            decrypt_pickle::<Vec<u64>>(&pickle, &pickle_key).err(),
+            Some(PickleFormatError::InvalidPickle)
+        );
+    }
+}
@@ -0,0 +1,482 @@
+//! Telling apart the pickle formats a store may contain.
+//!
+//! libolm and vodozemac pickles share the same encryption, an AES-256-CBC ciphertext with a
+//! truncated HMAC-SHA-256, so both can be decrypted before knowing which one it is. A vodozemac
+//! pickle decrypts to JSON, a libolm pickle to a binary encoding starting with a big endian
+//! version. The state of these bindings is pickled like a vodozemac object, see `pickle.rs`.
+
+use serde_json::Value;
+use vodozemac::hazmat::Cipher;
+use vodozemac::megolm::{GroupSession, InboundGroupSession};
+use vodozemac::olm::{Account, Session};
+use vodozemac::pk_encryption::PkDecryption;
+use zeroize::Zeroize;
+
+use super::{
+    VodozemacAccount, VodozemacGroupSession, VodozemacInboundGroupSession, VodozemacPkDecryption,
+    VodozemacSession,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickleFormat {
+    Libolm,
+    /// Also used for the state these bindings keep next to the vodozemac objects.
+    Vodozemac,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickleObject {
+    Account,
+    Session,
+    GroupSession,
+    InboundGroupSession,
+    PkDecryption,
+    InboundRoomKey,
+    OutboundGroupSession,
+    /// Any other state of these bindings, e.g. an `OlmSessionStore`.
+    BindingsState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum PickleFormatError {
+    #[error("The pickle isn't valid base64")]
+    NotBase64,
+    #[error("The pickle key is wrong or the pickle was modified")]
+    WrongKey,
+    #[error("The pickle doesn't contain a known object")]
+    UnknownObject,
+    #[error("The pickle contains a different object")]
+    ObjectMismatch,
+    #[error("The pickle is invalid")]
+    InvalidPickle,
+}
+
+pub struct PickleInfo {
+    pub format: PickleFormat,
+    pub object: PickleObject,
+    /// The version of a libolm pickle. vodozemac pickles aren't versioned.
+    pub version: Option<u32>,
+}
+
+impl PickleInfo {
+    /// Whether the object should be pickled again to move it to the vodozemac format.
+    ///
+    /// vodozemac can only pickle a `PkDecryption` in the libolm format, so it stays there.
+    pub fn needs_repickle(&self) -> bool {
+        self.format == PickleFormat::Libolm && self.object != PickleObject::PkDecryption
+    }
+}
+
+fn has_keys(json: &Value, keys: &[&str]) -> bool {
+    keys.iter().all(|key| json.get(key).is_some())
+}
+
+fn vodozemac_object(json: &Value) -> PickleObject {
+    if has_keys(
+        json,
+        &["signing_key", "diffie_hellman_key", "one_time_keys"],
+    ) {
+        PickleObject::Account
+    } else if has_keys(
+        json,
+        &["session_keys", "sending_ratchet", "receiving_chains"],
+    ) {
+        PickleObject::Session
+    } else if has_keys(json, &["initial_ratchet", "signing_key"]) {
+        PickleObject::InboundGroupSession
+    } else if has_keys(json, &["ratchet", "signing_key"]) {
+        PickleObject::GroupSession
+    } else if has_keys(json, &["session", "info"]) {
+        PickleObject::InboundRoomKey
+    } else if has_keys(json, &["session", "rotation"]) {
+        PickleObject::OutboundGroupSession
+    } else {
+        PickleObject::BindingsState
+    }
+}
+
+/// Several libolm objects share a version, so the only way to tell them apart is to unpickle.
+fn libolm_object(pickle: &str, pickle_key: &[u8]) -> Option<PickleObject> {
+    if Account::from_libolm_pickle(pickle, pickle_key).is_ok() {
+        Some(PickleObject::Account)
+    } else if Session::from_libolm_pickle(pickle, pickle_key).is_ok() {
+        Some(PickleObject::Session)
+    } else if InboundGroupSession::from_libolm_pickle(pickle, pickle_key).is_ok() {
+        Some(PickleObject::InboundGroupSession)
+    } else if GroupSession::from_libolm_pickle(pickle, pickle_key).is_ok() {
+        Some(PickleObject::GroupSession)
+    } else if PkDecryption::from_libolm_pickle(pickle, pickle_key).is_ok() {
+        Some(PickleObject::PkDecryption)
+    } else {
+        None
+    }
+}
+
+/// Find out what a pickle contains and in which format.
+///
+/// libolm pickle keys can have any length, all other formats use 32 byte keys.
+pub fn detect_pickle(pickle: String, pickle_key: Vec<u8>) -> Result<PickleInfo, PickleFormatError> {
+    let decoded = vodozemac::base64_decode(&pickle).map_err(|_| PickleFormatError::NotBase64)?;
+    let mut plaintext = Cipher::new_pickle(&pickle_key)
+        .decrypt_pickle(&decoded)
+        .map_err(|_| PickleFormatError::WrongKey)?;
+    let info = if plaintext.first() == Some(&b'{') {
+        let json = serde_json::from_slice::<Value>(&plaintext)
+            .map_err(|_| PickleFormatError::InvalidPickle)?;
+        PickleInfo {
+            format: PickleFormat::Vodozemac,
+            object: vodozemac_object(&json),
+            version: None,
+        }
+    } else {
+        let version = plaintext
+            .get(..4)
+            .map(|v| u32::from_be_bytes(v.try_into().expect("The slice has 4 bytes")))
+            .ok_or(PickleFormatError::InvalidPickle)?;
+        PickleInfo {
+            format: PickleFormat::Libolm,
+            object: libolm_object(&pickle, &pickle_key).ok_or(PickleFormatError::UnknownObject)?,
+            version: Some(version),
+        }
+    };
+    plaintext.zeroize();
+    Ok(info)
+}
+
+/// Unpickle `object` with the constructor matching the format of the pickle.
+fn from_any_pickle<T>(
+    pickle: String,
+    pickle_key: Vec<u8>,
+    object: PickleObject,
+    from_vodozemac: impl FnOnce(String, [u8; 32]) -> anyhow::Result<T>,
+    from_libolm: impl FnOnce(String, Vec<u8>) -> anyhow::Result<T>,
+) -> Result<(T, bool), PickleFormatError> {
+    let info = detect_pickle(pickle.clone(), pickle_key.clone())?;
+    if info.object != object {
+        return Err(PickleFormatError::ObjectMismatch);
+    }
+    let value = match info.format {
+        PickleFormat::Libolm => from_libolm(pickle, pickle_key),
+        PickleFormat::Vodozemac => {
+            let key = pickle_key
+                .as_slice()
+                .try_into()
+                .map_err(|_| PickleFormatError::WrongKey)?;
+            from_vodozemac(pickle, key)
+        }
+    }
+    .map_err(|_| PickleFormatError::InvalidPickle)?;
+    Ok((value, info.needs_repickle()))
+}
+
+pub struct UnpickledAccount {
+    pub account: VodozemacAccount,
+    /// Whether the account should be pickled again to move it to the vodozemac format.
+    pub needs_repickle: bool,
+}
+
+pub struct UnpickledSession {
+    pub session: VodozemacSession,
+    pub needs_repickle: bool,
+}
+
+pub struct UnpickledGroupSession {
+    pub session: VodozemacGroupSession,
+    pub needs_repickle: bool,
+}
+
+pub struct UnpickledInboundGroupSession {
+    pub session: VodozemacInboundGroupSession,
+    pub needs_repickle: bool,
+}
+
+pub struct UnpickledPkDecryption {
+    pub pk_decryption: VodozemacPkDecryption,
+    /// Always false, a `PkDecryption` can only be pickled in the libolm format.
+    pub needs_repickle: bool,
+}
+
+impl VodozemacAccount {
+    /// Unpickle an account from either a vodozemac or a libolm pickle.
+    pub fn from_any_pickle(
+        pickle: String,
+        pickle_key: Vec<u8>,
+    ) -> Result<UnpickledAccount, PickleFormatError> {
+        let (account, needs_repickle) = from_any_pickle(
+            pickle,
+            pickle_key,
+            PickleObject::Account,
+            Self::from_pickle_encrypted,
+            Self::from_olm_pickle_encrypted,
+        )?;
+        Ok(UnpickledAccount {
+            account,
+            needs_repickle,
+        })
+    }
+}
+
+impl VodozemacSession {
+    /// Unpickle a session from either a vodozemac or a libolm pickle.
+    pub fn from_any_pickle(
+        pickle: String,
+        pickle_key: Vec<u8>,
+    ) -> Result<UnpickledSession, PickleFormatError> {
+        let (session, needs_repickle) = from_any_pickle(
+            pickle,
+            pickle_key,
+            PickleObject::Session,
+            Self::from_pickle_encrypted,
+            Self::from_olm_pickle_encrypted,
+        )?;
+        Ok(UnpickledSession {
+            session,
+            needs_repickle,
+        })
+    }
+}
+
+impl VodozemacGroupSession {
+    /// Unpickle a group session from either a vodozemac or a libolm pickle.
+    pub fn from_any_pickle(
+        pickle: String,
+        pickle_key: Vec<u8>,
+    ) -> Result<UnpickledGroupSession, PickleFormatError> {
+        let (session, needs_repickle) = from_any_pickle(
+            pickle,
+            pickle_key,
+            PickleObject::GroupSession,
+            Self::from_pickle_encrypted,
+            Self::from_olm_pickle_encrypted,
+        )?;
+        Ok(UnpickledGroupSession {
+            session,
+            needs_repickle,
+        })
+    }
+}
+
+impl VodozemacInboundGroupSession {
+    /// Unpickle an inbound group session from either a vodozemac or a libolm pickle.
+    pub fn from_any_pickle(
+        pickle: String,
+        pickle_key: Vec<u8>,
+    ) -> Result<UnpickledInboundGroupSession, PickleFormatError> {
+        let (session, needs_repickle) = from_any_pickle(
+            pickle,
+            pickle_key,
+            PickleObject::InboundGroupSession,
+            Self::from_pickle_encrypted,
+            Self::from_olm_pickle_encrypted,
+        )?;
+        Ok(UnpickledInboundGroupSession {
+            session,
+            needs_repickle,
+        })
+    }
+}
+
+impl VodozemacPkDecryption {
+    /// Unpickle a `PkDecryption`, which only exists in the libolm format.
+    pub fn from_any_pickle(
+        pickle: String,
+        pickle_key: Vec<u8>,
+    ) -> Result<UnpickledPkDecryption, PickleFormatError> {
+        let (pk_decryption, needs_repickle) = from_any_pickle(
+            pickle,
+            pickle_key,
+            PickleObject::PkDecryption,
+            |_, _| anyhow::bail!("A PkDecryption has no vodozemac pickle"),
+            Self::from_libolm_pickle,
+        )?;
+        Ok(UnpickledPkDecryption {
+            pk_decryption,
+            needs_repickle,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::room_key_info::{InboundRoomKey, RoomKeyOrigin};
+    use super::super::test_utils::{
+        libolm_inbound_group_session_pickle, libolm_session_pickle, room_key_info,
+    };
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+    use vodozemac::olm::SessionConfig as OlmSessionConfig;
+
+    fn olm_session() -> VodozemacSession {
+        let alice = VodozemacAccount::new();
+        let bob = VodozemacAccount::new();
+        bob.generate_one_time_keys(1);
+        alice.create_outbound_session(
+            OlmSessionConfig::version_1().into(),
+            bob.curve25519_key(),
+            bob.one_time_keys().pop().unwrap().key,
+        )
+    }
+
+    fn detect(pickle: String, pickle_key: &[u8]) -> (PickleFormat, PickleObject, Option<u32>) {
+        let info = detect_pickle(pickle, pickle_key.to_vec()).unwrap();
+        (info.format, info.object, info.version)
+    }
+
+    #[test]
+    fn test_detect_pickle() {
+        let key = [1u8; 32];
+        let account = VodozemacAccount::new();
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let inbound = outbound.to_inbound();
+
+        assert_eq!(
+            detect(account.pickle_encrypted(key), &key),
+            (PickleFormat::Vodozemac, PickleObject::Account, None)
+        );
+        assert_eq!(
+            detect(outbound.pickle_encrypted(key), &key),
+            (PickleFormat::Vodozemac, PickleObject::GroupSession, None)
+        );
+        assert_eq!(
+            detect(inbound.pickle_encrypted(key), &key),
+            (
+                PickleFormat::Vodozemac,
+                PickleObject::InboundGroupSession,
+                None
+            )
+        );
+
+        let libolm_account = account
+            .account
+            .read()
+            .unwrap()
+            .to_libolm_pickle(b"short key")
+            .unwrap();
+        assert_eq!(
+            detect(libolm_account, b"short key"),
+            (PickleFormat::Libolm, PickleObject::Account, Some(4))
+        );
+        assert_eq!(
+            detect(
+                libolm_session_pickle(&olm_session(), b"short key"),
+                b"short key"
+            ),
+            (PickleFormat::Libolm, PickleObject::Session, Some(1))
+        );
+        assert_eq!(
+            detect(
+                libolm_inbound_group_session_pickle(&inbound, b"short key"),
+                b"short key"
+            ),
+            (
+                PickleFormat::Libolm,
+                PickleObject::InboundGroupSession,
+                Some(2)
+            )
+        );
+        let pk_decryption = VodozemacPkDecryption::new();
+        assert_eq!(
+            detect(pk_decryption.to_libolm_pickle(key), &key),
+            (PickleFormat::Libolm, PickleObject::PkDecryption, Some(1))
+        );
+
+        let room_key = InboundRoomKey::new(
+            &inbound,
+            room_key_info("!room:example.org", RoomKeyOrigin::Direct),
+        );
+        assert_eq!(
+            detect(room_key.pickle_encrypted(key), &key),
+            (PickleFormat::Vodozemac, PickleObject::InboundRoomKey, None)
+        );
+
+        assert_eq!(
+            detect_pickle(account.pickle_encrypted(key), vec![2u8; 32]).err(),
+            Some(PickleFormatError::WrongKey)
+        );
+        assert_eq!(
+            detect_pickle(
+                libolm_session_pickle(&olm_session(), &key),
+                b"short key".to_vec()
+            )
+            .err(),
+            Some(PickleFormatError::WrongKey)
+        );
+        assert_eq!(
+            detect_pickle("not base64!".to_owned(), key.to_vec()).err(),
+            Some(PickleFormatError::NotBase64)
+        );
+    }
+
+    #[test]
+    fn test_from_any_pickle() {
+        let key = [1u8; 32];
+        let account = VodozemacAccount::new();
+
+        let unpickled =
+            VodozemacAccount::from_any_pickle(account.pickle_encrypted(key), key.to_vec()).unwrap();
+        assert!(!unpickled.needs_repickle);
+        assert_eq!(
+            unpickled.account.curve25519_key().to_base64(),
+            account.curve25519_key().to_base64()
+        );
+
+        assert_eq!(
+            VodozemacAccount::from_any_pickle(account.pickle_encrypted(key), vec![2u8; 32]).err(),
+            Some(PickleFormatError::WrongKey)
+        );
+
+        let libolm = account
+            .account
+            .read()
+            .unwrap()
+            .to_libolm_pickle(&key)
+            .unwrap();
+        let unpickled = VodozemacAccount::from_any_pickle(libolm.clone(), key.to_vec()).unwrap();
+        assert!(unpickled.needs_repickle);
+        assert_eq!(
+            unpickled.account.ed25519_key().to_base64(),
+            account.ed25519_key().to_base64()
+        );
+
+        assert_eq!(
+            VodozemacSession::from_any_pickle(libolm, key.to_vec()).err(),
+            Some(PickleFormatError::ObjectMismatch)
+        );
+
+        let session = olm_session();
+        let unpickled =
+            VodozemacSession::from_any_pickle(libolm_session_pickle(&session, &key), key.to_vec())
+                .unwrap();
+        assert!(unpickled.needs_repickle);
+        assert_eq!(unpickled.session.session_id(), session.session_id());
+
+        let inbound = VodozemacGroupSession::new(SessionConfig::version_1().into()).to_inbound();
+        let unpickled = VodozemacInboundGroupSession::from_any_pickle(
+            libolm_inbound_group_session_pickle(&inbound, &key),
+            key.to_vec(),
+        )
+        .unwrap();
+        assert!(unpickled.needs_repickle);
+        assert_eq!(unpickled.session.session_id(), inbound.session_id());
+        assert_eq!(
+            VodozemacInboundGroupSession::from_any_pickle(
+                inbound.pickle_encrypted(key),
+                vec![2u8; 32]
+            )
+            .err(),
+            Some(PickleFormatError::WrongKey)
+        );
+
+        let pk_decryption = VodozemacPkDecryption::new();
+        let unpickled = VodozemacPkDecryption::from_any_pickle(
+            pk_decryption.to_libolm_pickle(key),
+            key.to_vec(),
+        )
+        .unwrap();
+        assert!(!unpickled.needs_repickle);
+        assert_eq!(
+            unpickled.pk_decryption.public_key(),
+            pk_decryption.public_key()
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! Moving pickles to a new pickle key, e.g. after the passcode of the app changed.
+//!
+//! Pickles are only decrypted and encrypted again, never unpickled, so every object these
+//! bindings or vodozemac can pickle is supported, in every format.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread;
+
+use flutter_rust_bridge::frb;
+use vodozemac::hazmat::Cipher;
+use vodozemac::{base64_decode, base64_encode};
+use zeroize::Zeroize;
+
+use super::pickle_format::PickleFormatError;
+use crate::frb_generated::StreamSink;
+
+/// The outcome for one pickle passed to `rekey_pickles`.
+pub struct RekeyResult {
+    /// The pickle encrypted with the new key.
+    pub pickle: Option<String>,
+    pub error: Option<PickleFormatError>,
+}
+
+/// An update sent by `rekey_pickles`.
+pub struct RekeyProgress {
+    /// The number of pickles done so far.
+    pub done: u32,
+    /// Only set on the last update, in the order of the pickles passed in.
+    pub results: Option<Vec<RekeyResult>>,
+}
+
+fn rekey(
+    pickle: &str,
+    old_pickle_key: &[u8; 32],
+    new_pickle_key: &[u8; 32],
+) -> Result<String, PickleFormatError> {
+    let decoded = base64_decode(pickle).map_err(|_| PickleFormatError::NotBase64)?;
+    // libolm pickles, vodozemac pickles and the state of these bindings share the same
+    // encryption.
+    let mut plaintext = Cipher::new_pickle(old_pickle_key)
+        .decrypt_pickle(&decoded)
+        .map_err(|_| PickleFormatError::WrongKey)?;
+    let ciphertext = Cipher::new_pickle(new_pickle_key).encrypt_pickle(&plaintext);
+    plaintext.zeroize();
+    Ok(base64_encode(ciphertext))
+}
+
+fn rekey_result(pickle: &str, old_pickle_key: &[u8; 32], new_pickle_key: &[u8; 32]) -> RekeyResult {
+    match rekey(pickle, old_pickle_key, new_pickle_key) {
+        Ok(pickle) => RekeyResult {
+            pickle: Some(pickle),
+            error: None,
+        },
+        Err(error) => RekeyResult {
+            pickle: None,
+            error: Some(error),
+        },
+    }
+}
+
+/// The number of threads to rekey on. Spawning threads panics on the web, so it is always 1
+/// there.
+#[cfg(target_arch = "wasm32")]
+fn available_threads() -> usize {
+    1
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn available_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Rekey the pickles on up to `threads` threads, calling `progress` with the number of pickles
+/// done so far after each one. With a single thread, no thread is spawned.
+fn rekey_in_parallel(
+    pickles: &[String],
+    old_pickle_key: &[u8; 32],
+    new_pickle_key: &[u8; 32],
+    threads: usize,
+    progress: impl Fn(u32) + Sync,
+) -> Vec<RekeyResult> {
+    let done = AtomicU32::new(0);
+    let rekey_chunk = |chunk: &[String]| {
+        chunk
+            .iter()
+            .map(|pickle| {
+                let result = rekey_result(pickle, old_pickle_key, new_pickle_key);
+                progress(done.fetch_add(1, Ordering::Relaxed) + 1);
+                result
+            })
+            .collect::<Vec<_>>()
+    };
+    if threads <= 1 {
+        return rekey_chunk(pickles);
+    }
+
+    let chunk_size = pickles.len().div_ceil(threads).max(1);
+    thread::scope(|scope| {
+        let workers: Vec<_> = pickles
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let rekey_chunk = &rekey_chunk;
+                scope.spawn(move || rekey_chunk(chunk))
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("Rekeying doesn't panic"))
+            .collect()
+    })
+}
+
+/// Encrypt pickles of any kind and format with a new pickle key.
+///
+/// Runs off the calling isolate. The number of pickles done so far is sent to `progress` after
+/// each one, and the last update carries the results in the order of `pickles`. A pickle that
+/// fails, e.g. because it was encrypted with a different key, doesn't stop the others.
+#[frb(dart_async)]
+pub fn rekey_pickles(
+    pickles: Vec<String>,
+    old_pickle_key: [u8; 32usize],
+    new_pickle_key: [u8; 32usize],
+    progress: StreamSink<RekeyProgress>,
+) {
+    let results = rekey_in_parallel(
+        &pickles,
+        &old_pickle_key,
+        &new_pickle_key,
+        available_threads(),
+        |done| {
+            // Nobody listening to the progress is no reason to stop.
+            let _ = progress.add(RekeyProgress {
+                done,
+                results: None,
+            });
+        },
+    );
+    let _ = progress.add(RekeyProgress {
+        done: results.len() as u32,
+        results: Some(results),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::super::olm_sessions::OlmSessionStore;
+    use super::super::{VodozemacAccount, VodozemacGroupSession, VodozemacPkDecryption};
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+
+    #[test]
+    fn test_rekey_pickles() {
+        let (old_key, new_key) = ([1u8; 32], [2u8; 32]);
+        let account = VodozemacAccount::new();
+        let group_session = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let pk_decryption = VodozemacPkDecryption::new();
+        let store = OlmSessionStore::new();
+        store.set_max_sessions_per_device(3);
+
+        let pickles = vec![
+            account.pickle_encrypted(old_key),
+            group_session.pickle_encrypted([3u8; 32]),
+            pk_decryption.to_libolm_pickle(old_key),
+            store.pickle_encrypted(old_key),
+            "not base64!".to_owned(),
+        ];
+        let reported = Mutex::new(vec![]);
+        let results = rekey_in_parallel(&pickles, &old_key, &new_key, 3, |done| {
+            reported.lock().unwrap().push(done)
+        });
+
+        let mut reported = reported.into_inner().unwrap();
+        reported.sort();
+        assert_eq!(reported, vec![1, 2, 3, 4, 5]);
+
+        let unpickled =
+            VodozemacAccount::from_pickle_encrypted(results[0].pickle.clone().unwrap(), new_key)
+                .unwrap();
+        assert_eq!(
+            unpickled.curve25519_key().to_base64(),
+            account.curve25519_key().to_base64()
+        );
+        assert_eq!(results[1].error, Some(PickleFormatError::WrongKey));
+        let unpickled = VodozemacPkDecryption::from_libolm_pickle(
+            results[2].pickle.clone().unwrap(),
+            new_key.to_vec(),
+        )
+        .unwrap();
+        assert_eq!(unpickled.public_key(), pk_decryption.public_key());
+        let unpickled =
+            OlmSessionStore::from_pickle_encrypted(results[3].pickle.clone().unwrap(), new_key)
+                .unwrap();
+        assert_eq!(unpickled.max_sessions_per_device(), 3);
+        assert_eq!(results[4].error, Some(PickleFormatError::NotBase64));
+    }
+
+    #[test]
+    fn test_rekey_pickles_without_threads() {
+        let (old_key, new_key) = ([1u8; 32], [2u8; 32]);
+        let accounts = [VodozemacAccount::new(), VodozemacAccount::new()];
+        let pickles = vec![
+            accounts[0].pickle_encrypted(old_key),
+            "not base64!".to_owned(),
+            accounts[1].pickle_encrypted(old_key),
+        ];
+
+        let caller = thread::current().id();
+        let reported = Mutex::new(vec![]);
+        let results = rekey_in_parallel(&pickles, &old_key, &new_key, 1, |done| {
+            // Nothing is spawned, everything runs on this thread, in order.
+            assert_eq!(thread::current().id(), caller);
+            reported.lock().unwrap().push(done)
+        });
+
+        assert_eq!(reported.into_inner().unwrap(), vec![1, 2, 3]);
+        assert_eq!(results[1].error, Some(PickleFormatError::NotBase64));
+        for (result, account) in [&results[0], &results[2]].into_iter().zip(&accounts) {
+            let unpickled =
+                VodozemacAccount::from_pickle_encrypted(result.pickle.clone().unwrap(), new_key)
+                    .unwrap();
+            assert_eq!(
+                unpickled.curve25519_key().to_base64(),
+                account.curve25519_key().to_base64()
+            );
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Detection of replayed Megolm messages.
+//! https://spec.matrix.org/v1.16/client-server-api/#mmegolmv1aes-sha2
+//!
+//! A homeserver could resend an old ciphertext as a new event. Since the ciphertext decrypts
+//! fine, the only way to notice is to remember which event used which message index of a
+//! session and reject a different event reusing it.
+
+use std::collections::{hash_map::Entry, HashMap};
+
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+use super::pickle::{decrypt_pickle, encrypt_pickle};
+use super::room_event::{decrypt_room_event, RoomEventDecryptionResult, RoomEventError};
+use super::{RustOpaqueNom, RwLock, VodozemacInboundGroupSession};
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct SeenEvent {
+    event_id: String,
+    origin_server_ts: u64,
+}
+
+/// The events seen per message index, keyed by the session id.
+#[frb(ignore)]
+#[derive(Default, Serialize, Deserialize)]
+pub struct ReplayState {
+    sessions: HashMap<String, HashMap<u32, SeenEvent>>,
+}
+
+/// Remembers which event used which message index of an inbound group session.
+pub struct MegolmReplayGuard {
+    pub state: RustOpaqueNom<RwLock<ReplayState>>,
+}
+
+impl Default for MegolmReplayGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<ReplayState> for MegolmReplayGuard {
+    fn from(state: ReplayState) -> Self {
+        Self {
+            state: RustOpaqueNom::new(RwLock::new(state)),
+        }
+    }
+}
+
+impl MegolmReplayGuard {
+    pub fn new() -> Self {
+        ReplayState::default().into()
+    }
+
+    /// Record that an event used a message index of a session.
+    ///
+    /// Returns false, without recording anything, if a different event already used the
+    /// index. Decrypting the same event again is fine.
+    pub fn record(
+        &self,
+        session_id: String,
+        message_index: u32,
+        event_id: String,
+        origin_server_ts: u64,
+    ) -> bool {
+        let seen = SeenEvent {
+            event_id,
+            origin_server_ts,
+        };
+        let mut state = self.state.write().expect("Failed to write guard");
+        match state
+            .sessions
+            .entry(session_id)
+            .or_default()
+            .entry(message_index)
+        {
+            Entry::Occupied(entry) => *entry.get() == seen,
+            Entry::Vacant(entry) => {
+                entry.insert(seen);
+                true
+            }
+        }
+    }
+
+    /// Forget everything recorded for a session, e.g. after the session was deleted.
+    pub fn forget_session(&self, session_id: String) {
+        self.state
+            .write()
+            .expect("Failed to write guard")
+            .sessions
+            .remove(&session_id);
+    }
+
+    /// Forget the events recorded for the message indices of a session before `index`, e.g.
+    /// after `discard_before(index)` made those messages undecryptable anyway.
+    pub fn forget_before(&self, session_id: String, index: u32) {
+        let mut state = self.state.write().expect("Failed to write guard");
+        if let Some(seen) = state.sessions.get_mut(&session_id) {
+            seen.retain(|message_index, _| *message_index >= index);
+            if seen.is_empty() {
+                state.sessions.remove(&session_id);
+            }
+        }
+    }
+
+    /// The highest message index of a session used by an event sent before `cutoff_ts`.
+    pub(crate) fn last_index_before(&self, session_id: &str, cutoff_ts: u64) -> Option<u32> {
+        self.state
+            .read()
+            .expect("Failed to read guard")
+            .sessions
+            .get(session_id)?
+            .iter()
+            .filter(|(_, seen)| seen.origin_server_ts < cutoff_ts)
+            .map(|(index, _)| *index)
+            .max()
+    }
+
+    pub fn pickle_encrypted(&self, pickle_key: [u8; 32usize]) -> String {
+        encrypt_pickle(
+            &*self.state.read().expect("Failed to read guard"),
+            &pickle_key,
+        )
+    }
+
+    pub fn from_pickle_encrypted(
+        pickle: String,
+        pickle_key: [u8; 32usize],
+    ) -> anyhow::Result<Self> {
+        Ok(decrypt_pickle::<ReplayState>(&pickle, &pickle_key)?.into())
+    }
+}
+
+/// Like `decrypt_room_event`, but fail with `RoomEventError::Replayed` if the message index was
+/// already used by a different event.
+pub fn decrypt_room_event_checked(
+    session: &VodozemacInboundGroupSession,
+    guard: &MegolmReplayGuard,
+    room_id: String,
+    event_id: String,
+    origin_server_ts: u64,
+    encrypted_content: String,
+) -> Result<RoomEventDecryptionResult, RoomEventError> {
+    let result = decrypt_room_event(session, room_id, encrypted_content)?;
+    if guard.record(
+        session.session_id(),
+        result.message_index,
+        event_id,
+        origin_server_ts,
+    ) {
+        Ok(result)
+    } else {
+        Err(RoomEventError::Replayed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::room_event::encrypt_room_event;
+    use super::super::{VodozemacAccount, VodozemacGroupSession};
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+
+    const ROOM: &str = "!room:example.org";
+
+    #[test]
+    fn test_replayed_event_is_rejected() {
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let inbound = outbound.to_inbound();
+        let guard = MegolmReplayGuard::new();
+        let encrypted = encrypt_room_event(
+            &outbound,
+            &VodozemacAccount::new().curve25519_key(),
+            "DEVICE".to_owned(),
+            ROOM.to_owned(),
+            "m.room.message".to_owned(),
+            "{}".to_owned(),
+        )
+        .unwrap();
+        let decrypt = |guard: &MegolmReplayGuard, event_id: &str, ts: u64| {
+            decrypt_room_event_checked(
+                &inbound,
+                guard,
+                ROOM.to_owned(),
+                event_id.to_owned(),
+                ts,
+                encrypted.clone(),
+            )
+            .map(|r| r.message_index)
+        };
+
+        assert_eq!(decrypt(&guard, "$first", 1000), Ok(0));
+        // Decrypting the same event again, e.g. after a restart, is not a replay.
+        assert_eq!(decrypt(&guard, "$first", 1000), Ok(0));
+        assert_eq!(
+            decrypt(&guard, "$second", 1000),
+            Err(RoomEventError::Replayed)
+        );
+        assert_eq!(
+            decrypt(&guard, "$first", 2000),
+            Err(RoomEventError::Replayed)
+        );
+
+        let pickle_key = [9u8; 32];
+        let guard = MegolmReplayGuard::from_pickle_encrypted(
+            guard.pickle_encrypted(pickle_key),
+            pickle_key,
+        )
+        .unwrap();
+        assert_eq!(
+            decrypt(&guard, "$second", 1000),
+            Err(RoomEventError::Replayed)
+        );
+        guard.forget_session(outbound.session_id());
+        assert_eq!(decrypt(&guard, "$second", 1000), Ok(0));
+    }
+
+    #[test]
+    fn test_forget_before() {
+        let guard = MegolmReplayGuard::new();
+        for index in 0..3 {
+            assert!(guard.record("session".to_owned(), index, format!("${index}"), 1000));
+        }
+
+        guard.forget_before("session".to_owned(), 2);
+        assert_eq!(guard.last_index_before("session", 2000), Some(2));
+        assert!(guard.record("session".to_owned(), 1, "$other".to_owned(), 1000));
+        assert!(!guard.record("session".to_owned(), 2, "$other".to_owned(), 1000));
+
+        guard.forget_before("session".to_owned(), 3);
+        assert!(guard.state.read().unwrap().sessions.is_empty());
+    }
+}
@@ -0,0 +1,273 @@
+//! Forward secrecy for stored Megolm sessions.
+//!
+//! An inbound group session can decrypt every message from its first known index on. Advancing
+//! the ratchet of a stored session makes older messages undecryptable, even if the store leaks.
+
+use super::pickle_format::PickleFormatError;
+use super::replay::MegolmReplayGuard;
+use super::room_key_info::InboundRoomKey;
+use super::VodozemacInboundGroupSession;
+
+/// How long messages of a room stay decryptable.
+pub struct RoomRetention {
+    pub room_id: String,
+    pub max_age_ms: u64,
+}
+
+/// A pickled `InboundRoomKey` after applying the retention policy.
+pub struct RetainedRoomKey {
+    pub session_id: String,
+    pub pickle: String,
+    pub first_known_index: u32,
+    /// Whether older messages were discarded, i.e. the pickle changed.
+    pub discarded: bool,
+}
+
+/// The outcome for one pickle passed to `apply_room_retention`.
+pub struct RetentionResult {
+    pub room_key: Option<RetainedRoomKey>,
+    pub error: Option<PickleFormatError>,
+}
+
+impl VodozemacInboundGroupSession {
+    /// Permanently forget the message keys before `index`, so only messages from `index` on can
+    /// be decrypted.
+    ///
+    /// Returns false if the session doesn't know any message before `index`.
+    pub fn discard_before(&self, index: u32) -> bool {
+        self.session
+            .write()
+            .expect("Failed to write session")
+            .advance_to(index)
+    }
+}
+
+fn apply_retention(
+    pickle: String,
+    pickle_key: &[u8; 32],
+    policies: &[RoomRetention],
+    guard: &MegolmReplayGuard,
+    now_ms: u64,
+) -> Result<RetainedRoomKey, PickleFormatError> {
+    let room_key = InboundRoomKey::unpickle(&pickle, pickle_key)?;
+    let session_id = room_key.session_id();
+    let room_id = room_key.info().room_id;
+
+    let discarded = policies
+        .iter()
+        .find(|policy| policy.room_id == room_id)
+        .and_then(|policy| {
+            guard.last_index_before(&session_id, now_ms.saturating_sub(policy.max_age_ms))
+        })
+        .is_some_and(|index| {
+            let index = index.saturating_add(1);
+            // The discarded messages can't be decrypted, so neither replayed, anymore.
+            guard.forget_before(session_id.clone(), index);
+            room_key.inbound_group_session().discard_before(index)
+        });
+
+    Ok(RetainedRoomKey {
+        session_id,
+        pickle: if discarded {
+            room_key.pickle_encrypted(*pickle_key)
+        } else {
+            pickle
+        },
+        first_known_index: room_key.first_known_index(),
+        discarded,
+    })
+}
+
+/// Discard the messages older than the retention of their room from pickled `InboundRoomKey`s.
+///
+/// The message indices of the sessions are mapped to time with the events recorded in `guard`,
+/// i.e. the events decrypted through `decrypt_room_event_checked`. A message is only discarded
+/// together with a later recorded message that is older than the retention, so old messages
+/// that were never decrypted through the guard, or only followed by such messages, are kept.
+/// The events recorded in `guard` for the discarded messages are forgotten. Sessions of rooms
+/// without a policy are returned unchanged. The results are in the order of `pickles`, a
+/// pickle that can't be unpickled doesn't stop the others.
+pub fn apply_room_retention(
+    pickles: Vec<String>,
+    pickle_key: [u8; 32usize],
+    policies: Vec<RoomRetention>,
+    guard: &MegolmReplayGuard,
+    now_ms: u64,
+) -> Vec<RetentionResult> {
+    pickles
+        .into_iter()
+        .map(
+            |pickle| match apply_retention(pickle, &pickle_key, &policies, guard, now_ms) {
+                Ok(room_key) => RetentionResult {
+                    room_key: Some(room_key),
+                    error: None,
+                },
+                Err(error) => RetentionResult {
+                    room_key: None,
+                    error: Some(error),
+                },
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::replay::decrypt_room_event_checked;
+    use super::super::room_event::{decrypt_room_event, encrypt_room_event};
+    use super::super::room_key_info::RoomKeyOrigin;
+    use super::super::test_utils::room_key_info;
+    use super::super::{VodozemacAccount, VodozemacGroupSession};
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+
+    fn room_key(session: &VodozemacGroupSession, room_id: &str) -> InboundRoomKey {
+        InboundRoomKey::new(
+            &session.to_inbound(),
+            room_key_info(room_id, RoomKeyOrigin::Direct),
+        )
+    }
+
+    fn encrypt(outbound: &VodozemacGroupSession, room_id: &str) -> String {
+        encrypt_room_event(
+            outbound,
+            &VodozemacAccount::new().curve25519_key(),
+            "DEVICE".to_owned(),
+            room_id.to_owned(),
+            "m.room.message".to_owned(),
+            "{}".to_owned(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_discard_before() {
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let inbound = outbound.to_inbound();
+        let first = outbound.encrypt("first".to_owned());
+        let second = outbound.encrypt("second".to_owned());
+
+        assert!(inbound.discard_before(1));
+        assert!(!inbound.discard_before(1));
+        assert_eq!(inbound.first_known_index(), 1);
+        assert!(inbound.decrypt(first).is_err());
+        assert_eq!(inbound.decrypt(second).unwrap().0, "second");
+    }
+
+    #[test]
+    fn test_room_retention() {
+        let guard = MegolmReplayGuard::new();
+        let pickle_key = [3u8; 32];
+        let short = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let unlimited = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let keys = [
+            room_key(&short, "!short:a"),
+            room_key(&unlimited, "!long:a"),
+        ];
+
+        for (room_key, outbound, room_id) in [
+            (&keys[0], &short, "!short:a"),
+            (&keys[1], &unlimited, "!long:a"),
+        ] {
+            for (i, ts) in [1_000u64, 5_000, 9_000].into_iter().enumerate() {
+                let encrypted = encrypt(outbound, room_id);
+                decrypt_room_event_checked(
+                    &room_key.inbound_group_session(),
+                    &guard,
+                    room_id.to_owned(),
+                    format!("${i}"),
+                    ts,
+                    encrypted,
+                )
+                .unwrap();
+            }
+        }
+
+        let pickles: Vec<_> = keys
+            .iter()
+            .map(|k| k.pickle_encrypted(pickle_key))
+            .collect();
+        let mut batch = pickles.clone();
+        batch.insert(1, keys[1].pickle_encrypted([4u8; 32]));
+        let results = apply_room_retention(
+            batch,
+            pickle_key,
+            vec![RoomRetention {
+                room_id: "!short:a".to_owned(),
+                max_age_ms: 5_000,
+            }],
+            &guard,
+            10_000,
+        );
+
+        // The pickle encrypted with another key doesn't stop the others.
+        assert_eq!(results.len(), 3);
+        assert!(results[1].room_key.is_none());
+        assert_eq!(results[1].error, Some(PickleFormatError::WrongKey));
+        let retained: Vec<_> = results
+            .into_iter()
+            .filter_map(|result| result.room_key)
+            .collect();
+
+        // Only the message sent at 1000 is older than 5 seconds.
+        assert!(retained[0].discarded);
+        assert_eq!(retained[0].session_id, short.session_id());
+        assert_eq!(retained[0].first_known_index, 1);
+        let unpickled =
+            InboundRoomKey::from_pickle_encrypted(retained[0].pickle.clone(), pickle_key).unwrap();
+        assert_eq!(unpickled.first_known_index(), 1);
+
+        // The guard forgot the discarded message, but still knows the others.
+        assert_eq!(guard.last_index_before(&short.session_id(), 2_000), None);
+        assert_eq!(
+            guard.last_index_before(&short.session_id(), 10_000),
+            Some(2)
+        );
+
+        assert!(!retained[1].discarded);
+        assert_eq!(retained[1].first_known_index, 0);
+        assert_eq!(retained[1].pickle, pickles[1]);
+    }
+
+    #[test]
+    fn test_messages_unknown_to_the_guard_are_kept() {
+        let guard = MegolmReplayGuard::new();
+        let pickle_key = [3u8; 32];
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let room_key = room_key(&outbound, "!room:a");
+
+        // The first two messages are old, but were decrypted without the guard.
+        for _ in 0..2 {
+            let encrypted = encrypt(&outbound, "!room:a");
+            decrypt_room_event(
+                &room_key.inbound_group_session(),
+                "!room:a".to_owned(),
+                encrypted,
+            )
+            .unwrap();
+        }
+        decrypt_room_event_checked(
+            &room_key.inbound_group_session(),
+            &guard,
+            "!room:a".to_owned(),
+            "$recent".to_owned(),
+            9_000,
+            encrypt(&outbound, "!room:a"),
+        )
+        .unwrap();
+
+        let results = apply_room_retention(
+            vec![room_key.pickle_encrypted(pickle_key)],
+            pickle_key,
+            vec![RoomRetention {
+                room_id: "!room:a".to_owned(),
+                max_age_ms: 5_000,
+            }],
+            &guard,
+            10_000,
+        );
+        let retained = results[0].room_key.as_ref().unwrap();
+        assert!(!retained.discarded);
+        assert_eq!(retained.first_known_index, 0);
+    }
+}
@@ -0,0 +1,260 @@
+//! Encryption of room events with Megolm.
+//! https://spec.matrix.org/v1.16/client-server-api/#mmegolmv1aes-sha2
+
+use serde_json::{json, Value};
+use vodozemac::megolm::MegolmMessage;
+
+use super::{VodozemacCurve25519PublicKey, VodozemacGroupSession, VodozemacInboundGroupSession};
+
+pub(crate) const MEGOLM_V1_ALGORITHM: &str = "m.megolm.v1.aes-sha2";
+pub(crate) const MEGOLM_V2_ALGORITHM: &str = "m.megolm.v2.aes-sha2";
+
+/// The length of the MAC of Megolm v1 messages, v2 messages carry the full 32 bytes.
+const TRUNCATED_MAC_LENGTH: usize = 8;
+
+/// Why an `m.room.encrypted` room event couldn't be decrypted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum RoomEventError {
+    #[error("The encrypted content is not a valid m.room.encrypted content")]
+    MalformedEvent,
+    #[error("The event is not encrypted with a supported Megolm algorithm")]
+    UnknownAlgorithm,
+    #[error("The event was encrypted with a different session")]
+    SessionMismatch,
+    #[error("The session couldn't decrypt the event")]
+    DecryptionFailed,
+    #[error("The decrypted payload is not a valid event")]
+    MalformedPayload,
+    #[error("The decrypted event was sent to a different room")]
+    RoomMismatch,
+    #[error("The message index was already used by a different event")]
+    Replayed,
+    #[error("The event's algorithm doesn't match the version of the session")]
+    AlgorithmMismatch,
+}
+
+/// A decrypted room event.
+pub struct RoomEventDecryptionResult {
+    pub event_type: String,
+    /// The decrypted content as JSON.
+    pub content: String,
+    pub message_index: u32,
+}
+
+pub(crate) fn megolm_algorithm(version: u8) -> &'static str {
+    match version {
+        1 => MEGOLM_V1_ALGORITHM,
+        _ => MEGOLM_V2_ALGORITHM,
+    }
+}
+
+/// Encrypt an event for a room and return the `m.room.encrypted` content to send.
+///
+/// `sender_key` is the Curve25519 key of the sending account and `device_id` its device. Both
+/// are deprecated in the content, but older clients still need them to find the session.
+pub fn encrypt_room_event(
+    session: &VodozemacGroupSession,
+    sender_key: &VodozemacCurve25519PublicKey,
+    device_id: String,
+    room_id: String,
+    event_type: String,
+    content: String,
+) -> anyhow::Result<String> {
+    let content: Value = serde_json::from_str(&content)?;
+    let payload = json!({
+        "type": event_type,
+        "content": content,
+        "room_id": room_id,
+    });
+
+    let mut session = session.session.write().expect("Failed to write session");
+    let algorithm = megolm_algorithm(session.session_config().version());
+    let ciphertext = session.encrypt(payload.to_string()).to_base64();
+    Ok(json!({
+        "algorithm": algorithm,
+        "sender_key": sender_key.to_base64(),
+        "device_id": device_id,
+        "session_id": session.session_id(),
+        "ciphertext": ciphertext,
+    })
+    .to_string())
+}
+
+/// Decrypt the content of an `m.room.encrypted` event received in `room_id`.
+///
+/// The `room_id` inside the encrypted payload has to match the room the event was received in,
+/// otherwise the event could have been replayed from another room.
+/// The `algorithm` of the content has to match the version of the session, so a v2 session
+/// can't be used to decrypt content claiming v1 and the other way around.
+pub fn decrypt_room_event(
+    session: &VodozemacInboundGroupSession,
+    room_id: String,
+    encrypted_content: String,
+) -> Result<RoomEventDecryptionResult, RoomEventError> {
+    let content: Value =
+        serde_json::from_str(&encrypted_content).map_err(|_| RoomEventError::MalformedEvent)?;
+    if !content.is_object() {
+        return Err(RoomEventError::MalformedEvent);
+    }
+
+    let algorithm = content["algorithm"].as_str().unwrap_or_default();
+    if ![MEGOLM_V1_ALGORITHM, MEGOLM_V2_ALGORITHM].contains(&algorithm) {
+        return Err(RoomEventError::UnknownAlgorithm);
+    }
+
+    let mut session = session.session.write().expect("Failed to write session");
+    if content["session_id"] != session.session_id() {
+        return Err(RoomEventError::SessionMismatch);
+    }
+    let message = content["ciphertext"]
+        .as_str()
+        .and_then(|c| MegolmMessage::from_base64(c).ok())
+        .ok_or(RoomEventError::MalformedEvent)?;
+
+    let decrypted = session
+        .decrypt(&message)
+        .map_err(|_| RoomEventError::DecryptionFailed)?;
+    // The inbound session doesn't expose its version, but only accepts the truncated MAC of
+    // v1 messages if it's a v1 session and the full MAC of v2 messages otherwise.
+    let version = if message.mac().len() == TRUNCATED_MAC_LENGTH {
+        1
+    } else {
+        2
+    };
+    if algorithm != megolm_algorithm(version) {
+        return Err(RoomEventError::AlgorithmMismatch);
+    }
+    let payload: Value = serde_json::from_slice(&decrypted.plaintext)
+        .map_err(|_| RoomEventError::MalformedPayload)?;
+    let (Some(event_type), Some(payload_content)) =
+        (payload["type"].as_str(), payload.get("content"))
+    else {
+        return Err(RoomEventError::MalformedPayload);
+    };
+    if !payload_content.is_object() {
+        return Err(RoomEventError::MalformedPayload);
+    }
+    if payload["room_id"] != room_id {
+        return Err(RoomEventError::RoomMismatch);
+    }
+
+    Ok(RoomEventDecryptionResult {
+        event_type: event_type.to_owned(),
+        content: payload_content.to_string(),
+        message_index: decrypted.message_index,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::VodozemacAccount;
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+
+    const ROOM: &str = "!room:example.org";
+
+    fn encrypt(session: &VodozemacGroupSession, content: &str) -> String {
+        encrypt_as(&VodozemacAccount::new(), session, content)
+    }
+
+    fn encrypt_as(
+        account: &VodozemacAccount,
+        session: &VodozemacGroupSession,
+        content: &str,
+    ) -> String {
+        encrypt_room_event(
+            session,
+            &account.curve25519_key(),
+            "DEVICE".to_owned(),
+            ROOM.to_owned(),
+            "m.room.message".to_owned(),
+            content.to_owned(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_room_event_roundtrip() {
+        let account = VodozemacAccount::new();
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let inbound = outbound.to_inbound();
+
+        for index in 0..2 {
+            let encrypted = encrypt_as(&account, &outbound, r#"{"msgtype":"m.text","body":"Hi"}"#);
+            let parsed: Value = serde_json::from_str(&encrypted).unwrap();
+            assert_eq!(parsed["algorithm"], MEGOLM_V1_ALGORITHM);
+            assert_eq!(parsed["sender_key"], account.curve25519_key().to_base64());
+            assert_eq!(parsed["device_id"], "DEVICE");
+            assert_eq!(parsed["session_id"], outbound.session_id());
+
+            let decrypted = decrypt_room_event(&inbound, ROOM.to_owned(), encrypted).unwrap();
+            assert_eq!(decrypted.event_type, "m.room.message");
+            assert_eq!(decrypted.message_index, index);
+            let content: Value = serde_json::from_str(&decrypted.content).unwrap();
+            assert_eq!(content["body"], "Hi");
+        }
+    }
+
+    #[test]
+    fn test_room_event_errors() {
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let inbound = outbound.to_inbound();
+        let decrypt = |room: &str, content: String| {
+            decrypt_room_event(&inbound, room.to_owned(), content).err()
+        };
+
+        let encrypted = encrypt(&outbound, "{}");
+        assert_eq!(
+            decrypt("!other:example.org", encrypted),
+            Some(RoomEventError::RoomMismatch)
+        );
+
+        let mut encrypted: Value = serde_json::from_str(&encrypt(&outbound, "{}")).unwrap();
+        encrypted["algorithm"] = json!("m.olm.v1.curve25519-aes-sha2");
+        assert_eq!(
+            decrypt(ROOM, encrypted.to_string()),
+            Some(RoomEventError::UnknownAlgorithm)
+        );
+
+        let other = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        assert_eq!(
+            decrypt(ROOM, encrypt(&other, "{}")),
+            Some(RoomEventError::SessionMismatch)
+        );
+
+        let ciphertext = outbound.encrypt("not json".to_owned());
+        let encrypted = json!({
+            "algorithm": MEGOLM_V1_ALGORITHM,
+            "session_id": outbound.session_id(),
+            "ciphertext": ciphertext,
+        });
+        assert_eq!(
+            decrypt(ROOM, encrypted.to_string()),
+            Some(RoomEventError::MalformedPayload)
+        );
+        assert_eq!(
+            decrypt(ROOM, "[]".to_owned()),
+            Some(RoomEventError::MalformedEvent)
+        );
+    }
+
+    #[test]
+    fn test_algorithm_must_match_the_session_version() {
+        for (config, other_algorithm) in [
+            (SessionConfig::version_1(), MEGOLM_V2_ALGORITHM),
+            (SessionConfig::version_2(), MEGOLM_V1_ALGORITHM),
+        ] {
+            let outbound = VodozemacGroupSession::new(config.into());
+            let inbound = outbound.to_inbound();
+
+            let encrypted = encrypt(&outbound, "{}");
+            let mut downgraded: Value = serde_json::from_str(&encrypted).unwrap();
+            downgraded["algorithm"] = json!(other_algorithm);
+            assert_eq!(
+                decrypt_room_event(&inbound, ROOM.to_owned(), downgraded.to_string()).err(),
+                Some(RoomEventError::AlgorithmMismatch)
+            );
+            assert!(decrypt_room_event(&inbound, ROOM.to_owned(), encrypted).is_ok());
+        }
+    }
+}
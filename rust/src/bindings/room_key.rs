@@ -0,0 +1,234 @@
+//! Sharing Megolm sessions with `m.room_key` to-device events.
+//! https://spec.matrix.org/v1.16/client-server-api/#mroom_key
+
+use serde_json::{json, Value};
+use vodozemac::megolm::{InboundGroupSession, SessionConfig, SessionKey};
+
+use super::device_keys::DeviceKeys;
+use super::megolm_signing::SessionKeySource;
+use super::room_event::{megolm_algorithm, MEGOLM_V1_ALGORITHM, MEGOLM_V2_ALGORITHM};
+use super::room_key_info::{InboundRoomKey, RoomKeyInfo, RoomKeyOrigin};
+use super::to_device::OlmPayload;
+use super::{VodozemacCurve25519PublicKey, VodozemacGroupSession};
+
+pub(crate) const ROOM_KEY_EVENT_TYPE: &str = "m.room_key";
+
+/// Why a received `m.room_key` was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum RoomKeyError {
+    #[error("The payload is not a room key event of the expected type")]
+    NotARoomKey,
+    #[error("The content is not a valid m.room_key content")]
+    MalformedContent,
+    #[error("The room key is not for a supported Megolm algorithm")]
+    UnknownAlgorithm,
+    #[error("The Olm session the key was received over doesn't belong to the sending device")]
+    SenderKeyMismatch,
+    #[error("The Ed25519 key claimed in the payload doesn't belong to the sending device")]
+    ClaimedKeyMismatch,
+    #[error("The session key is not a valid Megolm session key")]
+    InvalidSessionKey,
+    #[error("The session key doesn't belong to the session id of the content")]
+    SessionIdMismatch,
+}
+
+/// Build the `m.room_key` content to send the current state of `session` to the room members.
+pub fn room_key_content(
+    session: &VodozemacGroupSession,
+    room_id: String,
+    shared_history: bool,
+) -> String {
+    let session = session.session.read().expect("Failed to read session");
+    json!({
+        "algorithm": megolm_algorithm(session.session_config().version()),
+        "room_id": room_id,
+        "session_id": session.session_id(),
+        "session_key": session.session_key().to_base64(),
+        "shared_history": shared_history,
+    })
+    .to_string()
+}
+
+pub(crate) fn megolm_config(algorithm: &str) -> Option<SessionConfig> {
+    match algorithm {
+        MEGOLM_V1_ALGORITHM => Some(SessionConfig::version_1()),
+        MEGOLM_V2_ALGORITHM => Some(SessionConfig::version_2()),
+        _ => None,
+    }
+}
+
+/// Create the inbound session from a decrypted `m.room_key` to-device event.
+///
+/// `payload` is the validated Olm payload, `sender_key` the Curve25519 key of the Olm session
+/// that decrypted it and `sender_device` the device of the sender. Both keys of the payload
+/// have to belong to that device, otherwise the key could have been sent by anyone claiming
+/// the device's Ed25519 key.
+pub fn receive_room_key(
+    payload: &OlmPayload,
+    sender_key: &VodozemacCurve25519PublicKey,
+    sender_device: &DeviceKeys,
+) -> Result<InboundRoomKey, RoomKeyError> {
+    let content = checked_content(payload, ROOM_KEY_EVENT_TYPE, sender_key, sender_device)?;
+    let (Some(algorithm), Some(room_id), Some(session_id), Some(session_key)) = (
+        content["algorithm"].as_str(),
+        content["room_id"].as_str(),
+        content["session_id"].as_str(),
+        content["session_key"].as_str(),
+    ) else {
+        return Err(RoomKeyError::MalformedContent);
+    };
+    let config = megolm_config(algorithm).ok_or(RoomKeyError::UnknownAlgorithm)?;
+    let session_key =
+        SessionKey::from_base64(session_key).map_err(|_| RoomKeyError::InvalidSessionKey)?;
+
+    let session = InboundGroupSession::new(&session_key, config);
+    if session.session_id() != session_id {
+        return Err(RoomKeyError::SessionIdMismatch);
+    }
+
+    Ok(InboundRoomKey::from_session(
+        session,
+        SessionKeySource::SessionKey,
+        RoomKeyInfo {
+            algorithm: algorithm.to_owned(),
+            room_id: room_id.to_owned(),
+            sender_key: sender_key.to_base64(),
+            sender_claimed_ed25519: payload.sender_claimed_ed25519.to_base64(),
+            forwarding_curve25519_key_chain: vec![],
+            origin: RoomKeyOrigin::Direct,
+            shared_history: content["shared_history"].as_bool().unwrap_or_default(),
+            backed_up: false,
+        },
+    ))
+}
+
+/// Check that a key sharing payload of type `event_type` was sent over an Olm session with
+/// `sender_device` and return its content.
+pub(crate) fn checked_content(
+    payload: &OlmPayload,
+    event_type: &str,
+    sender_key: &VodozemacCurve25519PublicKey,
+    sender_device: &DeviceKeys,
+) -> Result<Value, RoomKeyError> {
+    if payload.event_type != event_type {
+        return Err(RoomKeyError::NotARoomKey);
+    }
+    if *sender_key.key != *sender_device.curve25519.key {
+        return Err(RoomKeyError::SenderKeyMismatch);
+    }
+    if *payload.sender_claimed_ed25519.key != *sender_device.ed25519.key {
+        return Err(RoomKeyError::ClaimedKeyMismatch);
+    }
+
+    let content: Value =
+        serde_json::from_str(&payload.content).map_err(|_| RoomKeyError::MalformedContent)?;
+    if content.is_object() {
+        Ok(content)
+    } else {
+        Err(RoomKeyError::MalformedContent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::device_keys;
+    use super::*;
+    use vodozemac::olm::Account;
+
+    fn payload(account: &Account, event_type: &str, content: String) -> OlmPayload {
+        OlmPayload {
+            event_type: event_type.to_owned(),
+            content,
+            sender: "@alice:example.org".to_owned(),
+            sender_device: Some("ALICE".to_owned()),
+            sender_claimed_ed25519: account.ed25519_key().into(),
+        }
+    }
+
+    #[test]
+    fn test_room_key_roundtrip() {
+        let alice = Account::new();
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_2().into());
+        outbound.encrypt("before sharing".to_owned());
+        let content = room_key_content(&outbound, "!room:example.org".to_owned(), true);
+
+        let received = receive_room_key(
+            &payload(&alice, ROOM_KEY_EVENT_TYPE, content),
+            &alice.curve25519_key().into(),
+            &device_keys(&alice, "@alice:example.org", "ALICE"),
+        )
+        .unwrap();
+        let info = received.info();
+        assert_eq!(info.algorithm, MEGOLM_V2_ALGORITHM);
+        assert_eq!(info.room_id, "!room:example.org");
+        assert_eq!(info.origin, RoomKeyOrigin::Direct);
+        assert!(info.shared_history);
+        assert_eq!(received.session_id(), outbound.session_id());
+        assert_eq!(received.first_known_index(), 1);
+
+        let ciphertext = outbound.encrypt("after sharing".to_owned());
+        assert_eq!(
+            received
+                .inbound_group_session()
+                .decrypt(ciphertext)
+                .unwrap()
+                .0,
+            "after sharing"
+        );
+    }
+
+    #[test]
+    fn test_room_key_rejections() {
+        let alice = Account::new();
+        let mallory = Account::new();
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let content = room_key_content(&outbound, "!room:example.org".to_owned(), false);
+        let receive = |payload: OlmPayload, sender_key: &Account| {
+            receive_room_key(
+                &payload,
+                &sender_key.curve25519_key().into(),
+                &device_keys(&alice, "@alice:example.org", "ALICE"),
+            )
+            .err()
+        };
+
+        assert_eq!(
+            receive(
+                payload(&alice, ROOM_KEY_EVENT_TYPE, content.clone()),
+                &mallory
+            ),
+            Some(RoomKeyError::SenderKeyMismatch)
+        );
+        assert_eq!(
+            receive(
+                payload(&mallory, ROOM_KEY_EVENT_TYPE, content.clone()),
+                &alice
+            ),
+            Some(RoomKeyError::ClaimedKeyMismatch)
+        );
+        assert_eq!(
+            receive(payload(&alice, "m.dummy", content.clone()), &alice),
+            Some(RoomKeyError::NotARoomKey)
+        );
+
+        let mut unknown: Value = serde_json::from_str(&content).unwrap();
+        unknown["algorithm"] = json!("m.megolm.v3");
+        assert_eq!(
+            receive(
+                payload(&alice, ROOM_KEY_EVENT_TYPE, unknown.to_string()),
+                &alice
+            ),
+            Some(RoomKeyError::UnknownAlgorithm)
+        );
+
+        let mut wrong_id: Value = serde_json::from_str(&content).unwrap();
+        wrong_id["session_id"] = json!("other");
+        assert_eq!(
+            receive(
+                payload(&alice, ROOM_KEY_EVENT_TYPE, wrong_id.to_string()),
+                &alice
+            ),
+            Some(RoomKeyError::SessionIdMismatch)
+        );
+    }
+}
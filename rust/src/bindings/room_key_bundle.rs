@@ -0,0 +1,419 @@
+//! Sharing the history of a room with invited users through encrypted room key bundles.
+//! https://github.com/matrix-org/matrix-spec-proposals/pull/4268
+//!
+//! The inviter exports the sessions of the room that may be shared, encrypts them like an
+//! attachment and uploads the result. The location and key of the upload are then sent to the
+//! invitee over Olm.
+
+use rand::RngCore;
+use serde_json::{json, Value};
+use vodozemac::megolm::{ExportedSessionKey, InboundGroupSession};
+use vodozemac::{base64_decode, base64_encode};
+use zeroize::Zeroize;
+
+use super::device_keys::DeviceKeys;
+use super::megolm_signing::SessionKeySource;
+use super::room_key::{checked_content, megolm_config, RoomKeyError};
+use super::room_key_info::{InboundRoomKey, RoomKeyInfo, RoomKeyOrigin};
+use super::to_device::{encrypt_to_device, OlmPayload};
+use super::{aes_ctr, sha256, VodozemacAccount, VodozemacCurve25519PublicKey, VodozemacSession};
+
+pub(crate) const ROOM_KEY_BUNDLE_EVENT_TYPE: &str = "io.element.msc4268.room_key_bundle";
+
+/// Why a downloaded room key bundle couldn't be imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum RoomKeyBundleError {
+    #[error("The downloaded bundle doesn't match the hash it was sent with")]
+    HashMismatch,
+    #[error("The decrypted bundle is not a valid room key bundle")]
+    MalformedBundle,
+}
+
+/// A room key bundle encrypted as an attachment, ready to be uploaded.
+pub struct EncryptedRoomKeyBundle {
+    pub room_id: String,
+    pub ciphertext: Vec<u8>,
+    /// The `EncryptedFile` without the `url`, which is only known after the upload.
+    pub file: String,
+}
+
+/// The location and decryption info of a room key bundle, as received from the inviter.
+pub struct RoomKeyBundleDownload {
+    pub room_id: String,
+    pub url: String,
+    /// The `EncryptedFile` needed to decrypt the download.
+    pub file: String,
+    /// The Curve25519 key of the device that sent the bundle.
+    pub sender_key: String,
+}
+
+/// The sessions imported from a room key bundle.
+pub struct ImportedRoomKeyBundle {
+    pub room_keys: Vec<InboundRoomKey>,
+    /// How many sessions of the bundle were skipped because they were invalid, for a different
+    /// room or not marked as `shared_history`.
+    pub skipped: u32,
+}
+
+fn base64url_encode(bytes: &[u8]) -> String {
+    base64_encode(bytes).replace('+', "-").replace('/', "_")
+}
+
+fn base64url_decode(input: &str) -> Option<Vec<u8>> {
+    base64_decode(input.replace('-', "+").replace('_', "/")).ok()
+}
+
+/// Export all sessions of `room_keys` that belong to `room_id` and may be shared with new
+/// members, and encrypt them as an attachment with a fresh key.
+pub fn create_room_key_bundle(
+    room_id: String,
+    room_keys: Vec<InboundRoomKey>,
+) -> EncryptedRoomKeyBundle {
+    let exported: Vec<Value> = room_keys
+        .iter()
+        .filter_map(|room_key| {
+            let info = room_key.info();
+            if info.room_id != room_id || !info.shared_history {
+                return None;
+            }
+            let session = room_key.session.read().expect("Failed to read session");
+            Some(json!({
+                "algorithm": info.algorithm,
+                "room_id": info.room_id,
+                "sender_key": info.sender_key,
+                "session_id": session.session_id(),
+                "session_key": session.export_at_first_known_index().to_base64(),
+                "sender_claimed_keys": { "ed25519": info.sender_claimed_ed25519 },
+                "forwarding_curve25519_key_chain": info.forwarding_curve25519_key_chain,
+                "shared_history": true,
+            }))
+        })
+        .collect();
+    let mut plaintext = json!({ "room_keys": exported, "withheld": [] })
+        .to_string()
+        .into_bytes();
+
+    let mut key = [0u8; 32];
+    let mut iv = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut key);
+    // The lower half of the IV is the block counter, which starts at zero.
+    rand::thread_rng().fill_bytes(&mut iv[..8]);
+    let ciphertext = aes_ctr(&plaintext, &key, &iv);
+    plaintext.zeroize();
+
+    let file = json!({
+        "v": "v2",
+        "key": {
+            "kty": "oct",
+            "key_ops": ["encrypt", "decrypt"],
+            "alg": "A256CTR",
+            "k": base64url_encode(&key),
+            "ext": true,
+        },
+        "iv": base64_encode(iv),
+        "hashes": { "sha256": base64_encode(sha256(ciphertext.clone())) },
+    });
+    key.zeroize();
+
+    EncryptedRoomKeyBundle {
+        room_id,
+        ciphertext,
+        file: file.to_string(),
+    }
+}
+
+impl EncryptedRoomKeyBundle {
+    /// Encrypt the location and key of the uploaded bundle for the invitee's device.
+    ///
+    /// `url` is the `mxc://` URI the ciphertext was uploaded to. Returns the
+    /// `m.room.encrypted` content to send to the device.
+    pub fn encrypt_for_device(
+        &self,
+        url: String,
+        account: &VodozemacAccount,
+        session: &VodozemacSession,
+        sender: String,
+        sender_device: String,
+        recipient: &DeviceKeys,
+    ) -> anyhow::Result<String> {
+        let mut file: Value = serde_json::from_str(&self.file)?;
+        file["url"] = json!(url);
+        let content = json!({ "room_id": self.room_id, "file": file });
+        encrypt_to_device(
+            account,
+            session,
+            sender,
+            sender_device,
+            recipient,
+            ROOM_KEY_BUNDLE_EVENT_TYPE.to_owned(),
+            content.to_string(),
+        )
+    }
+}
+
+/// Read the location and key of a room key bundle from a decrypted to-device payload.
+///
+/// Like room keys, the payload has to be sent over an Olm session with `sender_device`.
+pub fn receive_room_key_bundle(
+    payload: &OlmPayload,
+    sender_key: &VodozemacCurve25519PublicKey,
+    sender_device: &DeviceKeys,
+) -> Result<RoomKeyBundleDownload, RoomKeyError> {
+    let content = checked_content(
+        payload,
+        ROOM_KEY_BUNDLE_EVENT_TYPE,
+        sender_key,
+        sender_device,
+    )?;
+    let (Some(room_id), Some(url)) = (content["room_id"].as_str(), content["file"]["url"].as_str())
+    else {
+        return Err(RoomKeyError::MalformedContent);
+    };
+
+    Ok(RoomKeyBundleDownload {
+        room_id: room_id.to_owned(),
+        url: url.to_owned(),
+        file: content["file"].to_string(),
+        sender_key: sender_key.to_base64(),
+    })
+}
+
+impl RoomKeyBundleDownload {
+    /// Decrypt the downloaded bundle and import the sessions in it.
+    ///
+    /// Only sessions of the bundle's room that are marked as `shared_history` are imported,
+    /// as forwarded keys with the sender of the bundle appended to the forwarding chain.
+    pub fn decrypt(
+        &self,
+        ciphertext: Vec<u8>,
+    ) -> Result<ImportedRoomKeyBundle, RoomKeyBundleError> {
+        let file: Value =
+            serde_json::from_str(&self.file).map_err(|_| RoomKeyBundleError::MalformedBundle)?;
+        let (Some(key), Some(iv), Some(hash)) = (
+            file["key"]["k"].as_str().and_then(base64url_decode),
+            file["iv"].as_str().and_then(|iv| base64_decode(iv).ok()),
+            file["hashes"]["sha256"]
+                .as_str()
+                .and_then(|h| base64_decode(h).ok()),
+        ) else {
+            return Err(RoomKeyBundleError::MalformedBundle);
+        };
+        if key.len() != 32 || iv.len() != 16 {
+            return Err(RoomKeyBundleError::MalformedBundle);
+        }
+        if sha256(ciphertext.clone()) != hash {
+            return Err(RoomKeyBundleError::HashMismatch);
+        }
+
+        let mut plaintext = aes_ctr(&ciphertext, &key, &iv);
+        let bundle = serde_json::from_slice::<Value>(&plaintext);
+        plaintext.zeroize();
+        let bundle = bundle.map_err(|_| RoomKeyBundleError::MalformedBundle)?;
+        let exported = bundle["room_keys"]
+            .as_array()
+            .ok_or(RoomKeyBundleError::MalformedBundle)?;
+
+        let room_keys: Vec<InboundRoomKey> = exported
+            .iter()
+            .filter_map(|exported| self.import(exported))
+            .collect();
+        Ok(ImportedRoomKeyBundle {
+            skipped: (exported.len() - room_keys.len()) as u32,
+            room_keys,
+        })
+    }
+
+    fn import(&self, exported: &Value) -> Option<InboundRoomKey> {
+        if exported["room_id"] != self.room_id.as_str() || exported["shared_history"] != true {
+            return None;
+        }
+        let algorithm = exported["algorithm"].as_str()?;
+        let session_key =
+            ExportedSessionKey::from_base64(exported["session_key"].as_str()?).ok()?;
+        let session = InboundGroupSession::import(&session_key, megolm_config(algorithm)?);
+        if exported["session_id"] != session.session_id() {
+            return None;
+        }
+
+        let mut forwarding_curve25519_key_chain: Vec<String> = exported
+            ["forwarding_curve25519_key_chain"]
+            .as_array()
+            .map(|chain| {
+                chain
+                    .iter()
+                    .filter_map(|k| k.as_str().map(str::to_owned))
+                    .collect()
+            })
+            .unwrap_or_default();
+        forwarding_curve25519_key_chain.push(self.sender_key.clone());
+
+        Some(InboundRoomKey::from_session(
+            session,
+            SessionKeySource::ExportedSessionKey,
+            RoomKeyInfo {
+                algorithm: algorithm.to_owned(),
+                room_id: self.room_id.clone(),
+                sender_key: exported["sender_key"].as_str()?.to_owned(),
+                sender_claimed_ed25519: exported["sender_claimed_keys"]["ed25519"]
+                    .as_str()?
+                    .to_owned(),
+                forwarding_curve25519_key_chain,
+                origin: RoomKeyOrigin::Forwarded,
+                shared_history: true,
+                backed_up: false,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::{device_keys, room_key_info};
+    use super::super::to_device::{decrypt_to_device, validate_to_device_payload};
+    use super::super::VodozemacGroupSession;
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+    use vodozemac::olm::SessionConfig as OlmSessionConfig;
+
+    const ROOM: &str = "!room:example.org";
+
+    fn room_key(room_id: &str, shared_history: bool) -> (VodozemacGroupSession, InboundRoomKey) {
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let info = RoomKeyInfo {
+            sender_key: "creator_key".to_owned(),
+            sender_claimed_ed25519: "creator_ed25519".to_owned(),
+            shared_history,
+            ..room_key_info(room_id, RoomKeyOrigin::Direct)
+        };
+        let room_key = InboundRoomKey::new(&outbound.to_inbound(), info);
+        (outbound, room_key)
+    }
+
+    #[test]
+    fn test_bundle_roundtrip() {
+        let alice = VodozemacAccount::new();
+        let bob = VodozemacAccount::new();
+        bob.generate_one_time_keys(1);
+        let one_time_key = bob.one_time_keys().pop().unwrap().key;
+        let olm_session = alice.create_outbound_session(
+            OlmSessionConfig::version_1().into(),
+            bob.curve25519_key(),
+            one_time_key,
+        );
+
+        let (shared, shared_key) = room_key(ROOM, true);
+        let (_, private_key) = room_key(ROOM, false);
+        let (_, other_room_key) = room_key("!other:example.org", true);
+        let bundle = create_room_key_bundle(
+            ROOM.to_owned(),
+            vec![shared_key, private_key, other_room_key],
+        );
+        let encrypted = bundle
+            .encrypt_for_device(
+                "mxc://example.org/bundle".to_owned(),
+                &alice,
+                &olm_session,
+                "@alice:example.org".to_owned(),
+                "ALICE".to_owned(),
+                &device_keys(&bob, "@bob:example.org", "BOB"),
+            )
+            .unwrap();
+
+        let event = json!({
+            "type": "m.room.encrypted",
+            "sender": "@alice:example.org",
+            "content": serde_json::from_str::<Value>(&encrypted).unwrap(),
+        });
+        let decrypted = decrypt_to_device(&bob, vec![], event.to_string()).unwrap();
+        let payload = validate_to_device_payload(
+            &bob,
+            "@bob:example.org".to_owned(),
+            "@alice:example.org".to_owned(),
+            decrypted.plaintext,
+        )
+        .unwrap();
+        let download = receive_room_key_bundle(
+            &payload,
+            &decrypted.sender_key,
+            &device_keys(&alice, "@alice:example.org", "ALICE"),
+        )
+        .unwrap();
+        assert_eq!(download.url, "mxc://example.org/bundle");
+
+        let mut tampered = bundle.ciphertext.clone();
+        tampered[0] ^= 1;
+        assert_eq!(
+            download.decrypt(tampered).err(),
+            Some(RoomKeyBundleError::HashMismatch)
+        );
+
+        let imported = download.decrypt(bundle.ciphertext).unwrap();
+        assert_eq!(imported.skipped, 0);
+        assert_eq!(imported.room_keys.len(), 1);
+        let room_key = &imported.room_keys[0];
+        assert_eq!(room_key.session_id(), shared.session_id());
+        let info = room_key.info();
+        assert_eq!(info.origin, RoomKeyOrigin::Forwarded);
+        assert_eq!(info.sender_claimed_ed25519, "creator_ed25519");
+        assert_eq!(
+            info.forwarding_curve25519_key_chain,
+            vec![alice.curve25519_key().to_base64()]
+        );
+    }
+
+    /// The bundle entry `create_room_key_bundle` exports for `room_key`.
+    fn exported(room_key: &InboundRoomKey) -> Value {
+        let info = room_key.info();
+        json!({
+            "algorithm": info.algorithm,
+            "room_id": info.room_id,
+            "sender_key": info.sender_key,
+            "session_id": room_key.session_id(),
+            "session_key": room_key.inbound_group_session().export_at_first_known_index(),
+            "sender_claimed_keys": { "ed25519": info.sender_claimed_ed25519 },
+            "forwarding_curve25519_key_chain": [],
+            "shared_history": true,
+        })
+    }
+
+    #[test]
+    fn test_receiver_skips_sessions_that_may_not_be_shared() {
+        let (shared, shared_key) = room_key(ROOM, true);
+        let (_, other_room_key) = room_key("!other:example.org", true);
+        let (_, private_key) = room_key(ROOM, true);
+        let (_, unmarked_key) = room_key(ROOM, true);
+        let mut private = exported(&private_key);
+        private["shared_history"] = json!(false);
+        let mut unmarked = exported(&unmarked_key);
+        unmarked.as_object_mut().unwrap().remove("shared_history");
+
+        // A sender that doesn't filter the sessions it bundles.
+        let plaintext = json!({
+            "room_keys": [
+                exported(&shared_key),
+                exported(&other_room_key),
+                private,
+                unmarked,
+            ],
+            "withheld": [],
+        });
+        let (key, iv) = ([7u8; 32], [0u8; 16]);
+        let ciphertext = aes_ctr(plaintext.to_string().as_bytes(), &key, &iv);
+        let download = RoomKeyBundleDownload {
+            room_id: ROOM.to_owned(),
+            url: "mxc://example.org/bundle".to_owned(),
+            file: json!({
+                "key": { "k": base64url_encode(&key) },
+                "iv": base64_encode(iv),
+                "hashes": { "sha256": base64_encode(sha256(ciphertext.clone())) },
+            })
+            .to_string(),
+            sender_key: "inviter_key".to_owned(),
+        };
+
+        let imported = download.decrypt(ciphertext).unwrap();
+        assert_eq!(imported.skipped, 3);
+        assert_eq!(imported.room_keys.len(), 1);
+        assert_eq!(imported.room_keys[0].session_id(), shared.session_id());
+    }
+}
@@ -0,0 +1,276 @@
+//! Inbound group sessions bundled with the information needed to judge their messages.
+//! https://spec.matrix.org/v1.16/client-server-api/#megolm-session-authenticity
+
+use serde::{Deserialize, Serialize};
+use vodozemac::megolm::{InboundGroupSession, InboundGroupSessionPickle};
+
+use super::megolm_signing::SessionKeySource;
+use super::pickle::{decrypt_pickle, encrypt_pickle};
+use super::pickle_format::PickleFormatError;
+use super::room_event::{decrypt_room_event, RoomEventDecryptionResult, RoomEventError};
+use super::{RustOpaqueNom, RwLock, VodozemacInboundGroupSession};
+
+/// How a Megolm session ended up on this device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoomKeyOrigin {
+    /// Received in an `m.room_key` from the device that created it.
+    Direct,
+    /// Received in an `m.forwarded_room_key` from another device.
+    Forwarded,
+    /// Downloaded from the server-side key backup.
+    Backup,
+    /// Imported from a key export file.
+    Export,
+}
+
+/// How much a decrypted message can be trusted to come from the claimed sender.
+///
+/// Only messages of sessions received directly from their creator are authenticated. The
+/// caller still has to check whether the sending device is verified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAuthenticity {
+    Authenticated,
+    Forwarded,
+    Imported,
+}
+
+impl From<RoomKeyOrigin> for KeyAuthenticity {
+    fn from(origin: RoomKeyOrigin) -> Self {
+        match origin {
+            RoomKeyOrigin::Direct => Self::Authenticated,
+            RoomKeyOrigin::Forwarded => Self::Forwarded,
+            RoomKeyOrigin::Backup | RoomKeyOrigin::Export => Self::Imported,
+        }
+    }
+}
+
+/// Where a Megolm session came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoomKeyInfo {
+    pub algorithm: String,
+    pub room_id: String,
+    /// The Curve25519 key of the device that created the session.
+    pub sender_key: String,
+    /// The Ed25519 key the creator of the session claims to own.
+    pub sender_claimed_ed25519: String,
+    /// The Curve25519 keys of the devices that forwarded the session, oldest first.
+    pub forwarding_curve25519_key_chain: Vec<String>,
+    pub origin: RoomKeyOrigin,
+    /// Whether the sender allows sharing the key with users invited later, see MSC3061.
+    pub shared_history: bool,
+    /// Whether the session was uploaded to the server-side key backup.
+    pub backed_up: bool,
+}
+
+impl RoomKeyInfo {
+    pub fn authenticity(&self) -> KeyAuthenticity {
+        self.origin.into()
+    }
+}
+
+/// A room event decrypted with an `InboundRoomKey`.
+pub struct RoomKeyDecryptionResult {
+    pub event_type: String,
+    /// The decrypted content as JSON.
+    pub content: String,
+    pub message_index: u32,
+    pub authenticity: KeyAuthenticity,
+    pub sender_key: String,
+    pub sender_claimed_ed25519: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct InboundRoomKeyPickle {
+    session: InboundGroupSessionPickle,
+    key_source: SessionKeySource,
+    info: RoomKeyInfo,
+}
+
+/// An inbound group session together with its `RoomKeyInfo`.
+pub struct InboundRoomKey {
+    pub session: RustOpaqueNom<RwLock<InboundGroupSession>>,
+    pub source: RustOpaqueNom<RwLock<SessionKeySource>>,
+    pub key_info: RustOpaqueNom<RwLock<RoomKeyInfo>>,
+}
+
+impl InboundRoomKey {
+    pub fn new(session: &VodozemacInboundGroupSession, info: RoomKeyInfo) -> Self {
+        Self {
+            session: session.session.clone(),
+            source: session.source.clone(),
+            key_info: RustOpaqueNom::new(RwLock::new(info)),
+        }
+    }
+
+    pub(crate) fn from_session(
+        session: InboundGroupSession,
+        key_source: SessionKeySource,
+        info: RoomKeyInfo,
+    ) -> Self {
+        Self::new(
+            &VodozemacInboundGroupSession::from_session(session, key_source),
+            info,
+        )
+    }
+
+    pub fn inbound_group_session(&self) -> VodozemacInboundGroupSession {
+        VodozemacInboundGroupSession {
+            session: self.session.clone(),
+            source: self.source.clone(),
+        }
+    }
+
+    pub fn session_id(&self) -> String {
+        self.session
+            .read()
+            .expect("Failed to read session")
+            .session_id()
+    }
+
+    pub fn first_known_index(&self) -> u32 {
+        self.session
+            .read()
+            .expect("Failed to read session")
+            .first_known_index()
+    }
+
+    pub fn info(&self) -> RoomKeyInfo {
+        self.key_info.read().expect("Failed to read info").clone()
+    }
+
+    pub fn mark_backed_up(&self, backed_up: bool) {
+        self.key_info.write().expect("Failed to write info").backed_up = backed_up;
+    }
+
+    /// Decrypt an `m.room.encrypted` content received in `room_id`.
+    ///
+    /// Besides the checks of `decrypt_room_event`, the room has to match the room the session
+    /// was shared for.
+    pub fn decrypt_room_event(
+        &self,
+        room_id: String,
+        encrypted_content: String,
+    ) -> Result<RoomKeyDecryptionResult, RoomEventError> {
+        let info = self.info();
+        if info.room_id != room_id {
+            return Err(RoomEventError::RoomMismatch);
+        }
+
+        let RoomEventDecryptionResult {
+            event_type,
+            content,
+            message_index,
+        } = decrypt_room_event(&self.inbound_group_session(), room_id, encrypted_content)?;
+        Ok(RoomKeyDecryptionResult {
+            event_type,
+            content,
+            message_index,
+            authenticity: info.authenticity(),
+            sender_key: info.sender_key,
+            sender_claimed_ed25519: info.sender_claimed_ed25519,
+        })
+    }
+
+    pub fn pickle_encrypted(&self, pickle_key: [u8; 32usize]) -> String {
+        let pickle = InboundRoomKeyPickle {
+            session: self
+                .session
+                .read()
+                .expect("Failed to read session")
+                .pickle(),
+            key_source: self.inbound_group_session().key_source(),
+            info: self.info(),
+        };
+        encrypt_pickle(&pickle, &pickle_key)
+    }
+
+    pub fn from_pickle_encrypted(
+        pickle: String,
+        pickle_key: [u8; 32usize],
+    ) -> anyhow::Result<Self> {
+        Ok(Self::unpickle(&pickle, &pickle_key)?)
+    }
+
+    pub(crate) fn unpickle(pickle: &str, pickle_key: &[u8; 32]) -> Result<Self, PickleFormatError> {
+        let pickle: InboundRoomKeyPickle = decrypt_pickle(pickle, pickle_key)?;
+        Ok(Self::from_session(
+            InboundGroupSession::from(pickle.session),
+            pickle.key_source,
+            pickle.info,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::room_event::encrypt_room_event;
+    use super::super::test_utils::room_key_info;
+    use super::super::{VodozemacAccount, VodozemacGroupSession};
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+
+    const ROOM: &str = "!room:example.org";
+
+    #[test]
+    fn test_decryption_reports_authenticity() {
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let inbound = outbound.to_inbound();
+        let encrypted = encrypt_room_event(
+            &outbound,
+            &VodozemacAccount::new().curve25519_key(),
+            "DEVICE".to_owned(),
+            ROOM.to_owned(),
+            "m.room.message".to_owned(),
+            "{}".to_owned(),
+        )
+        .unwrap();
+
+        for (origin, authenticity) in [
+            (RoomKeyOrigin::Direct, KeyAuthenticity::Authenticated),
+            (RoomKeyOrigin::Forwarded, KeyAuthenticity::Forwarded),
+            (RoomKeyOrigin::Backup, KeyAuthenticity::Imported),
+        ] {
+            let room_key = InboundRoomKey::new(&inbound, room_key_info(ROOM, origin));
+            let result = room_key
+                .decrypt_room_event(ROOM.to_owned(), encrypted.clone())
+                .unwrap();
+            assert_eq!(result.authenticity, authenticity);
+            assert_eq!(result.sender_claimed_ed25519, "claimed_key");
+        }
+
+        let mut other_room = room_key_info(ROOM, RoomKeyOrigin::Direct);
+        other_room.room_id = "!other:example.org".to_owned();
+        let room_key = InboundRoomKey::new(&inbound, other_room);
+        assert_eq!(
+            room_key
+                .decrypt_room_event(ROOM.to_owned(), encrypted)
+                .err(),
+            Some(RoomEventError::RoomMismatch)
+        );
+    }
+
+    #[test]
+    fn test_info_is_pickled_with_the_session() {
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let room_key = InboundRoomKey::new(
+            &outbound.to_inbound(),
+            room_key_info(ROOM, RoomKeyOrigin::Export),
+        );
+        room_key.mark_backed_up(true);
+
+        let pickle_key = [6u8; 32];
+        let unpickled = InboundRoomKey::from_pickle_encrypted(
+            room_key.pickle_encrypted(pickle_key),
+            pickle_key,
+        )
+        .unwrap();
+        assert_eq!(unpickled.session_id(), outbound.session_id());
+        assert_eq!(
+            unpickled.info(),
+            RoomKeyInfo {
+                backed_up: true,
+                ..room_key_info(ROOM, RoomKeyOrigin::Export)
+            }
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Tracking which devices received the key of an outbound group session.
+//! https://spec.matrix.org/v1.16/client-server-api/#mroom_key_withheld
+
+use std::collections::HashMap;
+
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+use super::outbound_group_session::OutboundGroupSession;
+
+/// The reason a room key was withheld from a device, as sent in `m.room_key.withheld`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WithheldCode {
+    Blacklisted,
+    Unverified,
+    Unauthorised,
+    Unavailable,
+    NoOlm,
+}
+
+impl WithheldCode {
+    /// The `code` of the `m.room_key.withheld` content.
+    pub fn code(&self) -> String {
+        match self {
+            Self::Blacklisted => "m.blacklisted",
+            Self::Unverified => "m.unverified",
+            Self::Unauthorised => "m.unauthorised",
+            Self::Unavailable => "m.unavailable",
+            Self::NoOlm => "m.no_olm",
+        }
+        .to_owned()
+    }
+
+    pub fn from_code(code: String) -> Option<Self> {
+        [
+            Self::Blacklisted,
+            Self::Unverified,
+            Self::Unauthorised,
+            Self::Unavailable,
+            Self::NoOlm,
+        ]
+        .into_iter()
+        .find(|c| c.code() == code)
+    }
+}
+
+/// A device the room key is shared with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareRecipient {
+    pub user_id: String,
+    pub device_id: String,
+    pub curve25519_key: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+enum ShareOutcome {
+    Shared { message_index: u32 },
+    Withheld { code: WithheldCode },
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct DeviceShare {
+    curve25519_key: String,
+    outcome: ShareOutcome,
+}
+
+/// The devices that received or were denied the key, keyed by user and device id.
+#[frb(ignore)]
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct ShareState {
+    devices: HashMap<String, HashMap<String, DeviceShare>>,
+}
+
+impl ShareState {
+    fn get(&self, recipient: &ShareRecipient) -> Option<&DeviceShare> {
+        self.devices
+            .get(&recipient.user_id)?
+            .get(&recipient.device_id)
+            // A device that changed its key is a different device to us.
+            .filter(|s| s.curve25519_key == recipient.curve25519_key)
+    }
+
+    fn insert(&mut self, recipient: ShareRecipient, outcome: ShareOutcome) {
+        self.devices.entry(recipient.user_id).or_default().insert(
+            recipient.device_id,
+            DeviceShare {
+                curve25519_key: recipient.curve25519_key,
+                outcome,
+            },
+        );
+    }
+}
+
+impl OutboundGroupSession {
+    /// Record that the room key was sent to a device at the current message index.
+    pub fn record_share(&self, recipient: ShareRecipient) {
+        let message_index = self
+            .session
+            .read()
+            .expect("Failed to read session")
+            .message_index();
+        self.shares
+            .write()
+            .expect("Failed to write shares")
+            .insert(recipient, ShareOutcome::Shared { message_index });
+    }
+
+    /// Record that an `m.room_key.withheld` was sent to a device instead of the room key.
+    pub fn record_withheld(&self, recipient: ShareRecipient, code: WithheldCode) {
+        self.shares
+            .write()
+            .expect("Failed to write shares")
+            .insert(recipient, ShareOutcome::Withheld { code });
+    }
+
+    /// The message index the key was shared at with the device, if it was.
+    pub fn shared_message_index(&self, recipient: ShareRecipient) -> Option<u32> {
+        match self
+            .shares
+            .read()
+            .expect("Failed to read shares")
+            .get(&recipient)
+        {
+            Some(DeviceShare {
+                outcome: ShareOutcome::Shared { message_index },
+                ..
+            }) => Some(*message_index),
+            _ => None,
+        }
+    }
+
+    /// The reason the key was withheld from the device, if it was.
+    pub fn withheld_code(&self, recipient: ShareRecipient) -> Option<WithheldCode> {
+        match self
+            .shares
+            .read()
+            .expect("Failed to read shares")
+            .get(&recipient)
+        {
+            Some(DeviceShare {
+                outcome: ShareOutcome::Withheld { code },
+                ..
+            }) => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// The devices of `devices` that neither received the key nor were told it was withheld.
+    pub fn devices_missing_key(&self, devices: Vec<ShareRecipient>) -> Vec<ShareRecipient> {
+        let shares = self.shares.read().expect("Failed to read shares");
+        devices
+            .into_iter()
+            .filter(|d| shares.get(d).is_none())
+            .collect()
+    }
+
+    /// The devices that received the key but are not part of `devices` anymore.
+    ///
+    /// `devices` are all current devices of the room members. If any device is returned, the
+    /// session has to be rotated, as those devices could decrypt future messages.
+    pub fn removed_devices(&self, devices: Vec<ShareRecipient>) -> Vec<ShareRecipient> {
+        let shares = self.shares.read().expect("Failed to read shares");
+        let mut removed: Vec<ShareRecipient> = shares
+            .devices
+            .iter()
+            .flat_map(|(user_id, devices)| {
+                devices
+                    .iter()
+                    .map(move |(device_id, share)| (user_id, device_id, share))
+            })
+            .filter(|(_, _, share)| matches!(share.outcome, ShareOutcome::Shared { .. }))
+            .map(|(user_id, device_id, share)| ShareRecipient {
+                user_id: user_id.clone(),
+                device_id: device_id.clone(),
+                curve25519_key: share.curve25519_key.clone(),
+            })
+            .filter(|recipient| !devices.contains(recipient))
+            .collect();
+        removed.sort_by(|a, b| (&a.user_id, &a.device_id).cmp(&(&b.user_id, &b.device_id)));
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+
+    fn recipient(user_id: &str, device_id: &str, key: &str) -> ShareRecipient {
+        ShareRecipient {
+            user_id: user_id.to_owned(),
+            device_id: device_id.to_owned(),
+            curve25519_key: key.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_share_tracking() {
+        let session = OutboundGroupSession::new(SessionConfig::version_1().into(), 0, None, None);
+        let alice = recipient("@alice:example.org", "ALICE", "alice_key");
+        let bob = recipient("@bob:example.org", "BOB", "bob_key");
+        let carol = recipient("@carol:example.org", "CAROL", "carol_key");
+
+        session.record_share(alice.clone());
+        session.group_session().encrypt("hello".to_owned());
+        session.record_share(bob.clone());
+        session.record_withheld(carol.clone(), WithheldCode::Unverified);
+
+        assert_eq!(session.shared_message_index(alice.clone()), Some(0));
+        assert_eq!(session.shared_message_index(bob.clone()), Some(1));
+        assert_eq!(
+            session.withheld_code(carol.clone()),
+            Some(WithheldCode::Unverified)
+        );
+        assert_eq!(
+            WithheldCode::from_code("m.unverified".to_owned()),
+            Some(WithheldCode::Unverified)
+        );
+
+        let new_bob_device = recipient("@bob:example.org", "BOB2", "bob2_key");
+        let all = vec![
+            alice.clone(),
+            bob.clone(),
+            carol.clone(),
+            new_bob_device.clone(),
+        ];
+        assert_eq!(
+            session.devices_missing_key(all.clone()),
+            vec![new_bob_device]
+        );
+        assert!(session.removed_devices(all).is_empty());
+
+        // Carol never got the key, so her leaving doesn't matter. Bob's new key does.
+        let bob_new_key = recipient("@bob:example.org", "BOB", "bob_new_key");
+        let remaining = vec![alice.clone(), bob_new_key.clone()];
+        assert_eq!(session.removed_devices(remaining.clone()), vec![bob]);
+        assert_eq!(session.devices_missing_key(remaining), vec![bob_new_key]);
+
+        let pickle_key = [2u8; 32];
+        let unpickled = OutboundGroupSession::from_pickle_encrypted(
+            session.pickle_encrypted(pickle_key),
+            pickle_key,
+        )
+        .unwrap();
+        assert_eq!(unpickled.shared_message_index(alice), Some(0));
+        assert_eq!(
+            unpickled.withheld_code(carol),
+            Some(WithheldCode::Unverified)
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Fixtures shared by the tests of the binding modules.
+
+use serde_json::{json, Value};
+use vodozemac::hazmat::Cipher;
+use vodozemac::megolm::ExportedSessionKey;
+use vodozemac::olm::Account;
+
+use super::canonical_json::{add_signature, signable_json};
+use super::device_keys::{DeviceKeys, OLM_V1_ALGORITHM};
+use super::room_event::MEGOLM_V1_ALGORITHM;
+use super::room_key_info::{RoomKeyInfo, RoomKeyOrigin};
+use super::{
+    VodozemacAccount, VodozemacCurve25519PublicKey, VodozemacEd25519PublicKey,
+    VodozemacInboundGroupSession, VodozemacSession,
+};
+
+/// An account whose identity keys can be published as device keys.
+pub(crate) trait TestDevice {
+    fn device_identity(&self) -> (VodozemacEd25519PublicKey, VodozemacCurve25519PublicKey);
+}
+
+impl TestDevice for Account {
+    fn device_identity(&self) -> (VodozemacEd25519PublicKey, VodozemacCurve25519PublicKey) {
+        (self.ed25519_key().into(), self.curve25519_key().into())
+    }
+}
+
+impl TestDevice for VodozemacAccount {
+    fn device_identity(&self) -> (VodozemacEd25519PublicKey, VodozemacCurve25519PublicKey) {
+        (self.ed25519_key(), self.curve25519_key())
+    }
+}
+
+/// The device keys of `account`, as they would be downloaded from the server.
+pub(crate) fn device_keys(account: &impl TestDevice, user_id: &str, device_id: &str) -> DeviceKeys {
+    let (ed25519, curve25519) = account.device_identity();
+    DeviceKeys {
+        user_id: user_id.to_owned(),
+        device_id: device_id.to_owned(),
+        algorithms: vec![OLM_V1_ALGORITHM.to_owned()],
+        ed25519,
+        curve25519,
+        display_name: None,
+    }
+}
+
+/// The device keys of `account` as JSON, signed by the device like `/keys/query` returns them.
+pub(crate) fn signed_device_keys(account: &Account, user_id: &str, device_id: &str) -> Value {
+    let mut keys = json!({
+        "user_id": user_id,
+        "device_id": device_id,
+        "algorithms": [OLM_V1_ALGORITHM, MEGOLM_V1_ALGORITHM],
+        "keys": {
+            format!("curve25519:{device_id}"): account.curve25519_key().to_base64(),
+            format!("ed25519:{device_id}"): account.ed25519_key().to_base64(),
+        },
+    });
+    let key_id = format!("ed25519:{device_id}");
+    let signature = account.sign(signable_json(&keys).unwrap()).to_base64();
+    add_signature(&mut keys, user_id, &key_id, signature).unwrap();
+    keys
+}
+
+/// The info of a Megolm v1 room key that wasn't forwarded, shared or backed up.
+pub(crate) fn room_key_info(room_id: &str, origin: RoomKeyOrigin) -> RoomKeyInfo {
+    RoomKeyInfo {
+        algorithm: MEGOLM_V1_ALGORITHM.to_owned(),
+        room_id: room_id.to_owned(),
+        sender_key: "sender_key".to_owned(),
+        sender_claimed_ed25519: "claimed_key".to_owned(),
+        forwarding_curve25519_key_chain: vec![],
+        origin,
+        shared_history: false,
+        backed_up: false,
+    }
+}
+
+fn encrypt_libolm_pickle(plaintext: &[u8], pickle_key: &[u8]) -> String {
+    vodozemac::base64_encode(Cipher::new_pickle(pickle_key).encrypt_pickle(plaintext))
+}
+
+/// A libolm pickle of a session with the same keys, and so the same id, as `session`.
+///
+/// vodozemac can't write libolm session pickles, so this encodes the version 1 format by
+/// hand. The sending ratchet is made up, the session can't talk to the original peer.
+pub(crate) fn libolm_session_pickle(session: &VodozemacSession, pickle_key: &[u8]) -> String {
+    let keys = session.session.read().unwrap().session_keys();
+    let mut pickle = 1u32.to_be_bytes().to_vec();
+    // Whether a message was received.
+    pickle.push(0);
+    for key in [keys.identity_key, keys.base_key, keys.one_time_key] {
+        pickle.extend(key.as_bytes());
+    }
+    // The root key.
+    pickle.extend([1u8; 32]);
+    // One sending chain: its public and secret ratchet key, the chain key and its index.
+    pickle.extend(1u32.to_be_bytes());
+    pickle.extend([2u8; 32]);
+    pickle.extend([3u8; 32]);
+    pickle.extend([4u8; 32]);
+    pickle.extend(0u32.to_be_bytes());
+    // No receiving chains and no skipped message keys.
+    pickle.extend(0u32.to_be_bytes());
+    pickle.extend(0u32.to_be_bytes());
+    encrypt_libolm_pickle(&pickle, pickle_key)
+}
+
+/// A libolm pickle of `session`, in the version 2 format libolm used last.
+pub(crate) fn libolm_inbound_group_session_pickle(
+    session: &VodozemacInboundGroupSession,
+    pickle_key: &[u8],
+) -> String {
+    let export = ExportedSessionKey::from_base64(&session.export_at_first_known_index())
+        .unwrap()
+        .to_bytes();
+    // The export is a version byte, the ratchet index, the ratchet and the signing key.
+    let (index, rest) = export[1..].split_at(4);
+    let (ratchet, signing_key) = rest.split_at(128);
+
+    let mut pickle = 2u32.to_be_bytes().to_vec();
+    // The initial and the latest ratchet.
+    for _ in 0..2 {
+        pickle.extend(ratchet);
+        pickle.extend(index);
+    }
+    pickle.extend(signing_key);
+    // Whether the signing key was verified.
+    pickle.push(1);
+    encrypt_libolm_pickle(&pickle, pickle_key)
+}
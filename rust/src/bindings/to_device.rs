@@ -0,0 +1,455 @@
+//! Olm encrypted to-device messages and the payload envelope around them.
+//! https://spec.matrix.org/v1.16/client-server-api/#molmv1curve25519-aes-sha2
+
+use serde_json::{json, Value};
+use vodozemac::{
+    olm::{DecryptionError, OlmMessage, SessionCreationError},
+    Curve25519PublicKey, Ed25519PublicKey,
+};
+
+use super::device_keys::{DeviceKeys, OLM_V1_ALGORITHM};
+use super::{
+    VodozemacAccount, VodozemacCurve25519PublicKey, VodozemacEd25519PublicKey, VodozemacSession,
+};
+
+/// Why a decrypted Olm payload was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum OlmPayloadError {
+    #[error("The decrypted payload is not a valid to-device payload")]
+    MalformedPayload,
+    #[error("The payload was meant for a different user")]
+    RecipientMismatch,
+    #[error("The payload was encrypted for a different device")]
+    RecipientKeyMismatch,
+    #[error("The sender of the payload doesn't match the sender of the event")]
+    SenderMismatch,
+}
+
+/// The decrypted and validated payload of an Olm encrypted to-device event.
+pub struct OlmPayload {
+    pub event_type: String,
+    /// The event content as JSON.
+    pub content: String,
+    pub sender: String,
+    pub sender_device: Option<String>,
+    /// The Ed25519 key the sender claims to own. It is only authenticated once the sender's
+    /// Curve25519 key was matched to a device with this Ed25519 key.
+    pub sender_claimed_ed25519: VodozemacEd25519PublicKey,
+}
+
+/// Encrypt a to-device event for `recipient` and return the `m.room.encrypted` content.
+///
+/// `content` is the JSON content of the event of type `event_type` that is wrapped in the
+/// payload envelope before being encrypted with `session`.
+pub fn encrypt_to_device(
+    account: &VodozemacAccount,
+    session: &VodozemacSession,
+    sender: String,
+    sender_device: String,
+    recipient: &DeviceKeys,
+    event_type: String,
+    content: String,
+) -> anyhow::Result<String> {
+    let content: Value = serde_json::from_str(&content)?;
+    let identity_keys = account.identity_keys();
+    let payload = json!({
+        "type": event_type,
+        "content": content,
+        "sender": sender,
+        "sender_device": sender_device,
+        "keys": { "ed25519": identity_keys.ed25519.to_base64() },
+        "recipient": recipient.user_id,
+        "recipient_keys": { "ed25519": recipient.ed25519.to_base64() },
+    });
+
+    let message = session.encrypt(payload.to_string());
+    Ok(json!({
+        "algorithm": OLM_V1_ALGORITHM,
+        "sender_key": identity_keys.curve25519.to_base64(),
+        "ciphertext": {
+            recipient.curve25519.to_base64(): {
+                "type": message.message_type(),
+                "body": message.message(),
+            },
+        },
+    })
+    .to_string())
+}
+
+/// Validate the decrypted payload of a to-device event sent by `event_sender` to us.
+pub fn validate_to_device_payload(
+    account: &VodozemacAccount,
+    own_user_id: String,
+    event_sender: String,
+    plaintext: String,
+) -> Result<OlmPayload, OlmPayloadError> {
+    let payload: Value =
+        serde_json::from_str(&plaintext).map_err(|_| OlmPayloadError::MalformedPayload)?;
+    let own_ed25519 = account.ed25519_key();
+    payload_from_value(&payload, &own_user_id, &own_ed25519.key, &event_sender)
+}
+
+pub(crate) fn payload_from_value(
+    payload: &Value,
+    own_user_id: &str,
+    own_ed25519: &Ed25519PublicKey,
+    event_sender: &str,
+) -> Result<OlmPayload, OlmPayloadError> {
+    let (Some(event_type), Some(content), Some(sender), Some(recipient)) = (
+        payload["type"].as_str(),
+        payload.get("content").filter(|c| c.is_object()),
+        payload["sender"].as_str(),
+        payload["recipient"].as_str(),
+    ) else {
+        return Err(OlmPayloadError::MalformedPayload);
+    };
+    let sender_claimed_ed25519 = payload["keys"]["ed25519"]
+        .as_str()
+        .and_then(|k| Ed25519PublicKey::from_base64(k).ok())
+        .ok_or(OlmPayloadError::MalformedPayload)?;
+
+    if recipient != own_user_id {
+        return Err(OlmPayloadError::RecipientMismatch);
+    }
+    if payload["recipient_keys"]["ed25519"].as_str() != Some(&own_ed25519.to_base64()) {
+        return Err(OlmPayloadError::RecipientKeyMismatch);
+    }
+    if sender != event_sender {
+        return Err(OlmPayloadError::SenderMismatch);
+    }
+
+    Ok(OlmPayload {
+        event_type: event_type.to_owned(),
+        content: content.to_string(),
+        sender: sender.to_owned(),
+        sender_device: payload["sender_device"].as_str().map(str::to_owned),
+        sender_claimed_ed25519: sender_claimed_ed25519.into(),
+    })
+}
+
+/// Why an Olm encrypted to-device event couldn't be decrypted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ToDeviceDecryptionError {
+    #[error("The event is not a valid m.room.encrypted to-device event")]
+    MalformedEvent,
+    #[error("The event is not encrypted with Olm")]
+    UnsupportedAlgorithm,
+    #[error("The event contains no ciphertext for this device")]
+    NotForThisDevice,
+    #[error("The pre-key message was sent with a different identity key than the event")]
+    IdentityKeyMismatch,
+    #[error("The message was already decrypted")]
+    DuplicateMessage,
+    #[error("No session can decrypt the message, the session with the sender may be wedged")]
+    SessionWedged,
+    #[error("The decrypted plaintext is not valid UTF-8")]
+    MalformedPlaintext,
+}
+
+/// A decrypted Olm to-device event.
+///
+/// Whichever session decrypted the message advanced its ratchet and has to be pickled again.
+/// If a new session was created, the account used up a one-time key and has to be pickled
+/// again as well.
+pub struct ToDeviceDecryptionResult {
+    /// The decrypted payload, see `validate_to_device_payload`.
+    pub plaintext: String,
+    pub sender_key: VodozemacCurve25519PublicKey,
+    /// The id of the session that decrypted the message.
+    pub session_id: String,
+    /// The session created from a pre-key message, if none of the known sessions could decrypt
+    /// it.
+    pub new_session: Option<VodozemacSession>,
+}
+
+/// Decrypt an `m.room.encrypted` to-device event.
+///
+/// `sessions_for_sender` are the known sessions with the sender's Curve25519 key, all of which
+/// are tried before a pre-key message is used to create a new inbound session.
+pub fn decrypt_to_device(
+    account: &VodozemacAccount,
+    sessions_for_sender: Vec<VodozemacSession>,
+    event_json: String,
+) -> Result<ToDeviceDecryptionResult, ToDeviceDecryptionError> {
+    let event: Value =
+        serde_json::from_str(&event_json).map_err(|_| ToDeviceDecryptionError::MalformedEvent)?;
+    let content = &event["content"];
+    if content["algorithm"] != OLM_V1_ALGORITHM {
+        return Err(ToDeviceDecryptionError::UnsupportedAlgorithm);
+    }
+    let sender_key = content["sender_key"]
+        .as_str()
+        .and_then(|k| Curve25519PublicKey::from_base64(k).ok())
+        .ok_or(ToDeviceDecryptionError::MalformedEvent)?;
+    let own_curve25519 = account.curve25519_key().to_base64();
+    let message = olm_message(content, &own_curve25519)?;
+
+    let mut duplicate = false;
+    for session in &sessions_for_sender {
+        let mut inner = session.session.write().expect("Failed to write session");
+        match inner.decrypt(&message) {
+            Ok(plaintext) => {
+                return Ok(ToDeviceDecryptionResult {
+                    plaintext: String::from_utf8(plaintext)
+                        .map_err(|_| ToDeviceDecryptionError::MalformedPlaintext)?,
+                    sender_key: sender_key.into(),
+                    session_id: inner.session_id(),
+                    new_session: None,
+                });
+            }
+            Err(DecryptionError::MissingMessageKey(_)) => duplicate = true,
+            Err(_) => {}
+        }
+        // A pre-key message for a session we already have, that the session can't decrypt
+        // anymore, was already used to create it.
+        if let OlmMessage::PreKey(pre_key) = &message {
+            duplicate |= pre_key.session_id() == inner.session_id();
+        }
+    }
+    if duplicate {
+        return Err(ToDeviceDecryptionError::DuplicateMessage);
+    }
+
+    let OlmMessage::PreKey(pre_key) = &message else {
+        return Err(ToDeviceDecryptionError::SessionWedged);
+    };
+    let result = account
+        .account
+        .write()
+        .expect("Failed to write account")
+        .create_inbound_session(sender_key, pre_key)
+        .map_err(|e| match e {
+            SessionCreationError::MismatchedIdentityKey(..) => {
+                ToDeviceDecryptionError::IdentityKeyMismatch
+            }
+            _ => ToDeviceDecryptionError::SessionWedged,
+        })?;
+    Ok(ToDeviceDecryptionResult {
+        plaintext: String::from_utf8(result.plaintext)
+            .map_err(|_| ToDeviceDecryptionError::MalformedPlaintext)?,
+        sender_key: sender_key.into(),
+        session_id: result.session.session_id(),
+        new_session: Some(result.session.into()),
+    })
+}
+
+/// Parse the `{type, body}` ciphertext addressed to `own_curve25519` in `m.room.encrypted`
+/// content.
+fn olm_message(
+    content: &Value,
+    own_curve25519: &str,
+) -> Result<OlmMessage, ToDeviceDecryptionError> {
+    let ciphertext = content["ciphertext"]
+        .get(own_curve25519)
+        .ok_or(ToDeviceDecryptionError::NotForThisDevice)?;
+    let (Some(message_type), Some(body)) =
+        (ciphertext["type"].as_u64(), ciphertext["body"].as_str())
+    else {
+        return Err(ToDeviceDecryptionError::MalformedEvent);
+    };
+    vodozemac::base64_decode(body)
+        .ok()
+        .and_then(|body| OlmMessage::from_parts(message_type as usize, &body).ok())
+        .ok_or(ToDeviceDecryptionError::MalformedEvent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::device_keys;
+    use super::*;
+    use vodozemac::olm::SessionConfig;
+
+    #[test]
+    fn test_encrypt_and_validate_payload() {
+        let alice = VodozemacAccount::new();
+        let bob = VodozemacAccount::new();
+        bob.generate_one_time_keys(1);
+        let one_time_key = bob.one_time_keys().pop().unwrap().key;
+        let session = alice.create_outbound_session(
+            SessionConfig::version_2().into(),
+            bob.curve25519_key(),
+            one_time_key,
+        );
+
+        let encrypted: Value = serde_json::from_str(
+            &encrypt_to_device(
+                &alice,
+                &session,
+                "@alice:example.org".to_owned(),
+                "ALICE".to_owned(),
+                &device_keys(&bob, "@bob:example.org", "DEVICE"),
+                "m.dummy".to_owned(),
+                "{}".to_owned(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(encrypted["algorithm"], OLM_V1_ALGORITHM);
+        assert_eq!(encrypted["sender_key"], alice.curve25519_key().to_base64());
+
+        let ciphertext = &encrypted["ciphertext"][bob.curve25519_key().to_base64()];
+        assert_eq!(ciphertext["type"], 0);
+        let result = bob
+            .create_inbound_session(
+                alice.curve25519_key(),
+                ciphertext["body"].as_str().unwrap().to_owned(),
+            )
+            .unwrap();
+
+        let payload = validate_to_device_payload(
+            &bob,
+            "@bob:example.org".to_owned(),
+            "@alice:example.org".to_owned(),
+            result.plaintext.clone(),
+        )
+        .unwrap();
+        assert_eq!(payload.event_type, "m.dummy");
+        assert_eq!(payload.content, "{}");
+        assert_eq!(payload.sender_device.as_deref(), Some("ALICE"));
+        assert_eq!(
+            payload.sender_claimed_ed25519.to_base64(),
+            alice.ed25519_key().to_base64()
+        );
+
+        let validate = |own_user_id: &str, account: &VodozemacAccount, sender: &str| {
+            validate_to_device_payload(
+                account,
+                own_user_id.to_owned(),
+                sender.to_owned(),
+                result.plaintext.clone(),
+            )
+            .err()
+        };
+        assert_eq!(
+            validate("@carol:example.org", &bob, "@alice:example.org"),
+            Some(OlmPayloadError::RecipientMismatch)
+        );
+        assert_eq!(
+            validate("@bob:example.org", &alice, "@alice:example.org"),
+            Some(OlmPayloadError::RecipientKeyMismatch)
+        );
+        assert_eq!(
+            validate("@bob:example.org", &bob, "@mallory:example.org"),
+            Some(OlmPayloadError::SenderMismatch)
+        );
+    }
+
+    /// Another handle to the same session, like the one Dart passes in.
+    fn handle(session: &VodozemacSession) -> VodozemacSession {
+        VodozemacSession {
+            session: session.session.clone(),
+        }
+    }
+
+    fn to_device_event(encrypted: String) -> String {
+        json!({
+            "type": "m.room.encrypted",
+            "sender": "@alice:example.org",
+            "content": serde_json::from_str::<Value>(&encrypted).unwrap(),
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_decrypt_to_device_across_sessions() {
+        let alice = VodozemacAccount::new();
+        let bob = VodozemacAccount::new();
+        bob.generate_one_time_keys(2);
+        let bob_keys = device_keys(&bob, "@bob:example.org", "DEVICE");
+        let encrypt = |session: &VodozemacSession, event_type: &str| {
+            to_device_event(
+                encrypt_to_device(
+                    &alice,
+                    session,
+                    "@alice:example.org".to_owned(),
+                    "ALICE".to_owned(),
+                    &bob_keys,
+                    event_type.to_owned(),
+                    "{}".to_owned(),
+                )
+                .unwrap(),
+            )
+        };
+
+        let mut one_time_keys = bob.one_time_keys();
+        let old_session = alice.create_outbound_session(
+            SessionConfig::version_1().into(),
+            bob.curve25519_key(),
+            one_time_keys.pop().unwrap().key,
+        );
+        let new_session = alice.create_outbound_session(
+            SessionConfig::version_1().into(),
+            bob.curve25519_key(),
+            one_time_keys.pop().unwrap().key,
+        );
+
+        let first = encrypt(&old_session, "m.first");
+        let result = decrypt_to_device(&bob, vec![], first.clone()).unwrap();
+        let bob_old_session = result.new_session.unwrap();
+        assert_eq!(result.session_id, old_session.session_id());
+        assert_eq!(
+            decrypt_to_device(&bob, vec![handle(&bob_old_session)], first).err(),
+            Some(ToDeviceDecryptionError::DuplicateMessage)
+        );
+
+        let second = encrypt(&new_session, "m.second");
+        let result = decrypt_to_device(&bob, vec![handle(&bob_old_session)], second).unwrap();
+        let bob_new_session = result.new_session.unwrap();
+        assert_eq!(result.session_id, new_session.session_id());
+
+        let third = encrypt(&old_session, "m.third");
+        let result = decrypt_to_device(
+            &bob,
+            vec![handle(&bob_new_session), handle(&bob_old_session)],
+            third,
+        )
+        .unwrap();
+        assert!(result.new_session.is_none());
+        assert_eq!(result.session_id, old_session.session_id());
+        assert!(result.plaintext.contains("m.third"));
+    }
+
+    #[test]
+    fn test_undecryptable_normal_message_is_wedged() {
+        let alice = VodozemacAccount::new();
+        let bob = VodozemacAccount::new();
+        bob.generate_one_time_keys(1);
+        let session = alice.create_outbound_session(
+            SessionConfig::version_1().into(),
+            bob.curve25519_key(),
+            bob.one_time_keys().pop().unwrap().key,
+        );
+        let bob_keys = device_keys(&bob, "@bob:example.org", "DEVICE");
+        let encrypt = |event_type: &str| {
+            to_device_event(
+                encrypt_to_device(
+                    &alice,
+                    &session,
+                    "@alice:example.org".to_owned(),
+                    "ALICE".to_owned(),
+                    &bob_keys,
+                    event_type.to_owned(),
+                    "{}".to_owned(),
+                )
+                .unwrap(),
+            )
+        };
+
+        let bob_session = decrypt_to_device(&bob, vec![], encrypt("m.first"))
+            .unwrap()
+            .new_session
+            .unwrap();
+        let reply = bob_session.encrypt("reply".to_owned());
+        session.decrypt(reply).unwrap();
+
+        // Bob lost his session, so the normal message can't be decrypted anymore.
+        assert_eq!(
+            decrypt_to_device(&bob, vec![], encrypt("m.second")).err(),
+            Some(ToDeviceDecryptionError::SessionWedged)
+        );
+        assert_eq!(
+            decrypt_to_device(&VodozemacAccount::new(), vec![], encrypt("m.third")).err(),
+            Some(ToDeviceDecryptionError::NotForThisDevice)
+        );
+    }
+}
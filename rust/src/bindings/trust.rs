@@ -0,0 +1,406 @@
+//! Evaluation of device trust through the cross-signing chain.
+//! https://spec.matrix.org/v1.16/client-server-api/#cross-signing
+
+use serde_json::Value;
+use vodozemac::Ed25519PublicKey;
+
+use super::canonical_json::{check_signature, SignatureCheck};
+use super::device_keys::verify_self_signature;
+use super::VodozemacEd25519PublicKey;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceTrustLevel {
+    /// Signed by its owner's self-signing key, whose master key we verified.
+    CrossSignedVerified,
+    /// Signed by its owner's self-signing key, but we haven't verified the owner's master key.
+    CrossSignedUnverified,
+    /// Only carries a valid signature by its own device key.
+    SelfSigned,
+    /// One of the signatures in the chain is invalid, see `DeviceTrust::failed_link`.
+    SignatureInvalid,
+}
+
+/// A single signature in the chain from our master key down to a device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustLink {
+    /// The device keys signed by the device's own Ed25519 key.
+    DeviceSelfSignature,
+    /// The device keys signed by the owner's self-signing key.
+    DeviceBySelfSigningKey,
+    /// The owner's self-signing key signed by the owner's master key.
+    SelfSigningKeyByMasterKey,
+    /// The owner's master key signed by our user-signing key.
+    MasterKeyByUserSigningKey,
+}
+
+pub struct DeviceTrust {
+    pub user_id: String,
+    pub device_id: String,
+    pub level: DeviceTrustLevel,
+    /// Set when `level` is `SignatureInvalid`.
+    pub failed_link: Option<TrustLink>,
+}
+
+impl DeviceTrust {
+    fn new(user_id: &str, device_id: &str, level: DeviceTrustLevel) -> Self {
+        Self {
+            user_id: user_id.to_owned(),
+            device_id: device_id.to_owned(),
+            level,
+            failed_link: None,
+        }
+    }
+
+    fn invalid(user_id: &str, device_id: &str, link: TrustLink) -> Self {
+        Self {
+            failed_link: Some(link),
+            ..Self::new(user_id, device_id, DeviceTrustLevel::SignatureInvalid)
+        }
+    }
+}
+
+/// Evaluate the trust of every device in a `/keys/query` response.
+///
+/// `own_master_key` and `own_user_signing_key` must come from a trusted source, for example
+/// our `CrossSigningIdentity`, and not from the response itself.
+pub fn evaluate_device_trust(
+    own_user_id: String,
+    own_master_key: VodozemacEd25519PublicKey,
+    own_user_signing_key: Option<VodozemacEd25519PublicKey>,
+    keys_query_response: String,
+) -> anyhow::Result<Vec<DeviceTrust>> {
+    let response: Value = serde_json::from_str(&keys_query_response)?;
+    let evaluator = TrustEvaluator {
+        own_user_id: &own_user_id,
+        own_master_key: *own_master_key.key,
+        own_user_signing_key: own_user_signing_key.map(|k| *k.key),
+        response: &response,
+    };
+
+    let mut result = Vec::new();
+    let Some(users) = response["device_keys"].as_object() else {
+        return Ok(result);
+    };
+    for (user_id, devices) in users {
+        let identity = evaluator.identity(user_id);
+        let Some(devices) = devices.as_object() else {
+            continue;
+        };
+        for (device_id, device_keys) in devices {
+            result.push(identity.device_trust(user_id, device_id, device_keys));
+        }
+    }
+    Ok(result)
+}
+
+struct TrustEvaluator<'a> {
+    own_user_id: &'a str,
+    own_master_key: Ed25519PublicKey,
+    own_user_signing_key: Option<Ed25519PublicKey>,
+    response: &'a Value,
+}
+
+impl TrustEvaluator<'_> {
+    fn identity(&self, user_id: &str) -> UserIdentity {
+        let (Some(master_key), Some(self_signing_key)) = (
+            CrossSigningKey::parse(&self.response["master_keys"][user_id], user_id, "master"),
+            CrossSigningKey::parse(
+                &self.response["self_signing_keys"][user_id],
+                user_id,
+                "self_signing",
+            ),
+        ) else {
+            return UserIdentity {
+                self_signing_key: None,
+                chain: Ok(false),
+            };
+        };
+
+        let chain = if self_signing_key.signed_by(user_id, &master_key) != SignatureCheck::Valid {
+            Err(TrustLink::SelfSigningKeyByMasterKey)
+        } else {
+            self.master_key_verified(user_id, &master_key)
+        };
+        UserIdentity {
+            self_signing_key: Some(self_signing_key),
+            chain,
+        }
+    }
+
+    fn master_key_verified(
+        &self,
+        user_id: &str,
+        master_key: &CrossSigningKey,
+    ) -> Result<bool, TrustLink> {
+        if user_id == self.own_user_id {
+            return Ok(master_key.public_key == self.own_master_key);
+        }
+        let Some(user_signing_key) = self.own_user_signing_key else {
+            return Ok(false);
+        };
+        let key_id = format!("ed25519:{}", user_signing_key.to_base64());
+        match check_signature(
+            &master_key.value,
+            self.own_user_id,
+            &key_id,
+            &user_signing_key,
+        ) {
+            SignatureCheck::Valid => Ok(true),
+            SignatureCheck::Missing => Ok(false),
+            SignatureCheck::Invalid => Err(TrustLink::MasterKeyByUserSigningKey),
+        }
+    }
+}
+
+struct UserIdentity {
+    self_signing_key: Option<CrossSigningKey>,
+    /// Whether the self-signing key chains up to a master key we verified, or the link that
+    /// broke the chain.
+    chain: Result<bool, TrustLink>,
+}
+
+impl UserIdentity {
+    fn device_trust(&self, user_id: &str, device_id: &str, device_keys: &Value) -> DeviceTrust {
+        if verify_self_signature(device_keys, user_id, device_id).is_err() {
+            return DeviceTrust::invalid(user_id, device_id, TrustLink::DeviceSelfSignature);
+        }
+        let Some(self_signing_key) = &self.self_signing_key else {
+            return DeviceTrust::new(user_id, device_id, DeviceTrustLevel::SelfSigned);
+        };
+        let key_id = format!("ed25519:{}", self_signing_key.public_key.to_base64());
+        match check_signature(device_keys, user_id, &key_id, &self_signing_key.public_key) {
+            SignatureCheck::Valid => {}
+            SignatureCheck::Missing => {
+                return DeviceTrust::new(user_id, device_id, DeviceTrustLevel::SelfSigned)
+            }
+            SignatureCheck::Invalid => {
+                return DeviceTrust::invalid(user_id, device_id, TrustLink::DeviceBySelfSigningKey)
+            }
+        }
+        match self.chain {
+            Ok(true) => DeviceTrust::new(user_id, device_id, DeviceTrustLevel::CrossSignedVerified),
+            Ok(false) => {
+                DeviceTrust::new(user_id, device_id, DeviceTrustLevel::CrossSignedUnverified)
+            }
+            Err(link) => DeviceTrust::invalid(user_id, device_id, link),
+        }
+    }
+}
+
+/// A master, self-signing or user-signing key object from `/keys/query`.
+struct CrossSigningKey {
+    public_key: Ed25519PublicKey,
+    value: Value,
+}
+
+impl CrossSigningKey {
+    fn parse(value: &Value, user_id: &str, usage: &str) -> Option<Self> {
+        if value["user_id"] != user_id
+            || !value["usage"]
+                .as_array()
+                .is_some_and(|u| u.iter().any(|u| u == usage))
+        {
+            return None;
+        }
+        let keys = value["keys"].as_object()?;
+        if keys.len() != 1 {
+            return None;
+        }
+        let (key_id, key) = keys.iter().next()?;
+        let public_key = Ed25519PublicKey::from_base64(key.as_str()?).ok()?;
+        if *key_id != format!("ed25519:{}", public_key.to_base64()) {
+            return None;
+        }
+        Some(Self {
+            public_key,
+            value: value.clone(),
+        })
+    }
+
+    fn signed_by(&self, user_id: &str, signer: &CrossSigningKey) -> SignatureCheck {
+        let key_id = format!("ed25519:{}", signer.public_key.to_base64());
+        check_signature(&self.value, user_id, &key_id, &signer.public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::cross_signing::CrossSigningIdentity;
+    use super::super::test_utils::signed_device_keys;
+    use super::super::PkSigning;
+    use super::*;
+    use serde_json::json;
+    use vodozemac::olm::Account;
+
+    fn cross_signed(identity: &CrossSigningIdentity, keys: Value) -> Value {
+        let user_id = identity.user_id();
+        let device_id = keys["device_id"].as_str().unwrap().to_owned();
+        let upload: Value =
+            serde_json::from_str(&identity.sign_device(keys.to_string()).unwrap()).unwrap();
+        upload[user_id][device_id].clone()
+    }
+
+    fn query_response(identities: &[&CrossSigningIdentity], devices: Value) -> Value {
+        let mut response = json!({
+            "device_keys": devices,
+            "master_keys": {},
+            "self_signing_keys": {},
+        });
+        for identity in identities {
+            let upload: Value =
+                serde_json::from_str(&identity.device_signing_upload().unwrap()).unwrap();
+            response["master_keys"][identity.user_id()] = upload["master_key"].clone();
+            response["self_signing_keys"][identity.user_id()] = upload["self_signing_key"].clone();
+        }
+        response
+    }
+
+    fn trust_of(trust: &[DeviceTrust], device_id: &str) -> (DeviceTrustLevel, Option<TrustLink>) {
+        let device = trust.iter().find(|d| d.device_id == device_id).unwrap();
+        (device.level, device.failed_link)
+    }
+
+    #[test]
+    fn test_own_and_verified_users_devices() {
+        let alice = CrossSigningIdentity::new("@alice:example.org".to_owned());
+        let bob = CrossSigningIdentity::new("@bob:example.org".to_owned());
+        let alice_device = Account::new();
+        let bob_device = Account::new();
+        let bob_other_device = Account::new();
+
+        let mut response = query_response(
+            &[&alice, &bob],
+            json!({
+                "@alice:example.org": {
+                    "ALICE": cross_signed(&alice, signed_device_keys(&alice_device, "@alice:example.org", "ALICE")),
+                },
+                "@bob:example.org": {
+                    "BOB": cross_signed(&bob, signed_device_keys(&bob_device, "@bob:example.org", "BOB")),
+                    "BOB2": signed_device_keys(&bob_other_device, "@bob:example.org", "BOB2"),
+                },
+            }),
+        );
+
+        let trust = evaluate_device_trust(
+            alice.user_id(),
+            alice.master_key(),
+            Some(alice.user_signing_key()),
+            response.to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            trust_of(&trust, "ALICE"),
+            (DeviceTrustLevel::CrossSignedVerified, None)
+        );
+        assert_eq!(
+            trust_of(&trust, "BOB"),
+            (DeviceTrustLevel::CrossSignedUnverified, None)
+        );
+        assert_eq!(
+            trust_of(&trust, "BOB2"),
+            (DeviceTrustLevel::SelfSigned, None)
+        );
+
+        let signed: Value = serde_json::from_str(
+            &alice
+                .sign_user(response["master_keys"]["@bob:example.org"].to_string())
+                .unwrap(),
+        )
+        .unwrap();
+        response["master_keys"]["@bob:example.org"] =
+            signed["@bob:example.org"][bob.master_key().to_base64()].clone();
+
+        let trust = evaluate_device_trust(
+            alice.user_id(),
+            alice.master_key(),
+            Some(alice.user_signing_key()),
+            response.to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            trust_of(&trust, "BOB"),
+            (DeviceTrustLevel::CrossSignedVerified, None)
+        );
+    }
+
+    #[test]
+    fn test_invalid_links_are_reported() {
+        let alice = CrossSigningIdentity::new("@alice:example.org".to_owned());
+        let bob = CrossSigningIdentity::new("@bob:example.org".to_owned());
+        let mallory = CrossSigningIdentity::new("@bob:example.org".to_owned());
+
+        let mut tampered = signed_device_keys(&Account::new(), "@bob:example.org", "TAMPERED");
+        tampered["algorithms"] = json!(["m.olm.v1.curve25519-aes-sha2"]);
+        let mut response = query_response(
+            &[&bob],
+            json!({
+                "@bob:example.org": {
+                    "TAMPERED": tampered,
+                    "FORGED": cross_signed(&bob, signed_device_keys(&Account::new(), "@bob:example.org", "FORGED")),
+                },
+            }),
+        );
+        response["device_keys"]["@bob:example.org"]["FORGED"]["signatures"]["@bob:example.org"]
+            [format!("ed25519:{}", bob.self_signing_key().to_base64())] =
+            json!(PkSigning::from_secret_key(&bob.self_signing_secret_key())
+                .unwrap()
+                .sign("something else")
+                .to_base64());
+
+        let trust = evaluate_device_trust(
+            alice.user_id(),
+            alice.master_key(),
+            Some(alice.user_signing_key()),
+            response.to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            trust_of(&trust, "TAMPERED"),
+            (
+                DeviceTrustLevel::SignatureInvalid,
+                Some(TrustLink::DeviceSelfSignature)
+            )
+        );
+        assert_eq!(
+            trust_of(&trust, "FORGED"),
+            (
+                DeviceTrustLevel::SignatureInvalid,
+                Some(TrustLink::DeviceBySelfSigningKey)
+            )
+        );
+
+        // A self-signing key signed by a different master key breaks the chain.
+        let mut response = query_response(
+            &[&bob],
+            json!({
+                "@bob:example.org": {
+                    "BOB": cross_signed(&bob, signed_device_keys(&Account::new(), "@bob:example.org", "BOB")),
+                },
+            }),
+        );
+        let mallory_upload: Value =
+            serde_json::from_str(&mallory.device_signing_upload().unwrap()).unwrap();
+        let mut self_signing_key = response["self_signing_keys"]["@bob:example.org"].clone();
+        self_signing_key["signatures"] = mallory_upload["self_signing_key"]["signatures"].clone();
+        let mallory_key_id = format!("ed25519:{}", mallory.master_key().to_base64());
+        let bob_key_id = format!("ed25519:{}", bob.master_key().to_base64());
+        let forged = self_signing_key["signatures"]["@bob:example.org"][&mallory_key_id].clone();
+        self_signing_key["signatures"]["@bob:example.org"][&bob_key_id] = forged;
+        response["self_signing_keys"]["@bob:example.org"] = self_signing_key;
+
+        let trust = evaluate_device_trust(
+            alice.user_id(),
+            alice.master_key(),
+            None,
+            response.to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            trust_of(&trust, "BOB"),
+            (
+                DeviceTrustLevel::SignatureInvalid,
+                Some(TrustLink::SelfSigningKeyByMasterKey)
+            )
+        );
+    }
+}
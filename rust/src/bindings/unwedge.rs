@@ -0,0 +1,318 @@
+//! Recovering from wedged Olm sessions.
+//! https://spec.matrix.org/v1.16/client-server-api/#recovering-from-undecryptable-messages
+//!
+//! When a to-device message can't be decrypted with any session, the sender is asked to
+//! establish a new session by sending it an `m.dummy` event over a freshly created one. To
+//! avoid claiming all of a device's one-time keys, this is done at most once per cooldown:
+//! `UnwedgeTracker::start_attempt` has to succeed before a key is claimed for the device, and
+//! each started attempt creates at most one session.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::device_keys::DeviceKeys;
+use super::pickle::{decrypt_pickle, encrypt_pickle};
+use super::to_device::encrypt_to_device;
+use super::{RustOpaqueNom, RwLock, VodozemacAccount, VodozemacOlmSessionConfig, VodozemacSession};
+
+/// The cooldown the spec suggests between two attempts for the same device.
+const DEFAULT_COOLDOWN_MS: u64 = 60 * 60 * 1000;
+
+#[derive(Serialize, Deserialize)]
+pub struct UnwedgeState {
+    cooldown_ms: u64,
+    /// The time of the last attempt, keyed by the Curve25519 key of the device.
+    last_attempts: HashMap<String, u64>,
+    /// The devices with a started attempt that hasn't created a session yet.
+    #[serde(default)]
+    pending_attempts: HashSet<String>,
+}
+
+impl UnwedgeState {
+    fn can_unwedge(&self, sender_key: &str, now_ms: u64) -> bool {
+        self.last_attempts
+            .get(sender_key)
+            .is_none_or(|last| now_ms.saturating_sub(*last) >= self.cooldown_ms)
+    }
+
+    fn record_attempt(&mut self, sender_key: String, now_ms: u64) {
+        let cooldown_ms = self.cooldown_ms;
+        // Attempts that are past the cooldown don't limit anything anymore.
+        self.last_attempts
+            .retain(|_, last| now_ms.saturating_sub(*last) < cooldown_ms);
+        self.last_attempts.insert(sender_key.clone(), now_ms);
+        self.pending_attempts.insert(sender_key);
+    }
+
+    /// Consume the started attempt for the device, returning whether there was one.
+    fn take_attempt(&mut self, sender_key: &str) -> bool {
+        self.pending_attempts.remove(sender_key)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum UnwedgeError {
+    #[error("The device was already unwedged within the cooldown")]
+    RateLimited,
+    #[error("No attempt to unwedge the device was started")]
+    NotStarted,
+    #[error("The claimed one-time key was rejected")]
+    InvalidClaimedKey,
+}
+
+/// The unwedging attempts made per device, to be pickled next to the account.
+pub struct UnwedgeTracker {
+    pub state: RustOpaqueNom<RwLock<UnwedgeState>>,
+}
+
+impl Default for UnwedgeTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UnwedgeTracker {
+    pub fn new() -> Self {
+        UnwedgeState {
+            cooldown_ms: DEFAULT_COOLDOWN_MS,
+            last_attempts: HashMap::new(),
+            pending_attempts: HashSet::new(),
+        }
+        .into()
+    }
+
+    pub fn cooldown_ms(&self) -> u64 {
+        self.state.read().expect("Failed to read state").cooldown_ms
+    }
+
+    pub fn set_cooldown_ms(&self, cooldown_ms: u64) {
+        self.state
+            .write()
+            .expect("Failed to write state")
+            .cooldown_ms = cooldown_ms;
+    }
+
+    /// Whether a new session may be created for the device with the given Curve25519 key.
+    pub fn can_unwedge(&self, sender_key: String, now_ms: u64) -> bool {
+        self.state
+            .read()
+            .expect("Failed to read state")
+            .can_unwedge(&sender_key, now_ms)
+    }
+
+    /// Record an attempt to unwedge the device with the given Curve25519 key, before claiming a
+    /// one-time key for it. The attempt allows a single call to `unwedge_session`.
+    ///
+    /// Fails with `RateLimited` if the device was already unwedged within the cooldown, in
+    /// which case no key must be claimed.
+    pub fn start_attempt(&self, sender_key: String, now_ms: u64) -> Result<(), UnwedgeError> {
+        let mut state = self.state.write().expect("Failed to write state");
+        if !state.can_unwedge(&sender_key, now_ms) {
+            return Err(UnwedgeError::RateLimited);
+        }
+        state.record_attempt(sender_key, now_ms);
+        Ok(())
+    }
+
+    pub fn pickle_encrypted(&self, pickle_key: [u8; 32usize]) -> String {
+        encrypt_pickle(
+            &*self.state.read().expect("Failed to read state"),
+            &pickle_key,
+        )
+    }
+
+    pub fn from_pickle_encrypted(
+        pickle: String,
+        pickle_key: [u8; 32usize],
+    ) -> anyhow::Result<Self> {
+        Ok(decrypt_pickle::<UnwedgeState>(&pickle, &pickle_key)?.into())
+    }
+}
+
+impl From<UnwedgeState> for UnwedgeTracker {
+    fn from(state: UnwedgeState) -> Self {
+        Self {
+            state: RustOpaqueNom::new(RwLock::new(state)),
+        }
+    }
+}
+
+/// A new session with a device and the `m.room.encrypted` content of the `m.dummy` event that
+/// has to be sent to it.
+pub struct UnwedgeResult {
+    pub session: VodozemacSession,
+    pub encrypted_content: String,
+}
+
+impl VodozemacAccount {
+    /// Create a new outbound session with a device we failed to decrypt a message from.
+    ///
+    /// `claimed_key_json` is the one-time key claimed for the device after
+    /// `tracker.start_attempt` succeeded, see `create_outbound_session_from_claim`. Each call
+    /// consumes the started attempt, even if the claimed key is rejected, and fails with
+    /// `NotStarted` if there is none. The returned session has to be stored like any other.
+    pub fn unwedge_session(
+        &self,
+        tracker: &UnwedgeTracker,
+        config: VodozemacOlmSessionConfig,
+        own_user_id: String,
+        own_device_id: String,
+        device_keys: &DeviceKeys,
+        claimed_key_json: String,
+    ) -> Result<UnwedgeResult, UnwedgeError> {
+        let started = tracker
+            .state
+            .write()
+            .expect("Failed to write state")
+            .take_attempt(&device_keys.curve25519.to_base64());
+        if !started {
+            return Err(UnwedgeError::NotStarted);
+        }
+
+        let session = self
+            .create_outbound_session_from_claim(config, device_keys, claimed_key_json)
+            .map_err(|_| UnwedgeError::InvalidClaimedKey)?
+            .session;
+
+        let encrypted_content = encrypt_to_device(
+            self,
+            &session,
+            own_user_id,
+            own_device_id,
+            device_keys,
+            "m.dummy".to_owned(),
+            "{}".to_owned(),
+        )
+        .expect("The m.dummy content is valid JSON");
+
+        Ok(UnwedgeResult {
+            session,
+            encrypted_content,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::canonical_json::{add_signature, signable_json};
+    use super::super::test_utils::device_keys;
+    use super::super::to_device::decrypt_to_device;
+    use super::*;
+    use serde_json::{json, Value};
+    use vodozemac::olm::SessionConfig;
+
+    fn claim(account: &VodozemacAccount) -> String {
+        account.generate_one_time_keys(1);
+        let one_time_key = account.one_time_keys().pop().unwrap();
+        account.mark_keys_as_published();
+        let mut key = json!({ "key": one_time_key.key.to_base64() });
+        let signature = account.sign(signable_json(&key).unwrap()).to_base64();
+        add_signature(&mut key, "@bob:example.org", "ed25519:BOB", signature).unwrap();
+        json!({ format!("signed_curve25519:{}", one_time_key.keyid): key }).to_string()
+    }
+
+    /// Start an attempt and only claim a key of `bob` if that succeeds.
+    fn unwedge(
+        alice: &VodozemacAccount,
+        tracker: &UnwedgeTracker,
+        bob: &VodozemacAccount,
+        now_ms: u64,
+    ) -> Result<UnwedgeResult, UnwedgeError> {
+        tracker.start_attempt(bob.curve25519_key().to_base64(), now_ms)?;
+        alice.unwedge_session(
+            tracker,
+            SessionConfig::version_1().into(),
+            "@alice:example.org".to_owned(),
+            "ALICE".to_owned(),
+            &device_keys(bob, "@bob:example.org", "BOB"),
+            claim(bob),
+        )
+    }
+
+    #[test]
+    fn test_unwedging_sends_dummy_and_respects_cooldown() {
+        let alice = VodozemacAccount::new();
+        let bob = VodozemacAccount::new();
+        let tracker = UnwedgeTracker::new();
+        tracker.set_cooldown_ms(1000);
+
+        let result = unwedge(&alice, &tracker, &bob, 5000).unwrap();
+        let event = json!({
+            "type": "m.room.encrypted",
+            "sender": "@alice:example.org",
+            "content": serde_json::from_str::<Value>(&result.encrypted_content).unwrap(),
+        });
+        let decrypted = decrypt_to_device(&bob, vec![], event.to_string()).unwrap();
+        let plaintext: Value = serde_json::from_str(&decrypted.plaintext).unwrap();
+        assert_eq!(plaintext["type"], "m.dummy");
+        assert_eq!(
+            decrypted.new_session.unwrap().session_id(),
+            result.session.session_id()
+        );
+
+        let bob_key = bob.curve25519_key().to_base64();
+        assert!(!tracker.can_unwedge(bob_key.clone(), 5999));
+        assert_eq!(
+            unwedge(&alice, &tracker, &bob, 5999).err(),
+            Some(UnwedgeError::RateLimited)
+        );
+        assert!(tracker.can_unwedge(bob_key, 6000));
+        assert!(unwedge(&alice, &tracker, &bob, 6000).is_ok());
+    }
+
+    #[test]
+    fn test_unwedging_requires_a_started_attempt() {
+        let alice = VodozemacAccount::new();
+        let bob = VodozemacAccount::new();
+        let bob_key = bob.curve25519_key().to_base64();
+        let tracker = UnwedgeTracker::new();
+        // Without a cooldown every attempt may start, but each still allows a single session.
+        tracker.set_cooldown_ms(0);
+        let unwedge_session = |claimed_key_json| {
+            alice
+                .unwedge_session(
+                    &tracker,
+                    SessionConfig::version_1().into(),
+                    "@alice:example.org".to_owned(),
+                    "ALICE".to_owned(),
+                    &device_keys(&bob, "@bob:example.org", "BOB"),
+                    claimed_key_json,
+                )
+                .err()
+        };
+
+        assert_eq!(unwedge_session(claim(&bob)), Some(UnwedgeError::NotStarted));
+        tracker.start_attempt(bob_key.clone(), 5000).unwrap();
+        assert_eq!(
+            unwedge_session("{}".to_owned()),
+            Some(UnwedgeError::InvalidClaimedKey)
+        );
+        // The rejected key used up the attempt.
+        assert_eq!(unwedge_session(claim(&bob)), Some(UnwedgeError::NotStarted));
+
+        tracker.start_attempt(bob_key, 5000).unwrap();
+        assert_eq!(unwedge_session(claim(&bob)), None);
+        assert_eq!(unwedge_session(claim(&bob)), Some(UnwedgeError::NotStarted));
+    }
+
+    #[test]
+    fn test_state_survives_pickling() {
+        let alice = VodozemacAccount::new();
+        let bob = VodozemacAccount::new();
+        let tracker = UnwedgeTracker::new();
+        unwedge(&alice, &tracker, &bob, 5000).unwrap();
+
+        let pickle_key = [3u8; 32];
+        let tracker =
+            UnwedgeTracker::from_pickle_encrypted(tracker.pickle_encrypted(pickle_key), pickle_key)
+                .unwrap();
+        assert_eq!(tracker.cooldown_ms(), DEFAULT_COOLDOWN_MS);
+        assert!(!tracker.can_unwedge(bob.curve25519_key().to_base64(), 6000));
+        assert!(UnwedgeTracker::from_pickle_encrypted(
+            tracker.pickle_encrypted(pickle_key),
+            [4u8; 32]
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,211 @@
+//! Classification of room events that couldn't be decrypted, for reporting.
+
+use serde_json::Value;
+use vodozemac::megolm::{DecryptionError, MegolmMessage};
+
+use super::room_event::{MEGOLM_V1_ALGORITHM, MEGOLM_V2_ALGORITHM};
+use super::room_key_info::InboundRoomKey;
+use super::share_tracking::WithheldCode;
+
+/// Why a room event couldn't be decrypted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UtdCause {
+    /// We don't have the session the event was encrypted with.
+    SessionUnknown,
+    /// We have the session, but only from a later message index on.
+    IndexTooEarly,
+    /// The MAC or signature of the message doesn't match the session.
+    MacFailure,
+    /// The sender told us that it won't share the session with us.
+    Withheld,
+    /// The event was sent before this device existed, so it was never shared with it.
+    SentBeforeDeviceCreated,
+    /// The content or the ciphertext can't be parsed.
+    MalformedCiphertext,
+    /// The session or the decrypted event belong to a different room.
+    RoomMismatch,
+}
+
+pub struct UtdClassification {
+    pub cause: UtdCause,
+    /// The code of the `m.room_key.withheld` if the cause is `Withheld`.
+    pub withheld_code: Option<WithheldCode>,
+}
+
+impl From<UtdCause> for UtdClassification {
+    fn from(cause: UtdCause) -> Self {
+        Self {
+            cause,
+            withheld_code: None,
+        }
+    }
+}
+
+/// Figure out why the `m.room.encrypted` content of an event received in `room_id` can't be
+/// decrypted.
+///
+/// `candidates` are the sessions we know with the session id of the event, `withheld_code` the
+/// code of an `m.room_key.withheld` we received for it. Returns `None` if one of the candidates
+/// can decrypt the event after all.
+pub fn classify_utd(
+    room_id: String,
+    encrypted_content: String,
+    candidates: Vec<InboundRoomKey>,
+    withheld_code: Option<WithheldCode>,
+    origin_server_ts: u64,
+    device_created_at_ms: u64,
+) -> Option<UtdClassification> {
+    let Some((session_id, message)) = parse(&encrypted_content) else {
+        return Some(UtdCause::MalformedCiphertext.into());
+    };
+
+    let mut cause = None;
+    for candidate in candidates.iter().filter(|c| c.session_id() == session_id) {
+        let candidate_cause = if candidate.info().room_id != room_id {
+            UtdCause::RoomMismatch
+        } else {
+            let decrypted = candidate
+                .session
+                .write()
+                .expect("Failed to write session")
+                .decrypt(&message);
+            match decrypted {
+                Ok(decrypted) => match serde_json::from_slice::<Value>(&decrypted.plaintext) {
+                    Ok(payload) if payload["room_id"] == room_id.as_str() => return None,
+                    Ok(_) => UtdCause::RoomMismatch,
+                    Err(_) => UtdCause::MalformedCiphertext,
+                },
+                Err(DecryptionError::UnknownMessageIndex(..)) => UtdCause::IndexTooEarly,
+                Err(_) => UtdCause::MacFailure,
+            }
+        };
+        // An index that is too early is the most useful thing to report, another copy of the
+        // session may fix it.
+        if cause != Some(UtdCause::IndexTooEarly) {
+            cause = Some(candidate_cause);
+        }
+    }
+
+    Some(match (cause, withheld_code) {
+        (Some(cause), _) => cause.into(),
+        (None, Some(code)) => UtdClassification {
+            cause: UtdCause::Withheld,
+            withheld_code: Some(code),
+        },
+        (None, None) if origin_server_ts < device_created_at_ms => {
+            UtdCause::SentBeforeDeviceCreated.into()
+        }
+        (None, None) => UtdCause::SessionUnknown.into(),
+    })
+}
+
+fn parse(encrypted_content: &str) -> Option<(String, MegolmMessage)> {
+    let content: Value = serde_json::from_str(encrypted_content).ok()?;
+    let algorithm = content["algorithm"].as_str()?;
+    if algorithm != MEGOLM_V1_ALGORITHM && algorithm != MEGOLM_V2_ALGORITHM {
+        return None;
+    }
+    let message = MegolmMessage::from_base64(content["ciphertext"].as_str()?).ok()?;
+    Some((content["session_id"].as_str()?.to_owned(), message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::room_event::encrypt_room_event;
+    use super::super::room_key_info::RoomKeyOrigin;
+    use super::super::test_utils::room_key_info;
+    use super::super::{VodozemacAccount, VodozemacGroupSession, VodozemacInboundGroupSession};
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+
+    const ROOM: &str = "!room:example.org";
+
+    fn room_key(session: &VodozemacInboundGroupSession, room_id: &str) -> InboundRoomKey {
+        InboundRoomKey::new(session, room_key_info(room_id, RoomKeyOrigin::Direct))
+    }
+
+    fn classify(
+        encrypted: &str,
+        candidates: Vec<InboundRoomKey>,
+        withheld_code: Option<WithheldCode>,
+        origin_server_ts: u64,
+    ) -> Option<UtdCause> {
+        classify_utd(
+            ROOM.to_owned(),
+            encrypted.to_owned(),
+            candidates,
+            withheld_code,
+            origin_server_ts,
+            1000,
+        )
+        .map(|c| c.cause)
+    }
+
+    #[test]
+    fn test_classification() {
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let inbound = outbound.to_inbound();
+        let encrypted = encrypt_room_event(
+            &outbound,
+            &VodozemacAccount::new().curve25519_key(),
+            "DEVICE".to_owned(),
+            ROOM.to_owned(),
+            "m.room.message".to_owned(),
+            "{}".to_owned(),
+        )
+        .unwrap();
+        let late = outbound.to_inbound();
+
+        assert_eq!(
+            classify(&encrypted, vec![room_key(&inbound, ROOM)], None, 2000),
+            None
+        );
+        assert_eq!(
+            classify(&encrypted, vec![room_key(&late, ROOM)], None, 2000),
+            Some(UtdCause::IndexTooEarly)
+        );
+        assert_eq!(
+            classify(
+                &encrypted,
+                vec![room_key(&inbound, "!other:example.org")],
+                None,
+                2000
+            ),
+            Some(UtdCause::RoomMismatch)
+        );
+        assert_eq!(
+            classify(&encrypted, vec![], None, 2000),
+            Some(UtdCause::SessionUnknown)
+        );
+        assert_eq!(
+            classify(&encrypted, vec![], None, 500),
+            Some(UtdCause::SentBeforeDeviceCreated)
+        );
+        assert_eq!(
+            classify("{}", vec![], None, 2000),
+            Some(UtdCause::MalformedCiphertext)
+        );
+
+        let withheld = classify_utd(
+            ROOM.to_owned(),
+            encrypted.clone(),
+            vec![],
+            Some(WithheldCode::Unverified),
+            2000,
+            1000,
+        )
+        .unwrap();
+        assert_eq!(withheld.cause, UtdCause::Withheld);
+        assert_eq!(withheld.withheld_code, Some(WithheldCode::Unverified));
+
+        // A message of another session isn't signed with the key of the candidate.
+        let mut tampered: Value = serde_json::from_str(&encrypted).unwrap();
+        let other = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        tampered["ciphertext"] = Value::String(other.encrypt("forged".to_owned()));
+        let candidate = room_key(&inbound, ROOM);
+        assert_eq!(
+            classify(&tampered.to_string(), vec![candidate], None, 2000),
+            Some(UtdCause::MacFailure)
+        );
+    }
+}
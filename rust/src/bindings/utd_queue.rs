@@ -0,0 +1,376 @@
+//! Room events that couldn't be decrypted yet, retried once the missing session arrives.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use vodozemac::megolm::InboundGroupSession;
+
+use super::pickle::{decrypt_pickle, encrypt_pickle};
+use super::room_event::RoomEventError;
+use super::room_key_info::{InboundRoomKey, RoomKeyDecryptionResult, RoomKeyOrigin};
+use super::{RustOpaqueNom, RwLock};
+
+/// How many events are kept unless configured otherwise.
+const DEFAULT_MAX_EVENTS: u32 = 1000;
+
+#[derive(Serialize, Deserialize)]
+struct PendingEvent {
+    room_id: String,
+    session_id: String,
+    event_id: String,
+    encrypted_content: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UtdQueueState {
+    max_events: u32,
+    /// Oldest first.
+    events: VecDeque<PendingEvent>,
+}
+
+/// An event of the queue that could be decrypted with a new session.
+pub struct RetriedEvent {
+    pub event_id: String,
+    pub result: RoomKeyDecryptionResult,
+}
+
+/// Undecryptable room events, keyed by room and session id.
+pub struct UtdQueue {
+    pub state: RustOpaqueNom<RwLock<UtdQueueState>>,
+}
+
+impl Default for UtdQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<UtdQueueState> for UtdQueue {
+    fn from(state: UtdQueueState) -> Self {
+        Self {
+            state: RustOpaqueNom::new(RwLock::new(state)),
+        }
+    }
+}
+
+impl UtdQueue {
+    pub fn new() -> Self {
+        UtdQueueState {
+            max_events: DEFAULT_MAX_EVENTS,
+            events: VecDeque::new(),
+        }
+        .into()
+    }
+
+    pub fn max_events(&self) -> u32 {
+        self.state.read().expect("Failed to read queue").max_events
+    }
+
+    /// Change the limit, returning the ids of the oldest events dropped because of it.
+    pub fn set_max_events(&self, max_events: u32) -> Vec<String> {
+        let mut state = self.state.write().expect("Failed to write queue");
+        state.max_events = max_events;
+        Self::evict(&mut state)
+    }
+
+    fn evict(state: &mut UtdQueueState) -> Vec<String> {
+        let mut evicted = vec![];
+        while state.events.len() > state.max_events as usize {
+            if let Some(event) = state.events.pop_front() {
+                evicted.push(event.event_id);
+            }
+        }
+        evicted
+    }
+
+    /// Queue an `m.room.encrypted` content that couldn't be decrypted.
+    ///
+    /// Returns the ids of the oldest events that were dropped to stay within the limit. Adding
+    /// an event that is already queued does nothing.
+    pub fn add_event(
+        &self,
+        room_id: String,
+        event_id: String,
+        encrypted_content: String,
+    ) -> Result<Vec<String>, RoomEventError> {
+        let content: Value =
+            serde_json::from_str(&encrypted_content).map_err(|_| RoomEventError::MalformedEvent)?;
+        let session_id = content["session_id"]
+            .as_str()
+            .ok_or(RoomEventError::MalformedEvent)?
+            .to_owned();
+
+        let mut state = self.state.write().expect("Failed to write queue");
+        if state.events.iter().any(|e| e.event_id == event_id) {
+            return Ok(vec![]);
+        }
+        state.events.push_back(PendingEvent {
+            room_id,
+            session_id,
+            event_id,
+            encrypted_content,
+        });
+        Ok(Self::evict(&mut state))
+    }
+
+    /// Drop an event, e.g. because it was redacted. Returns false if it wasn't queued.
+    pub fn remove_event(&self, event_id: String) -> bool {
+        let mut state = self.state.write().expect("Failed to write queue");
+        let before = state.events.len();
+        state.events.retain(|e| e.event_id != event_id);
+        state.events.len() != before
+    }
+
+    pub fn len(&self) -> usize {
+        self.state
+            .read()
+            .expect("Failed to read queue")
+            .events
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The ids of the queued events waiting for a session.
+    pub fn pending_events(&self, room_id: String, session_id: String) -> Vec<String> {
+        self.state
+            .read()
+            .expect("Failed to read queue")
+            .events
+            .iter()
+            .filter(|e| e.room_id == room_id && e.session_id == session_id)
+            .map(|e| e.event_id.clone())
+            .collect()
+    }
+
+    /// Retry the events waiting for the session of `room_key`.
+    ///
+    /// The events that could be decrypted are removed from the queue and returned. Events the
+    /// session can't decrypt, e.g. because it only knows later message indices, stay queued.
+    pub fn add_room_key(&self, room_key: &InboundRoomKey) -> Vec<RetriedEvent> {
+        let room_id = room_key.info().room_id;
+        let session_id = room_key.session_id();
+
+        let mut state = self.state.write().expect("Failed to write queue");
+        let mut retried = vec![];
+        state.events.retain(|event| {
+            if event.room_id != room_id || event.session_id != session_id {
+                return true;
+            }
+            match room_key
+                .decrypt_room_event(event.room_id.clone(), event.encrypted_content.clone())
+            {
+                Ok(result) => {
+                    retried.push(RetriedEvent {
+                        event_id: event.event_id.clone(),
+                        result,
+                    });
+                    false
+                }
+                Err(_) => true,
+            }
+        });
+        retried
+    }
+
+    /// Merge `other` into `existing`, another copy of the same session, and retry the events
+    /// waiting for it.
+    ///
+    /// The merged session knows the earliest message index of both, and comes from a
+    /// `SessionKey` if either copy did. If `other` was received directly from the creator of
+    /// the session, its info replaces the one of `existing`.
+    /// Merging a session with itself changes nothing.
+    pub fn merge_room_key(
+        &self,
+        existing: &InboundRoomKey,
+        other: &InboundRoomKey,
+    ) -> anyhow::Result<Vec<RetriedEvent>> {
+        // Both keys may wrap the same session, merging it with itself changes nothing.
+        if std::ptr::eq(&*existing.session, &*other.session) {
+            return Ok(vec![]);
+        }
+
+        // Merge with a copy of `other`, so only one session is locked at a time. Holding both
+        // locks could deadlock with a concurrent merge in the other direction.
+        let mut other_session = InboundGroupSession::from(
+            other
+                .session
+                .read()
+                .expect("Failed to read session")
+                .pickle(),
+        );
+        {
+            let mut session = existing.session.write().expect("Failed to write session");
+            let merged = session.merge(&mut other_session).ok_or_else(|| {
+                anyhow::anyhow!("The sessions are not copies of the same session")
+            })?;
+            *session = merged;
+        }
+        let key_source = existing
+            .inbound_group_session()
+            .key_source()
+            .merged(other.inbound_group_session().key_source());
+        *existing.source.write().expect("Failed to write key source") = key_source;
+
+        let other_info = other.info();
+        if other_info.origin == RoomKeyOrigin::Direct
+            && existing.info().origin != RoomKeyOrigin::Direct
+        {
+            *existing.key_info.write().expect("Failed to write info") = other_info;
+        }
+        // The merged session may know more indices than the backed up copy.
+        existing.mark_backed_up(false);
+
+        Ok(self.add_room_key(existing))
+    }
+
+    pub fn pickle_encrypted(&self, pickle_key: [u8; 32usize]) -> String {
+        encrypt_pickle(
+            &*self.state.read().expect("Failed to read queue"),
+            &pickle_key,
+        )
+    }
+
+    pub fn from_pickle_encrypted(
+        pickle: String,
+        pickle_key: [u8; 32usize],
+    ) -> anyhow::Result<Self> {
+        Ok(decrypt_pickle::<UtdQueueState>(&pickle, &pickle_key)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::megolm_signing::SessionKeySource;
+    use super::super::room_event::encrypt_room_event;
+    use super::super::room_key_info::{KeyAuthenticity, RoomKeyInfo};
+    use super::super::test_utils::room_key_info;
+    use super::super::{VodozemacAccount, VodozemacGroupSession, VodozemacInboundGroupSession};
+    use super::*;
+    use vodozemac::megolm::SessionConfig;
+
+    const ROOM: &str = "!room:example.org";
+
+    fn info(origin: RoomKeyOrigin) -> RoomKeyInfo {
+        RoomKeyInfo {
+            backed_up: true,
+            ..room_key_info(ROOM, origin)
+        }
+    }
+
+    fn encrypt(session: &VodozemacGroupSession, body: &str) -> String {
+        encrypt_room_event(
+            session,
+            &VodozemacAccount::new().curve25519_key(),
+            "DEVICE".to_owned(),
+            ROOM.to_owned(),
+            "m.room.message".to_owned(),
+            format!(r#"{{"body":"{body}"}}"#),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_events_are_retried_when_the_key_arrives() {
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let full = outbound.to_inbound();
+        let queue = UtdQueue::new();
+        queue
+            .add_event(ROOM.to_owned(), "$0".to_owned(), encrypt(&outbound, "zero"))
+            .unwrap();
+        let late = outbound.to_inbound();
+        queue
+            .add_event(ROOM.to_owned(), "$1".to_owned(), encrypt(&outbound, "one"))
+            .unwrap();
+        assert_eq!(
+            queue.pending_events(ROOM.to_owned(), outbound.session_id()),
+            vec!["$0", "$1"]
+        );
+
+        // A forwarded key starting at index 1 only decrypts the second event.
+        let forwarded = InboundRoomKey::new(
+            &VodozemacInboundGroupSession::import(
+                late.export_at_first_known_index(),
+                SessionConfig::version_1().into(),
+            )
+            .unwrap(),
+            info(RoomKeyOrigin::Forwarded),
+        );
+        let retried = queue.add_room_key(&forwarded);
+        assert_eq!(retried.len(), 1);
+        assert_eq!(retried[0].event_id, "$1");
+        assert_eq!(retried[0].result.content, r#"{"body":"one"}"#);
+
+        let pickle_key = [4u8; 32];
+        let queue = UtdQueue::from_pickle_encrypted(queue.pickle_encrypted(pickle_key), pickle_key)
+            .unwrap();
+        assert_eq!(queue.len(), 1);
+
+        // Merging in the key from the creator makes the first event decryptable too.
+        assert_eq!(
+            forwarded.inbound_group_session().key_source(),
+            SessionKeySource::ExportedSessionKey
+        );
+        let direct = InboundRoomKey::new(&full, info(RoomKeyOrigin::Direct));
+        let retried = queue.merge_room_key(&forwarded, &direct).unwrap();
+        assert_eq!(retried.len(), 1);
+        assert_eq!(retried[0].event_id, "$0");
+        assert_eq!(
+            retried[0].result.authenticity,
+            KeyAuthenticity::Authenticated
+        );
+        assert_eq!(forwarded.first_known_index(), 0);
+        assert_eq!(
+            forwarded.inbound_group_session().key_source(),
+            SessionKeySource::SessionKey
+        );
+        assert!(!forwarded.info().backed_up);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_merge_with_itself() {
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let inbound = outbound.to_inbound();
+        let queue = UtdQueue::new();
+        let key = InboundRoomKey::new(&inbound, info(RoomKeyOrigin::Forwarded));
+        // Another key for the same session object.
+        let same_session = InboundRoomKey::new(&inbound, info(RoomKeyOrigin::Direct));
+
+        assert!(queue.merge_room_key(&key, &key).unwrap().is_empty());
+        assert!(queue
+            .merge_room_key(&key, &same_session)
+            .unwrap()
+            .is_empty());
+        assert_eq!(key.info().origin, RoomKeyOrigin::Forwarded);
+        assert!(key.info().backed_up);
+    }
+
+    #[test]
+    fn test_queue_is_bounded() {
+        let outbound = VodozemacGroupSession::new(SessionConfig::version_1().into());
+        let queue = UtdQueue::new();
+        for i in 0..3 {
+            let evicted = queue
+                .add_event(ROOM.to_owned(), format!("${i}"), encrypt(&outbound, "hi"))
+                .unwrap();
+            assert!(evicted.is_empty());
+        }
+        assert_eq!(queue.set_max_events(2), vec!["$0"]);
+        assert_eq!(
+            queue
+                .add_event(ROOM.to_owned(), "$3".to_owned(), encrypt(&outbound, "hi"))
+                .unwrap(),
+            vec!["$1"]
+        );
+        assert!(queue.remove_event("$2".to_owned()));
+        assert_eq!(queue.len(), 1);
+        assert_eq!(
+            queue.add_event(ROOM.to_owned(), "$4".to_owned(), "{}".to_owned()),
+            Err(RoomEventError::MalformedEvent)
+        );
+    }
+}
@@ -27,8 +27,12 @@ pub struct IOSDecryptResult {
 /// - error: Error message if decryption failed
 /// 
 /// Caller must free all non-NULL fields using `ios_free_result`
+///
+/// # Safety
+/// `pickled_session` and `ciphertext` must be NULL or point to NUL-terminated strings, and
+/// `pickle_key` must be NULL or point to 32 readable bytes
 #[no_mangle]
-pub extern "C" fn ios_decrypt_event(
+pub unsafe extern "C" fn ios_decrypt_event(
     pickled_session: *const c_char,
     pickle_key: *const [u8; 32],
     ciphertext: *const c_char,
@@ -80,7 +84,7 @@ pub extern "C" fn ios_decrypt_event(
 /// # Safety
 /// Must only be called with strings returned by iOS FFI functions
 #[no_mangle]
-pub extern "C" fn ios_free_string(s: *mut c_char) {
+pub unsafe extern "C" fn ios_free_string(s: *mut c_char) {
     if !s.is_null() {
         unsafe {
             let _ = CString::from_raw(s);
@@ -93,7 +97,7 @@ pub extern "C" fn ios_free_string(s: *mut c_char) {
 /// # Safety
 /// Must only be called with results returned by ios_decrypt_event
 #[no_mangle]
-pub extern "C" fn ios_free_result(result: IOSDecryptResult) {
+pub unsafe extern "C" fn ios_free_result(result: IOSDecryptResult) {
     ios_free_string(result.plaintext);
     ios_free_string(result.error);
 }
@@ -155,11 +159,9 @@ mod tests {
         let ciphertext_c = CString::new(ciphertext.to_base64()).unwrap();
 
         // Call the C function
-        let result = ios_decrypt_event(
-            pickled_c.as_ptr(),
-            &pickle_key,
-            ciphertext_c.as_ptr(),
-        );
+        let result = unsafe {
+            ios_decrypt_event(pickled_c.as_ptr(), &pickle_key, ciphertext_c.as_ptr())
+        };
 
         // Check for success
         if !result.error.is_null() {
@@ -173,7 +175,7 @@ mod tests {
         assert_eq!(result_plaintext, plaintext);
 
         // Clean up
-        ios_free_result(result);
+        unsafe { ios_free_result(result) };
     }
 
     #[test]
@@ -182,16 +184,14 @@ mod tests {
         let invalid_pickle = CString::new("invalid_base64_pickle").unwrap();
         let ciphertext = CString::new("some_ciphertext").unwrap();
 
-        let result = ios_decrypt_event(
-            invalid_pickle.as_ptr(),
-            &pickle_key,
-            ciphertext.as_ptr(),
-        );
+        let result = unsafe {
+            ios_decrypt_event(invalid_pickle.as_ptr(), &pickle_key, ciphertext.as_ptr())
+        };
 
         // Should have error
         assert!(!result.error.is_null());
         assert!(result.plaintext.is_null());
 
-        ios_free_result(result);
+        unsafe { ios_free_result(result) };
     }
 }